@@ -1,3 +1,9 @@
 fn main() {
+    // 업데이트 서명 공개키 없이 만든 릴리스는 업데이트를 설치할 수 없으므로 빌드 실패 처리
+    println!("cargo:rerun-if-env-changed=YDH_UPDATER_PUBKEY");
+    let has_pubkey = std::env::var("YDH_UPDATER_PUBKEY").map(|k| !k.trim().is_empty()).unwrap_or(false);
+    if std::env::var("PROFILE").as_deref() == Ok("release") && !has_pubkey {
+        panic!("릴리스 빌드에는 YDH_UPDATER_PUBKEY 환경 변수(업데이트 서명 공개키)가 필요합니다");
+    }
     tauri_build::build()
-} 
\ No newline at end of file
+}
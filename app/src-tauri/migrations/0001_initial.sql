@@ -0,0 +1,23 @@
+-- 영상 인덱스 기본 스키마
+CREATE TABLE IF NOT EXISTS videos (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    video_path TEXT NOT NULL UNIQUE,
+    captions_path TEXT NOT NULL,
+    video_id TEXT,
+    title TEXT NOT NULL,
+    channel TEXT NOT NULL,
+    upload_date TEXT,
+    duration TEXT,
+    duration_seconds INTEGER,
+    view_count INTEGER,
+    topic TEXT,               -- JSON 배열
+    source_url TEXT,
+    excerpt TEXT,
+    transcript_quality REAL,
+    captions_mtime INTEGER,   -- 인덱싱 시점 captions 파일 수정 시간 (unix 초)
+    indexed_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_videos_channel ON videos(channel);
+CREATE INDEX IF NOT EXISTS idx_videos_upload_date ON videos(upload_date);
+CREATE INDEX IF NOT EXISTS idx_videos_video_id ON videos(video_id);
@@ -0,0 +1,5 @@
+-- Obsidian 등에서 직접 수정된 captions.md 추적
+ALTER TABLE videos ADD COLUMN needs_reembedding INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE videos ADD COLUMN reconciled_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_videos_needs_reembedding ON videos(needs_reembedding);
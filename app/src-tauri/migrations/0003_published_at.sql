@@ -0,0 +1,4 @@
+-- 업로드 시각 (요일/시간대 분석용, UTC RFC3339)
+ALTER TABLE videos ADD COLUMN published_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_videos_channel_upload ON videos(channel, upload_date);
@@ -0,0 +1,9 @@
+-- 메타데이터 갱신 시점별 조회수 기록 (조회수 증가 추세 분석용)
+CREATE TABLE IF NOT EXISTS view_count_snapshots (
+    video_id TEXT NOT NULL,
+    captured_at TEXT NOT NULL,
+    view_count INTEGER NOT NULL,
+    PRIMARY KEY (video_id, captured_at)
+);
+
+CREATE INDEX IF NOT EXISTS idx_view_count_snapshots_captured_at ON view_count_snapshots(captured_at);
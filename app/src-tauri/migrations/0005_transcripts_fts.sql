@@ -0,0 +1,9 @@
+-- 자막 전문 검색 인덱스 (trigram: 한국어 조사가 붙은 단어도 부분 일치로 검색)
+CREATE VIRTUAL TABLE IF NOT EXISTS transcripts_fts USING fts5(
+    video_path UNINDEXED,
+    channel UNINDEXED,
+    upload_date UNINDEXED,
+    title,
+    content,
+    tokenize = 'trigram'
+);
@@ -0,0 +1,11 @@
+-- 영상 간 상호 참조 (설명/자막의 링크 또는 제목 언급)
+CREATE TABLE IF NOT EXISTS video_references (
+    source_video_id TEXT NOT NULL,
+    target_video_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    context TEXT,
+    detected_at TEXT NOT NULL,
+    PRIMARY KEY (source_video_id, target_video_id, kind)
+);
+
+CREATE INDEX IF NOT EXISTS idx_video_references_target ON video_references(target_video_id);
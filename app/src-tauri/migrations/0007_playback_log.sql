@@ -0,0 +1,9 @@
+-- 시청 기록 (로컬 전용, 채널/주제별 시청 시간 리포트용)
+CREATE TABLE IF NOT EXISTS playback_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    video_path TEXT NOT NULL,
+    watched_at TEXT NOT NULL,
+    seconds REAL NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_playback_log_watched_at ON playback_log(watched_at);
@@ -0,0 +1,14 @@
+-- 영상별 처리 파이프라인 상태 (다운로드 → 자막 → 썸네일 → 임베딩 → 요약)
+CREATE TABLE IF NOT EXISTS pipeline_status (
+    video_path TEXT PRIMARY KEY,
+    downloaded INTEGER NOT NULL DEFAULT 0,
+    captions INTEGER NOT NULL DEFAULT 0,
+    thumbnails INTEGER NOT NULL DEFAULT 0,
+    embedded INTEGER NOT NULL DEFAULT 0,
+    summarized INTEGER NOT NULL DEFAULT 0,
+    stuck_at TEXT,            -- 완료되지 않은 첫 단계 (모두 완료면 NULL)
+    captions_mtime INTEGER,   -- 자막 검사 시점 captions 파일 수정 시간 (변경 시에만 다시 읽음)
+    updated_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_pipeline_status_stuck_at ON pipeline_status(stuck_at);
@@ -0,0 +1,2 @@
+-- 영상 폴더에 있는 자막 트랙 언어 목록 (JSON 배열, 플레이어 자막 전환용)
+ALTER TABLE videos ADD COLUMN caption_languages TEXT;
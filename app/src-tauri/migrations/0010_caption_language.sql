@@ -0,0 +1,3 @@
+-- captions.md 본문에서 감지한 자막 언어 (ISO 639-1, 감지 실패 시 빈 문자열, 미검사 NULL)
+ALTER TABLE videos ADD COLUMN caption_language TEXT;
+CREATE INDEX IF NOT EXISTS idx_videos_caption_language ON videos(caption_language);
@@ -0,0 +1,23 @@
+-- 다운로드 실행 기록 (작업 큐에서 실행된 작업마다 한 건)
+CREATE TABLE IF NOT EXISTS download_runs (
+    job_id TEXT PRIMARY KEY,
+    kind TEXT NOT NULL,          -- batch, full_scan, since, single
+    started_at TEXT NOT NULL,
+    finished_at TEXT NOT NULL,
+    status TEXT NOT NULL,        -- completed, failed, cancelled
+    videos_added INTEGER NOT NULL DEFAULT 0,
+    failures INTEGER NOT NULL DEFAULT 0,
+    message TEXT
+);
+CREATE INDEX IF NOT EXISTS idx_download_runs_finished ON download_runs(finished_at);
+
+-- 실행별 채널 결과
+CREATE TABLE IF NOT EXISTS download_run_channels (
+    job_id TEXT NOT NULL REFERENCES download_runs(job_id) ON DELETE CASCADE,
+    channel TEXT NOT NULL,
+    videos_added INTEGER NOT NULL DEFAULT 0,
+    failures INTEGER NOT NULL DEFAULT 0,
+    error TEXT,
+    PRIMARY KEY (job_id, channel)
+);
+CREATE INDEX IF NOT EXISTS idx_download_run_channels_channel ON download_run_channels(channel);
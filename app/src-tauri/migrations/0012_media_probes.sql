@@ -0,0 +1,19 @@
+-- ffprobe로 읽은 영상 파일 기술 정보 (파일 크기/수정 시간이 바뀌면 다시 읽음)
+CREATE TABLE IF NOT EXISTS media_probes (
+    video_path TEXT PRIMARY KEY,
+    file_size INTEGER NOT NULL,
+    file_mtime INTEGER NOT NULL,   -- unix 초
+    video_codec TEXT,              -- h264, vp9, av1 ... (소문자)
+    width INTEGER,
+    height INTEGER,
+    fps REAL,
+    bitrate INTEGER,               -- 전체 비트레이트 (bit/s)
+    audio_codec TEXT,
+    audio_channels INTEGER,
+    duration_seconds REAL,
+    error TEXT,                    -- ffprobe 실패 시 메시지
+    probed_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_media_probes_video_codec ON media_probes(video_codec);
+CREATE INDEX IF NOT EXISTS idx_media_probes_height ON media_probes(height);
@@ -0,0 +1,9 @@
+-- 영상별 마지막 재생 위치 (앱 재시작 후 이어보기, 끝까지 본 영상은 삭제)
+CREATE TABLE IF NOT EXISTS watch_positions (
+    video_id TEXT PRIMARY KEY,
+    position_seconds REAL NOT NULL,
+    duration_seconds REAL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_watch_positions_updated_at ON watch_positions(updated_at);
@@ -0,0 +1,5 @@
+-- 즐겨찾기 영상 (video_id 기준이라 폴더 이동/인덱스 재구성 후에도 유지)
+CREATE TABLE IF NOT EXISTS favorites (
+    video_id TEXT PRIMARY KEY,
+    favorited_at TEXT NOT NULL
+);
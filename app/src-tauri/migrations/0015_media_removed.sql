@@ -0,0 +1,2 @@
+-- 보관 정책으로 미디어 파일만 삭제하고 자막은 남긴 영상 (1이면 재생 불가, 검색/노트는 유지)
+ALTER TABLE videos ADD COLUMN media_removed INTEGER NOT NULL DEFAULT 0;
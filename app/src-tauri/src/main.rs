@@ -3,15 +3,15 @@
 use tauri::command;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::io::{Write, BufRead, BufReader};
 use std::env;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use tauri::{Emitter, Window, State, Manager};
 use urlencoding::decode;
 use regex::Regex;
-use std::sync::{Arc, atomic::{AtomicBool, Ordering}};
+use std::sync::{Arc, atomic::{AtomicBool, AtomicUsize, Ordering}};
 use std::sync::Mutex;
 use std::thread;
 use std::time::{Duration, Instant};
@@ -35,6 +35,8 @@ struct VideoMetadata {
     video_id: Option<String>,
     source_url: Option<String>,
     excerpt: Option<String>,
+    transcript_quality: Option<f64>,
+    published_at: Option<String>,
 }
 
 // RAG 설정 관련 구조체들 (TypeScript와 동기화)
@@ -82,6 +84,8 @@ struct AnswerConfig {
     enable_adaptive_temperature: bool,
     factual_temperature: f64,
     analytical_temperature: f64,
+    #[serde(default)]
+    answer_language: Option<String>,  // None이면 채널 감지 언어 사용
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -139,6 +143,7 @@ impl Default for RAGSettings {
                 enable_adaptive_temperature: true,
                 factual_temperature: 0.4,
                 analytical_temperature: 0.65,
+                answer_language: None,
             },
             ui_preferences: UIPreferences {
                 show_advanced_settings: false,
@@ -169,13 +174,68 @@ struct VideoInfo {
     video_id: Option<String>,
     source_url: Option<String>,
     excerpt: Option<String>,
+    transcript_quality: Option<f64>,
+    published_at: Option<String>,
+    #[serde(default)]
+    status: VideoStatus,
+    #[serde(default)]
+    pipeline: Option<PipelineStatus>, // 인덱스 조회 시에만 채워짐
+    #[serde(default)]
+    caption_languages: Vec<String>,   // 플레이어에서 전환 가능한 자막 트랙 (captions.<언어>.vtt)
+    #[serde(default)]
+    caption_language: Option<String>, // captions.md 본문에서 감지한 언어 (인덱스 조회 시에만 채워짐)
+    #[serde(default)]
+    playlist: Option<String>,         // 재생목록 소스로 받은 영상의 재생목록 이름 (목록 조회 시에만 채워짐)
+    #[serde(default)]
+    favorite: bool,                   // 즐겨찾기 여부 (인덱스 조회 시에만 채워짐)
 }
 
-#[derive(Serialize, Deserialize)]
+// 목록 조회 시 영상 상태 (pending: 다운로드/인덱싱 진행 중, media_removed: 자막만 보관)
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+enum VideoStatus {
+    #[default]
+    Ready,
+    Pending,
+    MediaRemoved,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
 struct ChannelInfo {
     url: String,
     name: String,
     enabled: bool,
+    #[serde(default)]
+    memorialized: bool,
+    #[serde(default)]
+    playlist: bool, // 채널이 아닌 재생목록 URL
+    #[serde(default)]
+    state: ChannelState,
+    #[serde(default)]
+    quality: Option<String>,   // channels.toml의 채널 전용 화질 (없으면 전역 설정)
+    #[serde(default)]
+    language: Option<String>,  // channels.toml의 자막 언어 (없으면 감지 언어)
+    #[serde(default)]
+    group: Option<String>,
+    #[serde(default)]
+    filters: ChannelContentFilters,
+}
+
+// 채널 상태: 일시정지/보관 채널은 다운로드하지 않음 (보관 채널은 기본 목록에서도 숨기지만 검색/RAG에는 포함)
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+enum ChannelState {
+    #[default]
+    Active,
+    Paused,
+    Archived,
+}
+
+impl ChannelInfo {
+    // 배치 다운로드 대상 여부
+    fn downloadable(&self) -> bool {
+        self.enabled && !self.memorialized && self.state == ChannelState::Active
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -187,12 +247,15 @@ struct DownloadProgress {
     total_videos: u32,
     completed_videos: u32,
     log_message: String,
+    #[serde(default)]
+    throughput_bps: Option<f64>, // yt-dlp 진행률 줄의 현재 다운로드 속도 (bytes/s)
 }
 
 #[derive(Serialize, Deserialize)]
 struct AppStatus {
     total_videos: u32,
     total_channels: u32,
+    memorialized_channels: u32,
     vault_size_mb: f64,
     last_download: Option<String>,
     vector_db_status: String,
@@ -209,13 +272,6 @@ struct RecentVideos {
     channels: Vec<ChannelVideos>,
 }
 
-// 다운로드 중단을 위한 상태 관리
-#[derive(Default, Clone)]
-struct DownloadState {
-    is_cancelled: Arc<AtomicBool>,
-    current_process: Arc<Mutex<Option<std::process::Child>>>,
-}
-
 // 비디오 변환을 위한 상태 관리
 #[derive(Default, Clone)]
 struct ConversionState {
@@ -227,9 +283,70 @@ struct ConversionState {
 #[derive(Default, Clone)]
 struct EmbeddingState {
     is_cancelled: Arc<AtomicBool>,
+    is_running: Arc<AtomicBool>,
     current_process: Arc<Mutex<Option<std::process::Child>>>,
 }
 
+// 스코프를 벗어나면 실행 중 플래그를 해제 (조기 return 포함)
+struct RunningGuard(Arc<AtomicBool>);
+
+impl RunningGuard {
+    fn new(flag: &Arc<AtomicBool>) -> Self {
+        flag.store(true, Ordering::SeqCst);
+        RunningGuard(flag.clone())
+    }
+    
+    // 이미 실행 중이면 None (확인과 표시를 한 번에 해서 동시에 시작하지 않도록)
+    fn try_new(flag: &Arc<AtomicBool>) -> Option<Self> {
+        (!flag.swap(true, Ordering::SeqCst)).then(|| RunningGuard(flag.clone()))
+    }
+}
+
+impl Drop for RunningGuard {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
+// 썸네일/파형/미리보기 생성을 위한 상태 관리
+#[derive(Default, Clone)]
+struct DerivativeState {
+    is_running: Arc<AtomicBool>,
+    is_cancelled: Arc<AtomicBool>,
+    pending: Arc<Mutex<Vec<PathBuf>>>, // 실행 중에 끝난 다운로드가 새로 만든 영상 폴더 (실행 중인 작업이 이어서 처리)
+}
+
+// 메타데이터 일괄 수정 작업 상태 관리
+#[derive(Default, Clone)]
+struct MetadataJobState {
+    is_running: Arc<AtomicBool>,
+    is_cancelled: Arc<AtomicBool>,
+}
+
+// 업데이트 설치를 미뤄야 하는 vault 가져오기/이동/정리 작업 실행 여부
+#[derive(Default, Clone)]
+struct VaultJobState {
+    importing: Arc<AtomicBool>,
+    merging_channels: Arc<AtomicBool>,
+    migrating_layout: Arc<AtomicBool>,
+    retranscribing: Arc<AtomicBool>,
+    housekeeping: Arc<AtomicBool>,
+    index_maintenance: Arc<AtomicBool>,
+    finalizing_ingests: Arc<AtomicUsize>, // 업로드 여러 건이 동시에 등록될 수 있으므로 개수로 관리
+}
+
+// 재생 대기열 상태 관리 (앱 시작 시 config/play_queue.json에서 복원)
+#[derive(Default, Clone)]
+struct PlayQueueState {
+    entries: Arc<Mutex<Vec<QueueEntry>>>,
+}
+
+// 분리된 창들이 공유하는 현재 재생 세션
+#[derive(Default, Clone)]
+struct PlaybackSyncState {
+    session: Arc<Mutex<Option<PlaybackSession>>>,
+}
+
 // Range 지원 HTTP 서버 상태 관리
 #[derive(Default)]
 struct VideoServerState {
@@ -237,6 +354,28 @@ struct VideoServerState {
     server_handle: Arc<RwLock<Option<tokio::task::JoinHandle<()>>>>,
 }
 
+// Vault 잠금 상태 관리 (다른 인스턴스가 잠금을 가진 경우 읽기 전용)
+#[derive(Default, Clone)]
+struct VaultLockState {
+    read_only: Arc<AtomicBool>,
+}
+
+// LAN 공유 링크 서버 상태 관리 (앱 재시작 시 서명 키가 바뀌어 기존 링크 만료)
+#[derive(Clone)]
+struct ShareServerState {
+    port: Arc<RwLock<Option<u16>>>,
+    secret: Arc<std::sync::RwLock<Vec<u8>>>,
+}
+
+impl Default for ShareServerState {
+    fn default() -> Self {
+        Self {
+            port: Arc::new(RwLock::new(None)),
+            secret: Arc::new(std::sync::RwLock::new(new_share_secret())),
+        }
+    }
+}
+
 // 서버 에러 타입 정의
 #[derive(Debug)]
 struct ServerError;
@@ -251,6 +390,76 @@ struct AIProgressUpdate {
     details: Option<String>,
 }
 
+// 진행 이벤트 페이로드 스키마 (프런트엔드/외부 API 소비자용)
+// 필드를 추가/변경하면 해당 이벤트의 version을 올리고, 새 필드는 #[serde(default)]로 이전 페이로드와 호환 유지
+#[derive(Serialize, Clone, Copy)]
+struct EventFieldSchema {
+    name: &'static str,
+    #[serde(rename = "type")]
+    field_type: &'static str, // string / number / integer
+    optional: bool,           // null 또는 누락 가능
+    description: &'static str,
+}
+
+#[derive(Serialize, Clone)]
+struct EventSchema {
+    event: &'static str,
+    version: u32,
+    payload: &'static str,
+    description: &'static str,
+    fields: &'static [EventFieldSchema],
+}
+
+const fn event_field(name: &'static str, field_type: &'static str, optional: bool, description: &'static str) -> EventFieldSchema {
+    EventFieldSchema { name, field_type, optional, description }
+}
+
+const DOWNLOAD_PROGRESS_FIELDS: &[EventFieldSchema] = &[
+    event_field("channel", "string", false, "작업 대상 (병렬 작업은 이 값으로 구분)"),
+    event_field("status", "string", false, "진행 상태 (시작/진행 중/완료/실패 등)"),
+    event_field("progress", "number", false, "진행률 0~100"),
+    event_field("current_video", "string", false, "현재 처리 중인 항목"),
+    event_field("total_videos", "integer", false, "전체 항목 수"),
+    event_field("completed_videos", "integer", false, "완료한 항목 수"),
+    event_field("log_message", "string", false, "로그 한 줄"),
+    event_field("throughput_bps", "number", true, "현재 다운로드 속도 (bytes/s)"),
+];
+
+const AI_PROGRESS_FIELDS: &[EventFieldSchema] = &[
+    event_field("step", "string", false, "파이프라인 단계"),
+    event_field("message", "string", false, "진행 메시지"),
+    event_field("progress", "number", false, "진행률 0~100"),
+    event_field("details", "string", true, "추가 정보"),
+];
+
+fn event_schemas() -> Vec<EventSchema> {
+    let progress = |event, description| EventSchema {
+        event,
+        version: 1,
+        payload: "DownloadProgress",
+        description,
+        fields: DOWNLOAD_PROGRESS_FIELDS,
+    };
+    vec![
+        progress("download-progress", "영상 다운로드 진행 상황"),
+        progress("embedding-progress", "벡터 임베딩 진행 상황"),
+        progress("conversion-progress", "영상 변환 진행 상황"),
+        EventSchema {
+            event: "ai-progress",
+            version: 1,
+            payload: "AIProgressUpdate",
+            description: "AI 답변 생성 진행 상황",
+            fields: AI_PROGRESS_FIELDS,
+        },
+    ]
+}
+
+// 진행 이벤트 페이로드 스키마 목록
+#[command]
+fn get_event_schemas() -> Vec<EventSchema> {
+    event_schemas()
+}
+
 // 프로젝트 루트 경로 찾기
 fn get_project_root() -> PathBuf {
     let current_dir = env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
@@ -288,7 +497,7 @@ fn get_debug_info() -> Result<String, String> {
     let current_dir = env::current_dir().map_err(|e| e.to_string())?;
     let project_root = get_project_root();
     let vault_path = project_root.join("vault");
-    let channels_path = project_root.join("channels.txt");
+    let channels_path = project_root.join(CHANNELS_CONFIG_FILE);
     
     let mut info = Vec::new();
     info.push(format!("Current Directory: {}", current_dir.display()));
@@ -315,9 +524,8 @@ fn get_debug_info() -> Result<String, String> {
     Ok(info.join("\n"))
 }
 
-// 비디오 목록 조회
-#[command]
-fn list_videos() -> Result<Vec<VideoInfo>, String> {
+// vault 폴더를 직접 스캔한 영상 목록 (인덱스 동기화 등 내부용)
+fn scan_vault_videos() -> Result<Vec<VideoInfo>, String> {
     let project_root = get_project_root();
     let root = project_root.join("vault").join("10_videos");
     let mut videos = Vec::new();
@@ -326,79 +534,95 @@ fn list_videos() -> Result<Vec<VideoInfo>, String> {
         return Err(format!("비디오 디렉토리가 존재하지 않습니다: {}", root.display()));
     }
     
-    collect_videos(&root, &mut videos)?;
+    let mut files = Vec::new();
+    collect_video_files(&root, &mut files)?;
+    for path in files {
+        videos.push(read_video_info(&path)?);
+    }
     Ok(videos)
 }
 
-fn collect_videos(dir: &PathBuf, videos: &mut Vec<VideoInfo>) -> Result<(), String> {
+// vault 아래 video.mp4 파일 경로만 수집 (메타데이터는 읽지 않음)
+// 보관 정책으로 미디어만 삭제된 폴더도 captions.md가 있으면 video.mp4 경로로 포함
+fn collect_video_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
     let entries = fs::read_dir(dir).map_err(|e| format!("디렉토리 읽기 실패 {}: {}", dir.display(), e))?;
+    let video_file = dir.join("video.mp4");
+    if !video_file.exists() && dir.join("captions.md").is_file() {
+        files.push(video_file);
+    }
     
     for entry in entries {
         let entry = entry.map_err(|e| e.to_string())?;
         let path = entry.path();
         
         if path.is_dir() {
-            collect_videos(&path, videos)?;
+            collect_video_files(&path, files)?;
         } else if path.file_name().map(|n| n == "video.mp4").unwrap_or(false) {
-            let folder = path.parent().unwrap();
-            let captions_md = folder.join("captions.md");
-            let captions_txt = folder.join("captions.txt");
-            
-            // YAML frontmatter에서 메타데이터 읽기
-            let metadata = if captions_md.exists() {
-                parse_markdown_metadata(&captions_md)?
-            } else {
-                VideoMetadata {
-                    title: extract_title_from_path(&path),
-                    channel: extract_channel_from_path(&path),
-                    upload_date: None,
-                    duration: None,
-                    duration_seconds: None,
-                    view_count: None,
-                    topic: None,
-                    video_id: None,
-                    source_url: None,
-                    excerpt: None,
-                }
-            };
-            
-            // 프로젝트 루트 기준 상대 경로 생성 (asset protocol 호환)
-            let project_root = get_project_root();
-            
-            // 비디오 파일 상대 경로
-            let video_relative = if let Ok(relative) = path.strip_prefix(&project_root) {
-                relative.to_string_lossy().to_string()
-            } else {
-                path.to_string_lossy().to_string()
-            };
-            
-            // 캡션 파일 상대 경로
-            let captions_file = if captions_txt.exists() { captions_txt } else { captions_md };
-            let captions_relative = if let Ok(relative) = captions_file.strip_prefix(&project_root) {
-                relative.to_string_lossy().to_string()
-            } else {
-                captions_file.to_string_lossy().to_string()
-            };
-            
-            videos.push(VideoInfo {
-                video_path: video_relative,
-                captions_path: captions_relative,
-                title: metadata.title,
-                channel: metadata.channel,
-                upload_date: metadata.upload_date,
-                duration: metadata.duration,
-                duration_seconds: metadata.duration_seconds,
-                view_count: metadata.view_count,
-                topic: metadata.topic,
-                video_id: metadata.video_id,
-                source_url: metadata.source_url,
-                excerpt: metadata.excerpt,
-            });
+            files.push(path);
         }
     }
     Ok(())
 }
 
+// 프로젝트 루트 기준 상대 경로 (asset protocol 호환, 루트 밖이면 그대로)
+fn project_relative_path(path: &Path) -> String {
+    match path.strip_prefix(get_project_root()) {
+        Ok(relative) => relative.to_string_lossy().to_string(),
+        Err(_) => path.to_string_lossy().to_string(),
+    }
+}
+
+// 영상 파일 하나의 정보를 captions.md frontmatter에서 읽기
+fn read_video_info(path: &Path) -> Result<VideoInfo, String> {
+    let folder = path.parent().ok_or_else(|| format!("잘못된 영상 경로: {}", path.display()))?;
+    let captions_md = folder.join("captions.md");
+    let captions_txt = folder.join("captions.txt");
+    
+    // YAML frontmatter에서 메타데이터 읽기
+    let metadata = if captions_md.exists() {
+        parse_markdown_metadata(&captions_md)?
+    } else {
+        VideoMetadata {
+            title: extract_title_from_path(&path.to_path_buf()),
+            channel: extract_channel_from_path(&path.to_path_buf()),
+            upload_date: None,
+            duration: None,
+            duration_seconds: None,
+            view_count: None,
+            topic: None,
+            video_id: None,
+            source_url: None,
+            excerpt: None,
+            transcript_quality: None,
+            published_at: None,
+        }
+    };
+    
+    let captions_file = if captions_txt.exists() { captions_txt } else { captions_md };
+    Ok(VideoInfo {
+        video_path: project_relative_path(path),
+        captions_path: project_relative_path(&captions_file),
+        title: metadata.title,
+        channel: metadata.channel,
+        upload_date: metadata.upload_date,
+        duration: metadata.duration,
+        duration_seconds: metadata.duration_seconds,
+        view_count: metadata.view_count,
+        topic: metadata.topic,
+        video_id: metadata.video_id,
+        source_url: metadata.source_url,
+        excerpt: metadata.excerpt,
+        transcript_quality: metadata.transcript_quality,
+        published_at: metadata.published_at,
+        status: if path.exists() { VideoStatus::Ready } else { VideoStatus::MediaRemoved },
+        pipeline: None,
+        caption_languages: caption_track_languages(folder),
+        caption_language: None,
+        playlist: None,
+        favorite: false,
+    })
+}
+
 fn parse_markdown_metadata(path: &PathBuf) -> Result<VideoMetadata, String> {
     let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
     
@@ -418,6 +642,9 @@ fn parse_markdown_metadata(path: &PathBuf) -> Result<VideoMetadata, String> {
             let video_id = extract_yaml_field(yaml_content, "video_id");
             let source_url = extract_yaml_field(yaml_content, "source_url");
             let excerpt = extract_yaml_field(yaml_content, "excerpt");
+            let transcript_quality = extract_yaml_field(yaml_content, "transcript_quality")
+                .and_then(|s| s.parse::<f64>().ok());
+            let published_at = extract_yaml_field(yaml_content, "published_at").filter(|s| !s.is_empty());
             
             // topic 배열 파싱
             let topic = extract_yaml_array(yaml_content, "topic");
@@ -433,6 +660,8 @@ fn parse_markdown_metadata(path: &PathBuf) -> Result<VideoMetadata, String> {
                 video_id,
                 source_url,
                 excerpt,
+                transcript_quality,
+                published_at,
             });
         }
     }
@@ -448,6 +677,8 @@ fn parse_markdown_metadata(path: &PathBuf) -> Result<VideoMetadata, String> {
         video_id: None,
         source_url: None,
         excerpt: None,
+        transcript_quality: None,
+        published_at: None,
     })
 }
 
@@ -511,39 +742,213 @@ fn extract_channel_from_path(path: &PathBuf) -> String {
 }
 
 // 채널 목록 관리
+// 채널 목록 (보관 채널은 include_archived일 때만)
 #[command]
-fn list_channels() -> Result<Vec<ChannelInfo>, String> {
-    let project_root = get_project_root();
-    let channels_file = project_root.join("channels.txt");
-    
-    if !channels_file.exists() {
-        return Ok(vec![]);
+fn list_channels(include_archived: Option<bool>) -> Result<Vec<ChannelInfo>, String> {
+    let mut channels = read_channels()?;
+    if !include_archived.unwrap_or(false) {
+        channels.retain(|c| c.state != ChannelState::Archived);
+    }
+    Ok(channels)
+}
+
+// 채널 목록 설정 (프로젝트 루트의 channels.toml, 채널마다 [[channels]] 항목)
+const CHANNELS_CONFIG_FILE: &str = "channels.toml";
+// 이전 형식 (한 줄에 URL 하나, "# " 접두사로 비활성화) - 처음 읽을 때 channels.toml로 옮김
+const LEGACY_CHANNELS_FILE: &str = "channels.txt";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+struct ChannelsConfig {
+    #[serde(default)]
+    channels: Vec<ChannelEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct ChannelEntry {
+    url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,      // Vault 채널 폴더 이름 (없으면 URL에서 추출)
+    #[serde(default = "default_channel_enabled")]
+    enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    quality: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    group: Option<String>,
+    #[serde(default, skip_serializing_if = "ChannelContentFilters::is_empty")]
+    filters: ChannelContentFilters,
+}
+
+fn default_channel_enabled() -> bool {
+    true
+}
+
+impl ChannelEntry {
+    fn new(url: String, enabled: bool) -> Self {
+        ChannelEntry {
+            name: Some(extract_channel_name_from_url(&url)),
+            url,
+            enabled,
+            quality: None,
+            language: None,
+            group: None,
+            filters: ChannelContentFilters::default(),
+        }
     }
     
-    let content = fs::read_to_string(&channels_file).map_err(|e| e.to_string())?;
-    let mut channels = Vec::new();
-    
+    fn channel_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| extract_channel_name_from_url(&self.url))
+    }
+}
+
+const CHANNELS_CONFIG_HEADER: &str = "# Y-Data-House 채널 목록
+# 채널마다 [[channels]] 항목을 추가하세요 (url만 필수)
+#
+# [[channels]]
+# url = \"https://www.youtube.com/@채널명\"
+# enabled = true        # false면 다운로드하지 않음
+# quality = \"1080p\"     # 채널 전용 화질 (없으면 전역 설정)
+# language = \"ko\"       # 자막 언어 (없으면 감지 언어)
+# group = \"경제\"
+#
+# [channels.filters]
+# skip_shorts = true
+
+";
+
+fn channels_config_path() -> PathBuf {
+    get_project_root().join(CHANNELS_CONFIG_FILE)
+}
+
+// channels.txt → channels.toml 1회 이전 (주석 처리된 URL은 비활성 채널로, 원본은 .bak으로 보존)
+fn migrate_legacy_channels_file() -> Result<Option<ChannelsConfig>, String> {
+    let legacy_file = get_project_root().join(LEGACY_CHANNELS_FILE);
+    if !legacy_file.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&legacy_file).map_err(|e| e.to_string())?;
+    let mut config = ChannelsConfig::default();
     for line in content.lines() {
         let line = line.trim();
-        if line.is_empty() || line.starts_with('#') {
+        let (url, enabled) = match line.strip_prefix('#') {
+            Some(rest) => (rest.trim(), false),
+            None => (line, true),
+        };
+        // 설명 주석은 건너뛰고 주석 처리된 URL만 비활성 채널로 옮김
+        let is_url = url.starts_with("http") && (url.contains("youtube.com") || url.contains("youtu.be"));
+        if !is_url || config.channels.iter().any(|c| c.url == url) {
             continue;
         }
-        
-        let enabled = !line.starts_with("# ");
-        let url = if enabled { line } else { &line[2..] };
-        let name = extract_channel_name_from_url(url);
-        
-        channels.push(ChannelInfo {
-            url: url.to_string(),
-            name,
-            enabled,
-        });
+        config.channels.push(ChannelEntry::new(url.to_string(), enabled));
+    }
+    save_channels_config(&config)?;
+    fs::rename(&legacy_file, legacy_file.with_extension("txt.bak"))
+        .map_err(|e| format!("channels.txt 백업 실패: {}", e))?;
+    println!("📋 channels.txt의 채널 {}개를 channels.toml로 옮겼습니다", config.channels.len());
+    Ok(Some(config))
+}
+
+// channel_settings.json에 저장돼 있던 콘텐츠 필터 → channels.toml 1회 이전
+// (이전에는 두 값을 OR로 합쳐 적용했으므로 같은 방식으로 합쳐 동작을 유지)
+fn migrate_legacy_channel_filters(config: &mut ChannelsConfig) -> Result<(), String> {
+    let mut settings = load_channel_settings();
+    let legacy: Vec<(String, ChannelContentFilters)> = settings
+        .iter()
+        .map(|(name, s)| (name.clone(), ChannelContentFilters {
+            skip_shorts: s.skip_shorts,
+            skip_live: s.skip_live,
+            min_duration_seconds: s.min_duration_seconds,
+        }))
+        .filter(|(_, filters)| !filters.is_empty())
+        .collect();
+    if legacy.is_empty() {
+        return Ok(());
     }
+    for (name, filters) in &legacy {
+        if let Some(entry) = config.channels.iter_mut().find(|entry| &entry.channel_name() == name) {
+            entry.filters.skip_shorts |= filters.skip_shorts;
+            entry.filters.skip_live |= filters.skip_live;
+            entry.filters.min_duration_seconds = entry.filters.min_duration_seconds.or(filters.min_duration_seconds);
+        }
+        if let Some(s) = settings.get_mut(name) {
+            s.skip_shorts = false;
+            s.skip_live = false;
+            s.min_duration_seconds = None;
+        }
+    }
+    save_channels_config(config)?;
+    save_channel_settings(&settings)?;
+    println!("📋 채널 {}개의 콘텐츠 필터를 channels.toml로 옮겼습니다", legacy.len());
+    Ok(())
+}
+
+fn load_channels_config() -> Result<ChannelsConfig, String> {
+    let path = channels_config_path();
+    if !path.exists() {
+        return Ok(migrate_legacy_channels_file()?.unwrap_or_default());
+    }
+    let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    let mut config: ChannelsConfig = toml::from_str(&content).map_err(|e| format!("channels.toml 파싱 실패: {}", e))?;
+    migrate_legacy_channel_filters(&mut config)?;
+    Ok(config)
+}
+
+fn save_channels_config(config: &ChannelsConfig) -> Result<(), String> {
+    let body = toml::to_string_pretty(config).map_err(|e| format!("channels.toml 직렬화 실패: {}", e))?;
+    fs::write(channels_config_path(), format!("{}{}", CHANNELS_CONFIG_HEADER, body))
+        .map_err(|e| format!("channels.toml 저장 실패: {}", e))
+}
+
+// channels.toml의 전체 채널 (상태와 무관)
+fn read_channels() -> Result<Vec<ChannelInfo>, String> {
+    let config = load_channels_config()?;
+    let channel_settings = load_channel_settings();
     
+    let channels = config
+        .channels
+        .into_iter()
+        .map(|entry| {
+            let name = entry.channel_name();
+            let settings = channel_settings.get(&name);
+            ChannelInfo {
+                playlist: playlist_id_from_url(&entry.url).is_some(),
+                memorialized: settings.map(|s| s.memorialized_at.is_some()).unwrap_or(false),
+                state: settings.map(|s| s.state).unwrap_or_default(),
+                url: entry.url,
+                name,
+                enabled: entry.enabled,
+                quality: entry.quality,
+                language: entry.language,
+                group: entry.group,
+                filters: entry.filters,
+            }
+        })
+        .collect();
     Ok(channels)
 }
 
+// 재생목록 소스의 Vault 폴더 접두사 (playlist_<재생목록 ID>, 채널 폴더와 구분)
+const PLAYLIST_FOLDER_PREFIX: &str = "playlist_";
+
+// YouTube 재생목록 URL의 list ID (/playlist?list=... 또는 list 파라미터가 있는 watch URL)
+fn playlist_id_from_url(url: &str) -> Option<String> {
+    if !url.contains("youtube.com/") || url.contains("/@") || url.contains("/channel/") {
+        return None;
+    }
+    let query = url.split_once('?')?.1;
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == "list")
+        .map(|(_, value)| value.split('#').next().unwrap_or(value).to_string())
+        .filter(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+}
+
 fn extract_channel_name_from_url(url: &str) -> String {
+    if let Some(playlist_id) = playlist_id_from_url(url) {
+        return format!("{}{}", PLAYLIST_FOLDER_PREFIX, playlist_id);
+    }
     let raw_name = if let Some(at_pos) = url.rfind('@') {
         &url[at_pos+1..]
     } else if let Some(slash_pos) = url.rfind('/') {
@@ -559,2823 +964,17369 @@ fn extract_channel_name_from_url(url: &str) -> String {
     }
 }
 
-#[command]
-fn add_channel(url: String) -> Result<(), String> {
-    let project_root = get_project_root();
-    let channels_file = project_root.join("channels.txt");
+fn is_youtube_channel_id(value: &str) -> bool {
+    value.len() == 24 && value.starts_with("UC") && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+// 채널 URL 정규화 (@핸들, /channel/UC…, /c/…, /user/…, 재생목록, RSS 피드 → https://www.youtube.com/… 형식)
+// 탭 경로(/videos 등)와 쿼리는 버림, 네트워크 확인은 하지 않음
+fn normalize_channel_url(input: &str) -> Result<String, String> {
+    let value = input.trim().trim_matches('"');
+    let invalid = || format!("YouTube 채널 또는 재생목록 URL이 아닙니다: {}", value);
+    if value.is_empty() {
+        return Err("채널 URL을 입력하세요".to_string());
+    }
+    if is_youtube_channel_id(value) {
+        return Ok(format!("https://www.youtube.com/channel/{}", value));
+    }
+    if value.starts_with('@') {
+        if value.len() == 1 || value.contains(|c: char| c == '/' || c.is_whitespace()) {
+            return Err(invalid());
+        }
+        return Ok(format!("https://www.youtube.com/{}", value));
+    }
     
-    // channels.txt가 없으면 생성
-    if !channels_file.exists() {
-        create_channels_file()?;
+    let without_scheme = value.trim_start_matches("https://").trim_start_matches("http://");
+    let (host, rest) = without_scheme.split_once('/').unwrap_or((without_scheme, ""));
+    if !matches!(host.to_lowercase().as_str(), "youtube.com" | "www.youtube.com" | "m.youtube.com" | "music.youtube.com" | "youtu.be") {
+        return Err(invalid());
+    }
+    // 재생목록은 watch URL로 입력해도 재생목록 URL로 저장
+    if let Some(playlist_id) = playlist_id_from_url(&format!("https://www.youtube.com/{}", rest)) {
+        return Ok(format!("https://www.youtube.com/playlist?list={}", playlist_id));
+    }
+    // RSS 피드 (feeds/videos.xml?channel_id=UC...)
+    if let Some((_, channel_id)) = rest.split_once("channel_id=") {
+        let channel_id = channel_id.split('&').next().unwrap_or(channel_id);
+        return if is_youtube_channel_id(channel_id) {
+            Ok(format!("https://www.youtube.com/channel/{}", channel_id))
+        } else {
+            Err(invalid())
+        };
     }
     
-    // 중복 체크
-    let existing_channels = list_channels()?;
-    if existing_channels.iter().any(|c| c.url == url) {
-        return Err("채널이 이미 존재합니다".to_string());
+    let path = rest.split(['?', '#']).next().unwrap_or("");
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    match segments.as_slice() {
+        [handle, ..] if handle.starts_with('@') && handle.len() > 1 => Ok(format!("https://www.youtube.com/{}", handle)),
+        ["channel", channel_id, ..] if is_youtube_channel_id(channel_id) => Ok(format!("https://www.youtube.com/channel/{}", channel_id)),
+        [kind @ ("c" | "user"), name, ..] => Ok(format!("https://www.youtube.com/{}/{}", kind, name)),
+        _ => Err(invalid()),
+    }
+}
+
+// 채널 URL 확인 결과 (추가 전 미리보기)
+#[derive(Serialize)]
+struct ChannelUrlValidation {
+    url: String,                // 정규화된 URL (핸들이 있으면 @핸들 형식)
+    name: String,               // 채널 표시 이름 (재생목록은 재생목록 제목)
+    channel_id: Option<String>,
+    playlist: bool,
+    already_added: bool,
+}
+
+fn validate_channel_url_blocking(url: &str) -> Result<ChannelUrlValidation, String> {
+    let normalized = normalize_channel_url(url)?;
+    let info = fetch_channel_info_json(&normalized).map_err(|e| format!("채널을 찾을 수 없습니다: {}", e))?;
+    let text = |key: &str| info.get(key).and_then(|v| v.as_str()).filter(|s| !s.is_empty()).map(|s| s.to_string());
+    
+    let playlist = playlist_id_from_url(&normalized).is_some();
+    let canonical = if playlist {
+        normalized.clone()
+    } else {
+        // /channel/UC…, /c/… 로 입력해도 핸들 URL이 있으면 핸들 URL로 저장 (채널 폴더명과 맞춤)
+        text("uploader_url")
+            .filter(|u| u.contains("/@"))
+            .and_then(|u| normalize_channel_url(&u).ok())
+            .unwrap_or_else(|| normalized.clone())
+    };
+    let name = if playlist {
+        text("title")
+    } else {
+        text("channel").or_else(|| text("uploader"))
     }
+    .unwrap_or_else(|| extract_channel_name_from_url(&canonical));
+    
+    let config = load_channels_config()?;
+    let already_added = config.channels.iter().any(|c| c.url == canonical || c.url == normalized);
+    Ok(ChannelUrlValidation {
+        url: canonical,
+        name,
+        channel_id: text("channel_id"),
+        playlist,
+        already_added,
+    })
+}
+
+// 채널 URL 정규화 후 실제 존재하는지 확인 (추가 전 정식 URL과 표시 이름 확인용)
+#[command]
+async fn validate_channel_url(url: String) -> Result<ChannelUrlValidation, String> {
+    tokio::task::spawn_blocking(move || validate_channel_url_blocking(&url))
+        .await
+        .map_err(|e| format!("채널 URL 확인 작업 실패: {}", e))?
+}
+
+#[command]
+fn add_channel(url: String) -> Result<(), String> {
+    let url = normalize_channel_url(&url)?;
     
-    // 채널 추가
-    let mut file = fs::OpenOptions::new()
-        .append(true)
-        .open(&channels_file)
-        .map_err(|e| e.to_string())?;
+    let mut config = load_channels_config()?;
+    if config.channels.iter().any(|c| c.url == url) {
+        return Err("채널이 이미 존재합니다".to_string());
+    }
+    config.channels.push(ChannelEntry::new(url.clone(), true));
+    save_channels_config(&config)?;
     
-    writeln!(file, "{}", url).map_err(|e| e.to_string())?;
+    // 채널 소개 페이지는 백그라운드로 보관 (네트워크 지연으로 추가가 막히지 않도록)
+    thread::spawn(move || {
+        if let Err(e) = archive_channel_profile(&url) {
+            eprintln!("⚠️ 채널 소개 보관 실패 {}: {}", url, e);
+        }
+    });
     
     Ok(())
 }
 
 #[command]
 fn remove_channel(url: String) -> Result<(), String> {
-    let project_root = get_project_root();
-    let channels_file = project_root.join("channels.txt");
-    
-    if !channels_file.exists() {
-        return Err("channels.txt 파일이 존재하지 않습니다".to_string());
+    let mut config = load_channels_config()?;
+    let before = config.channels.len();
+    config.channels.retain(|c| c.url != url);
+    if config.channels.len() == before {
+        return Err(format!("등록되지 않은 채널입니다: {}", url));
     }
-    
-    let content = fs::read_to_string(&channels_file).map_err(|e| e.to_string())?;
-    let new_content: Vec<String> = content
-        .lines()
-        .filter(|line| {
-            let line = line.trim();
-            if line.starts_with("# ") {
-                &line[2..] != url
-            } else {
-                line != url
-            }
-        })
-        .map(|s| s.to_string())
-        .collect();
-    
-    fs::write(&channels_file, new_content.join("\n")).map_err(|e| e.to_string())?;
-    
-    Ok(())
+    save_channels_config(&config)
 }
 
 #[command]
 fn toggle_channel(url: String) -> Result<(), String> {
-    let project_root = get_project_root();
-    let channels_file = project_root.join("channels.txt");
-    
-    if !channels_file.exists() {
-        return Err("channels.txt 파일이 존재하지 않습니다".to_string());
-    }
-    
-    let content = fs::read_to_string(&channels_file).map_err(|e| e.to_string())?;
-    let new_content: Vec<String> = content
-        .lines()
-        .map(|line| {
-            let line = line.trim();
-            if line == url {
-                format!("# {}", line)
-            } else if line.starts_with("# ") && &line[2..] == url {
-                line[2..].to_string()
-            } else {
-                line.to_string()
-            }
-        })
-        .collect();
-    
-    fs::write(&channels_file, new_content.join("\n")).map_err(|e| e.to_string())?;
-    
-    Ok(())
+    let mut config = load_channels_config()?;
+    let entry = config
+        .channels
+        .iter_mut()
+        .find(|c| c.url == url)
+        .ok_or_else(|| format!("등록되지 않은 채널입니다: {}", url))?;
+    entry.enabled = !entry.enabled;
+    save_channels_config(&config)
 }
 
-fn create_channels_file() -> Result<(), String> {
-    let project_root = get_project_root();
-    let channels_file = project_root.join("channels.txt");
-    let content = r#"# Y-Data-House 채널 목록
-# 한 줄에 하나씩 YouTube 채널 URL을 입력하세요
-# '#'로 시작하는 줄은 주석으로 처리됩니다
-#
-# 예시:
-# https://www.youtube.com/@리베라루츠대학
-# https://www.youtube.com/@채널명2
-#
-# 아래에 다운로드할 채널 URL을 추가하세요:
+// 채널 순서 변경 (channels.toml 항목 순서 = 목록/최근 영상/배치 다운로드 순서)
+// ordered_urls에 없는 채널은 기존 순서대로 뒤에 남김
+#[command]
+fn reorder_channels(ordered_urls: Vec<String>) -> Result<Vec<ChannelInfo>, String> {
+    let mut config = load_channels_config()?;
+    if let Some(unknown) = ordered_urls.iter().find(|url| !config.channels.iter().any(|c| &c.url == *url)) {
+        return Err(format!("등록되지 않은 채널입니다: {}", unknown));
+    }
+    let position = |url: &str| ordered_urls.iter().position(|u| u == url).unwrap_or(usize::MAX);
+    // 안정 정렬이므로 목록에 없는 채널끼리는 기존 순서 유지
+    config.channels.sort_by_key(|c| position(&c.url));
+    save_channels_config(&config)?;
+    read_channels()
+}
 
-"#;
-    
-    fs::write(&channels_file, content).map_err(|e| e.to_string())?;
-    Ok(())
+// 사용자 지정 채널 순서 (채널 이름 → 위치)
+fn channel_order() -> HashMap<String, usize> {
+    read_channels()
+        .unwrap_or_default()
+        .into_iter()
+        .enumerate()
+        .map(|(index, channel)| (channel.name, index))
+        .collect()
 }
 
-// 다운로드 중단 명령어
+// channels.toml의 채널별 화질/자막 언어/그룹/필터 변경 (빈 문자열은 설정 해제)
 #[command]
-async fn cancel_download(state: State<'_, DownloadState>) -> Result<(), String> {
-    // 중단 플래그 설정
-    state.is_cancelled.store(true, Ordering::SeqCst);
-    
-    // 현재 실행 중인 프로세스 강제 종료
-    if let Ok(mut process_guard) = state.current_process.lock() {
-        if let Some(mut child) = process_guard.take() {
-            // 🔥 IMPROVED: 더 강력한 프로세스 종료
-            #[cfg(unix)]
-            {
-                // SIGTERM 먼저 시도
-                let _ = child.kill();
-                
-                // 1초 대기 후 강제 종료 확인
-                thread::sleep(Duration::from_millis(1000));
-                
-                // 여전히 실행 중이면 SIGKILL 시도
-                match child.try_wait() {
-                    Ok(Some(_)) => {
-                        // 프로세스가 종료됨
-                    }
-                    Ok(None) => {
-                        // 여전히 실행 중, 강제 종료 시도
-                        let pid = child.id();
-                        let _ = Command::new("kill")
-                            .args(&["-9", &pid.to_string()])
-                            .output();
-                        let _ = child.wait();
-                    }
-                    Err(_) => {
-                        // 오류 발생, 그냥 대기
-                        let _ = child.wait();
-                    }
-                }
-            }
-            
-            #[cfg(windows)]
-            {
-                // Windows에서는 기본 kill 사용
-                let _ = child.kill();
-                let _ = child.wait();
-            }
-        }
-    }
-    
-    // 중단 시 정리 작업 수행
-    cleanup_incomplete_downloads().await?;
-    
-    Ok(())
+fn update_channel_config(
+    url: String,
+    quality: Option<String>,
+    language: Option<String>,
+    group: Option<String>,
+    filters: Option<ChannelContentFilters>,
+) -> Result<ChannelInfo, String> {
+    let mut config = load_channels_config()?;
+    let entry = config
+        .channels
+        .iter_mut()
+        .find(|c| c.url == url)
+        .ok_or_else(|| format!("등록되지 않은 채널입니다: {}", url))?;
+    let non_empty = |value: Option<String>| value.map(|v| v.trim().to_string()).filter(|v| !v.is_empty());
+    entry.quality = non_empty(quality);
+    entry.language = non_empty(language);
+    entry.group = non_empty(group);
+    entry.filters = filters.unwrap_or_default();
+    save_channels_config(&config)?;
+    
+    read_channels()?
+        .into_iter()
+        .find(|c| c.url == url)
+        .ok_or_else(|| format!("등록되지 않은 채널입니다: {}", url))
 }
 
-// 불완전한 다운로드 정리
-async fn cleanup_incomplete_downloads() -> Result<(), String> {
-    let project_root = get_project_root();
-    let downloads_dir = project_root.join("vault").join("downloads");
-    
-    if !downloads_dir.exists() {
-        return Ok(());
-    }
-    
-    // downloads 폴더에서 불완전한 파일들 찾기
-    let entries = fs::read_dir(&downloads_dir).map_err(|e| e.to_string())?;
-    
-    for entry in entries.flatten() {
-        let path = entry.path();
-        if path.is_file() {
-            let filename = path.file_name().unwrap_or_default().to_string_lossy();
-            
-            // 임시 파일들 (.part, .ytdl, .tmp 등) 삭제
-            if filename.ends_with(".part") || 
-               filename.ends_with(".ytdl") || 
-               filename.ends_with(".tmp") ||
-               filename.contains(".f") && (filename.contains(".mp4") || filename.contains(".webm")) {
-                if let Err(e) = fs::remove_file(&path) {
-                    eprintln!("임시 파일 삭제 실패 {}: {}", path.display(), e);
-                }
-            }
-        }
+// 구독 목록 가져오기 (OPML / CSV / Google Takeout)
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+enum ChannelImportFormat {
+    Opml,    // RSS 리더/구독 앱 내보내기 (outline xmlUrl/htmlUrl)
+    Csv,     // URL 열이 있는 CSV (헤더가 없으면 첫 열)
+    Takeout, // Google Takeout subscriptions.csv (Channel Id 열) 또는 subscriptions.json
+    Json,    // export_channels JSON 백업 (활성 여부/채널 설정까지 복원)
+}
+
+#[derive(Serialize)]
+struct ChannelImportReport {
+    format: ChannelImportFormat,
+    found: u32,
+    added: Vec<String>,   // 추가한 채널 URL
+    skipped: Vec<String>, // 이미 등록된 채널 (파일 안의 중복 포함)
+    invalid: Vec<String>, // YouTube 채널로 인식하지 못한 값
+}
+
+fn detect_import_format(path: &Path, content: &str) -> ChannelImportFormat {
+    let extension = path.extension().map(|e| e.to_string_lossy().to_lowercase()).unwrap_or_default();
+    let first_line = content.lines().next().unwrap_or("").to_lowercase();
+    match extension.as_str() {
+        "opml" | "xml" => ChannelImportFormat::Opml,
+        "json" if content.trim_start().starts_with('{') => ChannelImportFormat::Json,
+        "json" => ChannelImportFormat::Takeout,
+        _ if first_line.contains("channel id") => ChannelImportFormat::Takeout,
+        _ if content.trim_start().starts_with('<') => ChannelImportFormat::Opml,
+        _ => ChannelImportFormat::Csv,
     }
-    
-    Ok(())
 }
 
-// yt-dlp 진행률 파싱 함수
-fn parse_ytdlp_progress(line: &str, window: &Window, channel_name: &str) {
-    // [download] 25.5% of 12.34MiB at 1.23MiB/s ETA 00:10
-    if let Some(percent_start) = line.find("] ") {
-        if let Some(percent_end) = line[percent_start + 2..].find("% of") {
-            let percent_str = &line[percent_start + 2..percent_start + 2 + percent_end];
-            if let Ok(percent) = percent_str.parse::<f32>() {
-                let progress = DownloadProgress {
-                    channel: channel_name.to_string(),
-                    status: "다운로드 중".to_string(),
-                    progress: percent,
-                    current_video: format!("📥 진행률: {:.1}%", percent),
-                    total_videos: 1,
-                    completed_videos: 0,
-                    log_message: line.to_string(),
-                };
-                let _ = window.emit("download-progress", &progress);
+// 따옴표로 감싼 필드("a,b", "" 이스케이프)를 지원하는 CSV 한 줄 분리
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
             }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field).trim().to_string()),
+            _ => field.push(c),
         }
     }
+    fields.push(field.trim().to_string());
+    fields
 }
 
-// 실시간 출력 캡처를 위한 헬퍼 함수
-fn run_process_with_realtime_output(
-    mut child: std::process::Child,
-    window: &Window,
-    channel_name: &str,
-    state: &State<'_, DownloadState>,
-) -> Result<(u32, u32, std::process::ExitStatus), String> {
-    let stdout = child.stdout.take().ok_or("stdout 캡처 실패")?;
-    let stderr = child.stderr.take().ok_or("stderr 캡처 실패")?;
-    
-    let mut channel_total_videos = 0u32;
-    let mut channel_downloaded_videos = 0u32;
-    
-    // 통계 정보 전송을 위한 채널
-    let (channel_total_tx, channel_total_rx) = std::sync::mpsc::channel::<u32>();
-    let (channel_downloaded_tx, channel_downloaded_rx) = std::sync::mpsc::channel::<u32>();
-    
-    // 🔥 NEW: 마지막 로그 수신 시간 추적 (15초 타임아웃으로 단축)
-    let last_activity = Arc::new(Mutex::new(Instant::now()));
-    let timeout_duration = Duration::from_secs(15);  // 15초로 단축
-    
-    // stdout 실시간 읽기 스레드
-    let window_clone = window.clone();
-    let channel_name_clone = channel_name.to_string();
-    let is_cancelled = state.is_cancelled.clone();
-    let last_activity_clone = last_activity.clone();
-    
-    let stdout_handle = thread::spawn(move || {
-        let reader = BufReader::new(stdout);
-        
-        for line in reader.lines() {
-            // 중단 신호 확인
-            if is_cancelled.load(Ordering::SeqCst) {
-                break;
-            }
-            
-            match line {
-                Ok(line_str) => {
-                    if line_str.trim().is_empty() {
-                        continue;
-                    }
-                    
-                    // 🔥 NEW: 활동 시간 업데이트 (타임아웃 방지)
-                    if let Ok(mut last_time) = last_activity_clone.lock() {
-                        *last_time = Instant::now();
-                    }
-                    
-                    // 실시간 로그 메시지 전송
-                    let log_progress = DownloadProgress {
-                        channel: channel_name_clone.clone(),
-                        status: "진행 중".to_string(),
-                        progress: 0.0,
-                        current_video: format!("📺 {}", channel_name_clone),
-                        total_videos: 0,
-                        completed_videos: 0,
-                        log_message: line_str.clone(),
-                    };
-                    let _ = window_clone.emit("download-progress", &log_progress);
-                    
-                    // 비디오 수 파싱
-                    if line_str.contains("총") && line_str.contains("개 영상을 발견했습니다") {
-                        if let Some(start) = line_str.find("총 ") {
-                            if let Some(end) = line_str[start..].find("개 영상을 발견했습니다") {
-                                let number_str = line_str[start + 2..start + end].trim();
-                                if let Ok(count) = number_str.parse::<u32>() {
-                                    let _ = channel_total_tx.send(count);
-                                }
-                            }
-                        }
-                    }
-                    
-                    // 다운로드 완료 수 파싱
-                    if line_str.contains("다운로드 완료:") && line_str.contains("개 성공") {
-                        if let Some(start) = line_str.find("다운로드 완료: ") {
-                            if let Some(end) = line_str[start..].find("개 성공") {
-                                let number_str = line_str[start + 7..start + end].trim();
-                                if let Ok(count) = number_str.parse::<u32>() {
-                                    let _ = channel_downloaded_tx.send(count);
-                                }
-                            }
-                        }
-                    }
-                    
-                    // yt-dlp 진행률 파싱
-                    if line_str.contains("[download]") && line_str.contains("%") {
-                        parse_ytdlp_progress(&line_str, &window_clone, &channel_name_clone);
-                    }
-                }
-                Err(_) => break,
-            }
+fn parse_opml_urls(content: &str) -> Vec<String> {
+    let outline = Regex::new(r"(?is)<outline\b[^>]*>").unwrap();
+    let xml_url = Regex::new(r#"(?i)\bxmlUrl\s*=\s*"([^"]*)""#).unwrap();
+    let html_url = Regex::new(r#"(?i)\bhtmlUrl\s*=\s*"([^"]*)""#).unwrap();
+    outline
+        .find_iter(content)
+        .filter_map(|tag| {
+            // 피드 URL이 채널 ID를 담고 있어 htmlUrl보다 우선
+            let captures = xml_url.captures(tag.as_str()).or_else(|| html_url.captures(tag.as_str()))?;
+            Some(captures[1].replace("&amp;", "&"))
+        })
+        .collect()
+}
+
+fn parse_csv_urls(content: &str, format: ChannelImportFormat) -> Vec<String> {
+    let mut lines = content.lines().map(|l| l.trim_start_matches('\u{feff}')).filter(|l| !l.trim().is_empty());
+    let Some(first) = lines.next() else { return Vec::new() };
+    let header: Vec<String> = split_csv_line(first).iter().map(|h| h.to_lowercase()).collect();
+    let wanted: &[&str] = match format {
+        ChannelImportFormat::Takeout => &["channel id", "channel url"],
+        _ => &["url", "channel url", "channel_url", "link", "channel id", "channel_id"],
+    };
+    let column = header.iter().position(|h| wanted.contains(&h.as_str()));
+    // 헤더가 없으면 첫 줄도 데이터로 취급하고 첫 열 사용
+    let rows: Vec<&str> = if column.is_some() { lines.collect() } else { std::iter::once(first).chain(lines).collect() };
+    let column = column.unwrap_or(0);
+    rows.into_iter().filter_map(|row| split_csv_line(row).into_iter().nth(column)).collect()
+}
+
+// Takeout subscriptions.json: [{ "snippet": { "resourceId": { "channelId": "UC..." } } }]
+fn parse_takeout_json_urls(content: &str) -> Result<Vec<String>, String> {
+    let items: Vec<serde_json::Value> = serde_json::from_str(content).map_err(|e| format!("Takeout JSON 파싱 실패: {}", e))?;
+    Ok(items
+        .iter()
+        .filter_map(|item| item.pointer("/snippet/resourceId/channelId").and_then(|v| v.as_str()).map(str::to_string))
+        .collect())
+}
+
+#[command]
+fn import_channels(path: String, format: Option<ChannelImportFormat>) -> Result<ChannelImportReport, String> {
+    let path = PathBuf::from(path);
+    let content = fs::read_to_string(&path).map_err(|e| format!("가져올 파일을 읽을 수 없습니다: {}", e))?;
+    let format = format.unwrap_or_else(|| detect_import_format(&path, &content));
+    let imported: Vec<Result<ExportedChannel, String>> = match format {
+        ChannelImportFormat::Json => {
+            let export: ChannelExport = serde_json::from_str(&content).map_err(|e| format!("채널 백업 파싱 실패: {}", e))?;
+            export.channels.into_iter().map(Ok).collect()
         }
-    });
-    
-    // stderr 실시간 읽기 스레드
-    let window_clone = window.clone();
-    let channel_name_clone = channel_name.to_string();
-    let is_cancelled_stderr = state.is_cancelled.clone();
-    let last_activity_stderr = last_activity.clone();
-    
-    let stderr_handle = thread::spawn(move || {
-        let reader = BufReader::new(stderr);
-        
-        for line in reader.lines() {
-            // 중단 신호 확인
-            if is_cancelled_stderr.load(Ordering::SeqCst) {
-                break;
-            }
-            
-            match line {
-                Ok(line_str) => {
-                    if !line_str.trim().is_empty() {
-                        // 🔥 NEW: 활동 시간 업데이트 (타임아웃 방지)
-                        if let Ok(mut last_time) = last_activity_stderr.lock() {
-                            *last_time = Instant::now();
-                        }
-                        
-                        let stderr_progress = DownloadProgress {
-                            channel: channel_name_clone.clone(),
-                            status: "정보".to_string(),
-                            progress: 0.0,
-                            current_video: format!("📺 {}", channel_name_clone),
-                            total_videos: 0,
-                            completed_videos: 0,
-                            log_message: format!("⚠️ {}", line_str),
-                        };
-                        let _ = window_clone.emit("download-progress", &stderr_progress);
-                    }
-                }
-                Err(_) => break,
-            }
+        _ => {
+            let values = match format {
+                ChannelImportFormat::Opml => parse_opml_urls(&content),
+                ChannelImportFormat::Takeout if content.trim_start().starts_with('[') => parse_takeout_json_urls(&content)?,
+                _ => parse_csv_urls(&content, format),
+            };
+            values
+                .into_iter()
+                .map(|value| {
+                    let url = normalize_channel_url(&value).map_err(|_| value)?;
+                    Ok(ExportedChannel { entry: ChannelEntry::new(url, true), settings: None })
+                })
+                .collect()
         }
-    });
+    };
     
-    // 프로세스 완료 대기 (타임아웃은 위에서 이미 설정됨)
-    let mut process_completed = false;
-    while !process_completed {
-        // 중단 신호 확인
-        if state.is_cancelled.load(Ordering::SeqCst) {
-            return Err("다운로드가 중단되었습니다".to_string());
-        }
-        
-        // 🔥 NEW: 타임아웃 감지 및 자동 kill (30초로 단축)
-        if let Ok(last_time) = last_activity.lock() {
-            if last_time.elapsed() > timeout_duration {
-                eprintln!("⚠️ 15초간 로그 없음 - 프로세스 강제 종료");
-                let _ = child.kill();
-                return Err("프로세스 타임아웃으로 중단되었습니다 (15초간 응답 없음)".to_string());
+    let mut config = load_channels_config()?;
+    let mut channel_settings = load_channel_settings();
+    let mut report = ChannelImportReport {
+        format,
+        found: imported.len() as u32,
+        added: Vec::new(),
+        skipped: Vec::new(),
+        invalid: Vec::new(),
+    };
+    for channel in imported {
+        let channel = match channel {
+            Ok(channel) => channel,
+            Err(value) => {
+                report.invalid.push(value);
+                continue;
             }
+        };
+        let url = channel.entry.url.clone();
+        let name = channel.entry.channel_name();
+        if config.channels.iter().any(|c| c.url == url || c.channel_name() == name) {
+            report.skipped.push(url);
+            continue;
         }
-        
-        // 프로세스 상태 확인
-        match child.try_wait() {
-            Ok(Some(_)) => {
-                process_completed = true;
-            }
-            Ok(None) => {
-                // 아직 실행 중, 잠시 대기
-                thread::sleep(Duration::from_millis(100));
-            }
-            Err(_) => {
-                process_completed = true;
-            }
+        // 백업의 채널 설정은 이 컴퓨터에 설정이 없는 채널만 복원
+        if let Some(settings) = channel.settings {
+            channel_settings.entry(name).or_insert(settings);
         }
+        config.channels.push(channel.entry);
+        report.added.push(url);
     }
-    
-    // 스레드 완료 대기
-    let _ = stdout_handle.join();
-    let _ = stderr_handle.join();
-    
-    // 통계 정보 수집
-    if let Ok(count) = channel_total_rx.try_recv() {
-        channel_total_videos = count;
+    if report.added.is_empty() {
+        return Ok(report);
     }
-    if let Ok(count) = channel_downloaded_rx.try_recv() {
-        channel_downloaded_videos = count;
+    save_channels_config(&config)?;
+    if format == ChannelImportFormat::Json {
+        save_channel_settings(&channel_settings)?;
     }
     
-    // 프로세스 최종 상태 확인
-    let output = child.wait_with_output().map_err(|e| e.to_string())?;
-    
-    Ok((channel_total_videos, channel_downloaded_videos, output.status))
+    // 채널 소개 페이지는 백그라운드에서 하나씩 보관
+    let added = report.added.clone();
+    thread::spawn(move || {
+        for url in added {
+            if let Err(e) = archive_channel_profile(&url) {
+                eprintln!("⚠️ 채널 소개 보관 실패 {}: {}", url, e);
+            }
+        }
+    });
+    Ok(report)
 }
 
-// 비디오 다운로드 (실시간 진행 상황 포함)
-#[command]
-async fn download_videos_with_progress(window: Window, state: State<'_, DownloadState>) -> Result<String, String> {
-    let channels = list_channels()?;
-    let enabled_channels: Vec<_> = channels.into_iter().filter(|c| c.enabled).collect();
-    
-    if enabled_channels.is_empty() {
-        return Err("활성화된 채널이 없습니다".to_string());
-    }
-    
-    // Python 가상환경 확인
-    let project_root = get_project_root();
-    let venv_python = project_root.join("venv").join("bin").join("python3");
-    if !venv_python.exists() {
-        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+// 채널 목록 내보내기 (OPML: 다른 구독 앱과 공유, JSON: channels.toml 항목 + 채널 설정 전체 백업)
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+enum ChannelExportFormat {
+    Opml,
+    Json,
+}
+
+const CHANNEL_EXPORT_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize, Clone)]
+struct ExportedChannel {
+    #[serde(flatten)]
+    entry: ChannelEntry,
+    #[serde(default)]
+    settings: Option<ChannelSettings>, // config/channel_settings.json의 채널 설정
+}
+
+#[derive(Serialize, Deserialize)]
+struct ChannelExport {
+    version: u32,
+    exported_at: String,
+    channels: Vec<ExportedChannel>,
+}
+
+// 채널 ID/재생목록 ID를 알 수 있으면 YouTube RSS 피드 URL
+fn youtube_feed_url(url: &str) -> Option<String> {
+    if let Some(playlist_id) = playlist_id_from_url(url) {
+        return Some(format!("https://www.youtube.com/feeds/videos.xml?playlist_id={}", playlist_id));
     }
-    
-    // 다운로드 시작 시 중단 플래그 초기화
-    state.is_cancelled.store(false, Ordering::SeqCst);
-    
-    // 시작 메시지
-    let start_progress = DownloadProgress {
-        channel: "전체".to_string(),
-        status: "시작".to_string(),
-        progress: 0.0,
-        current_video: "배치 다운로드 시작".to_string(),
-        total_videos: 0,
-        completed_videos: 0,
-        log_message: "🚀 모든 활성화된 채널의 배치 다운로드를 시작합니다...".to_string(),
+    let channel_id = url.split("/channel/").nth(1)?.split(['/', '?']).next()?;
+    Some(format!("https://www.youtube.com/feeds/videos.xml?channel_id={}", channel_id))
+}
+
+fn render_channels_opml(entries: &[ChannelEntry]) -> String {
+    let outline = |entry: &ChannelEntry| {
+        let name = xml_escape(&entry.channel_name());
+        let feed = youtube_feed_url(&entry.url).map(|f| format!(" xmlUrl=\"{}\"", xml_escape(&f))).unwrap_or_default();
+        format!(
+            "<outline type=\"rss\" text=\"{}\" title=\"{}\" htmlUrl=\"{}\"{} enabled=\"{}\"/>",
+            name,
+            name,
+            xml_escape(&entry.url),
+            feed,
+            entry.enabled
+        )
     };
-    let _ = window.emit("download-progress", &start_progress);
-    
-    // 🔥 IMPROVED: batch 명령어 사용으로 모든 채널을 안정적으로 배치 처리 + 디버그 모드
-    let child = Command::new(&venv_python)
-        .args(&["-u", "-m", "ydh", "batch"])
-        .current_dir(&project_root)
-        .env("PYTHONUNBUFFERED", "1")        // Python 출력 버퍼링 방지
-        .env("PYTHONIOENCODING", "utf-8")    // UTF-8 인코딩 강제
-        .env("YDH_YTDLP_SLEEP_INTERVAL", "2")     // 요청 간 2초 지연
-        .env("YDH_YTDLP_MAX_SLEEP_INTERVAL", "5") // 최대 5초 랜덤 지연
-        .env("YDH_YTDLP_SLEEP_REQUESTS", "20")    // 20회마다 추가 슬립
-        .env("YDH_YTDLP_SOCKET_TIMEOUT", "8")     // 8초 소켓 타임아웃
-        .env("YDH_YTDLP_RETRIES", "1")            // 1회 재시도
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .map_err(|e| e.to_string())?;
     
-    // 현재 프로세스를 상태에 저장 (중단을 위해)
-    {
-        if let Ok(mut process_guard) = state.current_process.lock() {
-            *process_guard = Some(child);
+    // 그룹은 카테고리 outline으로 묶고, 그룹 없는 채널은 body 바로 아래
+    let mut groups: BTreeMap<&str, Vec<&ChannelEntry>> = BTreeMap::new();
+    let mut ungrouped = Vec::new();
+    for entry in entries {
+        match entry.group.as_deref() {
+            Some(group) => groups.entry(group).or_default().push(entry),
+            None => ungrouped.push(entry),
         }
     }
     
-    // 프로세스를 다시 가져와서 처리
-    let child = if let Ok(mut process_guard) = state.current_process.lock() {
-        process_guard.take().unwrap()
-    } else {
-        return Err("프로세스 접근 실패".to_string());
-    };
-    
-    // 🔥 NEW: 실시간 출력 캡처로 프로세스 실행
-    match run_process_with_realtime_output(child, &window, "전체 채널", &state) {
-        Ok((total, downloaded, status)) => {
-            if status.success() {
-                let success_progress = DownloadProgress {
-                    channel: "전체".to_string(),
-                    status: "완료".to_string(),
-                    progress: 100.0,
-                    current_video: "모든 채널".to_string(),
-                    total_videos: total,
-                    completed_videos: downloaded,
-                    log_message: format!("🎉 배치 다운로드 완료! (총 {}/{}개)", downloaded, total),
-                };
-                let _ = window.emit("download-progress", &success_progress);
-                return Ok(format!("✅ 배치 다운로드 성공: {}/{}개 영상 다운로드 완료", downloaded, total));
-            } else {
-                let error_progress = DownloadProgress {
-                    channel: "전체".to_string(),
-                    status: "실패".to_string(),
-                    progress: 100.0,
-                    current_video: "모든 채널".to_string(),
-                    total_videos: total,
-                    completed_videos: downloaded,
-                    log_message: "❌ 배치 다운로드 중 오류 발생".to_string(),
-                };
-                let _ = window.emit("download-progress", &error_progress);
-                return Err("배치 다운로드 중 오류가 발생했습니다".to_string());
-            }
-        }
-        Err(err) => {
-            if err.contains("중단") {
-                return Ok("다운로드가 중단되었습니다".to_string());
-            } else {
-                return Err(format!("배치 다운로드 실패: {}", err));
-            }
+    let mut opml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<opml version=\"2.0\">\n");
+    opml.push_str(&format!(
+        "  <head>\n    <title>Y-Data-House 채널 목록</title>\n    <dateCreated>{}</dateCreated>\n  </head>\n  <body>\n",
+        chrono::Local::now().to_rfc2822()
+    ));
+    for (group, entries) in groups {
+        opml.push_str(&format!("    <outline text=\"{}\" title=\"{}\">\n", xml_escape(group), xml_escape(group)));
+        for entry in entries {
+            opml.push_str(&format!("      {}\n", outline(entry)));
         }
+        opml.push_str("    </outline>\n");
     }
+    for entry in ungrouped {
+        opml.push_str(&format!("    {}\n", outline(entry)));
+    }
+    opml.push_str("  </body>\n</opml>\n");
+    opml
 }
 
-// 기존 다운로드 함수 (호환성 유지)
+// 내보낸 내용을 반환 (path를 지정하면 파일로도 저장)
 #[command]
-async fn download_videos() -> Result<String, String> {
-    // 단순히 배치 다운로드 함수 호출
-    let project_root = get_project_root();
-    let venv_python = project_root.join("venv").join("bin").join("python3");
-    if !venv_python.exists() {
-        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+fn export_channels(format: ChannelExportFormat, path: Option<String>) -> Result<String, String> {
+    let entries = load_channels_config()?.channels;
+    let content = match format {
+        ChannelExportFormat::Opml => render_channels_opml(&entries),
+        ChannelExportFormat::Json => {
+            let mut settings = load_channel_settings();
+            let export = ChannelExport {
+                version: CHANNEL_EXPORT_VERSION,
+                exported_at: chrono::Local::now().to_rfc3339(),
+                channels: entries
+                    .into_iter()
+                    .map(|entry| ExportedChannel { settings: settings.remove(&entry.channel_name()), entry })
+                    .collect(),
+            };
+            serde_json::to_string_pretty(&export).map_err(|e| format!("채널 목록 직렬화 실패: {}", e))?
+        }
+    };
+    if let Some(path) = path.filter(|p| !p.trim().is_empty()) {
+        fs::write(path.trim(), &content).map_err(|e| format!("내보내기 파일 저장 실패: {}", e))?;
     }
+    Ok(content)
+}
 
-    let output = Command::new(&venv_python)
-        .args(&["-u", "-m", "ydh", "batch"])
-        .current_dir(&project_root)
-        .env("PYTHONUNBUFFERED", "1")
-        .env("PYTHONIOENCODING", "utf-8")
-        .output()
-        .map_err(|e| e.to_string())?;
+// 채널 상태 변경 (활성 ↔ 일시정지 ↔ 보관, channels.toml과 보관된 영상은 그대로 유지)
+#[command]
+fn set_channel_state(channel_name: String, state: ChannelState) -> Result<ChannelInfo, String> {
+    let mut channel = read_channels()?
+        .into_iter()
+        .find(|c| c.name == channel_name)
+        .ok_or_else(|| format!("등록되지 않은 채널입니다: {}", channel_name))?;
+    let mut settings = load_channel_settings();
+    let entry = settings.entry(channel_name).or_default();
+    if entry.state != state {
+        entry.state = state;
+        entry.state_changed_at = Some(chrono::Local::now().to_rfc3339());
+        save_channel_settings(&settings)?;
+    }
+    channel.state = state;
+    Ok(channel)
+}
 
-    if output.status.success() {
-        Ok("✅ 배치 다운로드 완료".to_string())
-    } else {
-        let error = String::from_utf8_lossy(&output.stderr);
-        Err(format!("❌ 배치 다운로드 실패: {}", error))
+// 채널별 설정 (config/channel_settings.json, 채널명 기준)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+struct ChannelSettings {
+    language: Option<String>,
+    language_confidence: Option<f64>,
+    language_detected_at: Option<String>,
+    glossary: BTreeMap<String, String>,  // 용어 → 선호 번역
+    retention: RetentionPolicy,
+    memorialized_at: Option<String>,     // 채널 삭제/비공개 감지 시점
+    memorial_reason: Option<String>,
+    last_liveness_check_at: Option<String>,
+    note_template: Option<String>,       // 다운로드 시 영상 노트 생성용 Tera 템플릿
+    download_since: Option<String>,      // 업로드 날짜 범위 (YYYYMMDD, 범위 밖 영상은 다운로드 안 함)
+    download_until: Option<String>,
+    max_videos: Option<u32>,             // 최신 N개 업로드만 다운로드 (재생목록 제외)
+    max_archive_mb: Option<u64>,         // 채널 폴더가 이 용량에 도달하면 다운로드 중지
+    subtitle_languages: Vec<String>,     // 받을 자막 언어 (우선순위 순, 비어 있으면 감지 언어 사용)
+    manual_subtitles_only: bool,         // 자동 생성 자막으로 대체하지 않음
+    proxy: Option<String>,               // 채널 전용 프록시 ("direct"면 직접 연결)
+    // 이전 버전이 저장한 콘텐츠 필터 (channels.toml의 filters로 1회 이전, 다시 저장하지 않음)
+    #[serde(skip_serializing)]
+    skip_shorts: bool,
+    #[serde(skip_serializing)]
+    skip_live: bool,
+    #[serde(skip_serializing)]
+    min_duration_seconds: Option<u32>,
+    state: ChannelState,                 // 활성/일시정지/보관
+    state_changed_at: Option<String>,
+}
+
+// 채널별 콘텐츠 필터 (Shorts/라이브/최소 길이)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+struct ChannelContentFilters {
+    skip_shorts: bool,
+    skip_live: bool,
+    min_duration_seconds: Option<u32>,
+}
+
+impl ChannelContentFilters {
+    fn is_empty(&self) -> bool {
+        !self.skip_shorts && !self.skip_live && self.min_duration_seconds.is_none()
     }
 }
 
-// 품질 매개변수를 받는 다운로드 함수 (batch 처리)
+// 채널별 보관 정책
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+enum RetentionPolicy {
+    #[default]
+    KeepAll,
+    KeepLastN { count: u32 },                // 최신 N개 영상만 보관
+    DropMediaOlderThan { days: u32 },        // 오래된 영상은 자막만 남기고 미디어 삭제
+}
+
+#[derive(Serialize, Deserialize)]
+struct ChannelLanguageInfo {
+    channel: String,
+    language: String,
+    confidence: f64,
+    sampled_files: u32,
+}
+
+// 언어 감지에 사용할 채널당 최대 자막 파일 수
+const LANGUAGE_SAMPLE_FILES: usize = 20;
+// 자막 파일당 언어 감지에 사용할 최대 글자 수
+const LANGUAGE_SAMPLE_CHARS: usize = 4000;
+
+fn load_channel_settings() -> BTreeMap<String, ChannelSettings> {
+    load_json_config("channel_settings.json").unwrap_or_default()
+}
+
+fn save_channel_settings(settings: &BTreeMap<String, ChannelSettings>) -> Result<(), String> {
+    save_json_config("channel_settings.json", settings)?;
+    invalidate_autocomplete_index(); // 용어집이 자동완성 용어 제안에 쓰임
+    Ok(())
+}
+
+// 채널 설정 조회 (없으면 기본값)
 #[command]
-async fn download_videos_with_progress_and_quality(window: Window, state: State<'_, DownloadState>, quality: String) -> Result<String, String> {
-    let channels = list_channels()?;
-    let enabled_channels: Vec<_> = channels.into_iter().filter(|c| c.enabled).collect();
-    
-    if enabled_channels.is_empty() {
-        return Err("활성화된 채널이 없습니다".to_string());
-    }
-    
-    // Python 가상환경 확인
-    let project_root = get_project_root();
-    let venv_python = project_root.join("venv").join("bin").join("python3");
-    if !venv_python.exists() {
-        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+fn get_channel_settings(channel_name: String) -> Result<ChannelSettings, String> {
+    Ok(load_channel_settings().remove(&channel_name).unwrap_or_default())
+}
+
+// captions.md 파일 재귀 수집
+fn collect_caption_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                collect_caption_files(&path, files);
+            } else if path.file_name().map(|n| n == "captions.md").unwrap_or(false) {
+                files.push(path);
+            }
+        }
     }
-    
-    // 다운로드 시작 시 중단 플래그 초기화
-    state.is_cancelled.store(false, Ordering::SeqCst);
-    
-    // 시작 메시지
-    let start_progress = DownloadProgress {
-        channel: "전체".to_string(),
-        status: "시작".to_string(),
-        progress: 0.0,
-        current_video: format!("배치 다운로드 시작 (품질: {})", quality),
-        total_videos: 0,
-        completed_videos: 0,
-        log_message: format!("🚀 모든 활성화된 채널의 배치 다운로드를 시작합니다... (품질: {})", quality),
-    };
-    let _ = window.emit("download-progress", &start_progress);
-    
-    // 🔥 IMPROVED: batch 명령어 사용으로 모든 채널을 안정적으로 배치 처리
-    let child = Command::new(&venv_python)
-        .args(&["-u", "-m", "ydh", "batch"])
-        .current_dir(&project_root)
-        .env("PYTHONUNBUFFERED", "1")        // Python 출력 버퍼링 방지
-        .env("PYTHONIOENCODING", "utf-8")    // UTF-8 인코딩 강제
-        .env("YDH_YTDLP_SLEEP_INTERVAL", "2")     // 요청 간 2초 지연
-        .env("YDH_YTDLP_MAX_SLEEP_INTERVAL", "5") // 최대 5초 랜덤 지연
-        .env("YDH_YTDLP_SLEEP_REQUESTS", "20")    // 20회마다 추가 슬립
-        .env("YDH_YTDLP_SOCKET_TIMEOUT", "8")     // 8초 소켓 타임아웃
-        .env("YDH_YTDLP_RETRIES", "1")            // 1회 재시도
-        .env("YDH_VIDEO_QUALITY", &quality)  // 품질 설정
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .map_err(|e| e.to_string())?;
-    
-    // 현재 프로세스를 상태에 저장 (중단을 위해)
-    {
-        if let Ok(mut process_guard) = state.current_process.lock() {
-            *process_guard = Some(child);
+}
+
+// 자막 본문 읽기 (YAML frontmatter 제외)
+fn read_captions_body(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    if let Some(rest) = content.strip_prefix("---") {
+        if let Some(end) = rest.find("---") {
+            return Some(rest[end + 3..].to_string());
         }
     }
-    
-    // 프로세스를 다시 가져와서 처리
-    let child = if let Ok(mut process_guard) = state.current_process.lock() {
-        process_guard.take().unwrap()
-    } else {
-        return Err("프로세스 접근 실패".to_string());
+    Some(content)
+}
+
+// whatlang 언어 코드를 자막/프롬프트에서 쓰는 ISO 639-1 코드로 변환
+fn iso_639_1_code(lang: whatlang::Lang) -> String {
+    use whatlang::Lang;
+    let code = match lang {
+        Lang::Kor => "ko",
+        Lang::Jpn => "ja",
+        Lang::Eng => "en",
+        Lang::Cmn => "zh",
+        Lang::Spa => "es",
+        Lang::Fra => "fr",
+        Lang::Deu => "de",
+        Lang::Rus => "ru",
+        Lang::Por => "pt",
+        Lang::Vie => "vi",
+        Lang::Tha => "th",
+        Lang::Ind => "id",
+        other => other.code(),
     };
-    
-    // 🔥 NEW: 실시간 출력 캡처로 프로세스 실행
-    match run_process_with_realtime_output(child, &window, "전체 채널", &state) {
-        Ok((total, downloaded, status)) => {
-            if status.success() {
-                let success_progress = DownloadProgress {
-                    channel: "전체".to_string(),
-                    status: "완료".to_string(),
-                    progress: 100.0,
-                    current_video: "모든 채널".to_string(),
-                    total_videos: total,
-                    completed_videos: downloaded,
-                    log_message: format!("🎉 배치 다운로드 완료! (총 {}/{}개, 품질: {})", downloaded, total, quality),
-                };
-                let _ = window.emit("download-progress", &success_progress);
-                Ok(format!("✅ 배치 다운로드 성공: {}/{}개 영상 다운로드 완료 (품질: {})", downloaded, total, quality))
-            } else {
-                let error_progress = DownloadProgress {
-                    channel: "전체".to_string(),
-                    status: "실패".to_string(),
-                    progress: 100.0,
-                    current_video: "모든 채널".to_string(),
-                    total_videos: total,
-                    completed_videos: downloaded,
-                    log_message: "❌ 배치 다운로드 중 오류 발생".to_string(),
-                };
-                let _ = window.emit("download-progress", &error_progress);
-                return Err("배치 다운로드 중 오류가 발생했습니다".to_string());
-            }
+    code.to_string()
+}
+
+// 채널 자막 샘플로 주 언어 감지 (신뢰도 가중 다수결)
+fn detect_language_for_channel(channel_name: &str) -> Result<ChannelLanguageInfo, String> {
+    let channel_dir = get_project_root().join("vault").join("10_videos").join(channel_name);
+    if !channel_dir.exists() {
+        return Err(format!("채널 디렉토리가 존재하지 않습니다: {}", channel_dir.display()));
+    }
+
+    let mut caption_files = Vec::new();
+    collect_caption_files(&channel_dir, &mut caption_files);
+    // 폴더명이 연도/날짜 순이므로 역순 정렬하면 최신 영상부터 샘플링됨
+    caption_files.sort();
+    caption_files.reverse();
+
+    let mut votes: HashMap<String, f64> = HashMap::new();
+    let mut sampled_files = 0u32;
+    for path in caption_files.iter().take(LANGUAGE_SAMPLE_FILES) {
+        let body = match read_captions_body(path) {
+            Some(body) => body,
+            None => continue,
+        };
+        let sample: String = body.chars().take(LANGUAGE_SAMPLE_CHARS).collect();
+        if let Some(info) = whatlang::detect(&sample) {
+            *votes.entry(iso_639_1_code(info.lang())).or_insert(0.0) += info.confidence();
+            sampled_files += 1;
         }
-        Err(err) => {
-            if err.contains("중단") {
-                return Ok("다운로드가 중단되었습니다".to_string());
-            } else {
-                return Err(format!("배치 다운로드 실패: {}", err));
+    }
+
+    let total_weight: f64 = votes.values().sum();
+    let (language, weight) = votes
+        .into_iter()
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .ok_or_else(|| format!("{} 채널에서 언어를 감지할 자막이 없습니다", channel_name))?;
+
+    Ok(ChannelLanguageInfo {
+        channel: channel_name.to_string(),
+        language,
+        confidence: if total_weight > 0.0 { weight / total_weight } else { 0.0 },
+        sampled_files,
+    })
+}
+
+fn store_channel_language(settings: &mut BTreeMap<String, ChannelSettings>, info: &ChannelLanguageInfo) {
+    let entry = settings.entry(info.channel.clone()).or_default();
+    entry.language = Some(info.language.clone());
+    entry.language_confidence = Some(info.confidence);
+    entry.language_detected_at = Some(chrono::Local::now().to_rfc3339());
+}
+
+// 채널 주 언어 감지 후 채널 설정에 저장
+#[command]
+fn detect_channel_language(channel_name: String) -> Result<ChannelLanguageInfo, String> {
+    let info = detect_language_for_channel(&channel_name)?;
+    let mut settings = load_channel_settings();
+    store_channel_language(&mut settings, &info);
+    save_channel_settings(&settings)?;
+    Ok(info)
+}
+
+// vault의 모든 채널 언어 감지
+#[command]
+fn detect_all_channel_languages() -> Result<Vec<ChannelLanguageInfo>, String> {
+    let mut settings = load_channel_settings();
+    let mut results = Vec::new();
+
+    for channel_name in get_available_channels_for_embedding(Some(true))? {
+        match detect_language_for_channel(&channel_name) {
+            Ok(info) => {
+                store_channel_language(&mut settings, &info);
+                results.push(info);
             }
+            Err(e) => eprintln!("언어 감지 실패 {}: {}", channel_name, e),
         }
     }
+
+    save_channel_settings(&settings)?;
+    Ok(results)
 }
 
-// 🔍 전체 무결성 검사 다운로드 (--full-scan)
+// 채널 용어집 저장 (RAG 답변 프롬프트에 주입됨)
 #[command]
-async fn download_videos_full_scan_with_progress(window: Window, state: State<'_, DownloadState>) -> Result<String, String> {
-    let channels = list_channels()?;
-    let enabled_channels: Vec<_> = channels.into_iter().filter(|c| c.enabled).collect();
+fn set_channel_glossary(channel_name: String, glossary: BTreeMap<String, String>) -> Result<(), String> {
+    let glossary = glossary
+        .into_iter()
+        .map(|(term, translation)| (term.trim().to_string(), translation.trim().to_string()))
+        .filter(|(term, translation)| !term.is_empty() && !translation.is_empty())
+        .collect();
     
-    if enabled_channels.is_empty() {
-        return Err("활성화된 채널이 없습니다".to_string());
+    let mut settings = load_channel_settings();
+    settings.entry(channel_name).or_default().glossary = glossary;
+    save_channel_settings(&settings)
+}
+
+// 채널별 업로드 날짜 범위 설정 (None이면 해당 경계 해제)
+#[command]
+fn set_channel_date_range(channel_name: String, since: Option<String>, until: Option<String>) -> Result<(), String> {
+    let since = since.filter(|d| !d.trim().is_empty()).map(|d| normalize_upload_date(&d)).transpose()?;
+    let until = until.filter(|d| !d.trim().is_empty()).map(|d| normalize_upload_date(&d)).transpose()?;
+    if let (Some(since), Some(until)) = (&since, &until) {
+        if since > until {
+            return Err("시작일이 종료일보다 늦습니다".to_string());
+        }
     }
     
-    // Python 가상환경 확인
-    let project_root = get_project_root();
-    let venv_python = project_root.join("venv").join("bin").join("python3");
-    if !venv_python.exists() {
-        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    let mut settings = load_channel_settings();
+    let entry = settings.entry(channel_name).or_default();
+    entry.download_since = since;
+    entry.download_until = until;
+    save_channel_settings(&settings)
+}
+
+// 채널별 다운로드 정책 (최신 N개 업로드, 채널 폴더 최대 용량, None이면 제한 해제)
+// 업로드 날짜 범위는 set_channel_date_range로 설정
+#[command]
+fn set_channel_download_policy(channel_name: String, max_videos: Option<u32>, max_archive_mb: Option<u64>) -> Result<(), String> {
+    if max_videos == Some(0) {
+        return Err("다운로드할 영상 수는 1개 이상이어야 합니다".to_string());
     }
-    
-    // 다운로드 시작 시 중단 플래그 초기화
-    state.is_cancelled.store(false, Ordering::SeqCst);
-    
-    // 시작 메시지
-    let start_progress = DownloadProgress {
-        channel: "전체".to_string(),
-        status: "시작".to_string(),
-        progress: 0.0,
-        current_video: "전체 무결성 검사 시작".to_string(),
-        total_videos: 0,
-        completed_videos: 0,
-        log_message: "🔍 전체 무결성 검사를 시작합니다. 모든 영상을 확인하여 누락된 영상을 복구합니다...".to_string(),
-    };
-    let _ = window.emit("download-progress", &start_progress);
-    
-    // 🔥 전체 무결성 검사 모드: --full-scan 플래그 사용
-    let child = Command::new(&venv_python)
-        .args(&["-u", "-m", "ydh", "batch", "--full-scan"])
-        .current_dir(&project_root)
-        .env("PYTHONUNBUFFERED", "1")        // Python 출력 버퍼링 방지
-        .env("PYTHONIOENCODING", "utf-8")    // UTF-8 인코딩 강제
-        .env("YDH_YTDLP_SLEEP_INTERVAL", "2")     // 요청 간 2초 지연
-        .env("YDH_YTDLP_MAX_SLEEP_INTERVAL", "5") // 최대 5초 랜덤 지연
-        .env("YDH_YTDLP_SLEEP_REQUESTS", "20")    // 20회마다 추가 슬립
-        .env("YDH_YTDLP_SOCKET_TIMEOUT", "10")    // 전체 검사시 타임아웃 증가
-        .env("YDH_YTDLP_RETRIES", "2")            // 전체 검사시 재시도 횟수 증가
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .map_err(|e| e.to_string())?;
-    
-    // 현재 프로세스를 상태에 저장 (중단을 위해)
-    {
-        if let Ok(mut process_guard) = state.current_process.lock() {
-            *process_guard = Some(child);
-        }
+    if max_archive_mb == Some(0) {
+        return Err("채널 최대 용량은 1MB 이상이어야 합니다".to_string());
     }
     
-    // 프로세스를 다시 가져와서 처리
-    let child = if let Ok(mut process_guard) = state.current_process.lock() {
-        process_guard.take().unwrap()
-    } else {
-        return Err("프로세스 접근 실패".to_string());
-    };
-    
-    // 🔥 실시간 출력 캡처로 프로세스 실행
-    match run_process_with_realtime_output(child, &window, "전체 무결성 검사", &state) {
-        Ok((total, downloaded, status)) => {
-            if status.success() {
-                let success_progress = DownloadProgress {
-                    channel: "전체".to_string(),
-                    status: "완료".to_string(),
-                    progress: 100.0,
-                    current_video: "모든 채널".to_string(),
-                    total_videos: total,
-                    completed_videos: downloaded,
-                    log_message: format!("🎉 전체 무결성 검사 완료! 누락된 {}개 영상을 복구했습니다.", downloaded),
-                };
-                let _ = window.emit("download-progress", &success_progress);
-                return Ok(format!("✅ 전체 무결성 검사 성공: {}개 누락 영상 복구 완료", downloaded));
-            } else {
-                let error_progress = DownloadProgress {
-                    channel: "전체".to_string(),
-                    status: "실패".to_string(),
-                    progress: 100.0,
-                    current_video: "모든 채널".to_string(),
-                    total_videos: total,
-                    completed_videos: downloaded,
-                    log_message: "❌ 전체 무결성 검사 중 오류 발생".to_string(),
-                };
-                let _ = window.emit("download-progress", &error_progress);
-                return Err("전체 무결성 검사 중 오류가 발생했습니다".to_string());
-            }
+    let mut settings = load_channel_settings();
+    let entry = settings.entry(channel_name).or_default();
+    entry.max_videos = max_videos;
+    entry.max_archive_mb = max_archive_mb;
+    save_channel_settings(&settings)
+}
+
+// 채널별 자막 언어 설정 (auto_generated_fallback: 수동 자막이 없으면 자동 생성 자막 사용)
+#[command]
+fn set_channel_subtitle_languages(channel_name: String, languages: Vec<String>, auto_generated_fallback: bool) -> Result<(), String> {
+    let mut cleaned: Vec<String> = Vec::new();
+    for language in languages.iter().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        if !language.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(format!("자막 언어 코드가 올바르지 않습니다: {}", language));
         }
-        Err(err) => {
-            if err.contains("중단") {
-                return Ok("전체 무결성 검사가 중단되었습니다".to_string());
-            } else {
-                return Err(format!("전체 무결성 검사 실패: {}", err));
-            }
+        if !cleaned.iter().any(|l| l == language) {
+            cleaned.push(language.to_string());
         }
     }
+    
+    let mut settings = load_channel_settings();
+    let entry = settings.entry(channel_name).or_default();
+    entry.subtitle_languages = cleaned;
+    entry.manual_subtitles_only = !auto_generated_fallback;
+    save_channel_settings(&settings)
 }
 
-// 사용 가능한 채널 목록 조회
+// 채널별 콘텐츠 필터 조회 (channels.toml의 [channels.filters])
 #[command]
-fn get_available_channels_for_embedding() -> Result<Vec<String>, String> {
-    let project_root = get_project_root();
-    let videos_path = project_root.join("vault").join("10_videos");
-    
-    if !videos_path.exists() {
-        return Ok(Vec::new());
+fn get_channel_content_filters(channel_name: String) -> Result<ChannelContentFilters, String> {
+    let config = load_channels_config()?;
+    Ok(config
+        .channels
+        .into_iter()
+        .find(|entry| entry.channel_name() == channel_name)
+        .map(|entry| entry.filters)
+        .unwrap_or_default())
+}
+
+// 채널별 콘텐츠 필터 설정 (min_duration_seconds가 0이면 길이 제한 해제)
+#[command]
+fn set_channel_content_filters(channel_name: String, filters: ChannelContentFilters) -> Result<(), String> {
+    let mut config = load_channels_config()?;
+    let entry = config
+        .channels
+        .iter_mut()
+        .find(|entry| entry.channel_name() == channel_name)
+        .ok_or_else(|| format!("channels.toml에 없는 채널입니다: {}", channel_name))?;
+    entry.filters = ChannelContentFilters {
+        min_duration_seconds: filters.min_duration_seconds.filter(|&seconds| seconds > 0),
+        ..filters
+    };
+    save_channels_config(&config)
+}
+
+// 채널에 저장된 주 언어 조회
+fn get_channel_language(channel_name: &str) -> Option<String> {
+    load_channel_settings().remove(channel_name).and_then(|s| s.language)
+}
+
+// 언어 코드별 자막 언어 후보 (yt-dlp / youtube-transcript-api 코드)
+fn subtitle_language_variants(language: &str) -> Vec<String> {
+    let variants: &[&str] = match language {
+        "ko" => &["ko", "ko-KR", "ko_KR"],
+        "ja" => &["ja", "ja-JP"],
+        "en" => &["en", "en-US", "en-GB"],
+        "zh" => &["zh", "zh-Hans", "zh-Hant", "zh-CN", "zh-TW"],
+        _ => &[],
+    };
+    if variants.is_empty() {
+        vec![language.to_string()]
+    } else {
+        variants.iter().map(|v| v.to_string()).collect()
     }
-    
-    let mut channels = Vec::new();
-    
-    match fs::read_dir(&videos_path) {
-        Ok(entries) => {
-            for entry in entries {
-                if let Ok(entry) = entry {
-                    let path = entry.path();
-                    if path.is_dir() {
-                        if let Some(channel_name) = path.file_name() {
-                            if let Some(name_str) = channel_name.to_str() {
-                                channels.push(name_str.to_string());
-                            }
-                        }
-                    }
-                }
+}
+
+// 다운로드 대상 채널들의 감지 언어로 YDH_SUBTITLE_LANGUAGES 값 생성
+// (감지된 언어가 없으면 Python 기본값과 동일한 한국어 우선순위 사용)
+fn subtitle_languages_env(channels: &[ChannelInfo]) -> String {
+    let settings = load_channel_settings();
+    let mut languages: Vec<String> = Vec::new();
+
+    for channel in channels {
+        // 직접 지정한 자막 언어 → channels.toml 언어 → 감지 언어 순
+        let channel_settings = settings.get(&channel.name).cloned().unwrap_or_default();
+        let preferred: Vec<String> = if !channel_settings.subtitle_languages.is_empty() {
+            channel_settings.subtitle_languages.clone()
+        } else {
+            channel.language.iter().chain(channel_settings.language.iter()).take(1).cloned().collect()
+        };
+        for variant in preferred.iter().flat_map(|language| subtitle_language_variants(language)) {
+            if !languages.contains(&variant) {
+                languages.push(variant);
             }
         }
-        Err(e) => return Err(format!("채널 디렉토리 읽기 실패: {}", e)),
     }
-    
-    channels.sort();
-    Ok(channels)
-}
 
-// 채널별 임베딩 생성 (진행 상황 포함)
-#[command]
-async fn create_embeddings_for_channels_with_progress(
-    window: Window, 
-    channels: Vec<String>,
-    state: State<'_, EmbeddingState>
-) -> Result<String, String> {
-    let project_root = get_project_root();
-    let embed_script = project_root.join("vault").join("90_indices").join("embed.py");
-    if !embed_script.exists() {
-        return Err(format!("embed.py 스크립트를 찾을 수 없습니다: {}", embed_script.display()));
-    }
-    
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    if !venv_python.exists() {
-        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    if languages.is_empty() {
+        languages = subtitle_language_variants("ko");
     }
-    
-    // 중단 상태 초기화
-    state.is_cancelled.store(false, Ordering::Relaxed);
-    
+    serde_json::to_string(&languages).unwrap_or_default()
+}
+
+// 채널 URL별 자막 설정 (YDH_CHANNEL_SUBTITLES, 자막 언어를 직접 지정한 채널만)
+fn channel_subtitles_env(channels: &[ChannelInfo]) -> String {
+    let settings = load_channel_settings();
+    let subtitles: BTreeMap<&str, serde_json::Value> = channels
+        .iter()
+        .filter_map(|channel| {
+            let s = settings.get(&channel.name)?;
+            if s.subtitle_languages.is_empty() && !s.manual_subtitles_only {
+                return None;
+            }
+            let mut languages: Vec<String> = Vec::new();
+            for variant in s.subtitle_languages.iter().flat_map(|language| subtitle_language_variants(language)) {
+                if !languages.contains(&variant) {
+                    languages.push(variant);
+                }
+            }
+            Some((channel.url.as_str(), serde_json::json!({ "languages": languages, "auto_generated": !s.manual_subtitles_only })))
+        })
+        .collect();
+    serde_json::to_string(&subtitles).unwrap_or_default()
+}
+
+// 업로드 날짜를 YYYYMMDD로 정규화 (YYYY-MM-DD도 허용)
+fn normalize_upload_date(value: &str) -> Result<String, String> {
+    let compact = value.trim().replace('-', "");
+    chrono::NaiveDate::parse_from_str(&compact, "%Y%m%d")
+        .map(|_| compact)
+        .map_err(|_| format!("날짜 형식이 올바르지 않습니다 (YYYY-MM-DD): {}", value))
+}
+
+// 채널 URL별 업로드 날짜 범위 (YDH_CHANNEL_DATE_RANGES, 범위가 설정된 채널만)
+fn channel_date_ranges_env(channels: &[ChannelInfo]) -> String {
+    let settings = load_channel_settings();
+    let ranges: BTreeMap<&str, BTreeMap<&str, &str>> = channels
+        .iter()
+        .filter_map(|channel| {
+            let s = settings.get(&channel.name)?;
+            let range: BTreeMap<&str, &str> = [("since", s.download_since.as_deref()), ("until", s.download_until.as_deref())]
+                .into_iter()
+                .filter_map(|(key, value)| Some((key, value?)))
+                .collect();
+            (!range.is_empty()).then_some((channel.url.as_str(), range))
+        })
+        .collect();
+    serde_json::to_string(&ranges).unwrap_or_default()
+}
+
+// 채널 URL별 다운로드 정책 (YDH_CHANNEL_DOWNLOAD_POLICIES, 정책이 설정된 채널만)
+// 용량 제한은 현재 채널 폴더 크기(archive_mb)를 함께 넘겨 다운로더가 남은 용량만큼만 받도록 함
+fn channel_download_policies_env(channels: &[ChannelInfo]) -> String {
+    let settings = load_channel_settings();
+    let policies: BTreeMap<&str, serde_json::Value> = channels
+        .iter()
+        .filter_map(|channel| {
+            let s = settings.get(&channel.name)?;
+            if s.max_videos.is_none() && s.max_archive_mb.is_none() {
+                return None;
+            }
+            let archive_mb = s.max_archive_mb.map(|_| bytes_to_mb(calculate_directory_size(&channel_vault_dir(&channel.name))));
+            Some((
+                channel.url.as_str(),
+                serde_json::json!({ "max_videos": s.max_videos, "max_archive_mb": s.max_archive_mb, "archive_mb": archive_mb }),
+            ))
+        })
+        .collect();
+    serde_json::to_string(&policies).unwrap_or_default()
+}
+
+// 채널 URL별 콘텐츠 필터 (YDH_CHANNEL_CONTENT_FILTERS, channels.toml에 필터가 설정된 채널만)
+fn channel_content_filters_env(channels: &[ChannelInfo]) -> String {
+    let filters: BTreeMap<&str, &ChannelContentFilters> = channels
+        .iter()
+        .filter(|channel| !channel.filters.is_empty())
+        .map(|channel| (channel.url.as_str(), &channel.filters))
+        .collect();
+    serde_json::to_string(&filters).unwrap_or_default()
+}
+
+// 언어별 임베딩 모델 (영어 외에는 다국어 모델 사용)
+fn embedding_model_for_language(language: &str) -> &'static str {
+    match language {
+        "en" => "all-MiniLM-L6-v2",
+        _ => "paraphrase-multilingual-MiniLM-L12-v2",
+    }
+}
+
+// 언어가 감지된 채널별 임베딩 모델 매핑 (YDH_EMBEDDING_MODELS, JSON, embed.py에서 컬렉션 모델로 사용)
+fn embedding_models_env() -> String {
+    let models: BTreeMap<String, &str> = load_channel_settings()
+        .into_iter()
+        .filter_map(|(channel, settings)| {
+            let model = embedding_model_for_language(settings.language.as_deref()?);
+            Some((channel, model))
+        })
+        .collect();
+    serde_json::to_string(&models).unwrap_or_default()
+}
+
+// 다운로드 작업 큐 (작업마다 ID/상태/진행률/로그를 가지며 한 번에 하나씩 실행)
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum DownloadJobRequest {
+    Batch { quality: Option<String> },
+    FullScan,
+    Since { date: String }, // 지정 날짜 이후 업로드된 영상만 (전체 검사, 시작일 이전에 도달하면 중단)
+    Single { url: String, format: Option<String>, channel_name: Option<String> },
+    Plan { plan_id: String }, // plan_batch_download로 만든 계획의 선택된 영상만
+}
+
+impl DownloadJobRequest {
+    fn kind(&self) -> &'static str {
+        match self {
+            DownloadJobRequest::Batch { .. } => "batch",
+            DownloadJobRequest::FullScan => "full_scan",
+            DownloadJobRequest::Since { .. } => "since",
+            DownloadJobRequest::Single { .. } => "single",
+            DownloadJobRequest::Plan { .. } => "plan",
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum DownloadJobStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Serialize, Clone)]
+struct DownloadJob {
+    id: String,
+    request: DownloadJobRequest,
+    status: DownloadJobStatus,
+    progress: f32,
+    total_videos: u32,
+    completed_videos: u32,
+    created_at: String,
+    started_at: Option<String>,
+    finished_at: Option<String>,
+    message: Option<String>,
+    logs: Vec<String>,
+}
+
+const DOWNLOAD_JOB_LOG_LINES: usize = 500;
+const DOWNLOAD_JOB_HISTORY: usize = 50;
+
+// 다운로드 작업 큐 상태 (is_running은 실행 중인 작업이 있는 동안 true)
+#[derive(Default, Clone)]
+struct DownloadState {
+    jobs: Arc<Mutex<Vec<DownloadJob>>>,
+    cancel_flags: Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>,
+    turn: Arc<std::sync::Condvar>,
+    is_running: Arc<AtomicBool>,
+}
+
+// 실행 스레드가 작업 상태를 갱신할 때 사용하는 핸들
+#[derive(Clone)]
+struct DownloadJobContext {
+    id: String,
+    cancelled: Arc<AtomicBool>,
+    state: DownloadState,
+}
+
+impl DownloadJobContext {
+    fn update<F: FnOnce(&mut DownloadJob)>(&self, update: F) {
+        if let Ok(mut jobs) = self.state.jobs.lock() {
+            if let Some(job) = jobs.iter_mut().find(|j| j.id == self.id) {
+                update(job);
+            }
+        }
+    }
+
+    fn log(&self, line: &str) {
+        self.update(|job| {
+            job.logs.push(line.to_string());
+            if job.logs.len() > DOWNLOAD_JOB_LOG_LINES {
+                let overflow = job.logs.len() - DOWNLOAD_JOB_LOG_LINES;
+                job.logs.drain(..overflow);
+            }
+        });
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+fn new_download_job_id() -> String {
+    format!("dl-{}-{:04x}", chrono::Local::now().format("%Y%m%d%H%M%S"), rand::random::<u16>())
+}
+
+fn emit_download_job(window: &Window, state: &DownloadState, id: &str) {
+    let job = state.jobs.lock().ok().and_then(|jobs| jobs.iter().find(|j| j.id == id).cloned());
+    if let Some(job) = job {
+        let _ = window.emit("download-job-changed", &job);
+    }
+}
+
+// 작업을 큐 끝에 추가 (오래된 완료 작업 기록은 정리)
+fn enqueue_download_job(state: &DownloadState, request: DownloadJobRequest) -> Result<DownloadJobContext, String> {
+    let id = new_download_job_id();
+    let cancelled = Arc::new(AtomicBool::new(false));
+    {
+        let mut jobs = state.jobs.lock().map_err(|e| e.to_string())?;
+        jobs.push(DownloadJob {
+            id: id.clone(),
+            request,
+            status: DownloadJobStatus::Queued,
+            progress: 0.0,
+            total_videos: 0,
+            completed_videos: 0,
+            created_at: chrono::Local::now().to_rfc3339(),
+            started_at: None,
+            finished_at: None,
+            message: None,
+            logs: Vec::new(),
+        });
+        let finished = jobs.iter().filter(|j| !matches!(j.status, DownloadJobStatus::Queued | DownloadJobStatus::Running)).count();
+        let mut excess = finished.saturating_sub(DOWNLOAD_JOB_HISTORY);
+        jobs.retain(|j| {
+            if excess > 0 && !matches!(j.status, DownloadJobStatus::Queued | DownloadJobStatus::Running) {
+                excess -= 1;
+                return false;
+            }
+            true
+        });
+    }
+    state.cancel_flags.lock().map_err(|e| e.to_string())?.insert(id.clone(), cancelled.clone());
+    Ok(DownloadJobContext { id, cancelled, state: state.clone() })
+}
+
+// 앞선 작업이 모두 끝날 때까지 대기 후 실행 상태로 전환 (대기 중 취소되면 false)
+fn wait_for_download_turn(ctx: &DownloadJobContext) -> bool {
+    let Ok(mut jobs) = ctx.state.jobs.lock() else { return false };
+    loop {
+        if ctx.is_cancelled() {
+            return false;
+        }
+        let running = jobs.iter().any(|j| j.status == DownloadJobStatus::Running);
+        let next = jobs.iter().find(|j| j.status == DownloadJobStatus::Queued).map(|j| j.id.as_str());
+        if !running && next == Some(ctx.id.as_str()) {
+            if let Some(job) = jobs.iter_mut().find(|j| j.id == ctx.id) {
+                job.status = DownloadJobStatus::Running;
+                job.started_at = Some(chrono::Local::now().to_rfc3339());
+            }
+            return true;
+        }
+        jobs = match ctx.state.turn.wait_timeout(jobs, Duration::from_secs(1)) {
+            Ok((guard, _)) => guard,
+            Err(_) => return false,
+        };
+    }
+}
+
+// 작업 실행 (차례가 올 때까지 대기) 후 결과를 작업 기록에 반영
+fn run_download_job(window: &Window, derivative_state: &DerivativeState, ctx: DownloadJobContext) -> Result<String, String> {
+    emit_download_job(window, &ctx.state, &ctx.id);
+    let result = if wait_for_download_turn(&ctx) {
+        emit_download_job(window, &ctx.state, &ctx.id);
+        let _running = RunningGuard::new(&ctx.state.is_running);
+        let request = ctx.state.jobs.lock().ok()
+            .and_then(|jobs| jobs.iter().find(|j| j.id == ctx.id).map(|j| j.request.clone()));
+        let started_at = chrono::Local::now();
+        let before = vault_video_folders();
+        let result = match &request {
+            Some(request) => execute_download_request(window, &ctx, request),
+            None => Err("다운로드 작업을 찾을 수 없습니다".to_string()),
+        };
+        if let Err(e) = record_download_run(&build_download_history_entry(&ctx, request.as_ref(), &started_at, &before, &result)) {
+            eprintln!("⚠️ {}", e);
+        }
+        // 새로 받은 영상을 인덱스에 반영 (변경된 captions.md만 다시 읽음)
+        if let Err(e) = reconcile_vault_index() {
+            eprintln!("⚠️ 다운로드 후 인덱스 동기화 실패: {}", e);
+        }
+        // 이번 작업이 만든 영상 폴더만 파생 파일 생성 (vault 전체를 다시 훑지 않음)
+        let created: Vec<PathBuf> = vault_video_folders().difference(&before).cloned().collect();
+        if !created.is_empty() {
+            spawn_derivative_generation(window, derivative_state, Some(created));
+        }
+        result
+    } else {
+        Ok("다운로드가 중단되었습니다".to_string())
+    };
+
+    let status = match &result {
+        _ if ctx.is_cancelled() => DownloadJobStatus::Cancelled,
+        Ok(_) => DownloadJobStatus::Completed,
+        Err(_) => DownloadJobStatus::Failed,
+    };
+    ctx.update(|job| {
+        job.status = status;
+        job.finished_at = Some(chrono::Local::now().to_rfc3339());
+        job.message = Some(match &result {
+            Ok(message) | Err(message) => message.clone(),
+        });
+        if status == DownloadJobStatus::Completed {
+            job.progress = 100.0;
+        }
+    });
+    if let Ok(mut flags) = ctx.state.cancel_flags.lock() {
+        flags.remove(&ctx.id);
+    }
+    ctx.state.turn.notify_all();
+    emit_download_job(window, &ctx.state, &ctx.id);
+    if status != DownloadJobStatus::Cancelled {
+        notify_job_finished(window, NotifiedJob::Download, &result);
+    }
+    result
+}
+
+// 명령에서 작업을 기다릴 때 사용 (큐 대기와 다운로드 동안 블로킹되므로 tokio 작업 스레드 밖에서 실행)
+async fn run_download_job_blocking(window: Window, derivative_state: DerivativeState, ctx: DownloadJobContext) -> Result<String, String> {
+    tokio::task::spawn_blocking(move || run_download_job(&window, &derivative_state, ctx))
+        .await
+        .map_err(|e| format!("다운로드 작업 실행 실패: {}", e))?
+}
+
+fn execute_download_request(
+    window: &Window,
+    ctx: &DownloadJobContext,
+    request: &DownloadJobRequest,
+) -> Result<String, String> {
+    ensure_disk_space(window)?;
+    match request {
+        DownloadJobRequest::Batch { quality } => execute_batch_download(window, ctx, quality.as_deref(), false, None),
+        DownloadJobRequest::FullScan => execute_batch_download(window, ctx, None, true, None),
+        DownloadJobRequest::Since { date } => execute_batch_download(window, ctx, None, true, Some(date)),
+        DownloadJobRequest::Single { url, format, channel_name } => {
+            execute_single_download(window, ctx, url, format.clone(), channel_name.clone())
+        }
+        DownloadJobRequest::Plan { plan_id } => execute_plan_download(window, ctx, plan_id),
+    }
+}
+
+// 실행 중인 자식 프로세스 강제 종료
+fn terminate_child(child: &mut std::process::Child) {
+    #[cfg(unix)]
+    {
+        // SIGTERM 먼저 시도
+        let _ = child.kill();
+        
+        // 1초 대기 후 강제 종료 확인
+        thread::sleep(Duration::from_millis(1000));
+        
+        // 여전히 실행 중이면 SIGKILL 시도
+        match child.try_wait() {
+            Ok(Some(_)) => {
+                // 프로세스가 종료됨
+            }
+            Ok(None) => {
+                // 여전히 실행 중, 강제 종료 시도
+                let pid = child.id();
+                let _ = Command::new("kill")
+                    .args(["-9", &pid.to_string()])
+                    .output();
+                let _ = child.wait();
+            }
+            Err(_) => {
+                // 오류 발생, 그냥 대기
+                let _ = child.wait();
+            }
+        }
+    }
+    
+    #[cfg(windows)]
+    {
+        // Windows에서는 기본 kill 사용
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
+// 다운로드 중단 명령어 (대기 중인 작업을 포함해 모든 다운로드 작업 취소)
+#[command]
+async fn cancel_download(state: State<'_, DownloadState>) -> Result<(), String> {
+    for flag in state.cancel_flags.lock().map_err(|e| e.to_string())?.values() {
+        flag.store(true, Ordering::SeqCst);
+    }
+    state.turn.notify_all();
+    
+    // 실행 중인 프로세스는 작업 스레드가 종료하므로 잠시 대기 후 정리
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while state.is_running.load(Ordering::SeqCst) && Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    
+    // 중단 시 정리 작업 수행
+    cleanup_incomplete_downloads().await?;
+    
+    Ok(())
+}
+
+// 다운로드 작업 추가 (즉시 작업 ID 반환, 진행 상황은 download-job-changed/download-progress 이벤트)
+#[command]
+fn enqueue_download(
+    window: Window,
+    state: State<'_, DownloadState>,
+    derivative_state: State<'_, DerivativeState>,
+    vault_lock: State<'_, VaultLockState>,
+    request: DownloadJobRequest,
+) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let ctx = enqueue_download_job(&state, request)?;
+    let id = ctx.id.clone();
+    let derivative_state = derivative_state.inner().clone();
+    thread::spawn(move || {
+        let _ = run_download_job(&window, &derivative_state, ctx);
+    });
+    Ok(id)
+}
+
+// 다운로드 작업 목록 (대기/실행 중 + 최근 완료 기록)
+#[command]
+fn list_jobs(state: State<'_, DownloadState>) -> Result<Vec<DownloadJob>, String> {
+    Ok(state.jobs.lock().map_err(|e| e.to_string())?.clone())
+}
+
+// 특정 작업 취소 (대기 중이면 바로 취소, 실행 중이면 프로세스 종료)
+#[command]
+fn cancel_job(window: Window, state: State<'_, DownloadState>, id: String) -> Result<(), String> {
+    let flag = state.cancel_flags.lock().map_err(|e| e.to_string())?.get(&id).cloned()
+        .ok_or_else(|| format!("취소할 수 있는 작업이 아닙니다: {}", id))?;
+    flag.store(true, Ordering::SeqCst);
+    state.turn.notify_all();
+    emit_download_job(&window, &state, &id);
+    Ok(())
+}
+
+// 다운로더가 영상 폴더에 남기는 이어받기 정보 (완료되면 삭제됨)
+const RESUME_MARKER_FILE: &str = "resume.json";
+
+#[derive(Deserialize)]
+struct ResumeMarker {
+    url: String,
+    title: Option<String>,
+    channel_name: Option<String>,
+    format: Option<String>,
+}
+
+#[derive(Serialize, Clone)]
+struct ResumedDownload {
+    job_id: String,
+    title: String,
+    folder: String,
+    partial_bytes: u64, // 이미 받아 둔 .part 파일 크기 합계
+}
+
+fn is_incomplete_download_file(filename: &str) -> bool {
+    filename.ends_with(".part") ||
+        filename.ends_with(".ytdl") ||
+        filename.ends_with(".tmp") ||
+        filename.contains(".f") && (filename.contains(".mp4") || filename.contains(".webm"))
+}
+
+// downloads 폴더와 영상별 하위 폴더의 임시 파일 목록
+fn incomplete_download_files(downloads_dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut dirs = vec![downloads_dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir).into_iter().flatten().flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                if dir == downloads_dir {
+                    dirs.push(path);
+                }
+            } else if is_incomplete_download_file(&path.file_name().unwrap_or_default().to_string_lossy()) {
+                files.push(path);
+            }
+        }
+    }
+    files
+}
+
+// 불완전한 다운로드 정리 (이어받기 모드에서는 .part 파일을 남겨 resume_incomplete_downloads로 이어받음)
+async fn cleanup_incomplete_downloads() -> Result<(), String> {
+    let project_root = get_project_root();
+    let downloads_dir = project_root.join("vault").join("downloads");
+    
+    if !downloads_dir.exists() {
+        return Ok(());
+    }
+    let settings: DownloaderSettings = load_json_config(DOWNLOADER_SETTINGS_FILE).unwrap_or_default();
+    if settings.resume_partials {
+        return Ok(());
+    }
+    
+    // 임시 파일들 (.part, .ytdl, .tmp 등)과 이어받기 정보 삭제
+    for path in incomplete_download_files(&downloads_dir) {
+        if let Err(e) = fs::remove_file(&path) {
+            eprintln!("임시 파일 삭제 실패 {}: {}", path.display(), e);
+        }
+        if let Some(folder) = path.parent().filter(|folder| *folder != downloads_dir) {
+            let _ = fs::remove_file(folder.join(RESUME_MARKER_FILE));
+        }
+    }
+    
+    Ok(())
+}
+
+// 중단된 다운로드 이어받기: 남아 있는 .part 파일마다 같은 출력 경로로 단일 다운로드 작업을 다시 추가 (yt-dlp --continue)
+#[command]
+fn resume_incomplete_downloads(
+    window: Window,
+    state: State<'_, DownloadState>,
+    derivative_state: State<'_, DerivativeState>,
+    vault_lock: State<'_, VaultLockState>,
+) -> Result<Vec<ResumedDownload>, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let downloads_dir = get_project_root().join("vault").join("downloads");
+    let mut partial_bytes: HashMap<PathBuf, u64> = HashMap::new();
+    for path in incomplete_download_files(&downloads_dir) {
+        if let Some(folder) = path.parent().filter(|folder| *folder != downloads_dir) {
+            *partial_bytes.entry(folder.to_path_buf()).or_default() += fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        }
+    }
+    
+    let mut folders: Vec<(PathBuf, u64)> = partial_bytes.into_iter().collect();
+    folders.sort();
+    let mut resumed = Vec::new();
+    for (folder, bytes) in folders {
+        let marker = match fs::read_to_string(folder.join(RESUME_MARKER_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str::<ResumeMarker>(&content).ok())
+        {
+            Some(marker) => marker,
+            None => continue, // 어떤 영상인지 알 수 없는 임시 파일은 건너뜀
+        };
+        let ctx = enqueue_download_job(&state, DownloadJobRequest::Single {
+            url: marker.url,
+            format: marker.format,
+            channel_name: marker.channel_name,
+        })?;
+        resumed.push(ResumedDownload {
+            job_id: ctx.id.clone(),
+            title: marker.title.unwrap_or_else(|| folder.file_name().unwrap_or_default().to_string_lossy().to_string()),
+            folder: folder.to_string_lossy().to_string(),
+            partial_bytes: bytes,
+        });
+        let window = window.clone();
+        let derivative_state = derivative_state.inner().clone();
+        thread::spawn(move || {
+            let _ = run_download_job(&window, &derivative_state, ctx);
+        });
+    }
+    Ok(resumed)
+}
+
+// 다운로더/정합성 검사 스크립트의 진행 이벤트 (stdout의 `PROGRESS:{json}` 한 줄, src/ydh/progress.py)
+const PROGRESS_PREFIX: &str = "PROGRESS:";
+
+#[derive(Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum BackendProgressEvent {
+    VideosFound { total: u32 },
+    VideoStarted { index: u32, title: String },
+    VideoFinished { index: u32, status: String },
+    Transfer { percent: f32, speed_bps: Option<f64> },
+    ChannelFinished { channel: String, total: u32, downloaded: u32, skipped: u32, failed: u32 },
+    Stage { progress: f32, message: String },
+}
+
+// 진행 이벤트 줄이 아니거나 알 수 없는 이벤트면 None (rag.py 등 다른 형식의 PROGRESS 줄 포함)
+fn parse_progress_line(line: &str) -> Option<BackendProgressEvent> {
+    serde_json::from_str(line.trim().strip_prefix(PROGRESS_PREFIX)?).ok()
+}
+
+// 한 다운로드 프로세스의 누적 진행 상황 (배치 모드는 여러 채널을 차례로 처리)
+#[derive(Default)]
+struct DownloadTally {
+    total: u32,               // 발견한 영상 수 (채널 합계)
+    finished_before: u32,     // 끝난 채널들의 영상 수
+    downloaded_before: u32,   // 끝난 채널들의 다운로드 성공 수
+    channel_downloaded: u32,  // 현재 채널의 다운로드 성공 수
+    current_index: u32,       // 현재 채널에서 처리 중인 영상 순번 (1부터)
+    current_title: String,
+    progress: f32,
+}
+
+impl DownloadTally {
+    fn downloaded(&self) -> u32 {
+        self.downloaded_before + self.channel_downloaded
+    }
+
+    // 전체 진행률: 처리한 영상 수 + 현재 영상의 전송 비율
+    fn update_progress(&mut self, current_fraction: f32) {
+        if self.total == 0 {
+            return;
+        }
+        let done = (self.finished_before + self.current_index.saturating_sub(1)) as f32 + current_fraction;
+        self.progress = (done / self.total as f32 * 100.0).min(100.0);
+    }
+
+    fn snapshot(&self, channel_name: &str, status: &str, current_video: String, log_message: String, throughput_bps: Option<f64>) -> DownloadProgress {
+        DownloadProgress {
+            channel: channel_name.to_string(),
+            status: status.to_string(),
+            progress: self.progress,
+            current_video,
+            total_videos: self.total,
+            completed_videos: self.downloaded(),
+            log_message,
+            throughput_bps,
+        }
+    }
+
+    // 진행 이벤트를 반영하고 앱에 보낼 진행 상황 반환
+    fn apply(&mut self, event: BackendProgressEvent, channel_name: &str) -> Option<DownloadProgress> {
+        match event {
+            BackendProgressEvent::VideosFound { total } => {
+                self.total += total;
+                Some(self.snapshot(channel_name, "진행 중", format!("📺 {}", channel_name), format!("🔍 {}개 영상 발견", total), None))
+            }
+            BackendProgressEvent::VideoStarted { index, title } => {
+                self.current_index = index;
+                self.current_title = title;
+                self.update_progress(0.0);
+                Some(self.snapshot(channel_name, "다운로드 중", format!("📥 {}", self.current_title), String::new(), None))
+            }
+            BackendProgressEvent::VideoFinished { index, status } => {
+                self.current_index = index;
+                if status == "downloaded" {
+                    self.channel_downloaded += 1;
+                }
+                self.update_progress(1.0);
+                None
+            }
+            BackendProgressEvent::Transfer { percent, speed_bps } => {
+                self.update_progress(percent / 100.0);
+                let current_video = match speed_bps {
+                    Some(bps) => format!("📥 {} · {:.1}% · {:.2}MB/s", self.current_title, percent, bps / (1024.0 * 1024.0)),
+                    None => format!("📥 {} · {:.1}%", self.current_title, percent),
+                };
+                Some(self.snapshot(channel_name, "다운로드 중", current_video, String::new(), speed_bps))
+            }
+            BackendProgressEvent::ChannelFinished { channel, total, downloaded, skipped, failed } => {
+                self.finished_before += total;
+                self.downloaded_before += downloaded;
+                self.channel_downloaded = 0;
+                self.current_index = 0;
+                self.update_progress(0.0);
+                let log_message = format!("✅ {} 완료: {}개 다운로드, {}개 건너뜀, {}개 실패", channel, downloaded, skipped, failed);
+                Some(self.snapshot(channel_name, "진행 중", format!("📺 {}", channel_name), log_message, None))
+            }
+            BackendProgressEvent::Stage { .. } => None,
+        }
+    }
+}
+
+// yt-dlp 진행률 파싱 함수 (파싱된 진행률 반환)
+fn parse_ytdlp_progress(line: &str, window: &Window, channel_name: &str) -> Option<f32> {
+    // [download] 25.5% of 12.34MiB at 1.23MiB/s ETA 00:10
+    let percent_start = line.find("] ")?;
+    let percent_end = line[percent_start + 2..].find("% of")?;
+    let percent_str = &line[percent_start + 2..percent_start + 2 + percent_end];
+    let percent = percent_str.trim().parse::<f32>().ok()?;
+    let throughput_bps = line
+        .split_once(" at ")
+        .and_then(|(_, rest)| rest.split_whitespace().next())
+        .and_then(|speed| speed.strip_suffix("/s"))
+        .and_then(parse_ytdlp_size);
+    let current_video = match throughput_bps {
+        Some(bps) => format!("📥 진행률: {:.1}% · {:.2}MB/s", percent, bps / (1024.0 * 1024.0)),
+        None => format!("📥 진행률: {:.1}%", percent),
+    };
+    let progress = DownloadProgress {
+        channel: channel_name.to_string(),
+        status: "다운로드 중".to_string(),
+        progress: percent,
+        current_video,
+        total_videos: 1,
+        completed_videos: 0,
+        log_message: line.to_string(),
+        throughput_bps,
+    };
+    let _ = window.emit("download-progress", &progress);
+    Some(percent)
+}
+
+// yt-dlp 크기 표기 (예: 1.23MiB, 512.0KiB) → bytes
+fn parse_ytdlp_size(value: &str) -> Option<f64> {
+    let split = value.find(|c: char| c.is_ascii_alphabetic())?;
+    let (number, unit) = value.split_at(split);
+    let multiplier = match unit {
+        "B" => 1.0,
+        "KiB" => 1024.0,
+        "MiB" => 1024.0 * 1024.0,
+        "GiB" => 1024.0 * 1024.0 * 1024.0,
+        "KB" => 1000.0,
+        "MB" => 1000.0 * 1000.0,
+        "GB" => 1000.0 * 1000.0 * 1000.0,
+        _ => return None,
+    };
+    number.parse::<f64>().ok().map(|n| n * multiplier)
+}
+
+// 실시간 출력 캡처를 위한 헬퍼 함수 (작업 로그/진행률도 함께 기록)
+fn run_process_with_realtime_output(
+    mut child: std::process::Child,
+    window: &Window,
+    channel_name: &str,
+    job: &DownloadJobContext,
+    operation: ProcessOperation,
+) -> Result<(u32, u32, std::process::ExitStatus), String> {
+    let stdout = child.stdout.take().ok_or("stdout 캡처 실패")?;
+    let stderr = child.stderr.take().ok_or("stderr 캡처 실패")?;
+    
+    // 마지막 로그 수신 시간 추적 (작업 종류별 무응답 타임아웃, 절반이 지나면 정체 알림)
+    let last_activity = Arc::new(Mutex::new(Instant::now()));
+    let timeout_duration = operation.inactivity_timeout();
+    let mut stalled_reported = false;
+    let mut last_disk_check = Instant::now();
+    let mut disk_warning_sent = false;
+    
+    // stdout 실시간 읽기 스레드
+    let window_clone = window.clone();
+    let channel_name_clone = channel_name.to_string();
+    let job_clone = job.clone();
+    let last_activity_clone = last_activity.clone();
+    
+    let stdout_handle = thread::spawn(move || {
+        let reader = BufReader::new(stdout);
+        let mut tally = DownloadTally::default();
+        
+        for line in reader.lines() {
+            // 중단 신호 확인
+            if job_clone.is_cancelled() {
+                break;
+            }
+            
+            match line {
+                Ok(line_str) => {
+                    if line_str.trim().is_empty() {
+                        continue;
+                    }
+                    
+                    // 🔥 NEW: 활동 시간 업데이트 (타임아웃 방지)
+                    if let Ok(mut last_time) = last_activity_clone.lock() {
+                        *last_time = Instant::now();
+                    }
+                    
+                    // 타입 있는 진행 이벤트 (로그에는 남기지 않음)
+                    if line_str.starts_with(PROGRESS_PREFIX) {
+                        if let Some(event) = parse_progress_line(&line_str) {
+                            if let Some(progress) = tally.apply(event, &channel_name_clone) {
+                                if !progress.log_message.is_empty() {
+                                    job_clone.log(&progress.log_message);
+                                }
+                                let _ = window_clone.emit("download-progress", &progress);
+                            }
+                            let (total, completed, percent) = (tally.total, tally.downloaded(), tally.progress);
+                            job_clone.update(|job| {
+                                job.total_videos = total;
+                                job.completed_videos = completed;
+                                job.progress = percent;
+                            });
+                        }
+                        continue;
+                    }
+                    job_clone.log(&line_str);
+                    
+                    // 실시간 로그 메시지 전송 (진행률은 마지막 이벤트 기준 유지)
+                    let log_progress = tally.snapshot(&channel_name_clone, "진행 중", format!("📺 {}", channel_name_clone), line_str.clone(), None);
+                    let _ = window_clone.emit("download-progress", &log_progress);
+                    
+                    // yt-dlp 자체 진행률 줄 (진행 이벤트를 내지 않는 이전 스크립트 호환)
+                    if line_str.contains("[download]") && line_str.contains("%") {
+                        if let Some(percent) = parse_ytdlp_progress(&line_str, &window_clone, &channel_name_clone) {
+                            job_clone.update(|job| job.progress = percent);
+                        }
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+        (tally.total, tally.downloaded())
+    });
+    
+    // stderr 실시간 읽기 스레드
+    let window_clone = window.clone();
+    let channel_name_clone = channel_name.to_string();
+    let job_stderr = job.clone();
+    let last_activity_stderr = last_activity.clone();
+    
+    let stderr_handle = thread::spawn(move || {
+        let reader = BufReader::new(stderr);
+        
+        for line in reader.lines() {
+            // 중단 신호 확인
+            if job_stderr.is_cancelled() {
+                break;
+            }
+            
+            match line {
+                Ok(line_str) => {
+                    if !line_str.trim().is_empty() {
+                        // 🔥 NEW: 활동 시간 업데이트 (타임아웃 방지)
+                        if let Ok(mut last_time) = last_activity_stderr.lock() {
+                            *last_time = Instant::now();
+                        }
+                        job_stderr.log(&format!("⚠️ {}", line_str));
+                        
+                        let stderr_progress = DownloadProgress {
+                            channel: channel_name_clone.clone(),
+                            status: "정보".to_string(),
+                            progress: 0.0,
+                            current_video: format!("📺 {}", channel_name_clone),
+                            total_videos: 0,
+                            completed_videos: 0,
+                            log_message: format!("⚠️ {}", line_str),
+                            throughput_bps: None,
+                        };
+                        let _ = window_clone.emit("download-progress", &stderr_progress);
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+    });
+    
+    // 프로세스 완료 대기 (타임아웃은 위에서 이미 설정됨)
+    let mut process_completed = false;
+    while !process_completed {
+        // 중단 신호 확인 (이 작업의 프로세스만 종료)
+        if job.is_cancelled() {
+            terminate_child(&mut child);
+            return Err("다운로드가 중단되었습니다".to_string());
+        }
+        
+        // 디스크 여유 공간: 경고 기준 아래면 한 번 알리고, 최소 기준 아래면 중단
+        if last_disk_check.elapsed() >= DISK_CHECK_INTERVAL {
+            last_disk_check = Instant::now();
+            match check_vault_disk_space() {
+                DiskSpaceCheck::Exhausted(warning) => {
+                    job.log(&warning.message);
+                    let _ = window.emit("disk-space-low", &warning);
+                    terminate_child(&mut child);
+                    return Err(format!("{} - 다운로드를 멈췄습니다", warning.message));
+                }
+                DiskSpaceCheck::Low(warning) if !disk_warning_sent => {
+                    disk_warning_sent = true;
+                    job.log(&warning.message);
+                    let _ = window.emit("disk-space-low", &warning);
+                }
+                _ => {}
+            }
+        }
+        
+        // 무응답 감지: 정체 알림 후 타임아웃이 지나면 종료
+        let idle = last_activity.lock().map(|t| t.elapsed()).unwrap_or_default();
+        if let Some(timeout) = timeout_duration {
+            if idle > timeout {
+                eprintln!("⚠️ {}초간 로그 없음 - 프로세스 강제 종료", timeout.as_secs());
+                let _ = child.kill();
+                return Err(format!("프로세스 타임아웃으로 중단되었습니다 ({}초간 응답 없음)", timeout.as_secs()));
+            }
+            if idle >= timeout / 2 && !stalled_reported {
+                stalled_reported = true;
+                let message = format!(
+                    "⏳ {}초간 출력 없음 - {}초 안에 응답이 없으면 종료합니다",
+                    idle.as_secs(),
+                    (timeout - idle).as_secs()
+                );
+                job.log(&message);
+                let mut current = (0.0, 0, 0);
+                job.update(|j| current = (j.progress, j.total_videos, j.completed_videos));
+                let stalled = DownloadProgress {
+                    channel: channel_name.to_string(),
+                    status: "정체".to_string(),
+                    progress: current.0,
+                    current_video: format!("📺 {}", channel_name),
+                    total_videos: current.1,
+                    completed_videos: current.2,
+                    log_message: message,
+                    throughput_bps: None,
+                };
+                let _ = window.emit("download-progress", &stalled);
+            } else if idle < timeout / 2 {
+                stalled_reported = false;
+            }
+        }
+        
+        // 프로세스 상태 확인
+        match child.try_wait() {
+            Ok(Some(_)) => {
+                process_completed = true;
+            }
+            Ok(None) => {
+                // 아직 실행 중, 잠시 대기
+                thread::sleep(Duration::from_millis(100));
+            }
+            Err(_) => {
+                process_completed = true;
+            }
+        }
+    }
+    
+    // 스레드 완료 대기 (통계는 stdout 진행 이벤트 집계)
+    let (channel_total_videos, channel_downloaded_videos) = stdout_handle.join().unwrap_or((0, 0));
+    let _ = stderr_handle.join();
+    
+    // 프로세스 최종 상태 확인
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+    
+    Ok((channel_total_videos, channel_downloaded_videos, output.status))
+}
+
+// 채널 병렬 다운로드 설정 (config/download_concurrency.json)
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+struct DownloadConcurrencySettings {
+    parallel_channels: u32, // 동시에 다운로드할 채널 수 (1이면 단일 프로세스 배치)
+}
+
+impl Default for DownloadConcurrencySettings {
+    fn default() -> Self {
+        DownloadConcurrencySettings { parallel_channels: 2 }
+    }
+}
+
+const DOWNLOAD_CONCURRENCY_FILE: &str = "download_concurrency.json";
+const MAX_PARALLEL_CHANNELS: u32 = 8;
+
+#[command]
+fn get_download_concurrency_settings() -> Result<DownloadConcurrencySettings, String> {
+    load_json_config(DOWNLOAD_CONCURRENCY_FILE)
+}
+
+#[command]
+fn save_download_concurrency_settings(settings: DownloadConcurrencySettings) -> Result<(), String> {
+    if !(1..=MAX_PARALLEL_CHANNELS).contains(&settings.parallel_channels) {
+        return Err(format!("동시 다운로드 채널 수는 1~{} 사이여야 합니다", MAX_PARALLEL_CHANNELS));
+    }
+    save_json_config(DOWNLOAD_CONCURRENCY_FILE, &settings)
+}
+
+// yt-dlp 인증 설정 (멤버십/연령 제한 영상용, config/auth.json)
+// cookies_file이 있으면 우선 사용, 없으면 browser(+프로필)의 쿠키 사용
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+struct AuthSettings {
+    cookies_file: Option<String>,    // Netscape 형식 cookies.txt 경로
+    browser: Option<String>,         // 예: chrome, firefox, safari
+    browser_profile: Option<String>, // 브라우저 프로필 이름 또는 경로 (없으면 기본 프로필)
+}
+
+#[derive(Serialize, Deserialize)]
+struct AuthCheckResult {
+    authenticated: bool,
+    source: String,
+    message: String,
+}
+
+const AUTH_SETTINGS_FILE: &str = "auth.json";
+// yt-dlp --cookies-from-browser 지원 브라우저
+const SUPPORTED_COOKIE_BROWSERS: &[&str] = &["brave", "chrome", "chromium", "edge", "firefox", "opera", "safari", "vivaldi", "whale"];
+// 로그인해야만 열리는 페이지 (인증 확인용)
+const AUTH_CHECK_URL: &str = "https://www.youtube.com/feed/subscriptions";
+
+fn load_auth_settings() -> AuthSettings {
+    load_json_config(AUTH_SETTINGS_FILE).unwrap_or_default()
+}
+
+#[command]
+fn get_auth_settings() -> Result<AuthSettings, String> {
+    load_json_config(AUTH_SETTINGS_FILE)
+}
+
+// cookies.txt 또는 브라우저 프로필 등록 (둘 다 비우면 인증 해제)
+#[command]
+fn save_auth_settings(settings: AuthSettings) -> Result<(), String> {
+    let non_empty = |value: Option<String>| value.map(|v| v.trim().to_string()).filter(|v| !v.is_empty());
+    let settings = AuthSettings {
+        cookies_file: non_empty(settings.cookies_file),
+        browser: non_empty(settings.browser).map(|b| b.to_lowercase()),
+        browser_profile: non_empty(settings.browser_profile),
+    };
+    if let Some(file) = &settings.cookies_file {
+        if !Path::new(file).is_file() {
+            return Err(format!("쿠키 파일을 찾을 수 없습니다: {}", file));
+        }
+    }
+    if let Some(browser) = &settings.browser {
+        if !SUPPORTED_COOKIE_BROWSERS.contains(&browser.as_str()) {
+            return Err(format!("지원하지 않는 브라우저입니다: {} (지원: {})", browser, SUPPORTED_COOKIE_BROWSERS.join(", ")));
+        }
+    }
+    if settings.browser_profile.is_some() && settings.browser.is_none() {
+        return Err("브라우저 프로필을 지정하려면 브라우저도 선택해야 합니다".to_string());
+    }
+    save_json_config(AUTH_SETTINGS_FILE, &settings)
+}
+
+// yt-dlp CLI 인증 인자
+fn ytdlp_auth_args(settings: &AuthSettings) -> Vec<String> {
+    if let Some(file) = &settings.cookies_file {
+        return vec!["--cookies".to_string(), file.clone()];
+    }
+    match (&settings.browser, &settings.browser_profile) {
+        (Some(browser), Some(profile)) => vec!["--cookies-from-browser".to_string(), format!("{}:{}", browser, profile)],
+        (Some(browser), None) => vec!["--cookies-from-browser".to_string(), browser.clone()],
+        _ => Vec::new(),
+    }
+}
+
+// Python 다운로더에 인증 설정 전달 (등록된 설정이 없으면 Python 기본값 유지)
+fn apply_auth_env(cmd: &mut Command) {
+    let settings = load_auth_settings();
+    if let Some(file) = &settings.cookies_file {
+        cmd.env("YDH_COOKIES_FILE", file);
+    }
+    if let Some(browser) = &settings.browser {
+        cmd.env("YDH_BROWSER", browser).env("YDH_USE_BROWSER_COOKIES", "true");
+    }
+    if let Some(profile) = &settings.browser_profile {
+        cmd.env("YDH_BROWSER_PROFILE", profile);
+    }
+}
+
+// 등록된 인증 정보로 로그인 전용 페이지를 조회해 인증 여부 확인
+#[command]
+async fn verify_auth() -> Result<AuthCheckResult, String> {
+    let settings = load_auth_settings();
+    let args = ytdlp_auth_args(&settings);
+    if args.is_empty() {
+        return Ok(AuthCheckResult {
+            authenticated: false,
+            source: "none".to_string(),
+            message: "등록된 쿠키 파일이나 브라우저 프로필이 없습니다".to_string(),
+        });
+    }
+    let source = match (&settings.cookies_file, &settings.browser) {
+        (Some(file), _) => format!("cookies: {}", file),
+        (None, Some(_)) => format!("browser: {}", args[1]),
+        _ => "none".to_string(),
+    };
+    
+    let project_root = get_project_root();
+    let venv_python = project_root.join("venv").join("bin").join("python3");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    let output = Command::new(&venv_python)
+        .args(["-m", "yt_dlp", "-J", "--flat-playlist", "--playlist-items", "1", "--no-warnings"])
+        .args(&args)
+        .arg(AUTH_CHECK_URL)
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| format!("yt-dlp 실행 실패: {}", e))?;
+    
+    let (authenticated, message) = if output.status.success() {
+        (true, "✅ 인증되었습니다 (멤버십/연령 제한 영상 다운로드 가능)".to_string())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr.lines().rev().find(|l| l.contains("ERROR")).unwrap_or(stderr.trim()).to_string();
+        (false, format!("❌ 인증 실패: {}", reason))
+    };
+    Ok(AuthCheckResult { authenticated, source, message })
+}
+
+// YouTube 차단 시 메타데이터/자막 미러 대체 설정 (config/mirror.json, 영상은 항상 yt-dlp)
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+struct MirrorSettings {
+    enabled: bool,
+    instance: Option<String>, // Invidious/Piped API 주소
+    mirror_type: String,      // "invidious" | "piped"
+    failure_threshold: u32,   // 연속 추출 실패 시 미러로 전환 (차단 메시지는 즉시)
+}
+
+impl Default for MirrorSettings {
+    fn default() -> Self {
+        MirrorSettings { enabled: false, instance: None, mirror_type: "invidious".to_string(), failure_threshold: 3 }
+    }
+}
+
+const MIRROR_SETTINGS_FILE: &str = "mirror.json";
+
+#[command]
+fn get_mirror_settings() -> Result<MirrorSettings, String> {
+    load_json_config(MIRROR_SETTINGS_FILE)
+}
+
+#[command]
+fn save_mirror_settings(settings: MirrorSettings) -> Result<(), String> {
+    if !matches!(settings.mirror_type.as_str(), "invidious" | "piped") {
+        return Err(format!("지원하지 않는 미러 종류입니다: {}", settings.mirror_type));
+    }
+    let instance = settings.instance.as_deref().map(str::trim).filter(|i| !i.is_empty());
+    if let Some(instance) = instance {
+        if !instance.starts_with("https://") && !instance.starts_with("http://") {
+            return Err(format!("미러 주소는 http(s)://로 시작해야 합니다: {}", instance));
+        }
+    } else if settings.enabled {
+        return Err("미러 대체를 켜려면 인스턴스 주소가 필요합니다".to_string());
+    }
+    let settings = MirrorSettings {
+        instance: instance.map(|i| i.trim_end_matches('/').to_string()),
+        failure_threshold: settings.failure_threshold.max(1),
+        ..settings
+    };
+    save_json_config(MIRROR_SETTINGS_FILE, &settings)
+}
+
+// Python 다운로더에 미러 대체 설정 전달
+fn apply_mirror_env(cmd: &mut Command) {
+    let settings: MirrorSettings = load_json_config(MIRROR_SETTINGS_FILE).unwrap_or_default();
+    let Some(instance) = settings.instance.filter(|_| settings.enabled) else { return };
+    cmd.env("YDH_MIRROR_FALLBACK_ENABLED", "true")
+        .env("YDH_MIRROR_INSTANCE", instance)
+        .env("YDH_MIRROR_TYPE", settings.mirror_type)
+        .env("YDH_MIRROR_FAILURE_THRESHOLD", settings.failure_threshold.to_string());
+}
+
+// 다운로드 프록시 설정 (config/proxy.json, 채널별 재정의는 ChannelSettings.proxy)
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+struct ProxySettings {
+    url: Option<String>, // 예: http://127.0.0.1:8080, socks5://127.0.0.1:9050
+}
+
+#[derive(Serialize, Deserialize)]
+struct ProxyTestResult {
+    ok: bool,
+    proxy: Option<String>,
+    latency_ms: u64,
+    message: String,
+}
+
+const PROXY_SETTINGS_FILE: &str = "proxy.json";
+// 채널별 설정에서 전역 프록시를 쓰지 않고 직접 연결
+const DIRECT_PROXY: &str = "direct";
+// 프록시 연결 확인용 영상 (YouTube 첫 업로드 영상)
+const PROXY_TEST_URL: &str = "https://www.youtube.com/watch?v=jNQXAC9IVRw";
+
+fn validate_proxy_url(url: &str) -> Result<(), String> {
+    let scheme = url.split_once("://").map(|(scheme, _)| scheme).unwrap_or("");
+    if !matches!(scheme, "http" | "https" | "socks4" | "socks4a" | "socks5" | "socks5h") {
+        return Err(format!("프록시 주소 형식이 올바르지 않습니다 (http/https/socks4/socks5): {}", url));
+    }
+    Ok(())
+}
+
+fn load_proxy_url() -> Option<String> {
+    load_json_config::<ProxySettings>(PROXY_SETTINGS_FILE).ok().and_then(|s| s.url)
+}
+
+#[command]
+fn get_proxy_settings() -> Result<ProxySettings, String> {
+    load_json_config(PROXY_SETTINGS_FILE)
+}
+
+#[command]
+fn save_proxy_settings(settings: ProxySettings) -> Result<(), String> {
+    let url = settings.url.map(|u| u.trim().to_string()).filter(|u| !u.is_empty());
+    if let Some(url) = &url {
+        validate_proxy_url(url)?;
+    }
+    save_json_config(PROXY_SETTINGS_FILE, &ProxySettings { url })
+}
+
+// 채널별 프록시 재정의 ("direct"면 직접 연결, None이면 전역 설정 사용)
+#[command]
+fn set_channel_proxy(channel_name: String, proxy: Option<String>) -> Result<(), String> {
+    let proxy = proxy.map(|p| p.trim().to_string()).filter(|p| !p.is_empty());
+    if let Some(proxy) = proxy.as_deref().filter(|p| *p != DIRECT_PROXY) {
+        validate_proxy_url(proxy)?;
+    }
+    let mut settings = load_channel_settings();
+    settings.entry(channel_name).or_default().proxy = proxy;
+    save_channel_settings(&settings)
+}
+
+// 채널에 적용할 프록시 (재정의 > 전역, "direct"면 None)
+fn effective_proxy(channel_name: Option<&str>) -> Option<String> {
+    let channel_proxy = channel_name.and_then(|name| load_channel_settings().remove(name)).and_then(|s| s.proxy);
+    match channel_proxy {
+        Some(proxy) if proxy == DIRECT_PROXY => None,
+        Some(proxy) => Some(proxy),
+        None => load_proxy_url(),
+    }
+}
+
+// 앱에서 직접 실행하는 yt-dlp 조회용 프록시 인자
+fn ytdlp_proxy_args() -> Vec<String> {
+    load_proxy_url().map(|url| vec!["--proxy".to_string(), url]).unwrap_or_default()
+}
+
+// 채널 URL별 프록시 (YDH_CHANNEL_PROXIES, 재정의한 채널만, 빈 문자열은 직접 연결)
+fn channel_proxies_env(channels: &[ChannelInfo]) -> String {
+    let settings = load_channel_settings();
+    let proxies: BTreeMap<&str, &str> = channels
+        .iter()
+        .filter_map(|channel| {
+            let proxy = settings.get(&channel.name)?.proxy.as_deref()?;
+            Some((channel.url.as_str(), if proxy == DIRECT_PROXY { "" } else { proxy }))
+        })
+        .collect();
+    serde_json::to_string(&proxies).unwrap_or_default()
+}
+
+// 채널 URL별 화질 (YDH_CHANNEL_QUALITIES, channels.toml에서 화질을 지정한 채널만)
+fn channel_qualities_env(channels: &[ChannelInfo]) -> String {
+    let qualities: BTreeMap<&str, &str> = channels
+        .iter()
+        .filter_map(|channel| Some((channel.url.as_str(), channel.quality.as_deref()?)))
+        .collect();
+    serde_json::to_string(&qualities).unwrap_or_default()
+}
+
+// 배치 실행 전 프록시 연결 확인 (None이면 저장된 전역 프록시)
+#[command]
+async fn test_proxy(proxy: Option<String>) -> Result<ProxyTestResult, String> {
+    let proxy = proxy.map(|p| p.trim().to_string()).filter(|p| !p.is_empty()).or_else(load_proxy_url);
+    let Some(proxy_url) = proxy.clone() else {
+        return Err("확인할 프록시가 없습니다".to_string());
+    };
+    validate_proxy_url(&proxy_url)?;
+    
+    let project_root = get_project_root();
+    let venv_python = project_root.join("venv").join("bin").join("python3");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    let started = Instant::now();
+    let output = Command::new(&venv_python)
+        .args(["-m", "yt_dlp", "--simulate", "--no-warnings", "--socket-timeout", "15", "--print", "id", "--proxy"])
+        .arg(&proxy_url)
+        .arg(PROXY_TEST_URL)
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| format!("yt-dlp 실행 실패: {}", e))?;
+    let latency_ms = started.elapsed().as_millis() as u64;
+    
+    let (ok, message) = if output.status.success() {
+        (true, format!("✅ 프록시를 통해 YouTube에 연결되었습니다 ({:.1}초)", latency_ms as f64 / 1000.0))
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr.lines().rev().find(|l| l.contains("ERROR")).unwrap_or(stderr.trim()).to_string();
+        (false, format!("❌ 프록시 연결 실패: {}", reason))
+    };
+    Ok(ProxyTestResult { ok, proxy, latency_ms, message })
+}
+
+// 디스크 여유 공간 보호 (config/disk_guard.json): 다운로드/변환 시작 전 확인, 다운로드 중에도 주기적으로 확인
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+struct DiskGuardSettings {
+    min_free_gb: f64,      // 이보다 적으면 시작하지 않고, 실행 중이면 중단
+    warn_free_gb: f64,     // 이보다 적으면 경고 (disk-space-low 이벤트)
+    block_below_min: bool, // false면 최소치 아래에서도 경고만
+}
+
+impl Default for DiskGuardSettings {
+    fn default() -> Self {
+        DiskGuardSettings { min_free_gb: 5.0, warn_free_gb: 20.0, block_below_min: true }
+    }
+}
+
+const DISK_GUARD_FILE: &str = "disk_guard.json";
+const DISK_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Serialize, Clone)]
+struct DiskSpaceWarning {
+    available_bytes: u64,
+    min_free_bytes: u64,
+    warn_free_bytes: u64,
+    aborting: bool,
+    message: String,
+}
+
+enum DiskSpaceCheck {
+    Ok,
+    Low(DiskSpaceWarning),      // 경고 기준 아래
+    Exhausted(DiskSpaceWarning), // 최소 기준 아래 (차단 설정 시)
+}
+
+#[command]
+fn get_disk_guard_settings() -> Result<DiskGuardSettings, String> {
+    load_json_config(DISK_GUARD_FILE)
+}
+
+#[command]
+fn set_disk_guard_settings(settings: DiskGuardSettings) -> Result<DiskGuardSettings, String> {
+    if !settings.min_free_gb.is_finite() || !settings.warn_free_gb.is_finite() || settings.min_free_gb < 0.0 {
+        return Err("여유 공간 기준은 0 이상이어야 합니다".to_string());
+    }
+    if settings.warn_free_gb < settings.min_free_gb {
+        return Err("경고 기준은 최소 여유 공간보다 작을 수 없습니다".to_string());
+    }
+    save_json_config(DISK_GUARD_FILE, &settings)?;
+    Ok(settings)
+}
+
+fn gb_to_bytes(gb: f64) -> u64 {
+    (gb * 1024.0 * 1024.0 * 1024.0) as u64
+}
+
+// vault 볼륨의 여유 공간을 기준과 비교 (조회 실패 시 막지 않음)
+fn check_vault_disk_space() -> DiskSpaceCheck {
+    let settings: DiskGuardSettings = load_json_config(DISK_GUARD_FILE).unwrap_or_default();
+    let Ok(available_bytes) = fs2::available_space(get_project_root().join("vault")) else { return DiskSpaceCheck::Ok };
+    let (min_free_bytes, warn_free_bytes) = (gb_to_bytes(settings.min_free_gb), gb_to_bytes(settings.warn_free_gb));
+    let available_gb = available_bytes as f64 / 1024.0 / 1024.0 / 1024.0;
+    if available_bytes < min_free_bytes && settings.block_below_min {
+        DiskSpaceCheck::Exhausted(DiskSpaceWarning {
+            available_bytes,
+            min_free_bytes,
+            warn_free_bytes,
+            aborting: true,
+            message: format!("💾 디스크 여유 공간 부족: {:.1}GB 남음 (최소 {:.1}GB 필요)", available_gb, settings.min_free_gb),
+        })
+    } else if available_bytes < warn_free_bytes {
+        DiskSpaceCheck::Low(DiskSpaceWarning {
+            available_bytes,
+            min_free_bytes,
+            warn_free_bytes,
+            aborting: false,
+            message: format!("💾 디스크 공간이 거의 찼습니다: {:.1}GB 남음", available_gb),
+        })
+    } else {
+        DiskSpaceCheck::Ok
+    }
+}
+
+// 작업 시작 전 확인: 부족하면 Err, 경고 기준 아래면 이벤트만 보내고 진행
+fn ensure_disk_space(window: &Window) -> Result<(), String> {
+    match check_vault_disk_space() {
+        DiskSpaceCheck::Ok => Ok(()),
+        DiskSpaceCheck::Low(warning) => {
+            let _ = window.emit("disk-space-low", &warning);
+            Ok(())
+        }
+        DiskSpaceCheck::Exhausted(warning) => {
+            let _ = window.emit("disk-space-low", &warning);
+            Err(format!("{} - 공간을 확보하거나 설정에서 기준을 낮추세요", warning.message))
+        }
+    }
+}
+
+// 다운로드 속도/동시성 제한 (config/download_limits.json, yt-dlp ratelimit / concurrent_fragment_downloads)
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+struct DownloadLimitSettings {
+    rate_limit_mbps: Option<f64>, // 전체 다운로드 속도 제한 (MB/s, None이면 무제한)
+    concurrent_fragments: u32,    // 영상 하나에서 동시에 받을 조각 수
+}
+
+impl Default for DownloadLimitSettings {
+    fn default() -> Self {
+        DownloadLimitSettings { rate_limit_mbps: None, concurrent_fragments: 1 }
+    }
+}
+
+const DOWNLOAD_LIMITS_FILE: &str = "download_limits.json";
+const MAX_CONCURRENT_FRAGMENTS: u32 = 16;
+
+#[command]
+fn get_download_limits() -> Result<DownloadLimitSettings, String> {
+    load_json_config(DOWNLOAD_LIMITS_FILE)
+}
+
+// 다운로드 속도 제한(MB/s, 0 또는 None이면 해제)과 동시 조각 수 설정
+#[command]
+fn set_download_limits(rate_limit_mbps: Option<f64>, concurrent_fragments: Option<u32>) -> Result<DownloadLimitSettings, String> {
+    let mut settings: DownloadLimitSettings = load_json_config(DOWNLOAD_LIMITS_FILE)?;
+    match rate_limit_mbps {
+        Some(rate) if rate < 0.0 || !rate.is_finite() => return Err("속도 제한은 0 이상이어야 합니다".to_string()),
+        Some(rate) if rate > 0.0 => settings.rate_limit_mbps = Some(rate),
+        _ => settings.rate_limit_mbps = None,
+    }
+    if let Some(fragments) = concurrent_fragments {
+        if !(1..=MAX_CONCURRENT_FRAGMENTS).contains(&fragments) {
+            return Err(format!("동시 조각 수는 1~{} 사이여야 합니다", MAX_CONCURRENT_FRAGMENTS));
+        }
+        settings.concurrent_fragments = fragments;
+    }
+    save_json_config(DOWNLOAD_LIMITS_FILE, &settings)?;
+    Ok(settings)
+}
+
+// Python 다운로더에 속도/동시성 제한 전달 (병렬 실행 시 전체 제한을 프로세스 수로 나눔)
+fn apply_download_limits_env(cmd: &mut Command, workers: usize) {
+    let settings: DownloadLimitSettings = load_json_config(DOWNLOAD_LIMITS_FILE).unwrap_or_default();
+    if let Some(rate) = settings.rate_limit_mbps {
+        let bytes_per_sec = (rate * 1024.0 * 1024.0 / workers.max(1) as f64) as u64;
+        cmd.env("YDH_RATE_LIMIT", bytes_per_sec.max(1).to_string());
+    }
+    cmd.env("YDH_CONCURRENT_FRAGMENTS", settings.concurrent_fragments.clamp(1, MAX_CONCURRENT_FRAGMENTS).to_string());
+}
+
+// yt-dlp 요청 간격/타임아웃/재시도 (config/downloader_settings.json, 모든 다운로드 실행에 공통 적용)
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+struct DownloaderSettings {
+    sleep_interval: u32,           // 요청 간 최소 지연 (초)
+    max_sleep_interval: u32,       // 최대 랜덤 지연 (초)
+    sleep_requests: u32,           // 요청 N회마다 추가 슬립
+    socket_timeout: u32,           // 소켓 타임아웃 (초)
+    retries: u32,                  // 재시도 횟수
+    full_scan_socket_timeout: u32, // 전체 무결성 검사 모드 소켓 타임아웃 (초)
+    full_scan_retries: u32,        // 전체 무결성 검사 모드 재시도 횟수
+    resume_partials: bool,         // 중단 시 .part 파일을 지우지 않고 이어받기용으로 보존
+    download_inactivity_timeout: u32,  // 채널 다운로드: 출력 없이 이 시간이 지나면 종료 (초, 0이면 끄기)
+    full_scan_inactivity_timeout: u32, // 전체 무결성 검사 (큰 재생목록 추출은 오래 조용할 수 있음)
+    single_inactivity_timeout: u32,    // 단일 영상 다운로드
+}
+
+impl Default for DownloaderSettings {
+    fn default() -> Self {
+        DownloaderSettings {
+            sleep_interval: 2,
+            max_sleep_interval: 5,
+            sleep_requests: 20,
+            socket_timeout: 8,
+            retries: 1,
+            full_scan_socket_timeout: 10,
+            full_scan_retries: 2,
+            resume_partials: true,
+            download_inactivity_timeout: 120,
+            full_scan_inactivity_timeout: 600,
+            single_inactivity_timeout: 120,
+        }
+    }
+}
+
+const DOWNLOADER_SETTINGS_FILE: &str = "downloader_settings.json";
+const MAX_SOCKET_TIMEOUT_SECS: u32 = 300;
+const MAX_YTDLP_RETRIES: u32 = 20;
+const MAX_INACTIVITY_TIMEOUT_SECS: u32 = 3600;
+
+// 출력 감시 타임아웃을 고르는 자식 프로세스 작업 종류
+#[derive(Clone, Copy)]
+enum ProcessOperation {
+    Download,
+    FullScan,
+    Single,
+}
+
+impl ProcessOperation {
+    // 출력 없이 허용하는 시간 (None이면 종료하지 않음)
+    fn inactivity_timeout(self) -> Option<Duration> {
+        let settings: DownloaderSettings = load_json_config(DOWNLOADER_SETTINGS_FILE).unwrap_or_default();
+        let secs = match self {
+            ProcessOperation::Download => settings.download_inactivity_timeout,
+            ProcessOperation::FullScan => settings.full_scan_inactivity_timeout,
+            ProcessOperation::Single => settings.single_inactivity_timeout,
+        };
+        (secs > 0).then(|| Duration::from_secs(secs.min(MAX_INACTIVITY_TIMEOUT_SECS) as u64))
+    }
+}
+
+#[command]
+fn get_downloader_settings() -> Result<DownloaderSettings, String> {
+    load_json_config(DOWNLOADER_SETTINGS_FILE)
+}
+
+#[command]
+fn set_downloader_settings(settings: DownloaderSettings) -> Result<DownloaderSettings, String> {
+    if settings.max_sleep_interval < settings.sleep_interval {
+        return Err("최대 지연은 최소 지연보다 짧을 수 없습니다".to_string());
+    }
+    for timeout in [settings.socket_timeout, settings.full_scan_socket_timeout] {
+        if !(1..=MAX_SOCKET_TIMEOUT_SECS).contains(&timeout) {
+            return Err(format!("소켓 타임아웃은 1~{}초 사이여야 합니다", MAX_SOCKET_TIMEOUT_SECS));
+        }
+    }
+    if settings.retries > MAX_YTDLP_RETRIES || settings.full_scan_retries > MAX_YTDLP_RETRIES {
+        return Err(format!("재시도 횟수는 0~{} 사이여야 합니다", MAX_YTDLP_RETRIES));
+    }
+    for timeout in [settings.download_inactivity_timeout, settings.full_scan_inactivity_timeout, settings.single_inactivity_timeout] {
+        if timeout > MAX_INACTIVITY_TIMEOUT_SECS {
+            return Err(format!("무응답 타임아웃은 0~{}초 사이여야 합니다 (0은 끄기)", MAX_INACTIVITY_TIMEOUT_SECS));
+        }
+    }
+    save_json_config(DOWNLOADER_SETTINGS_FILE, &settings)?;
+    Ok(settings)
+}
+
+// Python 다운로더에 yt-dlp 요청 간격/타임아웃/재시도 전달
+fn apply_downloader_env(cmd: &mut Command, full_scan: bool) {
+    let settings: DownloaderSettings = load_json_config(DOWNLOADER_SETTINGS_FILE).unwrap_or_default();
+    let (socket_timeout, retries) = if full_scan {
+        (settings.full_scan_socket_timeout, settings.full_scan_retries)
+    } else {
+        (settings.socket_timeout, settings.retries)
+    };
+    cmd.env("YDH_YTDLP_SLEEP_INTERVAL", settings.sleep_interval.to_string())
+        .env("YDH_YTDLP_MAX_SLEEP_INTERVAL", settings.max_sleep_interval.to_string())
+        .env("YDH_YTDLP_SLEEP_REQUESTS", settings.sleep_requests.to_string())
+        .env("YDH_YTDLP_SOCKET_TIMEOUT", socket_timeout.to_string())
+        .env("YDH_YTDLP_RETRIES", retries.to_string());
+}
+
+// ydh batch 프로세스 명령 구성 (channels_file을 지정하면 해당 채널 목록만 처리)
+fn batch_download_command(channels: &[ChannelInfo], quality: Option<&str>, full_scan: bool, since: Option<&str>, channels_file: Option<&Path>) -> Command {
+    let project_root = get_project_root();
+    let venv_python = project_root.join("venv").join("bin").join("python3");
+    let mut cmd = Command::new(&venv_python);
+    cmd.args(["-u", "-m", "ydh", "batch"])
+        .current_dir(&project_root)
+        .env("PYTHONUNBUFFERED", "1")        // Python 출력 버퍼링 방지
+        .env("PYTHONIOENCODING", "utf-8")    // UTF-8 인코딩 강제
+        .env("YDH_SUBTITLE_LANGUAGES", subtitle_languages_env(channels)) // 채널 감지 언어
+        .env("YDH_EXCLUDED_CHANNELS", excluded_channels_env(channels))     // 추모/일시정지/보관 채널 제외
+        .env("YDH_CHANNEL_DATE_RANGES", channel_date_ranges_env(channels)) // 채널별 업로드 날짜 범위
+        .env("YDH_CHANNEL_DOWNLOAD_POLICIES", channel_download_policies_env(channels)) // 채널별 최신 N개/최대 용량
+        .env("YDH_CHANNEL_SUBTITLES", channel_subtitles_env(channels))     // 채널별 자막 언어
+        .env("YDH_CHANNEL_CONTENT_FILTERS", channel_content_filters_env(channels)) // 채널별 Shorts/라이브/길이 필터
+        .env("YDH_CHANNEL_PROXIES", channel_proxies_env(channels))         // 채널별 프록시
+        .env("YDH_CHANNEL_QUALITIES", channel_qualities_env(channels))     // 채널별 화질 (channels.toml)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    apply_auth_env(&mut cmd);
+    apply_mirror_env(&mut cmd);
+    apply_download_limits_env(&mut cmd, 1);
+    apply_downloader_env(&mut cmd, full_scan);
+    if let Some(proxy) = load_proxy_url() {
+        cmd.env("YDH_PROXY_URL", proxy);
+    }
+    if let Some(since) = since {
+        cmd.env("YDH_DOWNLOAD_SINCE", since);
+    }
+    if let Some(file) = channels_file {
+        cmd.arg("--channels-file").arg(file);
+    }
+    if full_scan {
+        // 전체 무결성 검사 모드 (타임아웃/재시도는 full_scan_* 설정 사용)
+        cmd.arg("--full-scan");
+    }
+    if let Some(quality) = quality {
+        cmd.env("YDH_VIDEO_QUALITY", quality);  // 품질 설정
+    }
+    cmd
+}
+
+// 채널별 ydh batch 프로세스를 최대 limit개까지 동시에 실행
+// 슬롯마다 별도 다운로드 폴더를 쓰되 채널 아카이브는 공유 폴더에 두고, 채널별 결과는 last_batch_stats.json으로 합침
+fn run_parallel_channel_downloads(
+    window: &Window,
+    job: &DownloadJobContext,
+    channels: &[ChannelInfo],
+    quality: Option<&str>,
+    full_scan: bool,
+    since: Option<&str>,
+    limit: usize,
+) -> Result<(u32, u32, bool), String> {
+    let project_root = get_project_root();
+    let downloads_dir = project_root.join("vault").join("downloads");
+    let parallel_dir = downloads_dir.join(".parallel");
+    let work_dir = parallel_dir.join(&job.id);
+    fs::create_dir_all(&work_dir).map_err(|e| format!("병렬 작업 폴더 생성 실패: {}", e))?;
+    
+    let pending: Arc<Mutex<std::collections::VecDeque<usize>>> = Arc::new(Mutex::new((0..channels.len()).collect()));
+    let totals = Arc::new(Mutex::new((0u32, 0u32, Vec::<String>::new())));
+    let started_at = chrono::Local::now();
+    let operation = if full_scan { ProcessOperation::FullScan } else { ProcessOperation::Download };
+    
+    thread::scope(|scope| {
+        for slot in 0..limit.min(channels.len()) {
+            let pending = pending.clone();
+            let totals = totals.clone();
+            let work_dir = work_dir.clone();
+            let downloads_dir = downloads_dir.clone();
+            // 슬롯 폴더는 작업 간 유지 → 중단된 다운로드를 다음 실행에서 이어받음
+            let download_path = parallel_dir.join(format!("slot-{}", slot));
+            scope.spawn(move || {
+                let _ = fs::create_dir_all(&download_path);
+                loop {
+                    if job.is_cancelled() {
+                        break;
+                    }
+                    let Some(index) = pending.lock().ok().and_then(|mut q| q.pop_front()) else { break };
+                    let channel = &channels[index];
+                    let channels_file = work_dir.join(format!("channel-{}.txt", index));
+                    let stats_file = work_dir.join(format!("stats-{}.json", index));
+                    if let Err(e) = fs::write(&channels_file, format!("{}\n", channel.url)) {
+                        if let Ok(mut t) = totals.lock() {
+                            t.2.push(format!("{}: {}", channel.name, e));
+                        }
+                        continue;
+                    }
+                    
+                    let start_progress = DownloadProgress {
+                        channel: channel.name.clone(),
+                        status: "시작".to_string(),
+                        progress: 0.0,
+                        current_video: format!("📺 {}", channel.name),
+                        total_videos: 0,
+                        completed_videos: 0,
+                        log_message: format!("🚀 [{}] 채널 다운로드 시작 (슬롯 {})", channel.name, slot + 1),
+                        throughput_bps: None,
+                    };
+                    let _ = window.emit("download-progress", &start_progress);
+                    job.log(&start_progress.log_message);
+                    
+                    let mut cmd = batch_download_command(std::slice::from_ref(channel), quality, full_scan, since, Some(&channels_file));
+                    apply_download_limits_env(&mut cmd, limit.min(channels.len()));
+                    cmd.env("YDH_DOWNLOAD_PATH", &download_path)
+                        .env("YDH_DOWNLOAD_STATE_PATH", &downloads_dir)
+                        .env("YDH_BATCH_STATS_PATH", &stats_file);
+                    let outcome = cmd
+                        .spawn()
+                        .map_err(|e| e.to_string())
+                        .and_then(|child| run_process_with_realtime_output(child, window, &channel.name, job, operation));
+                    
+                    let (status, log_message) = match &outcome {
+                        Ok((total, downloaded, status)) if status.success() => {
+                            if let Ok(mut t) = totals.lock() {
+                                t.0 += total;
+                                t.1 += downloaded;
+                            }
+                            ("완료", format!("✅ [{}] 채널 완료 ({}/{}개)", channel.name, downloaded, total))
+                        }
+                        Ok(_) => {
+                            if let Ok(mut t) = totals.lock() {
+                                t.2.push(channel.name.clone());
+                            }
+                            ("실패", format!("❌ [{}] 채널 다운로드 중 오류 발생", channel.name))
+                        }
+                        Err(err) => {
+                            if !job.is_cancelled() {
+                                if let Ok(mut t) = totals.lock() {
+                                    t.2.push(channel.name.clone());
+                                }
+                            }
+                            ("실패", format!("❌ [{}] {}", channel.name, err))
+                        }
+                    };
+                    let channel_progress = DownloadProgress {
+                        channel: channel.name.clone(),
+                        status: status.to_string(),
+                        progress: 100.0,
+                        current_video: format!("📺 {}", channel.name),
+                        total_videos: 0,
+                        completed_videos: 0,
+                        log_message,
+                        throughput_bps: None,
+                    };
+                    let _ = window.emit("download-progress", &channel_progress);
+                    job.log(&channel_progress.log_message);
+                }
+            });
+        }
+    });
+    
+    // 채널별 결과 파일을 하나로 합쳐 실행 보고서가 읽을 수 있게 함
+    let channel_results: Vec<serde_json::Value> = (0..channels.len())
+        .filter_map(|index| fs::read_to_string(work_dir.join(format!("stats-{}.json", index))).ok())
+        .filter_map(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .flat_map(|stats| stats.get("channel_results").and_then(|r| r.as_array()).cloned().unwrap_or_default())
+        .collect();
+    let merged = serde_json::json!({
+        "finished_at": chrono::Local::now().to_rfc3339(),
+        "full_scan": full_scan,
+        "duration_seconds": (chrono::Local::now() - started_at).num_seconds(),
+        "parallel_channels": limit,
+        "channel_results": channel_results,
+    });
+    let stats_path = project_root.join("vault").join("90_indices").join("last_batch_stats.json");
+    let _ = fs::write(stats_path, serde_json::to_string_pretty(&merged).unwrap_or_default());
+    let _ = fs::remove_dir_all(&work_dir);
+    
+    if job.is_cancelled() {
+        return Err("다운로드가 중단되었습니다".to_string());
+    }
+    let (total, downloaded, failed_channels) = totals.lock().map_err(|e| e.to_string())?.clone();
+    if !failed_channels.is_empty() {
+        job.log(&format!("⚠️ 실패한 채널: {}", failed_channels.join(", ")));
+    }
+    job.update(|j| {
+        j.total_videos = total;
+        j.completed_videos = downloaded;
+    });
+    Ok((total, downloaded, failed_channels.is_empty()))
+}
+
+// 배치 실행 후 변경 보고서 (config/last_run_report.json, 선택 시 Markdown 노트)
+#[derive(Serialize, Deserialize, Clone)]
+struct RunReportVideo {
+    title: String,
+    folder: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct RunReportFailure {
+    video_id: Option<String>,
+    title: Option<String>,
+    reason: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+struct RunReportChannel {
+    channel: String,
+    new_videos: Vec<RunReportVideo>,
+    metadata_refreshed: u32,
+    failed: Vec<RunReportFailure>,
+    bytes_added: i64,
+    error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct RunReport {
+    job_id: String,
+    started_at: String,
+    finished_at: String,
+    full_scan: bool,
+    succeeded: bool,
+    message: String,
+    channels: Vec<RunReportChannel>,
+    total_new_videos: u32,
+    total_failed: u32,
+    total_metadata_refreshed: u32,
+    total_bytes_added: i64,
+    embedding_backlog: u32, // 이번 실행으로 새로 생긴 임베딩 대기 영상 수
+    note_path: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+struct RunReportSettings {
+    write_markdown_note: bool,
+}
+
+const LAST_RUN_REPORT_FILE: &str = "last_run_report.json";
+const RUN_REPORT_SETTINGS_FILE: &str = "run_report_settings.json";
+
+// 영상 폴더별 captions.md 수정 시각과 폴더 크기 (배치 전후 비교용)
+struct VideoFolderSnapshot {
+    channel: String,
+    captions_mtime: Option<i64>,
+    bytes: u64,
+}
+
+fn snapshot_vault_videos() -> HashMap<PathBuf, VideoFolderSnapshot> {
+    let root = get_project_root().join("vault").join("10_videos");
+    let subdirs = |dir: &Path| -> Vec<PathBuf> {
+        fs::read_dir(dir)
+            .map(|entries| entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()).collect())
+            .unwrap_or_default()
+    };
+    let mut snapshot = HashMap::new();
+    for channel_dir in subdirs(&root) {
+        let channel = channel_dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        for year_dir in subdirs(&channel_dir) {
+            for folder in subdirs(&year_dir) {
+                snapshot.insert(folder.clone(), VideoFolderSnapshot {
+                    channel: channel.clone(),
+                    captions_mtime: file_mtime_secs(&folder.join("captions.md")),
+                    bytes: calculate_directory_size(&folder),
+                });
+            }
+        }
+    }
+    snapshot
+}
+
+// Python 배치가 남긴 채널별 결과 (이번 실행 이후에 기록된 경우만)
+fn load_batch_stats_since(since: &chrono::DateTime<chrono::Local>) -> Option<serde_json::Value> {
+    let path = get_project_root().join("vault").join("90_indices").join("last_batch_stats.json");
+    let stats: serde_json::Value = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+    let finished_at = chrono::DateTime::parse_from_rfc3339(stats.get("finished_at")?.as_str()?).ok()?;
+    (finished_at >= *since).then_some(stats)
+}
+
+fn run_report_channel<'a>(channels: &'a mut BTreeMap<String, RunReportChannel>, channel: &str) -> &'a mut RunReportChannel {
+    channels
+        .entry(channel.to_string())
+        .or_insert_with(|| RunReportChannel { channel: channel.to_string(), ..Default::default() })
+}
+
+fn build_run_report(
+    job_id: &str,
+    full_scan: bool,
+    before: &HashMap<PathBuf, VideoFolderSnapshot>,
+    started_at: chrono::DateTime<chrono::Local>,
+    outcome: &Result<String, String>,
+) -> RunReport {
+    let after = snapshot_vault_videos();
+    let project_root = get_project_root();
+    let mut channels: BTreeMap<String, RunReportChannel> = BTreeMap::new();
+    for (folder, current) in &after {
+        let report = run_report_channel(&mut channels, &current.channel);
+        match before.get(folder) {
+            None => {
+                report.new_videos.push(RunReportVideo {
+                    title: extract_title_from_path(folder),
+                    folder: folder.strip_prefix(&project_root).unwrap_or(folder).to_string_lossy().to_string(),
+                });
+                report.bytes_added += current.bytes as i64;
+            }
+            Some(previous) => {
+                if previous.captions_mtime != current.captions_mtime {
+                    report.metadata_refreshed += 1;
+                }
+                report.bytes_added += current.bytes as i64 - previous.bytes as i64;
+            }
+        }
+    }
+    for (folder, previous) in before {
+        if !after.contains_key(folder) {
+            let report = run_report_channel(&mut channels, &previous.channel);
+            report.bytes_added -= previous.bytes as i64;
+        }
+    }
+    
+    if let Some(stats) = load_batch_stats_since(&started_at) {
+        for result in stats.get("channel_results").and_then(|r| r.as_array()).into_iter().flatten() {
+            let Some(channel) = result.get("channel").and_then(|c| c.as_str()) else { continue };
+            let report = run_report_channel(&mut channels, channel);
+            report.error = result.get("error").and_then(|e| e.as_str()).map(|e| e.to_string());
+            report.failed = result
+                .get("failed_items")
+                .and_then(|items| serde_json::from_value(items.clone()).ok())
+                .unwrap_or_default();
+        }
+    }
+    
+    let channels: Vec<RunReportChannel> = channels
+        .into_values()
+        .filter(|c| !c.new_videos.is_empty() || c.metadata_refreshed > 0 || !c.failed.is_empty() || c.error.is_some() || c.bytes_added != 0)
+        .collect();
+    let total_new_videos = channels.iter().map(|c| c.new_videos.len() as u32).sum();
+    RunReport {
+        job_id: job_id.to_string(),
+        started_at: started_at.to_rfc3339(),
+        finished_at: chrono::Local::now().to_rfc3339(),
+        full_scan,
+        succeeded: outcome.is_ok(),
+        message: match outcome {
+            Ok(message) | Err(message) => message.clone(),
+        },
+        total_new_videos,
+        total_failed: channels.iter().map(|c| c.failed.len() as u32).sum(),
+        total_metadata_refreshed: channels.iter().map(|c| c.metadata_refreshed).sum(),
+        total_bytes_added: channels.iter().map(|c| c.bytes_added).sum(),
+        embedding_backlog: total_new_videos,
+        channels,
+        note_path: None,
+    }
+}
+
+fn render_run_report_note(report: &RunReport) -> String {
+    let mut note = format!(
+        "---\ntype: run_report\njob_id: {}\nstarted_at: {}\nfinished_at: {}\nfull_scan: {}\nsucceeded: {}\n---\n\n# 다운로드 실행 보고서\n\n{}\n\n",
+        report.job_id, report.started_at, report.finished_at, report.full_scan, report.succeeded, report.message
+    );
+    note.push_str(&format!(
+        "- 새 영상: {}개\n- 실패: {}개\n- 메타데이터 갱신: {}개\n- 추가 용량: {:.1} MB\n- 임베딩 대기: {}개\n",
+        report.total_new_videos,
+        report.total_failed,
+        report.total_metadata_refreshed,
+        report.total_bytes_added as f64 / 1024.0 / 1024.0,
+        report.embedding_backlog
+    ));
+    for channel in &report.channels {
+        note.push_str(&format!("\n## {}\n\n", channel.channel));
+        if let Some(error) = &channel.error {
+            note.push_str(&format!("> ⚠️ {}\n\n", error));
+        }
+        for video in &channel.new_videos {
+            note.push_str(&format!("- 🆕 [[{}/captions|{}]]\n", video.folder.trim_start_matches("vault/"), video.title));
+        }
+        for failure in &channel.failed {
+            let label = failure.title.clone().or_else(|| failure.video_id.clone()).unwrap_or_else(|| "?".to_string());
+            note.push_str(&format!("- ❌ {} — {}\n", label, failure.reason));
+        }
+        if channel.metadata_refreshed > 0 {
+            note.push_str(&format!("- 🔄 메타데이터 갱신 {}개\n", channel.metadata_refreshed));
+        }
+    }
+    note
+}
+
+// 보고서 저장 (설정에 따라 vault/20_notes/run_reports/에 노트도 작성)
+fn save_run_report(mut report: RunReport) -> Result<RunReport, String> {
+    let settings: RunReportSettings = load_json_config(RUN_REPORT_SETTINGS_FILE).unwrap_or_default();
+    if settings.write_markdown_note {
+        let project_root = get_project_root();
+        let note_dir = project_root.join("vault").join("20_notes").join("run_reports");
+        fs::create_dir_all(&note_dir).map_err(|e| format!("보고서 폴더 생성 실패: {}", e))?;
+        let path = note_dir.join(format!("{}.md", chrono::Local::now().format("%Y-%m-%d_%H%M%S")));
+        fs::write(&path, render_run_report_note(&report)).map_err(|e| format!("보고서 노트 저장 실패: {}", e))?;
+        report.note_path = Some(path.strip_prefix(&project_root).unwrap_or(&path).to_string_lossy().to_string());
+    }
+    save_json_config(LAST_RUN_REPORT_FILE, &report)?;
+    Ok(report)
+}
+
+#[command]
+fn get_last_run_report() -> Result<Option<RunReport>, String> {
+    load_json_config(LAST_RUN_REPORT_FILE)
+}
+
+#[command]
+fn get_run_report_settings() -> Result<RunReportSettings, String> {
+    load_json_config(RUN_REPORT_SETTINGS_FILE)
+}
+
+#[command]
+fn save_run_report_settings(settings: RunReportSettings) -> Result<(), String> {
+    save_json_config(RUN_REPORT_SETTINGS_FILE, &settings)
+}
+
+// 다운로드 실행 기록 (인덱스 DB의 download_runs / download_run_channels)
+#[derive(Serialize, Deserialize, Clone, Default)]
+struct DownloadHistoryChannel {
+    channel: String,
+    videos_added: u32,
+    failures: u32,
+    error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct DownloadHistoryEntry {
+    job_id: String,
+    kind: String,
+    started_at: String,
+    finished_at: String,
+    status: String,
+    videos_added: u32,
+    failures: u32,
+    message: Option<String>,
+    channels: Vec<DownloadHistoryChannel>,
+}
+
+const DEFAULT_DOWNLOAD_HISTORY_LIMIT: usize = 50;
+
+// 채널별 영상 폴더 목록 (실행 전후 비교용, 파일 크기는 계산하지 않음)
+fn vault_video_folders() -> std::collections::HashSet<PathBuf> {
+    let root = get_project_root().join("vault").join("10_videos");
+    let subdirs = |dir: &Path| -> Vec<PathBuf> {
+        fs::read_dir(dir)
+            .map(|entries| entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()).collect())
+            .unwrap_or_default()
+    };
+    subdirs(&root)
+        .iter()
+        .flat_map(|channel_dir| subdirs(channel_dir))
+        .flat_map(|year_dir| subdirs(&year_dir))
+        .collect()
+}
+
+fn download_history_channel<'a>(channels: &'a mut BTreeMap<String, DownloadHistoryChannel>, channel: &str) -> &'a mut DownloadHistoryChannel {
+    channels
+        .entry(channel.to_string())
+        .or_insert_with(|| DownloadHistoryChannel { channel: channel.to_string(), ..Default::default() })
+}
+
+// 작업 결과로 실행 기록 작성 (배치 작업은 실행 보고서의 채널별 실패를 함께 기록)
+fn build_download_history_entry(
+    ctx: &DownloadJobContext,
+    request: Option<&DownloadJobRequest>,
+    started_at: &chrono::DateTime<chrono::Local>,
+    before: &std::collections::HashSet<PathBuf>,
+    result: &Result<String, String>,
+) -> DownloadHistoryEntry {
+    let mut channels: BTreeMap<String, DownloadHistoryChannel> = BTreeMap::new();
+    for folder in vault_video_folders().difference(before) {
+        if let Some(channel) = folder.parent().and_then(|year| year.parent()).and_then(|c| c.file_name()) {
+            download_history_channel(&mut channels, &channel.to_string_lossy()).videos_added += 1;
+        }
+    }
+    
+    let report: Option<RunReport> = load_json_config::<Option<RunReport>>(LAST_RUN_REPORT_FILE).ok().flatten();
+    match (request, report) {
+        (Some(DownloadJobRequest::Single { channel_name, .. }), _) => {
+            if let Err(message) = result {
+                let entry = download_history_channel(&mut channels, channel_name.as_deref().unwrap_or("단일 영상"));
+                entry.failures += 1;
+                entry.error = Some(message.clone());
+            }
+        }
+        (_, Some(report)) if report.job_id == ctx.id => {
+            for channel in report.channels {
+                let entry = download_history_channel(&mut channels, &channel.channel);
+                entry.failures += channel.failed.len() as u32;
+                entry.error = channel.error;
+            }
+        }
+        _ => {}
+    }
+    
+    let channels: Vec<DownloadHistoryChannel> = channels.into_values().collect();
+    DownloadHistoryEntry {
+        job_id: ctx.id.clone(),
+        kind: request.map(DownloadJobRequest::kind).unwrap_or("unknown").to_string(),
+        started_at: started_at.to_rfc3339(),
+        finished_at: chrono::Local::now().to_rfc3339(),
+        status: match result {
+            _ if ctx.is_cancelled() => "cancelled",
+            Ok(_) => "completed",
+            Err(_) => "failed",
+        }
+        .to_string(),
+        videos_added: channels.iter().map(|c| c.videos_added).sum(),
+        failures: channels.iter().map(|c| c.failures).sum(),
+        message: match result {
+            Ok(message) | Err(message) => Some(message.clone()),
+        },
+        channels,
+    }
+}
+
+fn record_download_run(entry: &DownloadHistoryEntry) -> Result<(), String> {
+    let mut conn = open_index_db()?;
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+    tx.execute(
+        "INSERT OR REPLACE INTO download_runs (job_id, kind, started_at, finished_at, status, videos_added, failures, message)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        rusqlite::params![
+            entry.job_id,
+            entry.kind,
+            entry.started_at,
+            entry.finished_at,
+            entry.status,
+            entry.videos_added,
+            entry.failures,
+            entry.message,
+        ],
+    )
+    .map_err(|e| format!("다운로드 기록 저장 실패: {}", e))?;
+    for channel in &entry.channels {
+        tx.execute(
+            "INSERT OR REPLACE INTO download_run_channels (job_id, channel, videos_added, failures, error) VALUES (?1, ?2, ?3, ?4, ?5)",
+            rusqlite::params![entry.job_id, channel.channel, channel.videos_added, channel.failures, channel.error],
+        )
+        .map_err(|e| format!("다운로드 기록 저장 실패: {}", e))?;
+    }
+    tx.commit().map_err(|e| format!("다운로드 기록 커밋 실패: {}", e))
+}
+
+// 마지막으로 성공한 다운로드 실행의 완료 시각
+fn last_download_finished_at() -> Option<String> {
+    open_index_db()
+        .ok()?
+        .query_row(
+            "SELECT finished_at FROM download_runs WHERE status = 'completed' ORDER BY finished_at DESC LIMIT 1",
+            [],
+            |row| row.get(0),
+        )
+        .ok()
+}
+
+// 다운로드 실행 기록 (최신순, channel을 지정하면 해당 채널이 포함된 실행만)
+#[command]
+fn get_download_history(limit: Option<usize>, channel: Option<String>) -> Result<Vec<DownloadHistoryEntry>, String> {
+    let conn = open_index_db()?;
+    let limit = limit.unwrap_or(DEFAULT_DOWNLOAD_HISTORY_LIMIT).clamp(1, 1000) as i64;
+    let mut stmt = conn
+        .prepare(
+            "SELECT job_id, kind, started_at, finished_at, status, videos_added, failures, message FROM download_runs
+             WHERE ?1 IS NULL OR job_id IN (SELECT job_id FROM download_run_channels WHERE channel = ?1)
+             ORDER BY finished_at DESC LIMIT ?2",
+        )
+        .map_err(|e| format!("다운로드 기록 조회 실패: {}", e))?;
+    let mut entries = stmt
+        .query_map(rusqlite::params![channel, limit], |row| {
+            Ok(DownloadHistoryEntry {
+                job_id: row.get(0)?,
+                kind: row.get(1)?,
+                started_at: row.get(2)?,
+                finished_at: row.get(3)?,
+                status: row.get(4)?,
+                videos_added: row.get(5)?,
+                failures: row.get(6)?,
+                message: row.get(7)?,
+                channels: Vec::new(),
+            })
+        })
+        .map_err(|e| format!("다운로드 기록 조회 실패: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("다운로드 기록 조회 실패: {}", e))?;
+    
+    let mut channel_stmt = conn
+        .prepare("SELECT channel, videos_added, failures, error FROM download_run_channels WHERE job_id = ?1 ORDER BY channel")
+        .map_err(|e| format!("다운로드 기록 조회 실패: {}", e))?;
+    for entry in &mut entries {
+        entry.channels = channel_stmt
+            .query_map([&entry.job_id], |row| {
+                Ok(DownloadHistoryChannel {
+                    channel: row.get(0)?,
+                    videos_added: row.get(1)?,
+                    failures: row.get(2)?,
+                    error: row.get(3)?,
+                })
+            })
+            .map_err(|e| format!("다운로드 기록 조회 실패: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("다운로드 기록 조회 실패: {}", e))?;
+    }
+    Ok(entries)
+}
+
+// 활성화된 전체 채널 배치 다운로드 (full_scan이면 --full-scan 무결성 검사, 완료 후 변경 보고서 작성)
+fn execute_batch_download(
+    window: &Window,
+    job: &DownloadJobContext,
+    quality: Option<&str>,
+    full_scan: bool,
+    since: Option<&str>,
+) -> Result<String, String> {
+    let channels = read_channels()?;
+    if !channels.iter().any(|c| c.enabled) {
+        return Err("활성화된 채널이 없습니다".to_string());
+    }
+    // 병렬/단일 프로세스 모두 같은 목록 사용 (추모/일시정지/보관 채널 제외)
+    let download_channels: Vec<ChannelInfo> = channels.into_iter().filter(|c| c.downloadable()).collect();
+    if download_channels.is_empty() {
+        return Err("다운로드할 수 있는 채널이 없습니다 (추모/일시정지/보관 채널 제외)".to_string());
+    }
+    
+    // Python 가상환경 확인
+    let venv_python = get_project_root().join("venv").join("bin").join("python3");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    
+    let quality_note = quality.map(|q| format!(" (품질: {})", q)).unwrap_or_default();
+    let (label, start_message) = if full_scan {
+        ("전체 무결성 검사", "🔍 전체 무결성 검사를 시작합니다. 모든 영상을 확인하여 누락된 영상을 복구합니다...".to_string())
+    } else {
+        ("배치 다운로드", format!("🚀 모든 활성화된 채널의 배치 다운로드를 시작합니다...{}", quality_note))
+    };
+    
+    // 시작 메시지
+    let start_progress = DownloadProgress {
+        channel: "전체".to_string(),
+        status: "시작".to_string(),
+        progress: 0.0,
+        current_video: format!("{} 시작{}", label, quality_note),
+        total_videos: 0,
+        completed_videos: 0,
+        log_message: start_message.clone(),
+        throughput_bps: None,
+    };
+    let _ = window.emit("download-progress", &start_progress);
+    job.log(&start_message);
+    if let Some(since) = since {
+        job.log(&format!("📅 {} 이후 업로드된 영상만 다운로드합니다", since));
+    }
+    
+    // 채널을 N개씩 병렬로 처리 (설정이 1이거나 채널이 하나면 단일 프로세스 배치)
+    let parallel = load_json_config::<DownloadConcurrencySettings>(DOWNLOAD_CONCURRENCY_FILE)
+        .unwrap_or_default()
+        .parallel_channels
+        .clamp(1, MAX_PARALLEL_CHANNELS) as usize;
+    
+    let before = snapshot_vault_videos();
+    let started_at = chrono::Local::now();
+    let outcome = if parallel > 1 && download_channels.len() > 1 {
+        job.log(&format!("⚡ {}개 채널을 최대 {}개씩 병렬로 다운로드합니다", download_channels.len(), parallel));
+        run_parallel_channel_downloads(window, job, &download_channels, quality, full_scan, since, parallel)
+    } else {
+        // 🔥 IMPROVED: batch 명령어 사용으로 모든 채널을 안정적으로 배치 처리
+        let downloads_dir = get_project_root().join("vault").join("downloads");
+        let channels_file = downloads_dir.join(format!(".batch-{}.txt", job.id));
+        let urls: String = download_channels.iter().map(|c| format!("{}\n", c.url)).collect();
+        fs::create_dir_all(&downloads_dir)
+            .and_then(|_| fs::write(&channels_file, urls))
+            .map_err(|e| format!("채널 목록 파일 저장 실패: {}", e))?;
+        let channel_label = if full_scan { "전체 무결성 검사" } else { "전체 채널" };
+        let operation = if full_scan { ProcessOperation::FullScan } else { ProcessOperation::Download };
+        let outcome = batch_download_command(&download_channels, quality, full_scan, since, Some(&channels_file))
+            .spawn()
+            .map_err(|e| e.to_string())
+            .and_then(|child| run_process_with_realtime_output(child, window, channel_label, job, operation))
+            .map(|(total, downloaded, status)| (total, downloaded, status.success()));
+        let _ = fs::remove_file(&channels_file);
+        outcome
+    };
+    
+    // 🔥 NEW: 실시간 출력 캡처로 프로세스 실행 결과 정리
+    let result = match outcome {
+        Ok((total, downloaded, succeeded)) => {
+            if succeeded {
+                let log_message = if full_scan {
+                    format!("🎉 전체 무결성 검사 완료! 누락된 {}개 영상을 복구했습니다.", downloaded)
+                } else {
+                    format!("🎉 배치 다운로드 완료! (총 {}/{}개{})", downloaded, total, quality.map(|q| format!(", 품질: {}", q)).unwrap_or_default())
+                };
+                let success_progress = DownloadProgress {
+                    channel: "전체".to_string(),
+                    status: "완료".to_string(),
+                    progress: 100.0,
+                    current_video: "모든 채널".to_string(),
+                    total_videos: total,
+                    completed_videos: downloaded,
+                    log_message,
+                    throughput_bps: None,
+                };
+                let _ = window.emit("download-progress", &success_progress);
+                render_missing_video_notes();
+                spawn_download_verification(window);
+                spawn_media_probe_harvest(window, &window.state::<MediaProbeState>());
+                if full_scan {
+                    Ok(format!("✅ 전체 무결성 검사 성공: {}개 누락 영상 복구 완료", downloaded))
+                } else {
+                    Ok(format!("✅ 배치 다운로드 성공: {}/{}개 영상 다운로드 완료{}", downloaded, total, quality_note))
+                }
+            } else {
+                let error_progress = DownloadProgress {
+                    channel: "전체".to_string(),
+                    status: "실패".to_string(),
+                    progress: 100.0,
+                    current_video: "모든 채널".to_string(),
+                    total_videos: total,
+                    completed_videos: downloaded,
+                    log_message: format!("❌ {} 중 오류 발생", label),
+                    throughput_bps: None,
+                };
+                let _ = window.emit("download-progress", &error_progress);
+                Err(format!("{} 중 오류가 발생했습니다", label))
+            }
+        }
+        Err(err) => {
+            if err.contains("중단") {
+                Ok(format!("{}가 중단되었습니다", label))
+            } else {
+                Err(format!("{} 실패: {}", label, err))
+            }
+        }
+    };
+    
+    if !job.is_cancelled() {
+        match save_run_report(build_run_report(&job.id, full_scan, &before, started_at, &result)) {
+            Ok(report) => {
+                let _ = window.emit("run-report-ready", &report);
+            }
+            Err(e) => eprintln!("실행 보고서 저장 실패: {}", e),
+        }
+    }
+    result
+}
+
+// 비디오 다운로드 (실시간 진행 상황 포함, 작업 큐를 거쳐 완료될 때까지 대기)
+#[command]
+async fn download_videos_with_progress(window: Window, state: State<'_, DownloadState>, derivative_state: State<'_, DerivativeState>, vault_lock: State<'_, VaultLockState>) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let ctx = enqueue_download_job(&state, DownloadJobRequest::Batch { quality: None })?;
+    run_download_job_blocking(window, derivative_state.inner().clone(), ctx).await
+}
+
+// 기존 다운로드 함수 (호환성 유지, 다른 다운로드와 같은 작업 큐 사용)
+#[command]
+async fn download_videos(window: Window, state: State<'_, DownloadState>, derivative_state: State<'_, DerivativeState>, vault_lock: State<'_, VaultLockState>) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let ctx = enqueue_download_job(&state, DownloadJobRequest::Batch { quality: None })?;
+    run_download_job_blocking(window, derivative_state.inner().clone(), ctx).await
+}
+
+// 품질 매개변수를 받는 다운로드 함수 (batch 처리)
+#[command]
+async fn download_videos_with_progress_and_quality(window: Window, state: State<'_, DownloadState>, derivative_state: State<'_, DerivativeState>, vault_lock: State<'_, VaultLockState>, quality: String) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let ctx = enqueue_download_job(&state, DownloadJobRequest::Batch { quality: Some(quality) })?;
+    run_download_job_blocking(window, derivative_state.inner().clone(), ctx).await
+}
+
+// 지정 날짜 이후 업로드된 영상만 다운로드 (채널 전체 기록 대신 최근 구간만 보관)
+#[command]
+async fn download_videos_since(window: Window, state: State<'_, DownloadState>, derivative_state: State<'_, DerivativeState>, vault_lock: State<'_, VaultLockState>, date: String) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let date = normalize_upload_date(&date)?;
+    let ctx = enqueue_download_job(&state, DownloadJobRequest::Since { date })?;
+    run_download_job_blocking(window, derivative_state.inner().clone(), ctx).await
+}
+
+// 🔍 전체 무결성 검사 다운로드 (--full-scan)
+#[command]
+async fn download_videos_full_scan_with_progress(window: Window, state: State<'_, DownloadState>, derivative_state: State<'_, DerivativeState>, vault_lock: State<'_, VaultLockState>) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let ctx = enqueue_download_job(&state, DownloadJobRequest::FullScan)?;
+    run_download_job_blocking(window, derivative_state.inner().clone(), ctx).await
+}
+
+// yt-dlp 포맷 정보
+#[derive(Serialize, Deserialize, Clone)]
+struct VideoFormat {
+    format_id: String,
+    ext: String,
+    resolution: Option<String>,
+    width: Option<u32>,
+    height: Option<u32>,
+    fps: Option<f64>,
+    vcodec: Option<String>,  // None이면 오디오 전용
+    acodec: Option<String>,  // None이면 비디오 전용
+    filesize: Option<u64>,   // 정확한 크기가 없으면 추정치
+    tbr: Option<f64>,
+    format_note: Option<String>,
+}
+
+// yt-dlp JSON의 포맷 항목을 구조체로 변환
+fn parse_video_format(value: &serde_json::Value) -> Option<VideoFormat> {
+    let text = |key: &str| value.get(key).and_then(|v| v.as_str()).map(|s| s.to_string());
+    // "none"은 해당 스트림이 없다는 의미
+    let codec = |key: &str| text(key).filter(|c| c != "none");
+    
+    Some(VideoFormat {
+        format_id: text("format_id")?,
+        ext: text("ext").unwrap_or_default(),
+        resolution: text("resolution"),
+        width: value.get("width").and_then(|v| v.as_u64()).map(|v| v as u32),
+        height: value.get("height").and_then(|v| v.as_u64()).map(|v| v as u32),
+        fps: value.get("fps").and_then(|v| v.as_f64()),
+        vcodec: codec("vcodec"),
+        acodec: codec("acodec"),
+        filesize: value.get("filesize").and_then(|v| v.as_u64())
+            .or_else(|| value.get("filesize_approx").and_then(|v| v.as_u64())),
+        tbr: value.get("tbr").and_then(|v| v.as_f64()),
+        format_note: text("format_note"),
+    })
+}
+
+// 영상의 사용 가능한 포맷/해상도/코덱 목록 조회
+#[command]
+async fn list_formats(url: String) -> Result<Vec<VideoFormat>, String> {
+    let project_root = get_project_root();
+    let venv_python = project_root.join("venv").join("bin").join("python3");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    
+    let output = Command::new(&venv_python)
+        .args(["-m", "yt_dlp", "-j", "--no-warnings", "--no-playlist", "--skip-download"])
+        .args(ytdlp_auth_args(&load_auth_settings()))
+        .args(ytdlp_proxy_args())
+        .arg(&url)
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| format!("yt-dlp 실행 실패: {}", e))?;
+    
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("포맷 목록 조회 실패: {}", stderr.trim()));
+    }
+    
+    let info: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("yt-dlp 출력 파싱 실패: {}", e))?;
+    let formats = info
+        .get("formats")
+        .and_then(|f| f.as_array())
+        .map(|formats| formats.iter().filter_map(parse_video_format).collect())
+        .unwrap_or_default();
+    
+    Ok(formats)
+}
+
+// 단일 영상 다운로드 (포맷 선택자를 지정하면 기본 품질 선택 대신 사용)
+#[command]
+async fn download_single_video(
+    window: Window,
+    state: State<'_, DownloadState>,
+    derivative_state: State<'_, DerivativeState>,
+    vault_lock: State<'_, VaultLockState>,
+    url: String,
+    format: Option<String>,
+    channel_name: Option<String>,
+) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let ctx = enqueue_download_job(&state, DownloadJobRequest::Single { url, format, channel_name })?;
+    run_download_job_blocking(window, derivative_state.inner().clone(), ctx).await
+}
+
+// ydh single 프로세스 명령 구성 (채널 이름이 있으면 채널별 프록시/자막 언어 적용)
+fn single_download_command(venv_python: &Path, url: &str, format: Option<&str>, channel_name: Option<&str>) -> Command {
+    let mut cmd = Command::new(venv_python);
+    cmd.args(["-u", "-m", "ydh", "single"])
+        .arg(url)
+        .current_dir(get_project_root())
+        .env("PYTHONUNBUFFERED", "1")
+        .env("PYTHONIOENCODING", "utf-8")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    apply_auth_env(&mut cmd);
+    apply_mirror_env(&mut cmd);
+    apply_download_limits_env(&mut cmd, 1);
+    apply_downloader_env(&mut cmd, false);
+    if let Some(proxy) = effective_proxy(channel_name) {
+        cmd.env("YDH_PROXY_URL", proxy);
+    }
+    if let Some(f) = format {
+        cmd.args(["--format", f]);
+    }
+    if let Some(name) = channel_name {
+        cmd.args(["--channel-name", name]);
+        if let Some(language) = get_channel_language(name) {
+            cmd.env("YDH_SUBTITLE_LANGUAGES", serde_json::to_string(&subtitle_language_variants(&language)).unwrap_or_default());
+        }
+    }
+    cmd
+}
+
+fn execute_single_download(
+    window: &Window,
+    job: &DownloadJobContext,
+    url: &str,
+    format: Option<String>,
+    channel_name: Option<String>,
+) -> Result<String, String> {
+    let project_root = get_project_root();
+    let venv_python = project_root.join("venv").join("bin").join("python3");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    
+    let format = format.map(|f| f.trim().to_string()).filter(|f| !f.is_empty());
+    let start_progress = DownloadProgress {
+        channel: channel_name.clone().unwrap_or_else(|| "단일 영상".to_string()),
+        status: "시작".to_string(),
+        progress: 0.0,
+        current_video: url.to_string(),
+        total_videos: 1,
+        completed_videos: 0,
+        log_message: match &format {
+            Some(f) => format!("🎬 단일 영상 다운로드 시작 (포맷: {})", f),
+            None => "🎬 단일 영상 다운로드 시작".to_string(),
+        },
+        throughput_bps: None,
+    };
+    job.log(&start_progress.log_message);
+    let _ = window.emit("download-progress", &start_progress);
+    
+    let child = single_download_command(&venv_python, url, format.as_deref(), channel_name.as_deref())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+    let channel_label = channel_name.unwrap_or_else(|| "단일 영상".to_string());
+    
+    match run_process_with_realtime_output(child, window, &channel_label, job, ProcessOperation::Single) {
+        Ok((_, _, status)) if status.success() => {
+            let success_progress = DownloadProgress {
+                channel: channel_label,
+                status: "완료".to_string(),
+                progress: 100.0,
+                current_video: url.to_string(),
+                total_videos: 1,
+                completed_videos: 1,
+                log_message: "🎉 단일 영상 다운로드 완료!".to_string(),
+                throughput_bps: None,
+            };
+            let _ = window.emit("download-progress", &success_progress);
+            render_missing_video_notes();
+            spawn_download_verification(window);
+            spawn_media_probe_harvest(window, &window.state::<MediaProbeState>());
+            Ok(format!("✅ 단일 영상 다운로드 완료: {}", url))
+        }
+        Ok(_) => Err("단일 영상 다운로드 중 오류가 발생했습니다".to_string()),
+        Err(err) => {
+            if err.contains("중단") {
+                Ok("단일 영상 다운로드가 중단되었습니다".to_string())
+            } else {
+                Err(format!("단일 영상 다운로드 실패: {}", err))
+            }
+        }
+    }
+}
+
+// 사용 가능한 채널 목록 조회 (보관 채널은 include_archived일 때만)
+#[command]
+fn get_available_channels_for_embedding(include_archived: Option<bool>) -> Result<Vec<String>, String> {
+    let project_root = get_project_root();
+    let videos_path = project_root.join("vault").join("10_videos");
+    
+    if !videos_path.exists() {
+        return Ok(Vec::new());
+    }
+    
+    let mut channels = Vec::new();
+    
+    match fs::read_dir(&videos_path) {
+        Ok(entries) => {
+            for entry in entries {
+                if let Ok(entry) = entry {
+                    let path = entry.path();
+                    if path.is_dir() {
+                        if let Some(channel_name) = path.file_name() {
+                            if let Some(name_str) = channel_name.to_str() {
+                                channels.push(name_str.to_string());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Err(e) => return Err(format!("채널 디렉토리 읽기 실패: {}", e)),
+    }
+    
+    if !include_archived.unwrap_or(false) {
+        let archived = archived_channel_names();
+        channels.retain(|c| !archived.contains(c));
+    }
+    channels.sort();
+    Ok(channels)
+}
+
+// 채널별 임베딩 생성 (진행 상황 포함)
+#[command]
+async fn create_embeddings_for_channels_with_progress(
+    window: Window,
+    channels: Vec<String>,
+    state: State<'_, EmbeddingState>,
+    vault_lock: State<'_, VaultLockState>
+) -> Result<String, String> {
+    let result = run_channel_embeddings(window.clone(), channels, state, vault_lock).await;
+    notify_job_finished(&window, NotifiedJob::Embedding, &result);
+    result
+}
+
+async fn run_channel_embeddings(
+    window: Window, 
+    channels: Vec<String>,
+    state: State<'_, EmbeddingState>,
+    vault_lock: State<'_, VaultLockState>
+) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let project_root = get_project_root();
+    let embed_script = project_root.join("vault").join("90_indices").join("embed.py");
+    if !embed_script.exists() {
+        return Err(format!("embed.py 스크립트를 찾을 수 없습니다: {}", embed_script.display()));
+    }
+    
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    
+    // 중단 상태 초기화
+    state.is_cancelled.store(false, Ordering::Relaxed);
+    
+    if channels.is_empty() {
+        return Err("선택된 채널이 없습니다.".to_string());
+    }
+    
+    // 인덱스 스냅샷/복원 및 다른 임베딩 작업과 겹치지 않도록 실행 중 표시
+    let _running = RunningGuard::try_new(&state.is_running).ok_or("임베딩 작업이 이미 실행 중입니다")?;
+    
+    let total_channels = channels.len() as u32;
+    let mut all_output = Vec::new();
+    
+    // 시작 진행 상황
+    let start_progress = DownloadProgress {
+        channel: format!("벡터 임베딩 ({} 채널)", total_channels),
+        status: "시작".to_string(),
+        progress: 0.0,
+        current_video: format!("선택된 {} 채널의 임베딩 생성 준비 중...", total_channels),
+        total_videos: total_channels,
+        completed_videos: 0,
+        log_message: format!("🧠 {} 채널의 벡터 임베딩 생성을 시작합니다...", total_channels),
+        throughput_bps: None,
+    };
+    let _ = window.emit("embedding-progress", &start_progress);
+    
+    // 모든 선택된 채널을 한 번에 처리
+    let processing_progress = DownloadProgress {
+        channel: format!("벡터 임베딩 ({} 채널)", total_channels),
+        status: "처리 중".to_string(),
+        progress: 50.0,
+        current_video: format!("📺 선택된 {} 채널 처리 중...", total_channels),
+        total_videos: total_channels,
+        completed_videos: 0,
+        log_message: format!("📊 {} 채널의 벡터 임베딩 생성 중...", channels.join(", ")),
+        throughput_bps: None,
+    };
+    let _ = window.emit("embedding-progress", &processing_progress);
+    
+    // Python 스크립트 실행 (선택된 모든 채널을 한 번에 처리)
+    let cmd = Command::new(&venv_python)
+        .arg(&embed_script)
+        .arg("channels")  // 특정 채널 모드
+        .args(&channels)  // 선택된 채널들
+        .current_dir(&project_root)
+        .env("PYTHONUNBUFFERED", "1")
+        .env("YDH_EMBEDDING_MODELS", embedding_models_env()) // 채널 언어별 임베딩 모델
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("스크립트 실행 실패: {}", e))?;
+    
+    // 실시간 출력 처리를 위한 BufReader 설정
+    use std::io::{BufRead, BufReader};
+    use std::sync::mpsc;
+    use std::thread;
+    
+    let mut child = cmd;
+    
+    let stdout = child.stdout.take().unwrap();
+    let stderr = child.stderr.take().unwrap();
+    
+    // stdout 실시간 처리 스레드
+    let (tx, rx) = mpsc::channel();
+    let tx_clone = tx.clone();
+    
+    thread::spawn(move || {
+        let reader = BufReader::new(stdout);
+        for line in reader.lines() {
+            if let Ok(line) = line {
+                let _ = tx.send(("stdout".to_string(), line));
+            }
+        }
+    });
+    
+    // stderr 실시간 처리 스레드
+    thread::spawn(move || {
+        let reader = BufReader::new(stderr);
+        for line in reader.lines() {
+            if let Ok(line) = line {
+                let _ = tx_clone.send(("stderr".to_string(), line));
+            }
+        }
+    });
+    
+    // 실시간 로그 처리 루프
+    let mut process_complete = false;
+    while !process_complete {
+        // 중단 확인
+        if state.is_cancelled.load(Ordering::Relaxed) {
+            let _ = child.kill();
+            let _ = child.wait();
+            
+            let cancel_progress = DownloadProgress {
+                channel: format!("벡터 임베딩 ({} 채널)", total_channels),
+                status: "중단됨".to_string(),
+                progress: 50.0,
+                current_video: "사용자가 중단했습니다".to_string(),
+                total_videos: total_channels,
+                completed_videos: 0,
+                log_message: "🛑 사용자가 임베딩 생성을 중단했습니다".to_string(),
+                throughput_bps: None,
+            };
+            let _ = window.emit("embedding-progress", &cancel_progress);
+            return Ok(format!("임베딩 생성이 중단되었습니다."));
+        }
+        
+        // 출력 받기 (타임아웃 설정)
+        match rx.recv_timeout(std::time::Duration::from_millis(100)) {
+            Ok((stream_type, line)) => {
+                if !line.trim().is_empty() {
+                    let log_progress = DownloadProgress {
+                        channel: format!("벡터 임베딩 ({} 채널)", total_channels),
+                        status: "처리 중".to_string(),
+                        progress: 70.0,
+                        current_video: "📺 임베딩 생성 중".to_string(),
+                        total_videos: total_channels,
+                        completed_videos: 0,
+                        log_message: if stream_type == "stderr" { 
+                            format!("⚠️ {}", line) 
+                        } else { 
+                            line.clone() 
+                        },
+                        throughput_bps: None,
+                    };
+                    let _ = window.emit("embedding-progress", &log_progress);
+                    all_output.push(line);
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                // 프로세스가 완료되었는지 확인
+                match child.try_wait() {
+                    Ok(Some(status)) => {
+                        process_complete = true;
+                        if !status.success() {
+                            let error_progress = DownloadProgress {
+                                channel: format!("벡터 임베딩 ({} 채널)", total_channels),
+                                status: "실패".to_string(),
+                                progress: 0.0,
+                                current_video: "❌ 임베딩 생성 실패".to_string(),
+                                total_videos: total_channels,
+                                completed_videos: 0,
+                                log_message: "❌ Python 스크립트 실행 실패".to_string(),
+                                throughput_bps: None,
+                            };
+                            let _ = window.emit("embedding-progress", &error_progress);
+                            return Err("임베딩 생성 실패".to_string());
+                        }
+                    }
+                    Ok(None) => {
+                        // 아직 실행 중
+                        continue;
+                    }
+                    Err(e) => {
+                        return Err(format!("프로세스 상태 확인 실패: {}", e));
+                    }
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                // 스레드가 종료됨, 프로세스 완료 확인
+                let _ = child.wait();
+                process_complete = true;
+            }
+        }
+    }
+    
+    // 현재 프로세스 정리
+    {
+        let mut process_guard = state.current_process.lock().unwrap();
+        *process_guard = None;
+    }
+    
+    if state.is_cancelled.load(Ordering::Relaxed) {
+        return Ok(format!("임베딩 생성이 중단되었습니다. {}개 채널 완료", total_channels));
+    }
+    
+    // 최종 완료
+    let final_progress = DownloadProgress {
+        channel: format!("벡터 임베딩 ({} 채널)", total_channels),
+        status: "완료".to_string(),
+        progress: 100.0,
+        current_video: "모든 채널 임베딩 완료".to_string(),
+        total_videos: total_channels,
+        completed_videos: total_channels,
+        log_message: format!("🎉 {}개 채널의 벡터 임베딩 생성이 완료되었습니다!", total_channels),
+        throughput_bps: None,
+    };
+    let _ = window.emit("embedding-progress", &final_progress);
+    spawn_watched_question_recheck(window.app_handle().clone(), Some(channels));
+    
+    Ok(format!("✅ {}개 채널의 벡터 임베딩 생성 완료\n{}", total_channels, all_output.join("\n")))
+}
+
+// 로컬 임베딩 백엔드 설정 (Vault별: vault/90_indices/embedding_backend.json)
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+struct EmbeddingBackendSettings {
+    backend: String,            // default | ort
+    provider: String,           // auto | coreml | cuda | directml | cpu
+    model_path: Option<String>, // 지정 시 model.onnx + tokenizer.json (모델 변경 시 재임베딩 필요)
+    batch_size: u32,            // 0이면 VRAM 기준 자동
+}
+
+impl Default for EmbeddingBackendSettings {
+    fn default() -> Self {
+        EmbeddingBackendSettings {
+            backend: "default".to_string(),
+            provider: "auto".to_string(),
+            model_path: None,
+            batch_size: 0,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct EmbeddingBenchmarkResult {
+    backend: String,
+    provider: String,
+    batch_size: Option<u32>,
+    texts: u32,
+    seconds: Option<f64>,
+    texts_per_second: Option<f64>,
+    error: Option<String>,
+}
+
+fn embedding_backend_settings_path() -> PathBuf {
+    get_project_root().join("vault").join("90_indices").join("embedding_backend.json")
+}
+
+#[command]
+fn get_embedding_backend_settings() -> Result<EmbeddingBackendSettings, String> {
+    match fs::read_to_string(embedding_backend_settings_path()) {
+        Ok(content) => serde_json::from_str(&content).map_err(|e| format!("임베딩 백엔드 설정 파싱 실패: {}", e)),
+        Err(_) => Ok(EmbeddingBackendSettings::default()),
+    }
+}
+
+#[command]
+fn save_embedding_backend_settings(settings: EmbeddingBackendSettings) -> Result<(), String> {
+    if !matches!(settings.backend.as_str(), "default" | "ort") {
+        return Err(format!("지원하지 않는 임베딩 백엔드입니다: {}", settings.backend));
+    }
+    if !matches!(settings.provider.as_str(), "auto" | "coreml" | "cuda" | "directml" | "cpu") {
+        return Err(format!("지원하지 않는 실행 공급자입니다: {}", settings.provider));
+    }
+    if let Some(model_path) = settings.model_path.as_deref().filter(|p| !p.trim().is_empty()) {
+        let model_dir = PathBuf::from(model_path);
+        if !model_dir.join("model.onnx").exists() || !model_dir.join("tokenizer.json").exists() {
+            return Err(format!("model.onnx와 tokenizer.json이 있는 폴더가 필요합니다: {}", model_path));
+        }
+    }
+    let json = serde_json::to_string_pretty(&settings).map_err(|e| e.to_string())?;
+    fs::write(embedding_backend_settings_path(), json).map_err(|e| format!("임베딩 백엔드 설정 저장 실패: {}", e))
+}
+
+// 임베딩 백엔드/실행 공급자별 처리량 비교 (자막 샘플 기준)
+#[command]
+async fn benchmark_embedding_backends(samples: Option<u32>) -> Result<Vec<EmbeddingBenchmarkResult>, String> {
+    let project_root = get_project_root();
+    let script = project_root.join("vault").join("90_indices").join("embedding_backend.py");
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    
+    let output = tokio::task::spawn_blocking(move || {
+        Command::new(&venv_python)
+            .arg(&script)
+            .arg("benchmark")
+            .arg(samples.unwrap_or(256).clamp(16, 4096).to_string())
+            .current_dir(script.parent().unwrap_or(&project_root))
+            .env("PYTHONIOENCODING", "utf-8")
+            .output()
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| format!("벤치마크 실행 실패: {}", e))?;
+    
+    if !output.status.success() {
+        return Err(format!("벤치마크 실패: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let last_line = stdout.lines().rev().find(|l| l.trim_start().starts_with('[')).unwrap_or("[]");
+    serde_json::from_str(last_line).map_err(|e| format!("벤치마크 결과 파싱 실패: {}", e))
+}
+
+// 벡터 인덱스 출처 (채널 컬렉션별 임베딩 모델/청크 설정)
+#[derive(Serialize, Deserialize, Clone)]
+struct EmbeddingProvenance {
+    embedding_model: String,
+    chunking: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct CollectionProvenance {
+    channel: String,
+    collection: String,
+    embedding_model: String,
+    chunking: String,
+    recorded: bool,              // false면 기록 이전 컬렉션 (기본 모델로 간주)
+    recorded_at: Option<String>,
+    documents: u32,
+    #[serde(default)]
+    expected_model: Option<String>, // 채널 감지 언어와 Vault 백엔드로 정해지는 모델
+    outdated: bool,              // 채널에 맞는 모델/청크 설정과 달라 재임베딩 필요
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct IndexProvenance {
+    current: EmbeddingProvenance,
+    collections: Vec<CollectionProvenance>,
+}
+
+fn read_index_provenance() -> Result<IndexProvenance, String> {
+    let project_root = get_project_root();
+    let embed_script = project_root.join("vault").join("90_indices").join("embed.py");
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    if !venv_python.exists() || !embed_script.exists() {
+        return Err("Python 가상환경 또는 embed.py를 찾을 수 없습니다".to_string());
+    }
+    let output = Command::new(&venv_python)
+        .arg(&embed_script)
+        .arg("provenance")
+        .current_dir(embed_script.parent().unwrap_or(&project_root))
+        .env("PYTHONIOENCODING", "utf-8")
+        .env("YDH_EMBEDDING_MODELS", embedding_models_env())
+        .output()
+        .map_err(|e| format!("embed.py 실행 실패: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("인덱스 출처 조회 실패: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let last_line = stdout.lines().rev().find(|l| l.trim_start().starts_with('{')).unwrap_or("{}");
+    serde_json::from_str(last_line).map_err(|e| format!("인덱스 출처 파싱 실패: {}", e))
+}
+
+// 채널별 벡터를 만든 임베딩 모델/청크 설정과 현재 설정 비교
+#[command]
+async fn get_index_provenance() -> Result<IndexProvenance, String> {
+    tokio::task::spawn_blocking(read_index_provenance)
+        .await
+        .map_err(|e| format!("인덱스 출처 조회 작업 실패: {}", e))?
+}
+
+// 임베딩 모델/청크 설정이 바뀐 채널의 컬렉션을 지우고 현재 설정으로 다시 임베딩
+#[command]
+async fn reembed_outdated(
+    window: Window,
+    state: State<'_, EmbeddingState>,
+    vault_lock: State<'_, VaultLockState>,
+    channels: Option<Vec<String>>,
+) -> Result<String, String> {
+    let result = run_outdated_reembedding(window.clone(), state, vault_lock, channels).await;
+    notify_job_finished(&window, NotifiedJob::Embedding, &result);
+    result
+}
+
+async fn run_outdated_reembedding(
+    window: Window,
+    state: State<'_, EmbeddingState>,
+    vault_lock: State<'_, VaultLockState>,
+    channels: Option<Vec<String>>,
+) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let _running = RunningGuard::try_new(&state.is_running).ok_or("임베딩 작업이 이미 실행 중입니다")?;
+    let provenance = tokio::task::spawn_blocking(read_index_provenance)
+        .await
+        .map_err(|e| format!("인덱스 출처 조회 작업 실패: {}", e))??;
+    // 채널을 지정하지 않으면 보관 채널은 건너뜀
+    let archived = archived_channel_names();
+    let outdated: Vec<String> = provenance
+        .collections
+        .iter()
+        .filter(|c| c.outdated)
+        .filter(|c| channels.as_ref().map(|names| names.contains(&c.channel)).unwrap_or(!archived.contains(&c.channel)))
+        .map(|c| c.channel.clone())
+        .collect();
+    if outdated.is_empty() {
+        return Ok("재임베딩이 필요한 채널이 없습니다".to_string());
+    }
+    
+    let total = outdated.len() as u32;
+    let progress = |status: &str, percent: f32, log_message: String| DownloadProgress {
+        channel: format!("재임베딩 ({} 채널)", total),
+        status: status.to_string(),
+        progress: percent,
+        current_video: format!("{} ({})", provenance.current.embedding_model, provenance.current.chunking),
+        total_videos: total,
+        completed_videos: if percent >= 100.0 { total } else { 0 },
+        log_message,
+        throughput_bps: None,
+    };
+    let _ = window.emit("embedding-progress", &progress("시작", 0.0, format!(
+        "🔁 임베딩 모델 변경으로 {}개 채널을 다시 임베딩합니다: {}", total, outdated.join(", ")
+    )));
+    
+    let project_root = get_project_root();
+    let embed_script = project_root.join("vault").join("90_indices").join("embed.py");
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    let window_clone = window.clone();
+    let emit_line = move |line: String| {
+        let _ = window_clone.emit("embedding-progress", &DownloadProgress {
+            channel: format!("재임베딩 ({} 채널)", total),
+            status: "처리 중".to_string(),
+            progress: 50.0,
+            current_video: String::new(),
+            total_videos: total,
+            completed_videos: 0,
+            log_message: line,
+            throughput_bps: None,
+        });
+    };
+    let rebuild_channels = outdated.clone();
+    let status = tokio::task::spawn_blocking(move || -> Result<std::process::ExitStatus, String> {
+        use std::io::{BufRead, BufReader};
+        let mut child = Command::new(&venv_python)
+            .arg(&embed_script)
+            .arg("rebuild")
+            .args(&rebuild_channels)
+            .current_dir(&project_root)
+            .env("PYTHONUNBUFFERED", "1")
+            .env("YDH_EMBEDDING_MODELS", embedding_models_env())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| format!("스크립트 실행 실패: {}", e))?;
+        if let Some(stdout) = child.stdout.take() {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if !line.trim().is_empty() {
+                    emit_line(line);
+                }
+            }
+        }
+        child.wait().map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| format!("재임베딩 작업 실패: {}", e))??;
+    
+    if !status.success() {
+        let _ = window.emit("embedding-progress", &progress("실패", 0.0, "❌ 재임베딩 실패".to_string()));
+        return Err("재임베딩 중 오류가 발생했습니다".to_string());
+    }
+    let _ = window.emit("embedding-progress", &progress("완료", 100.0, format!("✅ {}개 채널 재임베딩 완료", total)));
+    spawn_watched_question_recheck(window.app_handle().clone(), None);
+    Ok(format!("✅ {}개 채널 재임베딩 완료: {}", total, outdated.join(", ")))
+}
+
+// 임베딩 생성 중단
+#[command]
+async fn cancel_embedding(state: State<'_, EmbeddingState>) -> Result<(), String> {
+    state.is_cancelled.store(true, Ordering::Relaxed);
+    
+    // 실행 중인 프로세스는 메인 루프에서 처리됨
+    // 여기서는 중단 플래그만 설정
+    
+    Ok(())
+}
+
+// 벡터 임베딩 생성 (진행 상황 포함) - 기존 호환성 유지
+#[command]
+async fn create_embeddings_with_progress(window: Window, state: State<'_, EmbeddingState>, vault_lock: State<'_, VaultLockState>) -> Result<String, String> {
+    let result = run_all_embeddings(window.clone(), state, vault_lock).await;
+    notify_job_finished(&window, NotifiedJob::Embedding, &result);
+    result
+}
+
+async fn run_all_embeddings(window: Window, state: State<'_, EmbeddingState>, vault_lock: State<'_, VaultLockState>) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let _running = RunningGuard::try_new(&state.is_running).ok_or("임베딩 작업이 이미 실행 중입니다")?;
+    let project_root = get_project_root();
+    let embed_script = project_root.join("vault").join("90_indices").join("embed.py");
+    if !embed_script.exists() {
+        return Err(format!("embed.py 스크립트를 찾을 수 없습니다: {}", embed_script.display()));
+    }
+    
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    
+    // 시작 진행 상황
+    let start_progress = DownloadProgress {
+        channel: "벡터 임베딩".to_string(),
+        status: "시작".to_string(),
+        progress: 0.0,
+        current_video: "임베딩 생성 준비 중...".to_string(),
+        total_videos: 1,
+        completed_videos: 0,
+        log_message: "🧠 벡터 임베딩 생성을 시작합니다...".to_string(),
+        throughput_bps: None,
+    };
+    let _ = window.emit("embedding-progress", &start_progress);
+    
+    // Python 스크립트 실행
+    let output = Command::new(&venv_python)
+        .arg(&embed_script)
+        .current_dir(&project_root)
+        .env("PYTHONUNBUFFERED", "1")
+        .env("YDH_EXCLUDED_EMBEDDING_CHANNELS", excluded_embedding_channels_env()) // 보관 채널 제외
+        .env("YDH_EMBEDDING_MODELS", embedding_models_env()) // 채널 언어별 임베딩 모델
+        .output()
+        .map_err(|e| e.to_string())?;
+    
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let final_progress = DownloadProgress {
+            channel: "벡터 임베딩".to_string(),
+            status: "완료".to_string(),
+            progress: 100.0,
+            current_video: "임베딩 생성 완료".to_string(),
+            total_videos: 1,
+            completed_videos: 1,
+            log_message: "✅ 벡터 임베딩 생성 완료!".to_string(),
+            throughput_bps: None,
+        };
+        let _ = window.emit("embedding-progress", &final_progress);
+        spawn_watched_question_recheck(window.app_handle().clone(), None);
+        Ok(format!("✅ 벡터 임베딩 생성 완료\n{}", stdout))
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let error_progress = DownloadProgress {
+            channel: "벡터 임베딩".to_string(),
+            status: "실패".to_string(),
+            progress: 0.0,
+            current_video: "임베딩 생성 실패".to_string(),
+            total_videos: 1,
+            completed_videos: 0,
+            log_message: format!("❌ 벡터 임베딩 생성 실패: {}", stderr),
+            throughput_bps: None,
+        };
+        let _ = window.emit("embedding-progress", &error_progress);
+        Err(format!("벡터 임베딩 생성 실패: {}", stderr))
+    }
+}
+
+// 기존 벡터 임베딩 함수 (호환성 유지)
+#[command]
+async fn create_embeddings(state: State<'_, EmbeddingState>, vault_lock: State<'_, VaultLockState>) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let _running = RunningGuard::try_new(&state.is_running).ok_or("임베딩 작업이 이미 실행 중입니다")?;
+    let project_root = get_project_root();
+    let embed_script = project_root.join("vault").join("90_indices").join("embed.py");
+    if !embed_script.exists() {
+        return Err(format!("embed.py 스크립트를 찾을 수 없습니다: {}", embed_script.display()));
+    }
+    
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    
+    let output = Command::new(&venv_python)
+        .arg(&embed_script)
+        .current_dir(&project_root)
+        .env("YDH_EXCLUDED_EMBEDDING_CHANNELS", excluded_embedding_channels_env()) // 보관 채널 제외
+        .env("YDH_EMBEDDING_MODELS", embedding_models_env()) // 채널 언어별 임베딩 모델
+        .output()
+        .map_err(|e| e.to_string())?;
+    
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(format!("✅ 벡터 임베딩 생성 완료\n{}", stdout))
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!("벡터 임베딩 생성 실패: {}", stderr))
+    }
+}
+
+// 벡터 검색
+#[command]
+async fn vector_search(query: String) -> Result<String, String> {
+    let project_root = get_project_root();
+    let embed_script = project_root.join("vault").join("90_indices").join("embed.py");
+    if !embed_script.exists() {
+        return Err(format!("embed.py 스크립트를 찾을 수 없습니다: {}", embed_script.display()));
+    }
+    
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    let output = Command::new(&venv_python)
+        .args(&[embed_script.to_str().unwrap(), "search", &query])
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| e.to_string())?;
+    
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!("검색 실패: {}", stderr))
+    }
+}
+
+// RAG 질문-답변
+#[command]
+async fn ask_rag(query: String) -> Result<String, String> {
+    let project_root = get_project_root();
+    let rag_script = project_root.join("vault").join("90_indices").join("rag.py");
+    if !rag_script.exists() {
+        return Err(format!("rag.py 스크립트를 찾을 수 없습니다: {}", rag_script.display()));
+    }
+    
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    let output = Command::new(&venv_python)
+        .args(&[rag_script.to_str().unwrap(), &query])
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| e.to_string())?;
+    
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!("RAG 질문 실패: {}", stderr))
+    }
+}
+
+
+
+// 채널별 AI 질문 (DeepSeek, 실시간 진행 상황 포함)
+#[command]
+async fn ask_ai_with_progress(
+    window: Window, 
+    query: String, 
+    channel_name: String, 
+    model: String,
+    rag_settings: Option<RAGSettings>
+) -> Result<String, String> {
+    let project_root = get_project_root();
+    let rag_script = project_root.join("vault").join("90_indices").join("rag.py");
+    
+    if !rag_script.exists() {
+        return Err("RAG 스크립트를 찾을 수 없습니다".to_string());
+    }
+
+    // 초기 진행 상황 전송
+    let _ = window.emit("ai-progress", AIProgressUpdate {
+        step: "초기화".to_string(),
+        message: "🔍 검색 준비 중...".to_string(),
+        progress: 0.0,
+        details: Some(format!("채널: {} | 모델: {}", channel_name, model)),
+    });
+
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    
+    // 답변 언어 (설정의 명시적 지정 > 채널 감지 언어) 및 채널 용어집
+    let answer_language = rag_settings
+        .as_ref()
+        .and_then(|s| s.answer_config.answer_language.clone())
+        .filter(|l| !l.trim().is_empty())
+        .or_else(|| get_channel_language(&channel_name))
+        .unwrap_or_default();
+    let glossary = load_channel_settings()
+        .remove(&channel_name)
+        .map(|s| s.glossary)
+        .unwrap_or_default();
+    let glossary_json = serde_json::to_string(&glossary).unwrap_or_default();
+    
+    // RAG 설정을 JSON으로 직렬화
+    let settings_json = match rag_settings {
+        Some(settings) => serde_json::to_string(&settings).unwrap_or_default(),
+        None => String::new()
+    };
+    
+    let mut cmd_args = vec![
+        rag_script.to_str().unwrap(),
+        &query,
+        &channel_name,
+        "--progress",
+        "--model",
+        &model
+    ];
+    
+    // RAG 설정이 있으면 추가
+    if !settings_json.is_empty() {
+        cmd_args.push("--rag-settings");
+        cmd_args.push(&settings_json);
+    }
+    
+    let mut child = Command::new(&venv_python)
+        .args(&cmd_args)
+        .current_dir(&project_root)
+        .env("PYTHONUNBUFFERED", "1")
+        .env("YDH_ANSWER_LANGUAGE", &answer_language)  // 답변 언어 지시
+        .env("YDH_GLOSSARY", &glossary_json)           // 용어집 주입
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+
+    let stdout = child.stdout.take().unwrap();
+    let reader = BufReader::new(stdout);
+    let mut result = String::new();
+    let mut is_final_answer = false;
+    let mut all_output = String::new(); // 전체 출력 수집 (fallback용)
+
+    // 실시간 출력 처리
+    for line in reader.lines() {
+        let line = line.map_err(|e| e.to_string())?;
+        
+        // 모든 출력을 수집 (fallback용)
+        if !all_output.is_empty() {
+            all_output.push('\n');
+        }
+        all_output.push_str(&line);
+        
+        // 진행 상황 파싱
+        if line.starts_with("PROGRESS:") {
+            if let Some(progress_json) = line.strip_prefix("PROGRESS:") {
+                if let Ok(progress_data) = serde_json::from_str::<AIProgressUpdate>(progress_json) {
+                    let _ = window.emit("ai-progress", progress_data);
+                }
+            }
+        }
+        // 최종 답변 시작 표시
+        else if line.starts_with("FINAL_ANSWER:") {
+            is_final_answer = true;
+            let _ = window.emit("ai-progress", AIProgressUpdate {
+                step: "완료".to_string(),
+                message: "✅ 답변 생성 완료".to_string(),
+                progress: 100.0,
+                details: None,
+            });
+            
+            // FINAL_ANSWER: 라인에 이미 답변이 포함된 경우 처리
+            if let Some(answer_content) = line.strip_prefix("FINAL_ANSWER:") {
+                let trimmed = answer_content.trim();
+                if !trimmed.is_empty() {
+                    result.push_str(trimmed);
+                }
+            }
+        }
+        // 최종 답변 수집
+        else if is_final_answer {
+            if !result.is_empty() {
+                result.push('\n');
+            }
+            result.push_str(&line);
+        }
+        // PROGRESS 마커 없이 JSON이 바로 출력되는 경우 감지
+        else if line.trim().starts_with("{") && line.contains("\"answer\"") {
+            // JSON 응답으로 보이는 경우 수집 시작
+            if !result.is_empty() {
+                result.push('\n');
+            }
+            result.push_str(&line);
+            is_final_answer = true; // 이후 라인들도 수집
+        }
+    }
+
+    let status = child.wait().map_err(|e| e.to_string())?;
+    
+    if status.success() {
+        // 최적 응답 결정 로직
+        let final_result = if !result.is_empty() {
+            // FINAL_ANSWER 마커로 수집된 결과 우선 사용
+            result
+        } else if !all_output.is_empty() {
+            // 전체 출력에서 JSON 부분 추출 시도
+            if let Some(json_start) = all_output.find('{') {
+                if let Some(json_end) = all_output.rfind('}') {
+                    if json_end > json_start {
+                        // JSON 부분만 추출
+                        all_output[json_start..=json_end].to_string()
+                    } else {
+                        all_output
+                    }
+                } else {
+                    all_output
+                }
+            } else {
+                all_output
+            }
+        } else {
+            // fallback: 기본 방식으로 재실행
+            let output = Command::new(&venv_python)
+                .args(&[rag_script.to_str().unwrap(), &query, &channel_name, "--model", &model])
+                .current_dir(&project_root)
+                .env("PYTHONUNBUFFERED", "1")
+                .env("YDH_ANSWER_LANGUAGE", &answer_language)
+                .env("YDH_GLOSSARY", &glossary_json)
+                .output()
+                .map_err(|e| e.to_string())?;
+            
+            if output.status.success() {
+                String::from_utf8_lossy(&output.stdout).to_string()
+            } else {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(format!("DeepSeek RAG 질문 실패: {}", stderr));
+            }
+        };
+        
+        // 최종 결과 정리 (불필요한 PROGRESS 라인 제거)
+        let cleaned_result = final_result
+            .lines()
+            .filter(|line| !line.starts_with("PROGRESS:") && !line.starts_with("FINAL_ANSWER:"))
+            .collect::<Vec<&str>>()
+            .join("\n")
+            .trim()
+            .to_string();
+        
+        Ok(if cleaned_result.is_empty() { final_result } else { cleaned_result })
+    } else {
+        // 에러 발생 시 상세 에러 메시지 제공
+        let error_message = if all_output.is_empty() {
+            "Python 스크립트 실행 중 오류가 발생했습니다"
+        } else {
+            // 출력이 있는 경우 마지막 몇 줄을 에러 정보로 활용
+            let error_lines: Vec<&str> = all_output
+                .lines()
+                .filter(|line| line.contains("Error") || line.contains("Exception") || line.contains("Traceback"))
+                .collect();
+            
+            if !error_lines.is_empty() {
+                &error_lines.join("; ")
+            } else {
+                "Python 스크립트가 비정상적으로 종료되었습니다"
+            }
+        };
+        
+        Err(format!("DeepSeek RAG 질문 실패: {}", error_message))
+    }
+}
+
+
+
+// AI 질문 (실시간 진행 상황 포함)
+#[command]
+async fn ask_ai_universal_with_progress(
+    window: Window, 
+    query: String, 
+    channel_name: String, 
+    model: String,
+    rag_settings: Option<RAGSettings>
+) -> Result<String, String> {
+    ask_ai_with_progress(window, query, channel_name, model, rag_settings).await
+}
+
+// Python 스크립트 결과 캐시 (의존 파일 mtime이 바뀌거나 TTL이 지나면 다시 실행)
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[serde(rename_all = "snake_case")]
+enum ScriptCacheKind {
+    AiChannels,   // rag.py channels
+    PromptStatus, // auto_prompt.py status
+}
+
+const SCRIPT_CACHE_KINDS: [ScriptCacheKind; 2] = [ScriptCacheKind::AiChannels, ScriptCacheKind::PromptStatus];
+
+impl ScriptCacheKind {
+    fn ttl(self) -> Duration {
+        match self {
+            ScriptCacheKind::AiChannels => Duration::from_secs(10 * 60),
+            ScriptCacheKind::PromptStatus => Duration::from_secs(10 * 60),
+        }
+    }
+    
+    // 결과에 영향을 주는 파일/폴더 (폴더는 하위 파일 중 가장 최근 mtime)
+    fn dependencies(self) -> Vec<PathBuf> {
+        let indices = get_project_root().join("vault").join("90_indices");
+        let chroma = indices.join("chroma").join("chroma.sqlite3");
+        match self {
+            ScriptCacheKind::AiChannels => vec![chroma],
+            ScriptCacheKind::PromptStatus => vec![chroma, indices.join("prompts")],
+        }
+    }
+}
+
+struct ScriptCacheEntry {
+    fingerprint: Vec<Option<std::time::SystemTime>>,
+    cached_at: Instant,
+    output: String,
+}
+
+static SCRIPT_CACHE: Mutex<BTreeMap<ScriptCacheKind, ScriptCacheEntry>> = Mutex::new(BTreeMap::new());
+
+fn latest_mtime(path: &Path) -> Option<std::time::SystemTime> {
+    let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+    if !path.is_dir() {
+        return modified;
+    }
+    let entries = fs::read_dir(path).ok()?;
+    entries.flatten().filter_map(|entry| latest_mtime(&entry.path())).chain(modified).max()
+}
+
+// 캐시가 유효하면 저장된 출력, 아니면 run 결과를 저장 후 반환 (실패는 캐시하지 않음)
+fn cached_script_output(kind: ScriptCacheKind, run: impl FnOnce() -> Result<String, String>) -> Result<String, String> {
+    let fingerprint: Vec<Option<std::time::SystemTime>> = kind.dependencies().iter().map(|path| latest_mtime(path)).collect();
+    if let Ok(cache) = SCRIPT_CACHE.lock() {
+        if let Some(entry) = cache.get(&kind) {
+            if entry.fingerprint == fingerprint && entry.cached_at.elapsed() < kind.ttl() {
+                return Ok(entry.output.clone());
+            }
+        }
+    }
+    
+    let output = run()?;
+    if let Ok(mut cache) = SCRIPT_CACHE.lock() {
+        cache.insert(kind, ScriptCacheEntry { fingerprint, cached_at: Instant::now(), output: output.clone() });
+    }
+    Ok(output)
+}
+
+// 스크립트 결과 캐시 비우기 (kind 미지정 시 전체), 비운 항목 수 반환
+#[command]
+fn invalidate_cache(kind: Option<ScriptCacheKind>) -> Result<u32, String> {
+    let mut cache = SCRIPT_CACHE.lock().map_err(|_| "캐시 잠금 실패".to_string())?;
+    let kinds: Vec<ScriptCacheKind> = match kind {
+        Some(kind) => vec![kind],
+        None => SCRIPT_CACHE_KINDS.to_vec(),
+    };
+    Ok(kinds.into_iter().filter(|kind| cache.remove(kind).is_some()).count() as u32)
+}
+
+#[derive(Serialize, Deserialize)]
+struct AIChannelInfo {
+    name: String,
+    video_count: u32,
+    description: Option<String>,
+    last_updated: Option<String>,
+}
+
+
+
+// AI용 채널 목록 조회
+#[command]
+async fn get_available_channels_for_ai() -> Result<Vec<AIChannelInfo>, String> {
+    let project_root = get_project_root();
+    let rag_script = project_root.join("vault").join("90_indices").join("rag.py");
+    
+    if !rag_script.exists() {
+        return Ok(vec![]);
+    }
+    
+    let stdout = cached_script_output(ScriptCacheKind::AiChannels, || {
+        let venv_python = project_root.join("venv").join("bin").join("python");
+        let output = Command::new(&venv_python)
+            .args(&[rag_script.to_str().unwrap(), "channels"])
+            .current_dir(&project_root)
+            .output()
+            .map_err(|e| e.to_string())?;
+        
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        } else {
+            Err("채널 목록 조회 실패".to_string())
+        }
+    })?;
+    // 간단한 파싱으로 채널 목록 반환
+    Ok(parse_channel_list(&stdout))
+}
+
+fn parse_channel_list(output: &str) -> Vec<AIChannelInfo> {
+    let mut channels = Vec::new();
+    
+    println!("파싱할 출력:\n{}", output);
+    
+    // "1. channel_name (X개 영상)" 형태의 라인을 파싱 (이모지 및 기타 텍스트 무시)
+    for line in output.lines() {
+        println!("파싱 중인 라인: {}", line);
+        if let Some(captures) = regex::Regex::new(r"^\s*\d+\.\s*(.+?)\s*\((\d+)개\s*영상\)")
+            .ok()
+            .and_then(|re| re.captures(line))
+        {
+            if let (Some(name), Some(count_str)) = (captures.get(1), captures.get(2)) {
+                if let Ok(count) = count_str.as_str().parse::<u32>() {
+                    println!("파싱 성공: {} - {}개", name.as_str().trim(), count);
+                    channels.push(AIChannelInfo {
+                        name: name.as_str().trim().to_string(),
+                        video_count: count,
+                        description: None,
+                        last_updated: None,
+                    });
+                }
+            }
+        }
+    }
+    
+    println!("파싱된 채널 개수: {}", channels.len());
+    channels
+}
+
+// 채널별 프롬프트 조회
+#[command]
+async fn get_channel_prompt(channel_name: String) -> Result<String, String> {
+    let project_root = get_project_root();
+    let prompts_dir = project_root.join("vault").join("90_indices").join("prompts");
+    
+    // 채널명을 파일시스템에 안전한 형태로 변환
+    let safe_channel_name = sanitize_channel_name(&channel_name);
+    let channel_dir = prompts_dir.join(&safe_channel_name);
+    
+    if !channel_dir.exists() {
+        return Ok("{}".to_string()); // 기본 프롬프트 반환
+    }
+    
+    // 활성 버전 확인
+    let active_file = channel_dir.join("active.txt");
+    let version = if active_file.exists() {
+        std::fs::read_to_string(&active_file)
+            .map_err(|e| e.to_string())?
+            .trim()
+            .parse::<u32>()
+            .unwrap_or(1)
+    } else {
+        1
+    };
+    
+    // 프롬프트 파일 읽기
+    let prompt_file = channel_dir.join(format!("prompt_v{}.json", version));
+    if prompt_file.exists() {
+        std::fs::read_to_string(&prompt_file).map_err(|e| e.to_string())
+    } else {
+        Ok("{}".to_string())
+    }
+}
+
+// 전역 프롬프트 변수 파일 (모든 채널 프롬프트에서 {{변수명}}으로 참조)
+fn get_prompt_globals_path() -> PathBuf {
+    get_project_root().join("vault").join("90_indices").join("prompts").join("_globals.json")
+}
+
+#[command]
+fn get_prompt_globals() -> Result<String, String> {
+    let path = get_prompt_globals_path();
+    if !path.exists() {
+        return Ok("{}".to_string());
+    }
+    fs::read_to_string(&path).map_err(|e| format!("전역 프롬프트 변수 읽기 실패: {}", e))
+}
+
+// 전역 프롬프트 변수 저장 (다음 질문부터 모든 채널에 반영)
+#[command]
+fn set_prompt_globals(vault_lock: State<'_, VaultLockState>, json: String) -> Result<(), String> {
+    ensure_vault_writable(&vault_lock)?;
+    let value: serde_json::Value = serde_json::from_str(&json).map_err(|e| format!("JSON 형식 오류: {}", e))?;
+    if !value.is_object() {
+        return Err("전역 변수는 JSON 객체여야 합니다 (예: {\"tickers\": [\"AAPL\"]})".to_string());
+    }
+    
+    let path = get_prompt_globals_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("프롬프트 폴더 생성 실패: {}", e))?;
+    }
+    let pretty = serde_json::to_string_pretty(&value).map_err(|e| e.to_string())?;
+    let temp_path = path.with_extension("json.tmp");
+    fs::write(&temp_path, pretty).map_err(|e| format!("전역 프롬프트 변수 저장 실패: {}", e))?;
+    fs::rename(&temp_path, &path).map_err(|e| format!("전역 프롬프트 변수 저장 실패: {}", e))
+}
+
+fn sanitize_channel_name(name: &str) -> String {
+    // 특수문자를 밑줄로 변경하고 길이 제한
+    let sanitized = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() || "가나다라마바사아자차카타파하".contains(c) || c == '_' || c == '-' {
+            c
+        } else {
+            '_'
+        })
+        .collect::<String>();
+    
+    // 연속된 밑줄 제거
+    let re = regex::Regex::new(r"_+").unwrap();
+    let result = re.replace_all(&sanitized, "_");
+    
+    // 앞뒤 밑줄 제거하고 길이 제한
+    result.trim_matches('_').chars().take(50).collect()
+}
+
+// 채널별 제로샷 AI 프롬프트 생성
+#[command]
+async fn auto_generate_channel_prompt(vault_lock: State<'_, VaultLockState>, channel_name: String) -> Result<u32, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let project_root = get_project_root();
+    let auto_prompt_script = project_root.join("vault").join("90_indices").join("auto_prompt.py");
+    
+    if !auto_prompt_script.exists() {
+        return Err("제로샷 AI 프롬프트 생성 스크립트를 찾을 수 없습니다".to_string());
+    }
+    
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    let args = vec![
+        auto_prompt_script.to_str().unwrap(), 
+        "generate", 
+        &channel_name
+    ];
+    
+    let output = Command::new(&venv_python)
+        .args(&args)
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| e.to_string())?;
+    
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        // 버전 번호 추출 (예: "v3 생성 완료" -> 3)
+        if let Some(version_match) = stdout.find("v") {
+            if let Some(space_pos) = stdout[version_match..].find(" ") {
+                let version_str = &stdout[version_match + 1..version_match + space_pos];
+                if let Ok(version) = version_str.parse::<u32>() {
+                    return Ok(version);
+                }
+            }
+        }
+        Ok(1) // 기본값
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!("제로샷 AI 프롬프트 생성 실패: {}", stderr))
+    }
+}
+
+// 채널 분석 결과 조회
+#[command]
+async fn get_channel_analysis(channel_name: String) -> Result<String, String> {
+    let project_root = get_project_root();
+    let auto_prompt_script = project_root.join("vault").join("90_indices").join("auto_prompt.py");
+    
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    let output = Command::new(&venv_python)
+        .args(&[auto_prompt_script.to_str().unwrap(), "analyze", &channel_name])
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| e.to_string())?;
+    
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!("채널 분석 실패: {}", stderr))
+    }
+}
+
+// 모든 채널 자동 프롬프트 일괄 생성
+#[command]
+async fn batch_generate_prompts(vault_lock: State<'_, VaultLockState>) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let project_root = get_project_root();
+    let auto_prompt_script = project_root.join("vault").join("90_indices").join("auto_prompt.py");
+    
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    let output = Command::new(&venv_python)
+        .args(&[auto_prompt_script.to_str().unwrap(), "batch"])
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| e.to_string())?;
+    
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!("일괄 프롬프트 생성 실패: {}", stderr))
+    }
+}
+
+// 채널별 프롬프트 저장
+#[command]
+async fn save_channel_prompt(vault_lock: State<'_, VaultLockState>, channel_name: String, prompt_data: String) -> Result<u32, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let project_root = get_project_root();
+    let prompts_dir = project_root.join("vault").join("90_indices").join("prompts");
+    
+    let safe_channel_name = sanitize_channel_name(&channel_name);
+    let channel_dir = prompts_dir.join(&safe_channel_name);
+    
+    // 디렉토리 생성
+    std::fs::create_dir_all(&channel_dir).map_err(|e| e.to_string())?;
+    
+    // 기존 버전 확인
+    let existing_versions: Vec<u32> = std::fs::read_dir(&channel_dir)
+        .map_err(|e| e.to_string())?
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let filename = entry.file_name().to_string_lossy().to_string();
+            if filename.starts_with("prompt_v") && filename.ends_with(".json") {
+                let version_str = filename.strip_prefix("prompt_v")?.strip_suffix(".json")?;
+                version_str.parse().ok()
+            } else {
+                None
+            }
+        })
+        .collect();
+    
+    let new_version = existing_versions.iter().max().unwrap_or(&0) + 1;
+    
+    // 새 프롬프트 파일 저장
+    let prompt_file = channel_dir.join(format!("prompt_v{}.json", new_version));
+    std::fs::write(&prompt_file, &prompt_data).map_err(|e| e.to_string())?;
+    
+    // 활성 버전 업데이트
+    let active_file = channel_dir.join("active.txt");
+    std::fs::write(&active_file, new_version.to_string()).map_err(|e| e.to_string())?;
+    
+    Ok(new_version)
+}
+
+// 프롬프트 버전 목록 조회
+#[command]
+async fn get_prompt_versions(channel_name: String) -> Result<String, String> {
+    let project_root = get_project_root();
+    let auto_prompt_script = project_root.join("vault").join("90_indices").join("auto_prompt.py");
+    
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    let output = Command::new(&venv_python)
+        .args(&[auto_prompt_script.to_str().unwrap(), "versions", &channel_name])
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| e.to_string())?;
+    
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!("프롬프트 버전 조회 실패: {}", stderr))
+    }
+}
+
+// 프롬프트 현황 조회
+#[command]
+async fn get_prompt_status() -> Result<String, String> {
+    let project_root = get_project_root();
+    let auto_prompt_script = project_root.join("vault").join("90_indices").join("auto_prompt.py");
+    
+    cached_script_output(ScriptCacheKind::PromptStatus, || {
+        let venv_python = project_root.join("venv").join("bin").join("python");
+        let output = Command::new(&venv_python)
+            .args(&[auto_prompt_script.to_str().unwrap(), "status"])
+            .current_dir(&project_root)
+            .output()
+            .map_err(|e| e.to_string())?;
+        
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(format!("프롬프트 현황 조회 실패: {}", stderr))
+        }
+    })
+}
+
+// 마지막 정합성 검사 결과 (config/last_integrity_check.json, 보관소 상태 점수용)
+#[derive(Serialize, Deserialize, Clone)]
+struct IntegrityCheckRecord {
+    checked_at: String,
+    succeeded: bool,
+    issues: u32,
+}
+
+const LAST_INTEGRITY_CHECK_FILE: &str = "last_integrity_check.json";
+
+fn record_integrity_check(result: &Result<String, String>) {
+    let issues = match result {
+        Ok(output) => Regex::new(r"(\d+)개 문제 발견")
+            .ok()
+            .and_then(|re| re.captures(output).and_then(|c| c[1].parse().ok()))
+            .unwrap_or(0),
+        Err(_) => 0,
+    };
+    let record = IntegrityCheckRecord {
+        checked_at: chrono::Local::now().to_rfc3339(),
+        succeeded: result.is_ok(),
+        issues,
+    };
+    if let Err(e) = save_json_config(LAST_INTEGRITY_CHECK_FILE, &record) {
+        eprintln!("❌ 정합성 검사 결과 저장 실패: {}", e);
+    }
+}
+
+// 데이터 정합성 검사 (진행 상황 포함)
+#[command]
+async fn check_integrity_with_progress(window: Window) -> Result<String, String> {
+    let result = run_integrity_check(window.clone()).await;
+    record_integrity_check(&result);
+    notify_job_finished(&window, NotifiedJob::IntegrityCheck, &result);
+    result
+}
+
+async fn run_integrity_check(window: Window) -> Result<String, String> {
+    let project_root = get_project_root();
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    
+    // 시작 진행 상황
+    let start_progress = DownloadProgress {
+        channel: "정합성 검사".to_string(),
+        status: "시작".to_string(),
+        progress: 0.0,
+        current_video: "검사 준비 중...".to_string(),
+        total_videos: 1,
+        completed_videos: 0,
+        log_message: "🔍 데이터 정합성 검사를 시작합니다...".to_string(),
+        throughput_bps: None,
+    };
+    let _ = window.emit("integrity-progress", &start_progress);
+    
+    // 새로운 채널별 격리 정합성 검사 스크립트 실행 (실시간 로그)
+    let integrity_script = project_root.join("vault").join("90_indices").join("integrity_check.py");
+    if !integrity_script.exists() {
+        return Err(format!("정합성 검사 스크립트를 찾을 수 없습니다: {}", integrity_script.display()));
+    }
+    
+    let mut child = Command::new(&venv_python)
+        .arg(&integrity_script)
+        .current_dir(&project_root)
+        .env("PYTHONUNBUFFERED", "1")
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+    
+    let stdout = child.stdout.take().ok_or("stdout를 가져올 수 없습니다")?;
+    let stderr = child.stderr.take().ok_or("stderr를 가져올 수 없습니다")?;
+    
+    // 별도 스레드에서 실시간 로그 처리 (진행률은 스크립트의 stage 이벤트 기준, stderr 경고도 같은 진행률 사용)
+    let stage_progress = Arc::new(std::sync::atomic::AtomicU32::new(0f32.to_bits()));
+    let window_clone = window.clone();
+    let stage_progress_stdout = stage_progress.clone();
+    std::thread::spawn(move || {
+        let stdout_reader = std::io::BufReader::new(stdout);
+        let mut stage_message = "검사 준비 중...".to_string();
+        for line in stdout_reader.lines() {
+            if let Ok(line) = line {
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                let log_message = match parse_progress_line(line) {
+                    Some(BackendProgressEvent::Stage { progress, message }) => {
+                        stage_progress_stdout.store(progress.to_bits(), Ordering::Relaxed);
+                        stage_message = message;
+                        format!("🔍 {}", stage_message)
+                    }
+                    Some(_) => continue,
+                    None => line.to_string(),
+                };
+                let progress = DownloadProgress {
+                    channel: "정합성 검사".to_string(),
+                    status: "검사 중".to_string(),
+                    progress: f32::from_bits(stage_progress_stdout.load(Ordering::Relaxed)),
+                    current_video: stage_message.clone(),
+                    total_videos: 1,
+                    completed_videos: 0,
+                    log_message,
+                    throughput_bps: None,
+                };
+                let _ = window_clone.emit("integrity-progress", &progress);
+            }
+        }
+    });
+    
+    let window_clone2 = window.clone();
+    std::thread::spawn(move || {
+        let stderr_reader = std::io::BufReader::new(stderr);
+        for line in stderr_reader.lines() {
+            if let Ok(line) = line {
+                let line = line.trim();
+                if !line.is_empty() {
+                    let progress = DownloadProgress {
+                        channel: "정합성 검사".to_string(),
+                        status: "경고".to_string(),
+                        progress: f32::from_bits(stage_progress.load(Ordering::Relaxed)),
+                        current_video: "실시간 검사 중...".to_string(),
+                        total_videos: 1,
+                        completed_videos: 0,
+                        log_message: format!("⚠️ {}", line),
+                        throughput_bps: None,
+                    };
+                    let _ = window_clone2.emit("integrity-progress", &progress);
+                }
+            }
+        }
+    });
+    
+    // 프로세스 완료 대기
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+    
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let final_progress = DownloadProgress {
+            channel: "정합성 검사".to_string(),
+            status: "완료".to_string(),
+            progress: 100.0,
+            current_video: "검사 완료".to_string(),
+            total_videos: 1,
+            completed_videos: 1,
+            log_message: "✅ 데이터 정합성 검사 완료!".to_string(),
+            throughput_bps: None,
+        };
+        let _ = window.emit("integrity-progress", &final_progress);
+        Ok(format!("✅ 데이터 정합성 검사 완료\n{}", stdout))
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let error_progress = DownloadProgress {
+            channel: "정합성 검사".to_string(),
+            status: "실패".to_string(),
+            progress: 0.0,
+            current_video: "검사 실패".to_string(),
+            total_videos: 1,
+            completed_videos: 0,
+            log_message: format!("❌ 데이터 정합성 검사 실패: {}", stderr),
+            throughput_bps: None,
+        };
+        let _ = window.emit("integrity-progress", &error_progress);
+        Err(format!("데이터 정합성 검사 실패: {}", stderr))
+    }
+}
+
+// 기존 데이터 정합성 검사 함수 (호환성 유지)
+#[command]
+async fn check_integrity() -> Result<String, String> {
+    let project_root = get_project_root();
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    
+    let integrity_script = project_root.join("vault").join("90_indices").join("integrity_check.py");
+    if !integrity_script.exists() {
+        return Err(format!("정합성 검사 스크립트를 찾을 수 없습니다: {}", integrity_script.display()));
+    }
+    
+    let output = Command::new(&venv_python)
+        .arg(&integrity_script)
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| e.to_string())?;
+    
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(format!("✅ 데이터 정합성 검사 완료\n{}", stdout))
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!("데이터 정합성 검사 실패: {}", stderr))
+    }
+}
+
+// 자막 품질 점수 계산 (진행 상황 포함)
+#[command]
+async fn score_transcript_quality(window: Window, vault_lock: State<'_, VaultLockState>, channel: Option<String>, force: Option<bool>, lm_model: Option<String>) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let project_root = get_project_root();
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    
+    let quality_script = project_root.join("vault").join("90_indices").join("transcript_quality.py");
+    if !quality_script.exists() {
+        return Err(format!("자막 품질 스크립트를 찾을 수 없습니다: {}", quality_script.display()));
+    }
+    
+    let mut cmd = Command::new(&venv_python);
+    cmd.arg(&quality_script)
+        .current_dir(&project_root)
+        .env("PYTHONUNBUFFERED", "1");
+    if let Some(channel) = &channel {
+        cmd.args(["--channel", channel]);
+    }
+    if force.unwrap_or(false) {
+        cmd.arg("--force");
+    }
+    if let Some(model) = lm_model.filter(|m| !m.trim().is_empty()) {
+        cmd.args(["--lm", model.trim()]);
+    }
+    
+    let label = channel.unwrap_or_else(|| "전체 채널".to_string());
+    let before = captions_modified_times();
+    let result = run_script_with_progress(&window, "quality-progress", &label, cmd);
+    // 실패해도 중간까지 기록된 점수는 인덱스에 반영
+    reindex_videos_modified_since(&before);
+    result
+        .map(|_| format!("✅ 자막 품질 점수 계산 완료: {}", label))
+        .map_err(|e| format!("자막 품질 점수 계산 실패: {}", e))
+}
+
+// Python 스크립트 실행 후 "[현재/전체]" 형식의 로그를 진행률 이벤트로 전달
+fn run_script_with_progress(window: &Window, event: &str, label: &str, mut cmd: Command) -> Result<(), String> {
+    let mut child = cmd
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+    let stdout = child.stdout.take().ok_or("stdout를 가져올 수 없습니다")?;
+    let stderr = child.stderr.take().ok_or("stderr를 가져올 수 없습니다")?;
+    
+    // 모델 로딩 경고 등으로 stderr 버퍼가 차지 않도록 별도 스레드에서 수집
+    let stderr_handle = thread::spawn(move || {
+        let mut collected = String::new();
+        let _ = std::io::Read::read_to_string(&mut BufReader::new(stderr), &mut collected);
+        collected
+    });
+    
+    let progress_re = Regex::new(r"^\[(\d+)/(\d+)\]").unwrap();
+    for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (completed, total) = progress_re
+            .captures(line)
+            .and_then(|c| Some((c[1].parse::<u32>().ok()?, c[2].parse::<u32>().ok()?)))
+            .unwrap_or((0, 0));
+        let progress = DownloadProgress {
+            channel: label.to_string(),
+            status: "처리 중".to_string(),
+            progress: if total > 0 { completed as f32 / total as f32 * 100.0 } else { 0.0 },
+            current_video: String::new(),
+            total_videos: total,
+            completed_videos: completed,
+            log_message: line.to_string(),
+            throughput_bps: None,
+        };
+        let _ = window.emit(event, &progress);
+    }
+    
+    let status = child.wait().map_err(|e| e.to_string())?;
+    let stderr_output = stderr_handle.join().unwrap_or_default();
+    if status.success() {
+        Ok(())
+    } else {
+        Err(stderr_output)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct RetranscriptionEstimate {
+    model: String,
+    videos: u32,
+    total_audio_minutes: f64,
+    estimated_processing_minutes: f64,
+    unknown_duration_videos: u32,
+}
+
+const DEFAULT_WHISPER_MODEL: &str = "small";
+
+// CPU 기준 Whisper 처리 시간 / 오디오 길이 비율 (대략치)
+fn whisper_realtime_factor(model: &str) -> f64 {
+    match model {
+        "tiny" => 0.1,
+        "base" => 0.15,
+        "small" => 0.35,
+        "medium" => 0.8,
+        _ => 1.5,  // large 계열
+    }
+}
+
+// 품질 점수가 threshold 미만인 영상 목록
+fn low_quality_videos(threshold: f64, channel: Option<&str>) -> Result<Vec<VideoInfo>, String> {
+    Ok(scan_vault_videos()?
+        .into_iter()
+        .filter(|v| channel.map(|c| v.channel == c).unwrap_or(true))
+        .filter(|v| v.transcript_quality.map(|q| q < threshold).unwrap_or(false))
+        .collect())
+}
+
+// 재전사 대상 수와 예상 처리 시간 계산
+#[command]
+fn estimate_retranscription(threshold: f64, channel: Option<String>, model: Option<String>) -> Result<RetranscriptionEstimate, String> {
+    let model = model.unwrap_or_else(|| DEFAULT_WHISPER_MODEL.to_string());
+    let videos = low_quality_videos(threshold, channel.as_deref())?;
+    
+    let total_seconds: u32 = videos.iter().filter_map(|v| v.duration_seconds).sum();
+    let unknown_duration_videos = videos.iter().filter(|v| v.duration_seconds.is_none()).count() as u32;
+    let total_audio_minutes = total_seconds as f64 / 60.0;
+    
+    Ok(RetranscriptionEstimate {
+        estimated_processing_minutes: total_audio_minutes * whisper_realtime_factor(&model),
+        model,
+        videos: videos.len() as u32,
+        total_audio_minutes,
+        unknown_duration_videos,
+    })
+}
+
+// 저품질 자막을 Whisper로 재전사하고 해당 영상만 재임베딩
+#[command]
+async fn retranscribe_low_quality(
+    window: Window,
+    threshold: f64,
+    channel: Option<String>,
+    model: Option<String>,
+    limit: Option<u32>,
+    state: State<'_, EmbeddingState>,
+    vault_lock: State<'_, VaultLockState>,
+) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err("threshold는 0.0~1.0 사이여야 합니다".to_string());
+    }
+    // 재전사한 영상은 바로 재임베딩하므로 다른 임베딩 작업/인덱스 백업과 겹치지 않게 함
+    let _running = RunningGuard::try_new(&state.is_running).ok_or("임베딩 작업이 이미 실행 중입니다")?;
+    let _retranscribing = RunningGuard::new(&window.state::<VaultJobState>().retranscribing);
+    
+    let project_root = get_project_root();
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    let script = project_root.join("vault").join("90_indices").join("retranscribe.py");
+    if !script.exists() {
+        return Err(format!("재전사 스크립트를 찾을 수 없습니다: {}", script.display()));
+    }
+    
+    let model = model.unwrap_or_else(|| DEFAULT_WHISPER_MODEL.to_string());
+    let mut cmd = low_priority_command(&venv_python);
+    cmd.arg(&script)
+        .args(["--threshold", &threshold.to_string(), "--model", &model])
+        .current_dir(&project_root)
+        .env("PYTHONUNBUFFERED", "1");
+    apply_thread_limits(&mut cmd);
+    if let Some(channel) = &channel {
+        cmd.args(["--channel", channel]);
+        if let Some(language) = get_channel_language(channel) {
+            cmd.args(["--language", &language]);
+        }
+    }
+    cmd.env("YDH_EMBEDDING_MODELS", embedding_models_env()); // 재전사 후 재임베딩에 사용
+    if let Some(limit) = limit {
+        cmd.args(["--limit", &limit.to_string()]);
+    }
+    
+    let label = channel.unwrap_or_else(|| "전체 채널".to_string());
+    let before = captions_modified_times();
+    let result = run_script_with_progress(&window, "retranscribe-progress", &label, cmd);
+    // 재전사한 자막을 전문 검색/언어 인덱스에 반영 (재임베딩은 스크립트가 이미 수행)
+    reindex_videos_modified_since(&before);
+    result
+        .map(|_| format!("✅ 저품질 자막 재전사 완료: {}", label))
+        .map_err(|e| format!("재전사 실패: {}", e))
+}
+
+// 앱 상태 조회
+#[command]
+fn get_app_status() -> Result<AppStatus, String> {
+    let project_root = get_project_root();
+    let vault_path = project_root.join("vault");
+    let channels = read_channels().unwrap_or_default();
+    let videos = scan_vault_videos().unwrap_or_default();
+    
+    // Vault 크기 계산 (MB 단위로 반환)
+    let vault_size_bytes = calculate_directory_size(&vault_path);
+    let vault_size_mb = vault_size_bytes as f64 / (1024.0 * 1024.0);
+    
+    // 벡터 DB 상태 확인
+    let chroma_path = project_root.join("vault").join("90_indices").join("chroma");
+    let vector_db_status = if chroma_path.exists() {
+        "활성화됨".to_string()
+    } else {
+        "비활성화됨".to_string()
+    };
+    
+    // 마지막으로 성공한 다운로드 실행 시각 (다운로드 기록 기준)
+    let last_download = last_download_finished_at();
+    
+    Ok(AppStatus {
+        total_videos: videos.len() as u32,
+        total_channels: channels.len() as u32,
+        memorialized_channels: channels.iter().filter(|c| c.memorialized).count() as u32,
+        vault_size_mb: vault_size_mb,
+        last_download,
+        vector_db_status,
+    })
+}
+
+fn calculate_directory_size(path: &PathBuf) -> u64 {
+    if !path.exists() {
+        return 0;
+    }
+    
+    let mut size = 0;
+    if let Ok(entries) = fs::read_dir(path) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_file() {
+                if let Ok(metadata) = fs::metadata(&path) {
+                    size += metadata.len();
+                }
+            } else if path.is_dir() {
+                size += calculate_directory_size(&path);
+            }
+        }
+    }
+    size
+}
+
+// 저장 공간 예측 관련 구조체들
+#[derive(Serialize, Deserialize)]
+struct ChannelGrowth {
+    channel: String,
+    videos_per_month: f64,
+    avg_video_size_mb: f64,
+    projected_mb_per_month: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ForecastPoint {
+    month: String,
+    projected_vault_size_mb: f64,
+    projected_available_mb: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StorageForecast {
+    current_vault_size_mb: f64,
+    disk_total_mb: f64,
+    disk_available_mb: f64,
+    observed_mb_per_month: f64,
+    projected_mb_per_month: f64,
+    months_until_full: Option<f64>,
+    estimated_full_date: Option<String>,
+    channels: Vec<ChannelGrowth>,
+    forecast: Vec<ForecastPoint>,
+}
+
+// 업로드 주기/실제 증가량 계산에 사용할 최근 기간 (일)
+const GROWTH_WINDOW_DAYS: i64 = 180;
+
+fn bytes_to_mb(bytes: u64) -> f64 {
+    bytes as f64 / (1024.0 * 1024.0)
+}
+
+// vault 증가량 예측 및 디스크가 가득 차는 시점 추정
+#[command]
+fn get_storage_forecast(months: Option<u32>) -> Result<StorageForecast, String> {
+    let months = months.unwrap_or(12).clamp(1, 120);
+    let project_root = get_project_root();
+    let vault_path = project_root.join("vault");
+    let videos = scan_vault_videos()?;
+    
+    let today = chrono::Local::now().date_naive();
+    let window_start = today - chrono::Duration::days(GROWTH_WINDOW_DAYS);
+    let window_months = GROWTH_WINDOW_DAYS as f64 / 30.44;
+    
+    // 채널별: (전체 영상 수, 전체 크기, 최근 업로드 수)
+    let mut channel_totals: BTreeMap<String, (u32, u64, u32)> = BTreeMap::new();
+    let mut recently_downloaded_bytes = 0u64;
+    
+    for video in &videos {
+        let video_file = project_root.join(&video.video_path);
+        let folder_size = video_file.parent().map(|f| calculate_directory_size(&f.to_path_buf())).unwrap_or(0);
+        
+        let uploaded_recently = video.upload_date
+            .as_deref()
+            .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+            .map(|d| d >= window_start)
+            .unwrap_or(false);
+        
+        let totals = channel_totals.entry(video.channel.clone()).or_insert((0, 0, 0));
+        totals.0 += 1;
+        totals.1 += folder_size;
+        if uploaded_recently {
+            totals.2 += 1;
+        }
+        
+        // 실제 다운로드 시점은 영상 파일 수정 시간 기준
+        let downloaded_at = fs::metadata(&video_file)
+            .and_then(|m| m.modified())
+            .map(|t| chrono::DateTime::<chrono::Local>::from(t).date_naive());
+        if let Ok(downloaded_at) = downloaded_at {
+            if downloaded_at >= window_start {
+                recently_downloaded_bytes += folder_size;
+            }
+        }
+    }
+    
+    let channels: Vec<ChannelGrowth> = channel_totals
+        .into_iter()
+        .map(|(channel, (count, bytes, recent_uploads))| {
+            let avg_video_size_mb = bytes_to_mb(bytes) / count.max(1) as f64;
+            let videos_per_month = recent_uploads as f64 / window_months;
+            ChannelGrowth {
+                channel,
+                videos_per_month,
+                avg_video_size_mb,
+                projected_mb_per_month: videos_per_month * avg_video_size_mb,
+            }
+        })
+        .collect();
+    
+    let observed_mb_per_month = bytes_to_mb(recently_downloaded_bytes) / window_months;
+    let cadence_mb_per_month: f64 = channels.iter().map(|c| c.projected_mb_per_month).sum();
+    // 디스크 구매 시점 판단용이므로 업로드 주기 기반/실제 증가량 중 큰 값 사용
+    let projected_mb_per_month = cadence_mb_per_month.max(observed_mb_per_month);
+    
+    let current_vault_size_mb = bytes_to_mb(calculate_directory_size(&vault_path));
+    let disk_total_mb = bytes_to_mb(fs2::total_space(&vault_path).map_err(|e| format!("디스크 용량 조회 실패: {}", e))?);
+    let disk_available_mb = bytes_to_mb(fs2::available_space(&vault_path).map_err(|e| format!("디스크 여유 공간 조회 실패: {}", e))?);
+    
+    let months_until_full = if projected_mb_per_month > 0.0 {
+        Some(disk_available_mb / projected_mb_per_month)
+    } else {
+        None
+    };
+    let estimated_full_date = months_until_full.map(|m| {
+        (today + chrono::Duration::days((m * 30.44).round() as i64)).format("%Y-%m-%d").to_string()
+    });
+    
+    let forecast = (1..=months)
+        .map(|m| {
+            let growth = projected_mb_per_month * m as f64;
+            let month = today
+                .checked_add_months(chrono::Months::new(m))
+                .map(|d| d.format("%Y-%m").to_string())
+                .unwrap_or_default();
+            ForecastPoint {
+                month,
+                projected_vault_size_mb: current_vault_size_mb + growth,
+                projected_available_mb: (disk_available_mb - growth).max(0.0),
+            }
+        })
+        .collect();
+    
+    Ok(StorageForecast {
+        current_vault_size_mb,
+        disk_total_mb,
+        disk_available_mb,
+        observed_mb_per_month,
+        projected_mb_per_month,
+        months_until_full,
+        estimated_full_date,
+        channels,
+        forecast,
+    })
+}
+
+// 보관 정책 (housekeeping) 관련 구조체들
+#[derive(Serialize, Deserialize, Clone)]
+struct HousekeepingItem {
+    channel: String,
+    title: String,
+    folder: String,
+    upload_date: Option<String>,
+    action: String,  // "delete_video" | "delete_media"
+    files: Vec<String>,
+    freed_mb: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+struct HousekeepingReport {
+    dry_run: bool,
+    executed_at: String,
+    items: Vec<HousekeepingItem>,
+    total_freed_mb: f64,
+    errors: Vec<String>,
+}
+
+const HOUSEKEEPING_REPORT_FILE: &str = "housekeeping_report.json";
+// 자동 정리 실행 주기
+const HOUSEKEEPING_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+// 자막만 남길 때 삭제 대상 미디어 파일
+const MEDIA_FILE_NAMES: [&str; 2] = ["video.mp4", "preview.mp4"];
+
+// 채널 다운로드 정책(날짜 범위/최신 N개/최대 용량) 밖의 영상 목록 계산
+fn plan_policy_prune(channel: Option<&str>) -> Result<Vec<HousekeepingItem>, String> {
+    let settings = load_channel_settings();
+    let project_root = get_project_root();
+    
+    let mut videos_by_channel: BTreeMap<String, Vec<VideoInfo>> = BTreeMap::new();
+    for video in scan_vault_videos()? {
+        if channel.map(|c| c == video.channel).unwrap_or(true) {
+            videos_by_channel.entry(video.channel.clone()).or_default().push(video);
+        }
+    }
+    
+    let mut items = Vec::new();
+    for (channel, mut videos) in videos_by_channel {
+        let Some(policy) = settings.get(&channel) else {
+            continue;
+        };
+        let max_bytes = policy.max_archive_mb.map(|mb| mb * 1024 * 1024);
+        videos.sort_by(|a, b| b.upload_date.cmp(&a.upload_date));
+        
+        let mut kept = 0u32;
+        let mut kept_bytes = 0u64;
+        for video in videos {
+            let Some(folder) = project_root.join(&video.video_path).parent().map(Path::to_path_buf) else {
+                continue;
+            };
+            let size = calculate_directory_size(&folder);
+            let upload = video.upload_date.as_deref().and_then(compact_upload_date);
+            // 날짜를 모르는 영상은 날짜 범위로 지우지 않음
+            let out_of_range = upload
+                .as_deref()
+                .map(|d| {
+                    policy.download_since.as_deref().map(|since| d < since).unwrap_or(false)
+                        || policy.download_until.as_deref().map(|until| d > until).unwrap_or(false)
+                })
+                .unwrap_or(false);
+            let over_count = policy.max_videos.map(|max| kept >= max).unwrap_or(false);
+            let over_size = max_bytes.map(|max| kept_bytes + size > max).unwrap_or(false);
+            if !out_of_range && !over_count && !over_size {
+                kept += 1;
+                kept_bytes += size;
+                continue;
+            }
+            
+            let relative = folder.strip_prefix(&project_root).unwrap_or(&folder).to_string_lossy().to_string();
+            items.push(HousekeepingItem {
+                channel: channel.clone(),
+                title: video.title,
+                folder: relative.clone(),
+                upload_date: video.upload_date,
+                action: "policy_prune".to_string(),
+                files: vec![relative],
+                freed_mb: bytes_to_mb(size),
+            });
+        }
+    }
+    Ok(items)
+}
+
+// 채널별 보관 정책에 따라 삭제 대상 목록 계산
+fn plan_housekeeping() -> Result<Vec<HousekeepingItem>, String> {
+    let settings = load_channel_settings();
+    let project_root = get_project_root();
+    let today = chrono::Local::now().date_naive();
+    
+    let mut videos_by_channel: BTreeMap<String, Vec<VideoInfo>> = BTreeMap::new();
+    for video in scan_vault_videos()? {
+        videos_by_channel.entry(video.channel.clone()).or_default().push(video);
+    }
+    
+    let mut items = Vec::new();
+    for (channel, mut videos) in videos_by_channel {
+        let policy = settings.get(&channel).map(|s| s.retention.clone()).unwrap_or_default();
+        // 추모/보관 채널은 더 이상 새 영상이 없으므로 개수 제한으로 지우지 않음
+        let is_frozen = settings
+            .get(&channel)
+            .map(|s| s.memorialized_at.is_some() || s.state == ChannelState::Archived)
+            .unwrap_or(false);
+        
+        // 최신 업로드 순 정렬 (날짜 없는 영상은 가장 오래된 것으로 취급)
+        videos.sort_by(|a, b| b.upload_date.cmp(&a.upload_date));
+        
+        for (index, video) in videos.into_iter().enumerate() {
+            let Some(folder) = project_root.join(&video.video_path).parent().map(Path::to_path_buf) else {
+                continue;
+            };
+            
+            let (action, files): (&str, Vec<PathBuf>) = match policy {
+                RetentionPolicy::KeepAll => continue,
+                RetentionPolicy::KeepLastN { count } => {
+                    if is_frozen || index < count as usize {
+                        continue;
+                    }
+                    ("delete_video", vec![folder.clone()])
+                }
+                RetentionPolicy::DropMediaOlderThan { days } => {
+                    let is_old = video.upload_date
+                        .as_deref()
+                        .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+                        .map(|d| (today - d).num_days() > days as i64)
+                        .unwrap_or(false);
+                    if !is_old {
+                        continue;
+                    }
+                    let media: Vec<PathBuf> = MEDIA_FILE_NAMES.iter().map(|name| folder.join(name)).filter(|f| f.exists()).collect();
+                    if media.is_empty() {
+                        continue;
+                    }
+                    ("delete_media", media)
+                }
+            };
+            
+            let freed_bytes: u64 = files
+                .iter()
+                .map(|f| if f.is_dir() { calculate_directory_size(f) } else { fs::metadata(f).map(|m| m.len()).unwrap_or(0) })
+                .sum();
+            let relative = |p: &Path| p.strip_prefix(&project_root).unwrap_or(p).to_string_lossy().to_string();
+            
+            items.push(HousekeepingItem {
+                channel: channel.clone(),
+                title: video.title,
+                folder: relative(&folder),
+                upload_date: video.upload_date,
+                action: action.to_string(),
+                files: files.iter().map(|f| relative(f)).collect(),
+                freed_mb: bytes_to_mb(freed_bytes),
+            });
+        }
+    }
+    
+    Ok(items)
+}
+
+// 정리 대상을 휴지통으로 이동하고 오류 목록 반환
+fn trash_housekeeping_items(items: &[HousekeepingItem]) -> Vec<String> {
+    let project_root = get_project_root();
+    let mut errors = Vec::new();
+    for item in items {
+        for file in &item.files {
+            // 바로 지우지 않고 휴지통으로 이동 (보관 기간이 지나면 영구 삭제)
+            if let Err(e) = move_to_trash(&project_root.join(file), &format!("housekeeping:{}", item.action)) {
+                errors.push(e);
+            }
+        }
+        // 자막만 남긴 영상은 인덱스에 그대로 두고 미디어 삭제 상태만 반영
+        if item.action == "delete_media" {
+            let video_path = Path::new(&item.folder).join("video.mp4").to_string_lossy().to_string();
+            if let Err(e) = reindex_video(&video_path) {
+                errors.push(e);
+            }
+        }
+    }
+    errors
+}
+
+// 보관 정책 적용 (dry_run이면 삭제 없이 리포트만 생성)
+fn run_housekeeping_job(dry_run: bool) -> Result<HousekeepingReport, String> {
+    let items = plan_housekeeping()?;
+    let errors = if dry_run { Vec::new() } else { trash_housekeeping_items(&items) };
+    
+    let report = HousekeepingReport {
+        dry_run,
+        executed_at: chrono::Local::now().to_rfc3339(),
+        total_freed_mb: items.iter().map(|i| i.freed_mb).sum(),
+        items,
+        errors,
+    };
+    
+    if !dry_run {
+        save_json_config(HOUSEKEEPING_REPORT_FILE, &report)?;
+    }
+    Ok(report)
+}
+
+// 주기적 자동 정리 작업 시작 (정책이 keep_all이면 아무것도 삭제하지 않음)
+fn spawn_housekeeping_scheduler(vault_lock: VaultLockState, jobs: VaultJobState) {
+    thread::spawn(move || loop {
+        thread::sleep(HOUSEKEEPING_INTERVAL);
+        if vault_lock.read_only.load(Ordering::SeqCst) {
+            continue;
+        }
+        // 직접 실행한 정리가 진행 중이면 이번 주기는 건너뜀
+        let Some(_running) = RunningGuard::try_new(&jobs.housekeeping) else {
+            continue;
+        };
+        match run_housekeeping_job(false) {
+            Ok(report) if !report.items.is_empty() => {
+                println!("🧹 자동 정리 완료: {}개 항목, {:.1}MB 확보", report.items.len(), report.total_freed_mb);
+            }
+            Ok(_) => {}
+            Err(e) => eprintln!("❌ 자동 정리 실패: {}", e),
+        }
+        match purge_expired_trash() {
+            Ok(report) if !report.purged.is_empty() => {
+                println!("🗑️ 휴지통 정리 완료: {}개 항목, {:.1}MB 확보", report.purged.len(), report.freed_mb);
+            }
+            Ok(_) => {}
+            Err(e) => eprintln!("❌ 휴지통 정리 실패: {}", e),
+        }
+    });
+}
+
+// 휴지통 (vault/.trash/<항목 ID>/): 삭제/병합으로 지운 파일을 보관 기간 동안 복원 가능하게 유지
+const TRASH_DIR_NAME: &str = ".trash";
+const TRASH_MANIFEST_FILE: &str = "trash.json";
+const TRASH_PAYLOAD_DIR: &str = "payload";
+const TRASH_SETTINGS_FILE: &str = "trash_settings.json";
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+struct TrashSettings {
+    retention_days: u32, // 보관 기간이 지난 항목은 자동 정리 시 영구 삭제 (0이면 바로 삭제)
+}
+
+impl Default for TrashSettings {
+    fn default() -> Self {
+        TrashSettings { retention_days: 30 }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct TrashItem {
+    id: String,
+    original_path: String, // 프로젝트 루트 기준 상대 경로
+    reason: String,        // 예: "housekeeping:delete_video"
+    trashed_at: String,
+    size_mb: f64,
+    #[serde(default)]
+    expires_at: Option<String>, // 목록 조회 시에만 채워짐
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct TrashPurgeReport {
+    purged: Vec<String>,
+    freed_mb: f64,
+    errors: Vec<String>,
+}
+
+fn get_trash_dir() -> PathBuf {
+    get_project_root().join("vault").join(TRASH_DIR_NAME)
+}
+
+#[command]
+fn get_trash_settings() -> Result<TrashSettings, String> {
+    load_json_config(TRASH_SETTINGS_FILE)
+}
+
+#[command]
+fn save_trash_settings(settings: TrashSettings) -> Result<(), String> {
+    save_json_config(TRASH_SETTINGS_FILE, &settings)
+}
+
+// 파일/폴더를 휴지통으로 이동 (같은 볼륨이므로 rename), 항목 ID 반환
+fn move_to_trash(path: &Path, reason: &str) -> Result<String, String> {
+    let project_root = get_project_root();
+    let relative = path.strip_prefix(&project_root).unwrap_or(path).to_string_lossy().to_string();
+    let is_dir = path.is_dir();
+    let size_bytes = if is_dir {
+        calculate_directory_size(&path.to_path_buf())
+    } else {
+        fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+    };
+    
+    let now = chrono::Local::now();
+    let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let mut id = format!("{}-{}", now.format("%Y%m%d%H%M%S"), sanitize_vault_name(&name));
+    let mut suffix = 1;
+    while get_trash_dir().join(&id).exists() {
+        suffix += 1;
+        id = format!("{}-{}-{}", now.format("%Y%m%d%H%M%S"), sanitize_vault_name(&name), suffix);
+    }
+    
+    let item_dir = get_trash_dir().join(&id);
+    let payload_dir = item_dir.join(TRASH_PAYLOAD_DIR);
+    fs::create_dir_all(&payload_dir).map_err(|e| format!("휴지통 폴더 생성 실패: {}", e))?;
+    if let Err(e) = fs::rename(path, payload_dir.join(&name)) {
+        let _ = fs::remove_dir_all(&item_dir);
+        return Err(format!("휴지통으로 이동 실패 {}: {}", relative, e));
+    }
+    // 휴지통으로 옮긴 영상 폴더는 바로 인덱스에서 제거 (복원하면 동기화 때 다시 추가됨)
+    // 미디어 파일만 옮긴 경우는 자막이 남아 있으므로 인덱스 항목 유지
+    if is_dir {
+        if let Err(e) = forget_indexed_videos_under(path) {
+            eprintln!("⚠️ {}", e);
+        }
+    }
+    
+    let item = TrashItem {
+        id: id.clone(),
+        original_path: relative,
+        reason: reason.to_string(),
+        trashed_at: now.to_rfc3339(),
+        size_mb: bytes_to_mb(size_bytes),
+        expires_at: None,
+    };
+    let manifest = serde_json::to_string_pretty(&item).map_err(|e| e.to_string())?;
+    fs::write(item_dir.join(TRASH_MANIFEST_FILE), manifest).map_err(|e| format!("휴지통 항목 기록 실패: {}", e))?;
+    Ok(id)
+}
+
+fn load_trash_items() -> Vec<TrashItem> {
+    let mut items: Vec<TrashItem> = fs::read_dir(get_trash_dir())
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|e| fs::read_to_string(e.path().join(TRASH_MANIFEST_FILE)).ok())
+                .filter_map(|content| serde_json::from_str(&content).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+    items.sort_by(|a, b| b.trashed_at.cmp(&a.trashed_at));
+    items
+}
+
+fn trash_expires_at(item: &TrashItem, retention_days: u32) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    chrono::DateTime::parse_from_rfc3339(&item.trashed_at)
+        .ok()
+        .map(|trashed_at| trashed_at + chrono::Duration::days(retention_days as i64))
+}
+
+// 휴지통 항목 목록 (최근 삭제 순)
+#[command]
+fn list_trash() -> Result<Vec<TrashItem>, String> {
+    let settings: TrashSettings = load_json_config(TRASH_SETTINGS_FILE)?;
+    Ok(load_trash_items()
+        .into_iter()
+        .map(|mut item| {
+            item.expires_at = trash_expires_at(&item, settings.retention_days).map(|t| t.to_rfc3339());
+            item
+        })
+        .collect())
+}
+
+// 휴지통 항목을 원래 위치로 복원 (같은 경로에 파일이 생겼으면 거부)
+#[command]
+fn restore_from_trash(item: String, vault_lock: State<'_, VaultLockState>) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    if item.contains('/') || item.contains('\\') || item.contains("..") {
+        return Err(format!("잘못된 휴지통 항목: {}", item));
+    }
+    let item_dir = get_trash_dir().join(&item);
+    let manifest: TrashItem = fs::read_to_string(item_dir.join(TRASH_MANIFEST_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .ok_or_else(|| format!("휴지통 항목을 찾을 수 없습니다: {}", item))?;
+    
+    let project_root = get_project_root();
+    let target = project_root.join(&manifest.original_path);
+    if !target.starts_with(project_root.join("vault")) || manifest.original_path.contains("..") {
+        return Err(format!("복원 경로가 Vault 밖입니다: {}", manifest.original_path));
+    }
+    if target.exists() {
+        return Err(format!("원래 위치에 이미 파일이 있습니다: {}", manifest.original_path));
+    }
+    let name = target.file_name().ok_or("복원 경로가 올바르지 않습니다")?;
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("복원 폴더 생성 실패: {}", e))?;
+    }
+    fs::rename(item_dir.join(TRASH_PAYLOAD_DIR).join(name), &target)
+        .map_err(|e| format!("복원 실패 {}: {}", manifest.original_path, e))?;
+    let _ = fs::remove_dir_all(&item_dir);
+    
+    // 복원된 영상이 목록/검색에 다시 나타나도록 인덱스 동기화
+    if let Err(e) = reconcile_vault_index() {
+        eprintln!("⚠️ 복원 후 인덱스 동기화 실패: {}", e);
+    }
+    Ok(manifest.original_path)
+}
+
+// 보관 기간이 지난 휴지통 항목 영구 삭제
+fn purge_expired_trash() -> Result<TrashPurgeReport, String> {
+    let settings: TrashSettings = load_json_config(TRASH_SETTINGS_FILE)?;
+    let now = chrono::Local::now();
+    let mut report = TrashPurgeReport::default();
+    
+    for item in load_trash_items() {
+        if trash_expires_at(&item, settings.retention_days).map(|t| t > now).unwrap_or(false) {
+            continue;
+        }
+        match fs::remove_dir_all(get_trash_dir().join(&item.id)) {
+            Ok(()) => {
+                report.freed_mb += item.size_mb;
+                report.purged.push(item.id);
+            }
+            Err(e) => report.errors.push(format!("{} 영구 삭제 실패: {}", item.id, e)),
+        }
+    }
+    Ok(report)
+}
+
+// 메타데이터 일괄 수정 관련 구조체들
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+struct MetadataFilter {
+    channel: Option<String>,
+    video_paths: Option<Vec<String>>,
+    topic: Option<String>,
+    title_contains: Option<String>,
+    upload_from: Option<String>, // YYYY-MM-DD (포함)
+    upload_to: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+struct MetadataPatch {
+    add_topics: Vec<String>,
+    remove_topics: Vec<String>,
+    set_fields: BTreeMap<String, String>, // 예: {"channel": "올바른 채널명"}
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct MetadataChange {
+    video_path: String,
+    captions_path: String,
+    changes: Vec<String>,
+}
+
+// 일괄 수정 대상이 아닌 필드 (앱/인덱스가 관리)
+const PROTECTED_METADATA_FIELDS: &[&str] = &["video_id", "transcript_quality", "published_at"];
+
+fn metadata_filter_matches(video: &VideoInfo, filter: &MetadataFilter) -> bool {
+    if let Some(channel) = &filter.channel {
+        let folder = format!("vault/10_videos/{}/", sanitize_vault_name(channel));
+        if &video.channel != channel && !video.video_path.starts_with(&folder) {
+            return false;
+        }
+    }
+    if let Some(paths) = &filter.video_paths {
+        if !paths.contains(&video.video_path) {
+            return false;
+        }
+    }
+    if let Some(topic) = &filter.topic {
+        if !video.topic.as_ref().map(|t| t.contains(topic)).unwrap_or(false) {
+            return false;
+        }
+    }
+    if let Some(text) = &filter.title_contains {
+        if !video.title.to_lowercase().contains(&text.to_lowercase()) {
+            return false;
+        }
+    }
+    let upload = video.upload_date.as_deref().unwrap_or("");
+    if filter.upload_from.as_deref().map(|from| upload.is_empty() || upload < from).unwrap_or(false) {
+        return false;
+    }
+    if filter.upload_to.as_deref().map(|to| upload.is_empty() || upload > to).unwrap_or(false) {
+        return false;
+    }
+    true
+}
+
+// frontmatter의 필드 줄 교체 (없으면 frontmatter 끝에 추가)
+fn set_frontmatter_line(content: &str, key: &str, line: &str) -> Option<String> {
+    let rest = content.strip_prefix("---")?;
+    let end = rest.find("---")?;
+    let frontmatter = &rest[..end];
+    
+    let mut replaced = false;
+    let mut lines: Vec<String> = frontmatter
+        .lines()
+        .map(|l| match l.split_once(':') {
+            Some((k, _)) if k.trim() == key && !replaced => {
+                replaced = true;
+                line.to_string()
+            }
+            _ => l.to_string(),
+        })
+        .collect();
+    if !replaced {
+        if lines.last().map(|l| l.trim().is_empty()).unwrap_or(false) {
+            lines.pop();
+        }
+        lines.push(line.to_string());
+    }
+    Some(format!("---{}\n---{}", lines.join("\n"), &rest[end + 3..]))
+}
+
+// YAML 스칼라 값 (특수 문자가 있으면 따옴표)
+fn yaml_scalar(value: &str) -> String {
+    if value.is_empty() || value.chars().any(|c| matches!(c, ':' | '#' | '[' | ']' | '{' | '}' | '"' | '\'' | ',')) {
+        yaml_quote(value)
+    } else {
+        value.to_string()
+    }
+}
+
+// 한 파일에 패치 적용 → (새 내용, 변경 내역)
+fn apply_metadata_patch(content: &str, video: &VideoInfo, patch: &MetadataPatch) -> Result<(String, Vec<String>), String> {
+    let mut content = content.to_string();
+    let mut changes = Vec::new();
+    
+    if !patch.add_topics.is_empty() || !patch.remove_topics.is_empty() {
+        let before = video.topic.clone().unwrap_or_default();
+        let mut topics: Vec<String> = before.iter().filter(|t| !patch.remove_topics.contains(t)).cloned().collect();
+        for topic in &patch.add_topics {
+            if !topics.contains(topic) {
+                topics.push(topic.clone());
+            }
+        }
+        if topics != before {
+            // vault_writer.py와 같은 Python 리스트 표기
+            let items: Vec<String> = topics.iter().map(|t| format!("'{}'", t.replace('\'', "\\'"))).collect();
+            content = set_frontmatter_line(&content, "topic", &format!("topic: [{}]", items.join(", ")))
+                .ok_or("frontmatter가 없습니다")?;
+            changes.push(format!("topic: {:?} → {:?}", before, topics));
+        }
+    }
+    
+    for (key, value) in &patch.set_fields {
+        let current = content
+            .strip_prefix("---")
+            .and_then(|rest| rest.find("---").map(|end| &rest[..end]))
+            .and_then(|yaml| extract_yaml_field(yaml, key));
+        if current.as_deref() == Some(value.as_str()) {
+            continue;
+        }
+        content = set_frontmatter_line(&content, key, &format!("{}: {}", key, yaml_scalar(value)))
+            .ok_or("frontmatter가 없습니다")?;
+        changes.push(format!("{}: {} → {}", key, current.unwrap_or_default(), value));
+    }
+    
+    Ok((content, changes))
+}
+
+// 임시 파일에 쓴 뒤 교체 (중간에 중단되어도 원본 보존)
+fn write_file_atomically(path: &Path, content: &str) -> Result<(), String> {
+    let temp_path = path.with_extension("md.tmp");
+    fs::write(&temp_path, content).map_err(|e| format!("임시 파일 저장 실패 {}: {}", temp_path.display(), e))?;
+    fs::rename(&temp_path, path).map_err(|e| {
+        let _ = fs::remove_file(&temp_path);
+        format!("파일 교체 실패 {}: {}", path.display(), e)
+    })
+}
+
+fn plan_metadata_changes(filter: &MetadataFilter, patch: &MetadataPatch) -> Result<Vec<(PathBuf, String, MetadataChange)>, String> {
+    if let Some(key) = patch.set_fields.keys().find(|k| PROTECTED_METADATA_FIELDS.contains(&k.as_str())) {
+        return Err(format!("일괄 수정할 수 없는 필드입니다: {}", key));
+    }
+    
+    let mut planned = Vec::new();
+    for video in scan_vault_videos()?.iter().filter(|v| metadata_filter_matches(v, filter)) {
+        let path = frontmatter_path(video);
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let (new_content, changes) = apply_metadata_patch(&content, video, patch)?;
+        if changes.is_empty() {
+            continue;
+        }
+        let captions_path = path.strip_prefix(get_project_root()).unwrap_or(&path).to_string_lossy().to_string();
+        planned.push((path, new_content, MetadataChange { video_path: video.video_path.clone(), captions_path, changes }));
+    }
+    Ok(planned)
+}
+
+// 메타데이터 일괄 수정 (dry_run이면 변경 예정 내역만 반환, 아니면 백그라운드 실행)
+#[command]
+async fn bulk_update_metadata(
+    window: Window,
+    state: State<'_, MetadataJobState>,
+    vault_lock: State<'_, VaultLockState>,
+    filter: MetadataFilter,
+    patch: MetadataPatch,
+    dry_run: Option<bool>,
+) -> Result<Vec<MetadataChange>, String> {
+    let planned = plan_metadata_changes(&filter, &patch)?;
+    if dry_run.unwrap_or(true) {
+        return Ok(planned.into_iter().map(|(_, _, change)| change).collect());
+    }
+    ensure_vault_writable(&vault_lock)?;
+    
+    if state.is_running.swap(true, Ordering::SeqCst) {
+        return Err("이미 메타데이터 일괄 수정이 진행 중입니다".to_string());
+    }
+    state.is_cancelled.store(false, Ordering::SeqCst);
+    
+    let summary: Vec<MetadataChange> = planned.iter().map(|(_, _, change)| change.clone()).collect();
+    let state = state.inner().clone();
+    let batch_id = new_change_batch_id();
+    thread::spawn(move || {
+        let total = planned.len() as u32;
+        let mut completed = 0u32;
+        let mut failed = 0u32;
+        
+        for (path, content, change) in planned {
+            if state.is_cancelled.load(Ordering::SeqCst) {
+                break;
+            }
+            // 수정한 영상은 바로 인덱스에 반영 (목록/검색이 이전 값을 보여주지 않도록)
+            let written = write_frontmatter_with_journal(&path, &content, "bulk_update_metadata", &batch_id)
+                .and_then(|_| reindex_edited_video(&change.video_path));
+            let log_message = match written {
+                Ok(_) => format!("✏️ {}", change.changes.join(", ")),
+                Err(e) => {
+                    failed += 1;
+                    format!("❌ {}", e)
+                }
+            };
+            completed += 1;
+            let progress = DownloadProgress {
+                channel: "메타데이터 일괄 수정".to_string(),
+                status: "처리 중".to_string(),
+                progress: completed as f32 / total.max(1) as f32 * 100.0,
+                current_video: change.captions_path,
+                total_videos: total,
+                completed_videos: completed,
+                log_message,
+                throughput_bps: None,
+            };
+            let _ = window.emit("bulk-metadata-progress", &progress);
+        }
+        
+        invalidate_autocomplete_index();
+        let cancelled = state.is_cancelled.load(Ordering::SeqCst);
+        let final_progress = DownloadProgress {
+            channel: "메타데이터 일괄 수정".to_string(),
+            status: if cancelled { "중단됨" } else { "완료" }.to_string(),
+            progress: 100.0,
+            current_video: String::new(),
+            total_videos: total,
+            completed_videos: completed,
+            log_message: format!("✅ {}개 파일 수정 ({}개 실패)", completed - failed, failed),
+            throughput_bps: None,
+        };
+        let _ = window.emit("bulk-metadata-progress", &final_progress);
+        state.is_running.store(false, Ordering::SeqCst);
+    });
+    
+    Ok(summary)
+}
+
+#[command]
+fn cancel_bulk_metadata_update(state: State<'_, MetadataJobState>) -> Result<(), String> {
+    state.is_cancelled.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+// 메타데이터 변경 저널 (vault/90_indices/change_journal.jsonl, 추가 전용)
+// frontmatter를 수정하는 모든 경로는 write_frontmatter_with_journal을 거쳐 필드 단위로 기록
+#[derive(Serialize, Deserialize, Clone)]
+struct ChangeJournalEntry {
+    id: String,
+    batch_id: String,
+    timestamp: String,
+    cause: String, // bulk_update_metadata, layout_migration, revert 등
+    video_id: Option<String>,
+    captions_path: String, // 프로젝트 루트 기준
+    field: String,
+    old_value: Option<String>, // None이면 필드가 없었음 (frontmatter 원문 그대로)
+    new_value: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct ChangeBatchSummary {
+    batch_id: String,
+    timestamp: String,
+    cause: String,
+    entries: usize,
+    files: usize,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct RevertReport {
+    reverted: Vec<String>,  // 되돌린 항목 id
+    conflicts: Vec<String>, // 이후에 다시 바뀌어 건너뛴 항목 (id: 사유)
+    batch_id: Option<String>,
+}
+
+static CHANGE_JOURNAL_LOCK: Mutex<()> = Mutex::new(());
+// 같은 배치 안의 항목 id 구분용
+static CHANGE_JOURNAL_SEQ: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+fn get_change_journal_path() -> PathBuf {
+    get_project_root().join("vault").join("90_indices").join("change_journal.jsonl")
+}
+
+fn new_change_batch_id() -> String {
+    chrono::Local::now().format("%Y%m%d-%H%M%S-%3f").to_string()
+}
+
+// frontmatter 최상위 필드 → 원문 값 (목록/따옴표 포함, 줄 단위)
+fn frontmatter_raw_fields(content: &str) -> Vec<(String, String)> {
+    let Some(yaml) = content.strip_prefix("---").and_then(|rest| rest.find("---").map(|end| &rest[..end])) else {
+        return Vec::new();
+    };
+    yaml.lines()
+        .filter(|line| !line.starts_with(' ') && !line.starts_with('\t') && !line.starts_with('-'))
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .filter(|(key, _)| !key.is_empty())
+        .collect()
+}
+
+fn frontmatter_raw_field(content: &str, field: &str) -> Option<String> {
+    frontmatter_raw_fields(content).into_iter().find(|(key, _)| key == field).map(|(_, value)| value)
+}
+
+// frontmatter에서 필드 줄 제거 (되돌리기 시 원래 없던 필드)
+fn remove_frontmatter_line(content: &str, key: &str) -> Option<String> {
+    let rest = content.strip_prefix("---")?;
+    let end = rest.find("---")?;
+    let lines: Vec<&str> = rest[..end]
+        .split('\n')
+        .filter(|l| l.split_once(':').map(|(k, _)| k.trim() != key || l.starts_with(' ')).unwrap_or(true))
+        .collect();
+    Some(format!("---{}---{}", lines.join("\n"), &rest[end + 3..]))
+}
+
+fn append_change_journal(entries: &[ChangeJournalEntry]) -> Result<(), String> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+    let _guard = CHANGE_JOURNAL_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let path = get_change_journal_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("저널 폴더 생성 실패: {}", e))?;
+    }
+    let mut lines = String::new();
+    for entry in entries {
+        lines.push_str(&serde_json::to_string(entry).map_err(|e| e.to_string())?);
+        lines.push('\n');
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("변경 저널 열기 실패: {}", e))?;
+    file.write_all(lines.as_bytes()).map_err(|e| format!("변경 저널 기록 실패: {}", e))
+}
+
+fn load_change_journal() -> Result<Vec<ChangeJournalEntry>, String> {
+    let path = get_change_journal_path();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).map_err(|e| format!("변경 저널 읽기 실패: {}", e))?;
+    // 기록 도중 중단된 마지막 줄 등 깨진 줄은 건너뜀
+    Ok(content.lines().filter_map(|line| serde_json::from_str(line).ok()).collect())
+}
+
+// captions.md를 원자적으로 교체하고 바뀐 frontmatter 필드를 저널에 기록
+fn write_frontmatter_with_journal(path: &Path, content: &str, cause: &str, batch_id: &str) -> Result<usize, String> {
+    let before = fs::read_to_string(path).unwrap_or_default();
+    write_file_atomically(path, content)?;
+    
+    let old_fields = frontmatter_raw_fields(&before);
+    let new_fields = frontmatter_raw_fields(content);
+    let mut seen = std::collections::HashSet::new();
+    let keys: Vec<&String> = old_fields.iter().chain(new_fields.iter()).map(|(key, _)| key).filter(|key| seen.insert(key.as_str())).collect();
+    
+    let lookup = |fields: &[(String, String)], key: &str| fields.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone());
+    let video_id = lookup(&new_fields, "video_id")
+        .or_else(|| lookup(&old_fields, "video_id"))
+        .map(|v| v.trim_matches('"').trim_matches('\'').to_string());
+    let captions_path = path.strip_prefix(get_project_root()).unwrap_or(path).to_string_lossy().to_string();
+    let timestamp = chrono::Local::now().to_rfc3339();
+    
+    let entries: Vec<ChangeJournalEntry> = keys
+        .into_iter()
+        .filter_map(|key| {
+            let old_value = lookup(&old_fields, key);
+            let new_value = lookup(&new_fields, key);
+            (old_value != new_value).then(|| (key.clone(), old_value, new_value))
+        })
+        .map(|(field, old_value, new_value)| ChangeJournalEntry {
+            id: format!("{}-{}", batch_id, CHANGE_JOURNAL_SEQ.fetch_add(1, Ordering::SeqCst)),
+            batch_id: batch_id.to_string(),
+            timestamp: timestamp.clone(),
+            cause: cause.to_string(),
+            video_id: video_id.clone(),
+            captions_path: captions_path.clone(),
+            field,
+            old_value,
+            new_value,
+        })
+        .collect();
+    append_change_journal(&entries)?;
+    Ok(entries.len())
+}
+
+// 저널 항목의 현재 captions.md 위치 (폴더가 옮겨졌으면 인덱스에서 video_id로 찾음)
+fn resolve_journal_captions_path(entry: &ChangeJournalEntry) -> Option<PathBuf> {
+    let project_root = get_project_root();
+    let recorded = project_root.join(&entry.captions_path);
+    if recorded.exists() {
+        return Some(recorded);
+    }
+    let video_id = entry.video_id.as_ref()?;
+    let conn = open_index_db().ok()?;
+    let video_path: String = conn
+        .query_row("SELECT video_path FROM videos WHERE video_id = ?1", rusqlite::params![video_id], |row| row.get(0))
+        .ok()?;
+    let captions_md = project_root.join(video_path).parent()?.join("captions.md");
+    captions_md.exists().then_some(captions_md)
+}
+
+// 영상별 변경 이력 (최신순)
+#[command]
+fn get_change_history(video_id: String) -> Result<Vec<ChangeJournalEntry>, String> {
+    let mut entries: Vec<ChangeJournalEntry> = load_change_journal()?
+        .into_iter()
+        .filter(|e| e.video_id.as_deref() == Some(video_id.as_str()) || e.captions_path == video_id)
+        .collect();
+    entries.reverse();
+    Ok(entries)
+}
+
+// 최근 변경 배치 목록 (일괄 수정 한 번 = 배치 하나)
+#[command]
+fn list_change_batches(limit: Option<usize>) -> Result<Vec<ChangeBatchSummary>, String> {
+    let mut batches: Vec<ChangeBatchSummary> = Vec::new();
+    let mut files: HashMap<String, std::collections::HashSet<String>> = HashMap::new();
+    for entry in load_change_journal()? {
+        files.entry(entry.batch_id.clone()).or_default().insert(entry.captions_path.clone());
+        match batches.iter_mut().find(|b| b.batch_id == entry.batch_id) {
+            Some(batch) => batch.entries += 1,
+            None => batches.push(ChangeBatchSummary {
+                batch_id: entry.batch_id.clone(),
+                timestamp: entry.timestamp.clone(),
+                cause: entry.cause.clone(),
+                entries: 1,
+                files: 0,
+            }),
+        }
+    }
+    for batch in &mut batches {
+        batch.files = files.get(&batch.batch_id).map(|f| f.len()).unwrap_or(0);
+    }
+    batches.reverse();
+    batches.truncate(limit.unwrap_or(50));
+    Ok(batches)
+}
+
+// 선택한 저널 항목(또는 배치 전체)을 이전 값으로 되돌림
+// 이후 다른 변경으로 값이 달라진 필드는 덮어쓰지 않고 conflicts로 보고
+#[command]
+fn revert_changes(
+    vault_lock: State<'_, VaultLockState>,
+    entry_ids: Option<Vec<String>>,
+    batch_id: Option<String>,
+) -> Result<RevertReport, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let entry_ids: std::collections::HashSet<String> = entry_ids.unwrap_or_default().into_iter().collect();
+    if entry_ids.is_empty() && batch_id.is_none() {
+        return Err("되돌릴 항목 또는 배치를 지정하세요".to_string());
+    }
+    let selected: Vec<ChangeJournalEntry> = load_change_journal()?
+        .into_iter()
+        .filter(|e| entry_ids.contains(&e.id) || batch_id.as_deref() == Some(e.batch_id.as_str()))
+        .collect();
+    if selected.is_empty() {
+        return Err("저널에서 해당 변경을 찾을 수 없습니다".to_string());
+    }
+    
+    let mut report = RevertReport { reverted: Vec::new(), conflicts: Vec::new(), batch_id: None };
+    // 파일별로 모아서 최신 변경부터 역순 적용
+    let mut by_file: Vec<(PathBuf, Vec<ChangeJournalEntry>)> = Vec::new();
+    for entry in selected.into_iter().rev() {
+        let Some(path) = resolve_journal_captions_path(&entry) else {
+            report.conflicts.push(format!("{}: 파일을 찾을 수 없음 ({})", entry.id, entry.captions_path));
+            continue;
+        };
+        match by_file.iter_mut().find(|(p, _)| *p == path) {
+            Some((_, entries)) => entries.push(entry),
+            None => by_file.push((path, vec![entry])),
+        }
+    }
+    
+    let revert_batch = new_change_batch_id();
+    for (path, entries) in by_file {
+        let mut content = fs::read_to_string(&path).map_err(|e| format!("{} 읽기 실패: {}", path.display(), e))?;
+        let mut reverted = Vec::new();
+        for entry in entries {
+            if frontmatter_raw_field(&content, &entry.field) != entry.new_value {
+                report.conflicts.push(format!("{}: {} 값이 이후에 다시 변경됨", entry.id, entry.field));
+                continue;
+            }
+            let updated = match &entry.old_value {
+                Some(old) => set_frontmatter_line(&content, &entry.field, &format!("{}: {}", entry.field, old)),
+                None => remove_frontmatter_line(&content, &entry.field),
+            };
+            match updated {
+                Some(updated) => {
+                    content = updated;
+                    reverted.push(entry.id);
+                }
+                None => report.conflicts.push(format!("{}: frontmatter가 없습니다", entry.id)),
+            }
+        }
+        if !reverted.is_empty() {
+            write_frontmatter_with_journal(&path, &content, "revert", &revert_batch)?;
+            report.reverted.extend(reverted);
+        }
+    }
+    
+    if !report.reverted.is_empty() {
+        report.batch_id = Some(revert_batch);
+        // 인덱스도 되돌린 frontmatter 기준으로 갱신
+        reconcile_vault_index()?;
+    }
+    Ok(report)
+}
+
+// vault 폴더 구조 마이그레이션 관련 구조체들
+#[derive(Serialize, Deserialize, Clone)]
+struct LayoutMigrationItem {
+    from: String, // 프로젝트 루트 기준 영상 폴더 경로
+    to: String,
+    reasons: Vec<String>,
+    original_frontmatter: Option<String>, // 되돌리기용 (frontmatter를 수정한 경우만)
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct LayoutMigrationLog {
+    id: String,
+    created_at: String,
+    applied: bool,
+    reverted: bool,
+    items: Vec<LayoutMigrationItem>,
+    skipped: Vec<String>, // 업로드 날짜를 알 수 없거나 대상 폴더가 이미 있는 경우
+}
+
+const MAX_VIDEO_FOLDER_TITLE_CHARS: usize = 50;
+
+fn get_layout_migrations_dir() -> PathBuf {
+    get_project_root().join("vault").join("90_indices").join("layout_migrations")
+}
+
+// "YYYY-MM-DD" 또는 "YYYYMMDD" → "YYYYMMDD"
+fn compact_upload_date(value: &str) -> Option<String> {
+    let digits: String = value.chars().filter(|c| c.is_ascii_digit()).collect();
+    if digits.len() == 8 && chrono::NaiveDate::parse_from_str(&digits, "%Y%m%d").is_ok() {
+        Some(digits)
+    } else {
+        None
+    }
+}
+
+fn split_date_prefix(folder_name: &str) -> Option<(&str, &str)> {
+    let (prefix, rest) = folder_name.split_once('_')?;
+    compact_upload_date(prefix).filter(|_| prefix.len() == 8).map(|_| (prefix, rest))
+}
+
+// 영상 하나의 표준 경로(채널/연도/YYYYMMDD_제목)와 수정할 frontmatter 계산
+fn plan_video_layout(video: &VideoInfo) -> Result<Option<(LayoutMigrationItem, Option<String>)>, String> {
+    let project_root = get_project_root();
+    let folder = match project_root.join(&video.video_path).parent() {
+        Some(f) => f.to_path_buf(),
+        None => return Ok(None),
+    };
+    let relative = folder.strip_prefix(&project_root).unwrap_or(&folder).to_path_buf();
+    let components: Vec<String> = relative.components().map(|c| c.as_os_str().to_string_lossy().to_string()).collect();
+    if components.len() < 4 || components[0] != "vault" || components[1] != "10_videos" {
+        return Ok(None);
+    }
+    let folder_name = components[components.len() - 1].as_str();
+    
+    let upload_date = video
+        .upload_date
+        .as_deref()
+        .and_then(compact_upload_date)
+        .or_else(|| split_date_prefix(folder_name).map(|(d, _)| d.to_string()))
+        .or_else(|| video.published_at.as_deref().and_then(|p| p.get(..10)).and_then(compact_upload_date));
+    let upload_date = match upload_date {
+        Some(d) => d,
+        None => return Err(format!("업로드 날짜를 알 수 없음: {}", relative.display())),
+    };
+    
+    let mut reasons = Vec::new();
+    let title_part = match split_date_prefix(folder_name) {
+        Some((_, rest)) => rest.to_string(),
+        None => {
+            reasons.push("폴더명에 업로드 날짜 접두사 없음".to_string());
+            sanitize_vault_name(folder_name).chars().take(MAX_VIDEO_FOLDER_TITLE_CHARS).collect()
+        }
+    };
+    let canonical_name = format!("{}_{}", upload_date, title_part);
+    if split_date_prefix(folder_name).map(|(d, _)| d != upload_date).unwrap_or(false) {
+        reasons.push("폴더명 날짜가 업로드 날짜와 다름".to_string());
+    }
+    
+    let year = &upload_date[..4];
+    let in_year_folder = components.len() == 5 && components[3] == year;
+    if !in_year_folder {
+        reasons.push("연도 폴더 아래에 있지 않음".to_string());
+    }
+    let target = project_root
+        .join("vault")
+        .join("10_videos")
+        .join(&components[2])
+        .join(year)
+        .join(&canonical_name);
+    
+    // frontmatter 보정 (upload 형식 통일, 누락 필드 보충)
+    let captions_md = folder.join("captions.md");
+    let mut new_content = None;
+    let mut original_frontmatter = None;
+    if let Ok(content) = fs::read_to_string(&captions_md) {
+        let formatted = format!("{}-{}-{}", &upload_date[..4], &upload_date[4..6], &upload_date[6..]);
+        let frontmatter = content.strip_prefix("---").and_then(|r| r.find("---").map(|end| r[..end].to_string()));
+        if let Some(frontmatter) = frontmatter {
+            let mut updated = content.clone();
+            if extract_yaml_field(&frontmatter, "upload").as_deref() != Some(formatted.as_str()) {
+                reasons.push("frontmatter upload 형식 보정".to_string());
+                updated = set_frontmatter_line(&updated, "upload", &format!("upload: {}", formatted)).unwrap_or(updated);
+            }
+            if extract_yaml_field(&frontmatter, "title").map(|t| t.is_empty()).unwrap_or(true) {
+                reasons.push("frontmatter title 보충".to_string());
+                updated = set_frontmatter_line(&updated, "title", &format!("title: {}", yaml_scalar(&video.title))).unwrap_or(updated);
+            }
+            if updated != content {
+                original_frontmatter = Some(frontmatter);
+                new_content = Some(updated);
+            }
+        }
+    }
+    
+    if reasons.is_empty() || (target == folder && new_content.is_none()) {
+        return Ok(None);
+    }
+    let item = LayoutMigrationItem {
+        from: relative.to_string_lossy().to_string(),
+        to: target.strip_prefix(&project_root).unwrap_or(&target).to_string_lossy().to_string(),
+        reasons,
+        original_frontmatter,
+    };
+    Ok(Some((item, new_content)))
+}
+
+// 인덱스 DB의 경로를 폴더 이동에 맞춰 갱신
+fn rewrite_index_paths(tx: &rusqlite::Transaction, from: &str, to: &str) -> Result<(), String> {
+    let from_prefix = format!("{}/", from);
+    let to_prefix = format!("{}/", to);
+    for sql in [
+        "UPDATE videos SET video_path = ?2 || substr(video_path, length(?1) + 1),
+                           captions_path = ?2 || substr(captions_path, length(?1) + 1)
+         WHERE substr(video_path, 1, length(?1)) = ?1",
+        "UPDATE transcripts_fts SET video_path = ?2 || substr(video_path, length(?1) + 1)
+         WHERE substr(video_path, 1, length(?1)) = ?1",
+        "UPDATE playback_log SET video_path = ?2 || substr(video_path, length(?1) + 1)
+         WHERE substr(video_path, 1, length(?1)) = ?1",
+        "UPDATE pipeline_status SET video_path = ?2 || substr(video_path, length(?1) + 1)
+         WHERE substr(video_path, 1, length(?1)) = ?1",
+        "UPDATE media_probes SET video_path = ?2 || substr(video_path, length(?1) + 1)
+         WHERE substr(video_path, 1, length(?1)) = ?1",
+    ] {
+        tx.execute(sql, [&from_prefix, &to_prefix]).map_err(|e| format!("인덱스 경로 갱신 실패: {}", e))?;
+    }
+    Ok(())
+}
+
+// 영상 경로를 키로 저장한 설정(재노출 목록, 재생 대기열, UI 세션)을 폴더 이동에 맞춰 갱신
+fn rewrite_config_paths(queue: &PlayQueueState, from: &str, to: &str) -> Result<(), String> {
+    let from_prefix = format!("{}/", from);
+    let rebase = |path: &str| path.strip_prefix(&from_prefix).map(|rest| format!("{}/{}", to, rest));
+    
+    let items: BTreeMap<String, ResurfaceItem> = load_json_config(RESURFACE_ITEMS_FILE)?;
+    if items.keys().any(|path| rebase(path).is_some()) {
+        let items: BTreeMap<String, ResurfaceItem> = items
+            .into_iter()
+            .map(|(path, item)| (rebase(&path).unwrap_or(path), item))
+            .collect();
+        save_json_config(RESURFACE_ITEMS_FILE, &items)?;
+    }
+    
+    {
+        let mut entries = queue.entries.lock().map_err(|e| e.to_string())?;
+        let mut changed = false;
+        for entry in entries.iter_mut() {
+            if let Some(path) = rebase(&entry.video_path) {
+                entry.video_path = path;
+                changed = true;
+            }
+        }
+        if changed {
+            save_json_config(PLAY_QUEUE_FILE, &*entries)?;
+        }
+    }
+    
+    let mut session: UiSession = load_json_config(UI_SESSION_FILE)?;
+    let last = session.last_video_path.as_deref().and_then(rebase);
+    let anchor = session.scroll_anchor_video_path.as_deref().and_then(rebase);
+    if last.is_some() || anchor.is_some() {
+        session.last_video_path = last.or(session.last_video_path);
+        session.scroll_anchor_video_path = anchor.or(session.scroll_anchor_video_path);
+        save_json_config(UI_SESSION_FILE, &session)?;
+    }
+    Ok(())
+}
+
+fn move_video_folder(project_root: &Path, from: &str, to: &str) -> Result<(), String> {
+    let source = project_root.join(from);
+    let target = project_root.join(to);
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("폴더 생성 실패 {}: {}", parent.display(), e))?;
+    }
+    fs::rename(&source, &target).map_err(|e| format!("폴더 이동 실패 {} → {}: {}", from, to, e))?;
+    // 비게 된 기존 연도 폴더 정리
+    if let Some(parent) = source.parent() {
+        let _ = fs::remove_dir(parent);
+    }
+    Ok(())
+}
+
+fn save_layout_migration_log(log: &LayoutMigrationLog) -> Result<(), String> {
+    let dir = get_layout_migrations_dir();
+    fs::create_dir_all(&dir).map_err(|e| format!("마이그레이션 로그 폴더 생성 실패: {}", e))?;
+    let content = serde_json::to_string_pretty(log).map_err(|e| e.to_string())?;
+    fs::write(dir.join(format!("{}.json", log.id)), content).map_err(|e| format!("마이그레이션 로그 저장 실패: {}", e))
+}
+
+// 예전 폴더 구조(날짜 접두사/연도 폴더 없음)를 표준 구조로 변환 (기본 dry_run, 되돌리기 로그 기록)
+#[command]
+fn migrate_vault_layout(
+    jobs: State<'_, VaultJobState>,
+    vault_lock: State<'_, VaultLockState>,
+    queue: State<'_, PlayQueueState>,
+    dry_run: Option<bool>,
+) -> Result<LayoutMigrationLog, String> {
+    let dry_run = dry_run.unwrap_or(true);
+    let _running = if dry_run {
+        None
+    } else {
+        ensure_vault_writable(&vault_lock)?;
+        Some(RunningGuard::try_new(&jobs.migrating_layout).ok_or("이미 폴더 구조 변환이 진행 중입니다")?)
+    };
+    let project_root = get_project_root();
+    let now = chrono::Local::now();
+    let mut log = LayoutMigrationLog {
+        id: now.format("%Y%m%d_%H%M%S").to_string(),
+        created_at: now.to_rfc3339(),
+        applied: false,
+        reverted: false,
+        items: Vec::new(),
+        skipped: Vec::new(),
+    };
+    
+    let mut planned = Vec::new();
+    let mut targets = std::collections::HashSet::new();
+    for video in scan_vault_videos()? {
+        match plan_video_layout(&video) {
+            Ok(Some((item, new_content))) => {
+                if item.from != item.to && (project_root.join(&item.to).exists() || !targets.insert(item.to.clone())) {
+                    log.skipped.push(format!("대상 폴더가 이미 존재: {} → {}", item.from, item.to));
+                    continue;
+                }
+                planned.push((item, new_content));
+            }
+            Ok(None) => {}
+            Err(e) => log.skipped.push(e),
+        }
+    }
+    
+    if dry_run {
+        log.items = planned.into_iter().map(|(item, _)| item).collect();
+        return Ok(log);
+    }
+    
+    let mut conn = open_index_db()?;
+    let batch_id = new_change_batch_id();
+    for (item, new_content) in planned {
+        if item.from != item.to {
+            move_video_folder(&project_root, &item.from, &item.to)?;
+            let tx = conn.transaction().map_err(|e| e.to_string())?;
+            rewrite_index_paths(&tx, &item.from, &item.to)?;
+            tx.commit().map_err(|e| format!("인덱스 경로 갱신 커밋 실패: {}", e))?;
+            rewrite_config_paths(&queue, &item.from, &item.to)?;
+        }
+        if let Some(content) = &new_content {
+            write_frontmatter_with_journal(&project_root.join(&item.to).join("captions.md"), content, "layout_migration", &batch_id)?;
+        }
+        log.items.push(item);
+        // 중간에 실패해도 여기까지의 변경은 되돌릴 수 있도록 매번 기록
+        log.applied = true;
+        save_layout_migration_log(&log)?;
+    }
+    Ok(log)
+}
+
+#[command]
+fn list_layout_migrations() -> Result<Vec<LayoutMigrationLog>, String> {
+    let dir = get_layout_migrations_dir();
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut logs: Vec<LayoutMigrationLog> = fs::read_dir(&dir)
+        .map_err(|e| e.to_string())?
+        .flatten()
+        .filter_map(|e| fs::read_to_string(e.path()).ok())
+        .filter_map(|c| serde_json::from_str(&c).ok())
+        .collect();
+    logs.sort_by(|a, b| b.id.cmp(&a.id));
+    Ok(logs)
+}
+
+// 마이그레이션 로그를 역순으로 되돌림 (폴더 위치, frontmatter, 인덱스 경로)
+#[command]
+fn revert_vault_layout_migration(
+    jobs: State<'_, VaultJobState>,
+    vault_lock: State<'_, VaultLockState>,
+    queue: State<'_, PlayQueueState>,
+    migration_id: String,
+) -> Result<LayoutMigrationLog, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let _running = RunningGuard::try_new(&jobs.migrating_layout).ok_or("이미 폴더 구조 변환이 진행 중입니다")?;
+    let log_path = get_layout_migrations_dir().join(format!("{}.json", migration_id));
+    let content = fs::read_to_string(&log_path).map_err(|_| format!("마이그레이션 로그를 찾을 수 없습니다: {}", migration_id))?;
+    let mut log: LayoutMigrationLog = serde_json::from_str(&content).map_err(|e| format!("마이그레이션 로그 파싱 실패: {}", e))?;
+    if log.reverted {
+        return Err("이미 되돌린 마이그레이션입니다".to_string());
+    }
+    
+    let project_root = get_project_root();
+    let mut conn = open_index_db()?;
+    let batch_id = new_change_batch_id();
+    for item in log.items.iter().rev() {
+        if item.from != item.to {
+            move_video_folder(&project_root, &item.to, &item.from)?;
+            let tx = conn.transaction().map_err(|e| e.to_string())?;
+            rewrite_index_paths(&tx, &item.to, &item.from)?;
+            tx.commit().map_err(|e| format!("인덱스 경로 갱신 커밋 실패: {}", e))?;
+            rewrite_config_paths(&queue, &item.to, &item.from)?;
+        }
+        if let Some(original) = &item.original_frontmatter {
+            let captions_md = project_root.join(&item.from).join("captions.md");
+            let current = fs::read_to_string(&captions_md).map_err(|e| format!("{} 읽기 실패: {}", captions_md.display(), e))?;
+            if let Some(body) = current.strip_prefix("---").and_then(|r| r.find("---").map(|end| &r[end..])) {
+                write_frontmatter_with_journal(&captions_md, &format!("---{}{}", original, body), "layout_migration_revert", &batch_id)?;
+            }
+        }
+    }
+    
+    log.reverted = true;
+    save_layout_migration_log(&log)?;
+    Ok(log)
+}
+
+// 영상 노트 템플릿 (Tera 문법, set_note_template으로 채널별 지정)
+const VIDEO_NOTE_FILE: &str = "note.md";
+const DEFAULT_NOTE_TEMPLATE: &str = r#"---
+title: {{ title | json_encode() }}
+type: video-note
+channel: {{ channel | json_encode() }}
+video_id: {{ video_id | json_encode() }}
+upload: {{ upload_date }}
+source_url: {{ source_url }}
+topic: {{ topic | json_encode() }}
+created_date: {{ created_date }}
+---
+
+# {{ title }}
+
+![[{{ video_file }}]]
+
+## 📝 자막
+
+![[{{ captions_note }}]]
+
+## 💭 내 노트
+
+"#;
+
+// 템플릿 렌더링 컨텍스트 (captions.md frontmatter 기준)
+fn video_note_context(folder: &Path) -> Result<tera::Context, String> {
+    let captions_md = folder.join("captions.md");
+    let metadata = parse_markdown_metadata(&captions_md)?;
+    let vault_root = get_project_root().join("vault");
+    let vault_relative = |p: &Path| p.strip_prefix(&vault_root).unwrap_or(p).to_string_lossy().to_string();
+    
+    let mut context = tera::Context::new();
+    context.insert("title", &metadata.title);
+    context.insert("channel", &metadata.channel);
+    context.insert("video_id", &metadata.video_id.unwrap_or_default());
+    context.insert("upload_date", &metadata.upload_date.unwrap_or_default());
+    context.insert("published_at", &metadata.published_at.unwrap_or_default());
+    context.insert("duration", &metadata.duration.unwrap_or_default());
+    context.insert("duration_seconds", &metadata.duration_seconds.unwrap_or(0));
+    context.insert("view_count", &metadata.view_count.unwrap_or(0));
+    context.insert("topic", &metadata.topic.unwrap_or_default());
+    context.insert("source_url", &metadata.source_url.unwrap_or_default());
+    context.insert("excerpt", &metadata.excerpt.unwrap_or_default());
+    // Obsidian 링크용 vault 기준 경로
+    context.insert("video_file", &vault_relative(&folder.join("video.mp4")));
+    context.insert("captions_note", vault_relative(&captions_md).trim_end_matches(".md"));
+    context.insert("folder", &vault_relative(folder));
+    context.insert("created_date", &chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string());
+    Ok(context)
+}
+
+fn render_video_note(folder: &Path, template: &str) -> Result<(), String> {
+    let context = video_note_context(folder)?;
+    let note = tera::Tera::one_off(template, &context, false).map_err(|e| format!("노트 템플릿 렌더링 실패: {}", e))?;
+    fs::write(folder.join(VIDEO_NOTE_FILE), note).map_err(|e| format!("노트 저장 실패: {}", e))
+}
+
+fn collect_folders_missing_note(dir: &Path, folders: &mut Vec<PathBuf>) {
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                collect_folders_missing_note(&path, folders);
+            } else if path.file_name().map(|n| n == "captions.md").unwrap_or(false) {
+                if let Some(folder) = path.parent() {
+                    if !folder.join(VIDEO_NOTE_FILE).exists() {
+                        folders.push(folder.to_path_buf());
+                    }
+                }
+            }
+        }
+    }
+}
+
+// 템플릿이 지정된 채널의 새 영상에 노트 생성 (기존 노트는 덮어쓰지 않음)
+fn render_missing_video_notes() -> u32 {
+    let mut rendered = 0;
+    for (channel, settings) in load_channel_settings() {
+        let Some(template) = settings.note_template.filter(|t| !t.trim().is_empty()) else {
+            continue;
+        };
+        let mut folders = Vec::new();
+        collect_folders_missing_note(&channel_vault_dir(&channel), &mut folders);
+        for folder in folders {
+            match render_video_note(&folder, &template) {
+                Ok(()) => rendered += 1,
+                Err(e) => eprintln!("⚠️ 영상 노트 생성 실패 {}: {}", folder.display(), e),
+            }
+        }
+    }
+    if rendered > 0 {
+        println!("📝 영상 노트 {}개 생성", rendered);
+    }
+    rendered
+}
+
+// 채널 노트 템플릿 설정 (빈 문자열이면 해제)
+#[command]
+fn set_note_template(vault_lock: State<'_, VaultLockState>, channel: String, template: String) -> Result<(), String> {
+    ensure_vault_writable(&vault_lock)?;
+    if !template.trim().is_empty() {
+        tera::Tera::default()
+            .add_raw_template("note", &template)
+            .map_err(|e| format!("템플릿 문법 오류: {}", e))?;
+    }
+    
+    let mut settings = load_channel_settings();
+    settings.entry(channel).or_default().note_template = Some(template).filter(|t| !t.trim().is_empty());
+    save_channel_settings(&settings)
+}
+
+// 채널 노트 템플릿 조회 (미지정 시 기본 템플릿)
+#[command]
+fn get_note_template(channel: String) -> Result<String, String> {
+    Ok(load_channel_settings()
+        .get(&channel)
+        .and_then(|s| s.note_template.clone())
+        .unwrap_or_else(|| DEFAULT_NOTE_TEMPLATE.to_string()))
+}
+
+// 기존 영상에도 노트 생성
+#[command]
+fn render_video_notes(vault_lock: State<'_, VaultLockState>) -> Result<u32, String> {
+    ensure_vault_writable(&vault_lock)?;
+    Ok(render_missing_video_notes())
+}
+
+// 채널 보관 정책 설정
+#[command]
+fn set_channel_retention_policy(channel_name: String, policy: RetentionPolicy) -> Result<(), String> {
+    match policy {
+        RetentionPolicy::KeepLastN { count: 0 } => return Err("보관할 영상 수는 1개 이상이어야 합니다".to_string()),
+        RetentionPolicy::DropMediaOlderThan { days: 0 } => return Err("보관 기간은 1일 이상이어야 합니다".to_string()),
+        _ => {}
+    }
+    
+    let mut settings = load_channel_settings();
+    settings.entry(channel_name).or_default().retention = policy;
+    save_channel_settings(&settings)
+}
+
+// 정리 대상 미리보기 (삭제하지 않음)
+#[command]
+fn preview_housekeeping() -> Result<HousekeepingReport, String> {
+    run_housekeeping_job(true)
+}
+
+// 보관 정책 즉시 적용
+#[command]
+fn run_housekeeping(jobs: State<'_, VaultJobState>, vault_lock: State<'_, VaultLockState>) -> Result<HousekeepingReport, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let _running = RunningGuard::try_new(&jobs.housekeeping).ok_or("이미 보관 정책 정리가 진행 중입니다")?;
+    run_housekeeping_job(false)
+}
+
+// 다운로드 정책 밖의 영상 정리 (기본 dry_run, 실제 삭제는 휴지통으로 이동)
+#[command]
+fn prune_channel_policy(
+    jobs: State<'_, VaultJobState>,
+    vault_lock: State<'_, VaultLockState>,
+    channel: Option<String>,
+    dry_run: Option<bool>,
+) -> Result<HousekeepingReport, String> {
+    let dry_run = dry_run.unwrap_or(true);
+    let _running = if dry_run {
+        None
+    } else {
+        ensure_vault_writable(&vault_lock)?;
+        Some(RunningGuard::try_new(&jobs.housekeeping).ok_or("이미 보관 정책 정리가 진행 중입니다")?)
+    };
+    let items = plan_policy_prune(channel.as_deref())?;
+    let errors = if dry_run { Vec::new() } else { trash_housekeeping_items(&items) };
+    if !dry_run && !items.is_empty() {
+        reconcile_vault_index()?;
+    }
+    Ok(HousekeepingReport {
+        dry_run,
+        executed_at: chrono::Local::now().to_rfc3339(),
+        total_freed_mb: items.iter().map(|i| i.freed_mb).sum(),
+        items,
+        errors,
+    })
+}
+
+// 마지막 정리 실행 결과 조회
+#[command]
+fn get_last_housekeeping_report() -> Result<Option<HousekeepingReport>, String> {
+    if !get_config_file_path(HOUSEKEEPING_REPORT_FILE).exists() {
+        return Ok(None);
+    }
+    load_json_config(HOUSEKEEPING_REPORT_FILE).map(Some)
+}
+
+// 다운로드 검증 (영상 길이 비교) 관련 구조체들
+#[derive(Serialize, Deserialize, Clone)]
+struct IncompleteVideo {
+    channel: String,
+    title: String,
+    video_id: Option<String>,
+    video_path: String,
+    expected_seconds: u32,
+    actual_seconds: Option<f64>,  // None이면 ffprobe로 읽을 수 없는 파일
+    detected_at: String,
+    redownload_scheduled: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+struct DownloadVerificationReport {
+    last_verified_at: Option<String>,
+    checked_videos: u32,
+    incomplete: Vec<IncompleteVideo>,
+}
+
+const DOWNLOAD_VERIFICATION_FILE: &str = "incomplete_videos.json";
+// 허용 오차: 5초 또는 전체 길이의 2% 중 큰 값
+const DURATION_TOLERANCE_SECONDS: f64 = 5.0;
+const DURATION_TOLERANCE_RATIO: f64 = 0.02;
+
+// ffprobe로 로컬 영상 길이(초) 조회
+fn probe_duration_seconds(path: &Path) -> Option<f64> {
+    let output = Command::new("ffprobe")
+        .args(["-v", "error", "-show_entries", "format=duration", "-of", "default=noprint_wrappers=1:nokey=1"])
+        .arg(path)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout).trim().parse::<f64>().ok()
+}
+
+// 다운로드 아카이브에서 영상 ID를 제거하여 다음 다운로드 때 다시 받도록 예약
+fn unarchive_video_id(video_id: &str) -> bool {
+    let downloads_dir = get_project_root().join("vault").join("downloads");
+    let Ok(entries) = fs::read_dir(&downloads_dir) else {
+        return false;
+    };
+    
+    let archive_line = format!("youtube {}", video_id);
+    let mut removed = false;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let is_archive = path.file_name().map(|n| n.to_string_lossy().ends_with("_downloaded.txt")).unwrap_or(false);
+        if !is_archive {
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        if !content.lines().any(|line| line.trim() == archive_line) {
+            continue;
+        }
+        
+        let remaining: String = content
+            .lines()
+            .filter(|line| line.trim() != archive_line)
+            .map(|line| format!("{}\n", line))
+            .collect();
+        if fs::write(&path, remaining).is_ok() {
+            removed = true;
+        }
+    }
+    removed
+}
+
+// 메타데이터 길이와 실제 파일 길이를 비교 (full=false면 마지막 검증 이후 받은 영상만)
+fn verify_downloads(full: bool) -> Result<DownloadVerificationReport, String> {
+    let mut report: DownloadVerificationReport = load_json_config(DOWNLOAD_VERIFICATION_FILE)?;
+    let project_root = get_project_root();
+    
+    let since = if full {
+        report.incomplete.clear();
+        None
+    } else {
+        report.last_verified_at
+            .as_deref()
+            .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+            .map(|t| t.with_timezone(&chrono::Local))
+    };
+    
+    let mut checked = 0u32;
+    for video in scan_vault_videos()? {
+        let Some(expected) = video.duration_seconds.filter(|d| *d > 0) else {
+            continue;
+        };
+        let video_file = project_root.join(&video.video_path);
+        let Ok(modified) = fs::metadata(&video_file).and_then(|m| m.modified()) else {
+            continue;
+        };
+        if let Some(since) = since {
+            if chrono::DateTime::<chrono::Local>::from(modified) <= since {
+                continue;
+            }
+        }
+        
+        checked += 1;
+        report.incomplete.retain(|v| v.video_path != video.video_path);
+        
+        let actual = probe_duration_seconds(&video_file);
+        let tolerance = DURATION_TOLERANCE_SECONDS.max(expected as f64 * DURATION_TOLERANCE_RATIO);
+        let is_truncated = actual.map(|a| (expected as f64 - a).abs() > tolerance).unwrap_or(true);
+        if !is_truncated {
+            continue;
+        }
+        
+        let redownload_scheduled = video.video_id.as_deref().map(unarchive_video_id).unwrap_or(false);
+        report.incomplete.push(IncompleteVideo {
+            channel: video.channel,
+            title: video.title,
+            video_id: video.video_id,
+            video_path: video.video_path,
+            expected_seconds: expected,
+            actual_seconds: actual,
+            detected_at: chrono::Local::now().to_rfc3339(),
+            redownload_scheduled,
+        });
+    }
+    
+    // 재다운로드로 정상 파일이 된 항목은 위에서 제거되고, 삭제된 영상은 목록에서 정리
+    report.incomplete.retain(|v| project_root.join(&v.video_path).exists());
+    report.checked_videos = checked;
+    report.last_verified_at = Some(chrono::Local::now().to_rfc3339());
+    save_json_config(DOWNLOAD_VERIFICATION_FILE, &report)?;
+    Ok(report)
+}
+
+// 배치 다운로드 후 백그라운드 검증
+fn spawn_download_verification(window: &Window) {
+    let window = window.clone();
+    thread::spawn(move || match verify_downloads(false) {
+        Ok(report) => {
+            if !report.incomplete.is_empty() {
+                let _ = window.emit("download-verification", &report);
+            }
+        }
+        Err(e) => eprintln!("❌ 다운로드 검증 실패: {}", e),
+    });
+}
+
+// 다운로드된 영상 길이 검증 실행
+#[command]
+async fn verify_downloaded_videos(vault_lock: State<'_, VaultLockState>, full: Option<bool>) -> Result<DownloadVerificationReport, String> {
+    ensure_vault_writable(&vault_lock)?;
+    verify_downloads(full.unwrap_or(false))
+}
+
+// 불완전(잘린) 영상 목록 조회
+#[command]
+fn get_incomplete_videos() -> Result<Vec<IncompleteVideo>, String> {
+    let report: DownloadVerificationReport = load_json_config(DOWNLOAD_VERIFICATION_FILE)?;
+    Ok(report.incomplete)
+}
+
+// 영상 파일 기술 정보 (ffprobe 일괄 수집, 인덱스 media_probes 테이블)
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+struct MediaProbe {
+    video_codec: Option<String>,
+    width: Option<u32>,
+    height: Option<u32>,
+    fps: Option<f64>,
+    bitrate: Option<u64>,
+    audio_codec: Option<String>,
+    audio_channels: Option<u32>,
+    duration_seconds: Option<f64>,
+    error: Option<String>,
+}
+
+#[derive(Serialize, Clone)]
+struct MediaProbeEntry {
+    video_path: String,
+    title: Option<String>,
+    channel: Option<String>,
+    probed_at: String,
+    #[serde(flatten)]
+    probe: MediaProbe,
+}
+
+// 기술 정보 필터 (예: video_codec="vp9", below_height=720 → 720p 미만)
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct MediaProbeFilter {
+    video_codec: Option<String>,
+    audio_codec: Option<String>,
+    below_height: Option<u32>,
+    min_height: Option<u32>,
+    audio_channels: Option<u32>,
+    errors_only: bool, // ffprobe로 읽지 못한 파일만
+}
+
+#[derive(Serialize, Clone)]
+struct MediaProbeProgress {
+    probed: u32,
+    total: u32,
+    current: String,
+    done: bool,
+}
+
+#[derive(Default, Clone)]
+struct MediaProbeState {
+    running: Arc<AtomicBool>,
+}
+
+// "30000/1001" 형식의 프레임 레이트
+fn parse_frame_rate(rate: &str) -> Option<f64> {
+    let (num, den) = rate.split_once('/').unwrap_or((rate, "1"));
+    let (num, den) = (num.parse::<f64>().ok()?, den.parse::<f64>().ok()?);
+    (num > 0.0 && den > 0.0).then(|| (num / den * 1000.0).round() / 1000.0)
+}
+
+fn probe_media(path: &Path) -> MediaProbe {
+    let output = match Command::new("ffprobe")
+        .args(["-v", "error", "-print_format", "json", "-show_streams", "-show_format"])
+        .arg(path)
+        .output()
+    {
+        Ok(output) => output,
+        Err(e) => return MediaProbe { error: Some(format!("ffprobe 실행 실패: {}", e)), ..Default::default() },
+    };
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return MediaProbe { error: Some(if stderr.is_empty() { "ffprobe 실패".to_string() } else { stderr }), ..Default::default() };
+    }
+    let Ok(data) = serde_json::from_slice::<serde_json::Value>(&output.stdout) else {
+        return MediaProbe { error: Some("ffprobe 출력 파싱 실패".to_string()), ..Default::default() };
+    };
+    
+    let streams = data["streams"].as_array().cloned().unwrap_or_default();
+    let stream = |kind: &str| streams.iter().find(|s| s["codec_type"] == kind);
+    let number = |value: &serde_json::Value| value.as_str().and_then(|v| v.parse::<f64>().ok()).or_else(|| value.as_f64());
+    let video = stream("video");
+    let audio = stream("audio");
+    MediaProbe {
+        video_codec: video.and_then(|v| v["codec_name"].as_str()).map(str::to_lowercase),
+        width: video.and_then(|v| v["width"].as_u64()).map(|w| w as u32),
+        height: video.and_then(|v| v["height"].as_u64()).map(|h| h as u32),
+        fps: video.and_then(|v| v["avg_frame_rate"].as_str().and_then(parse_frame_rate).or_else(|| v["r_frame_rate"].as_str().and_then(parse_frame_rate))),
+        bitrate: number(&data["format"]["bit_rate"]).map(|b| b as u64),
+        audio_codec: audio.and_then(|a| a["codec_name"].as_str()).map(str::to_lowercase),
+        audio_channels: audio.and_then(|a| a["channels"].as_u64()).map(|c| c as u32),
+        duration_seconds: number(&data["format"]["duration"]),
+        error: None,
+    }
+}
+
+fn file_size_and_mtime(path: &Path) -> Option<(i64, i64)> {
+    let metadata = fs::metadata(path).ok()?;
+    let mtime = metadata.modified().ok()?.duration_since(std::time::UNIX_EPOCH).ok()?.as_secs() as i64;
+    Some((metadata.len() as i64, mtime))
+}
+
+fn media_probe_from_row(row: &rusqlite::Row, offset: usize) -> rusqlite::Result<MediaProbe> {
+    Ok(MediaProbe {
+        video_codec: row.get(offset)?,
+        width: row.get(offset + 1)?,
+        height: row.get(offset + 2)?,
+        fps: row.get(offset + 3)?,
+        bitrate: row.get::<_, Option<i64>>(offset + 4)?.map(|b| b as u64),
+        audio_codec: row.get(offset + 5)?,
+        audio_channels: row.get(offset + 6)?,
+        duration_seconds: row.get(offset + 7)?,
+        error: row.get(offset + 8)?,
+    })
+}
+
+const MEDIA_PROBE_COLUMNS: &str = "video_codec, width, height, fps, bitrate, audio_codec, audio_channels, duration_seconds, error";
+
+// 파일이 바뀌지 않았으면 인덱스에 저장된 기술 정보 (변환/업그레이드는 파일을 직접 읽지 않고 이 값을 사용)
+fn cached_media_probe(conn: &rusqlite::Connection, video_path: &str) -> Option<MediaProbe> {
+    let (size, mtime) = file_size_and_mtime(&get_project_root().join(video_path))?;
+    conn.query_row(
+        &format!("SELECT {} FROM media_probes WHERE video_path = ?1 AND file_size = ?2 AND file_mtime = ?3", MEDIA_PROBE_COLUMNS),
+        rusqlite::params![video_path, size, mtime],
+        |row| media_probe_from_row(row, 0),
+    )
+    .ok()
+}
+
+// 새로 받았거나 바뀐 영상만 ffprobe로 읽어 인덱스에 저장 (삭제된 영상의 정보는 정리)
+fn harvest_media_probes(window: Option<&Window>) -> Result<u32, String> {
+    let project_root = get_project_root();
+    let conn = open_index_db()?;
+    let mut known: HashMap<String, (i64, i64)> = HashMap::new();
+    {
+        let mut stmt = conn
+            .prepare("SELECT video_path, file_size, file_mtime FROM media_probes")
+            .map_err(|e| format!("기술 정보 조회 실패: {}", e))?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, (row.get::<_, i64>(1)?, row.get::<_, i64>(2)?))))
+            .map_err(|e| format!("기술 정보 조회 실패: {}", e))?;
+        known.extend(rows.flatten());
+    }
+    
+    let videos = scan_vault_videos()?;
+    let pending: Vec<(String, (i64, i64))> = videos
+        .iter()
+        .filter_map(|v| file_size_and_mtime(&project_root.join(&v.video_path)).map(|stat| (v.video_path.clone(), stat)))
+        .filter(|(path, stat)| known.get(path) != Some(stat))
+        .collect();
+    
+    let total = pending.len() as u32;
+    let mut probed = 0u32;
+    for (video_path, (size, mtime)) in pending {
+        let probe = probe_media(&project_root.join(&video_path));
+        conn.execute(
+            "INSERT OR REPLACE INTO media_probes (video_path, file_size, file_mtime, video_codec, width, height, fps, bitrate,
+                audio_codec, audio_channels, duration_seconds, error, probed_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+            rusqlite::params![
+                video_path, size, mtime, probe.video_codec, probe.width, probe.height, probe.fps,
+                probe.bitrate.map(|b| b as i64), probe.audio_codec, probe.audio_channels, probe.duration_seconds,
+                probe.error, chrono::Local::now().to_rfc3339(),
+            ],
+        )
+        .map_err(|e| format!("기술 정보 저장 실패: {}", e))?;
+        probed += 1;
+        if let Some(window) = window {
+            let _ = window.emit("media-probe-progress", &MediaProbeProgress { probed, total, current: video_path, done: false });
+        }
+    }
+    
+    let existing: std::collections::HashSet<&str> = videos.iter().map(|v| v.video_path.as_str()).collect();
+    for stale in known.keys().filter(|path| !existing.contains(path.as_str())) {
+        let _ = conn.execute("DELETE FROM media_probes WHERE video_path = ?1", [stale]);
+    }
+    if let Some(window) = window {
+        let _ = window.emit("media-probe-progress", &MediaProbeProgress { probed, total, current: String::new(), done: true });
+    }
+    Ok(probed)
+}
+
+// 백그라운드 기술 정보 수집 (이미 실행 중이면 false)
+fn spawn_media_probe_harvest(window: &Window, state: &MediaProbeState) -> bool {
+    if state.running.swap(true, Ordering::SeqCst) {
+        return false;
+    }
+    let window = window.clone();
+    let running = state.running.clone();
+    thread::spawn(move || {
+        match harvest_media_probes(Some(&window)) {
+            Ok(count) if count > 0 => println!("🎞️ 영상 기술 정보 수집: {}개", count),
+            Ok(_) => {}
+            Err(e) => eprintln!("❌ 영상 기술 정보 수집 실패: {}", e),
+        }
+        running.store(false, Ordering::SeqCst);
+    });
+    true
+}
+
+// 영상 기술 정보 수집 시작 (진행 상황은 media-probe-progress 이벤트)
+#[command]
+fn start_media_probe(window: Window, state: State<'_, MediaProbeState>, vault_lock: State<'_, VaultLockState>) -> Result<bool, String> {
+    ensure_vault_writable(&vault_lock)?;
+    Ok(spawn_media_probe_harvest(&window, &state))
+}
+
+// 영상 하나의 기술 정보 (수집 전이거나 파일이 바뀌었으면 None)
+#[command]
+fn get_media_probe(video_path: String) -> Result<Option<MediaProbe>, String> {
+    let conn = open_index_db()?;
+    Ok(cached_media_probe(&conn, &video_path))
+}
+
+// 기술 정보로 영상 찾기 (예: VP9 전체, 720p 미만)
+#[command]
+fn find_videos_by_media(filter: MediaProbeFilter) -> Result<Vec<MediaProbeEntry>, String> {
+    let conn = open_index_db()?;
+    let mut clauses: Vec<&str> = Vec::new();
+    let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+    if let Some(codec) = filter.video_codec.map(|c| c.trim().to_lowercase()).filter(|c| !c.is_empty()) {
+        clauses.push("p.video_codec = ?");
+        params.push(Box::new(codec));
+    }
+    if let Some(codec) = filter.audio_codec.map(|c| c.trim().to_lowercase()).filter(|c| !c.is_empty()) {
+        clauses.push("p.audio_codec = ?");
+        params.push(Box::new(codec));
+    }
+    if let Some(height) = filter.below_height {
+        clauses.push("p.height < ?");
+        params.push(Box::new(height));
+    }
+    if let Some(height) = filter.min_height {
+        clauses.push("p.height >= ?");
+        params.push(Box::new(height));
+    }
+    if let Some(channels) = filter.audio_channels {
+        clauses.push("p.audio_channels = ?");
+        params.push(Box::new(channels));
+    }
+    if filter.errors_only {
+        clauses.push("p.error IS NOT NULL");
+    }
+    let where_clause = if clauses.is_empty() { String::new() } else { format!("WHERE {}", clauses.join(" AND ")) };
+    let sql = format!(
+        "SELECT p.video_path, v.title, v.channel, p.probed_at, {} FROM media_probes p
+         LEFT JOIN videos v ON v.video_path = p.video_path {} ORDER BY v.channel, v.upload_date DESC",
+        MEDIA_PROBE_COLUMNS.split(", ").map(|c| format!("p.{}", c)).collect::<Vec<_>>().join(", "),
+        where_clause
+    );
+    let mut stmt = conn.prepare(&sql).map_err(|e| format!("기술 정보 검색 실패: {}", e))?;
+    let rows = stmt
+        .query_map(rusqlite::params_from_iter(params.iter().map(|p| p.as_ref())), |row| {
+            Ok(MediaProbeEntry {
+                video_path: row.get(0)?,
+                title: row.get(1)?,
+                channel: row.get(2)?,
+                probed_at: row.get(3)?,
+                probe: media_probe_from_row(row, 4)?,
+            })
+        })
+        .map_err(|e| format!("기술 정보 검색 실패: {}", e))?;
+    Ok(rows.flatten().collect())
+}
+
+// 벡터 인덱스 (Chroma) 스냅샷 관련 구조체들
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+struct IndexBackupSettings {
+    enabled: bool,
+    interval_hours: u64,
+    keep_snapshots: usize,
+}
+
+impl Default for IndexBackupSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_hours: 24,
+            keep_snapshots: 7,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct IndexSnapshot {
+    name: String,
+    created_at: String,
+    size_mb: f64,
+}
+
+const INDEX_BACKUP_SETTINGS_FILE: &str = "index_backup.json";
+const INDEX_SNAPSHOT_PREFIX: &str = "chroma_";
+const INDEX_SNAPSHOT_EXT: &str = ".tar.gz";
+// 스냅샷 필요 여부 확인 주기
+const INDEX_BACKUP_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+fn get_chroma_path() -> PathBuf {
+    get_project_root().join("vault").join("90_indices").join("chroma")
+}
+
+fn get_index_backups_dir() -> PathBuf {
+    get_project_root().join("vault").join("90_indices").join("backups")
+}
+
+// 스냅샷 목록 (최신순)
+fn collect_index_snapshots() -> Vec<IndexSnapshot> {
+    let Ok(entries) = fs::read_dir(get_index_backups_dir()) else {
+        return Vec::new();
+    };
+    
+    let mut snapshots: Vec<IndexSnapshot> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !name.starts_with(INDEX_SNAPSHOT_PREFIX) || !name.ends_with(INDEX_SNAPSHOT_EXT) {
+                return None;
+            }
+            let metadata = entry.metadata().ok()?;
+            let created_at = metadata
+                .modified()
+                .map(|t| chrono::DateTime::<chrono::Local>::from(t).to_rfc3339())
+                .unwrap_or_default();
+            Some(IndexSnapshot {
+                name,
+                created_at,
+                size_mb: bytes_to_mb(metadata.len()),
+            })
+        })
+        .collect();
+    // 파일명에 타임스탬프가 포함되어 있으므로 이름 역순 = 최신순
+    snapshots.sort_by(|a, b| b.name.cmp(&a.name));
+    snapshots
+}
+
+// chroma 디렉토리를 tar.gz로 스냅샷 후 보관 정책에 따라 오래된 스냅샷 정리
+fn create_index_snapshot(keep_snapshots: usize) -> Result<IndexSnapshot, String> {
+    let chroma_path = get_chroma_path();
+    if !chroma_path.exists() {
+        return Err("벡터 인덱스가 없습니다. 먼저 임베딩을 생성하세요".to_string());
+    }
+    
+    let backups_dir = get_index_backups_dir();
+    fs::create_dir_all(&backups_dir).map_err(|e| format!("백업 디렉토리 생성 실패: {}", e))?;
+    
+    let name = format!("{}{}{}", INDEX_SNAPSHOT_PREFIX, chrono::Local::now().format("%Y%m%d_%H%M%S"), INDEX_SNAPSHOT_EXT);
+    let final_path = backups_dir.join(&name);
+    // 중간에 실패한 스냅샷이 목록에 보이지 않도록 임시 파일에 먼저 기록
+    let temp_path = backups_dir.join(format!("{}.partial", name));
+    
+    let write_archive = || -> std::io::Result<()> {
+        let file = fs::File::create(&temp_path)?;
+        let encoder = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+        let mut builder = tar::Builder::new(encoder);
+        builder.append_dir_all("chroma", &chroma_path)?;
+        builder.into_inner()?.finish()?;
+        Ok(())
+    };
+    if let Err(e) = write_archive() {
+        let _ = fs::remove_file(&temp_path);
+        return Err(format!("스냅샷 생성 실패: {}", e));
+    }
+    fs::rename(&temp_path, &final_path).map_err(|e| format!("스냅샷 저장 실패: {}", e))?;
+    
+    // 보관 개수 초과분 삭제
+    for old in collect_index_snapshots().into_iter().skip(keep_snapshots.max(1)) {
+        if let Err(e) = fs::remove_file(backups_dir.join(&old.name)) {
+            eprintln!("오래된 스냅샷 삭제 실패 {}: {}", old.name, e);
+        }
+    }
+    
+    let size_mb = fs::metadata(&final_path).map(|m| bytes_to_mb(m.len())).unwrap_or(0.0);
+    Ok(IndexSnapshot {
+        name,
+        created_at: chrono::Local::now().to_rfc3339(),
+        size_mb,
+    })
+}
+
+// 주기적 벡터 인덱스 스냅샷 (임베딩 실행 중에는 건너뜀)
+fn spawn_index_backup_scheduler(embedding_state: EmbeddingState, vault_lock: VaultLockState) {
+    thread::spawn(move || loop {
+        thread::sleep(INDEX_BACKUP_CHECK_INTERVAL);
+        
+        let settings: IndexBackupSettings = load_json_config(INDEX_BACKUP_SETTINGS_FILE).unwrap_or_default();
+        if !settings.enabled || !get_chroma_path().exists() {
+            continue;
+        }
+        if vault_lock.read_only.load(Ordering::SeqCst) {
+            continue;
+        }
+        
+        let is_due = collect_index_snapshots()
+            .first()
+            .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s.created_at).ok())
+            .map(|t| chrono::Local::now().signed_duration_since(t) >= chrono::Duration::hours(settings.interval_hours as i64))
+            .unwrap_or(true);
+        if !is_due {
+            continue;
+        }
+        // 스냅샷 중에는 임베딩이 시작되지 않도록 같은 실행 중 표시를 잡음 (임베딩 중이면 건너뜀)
+        let Some(_running) = RunningGuard::try_new(&embedding_state.is_running) else { continue };
+        match create_index_snapshot(settings.keep_snapshots) {
+            Ok(snapshot) => println!("💾 벡터 인덱스 스냅샷 생성: {} ({:.1}MB)", snapshot.name, snapshot.size_mb),
+            Err(e) => eprintln!("❌ 벡터 인덱스 스냅샷 실패: {}", e),
+        }
+    });
+}
+
+// 벡터 인덱스 즉시 백업
+#[command]
+async fn backup_vector_index(state: State<'_, EmbeddingState>, vault_lock: State<'_, VaultLockState>) -> Result<IndexSnapshot, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let _running = RunningGuard::try_new(&state.is_running).ok_or("임베딩 생성 중에는 백업할 수 없습니다")?;
+    let settings: IndexBackupSettings = load_json_config(INDEX_BACKUP_SETTINGS_FILE)?;
+    create_index_snapshot(settings.keep_snapshots)
+}
+
+// 벡터 인덱스 스냅샷 목록 조회
+#[command]
+fn list_vector_index_snapshots() -> Result<Vec<IndexSnapshot>, String> {
+    Ok(collect_index_snapshots())
+}
+
+// 벡터 인덱스 백업 설정 조회/저장
+#[command]
+fn get_index_backup_settings() -> Result<IndexBackupSettings, String> {
+    load_json_config(INDEX_BACKUP_SETTINGS_FILE)
+}
+
+#[command]
+fn save_index_backup_settings(settings: IndexBackupSettings) -> Result<(), String> {
+    if settings.interval_hours == 0 || settings.keep_snapshots == 0 {
+        return Err("백업 주기와 보관 개수는 1 이상이어야 합니다".to_string());
+    }
+    save_json_config(INDEX_BACKUP_SETTINGS_FILE, &settings)
+}
+
+// 스냅샷에서 벡터 인덱스 복원
+#[command]
+async fn restore_vector_index(state: State<'_, EmbeddingState>, vault_lock: State<'_, VaultLockState>, snapshot: String) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    if snapshot.contains('/') || snapshot.contains('\\') || !snapshot.ends_with(INDEX_SNAPSHOT_EXT) {
+        return Err(format!("잘못된 스냅샷 이름입니다: {}", snapshot));
+    }
+    let _running = RunningGuard::try_new(&state.is_running).ok_or("임베딩 생성 중에는 복원할 수 없습니다")?;
+    
+    let snapshot_path = get_index_backups_dir().join(&snapshot);
+    if !snapshot_path.exists() {
+        return Err(format!("스냅샷을 찾을 수 없습니다: {}", snapshot));
+    }
+    
+    let chroma_path = get_chroma_path();
+    let indices_dir = chroma_path.parent().ok_or("인덱스 경로 오류")?.to_path_buf();
+    let restore_dir = indices_dir.join("chroma.restore");
+    let previous_dir = indices_dir.join("chroma.previous");
+    let _ = fs::remove_dir_all(&restore_dir);
+    
+    // 임시 디렉토리에 먼저 압축 해제
+    let file = fs::File::open(&snapshot_path).map_err(|e| format!("스냅샷 열기 실패: {}", e))?;
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(file));
+    if let Err(e) = archive.unpack(&restore_dir) {
+        let _ = fs::remove_dir_all(&restore_dir);
+        return Err(format!("스냅샷 압축 해제 실패: {}", e));
+    }
+    let restored_chroma = restore_dir.join("chroma");
+    if !restored_chroma.exists() {
+        let _ = fs::remove_dir_all(&restore_dir);
+        return Err("스냅샷에 chroma 디렉토리가 없습니다".to_string());
+    }
+    
+    // 기존 인덱스를 옮겨둔 뒤 교체, 실패 시 원복
+    let _ = fs::remove_dir_all(&previous_dir);
+    if chroma_path.exists() {
+        fs::rename(&chroma_path, &previous_dir).map_err(|e| format!("기존 인덱스 이동 실패: {}", e))?;
+    }
+    if let Err(e) = fs::rename(&restored_chroma, &chroma_path) {
+        if previous_dir.exists() {
+            let _ = fs::rename(&previous_dir, &chroma_path);
+        }
+        let _ = fs::remove_dir_all(&restore_dir);
+        return Err(format!("인덱스 교체 실패: {}", e));
+    }
+    let _ = fs::remove_dir_all(&restore_dir);
+    let _ = fs::remove_dir_all(&previous_dir);
+    
+    Ok(format!("✅ 벡터 인덱스 복원 완료: {}", snapshot))
+}
+
+// 영상 인덱스 DB (SQLite) 및 스키마 마이그레이션
+// 새 필드는 기존 파일을 수정하지 말고 다음 번호의 마이그레이션을 추가할 것
+const INDEX_MIGRATIONS: &[(i64, &str, &str)] = &[
+    (1, "initial", include_str!("../migrations/0001_initial.sql")),
+    (2, "reembedding_flags", include_str!("../migrations/0002_reembedding_flags.sql")),
+    (3, "published_at", include_str!("../migrations/0003_published_at.sql")),
+    (4, "view_count_snapshots", include_str!("../migrations/0004_view_count_snapshots.sql")),
+    (5, "transcripts_fts", include_str!("../migrations/0005_transcripts_fts.sql")),
+    (6, "video_references", include_str!("../migrations/0006_video_references.sql")),
+    (7, "playback_log", include_str!("../migrations/0007_playback_log.sql")),
+    (8, "pipeline_status", include_str!("../migrations/0008_pipeline_status.sql")),
+    (9, "caption_languages", include_str!("../migrations/0009_caption_languages.sql")),
+    (10, "caption_language", include_str!("../migrations/0010_caption_language.sql")),
+    (11, "download_history", include_str!("../migrations/0011_download_history.sql")),
+    (12, "media_probes", include_str!("../migrations/0012_media_probes.sql")),
+    (13, "watch_positions", include_str!("../migrations/0013_watch_positions.sql")),
+    (14, "favorites", include_str!("../migrations/0014_favorites.sql")),
+    (15, "media_removed", include_str!("../migrations/0015_media_removed.sql")),
+];
+
+#[derive(Serialize, Deserialize)]
+struct AppliedMigration {
+    version: i64,
+    name: String,
+    applied_at: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct IndexSchemaInfo {
+    db_path: String,
+    current_version: i64,
+    latest_version: i64,
+    applied: Vec<AppliedMigration>,
+}
+
+fn get_index_db_path() -> PathBuf {
+    get_project_root().join("vault").join("90_indices").join("index.db")
+}
+
+fn latest_index_schema_version() -> i64 {
+    INDEX_MIGRATIONS.last().map(|(version, _, _)| *version).unwrap_or(0)
+}
+
+// 마이그레이션 전 DB 파일 백업
+fn backup_index_db(conn: &rusqlite::Connection, current_version: i64) -> Result<PathBuf, String> {
+    let backups_dir = get_index_backups_dir();
+    fs::create_dir_all(&backups_dir).map_err(|e| format!("백업 디렉토리 생성 실패: {}", e))?;
+    let backup_path = backups_dir.join(format!("index_v{}_{}.db", current_version, chrono::Local::now().format("%Y%m%d_%H%M%S")));
+    
+    // WAL 내용까지 포함한 일관된 사본 생성
+    conn.execute("VACUUM INTO ?1", [backup_path.to_string_lossy().to_string()])
+        .map_err(|e| format!("인덱스 백업 실패: {}", e))?;
+    Ok(backup_path)
+}
+
+// 미적용 마이그레이션을 순서대로 트랜잭션 단위로 적용
+fn migrate_index_db(conn: &mut rusqlite::Connection) -> Result<(), String> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS schema_migrations (
+            version INTEGER PRIMARY KEY,
+            name TEXT NOT NULL,
+            applied_at TEXT NOT NULL
+        );",
+    )
+    .map_err(|e| format!("마이그레이션 테이블 생성 실패: {}", e))?;
+    
+    let current_version: i64 = conn
+        .query_row("PRAGMA user_version", [], |row| row.get(0))
+        .map_err(|e| format!("스키마 버전 조회 실패: {}", e))?;
+    let latest_version = latest_index_schema_version();
+    
+    // 더 최신 버전 앱이 만든 DB는 열지 않음 (구버전 스키마로 덮어쓰기 방지)
+    if current_version > latest_version {
+        return Err(format!(
+            "인덱스 스키마 버전({})이 앱이 지원하는 버전({})보다 높습니다. 앱을 업데이트하세요",
+            current_version, latest_version
+        ));
+    }
+    if current_version == latest_version {
+        return Ok(());
+    }
+    
+    if current_version > 0 {
+        let backup = backup_index_db(conn, current_version)?;
+        println!("💾 인덱스 마이그레이션 전 백업: {}", backup.display());
+    }
+    
+    for (version, name, sql) in INDEX_MIGRATIONS.iter().filter(|(v, _, _)| *v > current_version) {
+        let tx = conn.transaction().map_err(|e| e.to_string())?;
+        tx.execute_batch(sql)
+            .map_err(|e| format!("마이그레이션 {} ({}) 실패: {}", version, name, e))?;
+        tx.execute(
+            "INSERT INTO schema_migrations (version, name, applied_at) VALUES (?1, ?2, ?3)",
+            rusqlite::params![version, name, chrono::Local::now().to_rfc3339()],
+        )
+        .map_err(|e| e.to_string())?;
+        tx.pragma_update(None, "user_version", version).map_err(|e| e.to_string())?;
+        tx.commit().map_err(|e| format!("마이그레이션 {} 커밋 실패: {}", version, e))?;
+        println!("🗄️ 인덱스 마이그레이션 적용: v{} {}", version, name);
+    }
+    
+    Ok(())
+}
+
+// 인덱스 DB 열기 (필요 시 마이그레이션 수행)
+fn open_index_db() -> Result<rusqlite::Connection, String> {
+    let db_path = get_index_db_path();
+    if let Some(parent) = db_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("인덱스 디렉토리 생성 실패: {}", e))?;
+    }
+    
+    let mut conn = rusqlite::Connection::open(&db_path)
+        .map_err(|e| format!("인덱스 DB 열기 실패 {}: {}", db_path.display(), e))?;
+    conn.pragma_update(None, "journal_mode", "WAL").map_err(|e| e.to_string())?;
+    conn.pragma_update(None, "foreign_keys", "ON").map_err(|e| e.to_string())?;
+    conn.busy_timeout(Duration::from_secs(5)).map_err(|e| e.to_string())?;
+    
+    migrate_index_db(&mut conn)?;
+    Ok(conn)
+}
+
+// 인덱스 스키마 버전 및 적용된 마이그레이션 조회
+#[command]
+fn get_index_schema_version() -> Result<IndexSchemaInfo, String> {
+    let conn = open_index_db()?;
+    let current_version: i64 = conn
+        .query_row("PRAGMA user_version", [], |row| row.get(0))
+        .map_err(|e| e.to_string())?;
+    
+    let mut stmt = conn
+        .prepare("SELECT version, name, applied_at FROM schema_migrations ORDER BY version")
+        .map_err(|e| e.to_string())?;
+    let applied = stmt
+        .query_map([], |row| {
+            Ok(AppliedMigration {
+                version: row.get(0)?,
+                name: row.get(1)?,
+                applied_at: row.get(2)?,
+            })
+        })
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    
+    Ok(IndexSchemaInfo {
+        db_path: get_index_db_path().to_string_lossy().to_string(),
+        current_version,
+        latest_version: latest_index_schema_version(),
+        applied,
+    })
+}
+
+// 인덱스 DB 유지보수 (WAL 체크포인트 / VACUUM / 무결성 검사)
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+struct IndexMaintenanceState {
+    last_checkpoint_at: Option<String>,
+    last_vacuum_at: Option<String>,
+    last_integrity_check_at: Option<String>,
+    last_integrity_ok: Option<bool>,
+    last_integrity_messages: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct IndexHealth {
+    db_size_mb: f64,
+    wal_size_mb: f64,
+    page_count: i64,
+    freelist_count: i64,
+    fragmentation_ratio: f64,
+    schema_version: i64,
+    video_count: i64,
+    maintenance: IndexMaintenanceState,
+}
+
+const INDEX_MAINTENANCE_FILE: &str = "index_maintenance.json";
+const INDEX_MAINTENANCE_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const INDEX_VACUUM_INTERVAL_DAYS: i64 = 30;
+const INDEX_INTEGRITY_CHECK_INTERVAL_DAYS: i64 = 7;
+// 빈 페이지 비율이 이 값을 넘으면 주기와 관계없이 VACUUM
+const INDEX_VACUUM_FRAGMENTATION_THRESHOLD: f64 = 0.2;
+
+// 마지막 실행 후 지정 일수가 지났는지 확인
+fn is_older_than_days(timestamp: &Option<String>, days: i64) -> bool {
+    timestamp
+        .as_deref()
+        .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+        .map(|t| chrono::Local::now().signed_duration_since(t) >= chrono::Duration::days(days))
+        .unwrap_or(true)
+}
+
+fn index_page_stats(conn: &rusqlite::Connection) -> Result<(i64, i64), String> {
+    let page_count: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0)).map_err(|e| e.to_string())?;
+    let freelist_count: i64 = conn.query_row("PRAGMA freelist_count", [], |row| row.get(0)).map_err(|e| e.to_string())?;
+    Ok((page_count, freelist_count))
+}
+
+// 유지보수 실행: 체크포인트는 항상, 무결성 검사/VACUUM은 주기 또는 강제 시
+fn perform_index_maintenance(force: bool) -> Result<IndexMaintenanceState, String> {
+    let conn = open_index_db()?;
+    let mut state: IndexMaintenanceState = load_json_config(INDEX_MAINTENANCE_FILE)?;
+    let now = || chrono::Local::now().to_rfc3339();
+    
+    conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))
+        .map_err(|e| format!("WAL 체크포인트 실패: {}", e))?;
+    state.last_checkpoint_at = Some(now());
+    
+    if force || is_older_than_days(&state.last_integrity_check_at, INDEX_INTEGRITY_CHECK_INTERVAL_DAYS) {
+        let mut stmt = conn.prepare("PRAGMA integrity_check").map_err(|e| e.to_string())?;
+        let messages = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        let ok = messages.len() == 1 && messages[0] == "ok";
+        if !ok {
+            eprintln!("⚠️ 인덱스 무결성 검사 이상: {:?}", messages);
+        }
+        state.last_integrity_ok = Some(ok);
+        state.last_integrity_messages = if ok { Vec::new() } else { messages };
+        state.last_integrity_check_at = Some(now());
+    }
+    
+    let (page_count, freelist_count) = index_page_stats(&conn)?;
+    let fragmentation = freelist_count as f64 / page_count.max(1) as f64;
+    if force
+        || fragmentation > INDEX_VACUUM_FRAGMENTATION_THRESHOLD
+        || is_older_than_days(&state.last_vacuum_at, INDEX_VACUUM_INTERVAL_DAYS)
+    {
+        conn.execute_batch("VACUUM; PRAGMA optimize;")
+            .map_err(|e| format!("VACUUM 실패: {}", e))?;
+        state.last_vacuum_at = Some(now());
+    }
+    
+    save_json_config(INDEX_MAINTENANCE_FILE, &state)?;
+    Ok(state)
+}
+
+// 다운로드/임베딩이 없는 유휴 시간에만 주기적 유지보수 실행
+fn spawn_index_maintenance_scheduler(download_state: DownloadState, embedding_state: EmbeddingState, vault_lock: VaultLockState, jobs: VaultJobState) {
+    thread::spawn(move || loop {
+        thread::sleep(INDEX_MAINTENANCE_CHECK_INTERVAL);
+        
+        let is_busy = download_state.is_running.load(Ordering::SeqCst)
+            || embedding_state.is_running.load(Ordering::SeqCst)
+            || vault_lock.read_only.load(Ordering::SeqCst);
+        if is_busy || !get_index_db_path().exists() {
+            continue;
+        }
+        let Some(_running) = RunningGuard::try_new(&jobs.index_maintenance) else {
+            continue;
+        };
+        if let Err(e) = perform_index_maintenance(false) {
+            eprintln!("❌ 인덱스 유지보수 실패: {}", e);
+        }
+    });
+}
+
+// 인덱스 DB 상태 조회
+#[command]
+fn get_index_health() -> Result<IndexHealth, String> {
+    let conn = open_index_db()?;
+    let db_path = get_index_db_path();
+    let file_size = |path: PathBuf| fs::metadata(path).map(|m| bytes_to_mb(m.len())).unwrap_or(0.0);
+    
+    let (page_count, freelist_count) = index_page_stats(&conn)?;
+    let schema_version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0)).map_err(|e| e.to_string())?;
+    let video_count: i64 = conn.query_row("SELECT COUNT(*) FROM videos", [], |row| row.get(0)).map_err(|e| e.to_string())?;
+    
+    Ok(IndexHealth {
+        db_size_mb: file_size(db_path.clone()),
+        wal_size_mb: file_size(db_path.with_extension("db-wal")),
+        page_count,
+        freelist_count,
+        fragmentation_ratio: freelist_count as f64 / page_count.max(1) as f64,
+        schema_version,
+        video_count,
+        maintenance: load_json_config(INDEX_MAINTENANCE_FILE)?,
+    })
+}
+
+// 인덱스 유지보수 즉시 실행 (체크포인트 + 무결성 검사 + VACUUM)
+#[command]
+async fn run_index_maintenance(
+    state: State<'_, DownloadState>,
+    embedding_state: State<'_, EmbeddingState>,
+    jobs: State<'_, VaultJobState>,
+    vault_lock: State<'_, VaultLockState>,
+) -> Result<IndexMaintenanceState, String> {
+    ensure_vault_writable(&vault_lock)?;
+    if state.is_running.load(Ordering::SeqCst) || embedding_state.is_running.load(Ordering::SeqCst) {
+        return Err("다운로드 또는 임베딩이 진행 중입니다. 완료 후 다시 시도하세요".to_string());
+    }
+    let _running = RunningGuard::try_new(&jobs.index_maintenance).ok_or("이미 인덱스 유지보수가 진행 중입니다")?;
+    perform_index_maintenance(true)
+}
+
+// 인덱스 영상 조회 공통 SELECT (videos + 파이프라인 상태)
+const INDEXED_VIDEO_SELECT: &str = "SELECT v.video_path, v.captions_path, v.title, v.channel, v.upload_date, v.duration, v.duration_seconds,
+        v.view_count, v.topic, v.video_id, v.source_url, v.excerpt, v.transcript_quality, v.published_at,
+        p.downloaded, p.captions, p.thumbnails, p.embedded, p.summarized, p.stuck_at, p.updated_at,
+        v.caption_languages, v.caption_language,
+        EXISTS (SELECT 1 FROM favorites f WHERE f.video_id = v.video_id), v.media_removed
+ FROM videos v LEFT JOIN pipeline_status p ON p.video_path = v.video_path";
+
+fn indexed_video_from_row(row: &rusqlite::Row) -> rusqlite::Result<VideoInfo> {
+    let topic: Option<String> = row.get(8)?;
+    let pipeline_updated_at: Option<String> = row.get(20)?;
+    let pipeline = match pipeline_updated_at {
+        Some(updated_at) => Some(PipelineStatus {
+            downloaded: row.get(14)?,
+            captions: row.get(15)?,
+            thumbnails: row.get(16)?,
+            embedded: row.get(17)?,
+            summarized: row.get(18)?,
+            stuck_at: row.get::<_, Option<String>>(19)?.as_deref().and_then(PipelineStage::parse),
+            updated_at,
+        }),
+        None => None,
+    };
+    Ok(VideoInfo {
+        video_path: row.get(0)?,
+        captions_path: row.get(1)?,
+        title: row.get(2)?,
+        channel: row.get(3)?,
+        upload_date: row.get(4)?,
+        duration: row.get(5)?,
+        duration_seconds: row.get(6)?,
+        view_count: row.get(7)?,
+        topic: topic.and_then(|t| serde_json::from_str(&t).ok()),
+        video_id: row.get(9)?,
+        source_url: row.get(10)?,
+        excerpt: row.get(11)?,
+        transcript_quality: row.get(12)?,
+        published_at: row.get(13)?,
+        status: if row.get(24)? { VideoStatus::MediaRemoved } else { VideoStatus::Ready },
+        pipeline,
+        caption_languages: row
+            .get::<_, Option<String>>(21)?
+            .and_then(|l| serde_json::from_str(&l).ok())
+            .unwrap_or_default(),
+        caption_language: row.get::<_, Option<String>>(22)?.filter(|l| !l.is_empty()),
+        playlist: None,
+        favorite: row.get(23)?,
+    })
+}
+
+// 인덱스 DB의 영상 목록 (목록 조회용, 파일 시스템을 직접 읽지 않음)
+fn load_indexed_videos(conn: &rusqlite::Connection) -> Result<Vec<VideoInfo>, String> {
+    let mut stmt = conn
+        .prepare(&format!("{} ORDER BY v.channel, v.upload_date DESC", INDEXED_VIDEO_SELECT))
+        .map_err(|e| format!("인덱스 조회 실패: {}", e))?;
+    let videos = stmt
+        .query_map([], indexed_video_from_row)
+        .map_err(|e| format!("인덱스 조회 실패: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("인덱스 조회 실패: {}", e))?;
+    Ok(videos)
+}
+
+// 진행 중인 작업의 폴더로 볼 최근 수정 시간 (정리 작업으로 영상 파일만 지워진 오래된 폴더 제외)
+const PENDING_FOLDER_MAX_AGE_SECS: i64 = 24 * 60 * 60;
+
+// 아직 인덱싱되지 않은 영상 폴더 (다운로드 진행 중, 폴더 이름만 확인하고 파일은 읽지 않음)
+fn collect_pending_video_folders(indexed_folders: &std::collections::HashSet<PathBuf>) -> Vec<VideoInfo> {
+    let project_root = get_project_root();
+    let min_mtime = chrono::Utc::now().timestamp() - PENDING_FOLDER_MAX_AGE_SECS;
+    let root = project_root.join("vault").join("10_videos");
+    let mut pending = Vec::new();
+    let subdirs = |dir: &Path| -> Vec<PathBuf> {
+        fs::read_dir(dir)
+            .map(|entries| entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()).collect())
+            .unwrap_or_default()
+    };
+    
+    for channel_dir in subdirs(&root) {
+        for year_dir in subdirs(&channel_dir) {
+            for folder in subdirs(&year_dir) {
+                let relative = folder.strip_prefix(&project_root).unwrap_or(&folder).to_path_buf();
+                if indexed_folders.contains(&relative) || file_mtime_secs(&folder).unwrap_or(0) < min_mtime {
+                    continue;
+                }
+                let video_file = folder.join("video.mp4");
+                pending.push(VideoInfo {
+                    video_path: relative.join("video.mp4").to_string_lossy().to_string(),
+                    captions_path: relative.join("captions.md").to_string_lossy().to_string(),
+                    title: extract_title_from_path(&folder),
+                    channel: extract_channel_from_path(&video_file),
+                    upload_date: None,
+                    duration: None,
+                    duration_seconds: None,
+                    view_count: None,
+                    topic: None,
+                    video_id: None,
+                    source_url: None,
+                    excerpt: None,
+                    transcript_quality: None,
+                    published_at: None,
+                    status: VideoStatus::Pending,
+                    pipeline: None,
+                    caption_languages: Vec::new(),
+                    caption_language: None,
+                    playlist: None,
+                    favorite: false,
+                });
+            }
+        }
+    }
+    pending
+}
+
+// 비디오 목록 조회 (항상 인덱스 기준, 다운로드 중에는 미완성 폴더를 pending으로 표시)
+// 인덱스는 다운로드/변환/삭제 시점과 앱 시작 시 갱신되므로 여기서는 vault를 다시 스캔하지 않음
+#[command]
+fn list_videos(state: State<'_, DownloadState>, vault_lock: State<'_, VaultLockState>, caption_language: Option<String>, include_archived: Option<bool>) -> Result<Vec<VideoInfo>, String> {
+    let is_writing = state.is_running.load(Ordering::SeqCst) || vault_lock.read_only.load(Ordering::SeqCst);
+    let conn = open_index_db()?;
+    let mut videos = load_indexed_videos(&conn)?;
+    if is_writing {
+        let indexed_folders: std::collections::HashSet<PathBuf> = videos
+            .iter()
+            .filter_map(|v| Path::new(&v.video_path).parent().map(Path::to_path_buf))
+            .collect();
+        videos.extend(collect_pending_video_folders(&indexed_folders));
+    }
+    assign_video_playlists(&mut videos);
+    if !include_archived.unwrap_or(false) {
+        // 보관 채널 영상은 기본 목록에서 숨김 (검색/RAG에서는 그대로 조회됨)
+        let archived = archived_channel_names();
+        if !archived.is_empty() {
+            videos.retain(|v| video_channel_folder(&v.video_path).map(|c| !archived.contains(&c)).unwrap_or(true));
+        }
+    }
+    if let Some(language) = caption_language.filter(|l| !l.is_empty()) {
+        // 감지 전인 pending 영상은 언어를 알 수 없으므로 제외
+        videos.retain(|v| v.caption_language.as_deref().map(|l| caption_language_matches(l, &language)).unwrap_or(false));
+    }
+    Ok(videos)
+}
+
+// 페이지 단위 목록 정렬 기준 (값이 없는 영상은 방향과 관계없이 뒤로)
+#[derive(Deserialize, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+enum VideoSortBy {
+    #[default]
+    UploadDate,
+    ViewCount,
+    Duration,
+    Title,
+}
+
+impl VideoSortBy {
+    fn column(self) -> &'static str {
+        match self {
+            VideoSortBy::UploadDate => "v.upload_date",
+            VideoSortBy::ViewCount => "v.view_count",
+            VideoSortBy::Duration => "v.duration_seconds",
+            VideoSortBy::Title => "v.title COLLATE NOCASE",
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+#[derive(Serialize)]
+struct VideoPage {
+    videos: Vec<VideoInfo>,
+    total: u32,   // 조건에 맞는 인덱스 영상 수 (pending 제외)
+    offset: u32,
+    limit: u32,
+}
+
+// 목록 필터 (비어 있으면 보관 채널을 제외한 전체)
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct VideoListFilter {
+    caption_language: Option<String>,
+    include_archived: bool,
+    channel: Option<String>,            // 채널 이름 또는 vault 채널 폴더 이름
+    topic: Option<String>,              // frontmatter topic 태그 (대소문자 무시, 정확히 일치)
+    upload_from: Option<String>,        // YYYY-MM-DD (포함)
+    upload_to: Option<String>,
+    min_duration_seconds: Option<u32>,
+    max_duration_seconds: Option<u32>,
+    has_captions: Option<bool>,         // 파이프라인 자막 단계 기준
+    watched: Option<bool>,              // 시청 기록(playback_log) 유무
+    favorite: Option<bool>,
+}
+
+impl VideoListFilter {
+    // 보관 채널 포함 여부 외의 조건이 있는지
+    fn has_conditions(&self) -> bool {
+        let set = |value: &Option<String>| value.as_deref().map(|v| !v.trim().is_empty()).unwrap_or(false);
+        set(&self.caption_language)
+            || set(&self.channel)
+            || set(&self.topic)
+            || set(&self.upload_from)
+            || set(&self.upload_to)
+            || self.min_duration_seconds.is_some()
+            || self.max_duration_seconds.is_some()
+            || self.has_captions.is_some()
+            || self.watched.is_some()
+            || self.favorite.is_some()
+    }
+}
+
+// 목록 필터를 인덱스 조회 WHERE 조건으로 변환 (videos v, pipeline_status p 기준)
+fn video_filter_clauses(filter: VideoListFilter) -> (Vec<&'static str>, Vec<Box<dyn rusqlite::ToSql>>) {
+    let mut clauses: Vec<&str> = Vec::new();
+    let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+    let folder_prefix = |name: &str| {
+        let prefix = Path::new("vault").join("10_videos").join(name);
+        format!("{}{}", prefix.to_string_lossy(), std::path::MAIN_SEPARATOR)
+    };
+    if !filter.include_archived {
+        for name in archived_channel_names() {
+            clauses.push("instr(v.video_path, ?) != 1");
+            params.push(Box::new(folder_prefix(&name)));
+        }
+    }
+    if let Some(language) = filter.caption_language.filter(|l| !l.is_empty()) {
+        clauses.push("(v.caption_language = ? OR v.caption_language LIKE ? || '-%')");
+        params.push(Box::new(language.clone()));
+        params.push(Box::new(language));
+    }
+    if let Some(channel) = filter.channel.map(|c| c.trim().to_string()).filter(|c| !c.is_empty()) {
+        clauses.push("(v.channel = ? OR instr(v.video_path, ?) = 1)");
+        params.push(Box::new(channel.clone()));
+        params.push(Box::new(folder_prefix(&sanitize_vault_name(&channel))));
+    }
+    if let Some(topic) = filter.topic.map(|t| t.trim().to_string()).filter(|t| !t.is_empty()) {
+        clauses.push("EXISTS (SELECT 1 FROM json_each(v.topic) WHERE lower(json_each.value) = lower(?))");
+        params.push(Box::new(topic));
+    }
+    if let Some(from) = filter.upload_from.filter(|d| !d.is_empty()) {
+        clauses.push("v.upload_date >= ?");
+        params.push(Box::new(from));
+    }
+    if let Some(to) = filter.upload_to.filter(|d| !d.is_empty()) {
+        clauses.push("v.upload_date <= ?");
+        params.push(Box::new(to));
+    }
+    if let Some(min) = filter.min_duration_seconds {
+        clauses.push("v.duration_seconds >= ?");
+        params.push(Box::new(min));
+    }
+    if let Some(max) = filter.max_duration_seconds {
+        clauses.push("v.duration_seconds <= ?");
+        params.push(Box::new(max));
+    }
+    match filter.has_captions {
+        Some(true) => clauses.push("IFNULL(p.captions, 0) = 1"),
+        Some(false) => clauses.push("IFNULL(p.captions, 0) = 0"),
+        None => {}
+    }
+    match filter.watched {
+        Some(true) => clauses.push("EXISTS (SELECT 1 FROM playback_log l WHERE l.video_path = v.video_path)"),
+        Some(false) => clauses.push("NOT EXISTS (SELECT 1 FROM playback_log l WHERE l.video_path = v.video_path)"),
+        None => {}
+    }
+    match filter.favorite {
+        Some(true) => clauses.push("EXISTS (SELECT 1 FROM favorites f WHERE f.video_id = v.video_id)"),
+        Some(false) => clauses.push("NOT EXISTS (SELECT 1 FROM favorites f WHERE f.video_id = v.video_id)"),
+        None => {}
+    }
+    (clauses, params)
+}
+
+const VIDEO_PAGE_DEFAULT_LIMIT: u32 = 100;
+const VIDEO_PAGE_MAX_LIMIT: u32 = 1000;
+
+// 비디오 목록 페이지 조회 (정렬/페이지 나눔을 인덱스 DB에서 처리)
+// 다운로드 중이면 (필터가 없을 때) 첫 페이지 앞에 pending 폴더를 붙임
+#[command]
+fn list_videos_paged(
+    state: State<'_, DownloadState>,
+    vault_lock: State<'_, VaultLockState>,
+    offset: Option<u32>,
+    limit: Option<u32>,
+    sort_by: Option<VideoSortBy>,
+    order: Option<SortOrder>,
+    filter: Option<VideoListFilter>,
+) -> Result<VideoPage, String> {
+    let filter = filter.unwrap_or_default();
+    let offset = offset.unwrap_or(0);
+    let limit = limit.unwrap_or(VIDEO_PAGE_DEFAULT_LIMIT).clamp(1, VIDEO_PAGE_MAX_LIMIT);
+    let is_writing = state.is_running.load(Ordering::SeqCst) || vault_lock.read_only.load(Ordering::SeqCst);
+    // pending 폴더는 메타데이터가 없어 필터를 적용할 수 없으므로 조건이 없을 때만 표시
+    let unfiltered = !filter.has_conditions();
+    let (clauses, params) = video_filter_clauses(filter);
+    let where_clause = if clauses.is_empty() { String::new() } else { format!(" WHERE {}", clauses.join(" AND ")) };
+    let column = sort_by.unwrap_or_default().column();
+    let direction = match order.unwrap_or_default() {
+        SortOrder::Asc => "ASC",
+        SortOrder::Desc => "DESC",
+    };
+    
+    let conn = open_index_db()?;
+    let total: u32 = conn
+        .query_row(
+            &format!("SELECT COUNT(*) FROM videos v LEFT JOIN pipeline_status p ON p.video_path = v.video_path{}", where_clause),
+            rusqlite::params_from_iter(params.iter().map(|p| p.as_ref())),
+            |row| row.get(0),
+        )
+        .map_err(|e| format!("인덱스 조회 실패: {}", e))?;
+    let sql = format!(
+        "{}{} ORDER BY {} IS NULL, {} {}, v.video_path LIMIT {} OFFSET {}",
+        INDEXED_VIDEO_SELECT, where_clause, column, column, direction, limit, offset
+    );
+    let mut stmt = conn.prepare(&sql).map_err(|e| format!("인덱스 조회 실패: {}", e))?;
+    let mut videos = stmt
+        .query_map(rusqlite::params_from_iter(params.iter().map(|p| p.as_ref())), indexed_video_from_row)
+        .map_err(|e| format!("인덱스 조회 실패: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("인덱스 조회 실패: {}", e))?;
+    
+    if is_writing && offset == 0 && unfiltered {
+        let indexed_folders: std::collections::HashSet<PathBuf> = load_indexed_videos(&conn)?
+            .iter()
+            .filter_map(|v| Path::new(&v.video_path).parent().map(Path::to_path_buf))
+            .collect();
+        let mut pending = collect_pending_video_folders(&indexed_folders);
+        pending.append(&mut videos);
+        videos = pending;
+    }
+    assign_video_playlists(&mut videos);
+    Ok(VideoPage { videos, total, offset, limit })
+}
+
+fn archived_channel_names() -> std::collections::HashSet<String> {
+    load_channel_settings()
+        .into_iter()
+        .filter(|(_, s)| s.state == ChannelState::Archived)
+        .map(|(name, _)| name)
+        .collect()
+}
+
+// 전체 임베딩에서 건너뛸 채널 폴더 목록 (YDH_EXCLUDED_EMBEDDING_CHANNELS, JSON, embed.py에서 사용)
+fn excluded_embedding_channels_env() -> String {
+    let mut archived: Vec<String> = archived_channel_names().into_iter().collect();
+    archived.sort();
+    serde_json::to_string(&archived).unwrap_or_else(|_| "[]".to_string())
+}
+
+// vault/10_videos/<채널>/... 경로의 채널 폴더 이름
+fn video_channel_folder(video_path: &str) -> Option<String> {
+    Path::new(video_path)
+        .strip_prefix(Path::new("vault").join("10_videos"))
+        .ok()?
+        .components()
+        .next()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+}
+
+// 재생목록 폴더(10_videos/playlist_<ID>/...)의 영상에 재생목록 이름 표시 (보관된 재생목록 제목, 없으면 ID)
+fn assign_video_playlists(videos: &mut [VideoInfo]) {
+    let mut titles: HashMap<String, String> = HashMap::new();
+    for video in videos.iter_mut() {
+        let Some(folder) = Path::new(&video.video_path)
+            .strip_prefix(Path::new("vault").join("10_videos"))
+            .ok()
+            .and_then(|p| p.components().next())
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+        else {
+            continue;
+        };
+        let Some(playlist_id) = folder.strip_prefix(PLAYLIST_FOLDER_PREFIX) else { continue };
+        let title = titles
+            .entry(folder.clone())
+            .or_insert_with(|| load_channel_profile(&folder).and_then(|p| p.title).unwrap_or_else(|| playlist_id.to_string()));
+        video.playlist = Some(title.clone());
+    }
+}
+
+// 인덱스에 있는 자막 언어별 영상 수 (목록/검색 언어 필터 선택지)
+#[command]
+fn list_caption_languages() -> Result<Vec<(String, u32)>, String> {
+    let conn = open_index_db()?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT caption_language, COUNT(*) FROM videos
+             WHERE caption_language IS NOT NULL AND caption_language != ''
+             GROUP BY caption_language ORDER BY COUNT(*) DESC",
+        )
+        .map_err(|e| format!("자막 언어 조회 실패: {}", e))?;
+    let languages = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .map_err(|e| format!("자막 언어 조회 실패: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("자막 언어 조회 실패: {}", e))?;
+    Ok(languages)
+}
+
+// 자동완성 관련 구조체들
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[serde(rename_all = "snake_case")]
+enum SuggestionKind {
+    Title,
+    Channel,
+    Topic,
+    Entity, // 채널 용어집에 등록된 고유명사/용어
+}
+
+#[derive(Serialize, Clone)]
+struct AutocompleteSuggestion {
+    kind: SuggestionKind,
+    text: String,
+    video_path: Option<String>, // 제목 제안일 때만
+    count: u32,                 // 해당 채널/주제/용어의 영상(채널) 수
+}
+
+struct AutocompleteEntry {
+    kind: SuggestionKind,
+    text: String,
+    video_path: Option<String>,
+    count: u32,
+    popularity: f64, // 같은 조건일 때 순위 (제목은 조회수, 나머지는 영상 수)
+}
+
+// 정렬된 접두사 키 (단어 시작마다 하나씩) → 항목 번호, 이진 탐색으로 조회
+struct AutocompleteIndex {
+    generation: u64,
+    entries: Vec<AutocompleteEntry>,
+    keys: Vec<(String, usize, bool)>, // (소문자 키, 항목 번호, 전체 문자열 시작 여부)
+}
+
+// 인덱스가 바뀌면 증가 (vault 동기화/채널 용어집 저장 시) → 다음 조회 때 자동완성 인덱스 재생성
+static AUTOCOMPLETE_GENERATION: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
+
+#[derive(Default, Clone)]
+struct AutocompleteState {
+    index: Arc<std::sync::RwLock<Option<Arc<AutocompleteIndex>>>>,
+}
+
+const AUTOCOMPLETE_DEFAULT_LIMIT: usize = 10;
+const AUTOCOMPLETE_MAX_LIMIT: usize = 50;
+
+fn invalidate_autocomplete_index() {
+    AUTOCOMPLETE_GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+// 단어 시작 위치마다 접두사 키 생성 ("Rust 비동기 입문" → "rust 비동기 입문", "비동기 입문", "입문")
+fn autocomplete_keys(text: &str) -> Vec<(String, bool)> {
+    let lower = text.to_lowercase();
+    let mut keys = Vec::new();
+    let mut previous_alphanumeric = false;
+    for (offset, c) in lower.char_indices() {
+        if c.is_alphanumeric() && !previous_alphanumeric {
+            keys.push((lower[offset..].to_string(), keys.is_empty() && lower[..offset].trim().is_empty()));
+        }
+        previous_alphanumeric = c.is_alphanumeric();
+    }
+    keys
+}
+
+fn build_autocomplete_index(generation: u64) -> Result<AutocompleteIndex, String> {
+    let conn = open_index_db()?;
+    let mut stmt = conn
+        .prepare("SELECT video_path, title, channel, topic, view_count FROM videos")
+        .map_err(|e| format!("자동완성 인덱스 생성 실패: {}", e))?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, Option<i64>>(4)?,
+            ))
+        })
+        .map_err(|e| format!("자동완성 인덱스 생성 실패: {}", e))?;
+    
+    let mut entries = Vec::new();
+    let mut channels: HashMap<String, u32> = HashMap::new();
+    let mut topics: HashMap<String, u32> = HashMap::new();
+    for (video_path, title, channel, topic, view_count) in rows.flatten() {
+        entries.push(AutocompleteEntry {
+            kind: SuggestionKind::Title,
+            text: title,
+            video_path: Some(video_path),
+            count: 1,
+            popularity: (view_count.unwrap_or(0).max(0) as f64).ln_1p(),
+        });
+        *channels.entry(channel).or_default() += 1;
+        for topic in topic.and_then(|t| serde_json::from_str::<Vec<String>>(&t).ok()).unwrap_or_default() {
+            *topics.entry(topic).or_default() += 1;
+        }
+    }
+    let mut entities: HashMap<String, u32> = HashMap::new();
+    for settings in load_channel_settings().values() {
+        for term in settings.glossary.keys() {
+            *entities.entry(term.clone()).or_default() += 1;
+        }
+    }
+    for (kind, counts) in [(SuggestionKind::Channel, channels), (SuggestionKind::Topic, topics), (SuggestionKind::Entity, entities)] {
+        entries.extend(counts.into_iter().filter(|(text, _)| !text.trim().is_empty()).map(|(text, count)| AutocompleteEntry {
+            kind,
+            text,
+            video_path: None,
+            count,
+            popularity: (count as f64).ln_1p(),
+        }));
+    }
+    
+    let mut keys: Vec<(String, usize, bool)> = entries
+        .iter()
+        .enumerate()
+        .flat_map(|(index, entry)| autocomplete_keys(&entry.text).into_iter().map(move |(key, whole)| (key, index, whole)))
+        .collect();
+    keys.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    Ok(AutocompleteIndex { generation, entries, keys })
+}
+
+// 현재 세대의 자동완성 인덱스 (바뀐 경우에만 재생성)
+fn current_autocomplete_index(state: &AutocompleteState) -> Result<Arc<AutocompleteIndex>, String> {
+    let generation = AUTOCOMPLETE_GENERATION.load(Ordering::SeqCst);
+    if let Some(index) = state.index.read().map_err(|e| e.to_string())?.as_ref() {
+        if index.generation == generation {
+            return Ok(index.clone());
+        }
+    }
+    let index = Arc::new(build_autocomplete_index(generation)?);
+    *state.index.write().map_err(|e| e.to_string())? = Some(index.clone());
+    Ok(index)
+}
+
+// 검색창 입력 중 제안 (제목/채널/주제/용어, kinds를 비우면 전체)
+#[command]
+fn autocomplete(
+    state: State<'_, AutocompleteState>,
+    prefix: String,
+    kinds: Option<Vec<SuggestionKind>>,
+    limit: Option<usize>,
+) -> Result<Vec<AutocompleteSuggestion>, String> {
+    let prefix = prefix.trim().to_lowercase();
+    if prefix.is_empty() {
+        return Ok(Vec::new());
+    }
+    let limit = limit.unwrap_or(AUTOCOMPLETE_DEFAULT_LIMIT).clamp(1, AUTOCOMPLETE_MAX_LIMIT);
+    let kinds = kinds.filter(|k| !k.is_empty());
+    let index = current_autocomplete_index(&state)?;
+    
+    // 항목별 최고 점수: 전체 문자열이 접두사로 시작하면 가산, 채널/주제/용어는 제목보다 우선
+    let mut scores: HashMap<usize, f64> = HashMap::new();
+    let start = index.keys.partition_point(|(key, _, _)| key.as_str() < prefix.as_str());
+    for (key, entry_index, whole) in index.keys[start..].iter().take_while(|(key, _, _)| key.starts_with(&prefix)) {
+        let entry = &index.entries[*entry_index];
+        if kinds.as_ref().map(|k| !k.contains(&entry.kind)).unwrap_or(false) {
+            continue;
+        }
+        let kind_bonus = match entry.kind {
+            SuggestionKind::Channel => 1.5,
+            SuggestionKind::Topic | SuggestionKind::Entity => 1.0,
+            SuggestionKind::Title => 0.0,
+        };
+        let exact_bonus = if key.len() == prefix.len() { 1.0 } else { 0.0 };
+        let score = kind_bonus + exact_bonus + if *whole { 2.0 } else { 0.0 } + entry.popularity * 0.3;
+        let best = scores.entry(*entry_index).or_insert(f64::MIN);
+        *best = best.max(score);
+    }
+    
+    let mut ranked: Vec<(usize, f64)> = scores.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal).then(a.0.cmp(&b.0)));
+    Ok(ranked
+        .into_iter()
+        .take(limit)
+        .map(|(entry_index, _)| {
+            let entry = &index.entries[entry_index];
+            AutocompleteSuggestion {
+                kind: entry.kind,
+                text: entry.text.clone(),
+                video_path: entry.video_path.clone(),
+                count: entry.count,
+            }
+        })
+        .collect())
+}
+
+// vault ↔ 인덱스 동기화 관련 구조체들
+#[derive(Serialize, Deserialize, Default)]
+struct ReconcileReport {
+    scanned: u32,
+    added: u32,
+    updated: u32,
+    removed: u32,
+    flagged_for_reembedding: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ReembeddingCandidate {
+    video_path: String,
+    video_id: Option<String>,
+    title: String,
+    channel: String,
+}
+
+fn file_mtime_secs(path: &Path) -> Option<i64> {
+    let modified = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+    modified.duration_since(std::time::UNIX_EPOCH).ok().map(|d| d.as_secs() as i64)
+}
+
+// frontmatter가 있는 captions.md 경로 (VideoInfo.captions_path는 captions.txt일 수 있음)
+fn frontmatter_path(video: &VideoInfo) -> PathBuf {
+    let video_file = get_project_root().join(&video.video_path);
+    video_file.parent().map(|f| f.join("captions.md")).unwrap_or(video_file)
+}
+
+// 인덱스 레코드 추가/갱신
+fn upsert_video_record(conn: &rusqlite::Connection, video: &VideoInfo, captions_mtime: Option<i64>, needs_reembedding: bool) -> Result<(), String> {
+    let topic = video.topic.as_ref().and_then(|t| serde_json::to_string(t).ok());
+    let caption_languages = serde_json::to_string(&video.caption_languages).ok();
+    let now = chrono::Local::now().to_rfc3339();
+    conn.execute(
+        "INSERT INTO videos (video_path, captions_path, video_id, title, channel, upload_date, duration,
+                             duration_seconds, view_count, topic, source_url, excerpt, transcript_quality,
+                             captions_mtime, indexed_at, needs_reembedding, reconciled_at, published_at,
+                             caption_languages, media_removed)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?15, ?17, ?18, ?19)
+         ON CONFLICT(video_path) DO UPDATE SET
+             captions_path = excluded.captions_path, video_id = excluded.video_id, title = excluded.title,
+             channel = excluded.channel, upload_date = excluded.upload_date, duration = excluded.duration,
+             duration_seconds = excluded.duration_seconds, view_count = excluded.view_count, topic = excluded.topic,
+             source_url = excluded.source_url, excerpt = excluded.excerpt,
+             transcript_quality = excluded.transcript_quality, captions_mtime = excluded.captions_mtime,
+             indexed_at = excluded.indexed_at, reconciled_at = excluded.reconciled_at,
+             published_at = excluded.published_at, caption_languages = excluded.caption_languages,
+             media_removed = excluded.media_removed,
+             needs_reembedding = MAX(videos.needs_reembedding, excluded.needs_reembedding)",
+        rusqlite::params![
+            video.video_path,
+            video.captions_path,
+            video.video_id,
+            video.title,
+            video.channel,
+            video.upload_date,
+            video.duration,
+            video.duration_seconds,
+            video.view_count,
+            topic,
+            video.source_url,
+            video.excerpt,
+            video.transcript_quality,
+            captions_mtime,
+            now,
+            needs_reembedding as i64,
+            video.published_at,
+            caption_languages,
+            video.status == VideoStatus::MediaRemoved,
+        ],
+    )
+    .map_err(|e| format!("인덱스 레코드 저장 실패 {}: {}", video.video_path, e))?;
+    Ok(())
+}
+
+const TRANSCRIPT_SECTION: &str = "## 📝 자막 내용";
+
+// captions.md의 자막 본문 (태그/노트 섹션 제외)
+fn extract_transcript_text(content: &str) -> String {
+    let body = match content.strip_prefix("---") {
+        Some(rest) => rest.find("---").map(|end| &rest[end + 3..]).unwrap_or(content),
+        None => content,
+    };
+    let body = body.split_once(TRANSCRIPT_SECTION).map(|(_, rest)| rest).unwrap_or(body);
+    body.split("\n## ").next().unwrap_or("").trim().to_string()
+}
+
+// 자막 언어 감지에 필요한 최소 신뢰도 (미만이면 언어 미상으로 기록)
+const CAPTION_LANGUAGE_MIN_CONFIDENCE: f64 = 0.5;
+
+// captions.md의 자막 언어 (frontmatter의 caption_language가 있으면 우선, 없으면 본문으로 감지)
+fn detect_caption_language(content: &str) -> Option<String> {
+    let manual = content
+        .strip_prefix("---")
+        .and_then(|rest| rest.find("---").map(|end| &rest[..end]))
+        .and_then(|yaml| extract_yaml_field(yaml, "caption_language"))
+        .filter(|l| !l.is_empty());
+    if manual.is_some() {
+        return manual;
+    }
+    let sample: String = extract_transcript_text(content).chars().take(LANGUAGE_SAMPLE_CHARS).collect();
+    whatlang::detect(&sample)
+        .filter(|info| info.confidence() >= CAPTION_LANGUAGE_MIN_CONFIDENCE)
+        .map(|info| iso_639_1_code(info.lang()))
+}
+
+// 영상 자막 언어를 감지해 인덱스에 기록 (감지 실패는 빈 문자열로 저장해 매번 다시 검사하지 않음)
+fn index_caption_language(conn: &rusqlite::Connection, video: &VideoInfo) -> Result<(), String> {
+    let language = fs::read_to_string(frontmatter_path(video)).ok().and_then(|c| detect_caption_language(&c));
+    conn.execute(
+        "UPDATE videos SET caption_language = ?2 WHERE video_path = ?1",
+        rusqlite::params![video.video_path, language.unwrap_or_default()],
+    )
+    .map_err(|e| format!("자막 언어 저장 실패 {}: {}", video.video_path, e))?;
+    Ok(())
+}
+
+// 전문 검색 인덱스의 영상 자막 교체
+fn index_transcript_text(conn: &rusqlite::Connection, video: &VideoInfo) -> Result<(), String> {
+    let content = fs::read_to_string(frontmatter_path(video)).map(|c| extract_transcript_text(&c)).unwrap_or_default();
+    conn.execute("DELETE FROM transcripts_fts WHERE video_path = ?1", [&video.video_path])
+        .map_err(|e| e.to_string())?;
+    conn.execute(
+        "INSERT INTO transcripts_fts (video_path, channel, upload_date, title, content) VALUES (?1, ?2, ?3, ?4, ?5)",
+        rusqlite::params![video.video_path, video.channel, video.upload_date, video.title, content],
+    )
+    .map_err(|e| format!("전문 검색 인덱스 저장 실패 {}: {}", video.video_path, e))?;
+    Ok(())
+}
+
+// 영상 한 건을 인덱스에서 제거 (재생 기록/다운로드 기록은 유지)
+fn delete_indexed_video(conn: &rusqlite::Connection, video_path: &str) -> Result<(), String> {
+    for table in ["videos", "transcripts_fts", "pipeline_status", "media_probes"] {
+        conn.execute(&format!("DELETE FROM {} WHERE video_path = ?1", table), [video_path])
+            .map_err(|e| format!("인덱스 항목 삭제 실패 {}: {}", video_path, e))?;
+    }
+    Ok(())
+}
+
+// 삭제/이동된 경로(영상 폴더 또는 채널 폴더) 아래의 영상을 인덱스에서 제거
+fn forget_indexed_videos_under(path: &Path) -> Result<usize, String> {
+    let relative = PathBuf::from(project_relative_path(path));
+    let conn = open_index_db()?;
+    let paths: Vec<String> = {
+        let mut stmt = conn.prepare("SELECT video_path FROM videos").map_err(|e| e.to_string())?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0)).map_err(|e| e.to_string())?;
+        rows.flatten().filter(|p| Path::new(p).starts_with(&relative)).collect()
+    };
+    for video_path in &paths {
+        delete_indexed_video(&conn, video_path)?;
+    }
+    if !paths.is_empty() {
+        invalidate_autocomplete_index();
+    }
+    Ok(paths.len())
+}
+
+// 영상 한 건을 파일에서 다시 읽어 인덱스 갱신 (변환 등으로 파일이 바뀐 경우)
+fn reindex_video(video_path: &str) -> Result<(), String> {
+    let video = read_video_info(&get_project_root().join(video_path))?;
+    let conn = open_index_db()?;
+    upsert_video_record(&conn, &video, file_mtime_secs(&frontmatter_path(&video)), false)?;
+    index_transcript_text(&conn, &video)?;
+    index_caption_language(&conn, &video)?;
+    // 코덱/해상도가 바뀌었으므로 다음 수집 때 다시 읽음
+    conn.execute("DELETE FROM media_probes WHERE video_path = ?1", [video_path])
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+// 직접 수정한 frontmatter를 인덱스에 반영하고 재임베딩 대상으로 표시 (청크 메타데이터 갱신용)
+fn reindex_edited_video(video_path: &str) -> Result<(), String> {
+    reindex_video(video_path)?;
+    let conn = open_index_db()?;
+    conn.execute("UPDATE videos SET needs_reembedding = 1 WHERE video_path = ?1", [video_path])
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+// 영상별 captions.md 수정 시각 (외부 스크립트 실행 전후 비교용)
+fn captions_modified_times() -> HashMap<String, Option<std::time::SystemTime>> {
+    let mut files = Vec::new();
+    let _ = collect_video_files(&get_project_root().join("vault").join("10_videos"), &mut files);
+    files
+        .iter()
+        .map(|file| {
+            let captions_md = file.parent().map(|f| f.join("captions.md")).unwrap_or_else(|| file.clone());
+            (project_relative_path(file), fs::metadata(&captions_md).and_then(|m| m.modified()).ok())
+        })
+        .collect()
+}
+
+// 스크립트가 수정한 영상만 인덱스 갱신 (재임베딩 표시 없이), 갱신 수 반환
+fn reindex_videos_modified_since(before: &HashMap<String, Option<std::time::SystemTime>>) -> u32 {
+    let mut reindexed = 0u32;
+    for (video_path, modified) in captions_modified_times() {
+        if before.get(&video_path) == Some(&modified) {
+            continue;
+        }
+        match reindex_video(&video_path) {
+            Ok(()) => reindexed += 1,
+            Err(e) => eprintln!("⚠️ 인덱스 갱신 실패 {}: {}", video_path, e),
+        }
+    }
+    if reindexed > 0 {
+        invalidate_autocomplete_index();
+    }
+    reindexed
+}
+
+// vault 파일과 인덱스를 비교하여 변경된 항목 반영 (읽기 전용 모드면 거부)
+#[command]
+fn reconcile_vault(vault_lock: State<'_, VaultLockState>) -> Result<ReconcileReport, String> {
+    ensure_vault_writable(&vault_lock)?;
+    reconcile_vault_index()
+}
+
+// vault 파일과 인덱스를 비교하여 변경된 항목 반영
+// captions.md 수정 시간이 인덱스와 같은 영상은 다시 파싱하지 않음
+fn reconcile_vault_index() -> Result<ReconcileReport, String> {
+    let mut conn = open_index_db()?;
+    let mut report = ReconcileReport::default();
+    
+    let indexed: HashMap<String, Option<i64>> = {
+        let mut stmt = conn.prepare("SELECT video_path, captions_mtime FROM videos").map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, Option<i64>>(1)?)))
+            .map_err(|e| e.to_string())?
+            .collect::<Result<HashMap<_, _>, _>>()
+            .map_err(|e| e.to_string())?;
+        rows
+    };
+    let text_indexed: std::collections::HashSet<String> = {
+        let mut stmt = conn.prepare("SELECT video_path FROM transcripts_fts").map_err(|e| e.to_string())?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0)).map_err(|e| e.to_string())?;
+        rows.flatten().collect()
+    };
+    // 자막 언어를 아직 검사하지 않은 영상 (마이그레이션 이전에 인덱싱된 영상 포함)
+    let language_unchecked: std::collections::HashSet<String> = {
+        let mut stmt = conn.prepare("SELECT video_path FROM videos WHERE caption_language IS NULL").map_err(|e| e.to_string())?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0)).map_err(|e| e.to_string())?;
+        rows.flatten().collect()
+    };
+    
+    let videos_root = get_project_root().join("vault").join("10_videos");
+    if !videos_root.exists() {
+        return Err(format!("비디오 디렉토리가 존재하지 않습니다: {}", videos_root.display()));
+    }
+    let mut files = Vec::new();
+    collect_video_files(&videos_root, &mut files)?;
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+    let mut existing = std::collections::HashSet::new();
+    
+    for file in &files {
+        report.scanned += 1;
+        let video_path = project_relative_path(file);
+        let folder = file.parent().unwrap_or(&videos_root);
+        let mtime = file_mtime_secs(&folder.join("captions.md"));
+        
+        let (video, changed) = match indexed.get(&video_path) {
+            None => {
+                // 신규 영상은 embed.py가 처리하므로 재임베딩 표시 불필요
+                let video = read_video_info(file)?;
+                upsert_video_record(&tx, &video, mtime, false)?;
+                report.added += 1;
+                (Some(video), true)
+            }
+            Some(indexed_mtime) if mtime > *indexed_mtime => {
+                // 인덱싱 이후 수정된 파일 (Obsidian 직접 편집 등)
+                let video = read_video_info(file)?;
+                upsert_video_record(&tx, &video, mtime, indexed_mtime.is_some())?;
+                report.updated += 1;
+                if indexed_mtime.is_some() {
+                    report.flagged_for_reembedding.push(video_path.clone());
+                }
+                (Some(video), true)
+            }
+            Some(_) => {
+                // 자막 트랙/미디어 파일은 captions.md 수정 없이 추가·삭제될 수 있음
+                let caption_languages = serde_json::to_string(&caption_track_languages(folder)).unwrap_or_default();
+                tx.execute(
+                    "UPDATE videos SET caption_languages = ?2 WHERE video_path = ?1 AND IFNULL(caption_languages, '') != ?2",
+                    rusqlite::params![video_path, caption_languages],
+                )
+                .map_err(|e| e.to_string())?;
+                tx.execute(
+                    "UPDATE videos SET media_removed = ?2 WHERE video_path = ?1 AND media_removed != ?2",
+                    rusqlite::params![video_path, !file.exists()],
+                )
+                .map_err(|e| e.to_string())?;
+                (None, false)
+            }
+        };
+        
+        let needs_text = changed || !text_indexed.contains(&video_path);
+        let needs_language = changed || language_unchecked.contains(&video_path);
+        if needs_text || needs_language {
+            // 변경 없는 영상은 전문 검색/언어 정보가 빠진 경우에만 파싱
+            let video = match video {
+                Some(video) => video,
+                None => read_video_info(file)?,
+            };
+            if needs_text {
+                index_transcript_text(&tx, &video)?;
+            }
+            if needs_language {
+                index_caption_language(&tx, &video)?;
+            }
+        }
+        existing.insert(video_path);
+    }
+    
+    // vault에서 사라진 영상 정리
+    for path in indexed.keys().filter(|p| !existing.contains(p.as_str())) {
+        delete_indexed_video(&tx, path)?;
+        report.removed += 1;
+    }
+    
+    tx.commit().map_err(|e| format!("인덱스 동기화 커밋 실패: {}", e))?;
+    if report.added + report.updated + report.removed > 0 {
+        invalidate_autocomplete_index();
+    }
+    
+    let videos = load_indexed_videos(&conn)?;
+    if let Err(e) = refresh_pipeline_status(&mut conn, &videos) {
+        eprintln!("⚠️ 파이프라인 상태 갱신 실패: {}", e);
+    }
+    
+    if report.added + report.updated + report.removed > 0 {
+        if let Err(e) = rebuild_video_references(&mut conn) {
+            eprintln!("⚠️ 영상 참조 갱신 실패: {}", e);
+        }
+    }
+    Ok(report)
+}
+
+// 인덱스를 비우고 vault 전체를 다시 읽어 재구성 (인덱스가 꼬였을 때의 복구 수단)
+// 다운로드/재생 기록, 조회수 스냅샷, ffprobe 결과는 vault에서 다시 만들 수 없으므로 유지
+fn rebuild_index_blocking() -> Result<ReconcileReport, String> {
+    let mut conn = open_index_db()?;
+    let flagged: Vec<String> = {
+        let mut stmt = conn
+            .prepare("SELECT video_path FROM videos WHERE needs_reembedding = 1")
+            .map_err(|e| e.to_string())?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0)).map_err(|e| e.to_string())?;
+        rows.flatten().collect()
+    };
+    
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+    for table in ["videos", "transcripts_fts", "pipeline_status", "video_references"] {
+        tx.execute(&format!("DELETE FROM {}", table), [])
+            .map_err(|e| format!("인덱스 테이블 초기화 실패 {}: {}", table, e))?;
+    }
+    tx.commit().map_err(|e| format!("인덱스 초기화 커밋 실패: {}", e))?;
+    drop(conn);
+    
+    let report = reconcile_vault_index()?;
+    
+    // 재구성 전 재임베딩 대상으로 표시된 영상은 그대로 유지
+    let conn = open_index_db()?;
+    for video_path in &flagged {
+        conn.execute("UPDATE videos SET needs_reembedding = 1 WHERE video_path = ?1", [video_path])
+            .map_err(|e| e.to_string())?;
+    }
+    println!("🗄️ 인덱스 재구성 완료: 영상 {}개", report.added);
+    Ok(report)
+}
+
+#[command]
+async fn rebuild_index(state: State<'_, DownloadState>, vault_lock: State<'_, VaultLockState>) -> Result<ReconcileReport, String> {
+    ensure_vault_writable(&vault_lock)?;
+    if state.is_running.load(Ordering::SeqCst) {
+        return Err("다운로드 중에는 인덱스를 재구성할 수 없습니다".to_string());
+    }
+    tokio::task::spawn_blocking(rebuild_index_blocking)
+        .await
+        .map_err(|e| format!("인덱스 재구성 작업 실패: {}", e))?
+}
+
+// 영상 처리 파이프라인 단계 (다운로드 → 자막 → 썸네일 → 임베딩 → 요약 순으로 진행)
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum PipelineStage {
+    Downloaded,
+    Captions,
+    Thumbnails,
+    Embedded,
+    Summarized,
+}
+
+const PIPELINE_STAGES: [PipelineStage; 5] = [
+    PipelineStage::Downloaded,
+    PipelineStage::Captions,
+    PipelineStage::Thumbnails,
+    PipelineStage::Embedded,
+    PipelineStage::Summarized,
+];
+
+impl PipelineStage {
+    fn as_str(self) -> &'static str {
+        match self {
+            PipelineStage::Downloaded => "downloaded",
+            PipelineStage::Captions => "captions",
+            PipelineStage::Thumbnails => "thumbnails",
+            PipelineStage::Embedded => "embedded",
+            PipelineStage::Summarized => "summarized",
+        }
+    }
+    
+    fn parse(value: &str) -> Option<PipelineStage> {
+        PIPELINE_STAGES.into_iter().find(|stage| stage.as_str() == value)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+struct PipelineStatus {
+    downloaded: bool,
+    captions: bool,
+    thumbnails: bool,
+    embedded: bool,
+    summarized: bool,
+    stuck_at: Option<PipelineStage>, // 완료되지 않은 첫 단계 (모두 완료면 None)
+    updated_at: String,
+}
+
+impl PipelineStatus {
+    fn is_done(&self, stage: PipelineStage) -> bool {
+        match stage {
+            PipelineStage::Downloaded => self.downloaded,
+            PipelineStage::Captions => self.captions,
+            PipelineStage::Thumbnails => self.thumbnails,
+            PipelineStage::Embedded => self.embedded,
+            PipelineStage::Summarized => self.summarized,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct PipelineBacklogItem {
+    video_path: String,
+    captions_path: String,
+    video_id: Option<String>,
+    title: String,
+    channel: String,
+    source_url: Option<String>,
+}
+
+#[derive(Serialize)]
+struct PipelineStageBacklog {
+    stage: PipelineStage,
+    count: u32,
+    videos: Vec<PipelineBacklogItem>,
+}
+
+const SUMMARY_FILE: &str = "summary.md";
+// transcript_quality.py와 동일: 자막이 없을 때 captions.md에 기록되는 문구
+const NO_TRANSCRIPT_MARK: &str = "*자막을 사용할 수 없습니다.*";
+
+// Chroma에 임베딩된 video_id 목록 (chroma.sqlite3 직접 조회, 읽을 수 없으면 None)
+fn embedded_video_ids() -> Option<std::collections::HashSet<String>> {
+    let db_path = get_chroma_path().join("chroma.sqlite3");
+    if !db_path.exists() {
+        return Some(std::collections::HashSet::new());
+    }
+    let conn = rusqlite::Connection::open_with_flags(&db_path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY).ok()?;
+    let mut stmt = conn.prepare("SELECT DISTINCT embedding_id FROM embeddings").ok()?;
+    let ids = stmt.query_map([], |row| row.get::<_, String>(0)).ok()?.flatten().collect();
+    Some(ids)
+}
+
+// 영상별 파이프라인 상태 갱신 (자막 본문은 파일이 바뀐 경우에만 다시 읽음)
+fn refresh_pipeline_status(conn: &mut rusqlite::Connection, videos: &[VideoInfo]) -> Result<(), String> {
+    let previous: HashMap<String, (bool, bool, Option<i64>)> = {
+        let mut stmt = conn
+            .prepare("SELECT video_path, captions, embedded, captions_mtime FROM pipeline_status")
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, (row.get(1)?, row.get(2)?, row.get(3)?))))
+            .map_err(|e| e.to_string())?
+            .collect::<Result<HashMap<_, _>, _>>()
+            .map_err(|e| e.to_string())?;
+        rows
+    };
+    let embedded_ids = embedded_video_ids();
+    let project_root = get_project_root();
+    let now = chrono::Local::now().to_rfc3339();
+    
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+    for video in videos {
+        let video_file = project_root.join(&video.video_path);
+        let folder = video_file.parent().unwrap_or(&project_root);
+        let captions_file = frontmatter_path(video);
+        let captions_mtime = file_mtime_secs(&captions_file);
+        let prev = previous.get(&video.video_path);
+        
+        let captions = match prev {
+            Some((captions, _, mtime)) if mtime.is_some() && *mtime == captions_mtime => *captions,
+            _ => fs::read_to_string(&captions_file)
+                .map(|content| {
+                    let transcript = extract_transcript_text(&content);
+                    !transcript.is_empty() && transcript != NO_TRANSCRIPT_MARK
+                })
+                .unwrap_or(false),
+        };
+        // Chroma를 읽을 수 없으면 (임베딩 중 잠금 등) 이전 상태 유지
+        let embedded = match (&embedded_ids, &video.video_id) {
+            (Some(ids), Some(id)) => ids.contains(id),
+            (Some(_), None) => false,
+            (None, _) => prev.map(|p| p.1).unwrap_or(false),
+        };
+        let status = PipelineStatus {
+            downloaded: video_file.exists(),
+            captions,
+            thumbnails: folder.join("thumbnail.jpg").exists(),
+            embedded,
+            summarized: folder.join(SUMMARY_FILE).exists(),
+            stuck_at: None,
+            updated_at: now.clone(),
+        };
+        let stuck_at = PIPELINE_STAGES.into_iter().find(|stage| !status.is_done(*stage)).map(PipelineStage::as_str);
+        
+        tx.execute(
+            "INSERT INTO pipeline_status (video_path, downloaded, captions, thumbnails, embedded, summarized,
+                                          stuck_at, captions_mtime, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+             ON CONFLICT(video_path) DO UPDATE SET
+                 downloaded = excluded.downloaded, captions = excluded.captions, thumbnails = excluded.thumbnails,
+                 embedded = excluded.embedded, summarized = excluded.summarized, stuck_at = excluded.stuck_at,
+                 captions_mtime = excluded.captions_mtime, updated_at = excluded.updated_at",
+            rusqlite::params![
+                video.video_path,
+                status.downloaded,
+                status.captions,
+                status.thumbnails,
+                status.embedded,
+                status.summarized,
+                stuck_at,
+                captions_mtime,
+                status.updated_at,
+            ],
+        )
+        .map_err(|e| format!("파이프라인 상태 저장 실패 {}: {}", video.video_path, e))?;
+    }
+    tx.commit().map_err(|e| format!("파이프라인 상태 커밋 실패: {}", e))?;
+    Ok(())
+}
+
+// 특정 단계에서 멈춘 영상 목록
+fn pipeline_stage_items(conn: &rusqlite::Connection, stage: PipelineStage) -> Result<Vec<PipelineBacklogItem>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT v.video_path, v.captions_path, v.video_id, v.title, v.channel, v.source_url
+             FROM pipeline_status p JOIN videos v ON v.video_path = p.video_path
+             WHERE p.stuck_at = ?1 ORDER BY v.channel, v.upload_date DESC",
+        )
+        .map_err(|e| e.to_string())?;
+    let items = stmt
+        .query_map([stage.as_str()], |row| {
+            Ok(PipelineBacklogItem {
+                video_path: row.get(0)?,
+                captions_path: row.get(1)?,
+                video_id: row.get(2)?,
+                title: row.get(3)?,
+                channel: row.get(4)?,
+                source_url: row.get(5)?,
+            })
+        })
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    Ok(items)
+}
+
+// 단계별로 멈춘 영상 목록 (모든 단계 포함, 없으면 count 0)
+#[command]
+fn get_pipeline_backlog(state: State<'_, DownloadState>, vault_lock: State<'_, VaultLockState>) -> Result<Vec<PipelineStageBacklog>, String> {
+    if !state.is_running.load(Ordering::SeqCst) && !vault_lock.read_only.load(Ordering::SeqCst) {
+        reconcile_vault_index()?;
+    }
+    let conn = open_index_db()?;
+    PIPELINE_STAGES
+        .into_iter()
+        .map(|stage| {
+            let videos = pipeline_stage_items(&conn, stage)?;
+            Ok(PipelineStageBacklog { stage, count: videos.len() as u32, videos })
+        })
+        .collect()
+}
+
+// 특정 단계에서 멈춘 영상들의 누락된 단계 실행 (video_paths 미지정 시 해당 단계 전체)
+#[command]
+async fn remediate_pipeline_stage(
+    window: Window,
+    stage: PipelineStage,
+    video_paths: Option<Vec<String>>,
+    download_state: State<'_, DownloadState>,
+    derivative_state: State<'_, DerivativeState>,
+    embedding_state: State<'_, EmbeddingState>,
+    vault_lock: State<'_, VaultLockState>,
+) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let conn = open_index_db()?;
+    let targets: Vec<PipelineBacklogItem> = pipeline_stage_items(&conn, stage)?
+        .into_iter()
+        .filter(|item| video_paths.as_ref().map(|paths| paths.contains(&item.video_path)).unwrap_or(true))
+        .collect();
+    if targets.is_empty() {
+        return Ok(format!("'{}' 단계에서 멈춘 영상이 없습니다", stage.as_str()));
+    }
+    
+    let project_root = get_project_root();
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    let scripts_dir = project_root.join("vault").join("90_indices");
+    let captions_files: Vec<PathBuf> = targets
+        .iter()
+        .filter_map(|item| project_root.join(&item.video_path).parent().map(|f| f.join("captions.md")))
+        .collect();
+    // embed.py는 vault 채널 폴더 이름 기준 (vault/10_videos/<채널>/...)
+    let channel_dirs: Vec<String> = targets
+        .iter()
+        .filter_map(|item| Path::new(&item.video_path).components().nth(2).map(|c| c.as_os_str().to_string_lossy().to_string()))
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let label = format!("파이프라인: {} ({}개)", stage.as_str(), targets.len());
+    
+    let message = match stage {
+        PipelineStage::Downloaded => {
+            let mut queued = 0;
+            for item in &targets {
+                let Some(url) = item.source_url.clone() else { continue };
+                let ctx = enqueue_download_job(&download_state, DownloadJobRequest::Single {
+                    url,
+                    format: None,
+                    channel_name: Some(item.channel.clone()),
+                })?;
+                let window = window.clone();
+                let derivative_state = derivative_state.inner().clone();
+                thread::spawn(move || {
+                    let _ = run_download_job(&window, &derivative_state, ctx);
+                });
+                queued += 1;
+            }
+            format!("📥 {}개 영상 재다운로드를 대기열에 추가했습니다", queued)
+        }
+        PipelineStage::Captions => {
+            let _running = RunningGuard::try_new(&embedding_state.is_running).ok_or("임베딩 작업이 이미 실행 중입니다")?;
+            let mut cmd = low_priority_command(&venv_python);
+            cmd.arg(scripts_dir.join("retranscribe.py"))
+                .args(["--model", DEFAULT_WHISPER_MODEL, "--captions-files"])
+                .args(&captions_files)
+                .current_dir(&project_root)
+                .env("PYTHONUNBUFFERED", "1")
+                .env("YDH_EMBEDDING_MODELS", embedding_models_env());
+            apply_thread_limits(&mut cmd);
+            run_script_with_progress(&window, "pipeline-progress", &label, cmd)
+                .map_err(|e| format!("자막 전사 실패: {}", e))?;
+            format!("🎙️ {}개 영상 자막 전사 완료", targets.len())
+        }
+        PipelineStage::Thumbnails => {
+            if !spawn_derivative_generation(&window, derivative_state.inner(), None) {
+                return Err("미리보기 생성이 이미 진행 중입니다".to_string());
+            }
+            "🖼️ 썸네일 생성이 시작되었습니다".to_string()
+        }
+        PipelineStage::Embedded => {
+            let _running = RunningGuard::try_new(&embedding_state.is_running).ok_or("임베딩이 이미 진행 중입니다")?;
+            let mut cmd = Command::new(&venv_python);
+            cmd.arg(scripts_dir.join("embed.py"))
+                .arg("channels")
+                .args(&channel_dirs)
+                .current_dir(&project_root)
+                .env("PYTHONUNBUFFERED", "1")
+                .env("YDH_EMBEDDING_MODELS", embedding_models_env());
+            run_script_with_progress(&window, "pipeline-progress", &label, cmd)
+                .map_err(|e| format!("임베딩 실패: {}", e))?;
+            format!("🧠 {}개 채널 임베딩 완료", channel_dirs.len())
+        }
+        PipelineStage::Summarized => {
+            let mut cmd = Command::new(&venv_python);
+            cmd.arg(scripts_dir.join("summarize.py"))
+                .args(&captions_files)
+                .current_dir(&project_root)
+                .env("PYTHONUNBUFFERED", "1");
+            run_script_with_progress(&window, "pipeline-progress", &label, cmd)
+                .map_err(|e| format!("요약 생성 실패: {}", e))?;
+            format!("📝 {}개 영상 요약 생성 완료", targets.len())
+        }
+    };
+    
+    // 동기 실행한 단계는 바로 상태 반영 (대기열/백그라운드 작업은 다음 동기화 때 반영)
+    reconcile_vault_index()?;
+    Ok(message)
+}
+
+// 보관소 종합 상태 (정합성/파이프라인/인덱스/디스크/실패 영상을 하나의 점수와 권장 조치로 요약)
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+enum HealthPriority {
+    High,
+    Medium,
+    Low,
+}
+
+#[derive(Serialize)]
+struct HealthCheck {
+    key: String,
+    label: String,
+    ok: bool,
+    penalty: u32,
+    detail: String,
+}
+
+// 권장 조치: command는 프런트엔드에서 그대로 invoke할 기존 명령 이름
+#[derive(Serialize)]
+struct HealthAction {
+    priority: HealthPriority,
+    title: String,
+    description: String,
+    command: String,
+    args: serde_json::Value,
+}
+
+#[derive(Serialize)]
+struct VaultHealth {
+    score: u32,
+    level: String, // good / warning / critical
+    summary: String,
+    checked_at: String,
+    checks: Vec<HealthCheck>,
+    actions: Vec<HealthAction>,
+}
+
+const HEALTH_INTEGRITY_STALE_DAYS: i64 = 30;
+const HEALTH_WARNING_SCORE: u32 = 80;
+const HEALTH_CRITICAL_SCORE: u32 = 50;
+
+#[derive(Default)]
+struct HealthReport {
+    checks: Vec<HealthCheck>,
+    actions: Vec<HealthAction>,
+}
+
+impl HealthReport {
+    fn check(&mut self, key: &str, label: &str, penalty: u32, detail: String) {
+        self.checks.push(HealthCheck { key: key.to_string(), label: label.to_string(), ok: penalty == 0, penalty, detail });
+    }
+    
+    fn action(&mut self, priority: HealthPriority, title: &str, description: String, command: &str, args: serde_json::Value) {
+        self.actions.push(HealthAction {
+            priority,
+            title: title.to_string(),
+            description,
+            command: command.to_string(),
+            args,
+        });
+    }
+}
+
+fn health_integrity(report: &mut HealthReport) {
+    let record: Option<IntegrityCheckRecord> = load_json_config(LAST_INTEGRITY_CHECK_FILE).unwrap_or_default();
+    let check_action = |report: &mut HealthReport, priority, description: String| {
+        report.action(priority, "정합성 검사 실행", description, "check_integrity_with_progress", serde_json::json!({}));
+    };
+    match record {
+        None => {
+            report.check("integrity", "데이터 정합성", 5, "아직 정합성 검사를 실행한 적이 없습니다".to_string());
+            check_action(report, HealthPriority::Low, "영상 파일과 벡터 인덱스가 일치하는지 확인합니다".to_string());
+        }
+        Some(record) if !record.succeeded => {
+            report.check("integrity", "데이터 정합성", 10, "마지막 정합성 검사가 실패했습니다".to_string());
+            check_action(report, HealthPriority::Medium, "정합성 검사를 다시 실행해 결과를 확인합니다".to_string());
+        }
+        Some(record) if record.issues > 0 => {
+            report.check("integrity", "데이터 정합성", (10 + record.issues * 2).min(25), format!("{}개 문제가 발견되었습니다", record.issues));
+            report.action(
+                HealthPriority::High,
+                "누락된 임베딩 다시 만들기",
+                format!("정합성 검사에서 {}개 문제가 발견되었습니다. 누락된 벡터를 다시 만든 뒤 재검사하세요", record.issues),
+                "create_embeddings_with_progress",
+                serde_json::json!({}),
+            );
+        }
+        Some(record) => {
+            let stale = is_older_than_days(&Some(record.checked_at), HEALTH_INTEGRITY_STALE_DAYS);
+            let penalty = if stale { 3 } else { 0 };
+            let detail = if stale {
+                format!("마지막 검사 후 {}일이 지났습니다", HEALTH_INTEGRITY_STALE_DAYS)
+            } else {
+                "마지막 검사에서 문제가 없었습니다".to_string()
+            };
+            report.check("integrity", "데이터 정합성", penalty, detail);
+            if stale {
+                check_action(report, HealthPriority::Low, "오래된 검사 결과를 갱신합니다".to_string());
+            }
+        }
+    }
+}
+
+fn health_pipeline(report: &mut HealthReport) -> Result<(), String> {
+    let conn = open_index_db()?;
+    let total: i64 = conn.query_row("SELECT COUNT(*) FROM videos", [], |row| row.get(0)).map_err(|e| e.to_string())?;
+    let mut stuck_total = 0u32;
+    for stage in PIPELINE_STAGES {
+        let count = pipeline_stage_items(&conn, stage)?.len() as u32;
+        if count == 0 {
+            continue;
+        }
+        stuck_total += count;
+        // 다운로드 단계에서 멈춘 영상은 파일이 없으므로 가장 시급
+        let priority = if stage == PipelineStage::Downloaded { HealthPriority::High } else { HealthPriority::Medium };
+        report.action(
+            priority,
+            "멈춘 처리 단계 이어서 실행",
+            format!("'{}' 단계에서 {}개 영상이 멈춰 있습니다", stage.as_str(), count),
+            "remediate_pipeline_stage",
+            serde_json::json!({ "stage": stage }),
+        );
+    }
+    let ratio = stuck_total as f64 / total.max(1) as f64;
+    let penalty = if stuck_total == 0 { 0 } else { ((ratio * 50.0).ceil() as u32).clamp(2, 20) };
+    report.check("pipeline", "처리 대기", penalty, format!("전체 {}개 중 {}개 영상의 처리가 끝나지 않았습니다", total, stuck_total));
+    Ok(())
+}
+
+fn health_index(report: &mut HealthReport) -> Result<(), String> {
+    let health = get_index_health()?;
+    let mut penalty = 0;
+    let mut details = Vec::new();
+    if health.maintenance.last_integrity_ok == Some(false) {
+        penalty += 15;
+        details.push("인덱스 DB 무결성 검사에서 이상이 발견되었습니다".to_string());
+    }
+    if health.fragmentation_ratio > INDEX_VACUUM_FRAGMENTATION_THRESHOLD {
+        penalty += 3;
+        details.push(format!("빈 페이지 비율 {:.0}%", health.fragmentation_ratio * 100.0));
+    }
+    if penalty > 0 {
+        let priority = if health.maintenance.last_integrity_ok == Some(false) { HealthPriority::High } else { HealthPriority::Low };
+        report.action(priority, "인덱스 유지보수 실행", details.join(", "), "run_index_maintenance", serde_json::json!({}));
+    }
+    
+    // 임베딩 모델/청크 설정이 바뀐 컬렉션 (Python 환경이 없으면 건너뜀)
+    if let Ok(provenance) = read_index_provenance() {
+        let outdated: Vec<String> = provenance.collections.iter().filter(|c| c.outdated).map(|c| c.channel.clone()).collect();
+        if !outdated.is_empty() {
+            penalty += (outdated.len() as u32 * 4).min(15);
+            details.push(format!("{}개 채널의 벡터가 현재 임베딩 설정과 다릅니다", outdated.len()));
+            report.action(
+                HealthPriority::Medium,
+                "오래된 벡터 다시 임베딩",
+                format!("{} 채널을 현재 설정으로 다시 임베딩합니다", outdated.join(", ")),
+                "reembed_outdated",
+                serde_json::json!({}),
+            );
+        }
+    }
+    let detail = if details.is_empty() { "검색 인덱스가 최신 상태입니다".to_string() } else { details.join(" / ") };
+    report.check("index", "검색 인덱스", penalty, detail);
+    Ok(())
+}
+
+fn health_disk(report: &mut HealthReport) {
+    let (penalty, detail, priority) = match check_vault_disk_space() {
+        DiskSpaceCheck::Ok => (0, "여유 공간이 충분합니다".to_string(), None),
+        DiskSpaceCheck::Low(warning) => (10, warning.message, Some(HealthPriority::Medium)),
+        DiskSpaceCheck::Exhausted(warning) => (30, warning.message, Some(HealthPriority::High)),
+    };
+    report.check("disk", "디스크 여유 공간", penalty, detail.clone());
+    if let Some(priority) = priority {
+        report.action(priority, "저장 공간 예측 확인", format!("{}. 채널별 증가량을 보고 정리할 대상을 고르세요", detail), "get_storage_forecast", serde_json::json!({}));
+    }
+}
+
+fn health_failed_videos(report: &mut HealthReport) {
+    let verification: DownloadVerificationReport = load_json_config(DOWNLOAD_VERIFICATION_FILE).unwrap_or_default();
+    let run_report: Option<RunReport> = load_json_config::<Option<RunReport>>(LAST_RUN_REPORT_FILE).ok().flatten();
+    let incomplete = verification.incomplete.len() as u32;
+    let failed = run_report.as_ref().map(|r| r.total_failed).unwrap_or(0);
+    
+    let penalty = ((incomplete + failed) * 2).min(20);
+    report.check("failed_videos", "실패한 영상", penalty, format!("잘린 영상 {}개, 마지막 다운로드 실패 {}개", incomplete, failed));
+    if incomplete + failed > 0 {
+        report.action(
+            HealthPriority::Medium,
+            "실패한 영상 다시 받기",
+            format!("잘린 영상 {}개와 마지막 실행에서 실패한 {}개 영상을 다시 다운로드합니다", incomplete, failed),
+            "download_videos_with_progress",
+            serde_json::json!({}),
+        );
+    }
+    if verification.last_verified_at.is_none() {
+        report.action(
+            HealthPriority::Low,
+            "다운로드 검증 실행",
+            "받은 영상의 길이를 메타데이터와 비교해 잘린 파일을 찾습니다".to_string(),
+            "verify_downloaded_videos",
+            serde_json::json!({ "full": true }),
+        );
+    }
+}
+
+fn compute_vault_health() -> VaultHealth {
+    let mut report = HealthReport::default();
+    health_integrity(&mut report);
+    if let Err(e) = health_pipeline(&mut report) {
+        report.check("pipeline", "처리 대기", 0, format!("확인할 수 없음: {}", e));
+    }
+    if let Err(e) = health_index(&mut report) {
+        report.check("index", "검색 인덱스", 0, format!("확인할 수 없음: {}", e));
+    }
+    health_disk(&mut report);
+    health_failed_videos(&mut report);
+    
+    let penalty: u32 = report.checks.iter().map(|c| c.penalty).sum();
+    let score = 100u32.saturating_sub(penalty);
+    let (level, summary) = if score >= HEALTH_WARNING_SCORE {
+        ("good", "✅ 보관소 상태가 양호합니다")
+    } else if score >= HEALTH_CRITICAL_SCORE {
+        ("warning", "⚠️ 확인이 필요한 항목이 있습니다")
+    } else {
+        ("critical", "❌ 보관소에 조치가 필요합니다")
+    };
+    report.actions.sort_by_key(|a| a.priority);
+    
+    VaultHealth {
+        score,
+        level: level.to_string(),
+        summary: summary.to_string(),
+        checked_at: chrono::Local::now().to_rfc3339(),
+        checks: report.checks,
+        actions: report.actions,
+    }
+}
+
+// 보관소 종합 상태 점수와 우선순위별 권장 조치
+#[command]
+async fn get_vault_health() -> Result<VaultHealth, String> {
+    tokio::task::spawn_blocking(compute_vault_health)
+        .await
+        .map_err(|e| format!("보관소 상태 조회 작업 실패: {}", e))
+}
+
+// 자막 전문 검색 관련 구조체들
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+enum TextSearchBackend {
+    Tantivy,
+    #[default]
+    Fts5, // 인덱스 DB 내장, 메모리 사용량 최소 (저사양 NAS 등)
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+struct TextSearchSettings {
+    backend: TextSearchBackend,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+struct TranscriptSearchFilters {
+    channel: Option<String>,
+    upload_from: Option<String>,
+    upload_to: Option<String>,
+    caption_language: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TranscriptHit {
+    video_id: Option<String>,
+    video_path: String,
+    title: String,
+    channel: String,
+    upload_date: Option<String>,
+    snippet: String,
+    score: f64, // 클수록 관련도 높음
+}
+
+const TEXT_SEARCH_SETTINGS_FILE: &str = "text_search.json";
+const DEFAULT_TRANSCRIPT_SEARCH_LIMIT: usize = 50;
+
+// FTS5 질의 (trigram은 3글자 이상만 색인되므로 짧은 단어는 LIKE 조건으로 처리)
+fn fts5_search_transcripts(query: &str, filters: &TranscriptSearchFilters, limit: usize) -> Result<Vec<TranscriptHit>, String> {
+    let terms: Vec<&str> = query.split_whitespace().collect();
+    let match_terms: Vec<String> = terms
+        .iter()
+        .filter(|t| t.chars().count() >= 3)
+        .map(|t| format!("\"{}\"", t.replace('"', "\"\"")))
+        .collect();
+    let short_terms: Vec<&str> = terms.iter().copied().filter(|t| t.chars().count() < 3).collect();
+    
+    let mut sql = String::from(
+        "SELECT transcripts_fts.video_path, transcripts_fts.title, transcripts_fts.channel, transcripts_fts.upload_date, v.video_id, ",
+    );
+    let mut params: Vec<String> = Vec::new();
+    if match_terms.is_empty() {
+        sql.push_str("substr(transcripts_fts.content, 1, 200), 0.0 FROM transcripts_fts LEFT JOIN videos v ON v.video_path = transcripts_fts.video_path WHERE 1 = 1");
+    } else {
+        sql.push_str(
+            "snippet(transcripts_fts, 4, '[', ']', '…', 24), -bm25(transcripts_fts)
+             FROM transcripts_fts LEFT JOIN videos v ON v.video_path = transcripts_fts.video_path
+             WHERE transcripts_fts MATCH ?",
+        );
+        params.push(match_terms.join(" "));
+    }
+    for term in short_terms {
+        sql.push_str(" AND transcripts_fts.content LIKE '%' || ? || '%'");
+        params.push(term.to_string());
+    }
+    if let Some(channel) = &filters.channel {
+        sql.push_str(" AND (transcripts_fts.channel = ? OR transcripts_fts.video_path LIKE ?)");
+        params.push(channel.clone());
+        params.push(format!("vault/10_videos/{}/%", sanitize_vault_name(channel)));
+    }
+    if let Some(from) = &filters.upload_from {
+        sql.push_str(" AND transcripts_fts.upload_date >= ?");
+        params.push(from.clone());
+    }
+    if let Some(to) = &filters.upload_to {
+        sql.push_str(" AND transcripts_fts.upload_date <= ?");
+        params.push(to.clone());
+    }
+    if let Some(language) = filters.caption_language.as_ref().filter(|l| !l.is_empty()) {
+        sql.push_str(" AND (v.caption_language = ? OR v.caption_language LIKE ? || '-%')");
+        params.push(language.clone());
+        params.push(language.clone());
+    }
+    sql.push_str(if match_terms.is_empty() { " ORDER BY transcripts_fts.upload_date DESC" } else { " ORDER BY bm25(transcripts_fts)" });
+    sql.push_str(&format!(" LIMIT {}", limit));
+    
+    let conn = open_index_db()?;
+    let mut stmt = conn.prepare(&sql).map_err(|e| format!("자막 검색 실패: {}", e))?;
+    let hits = stmt
+        .query_map(rusqlite::params_from_iter(params.iter()), |row| {
+            Ok(TranscriptHit {
+                video_path: row.get(0)?,
+                title: row.get(1)?,
+                channel: row.get(2)?,
+                upload_date: row.get(3)?,
+                video_id: row.get(4)?,
+                snippet: row.get(5)?,
+                score: row.get(6)?,
+            })
+        })
+        .map_err(|e| format!("자막 검색 실패: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("자막 검색 실패: {}", e))?;
+    Ok(hits)
+}
+
+// 설정된 검색 엔진으로 자막 전문 검색
+#[command]
+fn search_transcripts(query: String, filters: Option<TranscriptSearchFilters>, limit: Option<usize>) -> Result<Vec<TranscriptHit>, String> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    let filters = filters.unwrap_or_default();
+    let limit = limit.unwrap_or(DEFAULT_TRANSCRIPT_SEARCH_LIMIT).clamp(1, 500);
+    
+    let settings: TextSearchSettings = load_json_config(TEXT_SEARCH_SETTINGS_FILE)?;
+    match settings.backend {
+        TextSearchBackend::Fts5 => fts5_search_transcripts(query, &filters, limit),
+        TextSearchBackend::Tantivy => Ok(tantivy_search_captions(query, &filters, limit)?
+            .into_iter()
+            .map(|hit| TranscriptHit {
+                video_id: hit.video_id,
+                video_path: hit.video_path,
+                title: hit.title,
+                channel: hit.channel,
+                upload_date: hit.upload_date,
+                snippet: hit.snippet,
+                score: hit.score,
+            })
+            .collect()),
+    }
+}
+
+#[command]
+fn get_text_search_settings() -> Result<TextSearchSettings, String> {
+    load_json_config(TEXT_SEARCH_SETTINGS_FILE)
+}
+
+#[command]
+fn save_text_search_settings(settings: TextSearchSettings) -> Result<(), String> {
+    save_json_config(TEXT_SEARCH_SETTINGS_FILE, &settings)
+}
+
+// Tantivy 자막 검색 인덱스 (타임코드 자막을 구간 단위 문서로 색인해 언급 위치까지 찾음)
+const TEXT_INDEX_TOKENIZER: &str = "caption_ngram";
+const TEXT_INDEX_WRITER_HEAP_BYTES: usize = 50_000_000;
+// 타임코드 자막 구간을 이 길이(초)만큼 묶어 문서 하나로 색인
+const TEXT_INDEX_WINDOW_SECONDS: f64 = 30.0;
+const TEXT_INDEX_SNIPPET_CHARS: usize = 160;
+
+#[derive(Serialize)]
+struct TextIndexReport {
+    videos: u32,
+    timed_videos: u32, // 타임코드 자막으로 색인한 영상 (나머지는 captions.md 본문 전체를 문서 하나로)
+    documents: u32,
+    built_at: String,
+}
+
+#[derive(Serialize)]
+struct CaptionSearchHit {
+    video_id: Option<String>,
+    video_path: String,
+    title: String,
+    channel: String,
+    upload_date: Option<String>,
+    snippet: String,
+    timestamp: Option<f64>, // 구간 시작 (초), 타임코드 자막이 없으면 None
+    score: f64,
+}
+
+struct TextIndexFields {
+    video_path: tantivy::schema::Field,
+    video_id: tantivy::schema::Field,
+    title: tantivy::schema::Field,
+    channel: tantivy::schema::Field,
+    channel_folder: tantivy::schema::Field,
+    upload_date: tantivy::schema::Field,
+    upload_day: tantivy::schema::Field, // YYYYMMDD (기간 필터용)
+    caption_language: tantivy::schema::Field,
+    start: tantivy::schema::Field,
+    text: tantivy::schema::Field,
+}
+
+fn get_text_index_path() -> PathBuf {
+    get_project_root().join("vault").join("90_indices").join("tantivy")
+}
+
+fn text_index_schema() -> tantivy::schema::Schema {
+    use tantivy::schema::{IndexRecordOption, TextFieldIndexing, TextOptions, FAST, INDEXED, STORED, STRING};
+    
+    let mut builder = tantivy::schema::Schema::builder();
+    builder.add_text_field("video_path", STRING | STORED);
+    builder.add_text_field("video_id", STRING | STORED);
+    builder.add_text_field("title", STORED);
+    builder.add_text_field("channel", STRING | STORED);
+    builder.add_text_field("channel_folder", STRING);
+    builder.add_text_field("upload_date", STORED);
+    builder.add_i64_field("upload_day", INDEXED | FAST);
+    builder.add_text_field("caption_language", STRING);
+    builder.add_f64_field("start", STORED);
+    // 한국어는 조사가 붙어 띄어쓰기 단위로 찾기 어려우므로 2~3글자 n-gram으로 색인 (위치 정보는 불필요)
+    let indexing = TextFieldIndexing::default()
+        .set_tokenizer(TEXT_INDEX_TOKENIZER)
+        .set_index_option(IndexRecordOption::WithFreqs);
+    builder.add_text_field("text", TextOptions::default().set_indexing_options(indexing).set_stored());
+    builder.build()
+}
+
+fn text_index_fields(schema: &tantivy::schema::Schema) -> Result<TextIndexFields, String> {
+    let field = |name: &str| schema.get_field(name).map_err(|_| format!("자막 검색 인덱스 스키마에 {} 필드가 없습니다. 인덱스를 다시 생성하세요", name));
+    Ok(TextIndexFields {
+        video_path: field("video_path")?,
+        video_id: field("video_id")?,
+        title: field("title")?,
+        channel: field("channel")?,
+        channel_folder: field("channel_folder")?,
+        upload_date: field("upload_date")?,
+        upload_day: field("upload_day")?,
+        caption_language: field("caption_language")?,
+        start: field("start")?,
+        text: field("text")?,
+    })
+}
+
+fn register_text_index_tokenizer(index: &tantivy::Index) -> Result<(), String> {
+    let ngram = tantivy::tokenizer::NgramTokenizer::new(2, 3, false).map_err(|e| e.to_string())?;
+    let analyzer = tantivy::tokenizer::TextAnalyzer::builder(ngram)
+        .filter(tantivy::tokenizer::LowerCaser)
+        .build();
+    index.tokenizers().register(TEXT_INDEX_TOKENIZER, analyzer);
+    Ok(())
+}
+
+fn open_text_index() -> Result<tantivy::Index, String> {
+    let path = get_text_index_path();
+    if !path.join("meta.json").exists() {
+        return Err("자막 검색 인덱스가 아직 없습니다. 먼저 인덱스를 생성하세요".to_string());
+    }
+    let index = tantivy::Index::open_in_dir(&path).map_err(|e| format!("자막 검색 인덱스 열기 실패: {}", e))?;
+    register_text_index_tokenizer(&index)?;
+    Ok(index)
+}
+
+// 타임코드 자막 구간을 일정 길이 단위로 묶음 (시작 시각, 본문)
+fn caption_windows(segments: &[TranscriptSegment]) -> Vec<(f64, String)> {
+    let mut windows: Vec<(f64, String)> = Vec::new();
+    for segment in segments {
+        match windows.last_mut() {
+            Some((start, text)) if segment.start - *start < TEXT_INDEX_WINDOW_SECONDS => {
+                text.push(' ');
+                text.push_str(&segment.text);
+            }
+            _ => windows.push((segment.start, segment.text.clone())),
+        }
+    }
+    windows
+}
+
+// 영상 인덱스의 전체 영상으로 자막 검색 인덱스를 새로 만들고 기존 인덱스와 교체
+fn build_text_index_blocking() -> Result<TextIndexReport, String> {
+    let videos = load_indexed_videos(&open_index_db()?)?;
+    let project_root = get_project_root();
+    let index_path = get_text_index_path();
+    let building_path = index_path.with_extension("building");
+    let _ = fs::remove_dir_all(&building_path);
+    fs::create_dir_all(&building_path).map_err(|e| format!("자막 검색 인덱스 디렉토리 생성 실패: {}", e))?;
+    
+    let schema = text_index_schema();
+    let fields = text_index_fields(&schema)?;
+    let index = tantivy::Index::create_in_dir(&building_path, schema).map_err(|e| format!("자막 검색 인덱스 생성 실패: {}", e))?;
+    register_text_index_tokenizer(&index)?;
+    let mut writer: tantivy::IndexWriter = index
+        .writer(TEXT_INDEX_WRITER_HEAP_BYTES)
+        .map_err(|e| format!("자막 검색 인덱스 쓰기 준비 실패: {}", e))?;
+    
+    let mut report = TextIndexReport { videos: 0, timed_videos: 0, documents: 0, built_at: String::new() };
+    for video in &videos {
+        let Some(folder) = project_root.join(&video.video_path).parent().map(Path::to_path_buf) else { continue };
+        let windows: Vec<(Option<f64>, String)> = match preferred_timed_caption(&folder, &video.channel)
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|content| caption_windows(&parse_timed_captions(&content)))
+            .filter(|windows| !windows.is_empty())
+        {
+            Some(windows) => {
+                report.timed_videos += 1;
+                windows.into_iter().map(|(start, text)| (Some(start), text)).collect()
+            }
+            None => {
+                let text = fs::read_to_string(frontmatter_path(video)).map(|c| extract_transcript_text(&c)).unwrap_or_default();
+                if text.is_empty() || text == NO_TRANSCRIPT_MARK {
+                    continue;
+                }
+                vec![(None, text)]
+            }
+        };
+        
+        let upload_day = video
+            .upload_date
+            .as_deref()
+            .and_then(|d| d.replace('-', "").parse::<i64>().ok())
+            .unwrap_or(0);
+        let channel_folder = video_channel_folder(&video.video_path).unwrap_or_default();
+        for (start, text) in windows {
+            let mut doc = tantivy::TantivyDocument::default();
+            doc.add_text(fields.video_path, &video.video_path);
+            if let Some(video_id) = &video.video_id {
+                doc.add_text(fields.video_id, video_id);
+            }
+            doc.add_text(fields.title, &video.title);
+            doc.add_text(fields.channel, &video.channel);
+            doc.add_text(fields.channel_folder, &channel_folder);
+            if let Some(upload_date) = &video.upload_date {
+                doc.add_text(fields.upload_date, upload_date);
+            }
+            doc.add_i64(fields.upload_day, upload_day);
+            if let Some(language) = &video.caption_language {
+                doc.add_text(fields.caption_language, language);
+            }
+            if let Some(start) = start {
+                doc.add_f64(fields.start, start);
+            }
+            doc.add_text(fields.text, &text);
+            writer.add_document(doc).map_err(|e| format!("자막 검색 인덱스 문서 추가 실패: {}", e))?;
+            report.documents += 1;
+        }
+        report.videos += 1;
+    }
+    writer.commit().map_err(|e| format!("자막 검색 인덱스 커밋 실패: {}", e))?;
+    writer.wait_merging_threads().map_err(|e| format!("자막 검색 인덱스 병합 실패: {}", e))?;
+    drop(index);
+    
+    // 완성된 인덱스로 교체 (실패하면 기존 인덱스 유지)
+    let previous_path = index_path.with_extension("previous");
+    let _ = fs::remove_dir_all(&previous_path);
+    if index_path.exists() {
+        fs::rename(&index_path, &previous_path).map_err(|e| format!("기존 자막 검색 인덱스 이동 실패: {}", e))?;
+    }
+    if let Err(e) = fs::rename(&building_path, &index_path) {
+        if previous_path.exists() {
+            let _ = fs::rename(&previous_path, &index_path);
+        }
+        return Err(format!("자막 검색 인덱스 교체 실패: {}", e));
+    }
+    let _ = fs::remove_dir_all(&previous_path);
+    
+    report.built_at = chrono::Local::now().to_rfc3339();
+    println!("🔎 자막 검색 인덱스 생성: 영상 {}개, 문서 {}개", report.videos, report.documents);
+    Ok(report)
+}
+
+// 자막 검색 인덱스 생성 (영상 인덱스 기준 전체 재생성)
+#[command]
+async fn build_text_index(vault_lock: State<'_, VaultLockState>) -> Result<TextIndexReport, String> {
+    ensure_vault_writable(&vault_lock)?;
+    tokio::task::spawn_blocking(build_text_index_blocking)
+        .await
+        .map_err(|e| format!("자막 검색 인덱스 생성 작업 실패: {}", e))?
+}
+
+// 스니펫의 일치 구간을 [ ]로 표시 (n-gram이라 겹치는 구간은 합침)
+fn mark_snippet(snippet: &tantivy::Snippet) -> String {
+    let fragment = snippet.fragment();
+    let mut ranges: Vec<std::ops::Range<usize>> = snippet.highlighted().to_vec();
+    ranges.sort_by_key(|r| r.start);
+    let mut merged: Vec<std::ops::Range<usize>> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    let mut marked = String::new();
+    let mut cursor = 0;
+    for range in merged {
+        marked.push_str(&fragment[cursor..range.start]);
+        marked.push('[');
+        marked.push_str(&fragment[range.clone()]);
+        marked.push(']');
+        cursor = range.end;
+    }
+    marked.push_str(&fragment[cursor..]);
+    marked
+}
+
+fn tantivy_search_captions(query: &str, filters: &TranscriptSearchFilters, limit: usize) -> Result<Vec<CaptionSearchHit>, String> {
+    use tantivy::query::{BooleanQuery, Occur, Query, RangeQuery, TermQuery};
+    use tantivy::schema::{IndexRecordOption, Value};
+    use tantivy::Term;
+    
+    let index = open_text_index()?;
+    let fields = text_index_fields(&index.schema())?;
+    let mut analyzer = index.tokenizer_for_field(fields.text).map_err(|e| e.to_string())?;
+    let term_query = |field: tantivy::schema::Field, value: &str| -> Box<dyn Query> {
+        Box::new(TermQuery::new(Term::from_field_text(field, value), IndexRecordOption::Basic))
+    };
+    
+    // 단어마다 n-gram이 모두 들어 있는 구간만 (1글자 단어는 n-gram이 없어 무시)
+    let mut clauses: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+    for word in query.split_whitespace() {
+        let mut stream = analyzer.token_stream(word);
+        while stream.advance() {
+            let gram = stream.token().text.clone();
+            clauses.push((Occur::Must, term_query(fields.text, &gram)));
+        }
+    }
+    if clauses.is_empty() {
+        return Ok(Vec::new());
+    }
+    let text_query = BooleanQuery::new(std::mem::take(&mut clauses));
+    clauses.push((Occur::Must, Box::new(text_query.clone())));
+    
+    if let Some(channel) = filters.channel.as_ref().filter(|c| !c.is_empty()) {
+        clauses.push((
+            Occur::Must,
+            Box::new(BooleanQuery::new(vec![
+                (Occur::Should, term_query(fields.channel, channel)),
+                (Occur::Should, term_query(fields.channel_folder, &sanitize_vault_name(channel))),
+            ])),
+        ));
+    }
+    if let Some(language) = filters.caption_language.as_ref().filter(|l| !l.is_empty()) {
+        let mut variants = subtitle_language_variants(language);
+        variants.push(language.clone());
+        clauses.push((
+            Occur::Must,
+            Box::new(BooleanQuery::new(
+                variants.iter().map(|v| (Occur::Should, term_query(fields.caption_language, v))).collect(),
+            )),
+        ));
+    }
+    let day = |date: &Option<String>| date.as_deref().and_then(|d| d.replace('-', "").parse::<i64>().ok());
+    let (from, to) = (day(&filters.upload_from), day(&filters.upload_to));
+    if from.is_some() || to.is_some() {
+        let bound = |value: Option<i64>| value.map(std::ops::Bound::Included).unwrap_or(std::ops::Bound::Unbounded);
+        clauses.push((Occur::Must, Box::new(RangeQuery::new_i64_bounds("upload_day".to_string(), bound(from), bound(to)))));
+    }
+    let query = BooleanQuery::new(clauses);
+    
+    let reader = index.reader().map_err(|e| format!("자막 검색 인덱스 읽기 실패: {}", e))?;
+    let searcher = reader.searcher();
+    let top_docs = searcher
+        .search(&query, &tantivy::collector::TopDocs::with_limit(limit))
+        .map_err(|e| format!("자막 검색 실패: {}", e))?;
+    let mut snippets = tantivy::SnippetGenerator::create(&searcher, &text_query, fields.text)
+        .map_err(|e| format!("자막 검색 실패: {}", e))?;
+    snippets.set_max_num_chars(TEXT_INDEX_SNIPPET_CHARS);
+    
+    let mut hits = Vec::new();
+    for (score, address) in top_docs {
+        let doc: tantivy::TantivyDocument = searcher.doc(address).map_err(|e| format!("자막 검색 결과 읽기 실패: {}", e))?;
+        let text = |field| doc.get_first(field).and_then(|v| v.as_str()).map(str::to_string);
+        hits.push(CaptionSearchHit {
+            video_id: text(fields.video_id),
+            video_path: text(fields.video_path).unwrap_or_default(),
+            title: text(fields.title).unwrap_or_default(),
+            channel: text(fields.channel).unwrap_or_default(),
+            upload_date: text(fields.upload_date),
+            snippet: mark_snippet(&snippets.snippet_from_doc(&doc)),
+            timestamp: doc.get_first(fields.start).and_then(|v| v.as_f64()),
+            score: score as f64,
+        });
+    }
+    Ok(hits)
+}
+
+// 자막 전문 검색 (Tantivy 인덱스, 일치한 자막 구간의 재생 위치 포함)
+#[command]
+async fn search_captions(query: String, filters: Option<TranscriptSearchFilters>, limit: Option<usize>) -> Result<Vec<CaptionSearchHit>, String> {
+    let query = query.trim().to_string();
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    let filters = filters.unwrap_or_default();
+    let limit = limit.unwrap_or(DEFAULT_TRANSCRIPT_SEARCH_LIMIT).clamp(1, 500);
+    tokio::task::spawn_blocking(move || tantivy_search_captions(&query, &filters, limit))
+        .await
+        .map_err(|e| format!("자막 검색 작업 실패: {}", e))?
+}
+
+// 자막 용어 추세 관련 구조체들
+#[derive(Serialize, Deserialize)]
+struct TermTrendPoint {
+    period: String,
+    channel: String,
+    occurrences: u32,
+    videos: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TermTrends {
+    term: String,
+    granularity: String,
+    total_occurrences: u32,
+    // 언급이 없는 기간은 포함되지 않음 (0으로 간주)
+    points: Vec<TermTrendPoint>,
+}
+
+// 업로드 날짜(YYYY-MM-DD)를 집계 단위로 변환
+fn trend_period(upload_date: &str, granularity: &str) -> Option<String> {
+    use chrono::Datelike;
+    
+    let date = chrono::NaiveDate::parse_from_str(upload_date, "%Y-%m-%d").ok()?;
+    Some(match granularity {
+        "year" => date.format("%Y").to_string(),
+        "week" => {
+            let week = date.iso_week();
+            format!("{}-W{:02}", week.year(), week.week())
+        }
+        _ => date.format("%Y-%m").to_string(),
+    })
+}
+
+// 전문 검색 인덱스 기준 용어 언급 빈도 추세 (채널 × 기간)
+#[command]
+fn get_term_trends(term: String, channels: Option<Vec<String>>, granularity: Option<String>) -> Result<TermTrends, String> {
+    let term = term.trim().to_string();
+    if term.is_empty() {
+        return Err("검색어를 입력하세요".to_string());
+    }
+    let granularity = match granularity.as_deref() {
+        Some("week") => "week",
+        Some("year") => "year",
+        _ => "month",
+    };
+    let channel_filters: Vec<(String, String)> = channels
+        .unwrap_or_default()
+        .into_iter()
+        .map(|c| {
+            let prefix = format!("vault/10_videos/{}/", sanitize_vault_name(&c));
+            (c, prefix)
+        })
+        .collect();
+    
+    let conn = open_index_db()?;
+    // trigram 토크나이저는 3글자 이상만 MATCH 가능하므로 짧은 용어는 LIKE로 조회
+    let (sql, param) = if term.chars().count() >= 3 {
+        (
+            "SELECT video_path, channel, upload_date, content FROM transcripts_fts WHERE content MATCH ?1",
+            format!("\"{}\"", term.replace('"', "\"\"")),
+        )
+    } else {
+        (
+            "SELECT video_path, channel, upload_date, content FROM transcripts_fts WHERE content LIKE '%' || ?1 || '%'",
+            term.clone(),
+        )
+    };
+    let mut stmt = conn.prepare(sql).map_err(|e| format!("용어 검색 실패: {}", e))?;
+    let rows = stmt
+        .query_map([param], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, Option<String>>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })
+        .map_err(|e| format!("용어 검색 실패: {}", e))?;
+    
+    let needle = term.to_lowercase();
+    let mut buckets: BTreeMap<(String, String), (u32, u32)> = BTreeMap::new();
+    for (video_path, channel, upload_date, content) in rows.flatten() {
+        if !channel_filters.is_empty()
+            && !channel_filters.iter().any(|(name, prefix)| *name == channel || video_path.starts_with(prefix.as_str()))
+        {
+            continue;
+        }
+        let Some(period) = upload_date.as_deref().and_then(|d| trend_period(d, granularity)) else {
+            continue;
+        };
+        let occurrences = content.to_lowercase().matches(needle.as_str()).count() as u32;
+        if occurrences == 0 {
+            continue;
+        }
+        let bucket = buckets.entry((period, channel)).or_insert((0, 0));
+        bucket.0 += occurrences;
+        bucket.1 += 1;
+    }
+    
+    let points: Vec<TermTrendPoint> = buckets
+        .into_iter()
+        .map(|((period, channel), (occurrences, videos))| TermTrendPoint { period, channel, occurrences, videos })
+        .collect();
+    
+    Ok(TermTrends {
+        total_occurrences: points.iter().map(|p| p.occurrences).sum(),
+        term,
+        granularity: granularity.to_string(),
+        points,
+    })
+}
+
+// 영상 간 상호 참조 관련 구조체들
+#[derive(Serialize, Deserialize)]
+struct VideoReference {
+    video_id: String,
+    title: String,
+    channel: String,
+    video_path: String,
+    kind: String,            // "url" (설명/자막의 명시적 링크) | "title" (자막에서 제목 언급)
+    context: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct VideoReferences {
+    cited_by: Vec<VideoReference>, // 이 영상을 언급한 영상들
+    cites: Vec<VideoReference>,    // 이 영상이 언급한 영상들
+}
+
+const MIN_REFERENCE_TITLE_CHARS: usize = 6;
+
+// 제목 언급 검색용 핵심 구절 (구분자로 나눈 가장 긴 부분)
+fn reference_title_phrase(title: &str) -> Option<String> {
+    title
+        .split(['|', '[', ']', '(', ')', '-', ':', '/', '#', '"'])
+        .map(|part| part.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|part| part.chars().filter(|c| c.is_alphanumeric()).count() >= MIN_REFERENCE_TITLE_CHARS)
+        .max_by_key(|part| part.chars().count())
+}
+
+// 설명(description.txt)과 captions.md에서 명시적 YouTube 링크 추출
+fn extract_linked_video_ids(video: &VideoInfo) -> Vec<(String, String)> {
+    let link_re = Regex::new(r"(?:youtube\.com/(?:watch\?(?:[^\s]*&)?v=|shorts/|live/)|youtu\.be/)([A-Za-z0-9_-]{11})").unwrap();
+    let captions = frontmatter_path(video);
+    let sources = [captions.with_file_name("description.txt"), captions];
+    
+    let mut linked = Vec::new();
+    for source in sources {
+        let Ok(text) = fs::read_to_string(&source) else {
+            continue;
+        };
+        for caps in link_re.captures_iter(&text) {
+            // 링크가 포함된 줄을 문맥으로 저장
+            let found = caps.get(0).unwrap();
+            let line_start = text[..found.start()].rfind('\n').map(|i| i + 1).unwrap_or(0);
+            let line_end = text[found.end()..].find('\n').map(|i| found.end() + i).unwrap_or(text.len());
+            linked.push((caps[1].to_string(), text[line_start..line_end].trim().chars().take(200).collect()));
+        }
+    }
+    linked
+}
+
+// 전체 영상 간 참조 재계산 (링크 + 자막 내 제목 언급)
+fn rebuild_video_references(conn: &mut rusqlite::Connection) -> Result<u32, String> {
+    let videos = scan_vault_videos()?;
+    let by_path: HashMap<&str, &str> = videos
+        .iter()
+        .filter_map(|v| Some((v.video_path.as_str(), v.video_id.as_deref()?)))
+        .collect();
+    let archived: std::collections::HashSet<&str> = by_path.values().copied().collect();
+    let now = chrono::Local::now().to_rfc3339();
+    
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+    tx.execute("DELETE FROM video_references", []).map_err(|e| e.to_string())?;
+    let mut inserted = 0u32;
+    
+    {
+        let mut insert = tx
+            .prepare(
+                "INSERT OR IGNORE INTO video_references (source_video_id, target_video_id, kind, context, detected_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+            )
+            .map_err(|e| e.to_string())?;
+        
+        for video in &videos {
+            let Some(source_id) = video.video_id.as_deref() else {
+                continue;
+            };
+            for (target_id, context) in extract_linked_video_ids(video) {
+                if target_id != source_id && archived.contains(target_id.as_str()) {
+                    inserted += insert
+                        .execute(rusqlite::params![source_id, target_id, "url", context, now])
+                        .map_err(|e| e.to_string())? as u32;
+                }
+            }
+        }
+        
+        // 다른 영상 자막에서 제목 핵심 구절이 등장하는 경우 (trigram 전문 검색)
+        let mut search = tx
+            .prepare(
+                "SELECT video_path, snippet(transcripts_fts, 4, '', '', '…', 16)
+                 FROM transcripts_fts WHERE content MATCH ?1 LIMIT 50",
+            )
+            .map_err(|e| e.to_string())?;
+        for video in &videos {
+            let (Some(target_id), Some(phrase)) = (video.video_id.as_deref(), reference_title_phrase(&video.title)) else {
+                continue;
+            };
+            let query = format!("\"{}\"", phrase.replace('"', "\"\""));
+            let matches: Vec<(String, String)> = search
+                .query_map([query], |row| Ok((row.get(0)?, row.get(1)?)))
+                .map_err(|e| e.to_string())?
+                .flatten()
+                .collect();
+            for (video_path, snippet) in matches {
+                let Some(source_id) = by_path.get(video_path.as_str()) else {
+                    continue;
+                };
+                if *source_id != target_id {
+                    inserted += insert
+                        .execute(rusqlite::params![source_id, target_id, "title", snippet, now])
+                        .map_err(|e| e.to_string())? as u32;
+                }
+            }
+        }
+    }
+    
+    tx.commit().map_err(|e| format!("영상 참조 저장 실패: {}", e))?;
+    Ok(inserted)
+}
+
+// 영상 간 참조 다시 계산
+#[command]
+fn detect_video_references(vault_lock: State<'_, VaultLockState>) -> Result<u32, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let mut conn = open_index_db()?;
+    rebuild_video_references(&mut conn)
+}
+
+// 영상을 언급한 영상/영상이 언급한 영상 조회
+#[command]
+fn get_video_references(video_id: String) -> Result<VideoReferences, String> {
+    let conn = open_index_db()?;
+    let query = |sql: &str| -> Result<Vec<VideoReference>, String> {
+        let mut stmt = conn.prepare(sql).map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map([&video_id], |row| {
+                Ok(VideoReference {
+                    video_id: row.get(0)?,
+                    title: row.get(1)?,
+                    channel: row.get(2)?,
+                    video_path: row.get(3)?,
+                    kind: row.get(4)?,
+                    context: row.get(5)?,
+                })
+            })
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        Ok(rows)
+    };
+    
+    Ok(VideoReferences {
+        cited_by: query(
+            "SELECT v.video_id, v.title, v.channel, v.video_path, r.kind, r.context
+             FROM video_references r JOIN videos v ON v.video_id = r.source_video_id
+             WHERE r.target_video_id = ?1 ORDER BY r.kind DESC, v.upload_date DESC",
+        )?,
+        cites: query(
+            "SELECT v.video_id, v.title, v.channel, v.video_path, r.kind, r.context
+             FROM video_references r JOIN videos v ON v.video_id = r.target_video_id
+             WHERE r.source_video_id = ?1 ORDER BY r.kind DESC, v.upload_date DESC",
+        )?,
+    })
+}
+
+// 재임베딩이 필요한 영상 목록
+#[command]
+fn get_videos_needing_reembedding() -> Result<Vec<ReembeddingCandidate>, String> {
+    let conn = open_index_db()?;
+    let mut stmt = conn
+        .prepare("SELECT video_path, video_id, title, channel FROM videos WHERE needs_reembedding = 1 ORDER BY channel, upload_date")
+        .map_err(|e| e.to_string())?;
+    let candidates = stmt
+        .query_map([], |row| {
+            Ok(ReembeddingCandidate {
+                video_path: row.get(0)?,
+                video_id: row.get(1)?,
+                title: row.get(2)?,
+                channel: row.get(3)?,
+            })
+        })
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    Ok(candidates)
+}
+
+// 표시된 영상의 임베딩을 다시 생성하고 표시 해제
+#[command]
+async fn reembed_flagged_videos(window: Window, state: State<'_, EmbeddingState>, vault_lock: State<'_, VaultLockState>) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let candidates = get_videos_needing_reembedding()?;
+    if candidates.is_empty() {
+        return Ok("재임베딩이 필요한 영상이 없습니다".to_string());
+    }
+    
+    let project_root = get_project_root();
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    let embed_script = project_root.join("vault").join("90_indices").join("embed.py");
+    if !venv_python.exists() || !embed_script.exists() {
+        return Err("Python 가상환경 또는 embed.py를 찾을 수 없습니다".to_string());
+    }
+    
+    let _running = RunningGuard::try_new(&state.is_running).ok_or("임베딩 작업이 이미 실행 중입니다")?;
+    let mut by_channel: BTreeMap<String, Vec<&ReembeddingCandidate>> = BTreeMap::new();
+    for candidate in candidates.iter().filter(|c| c.video_id.is_some()) {
+        by_channel.entry(candidate.channel.clone()).or_default().push(candidate);
+    }
+    
+    let conn = open_index_db()?;
+    let mut completed = 0u32;
+    for (channel, items) in &by_channel {
+        let mut cmd = Command::new(&venv_python);
+        cmd.arg(&embed_script)
+            .args(["reembed", channel])
+            .args(items.iter().filter_map(|c| c.video_id.as_deref()))
+            .current_dir(&project_root)
+            .env("PYTHONUNBUFFERED", "1")
+            .env("YDH_EMBEDDING_MODELS", embedding_models_env());
+        run_script_with_progress(&window, "embedding-progress", channel, cmd)
+            .map_err(|e| format!("{} 재임베딩 실패: {}", channel, e))?;
+        
+        for item in items {
+            conn.execute("UPDATE videos SET needs_reembedding = 0 WHERE video_path = ?1", [&item.video_path])
+                .map_err(|e| e.to_string())?;
+        }
+        completed += items.len() as u32;
+    }
+    
+    Ok(format!("✅ {}개 영상 재임베딩 완료", completed))
+}
+
+// 채널 업로드 주기 분석 관련 구조체들
+#[derive(Serialize, Deserialize, Clone)]
+struct ChannelCadence {
+    channel: String,
+    total_videos: u32,
+    videos_per_week: f64,           // 최근 12주 평균
+    previous_videos_per_week: f64,  // 그 이전 12주 평균
+    trend: String,                  // "rising" | "steady" | "declining" | "unknown"
+    typical_publish_weekday: Option<String>,
+    typical_publish_hour: Option<u32>, // 로컬 시간 기준 (published_at이 있는 영상만)
+    median_gap_days: Option<f64>,
+    last_upload_date: Option<String>,
+    days_since_last_upload: Option<i64>,
+    is_quiet: bool,                 // 평소 주기보다 업로드가 뜸한 상태
+    suggested_poll_interval_minutes: u32,
+}
+
+const CADENCE_WINDOW_WEEKS: i64 = 12;
+const QUIET_GAP_MULTIPLIER: f64 = 3.0;
+const QUIET_MIN_DAYS: i64 = 14;
+const MIN_POLL_INTERVAL_MINUTES: u32 = 30;
+const MAX_POLL_INTERVAL_MINUTES: u32 = 24 * 60;
+
+// 빈도가 가장 높은 값 (동률이면 작은 값)
+fn most_common<T: Ord + Copy>(values: &[T]) -> Option<T> {
+    let mut counts: BTreeMap<T, usize> = BTreeMap::new();
+    for value in values {
+        *counts.entry(*value).or_insert(0) += 1;
+    }
+    counts.into_iter().max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0))).map(|(value, _)| value)
+}
+
+// 업로드 간격 중앙값 기준 RSS 확인 주기 (평균 간격의 1/8, 30분~24시간)
+fn poll_interval_for_gap(median_gap_days: Option<f64>, is_quiet: bool) -> u32 {
+    match median_gap_days {
+        Some(_) if is_quiet => MAX_POLL_INTERVAL_MINUTES,
+        Some(gap) => ((gap * 24.0 * 60.0 / 8.0) as u32).clamp(MIN_POLL_INTERVAL_MINUTES, MAX_POLL_INTERVAL_MINUTES),
+        None => MAX_POLL_INTERVAL_MINUTES / 4,
+    }
+}
+
+fn compute_channel_cadence(channel: String, mut uploads: Vec<(chrono::NaiveDate, Option<chrono::DateTime<chrono::Local>>)>) -> ChannelCadence {
+    use chrono::Datelike;
+    use chrono::Timelike;
+    
+    uploads.sort_by_key(|(date, _)| *date);
+    let today = chrono::Local::now().date_naive();
+    let window_start = today - chrono::Duration::weeks(CADENCE_WINDOW_WEEKS);
+    let previous_start = window_start - chrono::Duration::weeks(CADENCE_WINDOW_WEEKS);
+    
+    let recent = uploads.iter().filter(|(d, _)| *d > window_start).count() as f64;
+    let previous = uploads.iter().filter(|(d, _)| *d > previous_start && *d <= window_start).count() as f64;
+    let videos_per_week = recent / CADENCE_WINDOW_WEEKS as f64;
+    let previous_videos_per_week = previous / CADENCE_WINDOW_WEEKS as f64;
+    
+    let trend = if recent + previous < 4.0 {
+        "unknown"
+    } else if recent > previous * 1.25 {
+        "rising"
+    } else if recent < previous * 0.75 {
+        "declining"
+    } else {
+        "steady"
+    };
+    
+    let weekdays: Vec<u32> = uploads.iter().map(|(d, _)| d.weekday().num_days_from_monday()).collect();
+    let hours: Vec<u32> = uploads.iter().filter_map(|(_, t)| t.map(|t| t.hour())).collect();
+    
+    let mut gaps: Vec<i64> = uploads.windows(2).map(|w| (w[1].0 - w[0].0).num_days()).collect();
+    gaps.sort_unstable();
+    let median_gap_days = if gaps.is_empty() {
+        None
+    } else if gaps.len() % 2 == 1 {
+        Some(gaps[gaps.len() / 2] as f64)
+    } else {
+        Some((gaps[gaps.len() / 2 - 1] + gaps[gaps.len() / 2]) as f64 / 2.0)
+    };
+    
+    let last_upload = uploads.last().map(|(d, _)| *d);
+    let days_since_last_upload = last_upload.map(|d| (today - d).num_days());
+    let is_quiet = match (median_gap_days, days_since_last_upload) {
+        (Some(gap), Some(days)) => days >= QUIET_MIN_DAYS && days as f64 > gap.max(1.0) * QUIET_GAP_MULTIPLIER,
+        _ => false,
+    };
+    
+    ChannelCadence {
+        channel,
+        total_videos: uploads.len() as u32,
+        videos_per_week: (videos_per_week * 100.0).round() / 100.0,
+        previous_videos_per_week: (previous_videos_per_week * 100.0).round() / 100.0,
+        trend: trend.to_string(),
+        typical_publish_weekday: most_common(&weekdays)
+            .map(|d| ["월", "화", "수", "목", "금", "토", "일"][d as usize].to_string()),
+        typical_publish_hour: most_common(&hours),
+        median_gap_days,
+        last_upload_date: last_upload.map(|d| d.format("%Y-%m-%d").to_string()),
+        days_since_last_upload,
+        is_quiet,
+        suggested_poll_interval_minutes: poll_interval_for_gap(median_gap_days, is_quiet),
+    }
+}
+
+// 인덱스의 업로드 날짜로 채널별 업로드 주기 계산
+fn compute_channel_cadences() -> Result<Vec<ChannelCadence>, String> {
+    let conn = open_index_db()?;
+    let mut stmt = conn
+        .prepare("SELECT channel, upload_date, published_at FROM videos WHERE upload_date IS NOT NULL AND upload_date != ''")
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, Option<String>>(2)?)))
+        .map_err(|e| e.to_string())?;
+    
+    let mut uploads_by_channel: BTreeMap<String, Vec<_>> = BTreeMap::new();
+    for (channel, upload_date, published_at) in rows.flatten() {
+        let Ok(date) = chrono::NaiveDate::parse_from_str(&upload_date, "%Y-%m-%d") else {
+            continue;
+        };
+        let published = published_at
+            .and_then(|p| chrono::DateTime::parse_from_rfc3339(&p).ok())
+            .map(|p| p.with_timezone(&chrono::Local));
+        uploads_by_channel.entry(channel).or_default().push((date, published));
+    }
+    
+    Ok(uploads_by_channel
+        .into_iter()
+        .map(|(channel, uploads)| compute_channel_cadence(channel, uploads))
+        .collect())
+}
+
+// 채널별 업로드 주기 조회 (reconcile_vault_index로 인덱스가 채워져 있어야 함)
+#[command]
+fn get_upload_cadence(channel: Option<String>) -> Result<Vec<ChannelCadence>, String> {
+    let cadences = compute_channel_cadences()?;
+    Ok(match channel {
+        Some(name) => cadences.into_iter().filter(|c| c.channel == name).collect(),
+        None => cadences,
+    })
+}
+
+// 평소보다 업로드가 뜸한 채널 목록
+#[command]
+fn get_quiet_channels() -> Result<Vec<ChannelCadence>, String> {
+    Ok(compute_channel_cadences()?.into_iter().filter(|c| c.is_quiet).collect())
+}
+
+// 채널 타임라인 시각화용 월별 집계
+#[derive(Serialize, Deserialize)]
+struct TopicCount {
+    topic: String,
+    count: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TimelineMonth {
+    month: String, // YYYY-MM
+    videos: u32,
+    total_minutes: f64,
+    dominant_topics: Vec<TopicCount>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ChannelTimeline {
+    channel: String,
+    total_videos: u32,
+    total_minutes: f64,
+    // 첫 업로드 월부터 마지막 업로드 월까지 빈 달 포함
+    months: Vec<TimelineMonth>,
+}
+
+const TIMELINE_TOP_TOPICS: usize = 3;
+
+#[command]
+fn get_channel_timeline(channel: String) -> Result<ChannelTimeline, String> {
+    use chrono::Datelike;
+    
+    let conn = open_index_db()?;
+    let folder_pattern = format!("vault/10_videos/{}/%", sanitize_vault_name(&channel));
+    let mut stmt = conn
+        .prepare(
+            "SELECT upload_date, duration_seconds, topic FROM videos
+             WHERE (channel = ?1 OR video_path LIKE ?2) AND upload_date IS NOT NULL AND upload_date != ''",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(rusqlite::params![channel, folder_pattern], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, Option<i64>>(1)?, row.get::<_, Option<String>>(2)?))
+        })
+        .map_err(|e| e.to_string())?;
+    
+    // 월 → (영상 수, 총 초, 태그별 횟수)
+    type MonthBucket = (u32, i64, HashMap<String, u32>);
+    let mut buckets: BTreeMap<(i32, u32), MonthBucket> = BTreeMap::new();
+    for (upload_date, duration_seconds, topic) in rows.flatten() {
+        let Ok(date) = chrono::NaiveDate::parse_from_str(&upload_date, "%Y-%m-%d") else {
+            continue;
+        };
+        let bucket = buckets.entry((date.year(), date.month())).or_default();
+        bucket.0 += 1;
+        bucket.1 += duration_seconds.unwrap_or(0);
+        let topics: Vec<String> = topic.and_then(|t| serde_json::from_str(&t).ok()).unwrap_or_default();
+        for topic in topics {
+            *bucket.2.entry(topic).or_insert(0) += 1;
+        }
+    }
+    
+    let mut months = Vec::new();
+    if let (Some(first), Some(last)) = (buckets.keys().next().copied(), buckets.keys().next_back().copied()) {
+        let (mut year, mut month) = first;
+        while (year, month) <= last {
+            let (videos, seconds, topic_counts) = buckets.remove(&(year, month)).unwrap_or_default();
+            let mut dominant_topics: Vec<TopicCount> = topic_counts
+                .into_iter()
+                .map(|(topic, count)| TopicCount { topic, count })
+                .collect();
+            dominant_topics.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.topic.cmp(&b.topic)));
+            dominant_topics.truncate(TIMELINE_TOP_TOPICS);
+            
+            months.push(TimelineMonth {
+                month: format!("{:04}-{:02}", year, month),
+                videos,
+                total_minutes: (seconds as f64 / 6.0).round() / 10.0,
+                dominant_topics,
+            });
+            (year, month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+        }
+    }
+    
+    Ok(ChannelTimeline {
+        channel,
+        total_videos: months.iter().map(|m| m.videos).sum(),
+        total_minutes: (months.iter().map(|m| m.total_minutes).sum::<f64>() * 10.0).round() / 10.0,
+        months,
+    })
+}
+
+// 시청 시간 리포트 관련 구조체들
+#[derive(Serialize, Deserialize)]
+struct UsageBucket {
+    name: String,
+    minutes: f64,
+    share: f64, // 기간 전체 대비 비율 (0~1)
+}
+
+#[derive(Serialize, Deserialize)]
+struct UsageWeek {
+    week: String, // ISO 주 (YYYY-Www)
+    total_minutes: f64,
+    by_channel: Vec<UsageBucket>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct UsageReport {
+    period: String,
+    since: String,
+    total_minutes: f64,
+    by_channel: Vec<UsageBucket>,
+    by_topic: Vec<UsageBucket>,
+    weeks: Vec<UsageWeek>,
+}
+
+const MAX_PLAYBACK_RECORD_SECONDS: f64 = 6.0 * 60.0 * 60.0;
+
+fn usage_buckets(seconds_by_name: HashMap<String, f64>, total_seconds: f64) -> Vec<UsageBucket> {
+    let mut buckets: Vec<UsageBucket> = seconds_by_name
+        .into_iter()
+        .map(|(name, seconds)| UsageBucket {
+            name,
+            minutes: (seconds / 6.0).round() / 10.0,
+            share: if total_seconds > 0.0 { (seconds / total_seconds * 1000.0).round() / 1000.0 } else { 0.0 },
+        })
+        .collect();
+    buckets.sort_by(|a, b| b.minutes.partial_cmp(&a.minutes).unwrap_or(std::cmp::Ordering::Equal));
+    buckets
+}
+
+// 재생한 시간 기록 (프론트엔드가 일시정지/종료 시 재생 구간 증가분 전달)
+#[command]
+fn record_playback(vault_lock: State<'_, VaultLockState>, video_path: String, seconds: f64) -> Result<(), String> {
+    ensure_vault_writable(&vault_lock)?;
+    insert_playback_log(&video_path, seconds)
+}
+
+fn insert_playback_log(video_path: &str, seconds: f64) -> Result<(), String> {
+    if !(seconds > 0.0 && seconds <= MAX_PLAYBACK_RECORD_SECONDS) {
+        return Err(format!("잘못된 재생 시간입니다: {}", seconds));
+    }
+    let conn = open_index_db()?;
+    conn.execute(
+        "INSERT INTO playback_log (video_path, watched_at, seconds) VALUES (?1, ?2, ?3)",
+        rusqlite::params![video_path, chrono::Local::now().to_rfc3339(), seconds],
+    )
+    .map_err(|e| format!("시청 기록 저장 실패: {}", e))?;
+    Ok(())
+}
+
+// 이어보기 (영상별 마지막 재생 위치, 인덱스 DB의 watch_positions)
+// 이보다 앞이면 처음부터 보는 것과 같으므로 저장하지 않음
+const WATCH_POSITION_MIN_SECONDS: f64 = 5.0;
+// 남은 시간이 이보다 짧으면 끝까지 본 것으로 보고 위치 삭제
+const WATCH_POSITION_FINISHED_REMAINING_SECONDS: f64 = 15.0;
+const DEFAULT_CONTINUE_WATCHING_LIMIT: u32 = 20;
+
+#[derive(Serialize)]
+struct ContinueWatchingItem {
+    video: VideoInfo,
+    position_seconds: f64,
+    duration_seconds: Option<f64>,
+    updated_at: String,
+}
+
+// 재생 위치 저장 (플레이어가 주기적으로/일시정지 시 호출, 끝부분이면 기록 삭제)
+#[command]
+fn set_watch_position(vault_lock: State<'_, VaultLockState>, video_id: String, seconds: f64, duration: Option<f64>) -> Result<(), String> {
+    ensure_vault_writable(&vault_lock)?;
+    if !seconds.is_finite() || seconds < 0.0 {
+        return Err(format!("잘못된 재생 위치입니다: {}", seconds));
+    }
+    let duration = duration.filter(|d| d.is_finite() && *d > 0.0);
+    let conn = open_index_db()?;
+    let finished = duration.map(|d| d - seconds <= WATCH_POSITION_FINISHED_REMAINING_SECONDS).unwrap_or(false);
+    if finished || seconds < WATCH_POSITION_MIN_SECONDS {
+        conn.execute("DELETE FROM watch_positions WHERE video_id = ?1", [&video_id])
+            .map_err(|e| format!("재생 위치 삭제 실패: {}", e))?;
+        return Ok(());
+    }
+    conn.execute(
+        "INSERT INTO watch_positions (video_id, position_seconds, duration_seconds, updated_at) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(video_id) DO UPDATE SET
+             position_seconds = excluded.position_seconds,
+             duration_seconds = IFNULL(excluded.duration_seconds, watch_positions.duration_seconds),
+             updated_at = excluded.updated_at",
+        rusqlite::params![video_id, seconds, duration, chrono::Local::now().to_rfc3339()],
+    )
+    .map_err(|e| format!("재생 위치 저장 실패: {}", e))?;
+    Ok(())
+}
+
+// 저장된 재생 위치 (없으면 None → 처음부터)
+#[command]
+fn get_watch_position(video_id: String) -> Result<Option<f64>, String> {
+    let conn = open_index_db()?;
+    Ok(conn
+        .query_row("SELECT position_seconds FROM watch_positions WHERE video_id = ?1", [&video_id], |row| row.get(0))
+        .ok())
+}
+
+// 보다 만 영상 목록 (최근 재생 순, vault에서 사라진 영상 제외)
+#[command]
+fn continue_watching(limit: Option<u32>) -> Result<Vec<ContinueWatchingItem>, String> {
+    let limit = limit.unwrap_or(DEFAULT_CONTINUE_WATCHING_LIMIT).clamp(1, 200);
+    let conn = open_index_db()?;
+    let positions: Vec<(String, f64, Option<f64>, String)> = {
+        let mut stmt = conn
+            .prepare(
+                "SELECT w.video_id, w.position_seconds, w.duration_seconds, w.updated_at FROM watch_positions w
+                 WHERE EXISTS (SELECT 1 FROM videos v WHERE v.video_id = w.video_id)
+                 ORDER BY w.updated_at DESC LIMIT ?1",
+            )
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map([limit], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)))
+            .map_err(|e| format!("이어보기 목록 조회 실패: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("이어보기 목록 조회 실패: {}", e))?;
+        rows
+    };
+    
+    let mut stmt = conn
+        .prepare(&format!("{} WHERE v.video_id = ?1 LIMIT 1", INDEXED_VIDEO_SELECT))
+        .map_err(|e| e.to_string())?;
+    let mut videos = Vec::new();
+    let mut found = Vec::new();
+    for (video_id, position_seconds, duration_seconds, updated_at) in positions {
+        if let Ok(video) = stmt.query_row([&video_id], indexed_video_from_row) {
+            videos.push(video);
+            found.push((position_seconds, duration_seconds, updated_at));
+        }
+    }
+    assign_video_playlists(&mut videos);
+    Ok(videos
+        .into_iter()
+        .zip(found)
+        .map(|(video, (position_seconds, duration_seconds, updated_at))| ContinueWatchingItem {
+            video,
+            position_seconds,
+            duration_seconds,
+            updated_at,
+        })
+        .collect())
+}
+
+// 즐겨찾기 전환 (변경 후 상태 반환)
+#[command]
+fn toggle_favorite(vault_lock: State<'_, VaultLockState>, video_id: String) -> Result<bool, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let conn = open_index_db()?;
+    let removed = conn
+        .execute("DELETE FROM favorites WHERE video_id = ?1", [&video_id])
+        .map_err(|e| format!("즐겨찾기 변경 실패: {}", e))?;
+    if removed > 0 {
+        return Ok(false);
+    }
+    let exists: bool = conn
+        .query_row("SELECT EXISTS (SELECT 1 FROM videos WHERE video_id = ?1)", [&video_id], |row| row.get(0))
+        .map_err(|e| e.to_string())?;
+    if !exists {
+        return Err(format!("영상을 찾을 수 없습니다: {}", video_id));
+    }
+    conn.execute(
+        "INSERT INTO favorites (video_id, favorited_at) VALUES (?1, ?2)",
+        rusqlite::params![video_id, chrono::Local::now().to_rfc3339()],
+    )
+    .map_err(|e| format!("즐겨찾기 변경 실패: {}", e))?;
+    Ok(true)
+}
+
+// 즐겨찾기 영상 목록 (최근에 추가한 순, vault에서 사라진 영상 제외)
+#[command]
+fn list_favorites() -> Result<Vec<VideoInfo>, String> {
+    let conn = open_index_db()?;
+    let mut stmt = conn
+        .prepare(&format!(
+            "{} JOIN favorites fav ON fav.video_id = v.video_id ORDER BY fav.favorited_at DESC",
+            INDEXED_VIDEO_SELECT
+        ))
+        .map_err(|e| format!("즐겨찾기 조회 실패: {}", e))?;
+    let mut videos = stmt
+        .query_map([], indexed_video_from_row)
+        .map_err(|e| format!("즐겨찾기 조회 실패: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("즐겨찾기 조회 실패: {}", e))?;
+    assign_video_playlists(&mut videos);
+    Ok(videos)
+}
+
+// 기간별(week/month/quarter/year) 채널·주제별 시청 시간
+#[command]
+fn get_usage_report(period: Option<String>) -> Result<UsageReport, String> {
+    use chrono::Datelike;
+    
+    let period = period.unwrap_or_else(|| "month".to_string());
+    let days = match period.as_str() {
+        "week" => 7,
+        "month" => 30,
+        "quarter" => 91,
+        "year" => 365,
+        other => return Err(format!("지원하지 않는 기간입니다: {}", other)),
+    };
+    let since = chrono::Local::now() - chrono::Duration::days(days);
+    
+    let conn = open_index_db()?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT p.video_path, p.watched_at, p.seconds, v.channel, v.topic
+             FROM playback_log p LEFT JOIN videos v ON v.video_path = p.video_path
+             WHERE p.watched_at >= ?1",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([since.to_rfc3339()], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, f64>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, Option<String>>(4)?,
+            ))
+        })
+        .map_err(|e| e.to_string())?;
+    
+    let mut total_seconds = 0.0;
+    let mut by_channel: HashMap<String, f64> = HashMap::new();
+    let mut by_topic: HashMap<String, f64> = HashMap::new();
+    let mut by_week: BTreeMap<String, HashMap<String, f64>> = BTreeMap::new();
+    
+    for (video_path, watched_at, seconds, channel, topic) in rows.flatten() {
+        let Ok(watched_at) = chrono::DateTime::parse_from_rfc3339(&watched_at) else {
+            continue;
+        };
+        // 인덱스에 없는 영상은 경로의 채널 폴더명 사용
+        let channel = channel
+            .or_else(|| Path::new(&video_path).components().nth(2).map(|c| c.as_os_str().to_string_lossy().to_string()))
+            .unwrap_or_else(|| "Unknown".to_string());
+        let topics: Vec<String> = topic.and_then(|t| serde_json::from_str(&t).ok()).unwrap_or_default();
+        
+        total_seconds += seconds;
+        *by_channel.entry(channel.clone()).or_insert(0.0) += seconds;
+        for topic in topics {
+            *by_topic.entry(topic).or_insert(0.0) += seconds;
+        }
+        let week = watched_at.with_timezone(&chrono::Local).iso_week();
+        *by_week
+            .entry(format!("{}-W{:02}", week.year(), week.week()))
+            .or_default()
+            .entry(channel)
+            .or_insert(0.0) += seconds;
+    }
+    
+    let weeks = by_week
+        .into_iter()
+        .map(|(week, channels)| {
+            let week_seconds: f64 = channels.values().sum();
+            UsageWeek {
+                week,
+                total_minutes: (week_seconds / 6.0).round() / 10.0,
+                by_channel: usage_buckets(channels, week_seconds),
+            }
+        })
+        .collect();
+    
+    Ok(UsageReport {
+        period,
+        since: since.to_rfc3339(),
+        total_minutes: (total_seconds / 6.0).round() / 10.0,
+        by_channel: usage_buckets(by_channel, total_seconds),
+        by_topic: usage_buckets(by_topic, total_seconds),
+        weeks,
+    })
+}
+
+// 조회수 추세 관련 구조체들
+#[derive(Serialize, Deserialize)]
+struct VideoTrend {
+    video_id: String,
+    title: String,
+    video_path: String,
+    upload_date: Option<String>,
+    view_count: i64,
+    previous_view_count: i64,
+    views_gained: i64,
+    views_per_day: f64,
+    growth_percent: Option<f64>,
+    since: String,
+    captured_at: String,
+}
+
+// 채널 URL의 동영상 탭 (flat-playlist로 영상별 조회수를 한 번에 조회)
+fn channel_videos_tab_url(url: &str) -> String {
+    let trimmed = url.trim_end_matches('/');
+    if trimmed.ends_with("/videos") {
+        trimmed.to_string()
+    } else {
+        format!("{}/videos", trimmed)
+    }
+}
+
+// 보관된 영상의 현재 조회수를 스냅샷으로 기록하고 인덱스 조회수 갱신
+// (captions.md는 수정하지 않음: 재임베딩 대상 표시 방지)
+fn refresh_channel_view_counts(channel: &ChannelInfo) -> Result<usize, String> {
+    let project_root = get_project_root();
+    let venv_python = project_root.join("venv").join("bin").join("python3");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    
+    let output = Command::new(&venv_python)
+        .args(["-m", "yt_dlp", "-J", "--flat-playlist", "--no-warnings"])
+        .args(ytdlp_auth_args(&load_auth_settings()))
+        .args(ytdlp_proxy_args())
+        .arg(channel_videos_tab_url(&channel.url))
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| format!("yt-dlp 실행 실패: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("영상 목록 조회 실패: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    let info: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("yt-dlp 출력 파싱 실패: {}", e))?;
+    
+    let counts: HashMap<String, i64> = info
+        .get("entries")
+        .and_then(|e| e.as_array())
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|entry| {
+                    let id = entry.get("id")?.as_str()?.to_string();
+                    let views = entry.get("view_count")?.as_i64()?;
+                    Some((id, views))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    
+    let mut conn = open_index_db()?;
+    let archived: Vec<String> = {
+        let mut stmt = conn
+            .prepare("SELECT DISTINCT video_id FROM videos WHERE video_id IS NOT NULL")
+            .map_err(|e| e.to_string())?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0)).map_err(|e| e.to_string())?;
+        rows.flatten().filter(|id| counts.contains_key(id)).collect()
+    };
+    
+    let now = chrono::Local::now().to_rfc3339();
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+    for video_id in &archived {
+        let views = counts[video_id];
+        tx.execute(
+            "INSERT OR REPLACE INTO view_count_snapshots (video_id, captured_at, view_count) VALUES (?1, ?2, ?3)",
+            rusqlite::params![video_id, now, views],
+        )
+        .map_err(|e| e.to_string())?;
+        tx.execute("UPDATE videos SET view_count = ?1 WHERE video_id = ?2", rusqlite::params![views, video_id])
+            .map_err(|e| e.to_string())?;
+    }
+    tx.commit().map_err(|e| format!("조회수 스냅샷 저장 실패: {}", e))?;
+    
+    println!("📈 조회수 갱신: {} ({}개 영상)", channel.name, archived.len());
+    Ok(archived.len())
+}
+
+// 조회수 메타데이터 즉시 갱신 (채널 미지정 시 전체, 추모 채널 제외)
+#[command]
+async fn refresh_view_counts(vault_lock: State<'_, VaultLockState>, channel: Option<String>) -> Result<u32, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let mut total = 0;
+    for info in read_channels()? {
+        if info.memorialized || info.state == ChannelState::Archived || channel.as_ref().map(|c| c != &info.name).unwrap_or(false) {
+            continue;
+        }
+        match refresh_channel_view_counts(&info) {
+            Ok(count) => total += count as u32,
+            Err(e) => eprintln!("⚠️ 조회수 갱신 실패 {}: {}", info.name, e),
+        }
+    }
+    Ok(total)
+}
+
+// 최근 days일(기본 7일) 동안 조회수가 빠르게 늘고 있는 보관 영상
+#[command]
+fn get_video_trends(channel: String, days: Option<u32>) -> Result<Vec<VideoTrend>, String> {
+    let days = days.unwrap_or(7).max(1) as i64;
+    let conn = open_index_db()?;
+    let folder_pattern = format!("vault/10_videos/{}/%", sanitize_vault_name(&channel));
+    
+    let mut stmt = conn
+        .prepare(
+            "SELECT s.video_id, s.captured_at, s.view_count, v.title, v.video_path, v.upload_date
+             FROM view_count_snapshots s JOIN videos v ON v.video_id = s.video_id
+             WHERE v.channel = ?1 OR v.video_path LIKE ?2
+             ORDER BY s.video_id, s.captured_at",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(rusqlite::params![channel, folder_pattern], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, i64>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
+            ))
+        })
+        .map_err(|e| e.to_string())?;
+    
+    // 영상별 (시각, 조회수) 시계열
+    type ViewSeries = (String, String, Option<String>, Vec<(chrono::DateTime<chrono::FixedOffset>, i64)>);
+    let mut series: BTreeMap<String, ViewSeries> = BTreeMap::new();
+    for (video_id, captured_at, views, title, video_path, upload_date) in rows.flatten() {
+        let Ok(at) = chrono::DateTime::parse_from_rfc3339(&captured_at) else {
+            continue;
+        };
+        series
+            .entry(video_id)
+            .or_insert_with(|| (title, video_path, upload_date, Vec::new()))
+            .3
+            .push((at, views));
+    }
+    
+    let mut trends: Vec<VideoTrend> = series
+        .into_iter()
+        .filter_map(|(video_id, (title, video_path, upload_date, points))| {
+            let (latest_at, latest_views) = *points.last()?;
+            // 기준점: days일 이전의 마지막 스냅샷 (없으면 가장 오래된 스냅샷)
+            let cutoff = latest_at - chrono::Duration::days(days);
+            let (base_at, base_views) = points.iter().rev().find(|(at, _)| *at <= cutoff).or(points.first()).copied()?;
+            let elapsed_days = (latest_at - base_at).num_minutes() as f64 / (24.0 * 60.0);
+            if elapsed_days <= 0.0 {
+                return None;
+            }
+            let gained = latest_views - base_views;
+            Some(VideoTrend {
+                video_id,
+                title,
+                video_path,
+                upload_date,
+                view_count: latest_views,
+                previous_view_count: base_views,
+                views_gained: gained,
+                views_per_day: (gained as f64 / elapsed_days * 10.0).round() / 10.0,
+                growth_percent: (base_views > 0).then(|| (gained as f64 / base_views as f64 * 1000.0).round() / 10.0),
+                since: base_at.to_rfc3339(),
+                captured_at: latest_at.to_rfc3339(),
+            })
+        })
+        .collect();
+    
+    trends.sort_by(|a, b| b.views_per_day.partial_cmp(&a.views_per_day).unwrap_or(std::cmp::Ordering::Equal));
+    Ok(trends)
+}
+
+// 채널 소개(About) 페이지 보관 관련 구조체들
+#[derive(Serialize, Deserialize, Clone)]
+struct ChannelProfile {
+    channel: String,
+    channel_id: Option<String>,
+    url: String,
+    title: Option<String>,
+    description: String,
+    links: Vec<String>,
+    subscriber_count: Option<u64>,
+    tags: Vec<String>,
+    avatar_url: Option<String>,
+    archived_at: String,
+}
+
+const CHANNEL_PROFILE_FILE: &str = "channel_profile.json";
+const CHANNEL_PROFILE_HISTORY_DIR: &str = "profile_history";
+const CHANNEL_PROFILE_REFRESH_DAYS: i64 = 30;
+const CHANNEL_LIVENESS_CHECK_DAYS: i64 = 7;
+const CHANNEL_PROFILE_CHECK_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+// Python VaultWriter.sanitize_filename과 동일한 규칙 (채널 폴더명)
+fn sanitize_vault_name(name: &str) -> String {
+    let cleaned = Regex::new(r#"[\\/*?:"<>|]"#).unwrap().replace_all(name, "_");
+    let cleaned = Regex::new(r"\s+").unwrap().replace_all(&cleaned, "-");
+    let cleaned = Regex::new(r"[-_]{2,}").unwrap().replace_all(&cleaned, "-");
+    cleaned.trim_matches(|c| c == '-' || c == '_').to_string()
+}
+
+fn channel_vault_dir(channel_name: &str) -> PathBuf {
+    get_project_root().join("vault").join("10_videos").join(sanitize_vault_name(channel_name))
+}
+
+// 채널 백필 예상치 (다운로드 전 영상 수/길이/용량/시간 확인용)
+#[derive(Serialize)]
+struct ChannelBackfillEstimate {
+    url: String,
+    since: Option<String>,
+    video_count: u32,             // 새로 받을 영상 수 (이미 보관된 영상 제외)
+    already_archived: u32,
+    undated_videos: u32,          // 목록에 날짜가 없어 since 필터를 적용하지 못한 영상
+    total_duration_seconds: u64,
+    sampled_videos: u32,
+    bytes_per_second: f64,        // 표본 영상의 실제 포맷 크기 기준
+    estimated_bytes: u64,
+    estimated_seconds: u64,
+    assumed_bandwidth_mbps: f64,
+    #[serde(skip)]
+    channel_name: Option<String>,      // 배치 예상치에서만 채워짐
+    #[serde(skip)]
+    candidates: Vec<BackfillCandidate>, // 다운로드 계획용 대상 영상 목록
+}
+
+#[derive(Clone)]
+struct BackfillCandidate {
+    video_id: String,
+    title: String,
+    upload_date: Option<String>,
+    duration_seconds: u64,
+}
+
+// 표본으로 포맷 크기를 조회할 영상 수
+const BACKFILL_SAMPLE_SIZE: usize = 5;
+// 다운로더 기본 포맷 (downloader.py와 동일)
+const DEFAULT_FORMAT_SELECTOR: &str = "bestvideo[ext=mp4][height<=1080]+bestaudio[ext=m4a]/best[height<=1080]/best";
+// 예상 시간 계산용 회선 속도와 영상당 고정 지연 (상세 정보 조회 + 요청 간 대기)
+const ASSUMED_BANDWIDTH_MBPS: f64 = 50.0;
+const PER_VIDEO_OVERHEAD_SECS: f64 = 6.0;
+// 표본을 얻지 못했을 때 사용하는 1080p 평균 비트레이트 (약 2.5 Mbps)
+const FALLBACK_BYTES_PER_SECOND: f64 = 2.5 * 1_000_000.0 / 8.0;
+
+// 영상 목록 항목의 업로드 날짜 (YYYYMMDD, flat-playlist에는 없을 수 있음)
+fn flat_entry_upload_date(entry: &serde_json::Value) -> Option<String> {
+    entry
+        .get("upload_date")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .or_else(|| {
+            let timestamp = entry.get("timestamp").and_then(|v| v.as_i64())?;
+            chrono::DateTime::from_timestamp(timestamp, 0).map(|t| t.format("%Y%m%d").to_string())
+        })
+}
+
+// 선택된 포맷의 예상 파일 크기 (분리 스트림이면 합산)
+fn selected_format_bytes(info: &serde_json::Value) -> Option<u64> {
+    let size = |f: &serde_json::Value| {
+        f.get("filesize").and_then(|v| v.as_u64()).or_else(|| f.get("filesize_approx").and_then(|v| v.as_u64()))
+    };
+    match info.get("requested_formats").and_then(|f| f.as_array()) {
+        Some(formats) => formats.iter().map(size).sum(),
+        None => size(info),
+    }
+}
+
+// 예상 시간 계산용 회선 속도 (Mbps): 다운로드 속도 제한이 설정돼 있으면 그 값
+fn estimate_bandwidth_mbps() -> f64 {
+    let limits: DownloadLimitSettings = load_json_config(DOWNLOAD_LIMITS_FILE).unwrap_or_default();
+    match limits.rate_limit_mbps {
+        Some(rate) => (rate * 1024.0 * 1024.0 * 8.0 / 1_000_000.0).min(ASSUMED_BANDWIDTH_MBPS),
+        None => ASSUMED_BANDWIDTH_MBPS,
+    }
+}
+
+// 이미 보관된 영상 ID (예상치 계산에서 제외)
+fn archived_video_ids() -> std::collections::HashSet<String> {
+    open_index_db()
+        .and_then(|conn| {
+            let mut stmt = conn
+                .prepare("SELECT DISTINCT video_id FROM videos WHERE video_id IS NOT NULL")
+                .map_err(|e| e.to_string())?;
+            let rows = stmt.query_map([], |row| row.get::<_, String>(0)).map_err(|e| e.to_string())?;
+            Ok(rows.flatten().collect())
+        })
+        .unwrap_or_default()
+}
+
+// 채널 전체(또는 since 이후) 백필 예상치 계산 (다운로드는 시작하지 않음)
+#[command]
+async fn estimate_channel_backfill(url: String, since: Option<String>) -> Result<ChannelBackfillEstimate, String> {
+    let since = since.filter(|d| !d.trim().is_empty()).map(|d| normalize_upload_date(&d)).transpose()?;
+    estimate_backfill(url, since, &archived_video_ids(), estimate_bandwidth_mbps())
+}
+
+fn estimate_backfill(
+    url: String,
+    since: Option<String>,
+    archived: &std::collections::HashSet<String>,
+    bandwidth_mbps: f64,
+) -> Result<ChannelBackfillEstimate, String> {
+    let project_root = get_project_root();
+    let venv_python = project_root.join("venv").join("bin").join("python3");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    
+    let output = Command::new(&venv_python)
+        .args(["-m", "yt_dlp", "-J", "--flat-playlist", "--no-warnings"])
+        .args(ytdlp_auth_args(&load_auth_settings()))
+        .args(ytdlp_proxy_args())
+        .arg(channel_videos_tab_url(&url))
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| format!("yt-dlp 실행 실패: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("영상 목록 조회 실패: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    let info: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("yt-dlp 출력 파싱 실패: {}", e))?;
+    let entries = info.get("entries").and_then(|e| e.as_array()).cloned().unwrap_or_default();
+    
+    let mut already_archived = 0;
+    let mut undated_videos = 0;
+    let mut candidates: Vec<BackfillCandidate> = Vec::new();
+    for entry in &entries {
+        let Some(id) = entry.get("id").and_then(|v| v.as_str()) else { continue };
+        let upload_date = flat_entry_upload_date(entry);
+        match (&since, &upload_date) {
+            (Some(since), Some(date)) if date < since => continue,
+            (Some(_), None) => undated_videos += 1,
+            _ => {}
+        }
+        if archived.contains(id) {
+            already_archived += 1;
+            continue;
+        }
+        candidates.push(BackfillCandidate {
+            video_id: id.to_string(),
+            title: entry.get("title").and_then(|v| v.as_str()).unwrap_or(id).to_string(),
+            upload_date,
+            duration_seconds: entry.get("duration").and_then(|v| v.as_f64()).unwrap_or(0.0) as u64,
+        });
+    }
+    let total_duration_seconds: u64 = candidates.iter().map(|c| c.duration_seconds).sum();
+    
+    // 목록 전체에 고르게 분포한 표본 영상의 실제 포맷 크기로 초당 용량 추정
+    let step = (candidates.len() / BACKFILL_SAMPLE_SIZE).max(1);
+    let sample_urls: Vec<String> = candidates
+        .iter()
+        .step_by(step)
+        .filter(|c| c.duration_seconds > 0)
+        .take(BACKFILL_SAMPLE_SIZE)
+        .map(|c| format!("https://www.youtube.com/watch?v={}", c.video_id))
+        .collect();
+    let (mut sampled_bytes, mut sampled_seconds, mut sampled_videos) = (0u64, 0f64, 0u32);
+    if !sample_urls.is_empty() {
+        let output = Command::new(&venv_python)
+            .args(["-m", "yt_dlp", "-j", "--no-warnings", "--skip-download", "--ignore-errors", "-f", DEFAULT_FORMAT_SELECTOR])
+            .args(ytdlp_auth_args(&load_auth_settings()))
+            .args(ytdlp_proxy_args())
+            .args(&sample_urls)
+            .current_dir(&project_root)
+            .output()
+            .map_err(|e| format!("yt-dlp 실행 실패: {}", e))?;
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let Ok(info) = serde_json::from_str::<serde_json::Value>(line) else { continue };
+            let duration = info.get("duration").and_then(|v| v.as_f64()).unwrap_or(0.0);
+            if let (Some(bytes), true) = (selected_format_bytes(&info), duration > 0.0) {
+                sampled_bytes += bytes;
+                sampled_seconds += duration;
+                sampled_videos += 1;
+            }
+        }
+    }
+    
+    let bytes_per_second = if sampled_seconds > 0.0 { sampled_bytes as f64 / sampled_seconds } else { FALLBACK_BYTES_PER_SECOND };
+    let estimated_bytes = (bytes_per_second * total_duration_seconds as f64) as u64;
+    let transfer_seconds = estimated_bytes as f64 * 8.0 / (bandwidth_mbps * 1_000_000.0);
+    let estimated_seconds = (transfer_seconds + PER_VIDEO_OVERHEAD_SECS * candidates.len() as f64) as u64;
+    
+    Ok(ChannelBackfillEstimate {
+        url,
+        since,
+        video_count: candidates.len() as u32,
+        already_archived,
+        undated_videos,
+        total_duration_seconds,
+        sampled_videos,
+        bytes_per_second,
+        estimated_bytes,
+        estimated_seconds,
+        assumed_bandwidth_mbps: bandwidth_mbps,
+        channel_name: None,
+        candidates,
+    })
+}
+
+#[derive(Serialize, Clone)]
+struct BatchEstimateFailure {
+    channel: String,
+    error: String,
+}
+
+#[derive(Serialize)]
+struct BatchDownloadEstimate {
+    since: Option<String>,
+    channels: Vec<ChannelBackfillEstimate>,
+    failed: Vec<BatchEstimateFailure>, // 목록을 조회하지 못한 채널 (합계에서 빠짐)
+    video_count: u32,
+    estimated_bytes: u64,
+    estimated_seconds: u64,
+    bandwidth_mbps: f64,
+    rate_limited: bool,               // 다운로드 속도 제한 기준으로 계산했는지
+    parallel_channels: u32,
+    warning: Option<String>,          // 대용량 다운로드 경고
+}
+
+// 이보다 크면 배치 시작 전에 경고
+const LARGE_BATCH_WARNING_BYTES: u64 = 50 * 1024 * 1024 * 1024;
+
+// 배치 다운로드 전체 예상 용량/시간 (다운로드 대상 채널 전체, 이미 보관된 영상 제외)
+#[command]
+async fn estimate_batch_download(since: Option<String>) -> Result<BatchDownloadEstimate, String> {
+    let since = since.filter(|d| !d.trim().is_empty()).map(|d| normalize_upload_date(&d)).transpose()?;
+    tokio::task::spawn_blocking(move || build_batch_estimate(since))
+        .await
+        .map_err(|e| format!("예상치 계산 작업 실패: {}", e))?
+}
+
+// 배치 예상치 계산 (다운로드 계획 생성에도 사용, 채널별 대상 영상 목록 포함)
+fn build_batch_estimate(since: Option<String>) -> Result<BatchDownloadEstimate, String> {
+    let channels: Vec<ChannelInfo> = read_channels()?.into_iter().filter(|c| c.downloadable()).collect();
     if channels.is_empty() {
-        return Err("선택된 채널이 없습니다.".to_string());
+        return Err("활성화된 채널이 없습니다".to_string());
+    }
+    let archived = archived_video_ids();
+    let bandwidth_mbps = estimate_bandwidth_mbps();
+    let parallel = load_json_config::<DownloadConcurrencySettings>(DOWNLOAD_CONCURRENCY_FILE)
+        .unwrap_or_default()
+        .parallel_channels
+        .clamp(1, MAX_PARALLEL_CHANNELS);
+    
+    let mut estimates = Vec::new();
+    let mut failed = Vec::new();
+    for channel in channels {
+        // 채널별 날짜 범위가 있으면 그 시작일과 배치 since 중 늦은 날짜 기준
+        let channel_since = load_channel_settings().get(&channel.name).and_then(|s| s.download_since.clone());
+        let effective_since = match (since.clone(), channel_since) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+        match estimate_backfill(channel.url.clone(), effective_since, &archived, bandwidth_mbps) {
+            Ok(estimate) => estimates.push(ChannelBackfillEstimate { channel_name: Some(channel.name), ..estimate }),
+            Err(error) => failed.push(BatchEstimateFailure { channel: channel.name, error }),
+        }
+    }
+    
+    let video_count: u32 = estimates.iter().map(|e| e.video_count).sum();
+    let estimated_bytes: u64 = estimates.iter().map(|e| e.estimated_bytes).sum();
+    // 전송 시간은 전체 회선(속도 제한)을 공유하고, 영상당 고정 지연만 병렬 채널 수만큼 줄어듦
+    let transfer_seconds = estimated_bytes as f64 * 8.0 / (bandwidth_mbps * 1_000_000.0);
+    let overhead_seconds = PER_VIDEO_OVERHEAD_SECS * video_count as f64 / parallel as f64;
+    let warning = (estimated_bytes > LARGE_BATCH_WARNING_BYTES).then(|| {
+        format!("⚠️ 약 {:.1}GB를 받게 됩니다. 저장 공간과 회선 사용량을 확인하세요", estimated_bytes as f64 / 1024.0 / 1024.0 / 1024.0)
+    });
+    Ok(BatchDownloadEstimate {
+        since,
+        channels: estimates,
+        failed,
+        video_count,
+        estimated_bytes,
+        estimated_seconds: (transfer_seconds + overhead_seconds) as u64,
+        bandwidth_mbps,
+        rate_limited: bandwidth_mbps < ASSUMED_BANDWIDTH_MBPS,
+        parallel_channels: parallel,
+        warning,
+    })
+}
+
+// 배치 다운로드 계획 (dry-run): 받을 영상 목록을 미리 보고 일부를 빼고 정확히 그 목록만 실행
+#[derive(Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum PlanItemStatus {
+    Pending,
+    Downloading,
+    Downloaded,
+    Failed,
+}
+
+#[derive(Serialize, Clone)]
+struct PlannedVideo {
+    video_id: String,
+    title: String,
+    upload_date: Option<String>,
+    duration_seconds: u64,
+    estimated_bytes: u64,
+    estimated_seconds: u64,
+    selected: bool,
+    status: PlanItemStatus,
+    error: Option<String>,
+}
+
+#[derive(Serialize, Clone)]
+struct PlannedChannel {
+    channel: String,
+    url: String,
+    since: Option<String>,
+    already_archived: u32,
+    videos: Vec<PlannedVideo>,
+}
+
+#[derive(Serialize, Clone)]
+struct DownloadPlan {
+    id: String,
+    created_at: String,
+    since: Option<String>,
+    channels: Vec<PlannedChannel>,
+    failed: Vec<BatchEstimateFailure>, // 목록을 조회하지 못한 채널 (계획에서 빠짐)
+    selected_videos: u32,
+    estimated_bytes: u64,              // 선택된 영상 기준
+    estimated_seconds: u64,
+    job_id: Option<String>,            // 마지막으로 실행한 다운로드 작업
+}
+
+impl DownloadPlan {
+    fn recompute_totals(&mut self) {
+        let selected = self.channels.iter().flat_map(|c| &c.videos).filter(|v| v.selected);
+        let (mut count, mut bytes, mut seconds) = (0, 0, 0);
+        for video in selected {
+            count += 1;
+            bytes += video.estimated_bytes;
+            seconds += video.estimated_seconds;
+        }
+        self.selected_videos = count;
+        self.estimated_bytes = bytes;
+        self.estimated_seconds = seconds;
+    }
+}
+
+// 영상 단위 진행 상황 (download-plan-progress 이벤트)
+#[derive(Serialize, Clone)]
+struct PlanItemProgress {
+    plan_id: String,
+    channel: String,
+    video_id: String,
+    status: PlanItemStatus,
+    index: u32, // 이번 실행에서의 순번 (1부터)
+    total: u32,
+    error: Option<String>,
+}
+
+#[derive(Default)]
+struct DownloadPlanState {
+    plans: Mutex<HashMap<String, DownloadPlan>>,
+}
+
+// 다운로드 대상 채널 전체의 계획 생성 (다운로드는 시작하지 않음, 모든 영상이 선택된 상태)
+#[command]
+async fn plan_batch_download(state: State<'_, DownloadPlanState>, since: Option<String>) -> Result<DownloadPlan, String> {
+    let since = since.filter(|d| !d.trim().is_empty()).map(|d| normalize_upload_date(&d)).transpose()?;
+    let estimate = tokio::task::spawn_blocking(move || build_batch_estimate(since))
+        .await
+        .map_err(|e| format!("계획 생성 작업 실패: {}", e))??;
+    
+    let bandwidth_bps = estimate.bandwidth_mbps * 1_000_000.0 / 8.0;
+    let channels = estimate.channels
+        .into_iter()
+        .map(|channel| PlannedChannel {
+            channel: channel.channel_name.clone().unwrap_or_else(|| channel.url.clone()),
+            videos: channel.candidates
+                .iter()
+                .map(|candidate| {
+                    let estimated_bytes = (channel.bytes_per_second * candidate.duration_seconds as f64) as u64;
+                    PlannedVideo {
+                        video_id: candidate.video_id.clone(),
+                        title: candidate.title.clone(),
+                        upload_date: candidate.upload_date.clone(),
+                        duration_seconds: candidate.duration_seconds,
+                        estimated_bytes,
+                        estimated_seconds: (estimated_bytes as f64 / bandwidth_bps + PER_VIDEO_OVERHEAD_SECS) as u64,
+                        selected: true,
+                        status: PlanItemStatus::Pending,
+                        error: None,
+                    }
+                })
+                .collect(),
+            url: channel.url,
+            since: channel.since,
+            already_archived: channel.already_archived,
+        })
+        .collect();
+    
+    let mut plan = DownloadPlan {
+        id: format!("plan-{}-{:04x}", chrono::Local::now().format("%Y%m%d%H%M%S"), rand::random::<u16>()),
+        created_at: chrono::Local::now().to_rfc3339(),
+        since: estimate.since,
+        channels,
+        failed: estimate.failed,
+        selected_videos: 0,
+        estimated_bytes: 0,
+        estimated_seconds: 0,
+        job_id: None,
+    };
+    plan.recompute_totals();
+    state.plans.lock().map_err(|e| e.to_string())?.insert(plan.id.clone(), plan.clone());
+    Ok(plan)
+}
+
+#[command]
+fn get_download_plan(state: State<'_, DownloadPlanState>, plan_id: String) -> Result<DownloadPlan, String> {
+    state.plans.lock().map_err(|e| e.to_string())?
+        .get(&plan_id)
+        .cloned()
+        .ok_or_else(|| format!("다운로드 계획을 찾을 수 없습니다: {}", plan_id))
+}
+
+// 계획 항목 선택/해제 (video_ids가 비어 있으면 채널 단위로 적용)
+#[command]
+fn set_plan_selection(
+    state: State<'_, DownloadPlanState>,
+    plan_id: String,
+    video_ids: Vec<String>,
+    channel: Option<String>,
+    selected: bool,
+) -> Result<DownloadPlan, String> {
+    let mut plans = state.plans.lock().map_err(|e| e.to_string())?;
+    let plan = plans.get_mut(&plan_id).ok_or_else(|| format!("다운로드 계획을 찾을 수 없습니다: {}", plan_id))?;
+    for planned in plan.channels.iter_mut().filter(|c| channel.as_deref().is_none_or(|name| c.channel == name)) {
+        for video in planned.videos.iter_mut() {
+            if video_ids.is_empty() || video_ids.contains(&video.video_id) {
+                video.selected = selected;
+            }
+        }
+    }
+    plan.recompute_totals();
+    Ok(plan.clone())
+}
+
+// 계획 실행 (즉시 작업 ID 반환, 선택됐고 아직 받지 않은 영상만 순서대로 다운로드)
+#[command]
+fn execute_plan(
+    window: Window,
+    state: State<'_, DownloadState>,
+    derivative_state: State<'_, DerivativeState>,
+    plan_state: State<'_, DownloadPlanState>,
+    vault_lock: State<'_, VaultLockState>,
+    plan_id: String,
+) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    {
+        let plans = plan_state.plans.lock().map_err(|e| e.to_string())?;
+        let plan = plans.get(&plan_id).ok_or_else(|| format!("다운로드 계획을 찾을 수 없습니다: {}", plan_id))?;
+        if plan.selected_videos == 0 {
+            return Err("선택된 영상이 없습니다".to_string());
+        }
+    }
+    let ctx = enqueue_download_job(&state, DownloadJobRequest::Plan { plan_id: plan_id.clone() })?;
+    let id = ctx.id.clone();
+    if let Some(plan) = plan_state.plans.lock().map_err(|e| e.to_string())?.get_mut(&plan_id) {
+        plan.job_id = Some(id.clone());
+    }
+    let derivative_state = derivative_state.inner().clone();
+    thread::spawn(move || {
+        let _ = run_download_job(&window, &derivative_state, ctx);
+    });
+    Ok(id)
+}
+
+fn set_plan_item_status(window: &Window, plan_id: &str, channel: &str, video_id: &str, status: PlanItemStatus, error: Option<String>, position: (u32, u32)) {
+    let state = window.state::<DownloadPlanState>();
+    if let Ok(mut plans) = state.plans.lock() {
+        let video = plans
+            .get_mut(plan_id)
+            .and_then(|plan| plan.channels.iter_mut().find(|c| c.channel == channel))
+            .and_then(|c| c.videos.iter_mut().find(|v| v.video_id == video_id));
+        if let Some(video) = video {
+            video.status = status;
+            video.error = error.clone();
+        }
+    }
+    let _ = window.emit("download-plan-progress", &PlanItemProgress {
+        plan_id: plan_id.to_string(),
+        channel: channel.to_string(),
+        video_id: video_id.to_string(),
+        status,
+        index: position.0,
+        total: position.1,
+        error,
+    });
+}
+
+fn execute_plan_download(
+    window: &Window,
+    job: &DownloadJobContext,
+    plan_id: &str,
+) -> Result<String, String> {
+    let venv_python = get_project_root().join("venv").join("bin").join("python3");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    let items: Vec<(String, String, String)> = {
+        let state = window.state::<DownloadPlanState>();
+        let plans = state.plans.lock().map_err(|e| e.to_string())?;
+        let plan = plans.get(plan_id).ok_or_else(|| format!("다운로드 계획을 찾을 수 없습니다: {}", plan_id))?;
+        plan.channels
+            .iter()
+            .flat_map(|c| c.videos.iter().map(move |v| (c, v)))
+            .filter(|(_, v)| v.selected && v.status != PlanItemStatus::Downloaded)
+            .map(|(c, v)| (c.channel.clone(), v.video_id.clone(), v.title.clone()))
+            .collect()
+    };
+    let total = items.len() as u32;
+    job.log(&format!("📋 다운로드 계획 실행: {}개 영상", total));
+    job.update(|j| j.total_videos = total);
+    
+    let (mut downloaded, mut failed) = (0u32, 0u32);
+    for (index, (channel, video_id, title)) in items.iter().enumerate() {
+        if job.is_cancelled() {
+            break;
+        }
+        let position = (index as u32 + 1, total);
+        set_plan_item_status(window, plan_id, channel, video_id, PlanItemStatus::Downloading, None, position);
+        job.log(&format!("[{}/{}] 📥 {}", position.0, total, title));
+        
+        let url = format!("https://www.youtube.com/watch?v={}", video_id);
+        let result = single_download_command(&venv_python, &url, None, Some(channel))
+            .spawn()
+            .map_err(|e| e.to_string())
+            .and_then(|child| run_process_with_realtime_output(child, window, channel, job, ProcessOperation::Single));
+        let (status, error) = match result {
+            Ok((_, _, exit)) if exit.success() => (PlanItemStatus::Downloaded, None),
+            Ok(_) => (PlanItemStatus::Failed, Some("다운로드 중 오류가 발생했습니다".to_string())),
+            // 중단된 항목은 다음 실행에서 다시 대상이 됨
+            Err(_) if job.is_cancelled() => (PlanItemStatus::Pending, None),
+            Err(e) => (PlanItemStatus::Failed, Some(e)),
+        };
+        match status {
+            PlanItemStatus::Downloaded => downloaded += 1,
+            PlanItemStatus::Failed => failed += 1,
+            _ => {}
+        }
+        set_plan_item_status(window, plan_id, channel, video_id, status, error, position);
+        job.update(|j| {
+            j.completed_videos = downloaded;
+            j.progress = position.0 as f32 / total.max(1) as f32 * 100.0;
+        });
+    }
+    
+    if downloaded > 0 {
+        render_missing_video_notes();
+        spawn_download_verification(window);
+        spawn_media_probe_harvest(window, &window.state::<MediaProbeState>());
+    }
+    if job.is_cancelled() {
+        return Ok(format!("다운로드 계획 실행이 중단되었습니다 ({}개 완료)", downloaded));
+    }
+    Ok(format!("✅ 다운로드 계획 실행 완료: {}개 성공, {}개 실패", downloaded, failed))
+}
+
+// yt-dlp로 채널 소개 정보 조회 (영상 목록은 받지 않음)
+// 채널/재생목록 메타데이터만 조회 (영상 목록 제외)
+fn fetch_channel_info_json(url: &str) -> Result<serde_json::Value, String> {
+    let project_root = get_project_root();
+    let venv_python = project_root.join("venv").join("bin").join("python3");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    
+    let output = Command::new(&venv_python)
+        .args(["-m", "yt_dlp", "-J", "--flat-playlist", "--playlist-items", "0", "--no-warnings"])
+        .args(ytdlp_auth_args(&load_auth_settings()))
+        .args(ytdlp_proxy_args())
+        .arg(url)
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| format!("yt-dlp 실행 실패: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("채널 정보 조회 실패: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    
+    serde_json::from_slice(&output.stdout).map_err(|e| format!("yt-dlp 출력 파싱 실패: {}", e))
+}
+
+fn fetch_channel_profile(url: &str) -> Result<ChannelProfile, String> {
+    let info = fetch_channel_info_json(url)?;
+    let text = |key: &str| info.get(key).and_then(|v| v.as_str()).map(|s| s.to_string());
+    
+    let description = text("description").unwrap_or_default();
+    let link_re = Regex::new(r"https?://[^\s)\]>]+").unwrap();
+    let mut links: Vec<String> = link_re.find_iter(&description).map(|m| m.as_str().to_string()).collect();
+    links.dedup();
+    
+    let avatar_url = info
+        .get("thumbnails")
+        .and_then(|t| t.as_array())
+        .and_then(|thumbnails| {
+            thumbnails
+                .iter()
+                .find(|t| t.get("id").and_then(|id| id.as_str()) == Some("avatar_uncropped"))
+                .or_else(|| thumbnails.last())
+        })
+        .and_then(|t| t.get("url").and_then(|u| u.as_str()))
+        .map(|s| s.to_string());
+    
+    Ok(ChannelProfile {
+        channel: extract_channel_name_from_url(url),
+        channel_id: text("channel_id"),
+        url: url.to_string(),
+        // 재생목록은 소유 채널이 아닌 재생목록 제목
+        title: if playlist_id_from_url(url).is_some() {
+            text("title")
+        } else {
+            text("channel").or_else(|| text("uploader"))
+        },
+        description,
+        links,
+        subscriber_count: info.get("channel_follower_count").and_then(|v| v.as_u64()),
+        tags: info
+            .get("tags")
+            .and_then(|t| t.as_array())
+            .map(|tags| tags.iter().filter_map(|t| t.as_str().map(|s| s.to_string())).collect())
+            .unwrap_or_default(),
+        avatar_url,
+        archived_at: chrono::Local::now().to_rfc3339(),
+    })
+}
+
+fn load_channel_profile(channel_name: &str) -> Option<ChannelProfile> {
+    let content = fs::read_to_string(channel_vault_dir(channel_name).join(CHANNEL_PROFILE_FILE)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+// 채널 소개 조회 후 채널 폴더에 저장
+fn archive_channel_profile(url: &str) -> Result<ChannelProfile, String> {
+    let profile = fetch_channel_profile(url)?;
+    store_channel_profile(&profile)?;
+    Ok(profile)
+}
+
+// 채널 소개 저장 (내용이 바뀌면 이전 버전을 이력으로 보관)
+fn store_channel_profile(profile: &ChannelProfile) -> Result<(), String> {
+    let channel_dir = channel_vault_dir(&profile.channel);
+    fs::create_dir_all(&channel_dir).map_err(|e| format!("채널 폴더 생성 실패: {}", e))?;
+    
+    if let Some(previous) = load_channel_profile(&profile.channel) {
+        let changed = previous.description != profile.description
+            || previous.links != profile.links
+            || previous.title != profile.title
+            || previous.subscriber_count != profile.subscriber_count;
+        if changed {
+            let history_dir = channel_dir.join(CHANNEL_PROFILE_HISTORY_DIR);
+            fs::create_dir_all(&history_dir).map_err(|e| e.to_string())?;
+            let stamp = chrono::DateTime::parse_from_rfc3339(&previous.archived_at)
+                .map(|t| t.format("%Y%m%d_%H%M%S").to_string())
+                .unwrap_or_else(|_| chrono::Local::now().format("%Y%m%d_%H%M%S").to_string());
+            let json = serde_json::to_string_pretty(&previous).map_err(|e| e.to_string())?;
+            fs::write(history_dir.join(format!("{}.json", stamp)), json).map_err(|e| e.to_string())?;
+        }
+    }
+    
+    let json = serde_json::to_string_pretty(profile).map_err(|e| e.to_string())?;
+    fs::write(channel_dir.join(CHANNEL_PROFILE_FILE), json).map_err(|e| format!("채널 소개 저장 실패: {}", e))
+}
+
+// 채널 삭제/비공개로 판단되는 yt-dlp 오류 (네트워크 오류 등 일시적 실패는 제외)
+fn classify_dead_channel_error(error: &str) -> Option<String> {
+    let lower = error.to_lowercase();
+    let reason = if lower.contains("terminated") {
+        "계정 해지됨"
+    } else if lower.contains("does not exist") || lower.contains("http error 404") {
+        "채널 없음"
+    } else if lower.contains("private") {
+        "비공개 전환"
+    } else if lower.contains("not available") || lower.contains("unavailable") {
+        "채널 이용 불가"
+    } else {
+        return None;
+    };
+    Some(reason.to_string())
+}
+
+// 채널 생존 확인: 소개 조회 성공 여부로 추모(memorialized) 상태 갱신
+fn check_channel_liveness(channel: &ChannelInfo, store_profile: bool) -> Result<(), String> {
+    let result = fetch_channel_profile(&channel.url);
+    let mut settings = load_channel_settings();
+    let entry = settings.entry(channel.name.clone()).or_default();
+    entry.last_liveness_check_at = Some(chrono::Local::now().to_rfc3339());
+    
+    match &result {
+        Ok(profile) => {
+            if entry.memorialized_at.take().is_some() {
+                println!("🔄 채널 복구 감지: {}", channel.name);
+            }
+            entry.memorial_reason = None;
+            if store_profile {
+                store_channel_profile(profile)?;
+            }
+        }
+        Err(e) => {
+            if let Some(reason) = classify_dead_channel_error(e) {
+                if entry.memorialized_at.is_none() {
+                    println!("🕯️ 채널 추모 처리: {} ({})", channel.name, reason);
+                    entry.memorialized_at = Some(chrono::Local::now().to_rfc3339());
+                }
+                entry.memorial_reason = Some(reason);
+            }
+        }
+    }
+    
+    save_channel_settings(&settings)?;
+    result.map(|_| ())
+}
+
+// 채널 생존 확인(주 1회) 및 채널 소개 갱신(월 1회)
+fn spawn_channel_profile_refresher(vault_lock: VaultLockState) {
+    thread::spawn(move || loop {
+        thread::sleep(CHANNEL_PROFILE_CHECK_INTERVAL);
+        if vault_lock.read_only.load(Ordering::SeqCst) {
+            continue;
+        }
+        
+        let settings = load_channel_settings();
+        for channel in read_channels().unwrap_or_default() {
+            let liveness_due = settings
+                .get(&channel.name)
+                .map(|s| is_older_than_days(&s.last_liveness_check_at, CHANNEL_LIVENESS_CHECK_DAYS))
+                .unwrap_or(true);
+            let profile_due = load_channel_profile(&channel.name)
+                .map(|p| is_older_than_days(&Some(p.archived_at), CHANNEL_PROFILE_REFRESH_DAYS))
+                .unwrap_or(true);
+            if !liveness_due && !profile_due {
+                continue;
+            }
+            match check_channel_liveness(&channel, profile_due) {
+                // 생존 확인 주기에 맞춰 조회수 스냅샷도 함께 기록
+                Ok(()) if liveness_due => {
+                    if let Err(e) = refresh_channel_view_counts(&channel) {
+                        eprintln!("⚠️ 조회수 갱신 실패 {}: {}", channel.name, e);
+                    }
+                }
+                Ok(()) => {}
+                Err(e) => eprintln!("⚠️ 채널 확인 실패 {}: {}", channel.name, e),
+            }
+            // 연속 요청으로 인한 차단 방지
+            thread::sleep(Duration::from_secs(5));
+        }
+    });
+}
+
+// 새 업로드 감시 (활성 채널의 YouTube RSS 피드를 주기적으로 확인)
+const CHANNEL_WATCH_SETTINGS_FILE: &str = "channel_watch.json";
+const CHANNEL_WATCH_STATE_FILE: &str = "channel_watch_state.json";
+const CHANNEL_WATCH_MIN_INTERVAL_MINUTES: u32 = 5;
+// 채널별로 기억할 최근 영상 ID 수 (피드에는 최신 15개만 나옴)
+const CHANNEL_WATCH_SEEN_LIMIT: usize = 100;
+const CHANNEL_FEED_TIMEOUT: Duration = Duration::from_secs(20);
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+struct ChannelWatchSettings {
+    enabled: bool,
+    interval_minutes: u32,
+    auto_download: bool, // 새 영상을 감시 작업이 바로 다운로드 대기열에 추가
+}
+
+impl Default for ChannelWatchSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_minutes: 30,
+            auto_download: false,
+        }
+    }
+}
+
+// 채널 URL별 감시 상태 (이미 알린 영상 ID)
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+struct ChannelWatchState {
+    last_checked_at: Option<String>,
+    seen_video_ids: Vec<String>,
+}
+
+// new-video-available 이벤트 payload
+#[derive(Serialize, Clone)]
+struct NewVideoAvailable {
+    channel: String,
+    channel_url: String,
+    video_id: String,
+    title: String,
+    url: String,
+    published_at: Option<String>,
+    thumbnail_url: String,
+    auto_download: bool,          // true면 다운로드 대기열에 추가됨 (이벤트는 알림용)
+}
+
+struct FeedEntry {
+    video_id: String,
+    title: String,
+    published_at: Option<String>,
+}
+
+// @핸들 URL은 보관된 채널 소개의 채널 ID로 피드 URL 구성
+fn channel_feed_url(channel: &ChannelInfo) -> Option<String> {
+    youtube_feed_url(&channel.url).or_else(|| {
+        let channel_id = load_channel_profile(&channel.name)?.channel_id?;
+        Some(format!("https://www.youtube.com/feeds/videos.xml?channel_id={}", channel_id))
+    })
+}
+
+fn fetch_channel_feed(feed_url: &str) -> Result<String, String> {
+    let mut builder = ureq::AgentBuilder::new().timeout(CHANNEL_FEED_TIMEOUT);
+    if let Some(proxy) = load_proxy_url() {
+        builder = builder.proxy(ureq::Proxy::new(&proxy).map_err(|e| format!("프록시 설정 오류: {}", e))?);
+    }
+    builder
+        .build()
+        .get(feed_url)
+        .call()
+        .map_err(|e| format!("RSS 피드 요청 실패: {}", e))?
+        .into_string()
+        .map_err(|e| format!("RSS 피드 읽기 실패: {}", e))
+}
+
+// 피드 항목 (최신순)
+fn parse_feed_entries(xml: &str) -> Vec<FeedEntry> {
+    let entry_re = Regex::new(r"(?s)<entry>(.*?)</entry>").unwrap();
+    let video_id_re = Regex::new(r"<yt:videoId>([^<]+)</yt:videoId>").unwrap();
+    let title_re = Regex::new(r"(?s)<title>(.*?)</title>").unwrap();
+    let published_re = Regex::new(r"<published>([^<]+)</published>").unwrap();
+    entry_re
+        .captures_iter(xml)
+        .filter_map(|entry| {
+            let body = entry.get(1)?.as_str();
+            Some(FeedEntry {
+                video_id: video_id_re.captures(body)?[1].trim().to_string(),
+                title: title_re.captures(body).map(|t| xml_unescape(t[1].trim())).unwrap_or_default(),
+                published_at: published_re.captures(body).map(|p| p[1].trim().to_string()),
+            })
+        })
+        .collect()
+}
+
+// 인덱스에 이미 있는 영상 ID (배치 다운로드로 먼저 받은 영상은 알리지 않음)
+fn indexed_video_ids() -> std::collections::HashSet<String> {
+    let Ok(conn) = open_index_db() else {
+        return Default::default();
+    };
+    let Ok(mut stmt) = conn.prepare("SELECT video_id FROM videos WHERE video_id IS NOT NULL") else {
+        return Default::default();
+    };
+    stmt.query_map([], |row| row.get::<_, String>(0))
+        .map(|rows| rows.flatten().collect())
+        .unwrap_or_default()
+}
+
+// 활성 채널 피드를 확인해 처음 보는 영상 목록 반환 (채널을 처음 확인할 때는 기존 영상을 알리지 않고 기록만)
+fn poll_channel_feeds(auto_download: bool) -> Result<Vec<NewVideoAvailable>, String> {
+    let mut state: BTreeMap<String, ChannelWatchState> = load_json_config(CHANNEL_WATCH_STATE_FILE).unwrap_or_default();
+    let archived = indexed_video_ids();
+    let mut found = Vec::new();
+    
+    let channels: Vec<ChannelInfo> = read_channels()?
+        .into_iter()
+        .filter(|c| c.enabled && !c.memorialized && c.state == ChannelState::Active)
+        .collect();
+    for channel in channels {
+        let Some(feed_url) = channel_feed_url(&channel) else {
+            continue; // 채널 ID를 아직 모름 (채널 소개가 보관되면 확인 가능)
+        };
+        let entries = match fetch_channel_feed(&feed_url) {
+            Ok(xml) => parse_feed_entries(&xml),
+            Err(e) => {
+                eprintln!("⚠️ 새 업로드 확인 실패 {}: {}", channel.name, e);
+                continue;
+            }
+        };
+        
+        let first_check = !state.contains_key(&channel.url);
+        let watch = state.entry(channel.url.clone()).or_default();
+        watch.last_checked_at = Some(chrono::Local::now().to_rfc3339());
+        // 오래된 영상부터 알림
+        for entry in entries.into_iter().rev() {
+            if watch.seen_video_ids.contains(&entry.video_id) {
+                continue;
+            }
+            watch.seen_video_ids.push(entry.video_id.clone());
+            if first_check || archived.contains(&entry.video_id) {
+                continue;
+            }
+            found.push(NewVideoAvailable {
+                channel: channel.name.clone(),
+                channel_url: channel.url.clone(),
+                url: format!("https://www.youtube.com/watch?v={}", entry.video_id),
+                thumbnail_url: format!("https://i.ytimg.com/vi/{}/hqdefault.jpg", entry.video_id),
+                video_id: entry.video_id,
+                title: entry.title,
+                published_at: entry.published_at,
+                auto_download,
+            });
+        }
+        let excess = watch.seen_video_ids.len().saturating_sub(CHANNEL_WATCH_SEEN_LIMIT);
+        watch.seen_video_ids.drain(..excess);
+        
+        // 연속 요청으로 인한 차단 방지
+        thread::sleep(Duration::from_secs(1));
+    }
+    
+    save_json_config(CHANNEL_WATCH_STATE_FILE, &state)?;
+    Ok(found)
+}
+
+// 자동 다운로드 대상은 바로 대기열에 추가한 뒤 알림 (웹뷰가 이벤트를 받지 못해도 다운로드는 진행)
+fn handle_new_videos(app: &tauri::AppHandle, videos: &mut [NewVideoAvailable]) {
+    if videos.iter().any(|v| v.auto_download) {
+        let writable = ensure_vault_writable(app.state::<VaultLockState>().inner());
+        match (app.get_webview_window("main").map(|w| w.as_ref().window()), writable) {
+            (Some(window), Ok(())) => {
+                let download_state = app.state::<DownloadState>().inner().clone();
+                let derivative_state = app.state::<DerivativeState>().inner().clone();
+                for video in videos.iter_mut().filter(|v| v.auto_download) {
+                    let request = DownloadJobRequest::Single {
+                        url: video.url.clone(),
+                        format: None,
+                        channel_name: Some(video.channel.clone()),
+                    };
+                    match enqueue_download_job(&download_state, request) {
+                        Ok(ctx) => {
+                            let window = window.clone();
+                            let derivative_state = derivative_state.clone();
+                            thread::spawn(move || {
+                                let _ = run_download_job(&window, &derivative_state, ctx);
+                            });
+                        }
+                        Err(e) => {
+                            eprintln!("⚠️ 자동 다운로드 추가 실패 {}: {}", video.title, e);
+                            video.auto_download = false;
+                        }
+                    }
+                }
+            }
+            (_, writable) => {
+                eprintln!("⚠️ 자동 다운로드를 건너뜁니다: {}", writable.err().unwrap_or_else(|| "메인 창이 없습니다".to_string()));
+                videos.iter_mut().for_each(|v| v.auto_download = false);
+            }
+        }
+    }
+    for video in videos.iter() {
+        let _ = app.emit("new-video-available", video);
+    }
+}
+
+// 감시가 켜져 있으면 설정된 주기마다 피드 확인 (설정 변경은 다음 주기부터 반영)
+fn spawn_channel_watcher(app: tauri::AppHandle) {
+    thread::spawn(move || loop {
+        let settings: ChannelWatchSettings = load_json_config(CHANNEL_WATCH_SETTINGS_FILE).unwrap_or_default();
+        let interval = settings.interval_minutes.max(CHANNEL_WATCH_MIN_INTERVAL_MINUTES) as u64;
+        thread::sleep(Duration::from_secs(interval * 60));
+        
+        let settings: ChannelWatchSettings = load_json_config(CHANNEL_WATCH_SETTINGS_FILE).unwrap_or_default();
+        if !settings.enabled {
+            continue;
+        }
+        match poll_channel_feeds(settings.auto_download) {
+            Ok(mut videos) => handle_new_videos(&app, &mut videos),
+            Err(e) => eprintln!("⚠️ 새 업로드 감시 실패: {}", e),
+        }
+    });
+}
+
+#[command]
+fn get_channel_watch_settings() -> Result<ChannelWatchSettings, String> {
+    load_json_config(CHANNEL_WATCH_SETTINGS_FILE)
+}
+
+#[command]
+fn save_channel_watch_settings(settings: ChannelWatchSettings) -> Result<(), String> {
+    if settings.interval_minutes < CHANNEL_WATCH_MIN_INTERVAL_MINUTES {
+        return Err(format!("확인 주기는 {}분 이상이어야 합니다", CHANNEL_WATCH_MIN_INTERVAL_MINUTES));
+    }
+    save_json_config(CHANNEL_WATCH_SETTINGS_FILE, &settings)
+}
+
+// 새 업로드 즉시 확인 (찾은 영상은 new-video-available 이벤트로도 알림, 자동 다운로드면 대기열에 추가)
+#[command]
+async fn check_new_uploads(app: tauri::AppHandle) -> Result<Vec<NewVideoAvailable>, String> {
+    let auto_download = load_json_config::<ChannelWatchSettings>(CHANNEL_WATCH_SETTINGS_FILE).unwrap_or_default().auto_download;
+    let mut videos = tokio::task::spawn_blocking(move || poll_channel_feeds(auto_download))
+        .await
+        .map_err(|e| format!("새 업로드 확인 작업 실패: {}", e))??;
+    handle_new_videos(&app, &mut videos);
+    Ok(videos)
+}
+
+// 다운로드에서 제외할 채널 URL 목록 (추모/일시정지/보관, JSON, Python에서 사용)
+fn excluded_channels_env(channels: &[ChannelInfo]) -> String {
+    let urls: Vec<&str> = channels.iter().filter(|c| c.memorialized || c.state != ChannelState::Active).map(|c| c.url.as_str()).collect();
+    serde_json::to_string(&urls).unwrap_or_else(|_| "[]".to_string())
+}
+
+#[derive(Serialize, Deserialize)]
+struct MemorialVideo {
+    title: String,
+    video_id: Option<String>,
+    upload_date: Option<String>,
+    video_path: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MemorialChannelReport {
+    channel: String,
+    url: String,
+    memorialized_at: Option<String>,
+    reason: Option<String>,
+    total_size_mb: f64,
+    // 원본이 사라졌으므로 보관본이 유일한 사본
+    videos: Vec<MemorialVideo>,
+}
+
+// 추모 채널 목록 즉시 갱신
+#[command]
+async fn check_dead_channels() -> Result<Vec<String>, String> {
+    for channel in read_channels()? {
+        if let Err(e) = check_channel_liveness(&channel, false) {
+            eprintln!("⚠️ 채널 확인 실패 {}: {}", channel.name, e);
+        }
+        thread::sleep(Duration::from_secs(2));
+    }
+    Ok(read_channels()?.into_iter().filter(|c| c.memorialized).map(|c| c.name).collect())
+}
+
+// 추모 채널별 유일 보관본 리포트
+#[command]
+fn get_memorial_report() -> Result<Vec<MemorialChannelReport>, String> {
+    let settings = load_channel_settings();
+    let videos = scan_vault_videos().unwrap_or_default();
+    let project_root = get_project_root();
+    
+    let reports = read_channels()?
+        .into_iter()
+        .filter(|c| c.memorialized)
+        .map(|channel| {
+            let folder_name = sanitize_vault_name(&channel.name);
+            let channel_settings = settings.get(&channel.name);
+            // vault 경로의 채널 폴더 기준으로 매칭 (frontmatter 채널명은 표시명일 수 있음)
+            let channel_videos: Vec<&VideoInfo> = videos
+                .iter()
+                .filter(|v| Path::new(&v.video_path).components().nth(2).map(|c| c.as_os_str() == folder_name.as_str()).unwrap_or(false))
+                .collect();
+            MemorialChannelReport {
+                total_size_mb: bytes_to_mb(calculate_directory_size(&channel_vault_dir(&channel.name))),
+                channel: channel.name,
+                url: channel.url,
+                memorialized_at: channel_settings.and_then(|s| s.memorialized_at.clone()),
+                reason: channel_settings.and_then(|s| s.memorial_reason.clone()),
+                videos: channel_videos
+                    .into_iter()
+                    .filter(|v| project_root.join(&v.video_path).exists())
+                    .map(|v| MemorialVideo {
+                        title: v.title.clone(),
+                        video_id: v.video_id.clone(),
+                        upload_date: v.upload_date.clone(),
+                        video_path: v.video_path.clone(),
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+    
+    Ok(reports)
+}
+
+// 보관된 채널 소개 조회
+#[command]
+fn get_channel_profile(channel: String) -> Result<Option<ChannelProfile>, String> {
+    Ok(load_channel_profile(&channel))
+}
+
+// 채널 소개 즉시 갱신
+#[command]
+async fn refresh_channel_profile(vault_lock: State<'_, VaultLockState>, url: String) -> Result<ChannelProfile, String> {
+    ensure_vault_writable(&vault_lock)?;
+    archive_channel_profile(&url)
+}
+
+// 채널별로 전체 비디오를 그룹핑하여 조회 (인기/최신 분리)
+#[command]
+fn get_recent_videos_by_channel(limit_per_channel: Option<usize>) -> Result<RecentVideos, String> {
+    let videos = scan_vault_videos()?;
+    let _limit = limit_per_channel.unwrap_or(5);
+    
+    // 채널별로 그룹핑 (전체 비디오)
+    let mut channel_groups: HashMap<String, Vec<VideoInfo>> = HashMap::new();
+    
+    for video in videos {
+        let channel_name = video.channel.clone();
+        channel_groups.entry(channel_name).or_insert_with(Vec::new).push(video);
+    }
+    
+    // 각 채널의 전체 비디오를 반환 (프론트엔드에서 인기/최신 분리)
+    let mut channels: Vec<ChannelVideos> = channel_groups
+        .into_iter()
+        .map(|(channel_name, videos)| {
+            ChannelVideos {
+                channel_name,
+                videos,
+            }
+        })
+        .collect();
+    
+    // 사용자 지정 채널 순서, 목록에 없는 채널(삭제된 채널 폴더 등)은 뒤에 이름순
+    let order = channel_order();
+    let position = |name: &str| order.get(name).copied().unwrap_or(usize::MAX);
+    channels.sort_by(|a, b| {
+        position(&a.channel_name).cmp(&position(&b.channel_name)).then_with(|| a.channel_name.cmp(&b.channel_name))
+    });
+    
+    Ok(RecentVideos { channels })
+}
+
+// 채널 상세 통계 (vault를 직접 읽어 계산)
+#[derive(Serialize)]
+struct ChannelStats {
+    channel: String,
+    video_count: u32,
+    total_duration_seconds: u64,
+    disk_usage_bytes: u64,
+    oldest_upload: Option<String>,
+    newest_upload: Option<String>,
+    captioned_videos: u32,          // 자막 본문이 있는 영상
+    caption_coverage: f64,          // 0~1
+    embedded_videos: Option<u32>,   // 벡터 DB를 읽을 수 없으면 None
+    embedding_coverage: Option<f64>,
+}
+
+fn compute_channel_stats(channel: &str) -> Result<ChannelStats, String> {
+    let project_root = get_project_root();
+    let videos: Vec<VideoInfo> = scan_vault_videos()?.into_iter().filter(|v| v.channel == channel).collect();
+    if videos.is_empty() {
+        return Err(format!("보관된 영상이 없는 채널입니다: {}", channel));
+    }
+    
+    // 채널 폴더 (vault/10_videos/<채널 폴더>/...) 크기
+    let channel_dirs: std::collections::BTreeSet<PathBuf> = videos
+        .iter()
+        .map(|v| Path::new(&v.video_path).components().take(3).collect::<PathBuf>())
+        .collect();
+    let disk_usage_bytes = channel_dirs.iter().map(|dir| calculate_directory_size(&project_root.join(dir))).sum();
+    
+    let captioned_videos = videos
+        .iter()
+        .filter(|v| {
+            fs::read_to_string(frontmatter_path(v))
+                .map(|content| {
+                    let transcript = extract_transcript_text(&content);
+                    !transcript.is_empty() && transcript != NO_TRANSCRIPT_MARK
+                })
+                .unwrap_or(false)
+        })
+        .count() as u32;
+    let embedded_videos = embedded_video_ids().map(|ids| {
+        videos.iter().filter(|v| v.video_id.as_ref().map(|id| ids.contains(id)).unwrap_or(false)).count() as u32
+    });
+    
+    let video_count = videos.len() as u32;
+    let uploads = || videos.iter().filter_map(|v| v.upload_date.clone());
+    Ok(ChannelStats {
+        channel: channel.to_string(),
+        video_count,
+        total_duration_seconds: videos.iter().filter_map(|v| v.duration_seconds).map(u64::from).sum(),
+        disk_usage_bytes,
+        oldest_upload: uploads().min(),
+        newest_upload: uploads().max(),
+        captioned_videos,
+        caption_coverage: captioned_videos as f64 / video_count as f64,
+        embedded_videos,
+        embedding_coverage: embedded_videos.map(|count| count as f64 / video_count as f64),
+    })
+}
+
+// 채널 상세 페이지용 통계 (영상 수/총 길이/용량/업로드 기간/자막·임베딩 비율)
+#[command]
+async fn get_channel_stats(channel: String) -> Result<ChannelStats, String> {
+    tokio::task::spawn_blocking(move || compute_channel_stats(&channel))
+        .await
+        .map_err(|e| format!("채널 통계 계산 작업 실패: {}", e))?
+}
+
+// 같은 채널이 여러 폴더로 나뉜 경우 (핸들 변경 후 다운로더가 새 폴더를 만든 경우 등)
+#[derive(Serialize)]
+struct SplitChannelFolder {
+    folder: String, // vault/10_videos 아래 채널 폴더 이름
+    video_count: u32,
+    newest_upload: Option<String>,
+}
+
+#[derive(Serialize)]
+struct SplitChannelGroup {
+    channel_id: String,
+    folders: Vec<SplitChannelFolder>,
+    suggested_target: String, // 최근 업로드가 있는 폴더 (현재 핸들일 가능성이 높음)
+}
+
+#[derive(Serialize)]
+struct ChannelMergeReport {
+    old: String,
+    new: String,
+    moved: u32,
+    metadata_updated: u32,
+    skipped: Vec<String>,     // 대상 폴더에 같은 영상 폴더가 이미 있어 옮기지 않은 항목
+    trashed: Option<String>,  // 정리된 기존 채널 폴더의 휴지통 항목 ID
+}
+
+fn video_channel_id(video: &VideoInfo) -> Option<String> {
+    let content = fs::read_to_string(frontmatter_path(video)).ok()?;
+    frontmatter_raw_field(&content, "channel_id")
+        .map(|id| id.trim_matches('"').trim_matches('\'').to_string())
+        .filter(|id| !id.is_empty())
+}
+
+// 영상 경로의 채널 폴더(vault/10_videos/<폴더>)별 채널 ID (frontmatter channel_id, 없으면 채널 소개 파일)
+// frontmatter channel은 업로더 표시 이름이라 핸들이 바뀐 두 폴더에서 같을 수 있으므로 폴더 이름으로 묶음
+fn channel_ids_by_channel_folder(videos: &[VideoInfo]) -> BTreeMap<String, std::collections::BTreeSet<String>> {
+    let mut ids: BTreeMap<String, std::collections::BTreeSet<String>> = BTreeMap::new();
+    for video in videos {
+        let Some(folder) = video_channel_folder(&video.video_path) else { continue };
+        let entry = ids.entry(folder).or_default();
+        if let Some(id) = video_channel_id(video) {
+            entry.insert(id);
+        }
+    }
+    let videos_dir = get_project_root().join("vault").join("10_videos");
+    for (folder, channel_ids) in ids.iter_mut() {
+        if channel_ids.is_empty() {
+            let profile = fs::read_to_string(videos_dir.join(folder).join(CHANNEL_PROFILE_FILE))
+                .ok()
+                .and_then(|c| serde_json::from_str::<ChannelProfile>(&c).ok());
+            if let Some(id) = profile.and_then(|p| p.channel_id) {
+                channel_ids.insert(id);
+            }
+        }
+    }
+    ids
+}
+
+fn is_in_channel_folder(video: &VideoInfo, folder: &str) -> bool {
+    video_channel_folder(&video.video_path).as_deref() == Some(folder)
+}
+
+fn find_split_channels() -> Result<Vec<SplitChannelGroup>, String> {
+    let videos = scan_vault_videos()?;
+    let mut folders_by_id: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (folder, ids) in channel_ids_by_channel_folder(&videos) {
+        for id in ids {
+            folders_by_id.entry(id).or_default().push(folder.clone());
+        }
+    }
+    
+    let mut groups = Vec::new();
+    for (channel_id, folder_names) in folders_by_id.into_iter().filter(|(_, f)| f.len() > 1) {
+        let folders: Vec<SplitChannelFolder> = folder_names
+            .into_iter()
+            .map(|folder| {
+                let folder_videos = || videos.iter().filter(|v| is_in_channel_folder(v, &folder));
+                SplitChannelFolder {
+                    video_count: folder_videos().count() as u32,
+                    newest_upload: folder_videos().filter_map(|v| v.upload_date.clone()).max(),
+                    folder,
+                }
+            })
+            .collect();
+        let suggested_target = folders
+            .iter()
+            .max_by(|a, b| a.newest_upload.cmp(&b.newest_upload).then(a.video_count.cmp(&b.video_count)))
+            .map(|f| f.folder.clone())
+            .unwrap_or_default();
+        groups.push(SplitChannelGroup { channel_id, folders, suggested_target });
+    }
+    Ok(groups)
+}
+
+// 채널 ID가 같은데 폴더가 나뉜 채널 목록
+#[command]
+async fn detect_split_channels() -> Result<Vec<SplitChannelGroup>, String> {
+    tokio::task::spawn_blocking(find_split_channels)
+        .await
+        .map_err(|e| format!("채널 폴더 분리 감지 작업 실패: {}", e))?
+}
+
+fn merge_channel_folders_blocking(queue: &PlayQueueState, old: &str, new: &str) -> Result<ChannelMergeReport, String> {
+    if old == new {
+        return Err("같은 채널 폴더끼리는 병합할 수 없습니다".to_string());
+    }
+    if [old, new].iter().any(|f| f.is_empty() || *f == "." || *f == ".." || f.contains(['/', '\\'])) {
+        return Err("잘못된 채널 폴더 이름입니다".to_string());
+    }
+    let project_root = get_project_root();
+    let videos = scan_vault_videos()?;
+    let old_videos: Vec<&VideoInfo> = videos.iter().filter(|v| is_in_channel_folder(v, old)).collect();
+    let new_videos: Vec<&VideoInfo> = videos.iter().filter(|v| is_in_channel_folder(v, new)).collect();
+    if old_videos.is_empty() {
+        return Err(format!("보관된 영상이 없는 채널 폴더입니다: {}", old));
+    }
+    
+    // 채널 ID가 모두 확인되는데 서로 다르면 다른 채널로 보고 거부
+    let ids = channel_ids_by_channel_folder(&videos);
+    let old_ids = ids.get(old).cloned().unwrap_or_default();
+    let new_ids = ids.get(new).cloned().unwrap_or_default();
+    if !old_ids.is_empty() && !new_ids.is_empty() && old_ids.is_disjoint(&new_ids) {
+        return Err(format!("채널 ID가 달라 병합할 수 없습니다: {} ↔ {}", old, new));
+    }
+    let channel_id = new_ids.iter().chain(old_ids.iter()).next().cloned();
+    
+    let old_dir = Path::new("vault").join("10_videos").join(old);
+    let new_dir = Path::new("vault").join("10_videos").join(new);
+    // frontmatter의 채널명은 대상 폴더 최신 영상 기준 (업로더 표시 이름)
+    let channel_line = new_videos
+        .iter()
+        .max_by(|a, b| a.upload_date.cmp(&b.upload_date))
+        .and_then(|v| fs::read_to_string(frontmatter_path(v)).ok())
+        .and_then(|content| frontmatter_raw_field(&content, "channel"))
+        .map(|raw| format!("channel: {}", raw))
+        .unwrap_or_else(|| format!("channel: {}", yaml_scalar(new)));
+    
+    let mut report = ChannelMergeReport {
+        old: old.to_string(),
+        new: new.to_string(),
+        moved: 0,
+        metadata_updated: 0,
+        skipped: Vec::new(),
+        trashed: None,
+    };
+    let mut conn = open_index_db()?;
+    let batch_id = new_change_batch_id();
+    for video in old_videos {
+        let folder = match Path::new(&video.video_path).parent() {
+            Some(f) => f.to_path_buf(),
+            None => continue,
+        };
+        // 기존 채널 폴더 기준 상대 경로 (폴더 밖이면 대상 경로를 만들 수 없으므로 건너뜀)
+        let Ok(rest) = folder.strip_prefix(&old_dir).map(|p| p.to_path_buf()) else {
+            report.skipped.push(format!("채널 폴더 밖의 영상: {}", folder.display()));
+            continue;
+        };
+        let from = folder.to_string_lossy().to_string();
+        let to = new_dir.join(&rest).to_string_lossy().to_string();
+        if project_root.join(&to).exists() {
+            report.skipped.push(format!("대상 폴더가 이미 존재: {}", to));
+            continue;
+        }
+        move_video_folder(&project_root, &from, &to)?;
+        let tx = conn.transaction().map_err(|e| e.to_string())?;
+        rewrite_index_paths(&tx, &from, &to)?;
+        tx.commit().map_err(|e| format!("인덱스 경로 갱신 커밋 실패: {}", e))?;
+        rewrite_config_paths(queue, &from, &to)?;
+        report.moved += 1;
+        
+        let captions_md = project_root.join(&to).join("captions.md");
+        if let Ok(content) = fs::read_to_string(&captions_md) {
+            let mut updated = set_frontmatter_line(&content, "channel", &channel_line).unwrap_or_else(|| content.clone());
+            if let Some(id) = &channel_id {
+                if frontmatter_raw_field(&content, "channel_id").map(|v| v.is_empty()).unwrap_or(true) {
+                    updated = set_frontmatter_line(&updated, "channel_id", &format!("channel_id: {}", id)).unwrap_or(updated);
+                }
+            }
+            if updated != content {
+                write_frontmatter_with_journal(&captions_md, &updated, "channel_merge", &batch_id)?;
+                report.metadata_updated += 1;
+            }
+        }
+    }
+    
+    // 옮기지 못한 영상이 없으면 남은 기존 폴더(채널 소개, 빈 연도 폴더 등)는 휴지통으로
+    let old_path = project_root.join(&old_dir);
+    if report.skipped.is_empty() && old_path.exists() {
+        report.trashed = Some(move_to_trash(&old_path, &format!("채널 폴더 병합: {} → {}", old, new))?);
+    }
+    
+    let mut settings = load_channel_settings();
+    if let Some(old_settings) = settings.remove(old) {
+        settings.entry(new.to_string()).or_insert(old_settings);
+        save_channel_settings(&settings)?;
+    }
+    
+    reconcile_vault_index()?;
+    Ok(report)
+}
+
+// 나뉜 채널 폴더를 하나로 병합 (영상 폴더 이동, frontmatter 채널명 보정, 인덱스 경로 갱신)
+#[command]
+async fn merge_channel_folders(
+    jobs: State<'_, VaultJobState>,
+    vault_lock: State<'_, VaultLockState>,
+    queue: State<'_, PlayQueueState>,
+    old: String,
+    new: String,
+) -> Result<ChannelMergeReport, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let running = RunningGuard::try_new(&jobs.merging_channels).ok_or("이미 채널 폴더 병합이 진행 중입니다")?;
+    let queue = queue.inner().clone();
+    tokio::task::spawn_blocking(move || {
+        let _running = running;
+        merge_channel_folders_blocking(&queue, &old, &new)
+    })
+        .await
+        .map_err(|e| format!("채널 폴더 병합 작업 실패: {}", e))?
+}
+
+// 관련 채널 추천 (보관된 영상의 주제 태그로 YouTube 검색)
+const SUGGEST_KEYWORD_COUNT: usize = 5;
+const SUGGEST_SEARCH_RESULTS: usize = 15;
+const SUGGEST_DEFAULT_LIMIT: usize = 10;
+const SUGGEST_MAX_LIMIT: usize = 30;
+
+#[derive(Serialize)]
+struct ChannelSuggestion {
+    channel: String,
+    channel_id: Option<String>,
+    url: String,
+    matched_keywords: Vec<String>, // 이 채널 영상이 검색된 키워드
+    sample_titles: Vec<String>,
+    hits: u32,                     // 검색 결과에 나온 영상 수
+}
+
+#[derive(Serialize)]
+struct ChannelSuggestions {
+    keywords: Vec<String>,
+    suggestions: Vec<ChannelSuggestion>,
+}
+
+// 보관된 영상에 가장 많이 붙은 주제 태그
+fn top_archive_keywords(videos: &[VideoInfo], count: usize) -> Vec<String> {
+    let mut counts: HashMap<String, u32> = HashMap::new();
+    for topic in videos.iter().filter_map(|v| v.topic.as_ref()).flatten() {
+        let topic = topic.trim().trim_start_matches('#').to_lowercase();
+        if topic.chars().count() >= 2 {
+            *counts.entry(topic).or_insert(0) += 1;
+        }
+    }
+    let mut ranked: Vec<(String, u32)> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    ranked.into_iter().take(count).map(|(topic, _)| topic).collect()
+}
+
+// yt-dlp 검색 결과 (flat) 영상 목록
+fn search_youtube_videos(query: &str, count: usize) -> Result<Vec<serde_json::Value>, String> {
+    let project_root = get_project_root();
+    let venv_python = project_root.join("venv").join("bin").join("python3");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    
+    let output = Command::new(&venv_python)
+        .args(["-m", "yt_dlp", "-J", "--flat-playlist", "--no-warnings"])
+        .args(ytdlp_auth_args(&load_auth_settings()))
+        .args(ytdlp_proxy_args())
+        .arg(format!("ytsearch{}:{}", count, query))
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| format!("yt-dlp 실행 실패: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("YouTube 검색 실패 ({}): {}", query, String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    let info: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("yt-dlp 출력 파싱 실패: {}", e))?;
+    Ok(info.get("entries").and_then(|e| e.as_array()).cloned().unwrap_or_default())
+}
+
+fn find_channel_suggestions(limit: usize) -> Result<ChannelSuggestions, String> {
+    let videos = scan_vault_videos()?;
+    let keywords = top_archive_keywords(&videos, SUGGEST_KEYWORD_COUNT);
+    if keywords.is_empty() {
+        return Err("주제 태그가 있는 영상이 없어 추천할 수 없습니다".to_string());
+    }
+    
+    // 이미 등록했거나 보관 중인 채널은 제외 (채널명/채널 ID 기준)
+    let mut known_names: std::collections::HashSet<String> = read_channels()?
+        .into_iter()
+        .flat_map(|c| [c.name.to_lowercase(), extract_channel_name_from_url(&c.url).to_lowercase()])
+        .collect();
+    known_names.extend(videos.iter().map(|v| v.channel.to_lowercase()));
+    let known_ids: std::collections::HashSet<String> = channel_ids_by_channel_folder(&videos).into_values().flatten().collect();
+    
+    let mut suggestions: BTreeMap<String, ChannelSuggestion> = BTreeMap::new();
+    for keyword in &keywords {
+        let entries = match search_youtube_videos(keyword, SUGGEST_SEARCH_RESULTS) {
+            Ok(entries) => entries,
+            Err(e) => {
+                println!("⚠️ {}", e);
+                continue;
+            }
+        };
+        for entry in entries {
+            let text = |key: &str| entry.get(key).and_then(|v| v.as_str()).filter(|s| !s.is_empty()).map(|s| s.to_string());
+            let Some(url) = text("uploader_url").or_else(|| text("channel_url")) else {
+                continue;
+            };
+            let channel_id = text("channel_id");
+            let handle = extract_channel_name_from_url(&url).to_lowercase();
+            let channel = text("channel").or_else(|| text("uploader")).unwrap_or_else(|| handle.clone());
+            if known_names.contains(&handle)
+                || known_names.contains(&channel.to_lowercase())
+                || channel_id.as_ref().map(|id| known_ids.contains(id)).unwrap_or(false)
+            {
+                continue;
+            }
+            
+            let suggestion = suggestions.entry(channel_id.clone().unwrap_or_else(|| handle.clone())).or_insert_with(|| ChannelSuggestion {
+                channel,
+                channel_id,
+                url,
+                matched_keywords: Vec::new(),
+                sample_titles: Vec::new(),
+                hits: 0,
+            });
+            suggestion.hits += 1;
+            if !suggestion.matched_keywords.contains(keyword) {
+                suggestion.matched_keywords.push(keyword.clone());
+            }
+            if let Some(title) = text("title") {
+                if suggestion.sample_titles.len() < 3 {
+                    suggestion.sample_titles.push(title);
+                }
+            }
+        }
+    }
+    
+    // 여러 키워드에 걸쳐 나온 채널 우선
+    let mut suggestions: Vec<ChannelSuggestion> = suggestions.into_values().collect();
+    suggestions.sort_by(|a, b| {
+        b.matched_keywords
+            .len()
+            .cmp(&a.matched_keywords.len())
+            .then(b.hits.cmp(&a.hits))
+            .then(a.channel.cmp(&b.channel))
+    });
+    suggestions.truncate(limit);
+    Ok(ChannelSuggestions { keywords, suggestions })
+}
+
+// 보관된 자막의 주제와 비슷한 채널 추천
+#[command]
+async fn suggest_channels(limit: Option<usize>) -> Result<ChannelSuggestions, String> {
+    let limit = limit.unwrap_or(SUGGEST_DEFAULT_LIMIT).clamp(1, SUGGEST_MAX_LIMIT);
+    tokio::task::spawn_blocking(move || find_channel_suggestions(limit))
+        .await
+        .map_err(|e| format!("채널 추천 작업 실패: {}", e))?
+}
+
+// 설정 관리
+#[command]
+fn get_config() -> Result<String, String> {
+    let project_root = get_project_root();
+    let config_path = project_root.join("pyproject.toml");
+    if config_path.exists() {
+        fs::read_to_string(&config_path).map_err(|e| e.to_string())
+    } else {
+        Ok("설정 파일이 없습니다".to_string())
+    }
+}
+
+// Range 요청을 지원하는 비디오 서버 시작
+#[command]
+async fn start_video_server(state: State<'_, VideoServerState>) -> Result<u16, String> {
+    let server_port_lock = state.server_port.read().await;
+    
+    // 이미 서버가 실행 중이면 포트 반환
+    if let Some(port) = *server_port_lock {
+        return Ok(port);
+    }
+    drop(server_port_lock);
+    
+    let project_root = get_project_root();
+    
+    // 사용 가능한 포트 찾기 (OS가 자동 할당)
+    let port = find_available_port().await?;
+    
+    // Range 지원 파일 서빙 필터 생성
+    let files = warp::path("video")
+        .and(warp::path::tail())
+        .and(warp::get())
+        .and(warp::header::optional::<String>("range"))
+        .and_then(move |tail: warp::path::Tail, range: Option<String>| {
+            let project_root = project_root.clone();
+            async move {
+                serve_video_with_range(project_root, tail.as_str(), range).await
+            }
+        });
+    
+    // 오디오 트랙만 제공 (긴 강연을 LAN에서 들을 때 대역폭 절약)
+    let audio_root = get_project_root();
+    let audio = warp::path("audio")
+        .and(warp::path::tail())
+        .and(warp::get())
+        .and(warp::header::optional::<String>("range"))
+        .and_then(move |tail: warp::path::Tail, range: Option<String>| {
+            let project_root = audio_root.clone();
+            let video_path = urlencoding::decode(tail.as_str()).map(|p| p.to_string()).unwrap_or_else(|_| tail.as_str().to_string());
+            async move {
+                serve_audio_with_range(project_root, video_path, range).await
+            }
+        });
+    
+    // 주제별 다이제스트 RSS 피드 (스케줄러가 생성한 파일)
+    let digests = warp::path!("digest" / String)
+        .and(warp::get())
+        .and_then(|file_name: String| async move {
+            let slug = file_name.strip_suffix(".xml").unwrap_or(&file_name).to_string();
+            let path = get_digests_dir().join(format!("{}.xml", sanitize_vault_name(&slug)));
+            match fs::read_to_string(&path) {
+                Ok(xml) => Ok(warp::reply::with_header(xml, "content-type", "application/rss+xml; charset=utf-8")),
+                Err(_) => Err(warp::reject::not_found()),
+            }
+        });
+    
+    // CORS 헤더 추가 (로컬 전용)
+    let cors = warp::cors()
+        .allow_origin("tauri://localhost")
+        .allow_origin("http://localhost:3000") // 개발용
+        .allow_headers(vec!["content-type", "range"])
+        .allow_methods(vec!["GET", "HEAD", "OPTIONS"]);
+    
+    let routes = files.or(audio).or(digests).with(cors);
+    
+    // 서버 시작 (127.0.0.1 바인딩으로 보안 강화)
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let server = warp::serve(routes).run(addr);
+    
+    let handle = tokio::spawn(server);
+    
+    // 상태 업데이트
+    *state.server_port.write().await = Some(port);
+    *state.server_handle.write().await = Some(handle);
+    
+    Ok(port)
+}
+
+// Range 요청을 지원하는 비디오 파일 서빙
+async fn serve_video_with_range(
+    project_root: PathBuf, 
+    file_path: &str, 
+    range_header: Option<String>
+) -> Result<impl warp::Reply, warp::Rejection> {
+    use warp::http::StatusCode;
+    use std::io::{Read, Seek, SeekFrom};
+    
+    // 보안: 경로 탐색 공격 방지
+    let cleaned_path = file_path.replace("..", "");
+    let safe_path = cleaned_path.trim_start_matches('/');
+    
+    // URL 디코딩 처리
+    let decoded_path = match urlencoding::decode(safe_path) {
+        Ok(decoded) => decoded.to_string(),
+        Err(_) => safe_path.to_string()
+    };
+    
+    // vault/ 경로를 올바르게 매핑
+    let full_path = project_root.join("vault").join(&decoded_path);
+    
+    if !full_path.exists() || !full_path.is_file() {
+        return Err(warp::reject::not_found());
+    }
+    
+    // MIME 타입 추정 (비디오 파일에 대해 명시적으로 설정)
+    let mime_type = if full_path.extension().map(|ext| ext == "mp4").unwrap_or(false) {
+        "video/mp4".to_string()
+    } else if full_path.extension().map(|ext| ext == "m4a").unwrap_or(false) {
+        "audio/mp4".to_string()
+    } else {
+        mime_guess::from_path(&full_path)
+            .first_or_octet_stream()
+            .to_string()
+    };
+    
+    // 파일 크기 확인
+    let file_size = match std::fs::metadata(&full_path) {
+        Ok(metadata) => metadata.len(),
+        Err(_) => return Err(warp::reject::not_found()),
+    };
+    
+    // Range 헤더 파싱
+    let (start, end) = parse_range_header(range_header.as_deref(), file_size);
+    let content_length = end - start + 1;
+    
+    // 파일 읽기
+    let mut file = match std::fs::File::open(&full_path) {
+        Ok(f) => f,
+        Err(_) => return Err(warp::reject::not_found()),
+    };
+    
+    // 시작 위치로 이동
+    if let Err(_) = file.seek(SeekFrom::Start(start)) {
+        return Err(warp::reject::not_found());
+    }
+    
+    // 요청된 범위만큼 읽기
+    let mut buffer = vec![0u8; content_length as usize];
+    if let Err(_) = file.read_exact(&mut buffer) {
+        return Err(warp::reject::not_found());
+    }
+    
+    // HTTP 응답 생성 (warp::reply::Response 사용)
+    use warp::http::Response;
+    
+    let status_code = if range_header.is_some() && (start != 0 || end + 1 != file_size) {
+        StatusCode::PARTIAL_CONTENT
+    } else {
+        StatusCode::OK
+    };
+    
+    let mut response_builder = Response::builder()
+        .status(status_code)
+        .header("content-type", mime_type)
+        .header("accept-ranges", "bytes")
+        .header("access-control-allow-origin", "*")
+        .header("access-control-allow-methods", "GET, HEAD, OPTIONS")
+        .header("access-control-allow-headers", "range")
+        .header("cache-control", "no-cache");
+    
+    if range_header.is_some() && (start != 0 || end + 1 != file_size) {
+        response_builder = response_builder
+            .header("content-range", format!("bytes {}-{}/{}", start, end, file_size))
+            .header("content-length", content_length.to_string());
+    } else {
+        response_builder = response_builder
+            .header("content-length", file_size.to_string());
+    }
+    
+         match response_builder.body(buffer) {
+         Ok(response) => Ok(response),
+         Err(_) => Err(warp::reject::custom(ServerError)),
+     }
+}
+
+// 오디오 전용 재생 (영상 폴더의 audio.m4a로 캐시, 원본보다 오래되면 다시 추출)
+const AUDIO_TRACK_FILE: &str = "audio.m4a";
+// 변환은 한 번에 하나씩 (같은 영상의 동시 요청이 임시 파일을 덮어쓰지 않도록)
+static AUDIO_EXTRACTION_LOCK: Mutex<()> = Mutex::new(());
+
+fn ensure_audio_track(video_file: &Path) -> Option<PathBuf> {
+    let folder = video_file.parent()?;
+    let audio = folder.join(AUDIO_TRACK_FILE);
+    let is_fresh = |audio: &Path| file_mtime_secs(audio).zip(file_mtime_secs(video_file)).map(|(a, v)| a >= v).unwrap_or(false);
+    if is_fresh(&audio) {
+        return Some(audio);
+    }
+    
+    let _guard = AUDIO_EXTRACTION_LOCK.lock().ok()?;
+    if is_fresh(&audio) {
+        return Some(audio);
+    }
+    let temp = folder.join("audio.tmp.m4a");
+    let video_str = video_file.to_string_lossy().to_string();
+    // AAC 트랙은 재인코딩 없이 복사, 그 외 코덱은 저비트레이트 AAC로 변환
+    let ok = run_throttled_ffmpeg(&["-i", &video_str, "-vn", "-c:a", "copy"], &temp)
+        || run_throttled_ffmpeg(&["-i", &video_str, "-vn", "-c:a", "aac", "-b:a", "96k"], &temp);
+    if !ok {
+        return None;
+    }
+    fs::rename(&temp, &audio).ok()?;
+    Some(audio)
+}
+
+// /audio/<vault 기준 영상 경로> 요청을 오디오 트랙 파일로 응답
+async fn serve_audio_with_range(
+    project_root: PathBuf,
+    video_path: String,
+    range_header: Option<String>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let vault_root = project_root.join("vault");
+    let video_file = vault_root.join(video_path.replace("..", "").trim_start_matches('/'));
+    if !video_file.is_file() {
+        return Err(warp::reject::not_found());
+    }
+    let audio = tokio::task::spawn_blocking(move || ensure_audio_track(&video_file))
+        .await
+        .ok()
+        .flatten()
+        .ok_or_else(warp::reject::not_found)?;
+    let relative = audio.strip_prefix(&vault_root).map_err(|_| warp::reject::not_found())?;
+    serve_video_with_range(project_root, &relative.to_string_lossy(), range_header).await
+}
+
+// Range 헤더 파싱 함수
+fn parse_range_header(range_header: Option<&str>, file_size: u64) -> (u64, u64) {
+    if let Some(range) = range_header {
+        if let Some(range_value) = range.strip_prefix("bytes=") {
+            if let Some((start_str, end_str)) = range_value.split_once('-') {
+                let start = start_str.parse::<u64>().unwrap_or(0);
+                let end = if end_str.is_empty() {
+                    file_size - 1
+                } else {
+                    end_str.parse::<u64>().unwrap_or(file_size - 1).min(file_size - 1)
+                };
+                return (start, end);
+            }
+        }
+    }
+    (0, file_size - 1)
+}
+
+// 사용 가능한 포트 찾기
+async fn find_available_port() -> Result<u16, String> {
+    use std::net::TcpListener;
+    
+    // OS가 자동으로 할당하는 방식 (포트 0 사용)
+    match TcpListener::bind("127.0.0.1:0") {
+        Ok(listener) => {
+            let port = listener.local_addr().unwrap().port();
+            drop(listener); // 바로 해제
+            Ok(port)
+        }
+        Err(_) => {
+            // fallback: 수동으로 포트 검색
+            for port in 8080..8090 {
+                if TcpListener::bind(format!("127.0.0.1:{}", port)).is_ok() {
+                    return Ok(port);
+                }
+            }
+            Err("사용 가능한 포트를 찾을 수 없습니다".to_string())
+        }
+    }
+}
+
+// 비디오 서버 중지
+#[command]
+async fn stop_video_server(state: State<'_, VideoServerState>) -> Result<(), String> {
+    let mut server_handle_lock = state.server_handle.write().await;
+    let mut server_port_lock = state.server_port.write().await;
+    
+    if let Some(handle) = server_handle_lock.take() {
+        handle.abort();
+    }
+    
+    *server_port_lock = None;
+    
+    Ok(())
+}
+
+// 비디오 서버 상태 확인
+#[command]
+async fn get_video_server_status(state: State<'_, VideoServerState>) -> Result<Option<u16>, String> {
+    let server_port_lock = state.server_port.read().await;
+    Ok(*server_port_lock)
+}
+
+// 비디오 URL 생성
+#[command]
+async fn get_video_url(video_path: String, state: State<'_, VideoServerState>) -> Result<String, String> {
+    let server_port_lock = state.server_port.read().await;
+    
+    if let Some(port) = *server_port_lock {
+        // vault/ 경로 제거하고 HTTP URL 생성
+        let clean_path = video_path.trim_start_matches("vault/");
+        
+        // URL 인코딩 처리 - 특수문자와 한글 문자 처리
+        let encoded_path = urlencoding::encode(clean_path).to_string();
+        
+        Ok(format!("http://127.0.0.1:{}/video/{}", port, encoded_path))
+    } else {
+        Err("비디오 서버가 실행되지 않았습니다. 먼저 서버를 시작해주세요.".to_string())
+    }
+}
+
+// 오디오 전용 URL 생성 (첫 요청 시 오디오 트랙 추출 후 캐시)
+#[command]
+async fn get_audio_url(video_path: String, state: State<'_, VideoServerState>) -> Result<String, String> {
+    let port = (*state.server_port.read().await)
+        .ok_or("비디오 서버가 실행되지 않았습니다. 먼저 서버를 시작해주세요.")?;
+    let encoded_path = urlencoding::encode(video_path.trim_start_matches("vault/")).to_string();
+    Ok(format!("http://127.0.0.1:{}/audio/{}", port, encoded_path))
+}
+
+// 공유 링크 관련 구조체들
+#[derive(Serialize, Deserialize, Clone)]
+struct ShareLink {
+    video_id: String,
+    title: String,
+    url: String,
+    audio_url: String,
+    transcript_url: Option<String>,
+    expires_at: String,
+}
+
+struct ShareRequest {
+    video_id: String,
+    expires: i64,
+    transcript: bool,
+    signature: String,
+}
+
+const DEFAULT_SHARE_TTL_MINUTES: u32 = 60;
+const MAX_SHARE_TTL_MINUTES: u32 = 7 * 24 * 60;
+
+fn new_share_secret() -> Vec<u8> {
+    use rand::RngCore;
+    let mut secret = vec![0u8; 32];
+    rand::thread_rng().fill_bytes(&mut secret);
+    secret
+}
+
+fn share_mac(secret: &[u8], video_id: &str, expires: i64, transcript: bool) -> hmac::Hmac<sha2::Sha256> {
+    use hmac::Mac;
+    let mut mac = hmac::Hmac::<sha2::Sha256>::new_from_slice(secret).expect("HMAC은 모든 키 길이 허용");
+    mac.update(format!("{}:{}:{}", video_id, expires, transcript as u8).as_bytes());
+    mac
+}
+
+// 홀수 길이면 마지막 조각이 범위를 벗어나 None
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok()).collect()
+}
+
+// 서명과 만료 시각 확인
+fn verify_share_request(secret: &[u8], request: &ShareRequest) -> bool {
+    use hmac::Mac;
+    if request.expires < chrono::Utc::now().timestamp() {
+        return false;
+    }
+    let Some(signature) = decode_hex(&request.signature) else {
+        return false;
+    };
+    share_mac(secret, &request.video_id, request.expires, request.transcript)
+        .verify_slice(&signature)
+        .is_ok()
+}
+
+// 기본 경로의 LAN 주소 (UDP connect는 실제 패킷을 보내지 않음)
+fn lan_ip_address() -> String {
+    std::net::UdpSocket::bind("0.0.0.0:0")
+        .and_then(|socket| {
+            socket.connect("8.8.8.8:80")?;
+            socket.local_addr()
+        })
+        .map(|addr| addr.ip().to_string())
+        .unwrap_or_else(|_| "127.0.0.1".to_string())
+}
+
+// 서명된 링크의 영상(또는 오디오/자막)만 제공
+async fn serve_shared_resource(
+    project_root: PathBuf,
+    secret: Arc<std::sync::RwLock<Vec<u8>>>,
+    request: ShareRequest,
+    resource: String,
+    range: Option<String>,
+) -> Result<Box<dyn warp::Reply>, warp::Rejection> {
+    let verified = secret.read().map(|secret| verify_share_request(&secret, &request)).unwrap_or(false);
+    if !verified || (resource == "transcript" && !request.transcript) {
+        return Err(warp::reject::not_found());
+    }
+    
+    // 요청마다 Vault를 스캔하지 않고 인덱스에서 영상 경로 조회
+    let (video_path, captions_path): (String, String) = open_index_db()
+        .ok()
+        .and_then(|conn| {
+            conn.query_row(
+                "SELECT video_path, captions_path FROM videos WHERE video_id = ?1 LIMIT 1",
+                [&request.video_id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .ok()
+        })
+        .ok_or_else(warp::reject::not_found)?;
+    
+    match resource.as_str() {
+        "video" => {
+            let relative = video_path.trim_start_matches("vault/").to_string();
+            let reply = serve_video_with_range(project_root, &relative, range).await?;
+            Ok(Box::new(reply))
+        }
+        "audio" => {
+            let relative = video_path.trim_start_matches("vault/").to_string();
+            let reply = serve_audio_with_range(project_root, relative, range).await?;
+            Ok(Box::new(reply))
+        }
+        "transcript" => {
+            let text = fs::read_to_string(project_root.join(&captions_path)).map_err(|_| warp::reject::not_found())?;
+            Ok(Box::new(warp::reply::with_header(text, "content-type", "text/plain; charset=utf-8")))
+        }
+        _ => Err(warp::reject::not_found()),
+    }
+}
+
+// 공유 서버 시작 (LAN 전체에 열리지만 서명된 /share 경로만 응답)
+async fn ensure_share_server(state: &ShareServerState) -> Result<u16, String> {
+    if let Some(port) = *state.port.read().await {
+        return Ok(port);
+    }
+    
+    let port = find_available_port().await?;
+    let project_root = get_project_root();
+    let secret = state.secret.clone();
+    let routes = warp::path!("share" / String / i64 / u8 / String / String)
+        .and(warp::get())
+        .and(warp::header::optional::<String>("range"))
+        .and_then(move |video_id: String, expires: i64, transcript: u8, signature: String, resource: String, range: Option<String>| {
+            let project_root = project_root.clone();
+            let secret = secret.clone();
+            let request = ShareRequest { video_id, expires, transcript: transcript == 1, signature };
+            async move { serve_shared_resource(project_root, secret, request, resource, range).await }
+        });
+    
+    let addr = SocketAddr::from(([0, 0, 0, 0], port));
+    tokio::spawn(warp::serve(routes).run(addr));
+    *state.port.write().await = Some(port);
+    Ok(port)
+}
+
+// 단일 영상용 시간 제한 공유 링크 생성
+#[command]
+async fn create_share_link(
+    state: State<'_, ShareServerState>,
+    video_id: String,
+    ttl_minutes: Option<u32>,
+    include_transcript: Option<bool>,
+) -> Result<ShareLink, String> {
+    let ttl = ttl_minutes.unwrap_or(DEFAULT_SHARE_TTL_MINUTES);
+    if ttl == 0 || ttl > MAX_SHARE_TTL_MINUTES {
+        return Err(format!("공유 시간은 1분 ~ {}분 사이여야 합니다", MAX_SHARE_TTL_MINUTES));
+    }
+    let video = scan_vault_videos()?
+        .into_iter()
+        .find(|v| v.video_id.as_deref() == Some(video_id.as_str()))
+        .ok_or_else(|| format!("영상을 찾을 수 없습니다: {}", video_id))?;
+    
+    let port = ensure_share_server(&state).await?;
+    let transcript = include_transcript.unwrap_or(false);
+    let expires_at = chrono::Utc::now() + chrono::Duration::minutes(ttl as i64);
+    let expires = expires_at.timestamp();
+    let signature: String = {
+        use hmac::Mac;
+        let secret = state.secret.read().map_err(|e| e.to_string())?;
+        share_mac(&secret, &video_id, expires, transcript)
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    };
+    
+    let base = format!(
+        "http://{}:{}/share/{}/{}/{}/{}",
+        lan_ip_address(),
+        port,
+        urlencoding::encode(&video_id),
+        expires,
+        transcript as u8,
+        signature
+    );
+    Ok(ShareLink {
+        video_id,
+        title: video.title,
+        url: format!("{}/video", base),
+        audio_url: format!("{}/audio", base),
+        transcript_url: transcript.then(|| format!("{}/transcript", base)),
+        expires_at: expires_at.with_timezone(&chrono::Local).to_rfc3339(),
+    })
+}
+
+// 발급된 모든 공유 링크 무효화 (서명 키 교체)
+#[command]
+fn revoke_share_links(state: State<'_, ShareServerState>) -> Result<(), String> {
+    *state.secret.write().map_err(|e| e.to_string())? = new_share_secret();
+    Ok(())
+}
+
+// 녹화 파일 업로드 수신 (/api/ingest, 청크 단위로 이어 올리기 가능)
+// POST로 세션 생성 → PATCH + Upload-Offset 헤더로 청크 추가 → 마지막 청크 후 vault에 영상으로 등록
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+struct IngestSettings {
+    enabled: bool,
+    port: u16,               // 다른 기기에서 접속하므로 고정 포트
+    token: String,           // Authorization: Bearer <token>
+    default_channel: String, // 요청에 채널이 없을 때 사용할 vault 채널 폴더
+    transcribe: bool,        // 등록 후 Whisper로 자막 생성
+}
+
+impl Default for IngestSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: 8765,
+            token: String::new(),
+            default_channel: "Local Recordings".to_string(),
+            transcribe: true,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum IngestSessionState {
+    Uploading,
+    Processing,
+    Completed,
+    Failed,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct IngestSession {
+    id: String,
+    filename: String,
+    size: u64,
+    offset: u64,
+    title: String,
+    channel: String,
+    recorded_at: Option<String>, // YYYYMMDD (없으면 업로드 날짜)
+    created_at: String,
+    updated_at: String,
+    state: IngestSessionState,
+    video_path: Option<String>,
+    error: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct IngestCreateRequest {
+    filename: String,
+    size: u64,
+    title: Option<String>,
+    channel: Option<String>,
+    recorded_at: Option<String>,
+}
+
+// 업로드 서버 상태 (설정을 바꾸면 다시 시작)
+#[derive(Default, Clone)]
+struct IngestServerState {
+    port: Arc<RwLock<Option<u16>>>,
+    handle: Arc<RwLock<Option<tokio::task::JoinHandle<()>>>>,
+}
+
+const INGEST_SETTINGS_FILE: &str = "ingest.json";
+const INGEST_CHUNK_MAX_BYTES: u64 = 64 * 1024 * 1024;
+const INGEST_FILE_MAX_BYTES: u64 = 50 * 1024 * 1024 * 1024;
+// 완료되지 않은 세션은 이 기간이 지나면 정리
+const INGEST_SESSION_TTL_DAYS: i64 = 7;
+const INGEST_VIDEO_EXTENSIONS: [&str; 6] = ["mp4", "mov", "m4v", "mkv", "webm", "avi"];
+
+// 업로드 중인 파일은 vault/.ingest/<세션>/ 아래에 모음 (session.json + upload.part)
+fn get_ingest_dir() -> PathBuf {
+    get_project_root().join("vault").join(".ingest")
+}
+
+fn load_ingest_session(id: &str) -> Option<IngestSession> {
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return None;
+    }
+    let content = fs::read_to_string(get_ingest_dir().join(id).join("session.json")).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn save_ingest_session(session: &mut IngestSession) -> Result<(), String> {
+    let dir = get_ingest_dir().join(&session.id);
+    fs::create_dir_all(&dir).map_err(|e| format!("업로드 폴더 생성 실패: {}", e))?;
+    session.updated_at = chrono::Local::now().to_rfc3339();
+    let content = serde_json::to_string_pretty(session).map_err(|e| e.to_string())?;
+    fs::write(dir.join("session.json"), content).map_err(|e| format!("업로드 세션 저장 실패: {}", e))
+}
+
+fn new_token() -> String {
+    new_share_secret().iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// 업로드 API 인증 실패 (이 경우만 401로 응답)
+#[derive(Debug)]
+struct IngestUnauthorized;
+
+impl warp::reject::Reject for IngestUnauthorized {}
+
+// Bearer 토큰 확인 (길이 외 정보가 새지 않도록 전체 비교)
+fn ingest_authorized(settings: &IngestSettings, authorization: Option<&str>) -> bool {
+    let Some(token) = authorization.and_then(|h| h.strip_prefix("Bearer ")) else {
+        return false;
+    };
+    !settings.token.is_empty()
+        && token.len() == settings.token.len()
+        && token.bytes().zip(settings.token.bytes()).fold(0u8, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+fn ingest_reply<T: Serialize>(status: warp::http::StatusCode, body: &T) -> warp::reply::Response {
+    use warp::Reply;
+    warp::reply::with_status(warp::reply::json(body), status).into_response()
+}
+
+fn ingest_error(status: warp::http::StatusCode, message: &str) -> warp::reply::Response {
+    ingest_reply(status, &serde_json::json!({ "error": message }))
+}
+
+// 거부된 요청의 상태 코드 (인증 실패만 401, 나머지는 원래 의미대로)
+fn ingest_rejection_reply(rejection: &warp::Rejection) -> warp::reply::Response {
+    use warp::http::StatusCode;
+    let (status, message) = if rejection.find::<IngestUnauthorized>().is_some() {
+        (StatusCode::UNAUTHORIZED, "인증 실패")
+    } else if rejection.is_not_found() {
+        (StatusCode::NOT_FOUND, "알 수 없는 경로입니다")
+    } else if rejection.find::<warp::reject::MethodNotAllowed>().is_some() {
+        (StatusCode::METHOD_NOT_ALLOWED, "허용되지 않는 메서드입니다")
+    } else if rejection.find::<warp::reject::PayloadTooLarge>().is_some() {
+        (StatusCode::PAYLOAD_TOO_LARGE, "요청 본문이 너무 큽니다")
+    } else if rejection.find::<warp::reject::LengthRequired>().is_some() {
+        (StatusCode::LENGTH_REQUIRED, "Content-Length 헤더가 필요합니다")
+    } else if rejection.find::<warp::reject::UnsupportedMediaType>().is_some() {
+        (StatusCode::UNSUPPORTED_MEDIA_TYPE, "지원하지 않는 Content-Type입니다")
+    } else if rejection.find::<warp::reject::MissingHeader>().is_some()
+        || rejection.find::<warp::reject::InvalidHeader>().is_some()
+        || rejection.find::<warp::reject::InvalidQuery>().is_some()
+        || rejection.find::<warp::filters::body::BodyDeserializeError>().is_some()
+    {
+        (StatusCode::BAD_REQUEST, "잘못된 요청입니다")
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, "요청을 처리하지 못했습니다")
+    };
+    ingest_error(status, message)
+}
+
+// 오래된 미완료 세션 정리
+fn purge_stale_ingest_sessions() {
+    let cutoff = chrono::Local::now() - chrono::Duration::days(INGEST_SESSION_TTL_DAYS);
+    let Ok(entries) = fs::read_dir(get_ingest_dir()) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let id = entry.file_name().to_string_lossy().to_string();
+        let stale = match load_ingest_session(&id) {
+            Some(session) => chrono::DateTime::parse_from_rfc3339(&session.updated_at)
+                .map(|t| t < cutoff)
+                .unwrap_or(true),
+            None => true,
+        };
+        if stale {
+            let _ = fs::remove_dir_all(entry.path());
+        }
+    }
+}
+
+fn create_ingest_session(vault_lock: &VaultLockState, request: IngestCreateRequest) -> warp::reply::Response {
+    use warp::http::StatusCode;
+    if vault_lock.read_only.load(Ordering::SeqCst) {
+        return ingest_error(StatusCode::SERVICE_UNAVAILABLE, "vault가 읽기 전용입니다");
     }
-    
-    let total_channels = channels.len() as u32;
-    let mut all_output = Vec::new();
-    
-    // 시작 진행 상황
-    let start_progress = DownloadProgress {
-        channel: format!("벡터 임베딩 ({} 채널)", total_channels),
-        status: "시작".to_string(),
-        progress: 0.0,
-        current_video: format!("선택된 {} 채널의 임베딩 생성 준비 중...", total_channels),
-        total_videos: total_channels,
-        completed_videos: 0,
-        log_message: format!("🧠 {} 채널의 벡터 임베딩 생성을 시작합니다...", total_channels),
+    let extension = Path::new(&request.filename)
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    if !INGEST_VIDEO_EXTENSIONS.contains(&extension.as_str()) {
+        return ingest_error(StatusCode::UNSUPPORTED_MEDIA_TYPE, "지원하지 않는 영상 형식입니다");
+    }
+    if request.size == 0 || request.size > INGEST_FILE_MAX_BYTES {
+        return ingest_error(StatusCode::PAYLOAD_TOO_LARGE, "파일 크기가 허용 범위를 벗어났습니다");
+    }
+    let recorded_at = match request.recorded_at.as_deref().map(normalize_upload_date).transpose() {
+        Ok(date) => date,
+        Err(e) => return ingest_error(StatusCode::BAD_REQUEST, &e),
     };
-    let _ = window.emit("embedding-progress", &start_progress);
-    
-    // 모든 선택된 채널을 한 번에 처리
-    let processing_progress = DownloadProgress {
-        channel: format!("벡터 임베딩 ({} 채널)", total_channels),
-        status: "처리 중".to_string(),
-        progress: 50.0,
-        current_video: format!("📺 선택된 {} 채널 처리 중...", total_channels),
-        total_videos: total_channels,
-        completed_videos: 0,
-        log_message: format!("📊 {} 채널의 벡터 임베딩 생성 중...", channels.join(", ")),
+    let settings: IngestSettings = load_json_config(INGEST_SETTINGS_FILE).unwrap_or_default();
+    let channel = request.channel.filter(|c| !c.trim().is_empty()).unwrap_or(settings.default_channel);
+    // 채널 이름이 vault/10_videos 밖을 가리키지 않도록 (".", ".." 또는 정리 후 빈 이름 거부)
+    if matches!(sanitize_vault_name(&channel).as_str(), "" | "." | "..") {
+        return ingest_error(StatusCode::BAD_REQUEST, "사용할 수 없는 채널 이름입니다");
+    }
+    purge_stale_ingest_sessions();
+    
+    let stem = Path::new(&request.filename).file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    let now = chrono::Local::now().to_rfc3339();
+    let mut session = IngestSession {
+        id: format!("ingest-{}-{:04x}", chrono::Local::now().format("%Y%m%d%H%M%S"), rand::random::<u16>()),
+        filename: request.filename,
+        size: request.size,
+        offset: 0,
+        title: request.title.filter(|t| !t.trim().is_empty()).unwrap_or(stem),
+        channel,
+        recorded_at,
+        created_at: now.clone(),
+        updated_at: now,
+        state: IngestSessionState::Uploading,
+        video_path: None,
+        error: None,
     };
-    let _ = window.emit("embedding-progress", &processing_progress);
-    
-    // Python 스크립트 실행 (선택된 모든 채널을 한 번에 처리)
-    let cmd = Command::new(&venv_python)
-        .arg(&embed_script)
-        .arg("channels")  // 특정 채널 모드
-        .args(&channels)  // 선택된 채널들
-        .current_dir(&project_root)
-        .env("PYTHONUNBUFFERED", "1")
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .map_err(|e| format!("스크립트 실행 실패: {}", e))?;
-    
-    // 실시간 출력 처리를 위한 BufReader 설정
-    use std::io::{BufRead, BufReader};
-    use std::sync::mpsc;
-    use std::thread;
-    
-    let mut child = cmd;
+    match save_ingest_session(&mut session) {
+        Ok(()) => ingest_reply(StatusCode::CREATED, &session),
+        Err(e) => ingest_error(StatusCode::INTERNAL_SERVER_ERROR, &e),
+    }
+}
+
+// 청크 추가 (Upload-Offset이 현재 크기와 다르면 409와 함께 현재 위치를 돌려줘 클라이언트가 이어서 전송)
+fn append_ingest_chunk(vault_lock: &VaultLockState, jobs: &VaultJobState, id: &str, offset: u64, chunk: &[u8]) -> warp::reply::Response {
+    use warp::http::StatusCode;
+    static INGEST_WRITE_LOCK: Mutex<()> = Mutex::new(());
+    let _guard = INGEST_WRITE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
     
-    let stdout = child.stdout.take().unwrap();
-    let stderr = child.stderr.take().unwrap();
+    let Some(mut session) = load_ingest_session(id) else {
+        return ingest_error(StatusCode::NOT_FOUND, "업로드 세션을 찾을 수 없습니다");
+    };
+    if session.state != IngestSessionState::Uploading {
+        return ingest_reply(StatusCode::CONFLICT, &session);
+    }
+    let part_path = get_ingest_dir().join(id).join("upload.part");
+    session.offset = fs::metadata(&part_path).map(|m| m.len()).unwrap_or(0);
+    if offset != session.offset {
+        return ingest_reply(StatusCode::CONFLICT, &session);
+    }
+    if session.offset + chunk.len() as u64 > session.size {
+        return ingest_error(StatusCode::PAYLOAD_TOO_LARGE, "선언한 파일 크기를 넘었습니다");
+    }
     
-    // stdout 실시간 처리 스레드
-    let (tx, rx) = mpsc::channel();
-    let tx_clone = tx.clone();
+    let written = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&part_path)
+        .and_then(|mut file| file.write_all(chunk).and_then(|_| file.sync_data()));
+    if let Err(e) = written {
+        return ingest_error(StatusCode::INTERNAL_SERVER_ERROR, &format!("청크 저장 실패: {}", e));
+    }
+    session.offset += chunk.len() as u64;
     
-    thread::spawn(move || {
-        let reader = BufReader::new(stdout);
-        for line in reader.lines() {
-            if let Ok(line) = line {
-                let _ = tx.send(("stdout".to_string(), line));
+    let is_complete = session.offset == session.size;
+    if is_complete {
+        if vault_lock.read_only.load(Ordering::SeqCst) {
+            return ingest_error(StatusCode::SERVICE_UNAVAILABLE, "vault가 읽기 전용입니다");
+        }
+        session.state = IngestSessionState::Processing;
+    }
+    // 등록 스레드가 완료/실패를 기록하기 전에 처리 중 상태를 먼저 저장 (나중 저장이 결과를 덮어쓰지 않도록)
+    if let Err(e) = save_ingest_session(&mut session) {
+        return ingest_error(StatusCode::INTERNAL_SERVER_ERROR, &e);
+    }
+    if is_complete {
+        let mut finished = session.clone();
+        let finalizing = jobs.finalizing_ingests.clone();
+        finalizing.fetch_add(1, Ordering::SeqCst);
+        thread::spawn(move || {
+            match finalize_ingest(&finished) {
+                Ok(video_path) => {
+                    finished.state = IngestSessionState::Completed;
+                    finished.video_path = Some(video_path);
+                }
+                Err(e) => {
+                    finished.state = IngestSessionState::Failed;
+                    finished.error = Some(e);
+                }
+            }
+            if let Err(e) = save_ingest_session(&mut finished) {
+                eprintln!("⚠️ {}", e);
+            }
+            finalizing.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+    ingest_reply(StatusCode::OK, &session)
+}
+
+fn ingest_session_status(id: &str) -> warp::reply::Response {
+    use warp::http::StatusCode;
+    match load_ingest_session(id) {
+        Some(mut session) => {
+            if session.state == IngestSessionState::Uploading {
+                session.offset = fs::metadata(get_ingest_dir().join(id).join("upload.part")).map(|m| m.len()).unwrap_or(0);
             }
+            ingest_reply(StatusCode::OK, &session)
         }
-    });
+        None => ingest_error(StatusCode::NOT_FOUND, "업로드 세션을 찾을 수 없습니다"),
+    }
+}
+
+fn cancel_ingest_session(id: &str) -> warp::reply::Response {
+    use warp::http::StatusCode;
+    match load_ingest_session(id) {
+        Some(session) if session.state == IngestSessionState::Processing => {
+            ingest_error(StatusCode::CONFLICT, "이미 vault에 등록 중입니다")
+        }
+        Some(_) => {
+            let _ = fs::remove_dir_all(get_ingest_dir().join(id));
+            ingest_reply(StatusCode::OK, &serde_json::json!({ "cancelled": id }))
+        }
+        None => ingest_error(StatusCode::NOT_FOUND, "업로드 세션을 찾을 수 없습니다"),
+    }
+}
+
+// vault 표준 영상 폴더 경로 (채널/연도/YYYYMMDD_제목, 이미 있으면 _2, _3 … 접미사)
+fn unique_video_folder(channel: &str, compact_date: &str, title: &str) -> PathBuf {
+    let title_part: String = sanitize_vault_name(title).chars().take(MAX_VIDEO_FOLDER_TITLE_CHARS).collect();
+    let year_dir = channel_vault_dir(channel).join(&compact_date[..4]);
+    let mut folder = year_dir.join(format!("{}_{}", compact_date, title_part));
+    let mut suffix = 2;
+    while folder.exists() {
+        folder = year_dir.join(format!("{}_{}_{}", compact_date, title_part, suffix));
+        suffix += 1;
+    }
+    folder
+}
+
+// 받은 파일을 vault 표준 위치(채널/연도/YYYYMMDD_제목/video.mp4)에 등록하고 자막 생성 예약
+fn finalize_ingest(session: &IngestSession) -> Result<String, String> {
+    let project_root = get_project_root();
+    let staging = get_ingest_dir().join(&session.id);
+    let part_path = staging.join("upload.part");
+    
+    let compact = session
+        .recorded_at
+        .clone()
+        .unwrap_or_else(|| chrono::Local::now().format("%Y%m%d").to_string());
+    let date = format!("{}-{}-{}", &compact[..4], &compact[4..6], &compact[6..8]);
+    let folder = unique_video_folder(&session.channel, &compact, &session.title);
+    fs::create_dir_all(&folder).map_err(|e| format!("영상 폴더 생성 실패: {}", e))?;
+    
+    let video_file = folder.join("video.mp4");
+    let is_mp4 = Path::new(&session.filename)
+        .extension()
+        .map(|e| e.eq_ignore_ascii_case("mp4"))
+        .unwrap_or(false);
+    if is_mp4 {
+        fs::rename(&part_path, &video_file).map_err(|e| format!("영상 파일 이동 실패: {}", e))?;
+    } else {
+        // 컨테이너만 바꿔보고, 안 되면 재인코딩
+        let input = part_path.to_string_lossy().to_string();
+        let converted = run_throttled_ffmpeg(&["-i", &input, "-c", "copy", "-movflags", "+faststart"], &video_file)
+            || run_throttled_ffmpeg(&["-i", &input, "-c:v", "libx264", "-c:a", "aac", "-movflags", "+faststart"], &video_file);
+        if !converted {
+            let _ = fs::remove_dir_all(&folder);
+            return Err("mp4 변환 실패 (ffmpeg 확인 필요)".to_string());
+        }
+        let _ = fs::remove_file(&part_path);
+    }
     
-    // stderr 실시간 처리 스레드
-    thread::spawn(move || {
-        let reader = BufReader::new(stderr);
-        for line in reader.lines() {
-            if let Ok(line) = line {
-                let _ = tx_clone.send(("stderr".to_string(), line));
+    let captions_md = folder.join("captions.md");
+    let content = format!(
+        "---\ntitle: {}\nupload: {}\nchannel: {}\nvideo_id: {}\ntopic: []\nsource: upload\noriginal_filename: {}\ncreated_date: {}\n---\n\n\
+         ## 📹 비디오 정보\n\n- **제목**: {}\n- **채널**: {}\n- **업로드**: {}\n\n{}\n\n{}\n\n## 💭 노트\n\n*여기에 개인적인 생각이나 메모를 추가하세요.*\n",
+        yaml_scalar(&session.title),
+        date,
+        yaml_scalar(&session.channel),
+        session.id,
+        yaml_scalar(&session.filename),
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
+        session.title,
+        session.channel,
+        date,
+        TRANSCRIPT_SECTION,
+        NO_TRANSCRIPT_MARK,
+    );
+    fs::write(&captions_md, content).map_err(|e| format!("captions.md 저장 실패: {}", e))?;
+    generate_derivatives_for_folder(&folder);
+    reconcile_vault_index()?;
+    
+    let settings: IngestSettings = load_json_config(INGEST_SETTINGS_FILE).unwrap_or_default();
+    if settings.transcribe {
+        transcribe_ingested_video(&captions_md);
+    }
+    Ok(video_file.strip_prefix(&project_root).unwrap_or(&video_file).to_string_lossy().to_string())
+}
+
+// 업로드된 영상 자막 생성 (retranscribe.py --captions-files, 실패해도 등록은 유지)
+fn transcribe_ingested_video(captions_md: &Path) {
+    let project_root = get_project_root();
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    let script = project_root.join("vault").join("90_indices").join("retranscribe.py");
+    if !venv_python.exists() || !script.exists() {
+        eprintln!("⚠️ 업로드 영상 전사 건너뜀: Python 환경 또는 재전사 스크립트 없음");
+        return;
+    }
+    let mut cmd = low_priority_command(&venv_python);
+    cmd.arg(&script)
+        .arg("--captions-files")
+        .arg(captions_md)
+        .current_dir(&project_root)
+        .env("PYTHONUNBUFFERED", "1")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    apply_thread_limits(&mut cmd);
+    match cmd.status() {
+        Ok(status) if status.success() => {
+            if let Err(e) = reconcile_vault_index() {
+                eprintln!("⚠️ {}", e);
             }
         }
-    });
+        Ok(status) => eprintln!("⚠️ 업로드 영상 전사 실패: {}", status),
+        Err(e) => eprintln!("⚠️ 업로드 영상 전사 실행 실패: {}", e),
+    }
+}
+
+// 업로드 서버 (재)시작, 비활성화 상태면 중지만 함
+async fn restart_ingest_server(state: &IngestServerState, vault_lock: VaultLockState, jobs: VaultJobState) -> Result<Option<u16>, String> {
+    if let Some(handle) = state.handle.write().await.take() {
+        handle.abort();
+    }
+    *state.port.write().await = None;
     
-    // 실시간 로그 처리 루프
-    let mut process_complete = false;
-    while !process_complete {
-        // 중단 확인
-        if state.is_cancelled.load(Ordering::Relaxed) {
-            let _ = child.kill();
-            let _ = child.wait();
-            
-            let cancel_progress = DownloadProgress {
-                channel: format!("벡터 임베딩 ({} 채널)", total_channels),
-                status: "중단됨".to_string(),
-                progress: 50.0,
-                current_video: "사용자가 중단했습니다".to_string(),
-                total_videos: total_channels,
-                completed_videos: 0,
-                log_message: "🛑 사용자가 임베딩 생성을 중단했습니다".to_string(),
-            };
-            let _ = window.emit("embedding-progress", &cancel_progress);
-            return Ok(format!("임베딩 생성이 중단되었습니다."));
-        }
-        
-        // 출력 받기 (타임아웃 설정)
-        match rx.recv_timeout(std::time::Duration::from_millis(100)) {
-            Ok((stream_type, line)) => {
-                if !line.trim().is_empty() {
-                    let log_progress = DownloadProgress {
-                        channel: format!("벡터 임베딩 ({} 채널)", total_channels),
-                        status: "처리 중".to_string(),
-                        progress: 70.0,
-                        current_video: "📺 임베딩 생성 중".to_string(),
-                        total_videos: total_channels,
-                        completed_videos: 0,
-                        log_message: if stream_type == "stderr" { 
-                            format!("⚠️ {}", line) 
-                        } else { 
-                            line.clone() 
-                        },
-                    };
-                    let _ = window.emit("embedding-progress", &log_progress);
-                    all_output.push(line);
-                }
-            }
-            Err(mpsc::RecvTimeoutError::Timeout) => {
-                // 프로세스가 완료되었는지 확인
-                match child.try_wait() {
-                    Ok(Some(status)) => {
-                        process_complete = true;
-                        if !status.success() {
-                            let error_progress = DownloadProgress {
-                                channel: format!("벡터 임베딩 ({} 채널)", total_channels),
-                                status: "실패".to_string(),
-                                progress: 0.0,
-                                current_video: "❌ 임베딩 생성 실패".to_string(),
-                                total_videos: total_channels,
-                                completed_videos: 0,
-                                log_message: "❌ Python 스크립트 실행 실패".to_string(),
-                            };
-                            let _ = window.emit("embedding-progress", &error_progress);
-                            return Err("임베딩 생성 실패".to_string());
-                        }
-                    }
-                    Ok(None) => {
-                        // 아직 실행 중
-                        continue;
-                    }
-                    Err(e) => {
-                        return Err(format!("프로세스 상태 확인 실패: {}", e));
-                    }
-                }
+    let settings: IngestSettings = load_json_config(INGEST_SETTINGS_FILE)?;
+    if !settings.enabled {
+        return Ok(None);
+    }
+    if settings.token.is_empty() {
+        return Err("업로드 토큰이 없습니다. 토큰을 다시 발급하세요".to_string());
+    }
+    
+    let auth = || {
+        warp::header::optional::<String>("authorization").and_then(|authorization: Option<String>| async move {
+            let settings: IngestSettings = load_json_config(INGEST_SETTINGS_FILE).unwrap_or_default();
+            if ingest_authorized(&settings, authorization.as_deref()) {
+                Ok(())
+            } else {
+                Err(warp::reject::custom(IngestUnauthorized))
             }
-            Err(mpsc::RecvTimeoutError::Disconnected) => {
-                // 스레드가 종료됨, 프로세스 완료 확인
-                let _ = child.wait();
-                process_complete = true;
+        })
+        .untuple_one()
+    };
+    let create_lock = vault_lock.clone();
+    let create = warp::path!("api" / "ingest")
+        .and(warp::post())
+        .and(auth())
+        .and(warp::body::content_length_limit(64 * 1024))
+        .and(warp::body::json())
+        .map(move |request: IngestCreateRequest| create_ingest_session(&create_lock, request));
+    let append = warp::path!("api" / "ingest" / String)
+        .and(warp::patch())
+        .and(auth())
+        .and(warp::header::<u64>("upload-offset"))
+        .and(warp::body::content_length_limit(INGEST_CHUNK_MAX_BYTES))
+        .and(warp::body::bytes())
+        .and_then(move |id: String, offset: u64, chunk: warp::hyper::body::Bytes| {
+            let vault_lock = vault_lock.clone();
+            let jobs = jobs.clone();
+            async move {
+                // 청크 기록/fsync와 전역 잠금 대기가 서버 작업 스레드를 막지 않도록 블로킹 스레드에서 실행
+                let reply = tokio::task::spawn_blocking(move || append_ingest_chunk(&vault_lock, &jobs, &id, offset, &chunk))
+                    .await
+                    .unwrap_or_else(|e| {
+                        ingest_error(warp::http::StatusCode::INTERNAL_SERVER_ERROR, &format!("청크 저장 실패: {}", e))
+                    });
+                Ok::<_, warp::Rejection>(reply)
             }
-        }
+        });
+    let status = warp::path!("api" / "ingest" / String)
+        .and(warp::get())
+        .and(auth())
+        .map(|id: String| ingest_session_status(&id));
+    let cancel = warp::path!("api" / "ingest" / String)
+        .and(warp::delete())
+        .and(auth())
+        .map(|id: String| cancel_ingest_session(&id));
+    let routes = create
+        .or(append)
+        .or(status)
+        .or(cancel)
+        .recover(|rejection: warp::Rejection| async move {
+            Ok::<_, std::convert::Infallible>(ingest_rejection_reply(&rejection))
+        });
+    
+    let addr = SocketAddr::from(([0, 0, 0, 0], settings.port));
+    let (_, server) = warp::serve(routes)
+        .try_bind_ephemeral(addr)
+        .map_err(|e| format!("업로드 서버 시작 실패 (포트 {}): {}", settings.port, e))?;
+    *state.handle.write().await = Some(tokio::spawn(server));
+    *state.port.write().await = Some(settings.port);
+    Ok(Some(settings.port))
+}
+
+#[command]
+fn get_ingest_settings() -> Result<IngestSettings, String> {
+    load_json_config(INGEST_SETTINGS_FILE)
+}
+
+// 설정 저장 후 서버 재시작 (처음 활성화하면 토큰 자동 발급), 접속 주소 반환
+#[command]
+async fn save_ingest_settings(
+    state: State<'_, IngestServerState>,
+    jobs: State<'_, VaultJobState>,
+    vault_lock: State<'_, VaultLockState>,
+    mut settings: IngestSettings,
+) -> Result<Option<String>, String> {
+    if settings.enabled && settings.token.is_empty() {
+        settings.token = new_token();
+    }
+    save_json_config(INGEST_SETTINGS_FILE, &settings)?;
+    let port = restart_ingest_server(&state, vault_lock.inner().clone(), jobs.inner().clone()).await?;
+    Ok(port.map(|port| format!("http://{}:{}/api/ingest", lan_ip_address(), port)))
+}
+
+// 토큰 재발급 (기존 토큰으로 진행 중인 업로드는 새 토큰으로 이어서 전송)
+#[command]
+fn regenerate_ingest_token() -> Result<IngestSettings, String> {
+    let mut settings: IngestSettings = load_json_config(INGEST_SETTINGS_FILE)?;
+    settings.token = new_token();
+    save_json_config(INGEST_SETTINGS_FILE, &settings)?;
+    Ok(settings)
+}
+
+#[command]
+fn list_ingest_sessions() -> Result<Vec<IngestSession>, String> {
+    let Ok(entries) = fs::read_dir(get_ingest_dir()) else {
+        return Ok(Vec::new());
+    };
+    let mut sessions: Vec<IngestSession> = entries
+        .flatten()
+        .filter_map(|e| load_ingest_session(&e.file_name().to_string_lossy()))
+        .collect();
+    sessions.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(sessions)
+}
+
+// 시스템 플레이어로 비디오 열기
+#[command]
+async fn open_in_system_player(video_path: String) -> Result<(), String> {
+    let project_root = get_project_root();
+    let full_path = project_root.join(&video_path);
+    
+    if !full_path.exists() {
+        return Err(format!("비디오 파일을 찾을 수 없습니다: {}", full_path.display()));
     }
     
-    // 현재 프로세스 정리
+    // 운영체제별 명령어 실행
+    #[cfg(target_os = "macos")]
     {
-        let mut process_guard = state.current_process.lock().unwrap();
-        *process_guard = None;
+        Command::new("open")
+            .arg(&full_path)
+            .spawn()
+            .map_err(|e| format!("macOS 시스템 플레이어 실행 실패: {}", e))?;
     }
     
-    if state.is_cancelled.load(Ordering::Relaxed) {
-        return Ok(format!("임베딩 생성이 중단되었습니다. {}개 채널 완료", total_channels));
+    #[cfg(target_os = "windows")]
+    {
+        Command::new("cmd")
+            .args(&["/C", "start", "", &full_path.to_string_lossy()])
+            .spawn()
+            .map_err(|e| format!("Windows 시스템 플레이어 실행 실패: {}", e))?;
+    }
+    
+    #[cfg(target_os = "linux")]
+    {
+        Command::new("xdg-open")
+            .arg(&full_path)
+            .spawn()
+            .map_err(|e| format!("Linux 시스템 플레이어 실행 실패: {}", e))?;
     }
     
-    // 최종 완료
-    let final_progress = DownloadProgress {
-        channel: format!("벡터 임베딩 ({} 채널)", total_channels),
-        status: "완료".to_string(),
-        progress: 100.0,
-        current_video: "모든 채널 임베딩 완료".to_string(),
-        total_videos: total_channels,
-        completed_videos: total_channels,
-        log_message: format!("🎉 {}개 채널의 벡터 임베딩 생성이 완료되었습니다!", total_channels),
-    };
-    let _ = window.emit("embedding-progress", &final_progress);
-    
-    Ok(format!("✅ {}개 채널의 벡터 임베딩 생성 완료\n{}", total_channels, all_output.join("\n")))
+    println!("🎬 시스템 플레이어로 비디오 열기: {}", full_path.display());
+    Ok(())
 }
 
-// 임베딩 생성 중단
+// 비디오 변환 관련 함수들
+
 #[command]
-async fn cancel_embedding(state: State<'_, EmbeddingState>) -> Result<(), String> {
-    state.is_cancelled.store(true, Ordering::Relaxed);
-    
-    // 실행 중인 프로세스는 메인 루프에서 처리됨
-    // 여기서는 중단 플래그만 설정
-    
-    Ok(())
+async fn convert_video_file(
+    window: Window,
+    vault_lock: State<'_, VaultLockState>,
+    video_path: String,
+    quality: String,
+    codec: String,
+    backup: bool,
+    state: State<'_, ConversionState>
+) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let result = run_video_conversion(window.clone(), video_path, quality, codec, backup, state).await;
+    notify_job_finished(&window, NotifiedJob::Conversion, &result);
+    result
 }
 
-// 벡터 임베딩 생성 (진행 상황 포함) - 기존 호환성 유지
-#[command]
-async fn create_embeddings_with_progress(window: Window) -> Result<String, String> {
+async fn run_video_conversion(
+    window: Window,
+    video_path: String, 
+    quality: String,
+    codec: String,
+    backup: bool,
+    state: State<'_, ConversionState>
+) -> Result<String, String> {
+    // 이미 변환 중인지 확인
+    if state.is_converting.load(Ordering::Relaxed) {
+        return Err("이미 변환이 진행 중입니다".to_string());
+    }
+    ensure_disk_space(&window)?;
+    
     let project_root = get_project_root();
-    let embed_script = project_root.join("vault").join("90_indices").join("embed.py");
-    if !embed_script.exists() {
-        return Err(format!("embed.py 스크립트를 찾을 수 없습니다: {}", embed_script.display()));
+    let video_full_path = project_root.join(&video_path);
+    
+    if !video_full_path.exists() {
+        return Err(format!("비디오 파일을 찾을 수 없습니다: {}", video_full_path.display()));
     }
     
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    if !venv_python.exists() {
-        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    // 수집된 기술 정보가 있으면 파일을 다시 읽지 않고 AV1 여부 판단
+    let indexed_codec = open_index_db()
+        .ok()
+        .and_then(|conn| cached_media_probe(&conn, &video_path))
+        .and_then(|probe| probe.video_codec);
+    if let Some(current) = indexed_codec.as_deref().filter(|c| !c.contains("av1") && !c.contains("av01")) {
+        return Ok(format!("AV1 코덱이 아니므로 변환을 건너뜁니다 (현재 코덱: {})", current));
     }
     
-    // 시작 진행 상황
-    let start_progress = DownloadProgress {
-        channel: "벡터 임베딩".to_string(),
-        status: "시작".to_string(),
-        progress: 0.0,
-        current_video: "임베딩 생성 준비 중...".to_string(),
-        total_videos: 1,
-        completed_videos: 0,
-        log_message: "🧠 벡터 임베딩 생성을 시작합니다...".to_string(),
+    // 변환 시작
+    state.is_converting.store(true, Ordering::Relaxed);
+    
+    // Python 가상환경 경로 찾기
+    let venv_path = project_root.join("venv");
+    let python_path = if venv_path.exists() {
+        #[cfg(target_os = "windows")]
+        {
+            venv_path.join("Scripts").join("python.exe")
+        }
+        #[cfg(not(target_os = "windows"))]
+        {
+            venv_path.join("bin").join("python")
+        }
+    } else {
+        PathBuf::from("python")
     };
-    let _ = window.emit("embedding-progress", &start_progress);
     
-    // Python 스크립트 실행
-    let output = Command::new(&venv_python)
-        .arg(&embed_script)
-        .current_dir(&project_root)
-        .env("PYTHONUNBUFFERED", "1")
-        .output()
-        .map_err(|e| e.to_string())?;
+    // ydh convert-single 명령어 구성
+    let mut cmd = low_priority_command(&python_path);
+    cmd.arg("-m")
+       .arg("ydh")
+       .arg("convert-single")
+       .arg(&video_full_path)
+       .arg("--quality")
+       .arg(&quality)
+       .arg("--codec")
+       .arg(&codec);
     
-    if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let final_progress = DownloadProgress {
-            channel: "벡터 임베딩".to_string(),
-            status: "완료".to_string(),
-            progress: 100.0,
-            current_video: "임베딩 생성 완료".to_string(),
-            total_videos: 1,
-            completed_videos: 1,
-            log_message: "✅ 벡터 임베딩 생성 완료!".to_string(),
-        };
-        let _ = window.emit("embedding-progress", &final_progress);
-        Ok(format!("✅ 벡터 임베딩 생성 완료\n{}", stdout))
+    if backup {
+        cmd.arg("--backup");
     } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        let error_progress = DownloadProgress {
-            channel: "벡터 임베딩".to_string(),
-            status: "실패".to_string(),
-            progress: 0.0,
-            current_video: "임베딩 생성 실패".to_string(),
-            total_videos: 1,
-            completed_videos: 0,
-            log_message: format!("❌ 벡터 임베딩 생성 실패: {}", stderr),
-        };
-        let _ = window.emit("embedding-progress", &error_progress);
-        Err(format!("벡터 임베딩 생성 실패: {}", stderr))
+        cmd.arg("--no-backup");
     }
-}
-
-// 기존 벡터 임베딩 함수 (호환성 유지)
-#[command]
-async fn create_embeddings() -> Result<String, String> {
-    let project_root = get_project_root();
-    let embed_script = project_root.join("vault").join("90_indices").join("embed.py");
-    if !embed_script.exists() {
-        return Err(format!("embed.py 스크립트를 찾을 수 없습니다: {}", embed_script.display()));
+    if indexed_codec.is_some() {
+        cmd.arg("--force"); // 코덱은 이미 확인함
     }
     
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    if !venv_python.exists() {
-        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
-    }
+    cmd.current_dir(&project_root)
+       .stdout(Stdio::piped())
+       .stderr(Stdio::piped());
+    apply_thread_limits(&mut cmd);
     
-    let output = Command::new(&venv_python)
-        .arg(&embed_script)
-        .current_dir(&project_root)
-        .output()
-        .map_err(|e| e.to_string())?;
+    // 명령어 실행
+    let child = cmd.spawn().map_err(|e| {
+        state.is_converting.store(false, Ordering::Relaxed);
+        format!("Python 프로세스 시작 실패: {}", e)
+    })?;
     
-    if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        Ok(format!("✅ 벡터 임베딩 생성 완료\n{}", stdout))
-    } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(format!("벡터 임베딩 생성 실패: {}", stderr))
-    }
-}
-
-// 벡터 검색
-#[command]
-async fn vector_search(query: String) -> Result<String, String> {
-    let project_root = get_project_root();
-    let embed_script = project_root.join("vault").join("90_indices").join("embed.py");
-    if !embed_script.exists() {
-        return Err(format!("embed.py 스크립트를 찾을 수 없습니다: {}", embed_script.display()));
+    // 프로세스 저장
+    {
+        let mut process_guard = state.current_process.lock().unwrap();
+        *process_guard = Some(child);
     }
     
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    let output = Command::new(&venv_python)
-        .args(&[embed_script.to_str().unwrap(), "search", &query])
-        .current_dir(&project_root)
-        .output()
-        .map_err(|e| e.to_string())?;
+    // 별도 스레드에서 출력 모니터링
+    let window_clone = window.clone();
+    let state_clone = state.inner().clone();
+    let video_path_clone = video_path.clone();
     
-    if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
-    } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(format!("검색 실패: {}", stderr))
-    }
+    tokio::spawn(async move {
+        let mut child = {
+            let mut process_guard = state_clone.current_process.lock().unwrap();
+            process_guard.take()
+        }.unwrap();
+        
+        // stderr에서 출력 읽기 (FFmpeg 출력)
+        if let Some(stderr) = child.stderr.take() {
+            let reader = BufReader::new(stderr);
+            
+            for line in reader.lines() {
+                if let Ok(line) = line {
+                    // 변환 진행 상황 파싱
+                    let progress = parse_conversion_progress(&line);
+                    
+                    let conversion_progress = DownloadProgress {
+                        channel: "변환".to_string(),
+                        status: "변환 중".to_string(),
+                        progress,
+                        current_video: video_path_clone.clone(),
+                        total_videos: 1,
+                        completed_videos: 0,
+                        log_message: line,
+                        throughput_bps: None,
+                    };
+                    
+                    let _ = window_clone.emit("conversion-progress", &conversion_progress);
+                }
+                
+                // 변환 중단 확인
+                if state_clone.is_converting.load(Ordering::Relaxed) == false {
+                    let _ = child.kill();
+                    break;
+                }
+            }
+        }
+        
+        // 프로세스 완료 대기
+        let result = child.wait();
+        
+        let final_progress = match result {
+            Ok(status) if status.success() => {
+                DownloadProgress {
+                    channel: "변환".to_string(),
+                    status: "완료".to_string(),
+                    progress: 100.0,
+                    current_video: video_path_clone.clone(),
+                    total_videos: 1,
+                    completed_videos: 1,
+                    log_message: "✅ 비디오 변환 완료!".to_string(),
+                    throughput_bps: None,
+                }
+            },
+            _ => {
+                DownloadProgress {
+                    channel: "변환".to_string(),
+                    status: "실패".to_string(),
+                    progress: 0.0,
+                    current_video: video_path_clone.clone(),
+                    total_videos: 1,
+                    completed_videos: 0,
+                    log_message: "❌ 비디오 변환 실패".to_string(),
+                    throughput_bps: None,
+                }
+            }
+        };
+        
+        let _ = window_clone.emit("conversion-progress", &final_progress);
+        if final_progress.completed_videos == 1 {
+            if let Err(e) = reindex_video(&video_path_clone) {
+                eprintln!("⚠️ 변환 후 인덱스 갱신 실패: {}", e);
+            }
+        }
+        
+        // 변환 상태 초기화
+        state_clone.is_converting.store(false, Ordering::Relaxed);
+    });
+    
+    Ok("비디오 변환이 시작되었습니다".to_string())
 }
 
-// RAG 질문-답변
 #[command]
-async fn ask_rag(query: String) -> Result<String, String> {
-    let project_root = get_project_root();
-    let rag_script = project_root.join("vault").join("90_indices").join("rag.py");
-    if !rag_script.exists() {
-        return Err(format!("rag.py 스크립트를 찾을 수 없습니다: {}", rag_script.display()));
-    }
-    
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    let output = Command::new(&venv_python)
-        .args(&[rag_script.to_str().unwrap(), &query])
-        .current_dir(&project_root)
-        .output()
-        .map_err(|e| e.to_string())?;
+async fn cancel_conversion(state: State<'_, ConversionState>) -> Result<(), String> {
+    state.is_converting.store(false, Ordering::Relaxed);
     
-    if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
-    } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(format!("RAG 질문 실패: {}", stderr))
+    if let Ok(mut process_guard) = state.current_process.lock() {
+        if let Some(mut child) = process_guard.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
     }
+    
+    Ok(())
 }
 
-
-
-// 채널별 AI 질문 (DeepSeek, 실시간 진행 상황 포함)
 #[command]
-async fn ask_ai_with_progress(
-    window: Window, 
-    query: String, 
-    channel_name: String, 
-    model: String,
-    rag_settings: Option<RAGSettings>
-) -> Result<String, String> {
-    let project_root = get_project_root();
-    let rag_script = project_root.join("vault").join("90_indices").join("rag.py");
-    
-    if !rag_script.exists() {
-        return Err("RAG 스크립트를 찾을 수 없습니다".to_string());
-    }
-
-    // 초기 진행 상황 전송
-    let _ = window.emit("ai-progress", AIProgressUpdate {
-        step: "초기화".to_string(),
-        message: "🔍 검색 준비 중...".to_string(),
-        progress: 0.0,
-        details: Some(format!("채널: {} | 모델: {}", channel_name, model)),
-    });
+async fn get_conversion_status(state: State<'_, ConversionState>) -> Result<bool, String> {
+    Ok(state.is_converting.load(Ordering::Relaxed))
+}
 
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    
-    // RAG 설정을 JSON으로 직렬화
-    let settings_json = match rag_settings {
-        Some(settings) => serde_json::to_string(&settings).unwrap_or_default(),
-        None => String::new()
-    };
-    
-    let mut cmd_args = vec![
-        rag_script.to_str().unwrap(),
-        &query,
-        &channel_name,
-        "--progress",
-        "--model",
-        &model
-    ];
+// FFmpeg 출력에서 변환 진행률 파싱
+fn parse_conversion_progress(line: &str) -> f32 {
+    // FFmpeg 시간 출력 파싱: time=00:01:23.45
+    if let Some(captures) = Regex::new(r"time=(\d{2}):(\d{2}):(\d{2})\.(\d+)").unwrap().captures(line) {
+        if let (Some(hours), Some(minutes), Some(seconds)) = 
+            (captures.get(1), captures.get(2), captures.get(3)) {
+            if let (Ok(h), Ok(m), Ok(s)) = 
+                (hours.as_str().parse::<f32>(), minutes.as_str().parse::<f32>(), seconds.as_str().parse::<f32>()) {
+                let total_seconds = h * 3600.0 + m * 60.0 + s;
+                // 예상 총 시간을 모르므로 임시로 무한 진행률 대신 시간만 반환
+                // 실제로는 비디오 길이를 알아야 정확한 퍼센트 계산 가능
+                return (total_seconds / 10.0).min(95.0); // 임시 계산
+            }
+        }
+    }
     
-    // RAG 설정이 있으면 추가
-    if !settings_json.is_empty() {
-        cmd_args.push("--rag-settings");
-        cmd_args.push(&settings_json);
+    // FFmpeg 프레임 출력: frame= 1234
+    if let Some(captures) = Regex::new(r"frame=\s*(\d+)").unwrap().captures(line) {
+        if let Some(frame_match) = captures.get(1) {
+            if let Ok(frame) = frame_match.as_str().parse::<f32>() {
+                return (frame / 100.0).min(95.0); // 임시 계산
+            }
+        }
     }
     
-    let mut child = Command::new(&venv_python)
-        .args(&cmd_args)
-        .current_dir(&project_root)
-        .env("PYTHONUNBUFFERED", "1")
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .map_err(|e| e.to_string())?;
+    -1.0 // 진행률을 파싱할 수 없는 경우
+}
 
-    let stdout = child.stdout.take().unwrap();
-    let reader = BufReader::new(stdout);
-    let mut result = String::new();
-    let mut is_final_answer = false;
-    let mut all_output = String::new(); // 전체 출력 수집 (fallback용)
+// 파생 파일 (썸네일/파형/미리보기) 생성 - 배치 다운로드 후 저우선순위로 실행
+const DERIVATIVE_FILES: [&str; 3] = ["thumbnail.jpg", "waveform.png", "preview.mp4"];
+// 영상 사이 대기 시간 (CPU 점유 완화)
+const DERIVATIVE_PAUSE: Duration = Duration::from_secs(2);
 
-    // 실시간 출력 처리
-    for line in reader.lines() {
-        let line = line.map_err(|e| e.to_string())?;
-        
-        // 모든 출력을 수집 (fallback용)
-        if !all_output.is_empty() {
-            all_output.push('\n');
-        }
-        all_output.push_str(&line);
-        
-        // 진행 상황 파싱
-        if line.starts_with("PROGRESS:") {
-            if let Some(progress_json) = line.strip_prefix("PROGRESS:") {
-                if let Ok(progress_data) = serde_json::from_str::<AIProgressUpdate>(progress_json) {
-                    let _ = window.emit("ai-progress", progress_data);
-                }
-            }
-        }
-        // 최종 답변 시작 표시
-        else if line.starts_with("FINAL_ANSWER:") {
-            is_final_answer = true;
-            let _ = window.emit("ai-progress", AIProgressUpdate {
-                step: "완료".to_string(),
-                message: "✅ 답변 생성 완료".to_string(),
-                progress: 100.0,
-                details: None,
-            });
-            
-            // FINAL_ANSWER: 라인에 이미 답변이 포함된 경우 처리
-            if let Some(answer_content) = line.strip_prefix("FINAL_ANSWER:") {
-                let trimmed = answer_content.trim();
-                if !trimmed.is_empty() {
-                    result.push_str(trimmed);
+// 영상 파일이 있고 파생 파일 중 하나라도 없는 폴더
+fn folder_needs_derivatives(folder: &Path) -> bool {
+    folder.join("video.mp4").exists() && DERIVATIVE_FILES.iter().any(|f| !folder.join(f).exists())
+}
+
+// 파생 파일이 하나라도 없는 영상 폴더 수집
+fn collect_folders_missing_derivatives(dir: &Path, folders: &mut Vec<PathBuf>) {
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                collect_folders_missing_derivatives(&path, folders);
+            } else if path.file_name().map(|n| n == "video.mp4").unwrap_or(false) {
+                if let Some(folder) = path.parent().filter(|f| folder_needs_derivatives(f)) {
+                    folders.push(folder.to_path_buf());
                 }
             }
         }
-        // 최종 답변 수집
-        else if is_final_answer {
-            if !result.is_empty() {
-                result.push('\n');
-            }
-            result.push_str(&line);
-        }
-        // PROGRESS 마커 없이 JSON이 바로 출력되는 경우 감지
-        else if line.trim().starts_with("{") && line.contains("\"answer\"") {
-            // JSON 응답으로 보이는 경우 수집 시작
-            if !result.is_empty() {
-                result.push('\n');
-            }
-            result.push_str(&line);
-            is_final_answer = true; // 이후 라인들도 수집
-        }
     }
+}
 
-    let status = child.wait().map_err(|e| e.to_string())?;
-    
-    if status.success() {
-        // 최적 응답 결정 로직
-        let final_result = if !result.is_empty() {
-            // FINAL_ANSWER 마커로 수집된 결과 우선 사용
-            result
-        } else if !all_output.is_empty() {
-            // 전체 출력에서 JSON 부분 추출 시도
-            if let Some(json_start) = all_output.find('{') {
-                if let Some(json_end) = all_output.rfind('}') {
-                    if json_end > json_start {
-                        // JSON 부분만 추출
-                        all_output[json_start..=json_end].to_string()
-                    } else {
-                        all_output
-                    }
-                } else {
-                    all_output
-                }
-            } else {
-                all_output
-            }
-        } else {
-            // fallback: 기본 방식으로 재실행
-            let output = Command::new(&venv_python)
-                .args(&[rag_script.to_str().unwrap(), &query, &channel_name, "--model", &model])
-                .current_dir(&project_root)
-                .env("PYTHONUNBUFFERED", "1")
-                .output()
-                .map_err(|e| e.to_string())?;
-            
-            if output.status.success() {
-                String::from_utf8_lossy(&output.stdout).to_string()
-            } else {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                return Err(format!("DeepSeek RAG 질문 실패: {}", stderr));
-            }
-        };
-        
-        // 최종 결과 정리 (불필요한 PROGRESS 라인 제거)
-        let cleaned_result = final_result
-            .lines()
-            .filter(|line| !line.starts_with("PROGRESS:") && !line.starts_with("FINAL_ANSWER:"))
-            .collect::<Vec<&str>>()
-            .join("\n")
-            .trim()
-            .to_string();
-        
-        Ok(if cleaned_result.is_empty() { final_result } else { cleaned_result })
-    } else {
-        // 에러 발생 시 상세 에러 메시지 제공
-        let error_message = if all_output.is_empty() {
-            "Python 스크립트 실행 중 오류가 발생했습니다"
-        } else {
-            // 출력이 있는 경우 마지막 몇 줄을 에러 정보로 활용
-            let error_lines: Vec<&str> = all_output
-                .lines()
-                .filter(|line| line.contains("Error") || line.contains("Exception") || line.contains("Traceback"))
-                .collect();
-            
-            if !error_lines.is_empty() {
-                &error_lines.join("; ")
-            } else {
-                "Python 스크립트가 비정상적으로 종료되었습니다"
-            }
-        };
-        
-        Err(format!("DeepSeek RAG 질문 실패: {}", error_message))
+// 무거운 하위 프로세스(ffmpeg/whisper/변환) 자원 제한 (config/resource_limits.json)
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+struct ResourceLimitSettings {
+    full_speed: bool,         // 제한 없이 최대 속도로 실행
+    nice: i32,                // CPU 우선순위 (0~19, 클수록 양보)
+    idle_io: bool,            // 디스크 IO 우선순위 낮춤 (Linux: ionice idle, macOS: taskpolicy throttle)
+    conversion_threads: u32,  // ffmpeg 변환/미리보기 스레드 수 (0이면 제한 없음)
+    whisper_threads: u32,     // Whisper 전사 CPU 스레드 수 (0이면 자동)
+}
+
+impl Default for ResourceLimitSettings {
+    fn default() -> Self {
+        ResourceLimitSettings { full_speed: false, nice: 19, idle_io: true, conversion_threads: 1, whisper_threads: 0 }
     }
 }
 
+const RESOURCE_LIMITS_FILE: &str = "resource_limits.json";
 
+fn load_resource_limits() -> ResourceLimitSettings {
+    load_json_config(RESOURCE_LIMITS_FILE).unwrap_or_default()
+}
 
-// AI 질문 (실시간 진행 상황 포함)
 #[command]
-async fn ask_ai_universal_with_progress(
-    window: Window, 
-    query: String, 
-    channel_name: String, 
-    model: String,
-    rag_settings: Option<RAGSettings>
-) -> Result<String, String> {
-    ask_ai_with_progress(window, query, channel_name, model, rag_settings).await
+fn get_resource_limits() -> Result<ResourceLimitSettings, String> {
+    load_json_config(RESOURCE_LIMITS_FILE)
+}
+
+#[command]
+fn save_resource_limits(settings: ResourceLimitSettings) -> Result<(), String> {
+    if !(0..=19).contains(&settings.nice) {
+        return Err("nice 값은 0~19 사이여야 합니다".to_string());
+    }
+    save_json_config(RESOURCE_LIMITS_FILE, &settings)
 }
 
-#[derive(Serialize, Deserialize)]
-struct AIChannelInfo {
-    name: String,
-    video_count: u32,
-    description: Option<String>,
-    last_updated: Option<String>,
+// 자원 제한을 적용한 프로세스 명령 (unix: nice + IO 우선순위, full_speed면 그대로 실행)
+fn low_priority_command<S: AsRef<std::ffi::OsStr>>(program: S) -> Command {
+    let limits = load_resource_limits();
+    if limits.full_speed {
+        return Command::new(program);
+    }
+    #[cfg(unix)]
+    {
+        let mut cmd = if !limits.idle_io {
+            Command::new("nice")
+        } else if cfg!(target_os = "macos") {
+            let mut cmd = Command::new("taskpolicy");
+            cmd.args(["-d", "throttle", "nice"]);
+            cmd
+        } else {
+            let mut cmd = Command::new("ionice");
+            cmd.args(["-c", "3", "nice"]);
+            cmd
+        };
+        cmd.args(["-n", &limits.nice.to_string()]).arg(program);
+        cmd
+    }
+    #[cfg(not(unix))]
+    {
+        Command::new(program)
+    }
 }
 
+// Python 하위 작업에 스레드 제한 전달 (ydh convert: YDH_FFMPEG_THREADS, retranscribe.py: YDH_WHISPER_THREADS)
+fn apply_thread_limits(cmd: &mut Command) {
+    let limits = load_resource_limits();
+    if limits.full_speed {
+        return;
+    }
+    if limits.conversion_threads > 0 {
+        cmd.env("YDH_FFMPEG_THREADS", limits.conversion_threads.to_string());
+    }
+    if limits.whisper_threads > 0 {
+        cmd.env("YDH_WHISPER_THREADS", limits.whisper_threads.to_string())
+            .env("OMP_NUM_THREADS", limits.whisper_threads.to_string());
+    }
+}
 
+// ffmpeg 저우선순위 실행 후 출력 파일 생성 여부 반환
+fn run_throttled_ffmpeg(args: &[&str], output: &Path) -> bool {
+    let limits = load_resource_limits();
+    let mut cmd = low_priority_command("ffmpeg");
+    cmd.args(["-y", "-hide_banner", "-loglevel", "error"]);
+    if !limits.full_speed && limits.conversion_threads > 0 {
+        cmd.args(["-threads", &limits.conversion_threads.to_string()]);
+    }
+    let status = cmd
+        .args(args)
+        .arg(output)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+    
+    let created = output.metadata().map(|m| m.len() > 0).unwrap_or(false);
+    if !created {
+        let _ = fs::remove_file(output);
+    }
+    status.map(|s| s.success()).unwrap_or(false) && created
+}
 
-// AI용 채널 목록 조회
-#[command]
-async fn get_available_channels_for_ai() -> Result<Vec<AIChannelInfo>, String> {
-    let project_root = get_project_root();
-    let rag_script = project_root.join("vault").join("90_indices").join("rag.py");
-    
-    if !rag_script.exists() {
-        return Ok(vec![]);
+// 영상 하나의 누락된 파생 파일 생성, 생성된 파일 수 반환
+fn generate_derivatives_for_folder(folder: &Path) -> u32 {
+    let video = folder.join("video.mp4");
+    let video_str = video.to_string_lossy().to_string();
+    let mut created = 0;
+    
+    let thumbnail = folder.join("thumbnail.jpg");
+    if !thumbnail.exists() {
+        // 짧은 영상은 5초 지점 프레임이 없으므로 처음 프레임으로 재시도
+        let ok = run_throttled_ffmpeg(&["-ss", "5", "-i", &video_str, "-frames:v", "1", "-vf", "scale=480:-2"], &thumbnail)
+            || run_throttled_ffmpeg(&["-i", &video_str, "-frames:v", "1", "-vf", "scale=480:-2"], &thumbnail);
+        if ok {
+            created += 1;
+        }
     }
     
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    let output = Command::new(&venv_python)
-        .args(&[rag_script.to_str().unwrap(), "channels"])
-        .current_dir(&project_root)
-        .output()
-        .map_err(|e| e.to_string())?;
+    let waveform = folder.join("waveform.png");
+    if !waveform.exists()
+        && run_throttled_ffmpeg(
+            &["-i", &video_str, "-filter_complex", "aformat=channel_layouts=mono,showwavespic=s=1200x120", "-frames:v", "1"],
+            &waveform,
+        )
+    {
+        created += 1;
+    }
     
-    if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        // 간단한 파싱으로 채널 목록 반환
-        let channels = parse_channel_list(&stdout);
-        Ok(channels)
-    } else {
-        Err("채널 목록 조회 실패".to_string())
+    let preview = folder.join("preview.mp4");
+    if !preview.exists() {
+        let preview_args = ["-i", &video_str, "-t", "6", "-vf", "scale=320:-2", "-an", "-c:v", "libx264", "-preset", "veryfast", "-crf", "32"];
+        let mut seek_args = vec!["-ss", "30"];
+        seek_args.extend_from_slice(&preview_args);
+        if run_throttled_ffmpeg(&seek_args, &preview) || run_throttled_ffmpeg(&preview_args, &preview) {
+            created += 1;
+        }
     }
+    
+    created
 }
 
-fn parse_channel_list(output: &str) -> Vec<AIChannelInfo> {
-    let mut channels = Vec::new();
-    
-    println!("파싱할 출력:\n{}", output);
+// 백그라운드 파생 파일 생성 시작 (이미 실행 중이면 false)
+// folders가 없으면 vault 전체에서 누락된 폴더를 찾고, 있으면 그 폴더만 처리
+// 이미 실행 중일 때 받은 폴더는 실행 중인 작업이 이어서 처리
+fn spawn_derivative_generation(window: &Window, state: &DerivativeState, folders: Option<Vec<PathBuf>>) -> bool {
+    let full_scan = folders.is_none();
+    if let Some(folders) = folders {
+        state.pending.lock().unwrap_or_else(|e| e.into_inner()).extend(folders);
+    }
+    if state.is_running.swap(true, Ordering::SeqCst) {
+        return false;
+    }
+    state.is_cancelled.store(false, Ordering::SeqCst);
     
-    // "1. channel_name (X개 영상)" 형태의 라인을 파싱 (이모지 및 기타 텍스트 무시)
-    for line in output.lines() {
-        println!("파싱 중인 라인: {}", line);
-        if let Some(captures) = regex::Regex::new(r"^\s*\d+\.\s*(.+?)\s*\((\d+)개\s*영상\)")
-            .ok()
-            .and_then(|re| re.captures(line))
-        {
-            if let (Some(name), Some(count_str)) = (captures.get(1), captures.get(2)) {
-                if let Ok(count) = count_str.as_str().parse::<u32>() {
-                    println!("파싱 성공: {} - {}개", name.as_str().trim(), count);
-                    channels.push(AIChannelInfo {
-                        name: name.as_str().trim().to_string(),
-                        video_count: count,
-                        description: None,
-                        last_updated: None,
-                    });
+    let window = window.clone();
+    let state = state.clone();
+    thread::spawn(move || {
+        let mut folders = Vec::new();
+        if full_scan {
+            collect_folders_missing_derivatives(&get_project_root().join("vault").join("10_videos"), &mut folders);
+        }
+        
+        let mut total = 0u32;
+        let mut completed = 0u32;
+        let mut files_created = 0u32;
+        
+        loop {
+            // 확인과 종료 표시를 같은 잠금 안에서 해서 그 사이에 추가된 폴더를 놓치지 않음
+            {
+                let mut pending = state.pending.lock().unwrap_or_else(|e| e.into_inner());
+                if !state.is_cancelled.load(Ordering::SeqCst) {
+                    folders.extend(pending.drain(..).filter(|f| folder_needs_derivatives(f)));
+                }
+                if folders.is_empty() || state.is_cancelled.load(Ordering::SeqCst) {
+                    state.is_running.store(false, Ordering::SeqCst);
+                    break;
+                }
+            }
+            total += folders.len() as u32;
+            
+            for folder in folders.drain(..) {
+                if state.is_cancelled.load(Ordering::SeqCst) {
+                    break;
                 }
+                
+                let name = folder.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+                files_created += generate_derivatives_for_folder(&folder);
+                completed += 1;
+                
+                let progress = DownloadProgress {
+                    channel: "미리보기 생성".to_string(),
+                    status: "처리 중".to_string(),
+                    progress: completed as f32 / total.max(1) as f32 * 100.0,
+                    current_video: name.clone(),
+                    total_videos: total,
+                    completed_videos: completed,
+                    log_message: format!("🖼️ 썸네일/파형/미리보기 생성: {}", name),
+                    throughput_bps: None,
+                };
+                let _ = window.emit("derivatives-progress", &progress);
+                
+                thread::sleep(DERIVATIVE_PAUSE);
             }
         }
-    }
+        
+        let cancelled = state.is_cancelled.load(Ordering::SeqCst);
+        let final_progress = DownloadProgress {
+            channel: "미리보기 생성".to_string(),
+            status: if cancelled { "중단됨" } else { "완료" }.to_string(),
+            progress: 100.0,
+            current_video: String::new(),
+            total_videos: total,
+            completed_videos: completed,
+            log_message: format!("✅ 파생 파일 {}개 생성 ({}/{}개 영상)", files_created, completed, total),
+            throughput_bps: None,
+        };
+        let _ = window.emit("derivatives-progress", &final_progress);
+    });
     
-    println!("파싱된 채널 개수: {}", channels.len());
-    channels
+    true
 }
 
-// 채널별 프롬프트 조회
+// 누락된 썸네일/파형/미리보기 생성 수동 시작
 #[command]
-async fn get_channel_prompt(channel_name: String) -> Result<String, String> {
-    let project_root = get_project_root();
-    let prompts_dir = project_root.join("vault").join("90_indices").join("prompts");
-    
-    // 채널명을 파일시스템에 안전한 형태로 변환
-    let safe_channel_name = sanitize_channel_name(&channel_name);
-    let channel_dir = prompts_dir.join(&safe_channel_name);
-    
-    if !channel_dir.exists() {
-        return Ok("{}".to_string()); // 기본 프롬프트 반환
-    }
-    
-    // 활성 버전 확인
-    let active_file = channel_dir.join("active.txt");
-    let version = if active_file.exists() {
-        std::fs::read_to_string(&active_file)
-            .map_err(|e| e.to_string())?
-            .trim()
-            .parse::<u32>()
-            .unwrap_or(1)
+async fn generate_video_derivatives(window: Window, state: State<'_, DerivativeState>, vault_lock: State<'_, VaultLockState>) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    if spawn_derivative_generation(&window, state.inner(), None) {
+        Ok("미리보기 생성이 시작되었습니다".to_string())
     } else {
-        1
-    };
-    
-    // 프롬프트 파일 읽기
-    let prompt_file = channel_dir.join(format!("prompt_v{}.json", version));
-    if prompt_file.exists() {
-        std::fs::read_to_string(&prompt_file).map_err(|e| e.to_string())
-    } else {
-        Ok("{}".to_string())
+        Err("미리보기 생성이 이미 진행 중입니다".to_string())
     }
 }
 
-fn sanitize_channel_name(name: &str) -> String {
-    // 특수문자를 밑줄로 변경하고 길이 제한
-    let sanitized = name
-        .chars()
-        .map(|c| if c.is_alphanumeric() || "가나다라마바사아자차카타파하".contains(c) || c == '_' || c == '-' {
-            c
-        } else {
-            '_'
-        })
-        .collect::<String>();
-    
-    // 연속된 밑줄 제거
-    let re = regex::Regex::new(r"_+").unwrap();
-    let result = re.replace_all(&sanitized, "_");
-    
-    // 앞뒤 밑줄 제거하고 길이 제한
-    result.trim_matches('_').chars().take(50).collect()
+#[command]
+async fn cancel_derivative_generation(state: State<'_, DerivativeState>) -> Result<(), String> {
+    state.is_cancelled.store(true, Ordering::SeqCst);
+    Ok(())
 }
 
-// 채널별 제로샷 AI 프롬프트 생성
 #[command]
-async fn auto_generate_channel_prompt(channel_name: String) -> Result<u32, String> {
+async fn get_derivative_status(state: State<'_, DerivativeState>) -> Result<bool, String> {
+    Ok(state.is_running.load(Ordering::SeqCst))
+}
+
+#[derive(Serialize, Deserialize)]
+struct VideoDetails {
+    video_id: String,
+    title: String,
+    transcript: String,
+    duration: Option<u32>,
+    upload_date: Option<String>,
+    description: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ChatSession {
+    id: String,
+    timestamp: String,
+    query: String,
+    response: ChatResponse,
+    channel: String,
+    model: String,
+}
+
+// 프론트엔드 AIResponse와 동기화 (저장된 세션 JSON 파싱용)
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+struct ChatResponse {
+    answer: String,
+    sources: Vec<AnswerSource>,
+    confidence: Option<f64>,
+    channel_used: String,
+    model_used: String,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+struct AnswerSource {
+    video_id: String,
+    title: String,
+    timestamp: Option<f64>,
+    relevance_score: f64,
+    excerpt: String,
+}
+
+// 비디오 상세 정보 조회 (AIAnswerComponent에서 사용)
+#[command]
+async fn get_video_details(video_id: String, channel_name: String) -> Result<VideoDetails, String> {
     let project_root = get_project_root();
-    let auto_prompt_script = project_root.join("vault").join("90_indices").join("auto_prompt.py");
+    let get_video_info_script = project_root.join("vault").join("90_indices").join("get_video_info.py");
     
-    if !auto_prompt_script.exists() {
-        return Err("제로샷 AI 프롬프트 생성 스크립트를 찾을 수 없습니다".to_string());
+    if !get_video_info_script.exists() {
+        return Err(format!("get_video_info.py 스크립트를 찾을 수 없습니다: {}", get_video_info_script.display()));
     }
     
     let venv_python = project_root.join("venv").join("bin").join("python");
-    let args = vec![
-        auto_prompt_script.to_str().unwrap(), 
-        "generate", 
-        &channel_name
-    ];
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
     
     let output = Command::new(&venv_python)
-        .args(&args)
+        .args(&[get_video_info_script.to_str().unwrap(), &video_id, &channel_name])
         .current_dir(&project_root)
         .output()
         .map_err(|e| e.to_string())?;
     
     if output.status.success() {
         let stdout = String::from_utf8_lossy(&output.stdout);
-        // 버전 번호 추출 (예: "v3 생성 완료" -> 3)
-        if let Some(version_match) = stdout.find("v") {
-            if let Some(space_pos) = stdout[version_match..].find(" ") {
-                let version_str = &stdout[version_match + 1..version_match + space_pos];
-                if let Ok(version) = version_str.parse::<u32>() {
-                    return Ok(version);
-                }
-            }
+        
+        // JSON 응답 파싱 시도
+        if let Ok(video_details) = serde_json::from_str::<VideoDetails>(&stdout) {
+            Ok(video_details)
+        } else {
+            // JSON 파싱 실패 시 기본 정보로 응답
+            Ok(VideoDetails {
+                video_id: video_id.clone(),
+                title: format!("영상 {}", video_id),
+                transcript: stdout.to_string(),
+                duration: None,
+                upload_date: None,
+                description: None,
+            })
         }
-        Ok(1) // 기본값
     } else {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(format!("제로샷 AI 프롬프트 생성 실패: {}", stderr))
+        Err(format!("비디오 정보 조회 실패: {}", stderr))
     }
 }
 
-// 채널 분석 결과 조회
+// 채널 목록 조회 (Python 스크립트 기반)
 #[command]
-async fn get_channel_analysis(channel_name: String) -> Result<String, String> {
+async fn get_channels_from_script() -> Result<Vec<AIChannelInfo>, String> {
     let project_root = get_project_root();
-    let auto_prompt_script = project_root.join("vault").join("90_indices").join("auto_prompt.py");
+    let list_channels_script = project_root.join("vault").join("90_indices").join("list_channels.py");
+    
+    if !list_channels_script.exists() {
+        return Err(format!("list_channels.py 스크립트를 찾을 수 없습니다: {}", list_channels_script.display()));
+    }
     
     let venv_python = project_root.join("venv").join("bin").join("python");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
+    
     let output = Command::new(&venv_python)
-        .args(&[auto_prompt_script.to_str().unwrap(), "analyze", &channel_name])
+        .arg(&list_channels_script)
         .current_dir(&project_root)
         .output()
         .map_err(|e| e.to_string())?;
     
     if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        
+        // JSON 응답 파싱 시도
+        if let Ok(channels) = serde_json::from_str::<Vec<AIChannelInfo>>(&stdout) {
+            Ok(channels)
+        } else {
+            // JSON 파싱 실패 시 기존 방식으로 파싱
+            let channels = parse_channel_list(&stdout);
+            Ok(channels)
+        }
     } else {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(format!("채널 분석 실패: {}", stderr))
+        Err(format!("채널 목록 조회 실패: {}", stderr))
     }
 }
 
-// 모든 채널 자동 프롬프트 일괄 생성
+// 채팅 세션 저장
 #[command]
-async fn batch_generate_prompts() -> Result<String, String> {
+async fn save_chat_session(vault_lock: State<'_, VaultLockState>, session_data: String) -> Result<(), String> {
+    ensure_vault_writable(&vault_lock)?;
     let project_root = get_project_root();
-    let auto_prompt_script = project_root.join("vault").join("90_indices").join("auto_prompt.py");
+    let sessions_dir = project_root.join("vault").join("90_indices").join("search_sessions");
     
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    let output = Command::new(&venv_python)
-        .args(&[auto_prompt_script.to_str().unwrap(), "batch"])
-        .current_dir(&project_root)
-        .output()
-        .map_err(|e| e.to_string())?;
+    // 디렉토리 생성 (존재하지 않는 경우)
+    std::fs::create_dir_all(&sessions_dir).map_err(|e| e.to_string())?;
+    
+    // 타임스탬프 기반 파일명 생성
+    let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
+    let session_file = sessions_dir.join(format!("session_{}.json", timestamp));
     
-    if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
-    } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(format!("일괄 프롬프트 생성 실패: {}", stderr))
-    }
+    // 세션 데이터 저장
+    std::fs::write(&session_file, &session_data).map_err(|e| e.to_string())?;
+    
+    Ok(())
 }
 
-// 채널별 프롬프트 저장
+// 최근 채팅 세션들 불러오기
 #[command]
-async fn save_channel_prompt(channel_name: String, prompt_data: String) -> Result<u32, String> {
+async fn load_recent_sessions(limit: Option<usize>) -> Result<Vec<String>, String> {
     let project_root = get_project_root();
-    let prompts_dir = project_root.join("vault").join("90_indices").join("prompts");
+    let sessions_dir = project_root.join("vault").join("90_indices").join("search_sessions");
     
-    let safe_channel_name = sanitize_channel_name(&channel_name);
-    let channel_dir = prompts_dir.join(&safe_channel_name);
+    if !sessions_dir.exists() {
+        return Ok(vec![]);
+    }
     
-    // 디렉토리 생성
-    std::fs::create_dir_all(&channel_dir).map_err(|e| e.to_string())?;
+    let limit = limit.unwrap_or(10); // 기본값 10개
+    let mut sessions = Vec::new();
     
-    // 기존 버전 확인
-    let existing_versions: Vec<u32> = std::fs::read_dir(&channel_dir)
+    // 세션 파일들 수집
+    let mut session_files: Vec<_> = std::fs::read_dir(&sessions_dir)
         .map_err(|e| e.to_string())?
         .filter_map(|entry| {
             let entry = entry.ok()?;
-            let filename = entry.file_name().to_string_lossy().to_string();
-            if filename.starts_with("prompt_v") && filename.ends_with(".json") {
-                let version_str = filename.strip_prefix("prompt_v")?.strip_suffix(".json")?;
-                version_str.parse().ok()
+            let path = entry.path();
+            let filename = path.file_name()?.to_str()?;
+            
+            // session_으로 시작하고 .json으로 끝나는 파일만
+            if filename.starts_with("session_") && filename.ends_with(".json") {
+                Some((path, entry.metadata().ok()?.modified().ok()?))
             } else {
                 None
             }
         })
         .collect();
     
-    let new_version = existing_versions.iter().max().unwrap_or(&0) + 1;
+    // 수정 시간 기준 내림차순 정렬 (최신순)
+    session_files.sort_by(|a, b| b.1.cmp(&a.1));
     
-    // 새 프롬프트 파일 저장
-    let prompt_file = channel_dir.join(format!("prompt_v{}.json", new_version));
-    std::fs::write(&prompt_file, &prompt_data).map_err(|e| e.to_string())?;
+    // 지정된 개수만큼 세션 데이터 로드
+    for (path, _) in session_files.into_iter().take(limit) {
+        if let Ok(session_data) = std::fs::read_to_string(&path) {
+            sessions.push(session_data);
+        }
+    }
     
-    // 활성 버전 업데이트
-    let active_file = channel_dir.join("active.txt");
-    std::fs::write(&active_file, new_version.to_string()).map_err(|e| e.to_string())?;
+    Ok(sessions)
+}
+
+// 세션 ID(또는 session_*.json 파일명)로 저장된 채팅 세션 찾기
+fn find_chat_session(session_id: &str) -> Result<ChatSession, String> {
+    let sessions_dir = get_project_root().join("vault").join("90_indices").join("search_sessions");
+    if !sessions_dir.exists() {
+        return Err("저장된 세션이 없습니다".to_string());
+    }
     
-    Ok(new_version)
+    let entries = fs::read_dir(&sessions_dir).map_err(|e| e.to_string())?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let filename = path.file_name().and_then(|f| f.to_str()).unwrap_or_default().to_string();
+        if !filename.starts_with("session_") || !filename.ends_with(".json") {
+            continue;
+        }
+        
+        let session: ChatSession = match fs::read_to_string(&path).ok().and_then(|c| serde_json::from_str(&c).ok()) {
+            Some(session) => session,
+            None => continue,
+        };
+        if session.id == session_id || filename.trim_end_matches(".json") == session_id {
+            return Ok(session);
+        }
+    }
+    
+    Err(format!("세션을 찾을 수 없습니다: {}", session_id))
 }
 
-// 프롬프트 버전 목록 조회
+// YAML 문자열 값 (JSON 문자열은 YAML 큰따옴표 문자열과 호환)
+fn yaml_quote(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+// 노트 기본 저장 폴더 (vault 기준)
+const DEFAULT_ANSWER_NOTES_FOLDER: &str = "20_notes/ai_answers";
+
+// AI 답변을 vault 문헌 노트(Markdown)로 저장
 #[command]
-async fn get_prompt_versions(channel_name: String) -> Result<String, String> {
+async fn save_answer_as_note(vault_lock: State<'_, VaultLockState>, session_id: String, folder: Option<String>) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let session = find_chat_session(&session_id)?;
     let project_root = get_project_root();
-    let auto_prompt_script = project_root.join("vault").join("90_indices").join("auto_prompt.py");
+    let vault_path = project_root.join("vault");
     
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    let output = Command::new(&venv_python)
-        .args(&[auto_prompt_script.to_str().unwrap(), "versions", &channel_name])
-        .current_dir(&project_root)
-        .output()
-        .map_err(|e| e.to_string())?;
+    let folder = folder
+        .map(|f| f.trim().trim_matches('/').to_string())
+        .filter(|f| !f.is_empty())
+        .unwrap_or_else(|| DEFAULT_ANSWER_NOTES_FOLDER.to_string());
+    if folder.split('/').any(|part| part == "..") {
+        return Err(format!("잘못된 폴더 경로입니다: {}", folder));
+    }
+    let notes_dir = vault_path.join(&folder);
+    fs::create_dir_all(&notes_dir).map_err(|e| format!("노트 폴더 생성 실패: {}", e))?;
+    
+    // 인용된 영상의 vault 내 자막 노트 경로 (Obsidian 위키링크용)
+    let videos = scan_vault_videos().unwrap_or_default();
+    let video_links: HashMap<String, String> = videos
+        .iter()
+        .filter_map(|v| {
+            let video_id = v.video_id.clone()?;
+            let link = v.captions_path
+                .trim_start_matches("vault/")
+                .trim_end_matches(".md")
+                .to_string();
+            Some((video_id, link))
+        })
+        .collect();
     
-    if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    let response = &session.response;
+    let channel = if session.channel.is_empty() { &response.channel_used } else { &session.channel };
+    let model = if session.model.is_empty() { &response.model_used } else { &session.model };
+    
+    // YAML frontmatter
+    let mut note = String::from("---\n");
+    note.push_str(&format!("title: {}\n", yaml_quote(&session.query)));
+    note.push_str("type: literature-note\n");
+    note.push_str(&format!("channel: {}\n", yaml_quote(channel)));
+    note.push_str(&format!("model: {}\n", yaml_quote(model)));
+    note.push_str(&format!("asked: {}\n", yaml_quote(&session.timestamp)));
+    note.push_str(&format!("created_date: {}\n", chrono::Local::now().format("%Y-%m-%d %H:%M:%S")));
+    if let Some(confidence) = response.confidence {
+        note.push_str(&format!("confidence: {:.2}\n", confidence));
+    }
+    let cited: Vec<String> = response.sources
+        .iter()
+        .map(|s| match video_links.get(&s.video_id) {
+            Some(link) => yaml_quote(&format!("[[{}]]", link)),
+            None => yaml_quote(&s.video_id),
+        })
+        .collect();
+    note.push_str(&format!("videos: [{}]\n", cited.join(", ")));
+    note.push_str("topic: ['ai-answer']\n");
+    note.push_str("---\n\n");
+    
+    // 본문
+    note.push_str(&format!("# {}\n\n", session.query));
+    note.push_str("## 🤖 답변\n\n");
+    note.push_str(response.answer.trim());
+    note.push_str("\n\n");
+    
+    if !response.sources.is_empty() {
+        note.push_str("## 📚 인용 영상\n\n");
+        for source in &response.sources {
+            let title = if source.title.is_empty() { &source.video_id } else { &source.title };
+            let title_link = match video_links.get(&source.video_id) {
+                Some(link) => format!("[[{}|{}]]", link, title),
+                None => title.to_string(),
+            };
+            let youtube_url = match source.timestamp {
+                Some(t) => format!("https://www.youtube.com/watch?v={}&t={}s", source.video_id, t as u64),
+                None => format!("https://www.youtube.com/watch?v={}", source.video_id),
+            };
+            note.push_str(&format!(
+                "- {} — [YouTube]({}) (관련도 {:.2})\n",
+                title_link, youtube_url, source.relevance_score
+            ));
+            let excerpt = source.excerpt.trim();
+            if !excerpt.is_empty() {
+                note.push_str(&format!("  > {}\n", excerpt.replace('\n', " ")));
+            }
+        }
+        note.push('\n');
+    }
+    
+    note.push_str("## 💭 노트\n\n");
+    note.push_str("*여기에 개인적인 생각이나 메모를 추가하세요.*\n");
+    
+    // 파일명: 날짜_질문 (중복 시 번호 추가)
+    let safe_query: String = session.query
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == ' ' || c == '-' { c } else { '_' })
+        .collect::<String>()
+        .trim()
+        .chars()
+        .take(50)
+        .collect();
+    let base_name = format!("{}_{}", chrono::Local::now().format("%Y%m%d"), safe_query);
+    let mut note_path = notes_dir.join(format!("{}.md", base_name));
+    let mut suffix = 2;
+    while note_path.exists() {
+        note_path = notes_dir.join(format!("{}_{}.md", base_name, suffix));
+        suffix += 1;
+    }
+    
+    fs::write(&note_path, note).map_err(|e| format!("노트 저장 실패: {}", e))?;
+    
+    let relative = note_path.strip_prefix(&project_root).unwrap_or(&note_path);
+    Ok(relative.to_string_lossy().to_string())
+}
+
+// 인용 형식 (클립보드 복사용 텍스트)
+#[derive(Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum CitationStyle {
+    Apa,
+    Mla,
+    Plain,
+}
+
+const MLA_MONTHS: [&str; 12] = ["Jan.", "Feb.", "Mar.", "Apr.", "May", "June", "July", "Aug.", "Sept.", "Oct.", "Nov.", "Dec."];
+
+// 초 → m:ss 또는 h:mm:ss
+fn citation_timestamp(seconds: f64) -> String {
+    let total = seconds.max(0.0) as u64;
+    let (hours, minutes, secs) = (total / 3600, total % 3600 / 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, secs)
     } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(format!("프롬프트 버전 조회 실패: {}", stderr))
+        format!("{}:{:02}", minutes, secs)
     }
 }
 
-// 프롬프트 현황 조회
+fn citation_text(video: &VideoInfo, video_id: &str, timestamp: Option<f64>, style: CitationStyle) -> String {
+    use chrono::Datelike;
+    let url = match timestamp {
+        Some(t) => format!("https://www.youtube.com/watch?v={}&t={}s", video_id, t.max(0.0) as u64),
+        None => format!("https://www.youtube.com/watch?v={}", video_id),
+    };
+    let date = video.upload_date
+        .as_deref()
+        .and_then(compact_upload_date)
+        .and_then(|d| chrono::NaiveDate::parse_from_str(&d, "%Y%m%d").ok());
+    let title = video.title.trim();
+    let channel = video.channel.trim();
+    let at = timestamp.map(citation_timestamp);
+    
+    match style {
+        CitationStyle::Apa => {
+            let date = date.map(|d| d.format("%Y, %B %-d").to_string()).unwrap_or_else(|| "n.d.".to_string());
+            let mut text = format!("{}. ({}). {} [Video]. YouTube. {}", channel, date, title, url);
+            if let Some(at) = at {
+                text.push_str(&format!(" ({})", at));
+            }
+            text
+        }
+        CitationStyle::Mla => {
+            let mut text = format!("\"{}.\" YouTube, uploaded by {}", title.trim_end_matches('.'), channel);
+            if let Some(d) = date {
+                text.push_str(&format!(", {} {} {}", d.day(), MLA_MONTHS[d.month0() as usize], d.year()));
+            }
+            text.push_str(&format!(", {}", url));
+            if let Some(at) = at {
+                text.push_str(&format!(", {}", at));
+            }
+            text.push('.');
+            text
+        }
+        CitationStyle::Plain => {
+            let mut text = format!("{} — {}", channel, title);
+            if let Some(d) = date {
+                text.push_str(&format!(" ({})", d.format("%Y-%m-%d")));
+            }
+            if let Some(at) = at {
+                text.push_str(&format!(" [{}]", at));
+            }
+            text.push_str(&format!(" {}", url));
+            text
+        }
+    }
+}
+
+// 보관된 영상 하나의 인용문
 #[command]
-async fn get_prompt_status() -> Result<String, String> {
-    let project_root = get_project_root();
-    let auto_prompt_script = project_root.join("vault").join("90_indices").join("auto_prompt.py");
-    
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    let output = Command::new(&venv_python)
-        .args(&[auto_prompt_script.to_str().unwrap(), "status"])
-        .current_dir(&project_root)
-        .output()
-        .map_err(|e| e.to_string())?;
+fn format_citation(video_id: String, timestamp: Option<f64>, style: CitationStyle) -> Result<String, String> {
+    let video = scan_vault_videos()?
+        .into_iter()
+        .find(|v| v.video_id.as_deref() == Some(video_id.as_str()))
+        .ok_or_else(|| format!("영상을 찾을 수 없습니다: {}", video_id))?;
+    Ok(citation_text(&video, &video_id, timestamp, style))
+}
+
+// 채팅 세션의 인용 영상 전체 (답변 순서, 같은 영상·시점 중복 제외, 줄 단위)
+#[command]
+async fn export_session_citations(session_id: String, style: CitationStyle) -> Result<String, String> {
+    let session = find_chat_session(&session_id)?;
+    let videos: HashMap<String, VideoInfo> = scan_vault_videos()?
+        .into_iter()
+        .filter_map(|v| Some((v.video_id.clone()?, v)))
+        .collect();
     
-    if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
-    } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(format!("프롬프트 현황 조회 실패: {}", stderr))
+    let mut seen = std::collections::HashSet::new();
+    let mut citations = Vec::new();
+    for source in &session.response.sources {
+        let timestamp = source.timestamp.map(|t| t.max(0.0) as u64);
+        if !seen.insert((source.video_id.clone(), timestamp)) {
+            continue;
+        }
+        let Some(video) = videos.get(&source.video_id) else { continue };
+        citations.push(citation_text(video, &source.video_id, source.timestamp, style));
+    }
+    if citations.is_empty() {
+        return Err("인용할 수 있는 보관 영상이 없습니다".to_string());
     }
+    Ok(citations.join("\n"))
 }
 
-// 데이터 정합성 검사 (진행 상황 포함)
+// 리서치 워크스페이스 관련 구조체들
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum WorkspaceItem {
+    Video { video_id: String, title: Option<String>, note: Option<String> },
+    SavedSearch { query: String, channel: Option<String> },
+    Highlight { video_id: String, text: String, timestamp: Option<f64>, note: Option<String> },
+    ChatSession { session_id: String },
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct WorkspaceEntry {
+    id: String,
+    added_at: String,
+    #[serde(flatten)]
+    item: WorkspaceItem,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct Workspace {
+    id: String,
+    name: String,
+    #[serde(default)]
+    description: String,
+    created_at: String,
+    updated_at: String,
+    #[serde(default)]
+    items: Vec<WorkspaceEntry>,
+}
+
+fn get_workspaces_dir() -> PathBuf {
+    get_project_root().join("vault").join("90_indices").join("workspaces")
+}
+
+fn workspace_file(workspace_id: &str) -> Result<PathBuf, String> {
+    if workspace_id.is_empty() || !workspace_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err(format!("잘못된 워크스페이스 ID입니다: {}", workspace_id));
+    }
+    Ok(get_workspaces_dir().join(format!("{}.json", workspace_id)))
+}
+
+fn load_workspace(workspace_id: &str) -> Result<Workspace, String> {
+    let path = workspace_file(workspace_id)?;
+    let content = fs::read_to_string(&path).map_err(|_| format!("워크스페이스를 찾을 수 없습니다: {}", workspace_id))?;
+    serde_json::from_str(&content).map_err(|e| format!("워크스페이스 파싱 실패 {}: {}", workspace_id, e))
+}
+
+fn save_workspace(workspace: &Workspace) -> Result<(), String> {
+    fs::create_dir_all(get_workspaces_dir()).map_err(|e| format!("워크스페이스 폴더 생성 실패: {}", e))?;
+    let json = serde_json::to_string_pretty(workspace).map_err(|e| e.to_string())?;
+    fs::write(workspace_file(&workspace.id)?, json).map_err(|e| format!("워크스페이스 저장 실패: {}", e))
+}
+
+// 새 워크스페이스 생성
 #[command]
-async fn check_integrity_with_progress(window: Window) -> Result<String, String> {
-    let project_root = get_project_root();
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    if !venv_python.exists() {
-        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+fn create_workspace(vault_lock: State<'_, VaultLockState>, name: String, description: Option<String>) -> Result<Workspace, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("워크스페이스 이름을 입력하세요".to_string());
     }
-    
-    // 시작 진행 상황
-    let start_progress = DownloadProgress {
-        channel: "정합성 검사".to_string(),
-        status: "시작".to_string(),
-        progress: 0.0,
-        current_video: "검사 준비 중...".to_string(),
-        total_videos: 1,
-        completed_videos: 0,
-        log_message: "🔍 데이터 정합성 검사를 시작합니다...".to_string(),
-    };
-    let _ = window.emit("integrity-progress", &start_progress);
-    
-    // 진행률 업데이트 (25% - 시작)
-    let progress_25 = DownloadProgress {
-        channel: "정합성 검사".to_string(),
-        status: "시작".to_string(),
-        progress: 25.0,
-        current_video: "검사 스크립트 실행 중...".to_string(),
-        total_videos: 1,
-        completed_videos: 0,
-        log_message: "🔍 데이터 정합성 검사 스크립트 실행 중...".to_string(),
+    let now = chrono::Local::now();
+    let workspace = Workspace {
+        id: format!("ws_{}", now.format("%Y%m%d_%H%M%S_%3f")),
+        name,
+        description: description.unwrap_or_default(),
+        created_at: now.to_rfc3339(),
+        updated_at: now.to_rfc3339(),
+        items: Vec::new(),
     };
-    let _ = window.emit("integrity-progress", &progress_25);
+    save_workspace(&workspace)?;
+    Ok(workspace)
+}
+
+// 워크스페이스 목록 (최근 수정 순)
+#[command]
+fn list_workspaces() -> Result<Vec<Workspace>, String> {
+    let dir = get_workspaces_dir();
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut workspaces: Vec<Workspace> = fs::read_dir(&dir)
+        .map_err(|e| e.to_string())?
+        .flatten()
+        .filter(|e| e.path().extension().map(|ext| ext == "json").unwrap_or(false))
+        .filter_map(|e| fs::read_to_string(e.path()).ok())
+        .filter_map(|c| serde_json::from_str(&c).ok())
+        .collect();
+    workspaces.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+    Ok(workspaces)
+}
+
+#[command]
+fn get_workspace(workspace_id: String) -> Result<Workspace, String> {
+    load_workspace(&workspace_id)
+}
+
+// 워크스페이스에 영상/검색/하이라이트/채팅 세션 추가
+#[command]
+fn add_item(vault_lock: State<'_, VaultLockState>, workspace_id: String, item: WorkspaceItem) -> Result<WorkspaceEntry, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let mut workspace = load_workspace(&workspace_id)?;
+    
+    // 같은 영상/세션을 중복으로 고정하지 않음
+    let duplicate = workspace.items.iter().find(|entry| match (&entry.item, &item) {
+        (WorkspaceItem::Video { video_id: a, .. }, WorkspaceItem::Video { video_id: b, .. }) => a == b,
+        (WorkspaceItem::ChatSession { session_id: a }, WorkspaceItem::ChatSession { session_id: b }) => a == b,
+        _ => false,
+    });
+    if let Some(existing) = duplicate {
+        return Ok(existing.clone());
+    }
     
-    // 진행률 업데이트 (50% - 검사 중)
-    let progress_50 = DownloadProgress {
-        channel: "정합성 검사".to_string(),
-        status: "검사 중".to_string(),
-        progress: 50.0,
-        current_video: "파일 검사 중...".to_string(),
-        total_videos: 1,
-        completed_videos: 0,
-        log_message: "📁 Vault 파일 구조 및 메타데이터 검사 중...".to_string(),
+    let now = chrono::Local::now();
+    let entry = WorkspaceEntry {
+        id: format!("item_{}_{}", now.format("%Y%m%d%H%M%S%3f"), workspace.items.len()),
+        added_at: now.to_rfc3339(),
+        item,
     };
-    let _ = window.emit("integrity-progress", &progress_50);
-    
-    // 새로운 채널별 격리 정합성 검사 스크립트 실행 (실시간 로그)
-    let integrity_script = project_root.join("vault").join("90_indices").join("integrity_check.py");
-    if !integrity_script.exists() {
-        return Err(format!("정합성 검사 스크립트를 찾을 수 없습니다: {}", integrity_script.display()));
+    workspace.items.push(entry.clone());
+    workspace.updated_at = now.to_rfc3339();
+    save_workspace(&workspace)?;
+    Ok(entry)
+}
+
+#[command]
+fn remove_item(vault_lock: State<'_, VaultLockState>, workspace_id: String, item_id: String) -> Result<(), String> {
+    ensure_vault_writable(&vault_lock)?;
+    let mut workspace = load_workspace(&workspace_id)?;
+    let before = workspace.items.len();
+    workspace.items.retain(|entry| entry.id != item_id);
+    if workspace.items.len() == before {
+        return Err(format!("항목을 찾을 수 없습니다: {}", item_id));
     }
+    workspace.updated_at = chrono::Local::now().to_rfc3339();
+    save_workspace(&workspace)
+}
+
+#[command]
+fn delete_workspace(vault_lock: State<'_, VaultLockState>, workspace_id: String) -> Result<(), String> {
+    ensure_vault_writable(&vault_lock)?;
+    let path = workspace_file(&workspace_id)?;
+    fs::remove_file(&path).map_err(|e| format!("워크스페이스 삭제 실패: {}", e))
+}
+
+// 워크스페이스를 vault Markdown 노트(기본) 또는 JSON으로 내보내기
+#[command]
+fn export_workspace(vault_lock: State<'_, VaultLockState>, workspace_id: String, format: Option<String>) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let workspace = load_workspace(&workspace_id)?;
+    let project_root = get_project_root();
+    let export_dir = project_root.join("vault").join("20_notes").join("workspaces");
+    fs::create_dir_all(&export_dir).map_err(|e| format!("내보내기 폴더 생성 실패: {}", e))?;
     
-    let mut child = Command::new(&venv_python)
-        .arg(&integrity_script)
-        .current_dir(&project_root)
-        .env("PYTHONUNBUFFERED", "1")
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()
-        .map_err(|e| e.to_string())?;
+    let safe_name: String = workspace.name
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == ' ' || c == '-' { c } else { '_' })
+        .collect::<String>()
+        .trim()
+        .chars()
+        .take(50)
+        .collect();
     
-    let stdout = child.stdout.take().ok_or("stdout를 가져올 수 없습니다")?;
-    let stderr = child.stderr.take().ok_or("stderr를 가져올 수 없습니다")?;
+    if format.as_deref() == Some("json") {
+        let path = export_dir.join(format!("{}.json", safe_name));
+        let json = serde_json::to_string_pretty(&workspace).map_err(|e| e.to_string())?;
+        fs::write(&path, json).map_err(|e| format!("내보내기 실패: {}", e))?;
+        return Ok(path.strip_prefix(&project_root).unwrap_or(&path).to_string_lossy().to_string());
+    }
     
-    // 별도 스레드에서 실시간 로그 처리
-    let window_clone = window.clone();
-    std::thread::spawn(move || {
-        let stdout_reader = std::io::BufReader::new(stdout);
-        for line in stdout_reader.lines() {
-            if let Ok(line) = line {
-                let line = line.trim();
-                if !line.is_empty() {
-                    let progress = DownloadProgress {
-                        channel: "정합성 검사".to_string(),
-                        status: "검사 중".to_string(),
-                        progress: 75.0,
-                        current_video: "실시간 검사 중...".to_string(),
-                        total_videos: 1,
-                        completed_videos: 0,
-                        log_message: line.to_string(),
-                    };
-                    let _ = window_clone.emit("integrity-progress", &progress);
-                }
-            }
+    let videos = scan_vault_videos().unwrap_or_default();
+    let video_link = |video_id: &str, title: Option<&str>| -> String {
+        let found = videos.iter().find(|v| v.video_id.as_deref() == Some(video_id));
+        let title = title.map(|t| t.to_string()).or_else(|| found.map(|v| v.title.clone())).unwrap_or_else(|| video_id.to_string());
+        match found {
+            Some(v) => format!("[[{}|{}]]", v.captions_path.trim_start_matches("vault/").trim_end_matches(".md"), title),
+            None => format!("[{}](https://www.youtube.com/watch?v={})", title, video_id),
         }
-    });
+    };
+    
+    let mut note = String::from("---\n");
+    note.push_str(&format!("title: {}\n", yaml_quote(&workspace.name)));
+    note.push_str("type: research-workspace\n");
+    note.push_str(&format!("workspace_id: {}\n", yaml_quote(&workspace.id)));
+    note.push_str(&format!("created: {}\n", yaml_quote(&workspace.created_at)));
+    note.push_str(&format!("exported: {}\n", chrono::Local::now().format("%Y-%m-%d %H:%M:%S")));
+    note.push_str("topic: ['research']\n");
+    note.push_str("---\n\n");
+    note.push_str(&format!("# {}\n\n", workspace.name));
+    if !workspace.description.trim().is_empty() {
+        note.push_str(workspace.description.trim());
+        note.push_str("\n\n");
+    }
     
-    let window_clone2 = window.clone();
-    std::thread::spawn(move || {
-        let stderr_reader = std::io::BufReader::new(stderr);
-        for line in stderr_reader.lines() {
-            if let Ok(line) = line {
-                let line = line.trim();
-                if !line.is_empty() {
-                    let progress = DownloadProgress {
-                        channel: "정합성 검사".to_string(),
-                        status: "경고".to_string(),
-                        progress: 75.0,
-                        current_video: "실시간 검사 중...".to_string(),
-                        total_videos: 1,
-                        completed_videos: 0,
-                        log_message: format!("⚠️ {}", line),
-                    };
-                    let _ = window_clone2.emit("integrity-progress", &progress);
-                }
+    let pinned: Vec<String> = workspace.items.iter().filter_map(|e| match &e.item {
+        WorkspaceItem::Video { video_id, title, note: memo } => Some(match memo {
+            Some(memo) if !memo.trim().is_empty() => format!("- {} — {}", video_link(video_id, title.as_deref()), memo.trim()),
+            _ => format!("- {}", video_link(video_id, title.as_deref())),
+        }),
+        _ => None,
+    }).collect();
+    if !pinned.is_empty() {
+        note.push_str(&format!("## 📌 고정한 영상\n\n{}\n\n", pinned.join("\n")));
+    }
+    
+    let highlights: Vec<String> = workspace.items.iter().filter_map(|e| match &e.item {
+        WorkspaceItem::Highlight { video_id, text, timestamp, note: memo } => {
+            let mut line = format!("> {}\n\n— {}", text.trim().replace('\n', " "), video_link(video_id, None));
+            if let Some(t) = timestamp {
+                line.push_str(&format!(" ([{}s](https://www.youtube.com/watch?v={}&t={}s))", *t as u64, video_id, *t as u64));
             }
+            if let Some(memo) = memo.as_ref().filter(|m| !m.trim().is_empty()) {
+                line.push_str(&format!("\n\n{}", memo.trim()));
+            }
+            Some(line)
         }
-    });
+        _ => None,
+    }).collect();
+    if !highlights.is_empty() {
+        note.push_str(&format!("## ✨ 하이라이트\n\n{}\n\n", highlights.join("\n\n")));
+    }
     
-    // 프로세스 완료 대기
-    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+    let searches: Vec<String> = workspace.items.iter().filter_map(|e| match &e.item {
+        WorkspaceItem::SavedSearch { query, channel } => Some(match channel {
+            Some(channel) => format!("- `{}` ({})", query, channel),
+            None => format!("- `{}`", query),
+        }),
+        _ => None,
+    }).collect();
+    if !searches.is_empty() {
+        note.push_str(&format!("## 🔎 저장한 검색\n\n{}\n\n", searches.join("\n")));
+    }
     
-    // 진행률 업데이트 (75% - 거의 완료)
-    let progress_75 = DownloadProgress {
-        channel: "정합성 검사".to_string(),
-        status: "완료 중".to_string(),
-        progress: 75.0,
-        current_video: "검사 결과 정리 중...".to_string(),
-        total_videos: 1,
-        completed_videos: 0,
-        log_message: "📋 검사 결과 정리 및 보고서 생성 중...".to_string(),
-    };
-    let _ = window.emit("integrity-progress", &progress_75);
+    let chats: Vec<String> = workspace.items.iter().filter_map(|e| match &e.item {
+        WorkspaceItem::ChatSession { session_id } => Some(match find_chat_session(session_id) {
+            Ok(session) => format!("### {}\n\n{}", session.query, session.response.answer.trim()),
+            Err(_) => format!("### {}\n\n*세션을 찾을 수 없습니다.*", session_id),
+        }),
+        _ => None,
+    }).collect();
+    if !chats.is_empty() {
+        note.push_str(&format!("## 🤖 대화\n\n{}\n\n", chats.join("\n\n")));
+    }
     
-    if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let final_progress = DownloadProgress {
-            channel: "정합성 검사".to_string(),
-            status: "완료".to_string(),
-            progress: 100.0,
-            current_video: "검사 완료".to_string(),
-            total_videos: 1,
-            completed_videos: 1,
-            log_message: "✅ 데이터 정합성 검사 완료!".to_string(),
-        };
-        let _ = window.emit("integrity-progress", &final_progress);
-        Ok(format!("✅ 데이터 정합성 검사 완료\n{}", stdout))
-    } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        let error_progress = DownloadProgress {
-            channel: "정합성 검사".to_string(),
-            status: "실패".to_string(),
-            progress: 0.0,
-            current_video: "검사 실패".to_string(),
-            total_videos: 1,
-            completed_videos: 0,
-            log_message: format!("❌ 데이터 정합성 검사 실패: {}", stderr),
-        };
-        let _ = window.emit("integrity-progress", &error_progress);
-        Err(format!("데이터 정합성 검사 실패: {}", stderr))
+    note.push_str("## 💭 노트\n\n");
+    note.push_str("*여기에 개인적인 생각이나 메모를 추가하세요.*\n");
+    
+    // 같은 워크스페이스는 같은 노트로 덮어씀 (반복 내보내기)
+    let path = export_dir.join(format!("{}.md", safe_name));
+    fs::write(&path, note).map_err(|e| format!("내보내기 실패: {}", e))?;
+    Ok(path.strip_prefix(&project_root).unwrap_or(&path).to_string_lossy().to_string())
+}
+
+// 사용자 재생목록 (채널을 가리지 않는 학습 순서, vault/90_indices/playlists/<id>.json)
+#[derive(Serialize, Deserialize, Clone)]
+struct UserPlaylist {
+    id: String,
+    name: String,
+    #[serde(default)]
+    description: String,
+    created_at: String,
+    updated_at: String,
+    #[serde(default)]
+    video_ids: Vec<String>,           // 재생 순서
+}
+
+// 목록 조회용: 인덱스에서 찾은 영상을 순서대로 채운 재생목록
+#[derive(Serialize)]
+struct UserPlaylistView {
+    #[serde(flatten)]
+    playlist: UserPlaylist,
+    videos: Vec<VideoInfo>,
+    missing_video_ids: Vec<String>,   // 삭제되었거나 아직 인덱싱되지 않은 영상
+}
+
+fn get_playlists_dir() -> PathBuf {
+    get_project_root().join("vault").join("90_indices").join("playlists")
+}
+
+fn playlist_file(playlist_id: &str) -> Result<PathBuf, String> {
+    if playlist_id.is_empty() || !playlist_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err(format!("잘못된 재생목록 ID입니다: {}", playlist_id));
     }
+    Ok(get_playlists_dir().join(format!("{}.json", playlist_id)))
 }
 
-// 기존 데이터 정합성 검사 함수 (호환성 유지)
+fn load_playlist(playlist_id: &str) -> Result<UserPlaylist, String> {
+    let path = playlist_file(playlist_id)?;
+    let content = fs::read_to_string(&path).map_err(|_| format!("재생목록을 찾을 수 없습니다: {}", playlist_id))?;
+    serde_json::from_str(&content).map_err(|e| format!("재생목록 파싱 실패 {}: {}", playlist_id, e))
+}
+
+fn save_playlist(playlist: &mut UserPlaylist) -> Result<(), String> {
+    playlist.updated_at = chrono::Local::now().to_rfc3339();
+    fs::create_dir_all(get_playlists_dir()).map_err(|e| format!("재생목록 폴더 생성 실패: {}", e))?;
+    let json = serde_json::to_string_pretty(playlist).map_err(|e| e.to_string())?;
+    fs::write(playlist_file(&playlist.id)?, json).map_err(|e| format!("재생목록 저장 실패: {}", e))
+}
+
+// 새 재생목록 생성
 #[command]
-async fn check_integrity() -> Result<String, String> {
-    let project_root = get_project_root();
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    if !venv_python.exists() {
-        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+fn create_playlist(vault_lock: State<'_, VaultLockState>, name: String, description: Option<String>) -> Result<UserPlaylist, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("재생목록 이름을 입력하세요".to_string());
     }
-    
-    let integrity_script = project_root.join("vault").join("90_indices").join("integrity_check.py");
-    if !integrity_script.exists() {
-        return Err(format!("정합성 검사 스크립트를 찾을 수 없습니다: {}", integrity_script.display()));
+    let now = chrono::Local::now();
+    let mut playlist = UserPlaylist {
+        id: format!("pl_{}", now.format("%Y%m%d_%H%M%S_%3f")),
+        name,
+        description: description.unwrap_or_default(),
+        created_at: now.to_rfc3339(),
+        updated_at: now.to_rfc3339(),
+        video_ids: Vec::new(),
+    };
+    save_playlist(&mut playlist)?;
+    Ok(playlist)
+}
+
+// 재생목록 끝에 영상 추가 (이미 있으면 그대로)
+#[command]
+fn add_to_playlist(vault_lock: State<'_, VaultLockState>, playlist_id: String, video_id: String) -> Result<UserPlaylist, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let mut playlist = load_playlist(&playlist_id)?;
+    if playlist.video_ids.contains(&video_id) {
+        return Ok(playlist);
     }
-    
-    let output = Command::new(&venv_python)
-        .arg(&integrity_script)
-        .current_dir(&project_root)
-        .output()
+    let conn = open_index_db()?;
+    let exists: bool = conn
+        .query_row("SELECT EXISTS (SELECT 1 FROM videos WHERE video_id = ?1)", [&video_id], |row| row.get(0))
         .map_err(|e| e.to_string())?;
-    
-    if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        Ok(format!("✅ 데이터 정합성 검사 완료\n{}", stdout))
-    } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(format!("데이터 정합성 검사 실패: {}", stderr))
+    if !exists {
+        return Err(format!("영상을 찾을 수 없습니다: {}", video_id));
     }
+    playlist.video_ids.push(video_id);
+    save_playlist(&mut playlist)?;
+    Ok(playlist)
 }
 
-// 앱 상태 조회
 #[command]
-fn get_app_status() -> Result<AppStatus, String> {
-    let project_root = get_project_root();
-    let vault_path = project_root.join("vault");
-    let channels = list_channels().unwrap_or_default();
-    let videos = list_videos().unwrap_or_default();
-    
-    // Vault 크기 계산 (MB 단위로 반환)
-    let vault_size_bytes = calculate_directory_size(&vault_path);
-    let vault_size_mb = vault_size_bytes as f64 / (1024.0 * 1024.0);
-    
-    // 벡터 DB 상태 확인
-    let chroma_path = project_root.join("vault").join("90_indices").join("chroma");
-    let vector_db_status = if chroma_path.exists() {
-        "활성화됨".to_string()
-    } else {
-        "비활성화됨".to_string()
+fn remove_from_playlist(vault_lock: State<'_, VaultLockState>, playlist_id: String, video_id: String) -> Result<UserPlaylist, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let mut playlist = load_playlist(&playlist_id)?;
+    let before = playlist.video_ids.len();
+    playlist.video_ids.retain(|id| id != &video_id);
+    if playlist.video_ids.len() == before {
+        return Err(format!("재생목록에 없는 영상입니다: {}", video_id));
+    }
+    save_playlist(&mut playlist)?;
+    Ok(playlist)
+}
+
+// 재생 순서 변경 (기존 영상을 빠짐없이 새 순서로 전달해야 함)
+#[command]
+fn reorder_playlist(vault_lock: State<'_, VaultLockState>, playlist_id: String, video_ids: Vec<String>) -> Result<UserPlaylist, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let mut playlist = load_playlist(&playlist_id)?;
+    let mut current = playlist.video_ids.clone();
+    let mut requested = video_ids.clone();
+    current.sort();
+    requested.sort();
+    if current != requested {
+        return Err("재생목록이 그 사이 변경되었습니다. 목록을 새로고침한 뒤 다시 시도하세요".to_string());
+    }
+    playlist.video_ids = video_ids;
+    save_playlist(&mut playlist)?;
+    Ok(playlist)
+}
+
+#[command]
+fn rename_playlist(vault_lock: State<'_, VaultLockState>, playlist_id: String, name: String, description: Option<String>) -> Result<UserPlaylist, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("재생목록 이름을 입력하세요".to_string());
+    }
+    let mut playlist = load_playlist(&playlist_id)?;
+    playlist.name = name;
+    if let Some(description) = description {
+        playlist.description = description;
+    }
+    save_playlist(&mut playlist)?;
+    Ok(playlist)
+}
+
+#[command]
+fn delete_playlist(vault_lock: State<'_, VaultLockState>, playlist_id: String) -> Result<(), String> {
+    ensure_vault_writable(&vault_lock)?;
+    let path = playlist_file(&playlist_id)?;
+    fs::remove_file(&path).map_err(|e| format!("재생목록 삭제 실패: {}", e))
+}
+
+// 재생목록 목록 (최근 수정 순, 영상은 재생 순서대로 인덱스에서 조회)
+#[command]
+fn list_playlists() -> Result<Vec<UserPlaylistView>, String> {
+    let dir = get_playlists_dir();
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut playlists: Vec<UserPlaylist> = fs::read_dir(&dir)
+        .map_err(|e| e.to_string())?
+        .flatten()
+        .filter(|e| e.path().extension().map(|ext| ext == "json").unwrap_or(false))
+        .filter_map(|e| fs::read_to_string(e.path()).ok())
+        .filter_map(|c| serde_json::from_str(&c).ok())
+        .collect();
+    playlists.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+
+    let conn = open_index_db()?;
+    let mut stmt = conn
+        .prepare(&format!("{} WHERE v.video_id = ?1", INDEXED_VIDEO_SELECT))
+        .map_err(|e| format!("재생목록 영상 조회 실패: {}", e))?;
+    let mut views = Vec::with_capacity(playlists.len());
+    for playlist in playlists {
+        let mut videos = Vec::new();
+        let mut missing_video_ids = Vec::new();
+        for video_id in &playlist.video_ids {
+            match stmt.query_row([video_id], indexed_video_from_row) {
+                Ok(video) => videos.push(video),
+                Err(_) => missing_video_ids.push(video_id.clone()),
+            }
+        }
+        assign_video_playlists(&mut videos);
+        views.push(UserPlaylistView { playlist, videos, missing_video_ids });
+    }
+    Ok(views)
+}
+
+// 앱 재시작 후 이어보기용 UI 상태 (config/ui_session.json)
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+struct UiSession {
+    last_video_path: Option<String>,
+    playback_position_seconds: Option<f64>,
+    active_channel_filter: Option<String>,
+    active_tab: Option<String>,
+    search_query: Option<String>,
+    scroll_offset: Option<f64>,
+    scroll_anchor_video_path: Option<String>, // 목록이 바뀌어도 복원할 기준 영상
+    saved_at: Option<String>,
+}
+
+const UI_SESSION_FILE: &str = "ui_session.json";
+
+#[command]
+fn save_ui_session(state: UiSession) -> Result<(), String> {
+    let session = UiSession {
+        saved_at: Some(chrono::Local::now().to_rfc3339()),
+        ..state
     };
-    
-    // 마지막 다운로드 시간 (구현 필요)
-    let last_download = None; // TODO: 실제 구현
-    
-    Ok(AppStatus {
-        total_videos: videos.len() as u32,
-        total_channels: channels.len() as u32,
-        vault_size_mb: vault_size_mb,
-        last_download,
-        vector_db_status,
+    save_json_config(UI_SESSION_FILE, &session)
+}
+
+// 저장된 UI 상태 (마지막 영상이 삭제된 경우 재생 위치 제외)
+#[command]
+fn get_ui_session() -> Result<UiSession, String> {
+    let mut session: UiSession = load_json_config(UI_SESSION_FILE)?;
+    let project_root = get_project_root();
+    if let Some(path) = &session.last_video_path {
+        if !project_root.join(path).exists() {
+            session.last_video_path = None;
+            session.playback_position_seconds = None;
+        }
+    }
+    if let Some(path) = &session.scroll_anchor_video_path {
+        if !project_root.join(path).exists() {
+            session.scroll_anchor_video_path = None;
+        }
+    }
+    Ok(session)
+}
+
+// 재생 대기열 (config/play_queue.json에 저장, 데스크톱 UI/원격 기능이 공유)
+#[derive(Serialize, Deserialize, Clone)]
+struct QueueEntry {
+    video_id: String,
+    title: String,
+    video_path: String,
+    added_at: String,
+}
+
+const PLAY_QUEUE_FILE: &str = "play_queue.json";
+
+fn queue_entry_for(video_id: &str) -> Result<QueueEntry, String> {
+    let video = scan_vault_videos()?
+        .into_iter()
+        .find(|v| v.video_id.as_deref() == Some(video_id))
+        .ok_or_else(|| format!("영상을 찾을 수 없습니다: {}", video_id))?;
+    Ok(QueueEntry {
+        video_id: video_id.to_string(),
+        title: video.title,
+        video_path: video.video_path,
+        added_at: chrono::Local::now().to_rfc3339(),
+    })
+}
+
+// 대기열 변경 후 저장 및 변경 이벤트 전송
+fn update_play_queue<F>(window: &Window, state: &PlayQueueState, update: F) -> Result<Vec<QueueEntry>, String>
+where
+    F: FnOnce(&mut Vec<QueueEntry>) -> Result<(), String>,
+{
+    let mut entries = state.entries.lock().map_err(|e| e.to_string())?;
+    update(&mut entries)?;
+    save_json_config(PLAY_QUEUE_FILE, &*entries)?;
+    let _ = window.emit("play-queue-changed", &*entries);
+    Ok(entries.clone())
+}
+
+// 다음에 재생 (대기열 맨 앞, 이미 있으면 앞으로 이동)
+#[command]
+fn queue_next(window: Window, state: State<'_, PlayQueueState>, video_id: String) -> Result<Vec<QueueEntry>, String> {
+    let entry = queue_entry_for(&video_id)?;
+    update_play_queue(&window, &state, |entries| {
+        entries.retain(|e| e.video_id != video_id);
+        entries.insert(0, entry);
+        Ok(())
+    })
+}
+
+// 대기열 끝에 추가 (이미 있으면 무시)
+#[command]
+fn add_to_play_queue(window: Window, state: State<'_, PlayQueueState>, video_id: String) -> Result<Vec<QueueEntry>, String> {
+    let entry = queue_entry_for(&video_id)?;
+    update_play_queue(&window, &state, |entries| {
+        if !entries.iter().any(|e| e.video_id == video_id) {
+            entries.push(entry);
+        }
+        Ok(())
+    })
+}
+
+#[command]
+fn get_play_queue(state: State<'_, PlayQueueState>) -> Result<Vec<QueueEntry>, String> {
+    Ok(state.entries.lock().map_err(|e| e.to_string())?.clone())
+}
+
+// 대기열 순서 변경 (video_ids는 현재 대기열의 순열이어야 함)
+#[command]
+fn reorder_play_queue(window: Window, state: State<'_, PlayQueueState>, video_ids: Vec<String>) -> Result<Vec<QueueEntry>, String> {
+    update_play_queue(&window, &state, |entries| {
+        let mut current: Vec<&str> = entries.iter().map(|e| e.video_id.as_str()).collect();
+        let mut requested: Vec<&str> = video_ids.iter().map(|id| id.as_str()).collect();
+        current.sort_unstable();
+        requested.sort_unstable();
+        if current != requested {
+            return Err("대기열이 변경되었습니다. 다시 불러온 후 순서를 바꾸세요".to_string());
+        }
+        entries.sort_by_key(|e| video_ids.iter().position(|id| *id == e.video_id));
+        Ok(())
+    })
+}
+
+#[command]
+fn remove_from_play_queue(window: Window, state: State<'_, PlayQueueState>, video_id: String) -> Result<Vec<QueueEntry>, String> {
+    update_play_queue(&window, &state, |entries| {
+        entries.retain(|e| e.video_id != video_id);
+        Ok(())
+    })
+}
+
+// 대기열에서 다음 영상 꺼내기 (재생 종료 시)
+#[command]
+fn pop_play_queue(window: Window, state: State<'_, PlayQueueState>) -> Result<Option<QueueEntry>, String> {
+    let mut next = None;
+    update_play_queue(&window, &state, |entries| {
+        if !entries.is_empty() {
+            next = Some(entries.remove(0));
+        }
+        Ok(())
+    })?;
+    Ok(next)
+}
+
+#[command]
+fn clear_play_queue(window: Window, state: State<'_, PlayQueueState>) -> Result<(), String> {
+    update_play_queue(&window, &state, |entries| {
+        entries.clear();
+        Ok(())
+    })
+    .map(|_| ())
+}
+
+// 분리된 플레이어/자막 창 간 공유 재생 상태 (백엔드가 창 사이 동기화를 중계)
+#[derive(Serialize, Deserialize, Clone)]
+struct PlaybackSession {
+    video_id: String,
+    video_path: String,
+    title: String,
+    position_seconds: f64,
+    playing: bool,
+    #[serde(default)]
+    window_label: String,
+    #[serde(default)]
+    updated_at: String,
+}
+
+// 분리된 창에서 표시할 영상 정보
+#[derive(Serialize)]
+struct DetachedViewVideo {
+    video_id: String,
+    title: String,
+    channel: String,
+    video_path: String,
+    transcript: String,
+}
+
+fn player_window_label(video_id: &str) -> String {
+    format!("player-{}", sanitize_window_label(video_id))
+}
+
+fn transcript_window_label(video_id: &str) -> String {
+    format!("transcript-{}", sanitize_window_label(video_id))
+}
+
+// 창 라벨에는 영문/숫자/-/_ 만 허용
+fn sanitize_window_label(value: &str) -> String {
+    value.chars().map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' }).collect()
+}
+
+fn open_detached_window(app: &tauri::AppHandle, label: &str, url: String, title: &str, size: (f64, f64)) -> Result<(), String> {
+    if let Some(existing) = app.get_webview_window(label) {
+        existing.show().map_err(|e| e.to_string())?;
+        return existing.set_focus().map_err(|e| e.to_string());
+    }
+    tauri::WebviewWindowBuilder::new(app, label, tauri::WebviewUrl::App(url.into()))
+        .title(title)
+        .inner_size(size.0, size.1)
+        .build()
+        .map(|_| ())
+        .map_err(|e| format!("창을 열 수 없습니다: {}", e))
+}
+
+// 플레이어 전용 창 열기 (이미 열려 있으면 포커스 후 지정 위치로 이동)
+#[command]
+fn open_player_window(window: Window, video_id: String, timestamp: Option<f64>) -> Result<String, String> {
+    let video = queue_entry_for(&video_id)?;
+    let app = window.app_handle();
+    let label = player_window_label(&video_id);
+    let position = timestamp.unwrap_or(0.0).max(0.0);
+    let already_open = app.get_webview_window(&label).is_some();
+    let url = format!(
+        "index.html?view=player&video_id={}&t={}",
+        urlencoding::encode(&video_id),
+        position
+    );
+    open_detached_window(app, &label, url, &format!("▶ {}", video.title), (960.0, 540.0))?;
+    if already_open && timestamp.is_some() {
+        let _ = app.emit_to(label.as_str(), "playback-control", serde_json::json!({ "command": "seek", "position": position }));
+    }
+    Ok(label)
+}
+
+// 자막 전용 창 열기 (플레이어 창의 재생 위치를 playback-state-changed로 따라감)
+#[command]
+fn open_transcript_window(window: Window, video_id: String) -> Result<String, String> {
+    let video = queue_entry_for(&video_id)?;
+    let app = window.app_handle();
+    let label = transcript_window_label(&video_id);
+    let url = format!("index.html?view=transcript&video_id={}", urlencoding::encode(&video_id));
+    open_detached_window(app, &label, url, &format!("📝 {}", video.title), (480.0, 720.0))?;
+    Ok(label)
+}
+
+// 분리된 창이 처음 로드될 때 필요한 영상 경로와 자막
+#[command]
+fn get_detached_view_video(video_id: String) -> Result<DetachedViewVideo, String> {
+    let video = scan_vault_videos()?
+        .into_iter()
+        .find(|v| v.video_id.as_deref() == Some(video_id.as_str()))
+        .ok_or_else(|| format!("영상을 찾을 수 없습니다: {}", video_id))?;
+    let transcript = fs::read_to_string(get_project_root().join(&video.captions_path))
+        .map(|content| extract_transcript_text(&content))
+        .unwrap_or_default();
+    Ok(DetachedViewVideo {
+        video_id,
+        title: video.title,
+        channel: video.channel,
+        video_path: video.video_path,
+        transcript,
     })
 }
 
-fn calculate_directory_size(path: &PathBuf) -> u64 {
-    if !path.exists() {
-        return 0;
+// 자막-번역 정렬 관련 구조체들
+#[derive(Serialize)]
+struct TranscriptSegment {
+    start: f64,
+    end: f64,
+    text: String,
+}
+
+#[derive(Serialize)]
+struct AlignedSegment {
+    start: f64,
+    end: f64,
+    original: String,
+    translated: String, // 해당 구간에 겹치는 번역 자막 (없으면 빈 문자열)
+}
+
+#[derive(Serialize)]
+struct AlignedTranscript {
+    video_id: String,
+    original_language: String,
+    translated_language: String,
+    segments: Vec<AlignedSegment>,
+}
+
+// VTT/SRT 타임코드 (00:01:02.345 / 00:01:02,345 / 01:02.345) → 초
+fn parse_caption_timecode(value: &str) -> Option<f64> {
+    let value = value.trim().replace(',', ".");
+    let mut seconds = 0.0;
+    for part in value.split(':') {
+        seconds = seconds * 60.0 + part.parse::<f64>().ok()?;
+    }
+    Some(seconds)
+}
+
+// 타임코드가 있는 자막 파일(VTT/SRT)을 구간 목록으로 변환 (자동 자막의 반복 줄 제거)
+fn parse_timed_captions(content: &str) -> Vec<TranscriptSegment> {
+    let tag_re = Regex::new(r"<[^>]+>").unwrap();
+    let mut segments: Vec<TranscriptSegment> = Vec::new();
+    let mut lines = content.lines().peekable();
+    
+    while let Some(line) = lines.next() {
+        let Some((start, rest)) = line.split_once("-->") else { continue };
+        let end = rest.split_whitespace().next().unwrap_or("");
+        let (Some(start), Some(end)) = (parse_caption_timecode(start), parse_caption_timecode(end)) else { continue };
+        
+        let mut text_lines = Vec::new();
+        while let Some(next) = lines.peek() {
+            if next.trim().is_empty() {
+                break;
+            }
+            let cleaned = tag_re.replace_all(next, "").trim().to_string();
+            // 자동 자막은 이전 구간의 줄을 반복하므로 새 줄만 사용
+            let repeated = segments.last().map(|s| s.text.ends_with(&cleaned)).unwrap_or(false);
+            if !cleaned.is_empty() && !repeated && !text_lines.contains(&cleaned) {
+                text_lines.push(cleaned);
+            }
+            lines.next();
+        }
+        if !text_lines.is_empty() {
+            segments.push(TranscriptSegment { start, end, text: text_lines.join(" ") });
+        }
+    }
+    segments
+}
+
+// 영상 폴더의 타임코드 자막 파일 (언어 코드, 경로) - yt-dlp 이름 규칙: 제목.ko.vtt
+fn timed_caption_files(folder: &Path) -> Vec<(String, PathBuf)> {
+    let mut files: Vec<(String, PathBuf)> = fs::read_dir(folder)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| matches!(p.extension().and_then(|e| e.to_str()), Some("vtt") | Some("srt")))
+                .filter_map(|p| {
+                    let language = Path::new(p.file_stem()?).extension()?.to_string_lossy().to_string();
+                    Some((language, p))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files
+}
+
+// 플레이어용 자막 트랙 언어 (Vault에 복사된 captions.<언어>.vtt)
+fn caption_track_languages(folder: &Path) -> Vec<String> {
+    let mut languages: Vec<String> = timed_caption_files(folder)
+        .into_iter()
+        .filter(|(_, path)| {
+            path.extension().map(|e| e == "vtt").unwrap_or(false)
+                && path.file_name().map(|n| n.to_string_lossy().starts_with("captions.")).unwrap_or(false)
+        })
+        .map(|(language, _)| language)
+        .collect();
+    languages.dedup();
+    languages
+}
+
+fn caption_language_matches(file_language: &str, language: &str) -> bool {
+    file_language == language || subtitle_language_variants(language).iter().any(|v| v == file_language)
+}
+
+// 원문 구간마다 중간 시점이 그 구간에 속하는 번역 구간을 붙임
+fn align_segments(original: &[TranscriptSegment], translated: &[TranscriptSegment]) -> Vec<AlignedSegment> {
+    let mut aligned: Vec<AlignedSegment> = original
+        .iter()
+        .map(|s| AlignedSegment { start: s.start, end: s.end, original: s.text.clone(), translated: String::new() })
+        .collect();
+    if aligned.is_empty() {
+        return aligned;
     }
-    
-    let mut size = 0;
-    if let Ok(entries) = fs::read_dir(path) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.is_file() {
-                if let Ok(metadata) = fs::metadata(&path) {
-                    size += metadata.len();
-                }
-            } else if path.is_dir() {
-                size += calculate_directory_size(&path);
-            }
+    for segment in translated {
+        let middle = (segment.start + segment.end) / 2.0;
+        let index = original.partition_point(|o| o.start <= middle).saturating_sub(1);
+        let target = &mut aligned[index].translated;
+        if !target.is_empty() {
+            target.push(' ');
         }
+        target.push_str(&segment.text);
     }
-    size
+    aligned
 }
 
-// 채널별로 전체 비디오를 그룹핑하여 조회 (인기/최신 분리)
+// 원문 자막과 번역 자막을 타임코드 기준으로 정렬 (이중 자막 표시용)
 #[command]
-fn get_recent_videos_by_channel(limit_per_channel: Option<usize>) -> Result<RecentVideos, String> {
-    let videos = list_videos()?;
-    let _limit = limit_per_channel.unwrap_or(5);
-    
-    // 채널별로 그룹핑 (전체 비디오)
-    let mut channel_groups: HashMap<String, Vec<VideoInfo>> = HashMap::new();
-    
-    for video in videos {
-        let channel_name = video.channel.clone();
-        channel_groups.entry(channel_name).or_insert_with(Vec::new).push(video);
-    }
-    
-    // 각 채널의 전체 비디오를 반환 (프론트엔드에서 인기/최신 분리)
-    let mut channels: Vec<ChannelVideos> = channel_groups
+fn get_aligned_transcript(video_id: String, lang: String) -> Result<AlignedTranscript, String> {
+    let video = scan_vault_videos()?
         .into_iter()
-        .map(|(channel_name, videos)| {
-            ChannelVideos {
-                channel_name,
-                videos,
-            }
+        .find(|v| v.video_id.as_deref() == Some(video_id.as_str()))
+        .ok_or_else(|| format!("영상을 찾을 수 없습니다: {}", video_id))?;
+    let folder = get_project_root()
+        .join(&video.video_path)
+        .parent()
+        .map(Path::to_path_buf)
+        .ok_or("영상 폴더를 찾을 수 없습니다")?;
+    
+    let files = timed_caption_files(&folder);
+    let (translated_language, translated_path) = files
+        .iter()
+        .find(|(language, _)| caption_language_matches(language, &lang))
+        .cloned()
+        .ok_or_else(|| format!("'{}' 번역 자막 파일이 없습니다", lang))?;
+    // 원문: 채널 언어 자막 우선, 없으면 번역 언어가 아닌 첫 자막
+    let channel_language = get_channel_language(&video.channel);
+    let (original_language, original_path) = files
+        .iter()
+        .filter(|(language, _)| !caption_language_matches(language, &lang))
+        .max_by_key(|(language, _)| channel_language.as_deref().map(|c| caption_language_matches(language, c)).unwrap_or(false))
+        .cloned()
+        .ok_or("타임코드가 있는 원문 자막 파일이 없습니다")?;
+    
+    let read = |path: &Path| fs::read_to_string(path).map_err(|e| format!("자막 파일 읽기 실패 {}: {}", path.display(), e));
+    let original = parse_timed_captions(&read(&original_path)?);
+    let translated = parse_timed_captions(&read(&translated_path)?);
+    
+    Ok(AlignedTranscript {
+        video_id,
+        original_language,
+        translated_language,
+        segments: align_segments(&original, &translated),
+    })
+}
+
+// 영상별 자막 구간 인덱스 (재생 위치 → 현재 자막 줄 조회용, 처음 조회 시 생성)
+struct SegmentIndex {
+    caption_path: PathBuf,
+    modified: Option<std::time::SystemTime>,
+    segments: Vec<TranscriptSegment>,
+    last_used: Instant,
+}
+
+// 재생 중 자막 따라가기 캐시 (초당 4~10회 조회해도 자막 파일을 다시 파싱하지 않음)
+#[derive(Default, Clone)]
+struct SegmentIndexState {
+    indexes: Arc<Mutex<HashMap<String, SegmentIndex>>>,
+}
+
+// 캐시에 보관할 최대 영상 수 (초과 시 가장 오래 쓰지 않은 영상부터 제거)
+const SEGMENT_INDEX_CACHE_SIZE: usize = 16;
+
+#[derive(Serialize)]
+struct SegmentAtPosition {
+    index: usize,
+    start: f64,
+    end: f64,
+    text: String,
+    active: bool, // false면 구간 사이 공백 (직전 구간 반환)
+}
+
+fn file_modified(path: &Path) -> Option<std::time::SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+// 영상의 따라가기용 자막 파일 (채널 언어 자막 우선, 없으면 첫 타임코드 자막)
+fn segment_index_caption_path(video_id: &str) -> Result<PathBuf, String> {
+    let video = scan_vault_videos()?
+        .into_iter()
+        .find(|v| v.video_id.as_deref() == Some(video_id))
+        .ok_or_else(|| format!("영상을 찾을 수 없습니다: {}", video_id))?;
+    let folder = get_project_root()
+        .join(&video.video_path)
+        .parent()
+        .map(Path::to_path_buf)
+        .ok_or("영상 폴더를 찾을 수 없습니다")?;
+    preferred_timed_caption(&folder, &video.channel).ok_or_else(|| "타임코드가 있는 자막 파일이 없습니다".to_string())
+}
+
+// 영상 폴더의 대표 타임코드 자막 (채널 언어 자막 우선, 같으면 Vault 자막 트랙 우선)
+fn preferred_timed_caption(folder: &Path, channel: &str) -> Option<PathBuf> {
+    let channel_language = get_channel_language(channel);
+    timed_caption_files(folder)
+        .into_iter()
+        .max_by_key(|(language, path)| {
+            let preferred = channel_language.as_deref().map(|c| caption_language_matches(language, c)).unwrap_or(false);
+            let vault_track = path.file_name().map(|n| n.to_string_lossy().starts_with("captions.")).unwrap_or(false);
+            (preferred, vault_track)
         })
-        .collect();
-    
-    // 채널을 이름순으로 정렬
-    channels.sort_by(|a, b| a.channel_name.cmp(&b.channel_name));
-    
-    Ok(RecentVideos { channels })
+        .map(|(_, path)| path)
 }
 
-// 설정 관리
+fn build_segment_index(video_id: &str) -> Result<SegmentIndex, String> {
+    let caption_path = segment_index_caption_path(video_id)?;
+    let content = fs::read_to_string(&caption_path)
+        .map_err(|e| format!("자막 파일 읽기 실패 {}: {}", caption_path.display(), e))?;
+    Ok(SegmentIndex {
+        modified: file_modified(&caption_path),
+        segments: parse_timed_captions(&content),
+        caption_path,
+        last_used: Instant::now(),
+    })
+}
+
+// 재생 위치의 자막 구간 (첫 구간 이전이면 None, 자막 파일이 바뀌면 인덱스 재생성)
 #[command]
-fn get_config() -> Result<String, String> {
-    let project_root = get_project_root();
-    let config_path = project_root.join("pyproject.toml");
-    if config_path.exists() {
-        fs::read_to_string(&config_path).map_err(|e| e.to_string())
-    } else {
-        Ok("설정 파일이 없습니다".to_string())
+fn get_segment_at(state: State<'_, SegmentIndexState>, video_id: String, position: f64) -> Result<Option<SegmentAtPosition>, String> {
+    let mut indexes = state.indexes.lock().map_err(|e| e.to_string())?;
+    let stale = indexes
+        .get(&video_id)
+        .map(|index| file_modified(&index.caption_path) != index.modified)
+        .unwrap_or(true);
+    if stale {
+        let index = build_segment_index(&video_id)?;
+        if !indexes.contains_key(&video_id) && indexes.len() >= SEGMENT_INDEX_CACHE_SIZE {
+            let oldest = indexes.iter().min_by_key(|(_, index)| index.last_used).map(|(id, _)| id.clone());
+            if let Some(oldest) = oldest {
+                indexes.remove(&oldest);
+            }
+        }
+        indexes.insert(video_id.clone(), index);
     }
+    
+    let Some(index) = indexes.get_mut(&video_id) else { return Ok(None) };
+    index.last_used = Instant::now();
+    let position = position.max(0.0);
+    let found = index.segments.partition_point(|s| s.start <= position);
+    let Some(found) = found.checked_sub(1) else { return Ok(None) };
+    let segment = &index.segments[found];
+    Ok(Some(SegmentAtPosition {
+        index: found,
+        start: segment.start,
+        end: segment.end,
+        text: segment.text.clone(),
+        active: position < segment.end,
+    }))
 }
 
-// Range 요청을 지원하는 비디오 서버 시작
+// 플레이어 창이 재생 상태를 보고하면 모든 창에 전달
 #[command]
-async fn start_video_server(state: State<'_, VideoServerState>) -> Result<u16, String> {
-    let server_port_lock = state.server_port.read().await;
-    
-    // 이미 서버가 실행 중이면 포트 반환
-    if let Some(port) = *server_port_lock {
-        return Ok(port);
+fn update_playback_state(window: Window, state: State<'_, PlaybackSyncState>, mut session: PlaybackSession) -> Result<(), String> {
+    session.window_label = window.label().to_string();
+    session.updated_at = chrono::Local::now().to_rfc3339();
+    *state.session.lock().map_err(|e| e.to_string())? = Some(session.clone());
+    window.app_handle().emit("playback-state-changed", &session).map_err(|e| e.to_string())
+}
+
+#[command]
+fn get_playback_state(state: State<'_, PlaybackSyncState>) -> Result<Option<PlaybackSession>, String> {
+    Ok(state.session.lock().map_err(|e| e.to_string())?.clone())
+}
+
+// 다른 창(메인/자막)에서 플레이어 창 제어: play, pause, seek
+#[command]
+fn control_playback(window: Window, state: State<'_, PlaybackSyncState>, command: String, position: Option<f64>) -> Result<(), String> {
+    if !matches!(command.as_str(), "play" | "pause" | "seek") {
+        return Err(format!("지원하지 않는 재생 명령입니다: {}", command));
     }
-    drop(server_port_lock);
-    
-    let project_root = get_project_root();
-    
-    // 사용 가능한 포트 찾기 (OS가 자동 할당)
-    let port = find_available_port().await?;
-    
-    // Range 지원 파일 서빙 필터 생성
-    let files = warp::path("video")
-        .and(warp::path::tail())
-        .and(warp::get())
-        .and(warp::header::optional::<String>("range"))
-        .and_then(move |tail: warp::path::Tail, range: Option<String>| {
-            let project_root = project_root.clone();
-            async move {
-                serve_video_with_range(project_root, tail.as_str(), range).await
-            }
-        });
-    
-    // CORS 헤더 추가 (로컬 전용)
-    let cors = warp::cors()
-        .allow_origin("tauri://localhost")
-        .allow_origin("http://localhost:3000") // 개발용
-        .allow_headers(vec!["content-type", "range"])
-        .allow_methods(vec!["GET", "HEAD", "OPTIONS"]);
-    
-    let routes = files.with(cors);
-    
-    // 서버 시작 (127.0.0.1 바인딩으로 보안 강화)
-    let addr = SocketAddr::from(([127, 0, 0, 1], port));
-    let server = warp::serve(routes).run(addr);
-    
-    let handle = tokio::spawn(server);
-    
-    // 상태 업데이트
-    *state.server_port.write().await = Some(port);
-    *state.server_handle.write().await = Some(handle);
-    
-    Ok(port)
+    let target = state
+        .session
+        .lock()
+        .map_err(|e| e.to_string())?
+        .as_ref()
+        .map(|s| s.window_label.clone())
+        .ok_or("재생 중인 플레이어 창이 없습니다")?;
+    window
+        .app_handle()
+        .emit_to(target.as_str(), "playback-control", serde_json::json!({ "command": command, "position": position }))
+        .map_err(|e| e.to_string())
 }
 
-// Range 요청을 지원하는 비디오 파일 서빙
-async fn serve_video_with_range(
-    project_root: PathBuf, 
-    file_path: &str, 
-    range_header: Option<String>
-) -> Result<impl warp::Reply, warp::Rejection> {
-    use warp::http::StatusCode;
-    use std::io::{Read, Seek, SeekFrom};
-    
-    // 보안: 경로 탐색 공격 방지
-    let cleaned_path = file_path.replace("..", "");
-    let safe_path = cleaned_path.trim_start_matches('/');
-    
-    // URL 디코딩 처리
-    let decoded_path = match urlencoding::decode(safe_path) {
-        Ok(decoded) => decoded.to_string(),
-        Err(_) => safe_path.to_string()
-    };
-    
-    // vault/ 경로를 올바르게 매핑
-    let full_path = project_root.join("vault").join(&decoded_path);
-    
-    if !full_path.exists() || !full_path.is_file() {
-        return Err(warp::reject::not_found());
+// 재생 이어받기 대상 (앱 내 플레이어 / 분리된 창 / 시스템 플레이어)
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum HandoffTarget {
+    Embedded,
+    Detached,
+    System,
+}
+
+#[derive(Serialize, Clone)]
+struct PlaybackHandoff {
+    video_id: String,
+    video_path: String,
+    position_seconds: f64,
+    target: HandoffTarget,
+    // 시스템 플레이어가 시작 위치 인자를 지원하지 않아 처음부터 재생되는 경우 false
+    position_applied: bool,
+    player: Option<String>,
+}
+
+// 시작 위치 인자를 지원하는 외부 플레이어 (먼저 찾은 것을 사용)
+fn system_player_with_start_time(full_path: &Path, position: f64) -> Option<(String, Command)> {
+    let start = format!("{:.1}", position);
+    let mut candidates: Vec<(String, Vec<String>)> = vec![
+        ("mpv".to_string(), vec![format!("--start={}", start)]),
+        ("vlc".to_string(), vec![format!("--start-time={}", start)]),
+    ];
+    if cfg!(target_os = "macos") {
+        candidates.push(("/Applications/IINA.app/Contents/MacOS/iina-cli".to_string(), vec![format!("--mpv-start={}", start)]));
+        candidates.push(("/Applications/VLC.app/Contents/MacOS/VLC".to_string(), vec![format!("--start-time={}", start)]));
+    }
+    candidates.into_iter().find_map(|(program, args)| {
+        let available = if program.starts_with('/') {
+            Path::new(&program).exists()
+        } else {
+            Command::new(&program).arg("--version").stdout(Stdio::null()).stderr(Stdio::null()).status().is_ok()
+        };
+        if !available {
+            return None;
+        }
+        let mut cmd = Command::new(&program);
+        cmd.args(&args).arg(full_path);
+        let name = Path::new(&program).file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or(program);
+        Some((name, cmd))
+    })
+}
+
+// 현재 재생을 다른 플레이어로 넘기기 (위치 유지, 넘기기 전까지의 시청 시간은 한 번만 기록)
+#[command]
+async fn handoff_playback(
+    window: Window,
+    state: State<'_, PlaybackSyncState>,
+    video_id: String,
+    position: f64,
+    target: HandoffTarget,
+    watched_seconds: Option<f64>,
+) -> Result<PlaybackHandoff, String> {
+    let video = queue_entry_for(&video_id)?;
+    let position = position.max(0.0);
+    let app = window.app_handle().clone();
+    
+    // 넘기는 쪽 플레이어가 아직 보고하지 않은 재생 시간 (읽기 전용 모드면 기록하지 않음)
+    let read_only = app.state::<VaultLockState>().read_only.load(Ordering::SeqCst);
+    if let Some(seconds) = watched_seconds.filter(|s| *s >= 1.0 && !read_only) {
+        insert_playback_log(&video.video_path, seconds)?;
     }
     
-    // MIME 타입 추정 (비디오 파일에 대해 명시적으로 설정)
-    let mime_type = if full_path.extension().map(|ext| ext == "mp4").unwrap_or(false) {
-        "video/mp4".to_string()
-    } else {
-        mime_guess::from_path(&full_path)
-            .first_or_octet_stream()
-            .to_string()
-    };
-    
-    // 파일 크기 확인
-    let file_size = match std::fs::metadata(&full_path) {
-        Ok(metadata) => metadata.len(),
-        Err(_) => return Err(warp::reject::not_found()),
-    };
-    
-    // Range 헤더 파싱
-    let (start, end) = parse_range_header(range_header.as_deref(), file_size);
-    let content_length = end - start + 1;
-    
-    // 파일 읽기
-    let mut file = match std::fs::File::open(&full_path) {
-        Ok(f) => f,
-        Err(_) => return Err(warp::reject::not_found()),
+    let detached_label = player_window_label(&video_id);
+    let (position_applied, player) = match target {
+        HandoffTarget::Embedded => (true, Some("main".to_string())),
+        HandoffTarget::Detached => {
+            let label = open_player_window(window.clone(), video_id.clone(), Some(position))?;
+            (true, Some(label))
+        }
+        HandoffTarget::System => {
+            let full_path = get_project_root().join(&video.video_path);
+            if !full_path.exists() {
+                return Err(format!("비디오 파일을 찾을 수 없습니다: {}", full_path.display()));
+            }
+            match system_player_with_start_time(&full_path, position) {
+                Some((name, mut cmd)) => {
+                    cmd.spawn().map_err(|e| format!("{} 실행 실패: {}", name, e))?;
+                    (true, Some(name))
+                }
+                None => {
+                    open_in_system_player(video.video_path.clone()).await?;
+                    (false, None)
+                }
+            }
+        }
     };
     
-    // 시작 위치로 이동
-    if let Err(_) = file.seek(SeekFrom::Start(start)) {
-        return Err(warp::reject::not_found());
+    // 분리된 창에서 다른 곳으로 넘기면 창을 닫음
+    if target != HandoffTarget::Detached {
+        if let Some(detached) = app.get_webview_window(&detached_label) {
+            let _ = detached.close();
+        }
     }
     
-    // 요청된 범위만큼 읽기
-    let mut buffer = vec![0u8; content_length as usize];
-    if let Err(_) = file.read_exact(&mut buffer) {
-        return Err(warp::reject::not_found());
-    }
+    // 이어보기 위치를 UI 세션에도 반영 (앱 재시작 후 같은 위치에서 재개)
+    let mut ui_session: UiSession = load_json_config(UI_SESSION_FILE).unwrap_or_default();
+    ui_session.last_video_path = Some(video.video_path.clone());
+    ui_session.playback_position_seconds = Some(position);
+    save_ui_session(ui_session)?;
     
-    // HTTP 응답 생성 (warp::reply::Response 사용)
-    use warp::http::Response;
+    {
+        let mut session = state.session.lock().map_err(|e| e.to_string())?;
+        // 시스템 플레이어는 재생 상태를 보고하지 않으므로 공유 세션 종료
+        *session = (target != HandoffTarget::System).then(|| PlaybackSession {
+            video_id: video_id.clone(),
+            video_path: video.video_path.clone(),
+            title: video.title.clone(),
+            position_seconds: position,
+            playing: true,
+            window_label: player.clone().unwrap_or_default(),
+            updated_at: chrono::Local::now().to_rfc3339(),
+        });
+    }
     
-    let status_code = if range_header.is_some() && (start != 0 || end + 1 != file_size) {
-        StatusCode::PARTIAL_CONTENT
-    } else {
-        StatusCode::OK
+    let handoff = PlaybackHandoff {
+        video_id,
+        video_path: video.video_path,
+        position_seconds: position,
+        target,
+        position_applied,
+        player,
     };
-    
-    let mut response_builder = Response::builder()
-        .status(status_code)
-        .header("content-type", mime_type)
-        .header("accept-ranges", "bytes")
-        .header("access-control-allow-origin", "*")
-        .header("access-control-allow-methods", "GET, HEAD, OPTIONS")
-        .header("access-control-allow-headers", "range")
-        .header("cache-control", "no-cache");
-    
-    if range_header.is_some() && (start != 0 || end + 1 != file_size) {
-        response_builder = response_builder
-            .header("content-range", format!("bytes {}-{}/{}", start, end, file_size))
-            .header("content-length", content_length.to_string());
-    } else {
-        response_builder = response_builder
-            .header("content-length", file_size.to_string());
+    app.emit("playback-handoff", &handoff).map_err(|e| e.to_string())?;
+    Ok(handoff)
+}
+
+// 중요 영상 재노출(간격 반복) 관련 구조체들
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+struct ResurfaceSettings {
+    intervals_days: Vec<u32>, // 표시 후 1주 → 1달 → 3달
+    notify: bool,
+}
+
+impl Default for ResurfaceSettings {
+    fn default() -> Self {
+        Self {
+            intervals_days: vec![7, 30, 90],
+            notify: true,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct ResurfaceItem {
+    title: String,
+    marked_at: String,
+    stage: usize,                 // 지금까지 복습한 횟수
+    next_due_at: Option<String>,  // None이면 모든 간격 완료
+    last_reviewed_at: Option<String>,
+    notified_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct ResurfacedVideo {
+    video_path: String,
+    title: String,
+    marked_at: String,
+    stage: usize,
+    due_at: String,
+}
+
+const RESURFACE_SETTINGS_FILE: &str = "resurface_settings.json";
+const RESURFACE_ITEMS_FILE: &str = "resurface.json";
+const RESURFACE_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+fn resurface_due_at(from: chrono::DateTime<chrono::Local>, settings: &ResurfaceSettings, stage: usize) -> Option<String> {
+    settings
+        .intervals_days
+        .get(stage)
+        .map(|days| (from + chrono::Duration::days(*days as i64)).to_rfc3339())
+}
+
+fn due_resurfaced_videos() -> Result<Vec<ResurfacedVideo>, String> {
+    let items: BTreeMap<String, ResurfaceItem> = load_json_config(RESURFACE_ITEMS_FILE)?;
+    let now = chrono::Local::now();
+    Ok(items
+        .into_iter()
+        .filter_map(|(video_path, item)| {
+            let due_at = item.next_due_at?;
+            let due = chrono::DateTime::parse_from_rfc3339(&due_at).ok()?;
+            (due <= now).then_some(ResurfacedVideo {
+                video_path,
+                title: item.title,
+                marked_at: item.marked_at,
+                stage: item.stage,
+                due_at,
+            })
+        })
+        .collect())
+}
+
+// 중요 표시/해제 (표시 시 첫 간격 후 재노출 예약)
+#[command]
+fn mark_video_important(video_path: String, important: bool) -> Result<(), String> {
+    let mut items: BTreeMap<String, ResurfaceItem> = load_json_config(RESURFACE_ITEMS_FILE)?;
+    if !important {
+        items.remove(&video_path);
+        return save_json_config(RESURFACE_ITEMS_FILE, &items);
+    }
+    if items.contains_key(&video_path) {
+        return Ok(());
     }
     
-         match response_builder.body(buffer) {
-         Ok(response) => Ok(response),
-         Err(_) => Err(warp::reject::custom(ServerError)),
-     }
+    let title = scan_vault_videos()
+        .unwrap_or_default()
+        .into_iter()
+        .find(|v| v.video_path == video_path)
+        .map(|v| v.title)
+        .ok_or_else(|| format!("영상을 찾을 수 없습니다: {}", video_path))?;
+    let settings: ResurfaceSettings = load_json_config(RESURFACE_SETTINGS_FILE)?;
+    let now = chrono::Local::now();
+    items.insert(
+        video_path,
+        ResurfaceItem {
+            title,
+            marked_at: now.to_rfc3339(),
+            stage: 0,
+            next_due_at: resurface_due_at(now, &settings, 0),
+            last_reviewed_at: None,
+            notified_at: None,
+        },
+    );
+    save_json_config(RESURFACE_ITEMS_FILE, &items)
 }
 
-// Range 헤더 파싱 함수
-fn parse_range_header(range_header: Option<&str>, file_size: u64) -> (u64, u64) {
-    if let Some(range) = range_header {
-        if let Some(range_value) = range.strip_prefix("bytes=") {
-            if let Some((start_str, end_str)) = range_value.split_once('-') {
-                let start = start_str.parse::<u64>().unwrap_or(0);
-                let end = if end_str.is_empty() {
-                    file_size - 1
-                } else {
-                    end_str.parse::<u64>().unwrap_or(file_size - 1).min(file_size - 1)
-                };
-                return (start, end);
+// 중요 표시된 영상 목록 (예약 상태 포함)
+#[command]
+fn get_important_videos() -> Result<BTreeMap<String, ResurfaceItem>, String> {
+    load_json_config(RESURFACE_ITEMS_FILE)
+}
+
+// 지금 다시 볼 차례인 영상
+#[command]
+fn get_resurfaced_videos() -> Result<Vec<ResurfacedVideo>, String> {
+    due_resurfaced_videos()
+}
+
+// 재노출된 영상 복습 완료 → 다음 간격으로 예약
+#[command]
+fn review_resurfaced_video(video_path: String) -> Result<Option<String>, String> {
+    let mut items: BTreeMap<String, ResurfaceItem> = load_json_config(RESURFACE_ITEMS_FILE)?;
+    let settings: ResurfaceSettings = load_json_config(RESURFACE_SETTINGS_FILE)?;
+    let item = items.get_mut(&video_path).ok_or_else(|| format!("중요 표시된 영상이 아닙니다: {}", video_path))?;
+    
+    let now = chrono::Local::now();
+    item.stage += 1;
+    item.last_reviewed_at = Some(now.to_rfc3339());
+    item.next_due_at = resurface_due_at(now, &settings, item.stage);
+    item.notified_at = None;
+    let next_due_at = item.next_due_at.clone();
+    
+    save_json_config(RESURFACE_ITEMS_FILE, &items)?;
+    Ok(next_due_at)
+}
+
+#[command]
+fn get_resurface_settings() -> Result<ResurfaceSettings, String> {
+    load_json_config(RESURFACE_SETTINGS_FILE)
+}
+
+#[command]
+fn save_resurface_settings(settings: ResurfaceSettings) -> Result<(), String> {
+    if settings.intervals_days.is_empty() || settings.intervals_days.contains(&0) {
+        return Err("재노출 간격은 1일 이상이어야 합니다".to_string());
+    }
+    save_json_config(RESURFACE_SETTINGS_FILE, &settings)
+}
+
+// 재노출 시점이 된 영상을 프론트엔드에 알림 (영상당 예약마다 1회)
+fn spawn_resurface_scheduler(app: tauri::AppHandle) {
+    thread::spawn(move || loop {
+        thread::sleep(RESURFACE_CHECK_INTERVAL);
+        
+        let settings: ResurfaceSettings = load_json_config(RESURFACE_SETTINGS_FILE).unwrap_or_default();
+        if !settings.notify {
+            continue;
+        }
+        let Ok(mut items) = load_json_config::<BTreeMap<String, ResurfaceItem>>(RESURFACE_ITEMS_FILE) else {
+            continue;
+        };
+        let due: Vec<ResurfacedVideo> = due_resurfaced_videos()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|v| items.get(&v.video_path).map(|i| i.notified_at.is_none()).unwrap_or(false))
+            .collect();
+        if due.is_empty() {
+            continue;
+        }
+        
+        let _ = app.emit("resurface-due", &due);
+        let now = chrono::Local::now().to_rfc3339();
+        for video in &due {
+            if let Some(item) = items.get_mut(&video.video_path) {
+                item.notified_at = Some(now.clone());
             }
         }
-    }
-    (0, file_size - 1)
+        if let Err(e) = save_json_config(RESURFACE_ITEMS_FILE, &items) {
+            eprintln!("⚠️ 재노출 알림 상태 저장 실패: {}", e);
+        }
+    });
 }
 
-// 사용 가능한 포트 찾기
-async fn find_available_port() -> Result<u16, String> {
-    use std::net::TcpListener;
+// 모든 채팅 세션 파일 삭제
+#[command]
+async fn clear_all_sessions(vault_lock: State<'_, VaultLockState>) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let project_root = get_project_root();
+    let sessions_dir = project_root.join("vault").join("90_indices").join("search_sessions");
     
-    // OS가 자동으로 할당하는 방식 (포트 0 사용)
-    match TcpListener::bind("127.0.0.1:0") {
-        Ok(listener) => {
-            let port = listener.local_addr().unwrap().port();
-            drop(listener); // 바로 해제
-            Ok(port)
-        }
-        Err(_) => {
-            // fallback: 수동으로 포트 검색
-            for port in 8080..8090 {
-                if TcpListener::bind(format!("127.0.0.1:{}", port)).is_ok() {
-                    return Ok(port);
+    if !sessions_dir.exists() {
+        return Ok("삭제할 세션이 없습니다.".to_string());
+    }
+    
+    let mut deleted_count = 0;
+    
+    // 세션 파일들 삭제
+    let entries = std::fs::read_dir(&sessions_dir).map_err(|e| e.to_string())?;
+    
+    for entry in entries {
+        if let Ok(entry) = entry {
+            let path = entry.path();
+            if let Some(filename) = path.file_name().and_then(|f| f.to_str()) {
+                // session_으로 시작하고 .json으로 끝나는 파일만 삭제
+                if filename.starts_with("session_") && filename.ends_with(".json") {
+                    if let Err(e) = std::fs::remove_file(&path) {
+                        eprintln!("세션 파일 삭제 실패 {}: {}", path.display(), e);
+                    } else {
+                        deleted_count += 1;
+                    }
                 }
             }
-            Err("사용 가능한 포트를 찾을 수 없습니다".to_string())
         }
     }
+    
+    Ok(format!("{}개의 세션 파일을 삭제했습니다.", deleted_count))
 }
 
-// 비디오 서버 중지
+// 모든 채널의 무결성 검사 (개별 채널별)
 #[command]
-async fn stop_video_server(state: State<'_, VideoServerState>) -> Result<(), String> {
-    let mut server_handle_lock = state.server_handle.write().await;
-    let mut server_port_lock = state.server_port.write().await;
+async fn check_channel_integrity(channel_name: String) -> Result<String, String> {
+    let project_root = get_project_root();
+    let integrity_script = project_root.join("vault").join("90_indices").join("integrity_check.py");
     
-    if let Some(handle) = server_handle_lock.take() {
-        handle.abort();
+    if !integrity_script.exists() {
+        return Err(format!("정합성 검사 스크립트를 찾을 수 없습니다: {}", integrity_script.display()));
     }
     
-    *server_port_lock = None;
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    if !venv_python.exists() {
+        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    }
     
-    Ok(())
+    let output = Command::new(&venv_python)
+        .args(&[integrity_script.to_str().unwrap(), "--channel", &channel_name])
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| e.to_string())?;
+    
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(format!("✅ {} 채널 정합성 검사 완료\n{}", channel_name, stdout))
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!("{} 채널 정합성 검사 실패: {}", channel_name, stderr))
+    }
 }
 
-// 비디오 서버 상태 확인
+// RAG 컨트롤러 상태 조회
 #[command]
-async fn get_video_server_status(state: State<'_, VideoServerState>) -> Result<Option<u16>, String> {
-    let server_port_lock = state.server_port.read().await;
-    Ok(*server_port_lock)
+async fn get_rag_controller_status() -> Result<String, String> {
+    let project_root = get_project_root();
+    let rag_script = project_root.join("vault").join("90_indices").join("rag.py");
+    
+    if !rag_script.exists() {
+        return Err("RAG 스크립트를 찾을 수 없습니다".to_string());
+    }
+    
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    let output = Command::new(&venv_python)
+        .args(&[rag_script.to_str().unwrap(), "status"])
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| e.to_string())?;
+    
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!("RAG 상태 조회 실패: {}", stderr))
+    }
 }
 
-// 비디오 URL 생성
+// RAG 캐시 정리
 #[command]
-async fn get_video_url(video_path: String, state: State<'_, VideoServerState>) -> Result<String, String> {
-    let server_port_lock = state.server_port.read().await;
+async fn clear_rag_cache(vault_lock: State<'_, VaultLockState>) -> Result<String, String> {
+    ensure_vault_writable(&vault_lock)?;
+    let project_root = get_project_root();
+    let rag_script = project_root.join("vault").join("90_indices").join("rag.py");
     
-    if let Some(port) = *server_port_lock {
-        // vault/ 경로 제거하고 HTTP URL 생성
-        let clean_path = video_path.trim_start_matches("vault/");
-        
-        // URL 인코딩 처리 - 특수문자와 한글 문자 처리
-        let encoded_path = urlencoding::encode(clean_path).to_string();
-        
-        Ok(format!("http://127.0.0.1:{}/video/{}", port, encoded_path))
+    if !rag_script.exists() {
+        return Err("RAG 스크립트를 찾을 수 없습니다".to_string());
+    }
+    
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    let output = Command::new(&venv_python)
+        .args(&[rag_script.to_str().unwrap(), "clear-cache"])
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| e.to_string())?;
+    
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
     } else {
-        Err("비디오 서버가 실행되지 않았습니다. 먼저 서버를 시작해주세요.".to_string())
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!("RAG 캐시 정리 실패: {}", stderr))
     }
 }
 
-// 시스템 플레이어로 비디오 열기
+// 고급 검색 설정 (RAG 컨트롤러 기반)
 #[command]
-async fn open_in_system_player(video_path: String) -> Result<(), String> {
+async fn advanced_rag_search(
+    query: String, 
+    channel_name: String, 
+    model: String,
+    search_config: Option<String>
+) -> Result<String, String> {
     let project_root = get_project_root();
-    let full_path = project_root.join(&video_path);
+    let rag_script = project_root.join("vault").join("90_indices").join("rag.py");
     
-    if !full_path.exists() {
-        return Err(format!("비디오 파일을 찾을 수 없습니다: {}", full_path.display()));
+    if !rag_script.exists() {
+        return Err("RAG 스크립트를 찾을 수 없습니다".to_string());
     }
     
-    // 운영체제별 명령어 실행
-    #[cfg(target_os = "macos")]
-    {
-        Command::new("open")
-            .arg(&full_path)
-            .spawn()
-            .map_err(|e| format!("macOS 시스템 플레이어 실행 실패: {}", e))?;
-    }
+    let venv_python = project_root.join("venv").join("bin").join("python");
     
-    #[cfg(target_os = "windows")]
-    {
-        Command::new("cmd")
-            .args(&["/C", "start", "", &full_path.to_string_lossy()])
-            .spawn()
-            .map_err(|e| format!("Windows 시스템 플레이어 실행 실패: {}", e))?;
+    let mut args = vec![
+        rag_script.to_str().unwrap().to_string(),
+        query,
+        channel_name,
+        "--model".to_string(),
+        model
+    ];
+    
+    // 고급 검색 설정이 있는 경우 추가
+    if let Some(config) = search_config {
+        args.push("--config".to_string());
+        args.push(config);
     }
     
-    #[cfg(target_os = "linux")]
-    {
-        Command::new("xdg-open")
-            .arg(&full_path)
-            .spawn()
-            .map_err(|e| format!("Linux 시스템 플레이어 실행 실패: {}", e))?;
+    let output = Command::new(&venv_python)
+        .args(&args)
+        .current_dir(&project_root)
+        .output()
+        .map_err(|e| e.to_string())?;
+    
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!("고급 RAG 검색 실패: {}", stderr))
+    }
+}
+
+// 설정 파일 경로 헬퍼 함수
+fn get_settings_file_path() -> PathBuf {
+    let project_root = get_project_root();
+    project_root.join("config").join("rag_settings.json")
+}
+
+// 설정 디렉토리 확인 및 생성
+fn ensure_config_directory() -> Result<(), String> {
+    let config_dir = get_project_root().join("config");
+    if !config_dir.exists() {
+        fs::create_dir_all(&config_dir)
+            .map_err(|e| format!("설정 디렉토리 생성 실패: {}", e))?;
     }
-    
-    println!("🎬 시스템 플레이어로 비디오 열기: {}", full_path.display());
     Ok(())
 }
 
-// 비디오 변환 관련 함수들
+// config/ 아래 JSON 설정 파일 경로
+fn get_config_file_path(file_name: &str) -> PathBuf {
+    get_project_root().join("config").join(file_name)
+}
 
-#[command]
-async fn convert_video_file(
-    window: Window,
-    video_path: String, 
-    quality: String,
-    codec: String,
-    backup: bool,
-    state: State<'_, ConversionState>
-) -> Result<String, String> {
-    // 이미 변환 중인지 확인
-    if state.is_converting.load(Ordering::Relaxed) {
-        return Err("이미 변환이 진행 중입니다".to_string());
+// JSON 설정 파일 로드 (파일이 없으면 기본값)
+fn load_json_config<T: serde::de::DeserializeOwned + Default>(file_name: &str) -> Result<T, String> {
+    let path = get_config_file_path(file_name);
+    if !path.exists() {
+        return Ok(T::default());
     }
     
-    let project_root = get_project_root();
-    let video_full_path = project_root.join(&video_path);
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("설정 파일 읽기 실패 {}: {}", path.display(), e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("설정 파일 파싱 실패 {}: {}", path.display(), e))
+}
+
+// JSON 설정 파일 저장
+fn save_json_config<T: Serialize>(file_name: &str, value: &T) -> Result<(), String> {
+    ensure_config_directory()?;
     
-    if !video_full_path.exists() {
-        return Err(format!("비디오 파일을 찾을 수 없습니다: {}", video_full_path.display()));
-    }
+    let json = serde_json::to_string_pretty(value)
+        .map_err(|e| format!("설정 직렬화 실패: {}", e))?;
+    fs::write(get_config_file_path(file_name), json)
+        .map_err(|e| format!("설정 파일 저장 실패: {}", e))
+}
+
+// RAG 설정 저장
+#[command]
+async fn save_rag_settings(settings: RAGSettings) -> Result<String, String> {
+    ensure_config_directory()?;
     
-    // 변환 시작
-    state.is_converting.store(true, Ordering::Relaxed);
+    let settings_path = get_settings_file_path();
+    let settings_json = serde_json::to_string_pretty(&settings)
+        .map_err(|e| format!("설정 직렬화 실패: {}", e))?;
     
-    // Python 가상환경 경로 찾기
-    let venv_path = project_root.join("venv");
-    let python_path = if venv_path.exists() {
-        #[cfg(target_os = "windows")]
-        {
-            venv_path.join("Scripts").join("python.exe")
-        }
-        #[cfg(not(target_os = "windows"))]
-        {
-            venv_path.join("bin").join("python")
-        }
-    } else {
-        PathBuf::from("python")
-    };
+    fs::write(&settings_path, settings_json)
+        .map_err(|e| format!("설정 파일 저장 실패: {}", e))?;
     
-    // ydh convert-single 명령어 구성
-    let mut cmd = Command::new(&python_path);
-    cmd.arg("-m")
-       .arg("ydh")
-       .arg("convert-single")
-       .arg(&video_full_path)
-       .arg("--quality")
-       .arg(&quality)
-       .arg("--codec")
-       .arg(&codec);
+    println!("✅ RAG 설정이 저장되었습니다: {}", settings_path.display());
+    Ok(format!("설정이 성공적으로 저장되었습니다: {}", settings_path.display()))
+}
+
+// RAG 설정 로드
+#[command]
+async fn load_rag_settings() -> Result<RAGSettings, String> {
+    let settings_path = get_settings_file_path();
     
-    if backup {
-        cmd.arg("--backup");
-    } else {
-        cmd.arg("--no-backup");
+    if !settings_path.exists() {
+        println!("🔧 설정 파일이 없어 기본값을 반환합니다: {}", settings_path.display());
+        return Ok(RAGSettings::default());
     }
     
-    cmd.current_dir(&project_root)
-       .stdout(Stdio::piped())
-       .stderr(Stdio::piped());
+    let settings_content = fs::read_to_string(&settings_path)
+        .map_err(|e| format!("설정 파일 읽기 실패: {}", e))?;
     
-    // 명령어 실행
-    let child = cmd.spawn().map_err(|e| {
-        state.is_converting.store(false, Ordering::Relaxed);
-        format!("Python 프로세스 시작 실패: {}", e)
-    })?;
+    let settings: RAGSettings = serde_json::from_str(&settings_content)
+        .map_err(|e| format!("설정 파일 파싱 실패: {}", e))?;
     
-    // 프로세스 저장
-    {
-        let mut process_guard = state.current_process.lock().unwrap();
-        *process_guard = Some(child);
-    }
+    println!("✅ RAG 설정이 로드되었습니다: {}", settings_path.display());
+    Ok(settings)
+}
+
+// RAG 설정 초기화 (기본값으로 리셋)
+#[command]
+async fn reset_rag_settings() -> Result<String, String> {
+    let default_settings = RAGSettings::default();
+    save_rag_settings(default_settings).await
+}
+
+// 설정 프리셋 적용
+#[command]
+async fn apply_rag_preset(preset_name: String) -> Result<RAGSettings, String> {
+    let settings = match preset_name.as_str() {
+        "default" => RAGSettings::default(),
+        "fast" => {
+            let mut settings = RAGSettings::default();
+            settings.fast_mode = true;
+            settings.search_config.enable_rerank = false;
+            settings.search_config.enable_rag_fusion = false;
+            settings.search_config.max_results = 8;
+            settings.answer_config.enable_self_refine = false;
+            settings.answer_config.max_tokens = 600;
+            settings
+        },
+        "quality" => {
+            let mut settings = RAGSettings::default();
+            settings.search_config.enable_rerank = true;
+            settings.search_config.enable_rag_fusion = true;
+            settings.search_config.max_results = 20;
+            settings.search_config.rerank_top_k = 8;
+            settings.answer_config.enable_self_refine = true;
+            settings.answer_config.enable_react = true;
+            settings.answer_config.max_tokens = 1200;
+            settings
+        },
+        "research" => {
+            let mut settings = RAGSettings::default();
+            settings.debug_mode = true;
+            settings.search_config.similarity_threshold = 0.05;
+            settings.search_config.max_results = 25;
+            settings.search_config.enable_rag_fusion = true;
+            settings.search_config.rag_fusion_queries = 6;
+            settings.answer_config.style = AnswerStyle::Analytical;
+            settings.answer_config.enable_react = true;
+            settings.answer_config.max_tokens = 1500;
+            settings.ui_preferences.show_advanced_settings = true;
+            settings.ui_preferences.show_debug_info = true;
+            settings.ui_preferences.auto_expand_sources = true;
+            settings
+        },
+        _ => return Err(format!("알 수 없는 프리셋: {}", preset_name))
+    };
     
-    // 별도 스레드에서 출력 모니터링
-    let window_clone = window.clone();
-    let state_clone = state.inner().clone();
-    let video_path_clone = video_path.clone();
+    save_rag_settings(settings.clone()).await?;
+    Ok(settings)
+}
+
+// 설정 파일 존재 여부 확인
+#[command]
+async fn check_rag_settings_exists() -> Result<bool, String> {
+    let settings_path = get_settings_file_path();
+    Ok(settings_path.exists())
+}
+
+// 설정 검증
+#[command]
+async fn validate_rag_settings(settings: RAGSettings) -> Result<RAGSettings, String> {
+    // 범위 검증 및 수정
+    let mut validated = settings;
     
-    tokio::spawn(async move {
-        let mut child = {
-            let mut process_guard = state_clone.current_process.lock().unwrap();
-            process_guard.take()
-        }.unwrap();
-        
-        // stderr에서 출력 읽기 (FFmpeg 출력)
-        if let Some(stderr) = child.stderr.take() {
-            let reader = BufReader::new(stderr);
-            
-            for line in reader.lines() {
-                if let Ok(line) = line {
-                    // 변환 진행 상황 파싱
-                    let progress = parse_conversion_progress(&line);
-                    
-                    let conversion_progress = DownloadProgress {
-                        channel: "변환".to_string(),
-                        status: "변환 중".to_string(),
-                        progress,
-                        current_video: video_path_clone.clone(),
-                        total_videos: 1,
-                        completed_videos: 0,
-                        log_message: line,
-                    };
-                    
-                    let _ = window_clone.emit("conversion-progress", &conversion_progress);
-                }
-                
-                // 변환 중단 확인
-                if state_clone.is_converting.load(Ordering::Relaxed) == false {
-                    let _ = child.kill();
-                    break;
-                }
-            }
-        }
-        
-        // 프로세스 완료 대기
-        let result = child.wait();
-        
-        let final_progress = match result {
-            Ok(status) if status.success() => {
-                DownloadProgress {
-                    channel: "변환".to_string(),
-                    status: "완료".to_string(),
-                    progress: 100.0,
-                    current_video: video_path_clone.clone(),
-                    total_videos: 1,
-                    completed_videos: 1,
-                    log_message: "✅ 비디오 변환 완료!".to_string(),
-                }
-            },
-            _ => {
-                DownloadProgress {
-                    channel: "변환".to_string(),
-                    status: "실패".to_string(),
-                    progress: 0.0,
-                    current_video: video_path_clone.clone(),
-                    total_videos: 1,
-                    completed_videos: 0,
-                    log_message: "❌ 비디오 변환 실패".to_string(),
-                }
-            }
-        };
-        
-        let _ = window_clone.emit("conversion-progress", &final_progress);
-        
-        // 변환 상태 초기화
-        state_clone.is_converting.store(false, Ordering::Relaxed);
-    });
+    // SearchConfig 검증
+    validated.search_config.max_results = validated.search_config.max_results.clamp(1, 50);
+    validated.search_config.similarity_threshold = validated.search_config.similarity_threshold.clamp(0.0, 1.0);
+    validated.search_config.precision_threshold = validated.search_config.precision_threshold.clamp(0.0, 1.0);
+    validated.search_config.rag_fusion_queries = validated.search_config.rag_fusion_queries.clamp(2, 8);
+    validated.search_config.rerank_threshold = validated.search_config.rerank_threshold.clamp(0.0, 1.0);
+    validated.search_config.rerank_top_k = validated.search_config.rerank_top_k.clamp(1, 20);
+    
+    // AnswerConfig 검증
+    validated.answer_config.max_bullets = validated.answer_config.max_bullets.clamp(1, 10);
+    validated.answer_config.max_tokens = validated.answer_config.max_tokens.clamp(100, 2000);
+    validated.answer_config.temperature = validated.answer_config.temperature.clamp(0.0, 2.0);
+    validated.answer_config.factual_temperature = validated.answer_config.factual_temperature.clamp(0.0, 1.0);
+    validated.answer_config.analytical_temperature = validated.answer_config.analytical_temperature.clamp(0.0, 1.0);
     
-    Ok("비디오 변환이 시작되었습니다".to_string())
+    // Performance 검증
+    validated.performance.target_response_time_ms = validated.performance.target_response_time_ms.clamp(100, 10000);
+    validated.performance.max_concurrent_searches = validated.performance.max_concurrent_searches.clamp(1, 5);
+    validated.performance.cache_ttl_hours = validated.performance.cache_ttl_hours.clamp(1, 720); // 1시간~30일
+    
+    Ok(validated)
 }
 
-#[command]
-async fn cancel_conversion(state: State<'_, ConversionState>) -> Result<(), String> {
-    state.is_converting.store(false, Ordering::Relaxed);
+// 질문 감시 ("이 질문 지켜보기") 관련 구조체들
+#[derive(Serialize, Deserialize, Clone)]
+struct WatchedAnswer {
+    answer: String,
+    sources: Vec<String>,
+    answered_at: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct WatchedQuestion {
+    id: String,
+    query: String,
+    channels: Vec<String>,
+    notify: bool,
+    created_at: String,
+    last_checked_at: Option<String>,
+    answers: BTreeMap<String, WatchedAnswer>, // 채널별 마지막 답변
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct WatchedQuestionChange {
+    question_id: String,
+    query: String,
+    channel: String,
+    previous_answer: String,
+    answer: String,
+    new_sources: Vec<String>,
+    change_ratio: f64,
+}
+
+const WATCHED_QUESTIONS_FILE: &str = "watched_questions.json";
+const WATCH_QUESTION_MODEL: &str = "deepseek-chat";
+// 단어 집합 기준 변화율이 이 값 이상이거나 새 출처가 인용되면 "답변이 바뀜"으로 판단
+const ANSWER_CHANGE_THRESHOLD: f64 = 0.35;
+
+// rag.py를 캐시 없이 실행하여 답변과 인용 영상 ID 반환
+fn run_rag_query(query: &str, channel: &str) -> Result<(String, Vec<String>), String> {
+    let project_root = get_project_root();
+    let rag_script = project_root.join("vault").join("90_indices").join("rag.py");
+    let venv_python = project_root.join("venv").join("bin").join("python");
+    if !rag_script.exists() || !venv_python.exists() {
+        return Err("Python 가상환경 또는 rag.py를 찾을 수 없습니다".to_string());
+    }
+    let glossary = load_channel_settings().remove(channel).map(|s| s.glossary).unwrap_or_default();
     
-    if let Ok(mut process_guard) = state.current_process.lock() {
-        if let Some(mut child) = process_guard.take() {
-            let _ = child.kill();
-            let _ = child.wait();
-        }
+    let output = Command::new(&venv_python)
+        .arg(&rag_script)
+        .args([query, channel, "--progress", "--model", WATCH_QUESTION_MODEL])
+        .current_dir(&project_root)
+        .env("PYTHONUNBUFFERED", "1")
+        .env("YDH_RAG_NO_CACHE", "1") // 캐시된 답변이 아닌 새 답변과 비교
+        .env("YDH_ANSWER_LANGUAGE", get_channel_language(channel).unwrap_or_default())
+        .env("YDH_GLOSSARY", serde_json::to_string(&glossary).unwrap_or_default())
+        .output()
+        .map_err(|e| format!("rag.py 실행 실패: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("질문 재실행 실패: {}", String::from_utf8_lossy(&output.stderr)));
     }
     
-    Ok(())
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let final_answer = stdout.split_once("FINAL_ANSWER:").map(|(_, rest)| rest.trim()).unwrap_or("");
+    let parsed: serde_json::Value = serde_json::from_str(final_answer).map_err(|e| format!("답변 파싱 실패: {}", e))?;
+    let answer = parsed["answer"].as_str().unwrap_or_default().to_string();
+    let sources = parsed["sources"]
+        .as_array()
+        .map(|s| s.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+        .unwrap_or_default();
+    Ok((answer, sources))
 }
 
-#[command]
-async fn get_conversion_status(state: State<'_, ConversionState>) -> Result<bool, String> {
-    Ok(state.is_converting.load(Ordering::Relaxed))
+// 두 답변의 단어 집합 차이 (0.0 = 동일, 1.0 = 완전히 다름)
+fn answer_change_ratio(previous: &str, current: &str) -> f64 {
+    let words = |text: &str| -> std::collections::HashSet<String> {
+        text.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()).map(|w| w.to_lowercase()).collect()
+    };
+    let (a, b) = (words(previous), words(current));
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    1.0 - a.intersection(&b).count() as f64 / union as f64
 }
 
-// FFmpeg 출력에서 변환 진행률 파싱
-fn parse_conversion_progress(line: &str) -> f32 {
-    // FFmpeg 시간 출력 파싱: time=00:01:23.45
-    if let Some(captures) = Regex::new(r"time=(\d{2}):(\d{2}):(\d{2})\.(\d+)").unwrap().captures(line) {
-        if let (Some(hours), Some(minutes), Some(seconds)) = 
-            (captures.get(1), captures.get(2), captures.get(3)) {
-            if let (Ok(h), Ok(m), Ok(s)) = 
-                (hours.as_str().parse::<f32>(), minutes.as_str().parse::<f32>(), seconds.as_str().parse::<f32>()) {
-                let total_seconds = h * 3600.0 + m * 60.0 + s;
-                // 예상 총 시간을 모르므로 임시로 무한 진행률 대신 시간만 반환
-                // 실제로는 비디오 길이를 알아야 정확한 퍼센트 계산 가능
-                return (total_seconds / 10.0).min(95.0); // 임시 계산
+fn count_videos_indexed_since(conn: &rusqlite::Connection, channel: &str, since: &str) -> i64 {
+    conn.query_row(
+        "SELECT COUNT(*) FROM videos WHERE (channel = ?1 OR video_path LIKE ?2) AND indexed_at > ?3",
+        rusqlite::params![channel, format!("vault/10_videos/{}/%", sanitize_vault_name(channel)), since],
+        |row| row.get(0),
+    )
+    .unwrap_or(0)
+}
+
+// 감시 중인 질문 재실행 (force가 아니면 마지막 확인 이후 새 영상이 있는 채널만)
+fn recheck_watched_questions(app: Option<&tauri::AppHandle>, only_id: Option<&str>, channels: Option<&[String]>, force: bool) -> Result<Vec<WatchedQuestionChange>, String> {
+    let mut questions: Vec<WatchedQuestion> = load_json_config(WATCHED_QUESTIONS_FILE)?;
+    let conn = open_index_db()?;
+    let mut changes = Vec::new();
+    
+    for question in questions.iter_mut().filter(|q| only_id.map(|id| q.id == id).unwrap_or(true)) {
+        let now = chrono::Local::now().to_rfc3339();
+        for channel in question.channels.clone() {
+            if channels.map(|c| !c.contains(&channel)).unwrap_or(false) {
+                continue;
+            }
+            let has_new_videos = question
+                .last_checked_at
+                .as_deref()
+                .map(|since| count_videos_indexed_since(&conn, &channel, since) > 0)
+                .unwrap_or(true);
+            if !force && question.answers.contains_key(&channel) && !has_new_videos {
+                continue;
+            }
+            
+            let (answer, sources) = match run_rag_query(&question.query, &channel) {
+                Ok(result) => result,
+                Err(e) => {
+                    eprintln!("⚠️ 감시 질문 재실행 실패 ({} / {}): {}", question.query, channel, e);
+                    continue;
+                }
+            };
+            if let Some(previous) = question.answers.get(&channel) {
+                let change_ratio = answer_change_ratio(&previous.answer, &answer);
+                let new_sources: Vec<String> = sources.iter().filter(|s| !previous.sources.contains(s)).cloned().collect();
+                if change_ratio >= ANSWER_CHANGE_THRESHOLD || !new_sources.is_empty() {
+                    let change = WatchedQuestionChange {
+                        question_id: question.id.clone(),
+                        query: question.query.clone(),
+                        channel: channel.clone(),
+                        previous_answer: previous.answer.clone(),
+                        answer: answer.clone(),
+                        new_sources,
+                        change_ratio,
+                    };
+                    if let (true, Some(app)) = (question.notify, app) {
+                        let _ = app.emit("watched-question-changed", &change);
+                    }
+                    changes.push(change);
+                }
             }
+            question.answers.insert(channel, WatchedAnswer { answer, sources, answered_at: now.clone() });
         }
+        question.last_checked_at = Some(now);
     }
     
-    // FFmpeg 프레임 출력: frame= 1234
-    if let Some(captures) = Regex::new(r"frame=\s*(\d+)").unwrap().captures(line) {
-        if let Some(frame_match) = captures.get(1) {
-            if let Ok(frame) = frame_match.as_str().parse::<f32>() {
-                return (frame / 100.0).min(95.0); // 임시 계산
-            }
+    save_json_config(WATCHED_QUESTIONS_FILE, &questions)?;
+    Ok(changes)
+}
+
+// 임베딩 완료 후 관련 채널의 감시 질문 재실행
+fn spawn_watched_question_recheck(app: tauri::AppHandle, channels: Option<Vec<String>>) {
+    thread::spawn(move || {
+        if let Err(e) = recheck_watched_questions(Some(&app), None, channels.as_deref(), false) {
+            eprintln!("⚠️ 감시 질문 확인 실패: {}", e);
         }
+    });
+}
+
+// 질문 감시 등록 (기준 답변은 백그라운드에서 생성)
+#[command]
+fn watch_question(window: Window, query: String, channels: Vec<String>, notify: Option<bool>) -> Result<WatchedQuestion, String> {
+    let query = query.trim().to_string();
+    if query.is_empty() || channels.is_empty() {
+        return Err("질문과 채널을 지정하세요".to_string());
     }
+    let mut questions: Vec<WatchedQuestion> = load_json_config(WATCHED_QUESTIONS_FILE)?;
+    let now = chrono::Local::now();
+    let question = WatchedQuestion {
+        id: format!("q{}", now.timestamp_millis()),
+        query,
+        channels,
+        notify: notify.unwrap_or(true),
+        created_at: now.to_rfc3339(),
+        last_checked_at: None,
+        answers: BTreeMap::new(),
+    };
+    questions.push(question.clone());
+    save_json_config(WATCHED_QUESTIONS_FILE, &questions)?;
     
-    -1.0 // 진행률을 파싱할 수 없는 경우
+    let app = window.app_handle().clone();
+    let id = question.id.clone();
+    thread::spawn(move || {
+        if let Err(e) = recheck_watched_questions(Some(&app), Some(&id), None, true) {
+            eprintln!("⚠️ 감시 질문 기준 답변 생성 실패: {}", e);
+        }
+    });
+    Ok(question)
 }
 
-#[derive(Serialize, Deserialize)]
-struct VideoDetails {
-    video_id: String,
+#[command]
+fn list_watched_questions() -> Result<Vec<WatchedQuestion>, String> {
+    load_json_config(WATCHED_QUESTIONS_FILE)
+}
+
+#[command]
+fn unwatch_question(question_id: String) -> Result<(), String> {
+    let mut questions: Vec<WatchedQuestion> = load_json_config(WATCHED_QUESTIONS_FILE)?;
+    let before = questions.len();
+    questions.retain(|q| q.id != question_id);
+    if questions.len() == before {
+        return Err(format!("감시 중인 질문이 아닙니다: {}", question_id));
+    }
+    save_json_config(WATCHED_QUESTIONS_FILE, &questions)
+}
+
+// 새 영상 여부와 관계없이 즉시 재실행
+#[command]
+async fn recheck_watched_question(window: Window, question_id: String) -> Result<Vec<WatchedQuestionChange>, String> {
+    let app = window.app_handle().clone();
+    tokio::task::spawn_blocking(move || recheck_watched_questions(Some(&app), Some(&question_id), None, true))
+        .await
+        .map_err(|e| format!("질문 재실행 작업 실패: {}", e))?
+}
+
+// 주제별 다이제스트 (RSS/이메일) 관련 구조체들
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+struct DigestTopic {
+    topic: String,
+    rss: bool,
+    email: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+struct SmtpSettings {
+    host: String,
+    port: u16,
+    starttls: bool,
+    username: String,
+    #[serde(skip_serializing)]
+    password: String, // 이전 버전 설정 파일의 비밀번호 (.env로 이전 후 비움)
+    from: String,
+    to: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+struct DigestSettings {
+    enabled: bool,
+    topics: Vec<DigestTopic>,
+    interval_hours: u32,
+    lookback_days: u32, // RSS 피드에 포함할 기간
+    max_items: usize,
+    smtp: Option<SmtpSettings>,
+}
+
+impl Default for DigestSettings {
+    fn default() -> Self {
+        Self { enabled: false, topics: Vec::new(), interval_hours: 24, lookback_days: 7, max_items: 20, smtp: None }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct DigestItem {
     title: String,
-    transcript: String,
-    duration: Option<u32>,
+    channel: String,
     upload_date: Option<String>,
-    description: Option<String>,
+    video_id: Option<String>,
+    source_url: Option<String>,
+    summary: String,
 }
 
 #[derive(Serialize, Deserialize)]
-struct ChatSession {
-    id: String,
-    timestamp: String,
-    query: String,
-    response: String,
-    channel: String,
-    model: String,
+struct TopicDigest {
+    topic: String,
+    generated_at: String,
+    items: Vec<DigestItem>,
 }
 
-// 비디오 상세 정보 조회 (AIAnswerComponent에서 사용)
-#[command]
-async fn get_video_details(video_id: String, channel_name: String) -> Result<VideoDetails, String> {
-    let project_root = get_project_root();
-    let get_video_info_script = project_root.join("vault").join("90_indices").join("get_video_info.py");
-    
-    if !get_video_info_script.exists() {
-        return Err(format!("get_video_info.py 스크립트를 찾을 수 없습니다: {}", get_video_info_script.display()));
+#[derive(Serialize, Deserialize)]
+struct DigestPublishResult {
+    topic: String,
+    items: usize,
+    rss_updated: bool,
+    emailed: bool,
+    error: Option<String>,
+}
+
+const DIGEST_SETTINGS_FILE: &str = "digest_settings.json";
+const DIGEST_STATE_FILE: &str = "digest_state.json"; // 주제별 마지막 이메일 발송 시각
+const DIGEST_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const DIGEST_SUMMARY_CHARS: usize = 300;
+const SMTP_PASSWORD_ENV: &str = "YDH_SMTP_PASSWORD";
+
+// SMTP 비밀번호는 설정 파일에 두지 않고 환경 변수 또는 프로젝트 .env에서 읽음 (API 키와 동일)
+fn smtp_password() -> Option<String> {
+    std::env::var(SMTP_PASSWORD_ENV).ok().filter(|v| !v.is_empty()).or_else(|| {
+        let content = fs::read_to_string(get_project_root().join(".env")).ok()?;
+        content.lines().find_map(|line| {
+            let (key, value) = line.trim().split_once('=')?;
+            (key.trim() == SMTP_PASSWORD_ENV).then(|| value.trim().trim_matches('"').trim_matches('\'').to_string())
+        })
+    })
+}
+
+// 다이제스트 설정 로드 (설정 파일에 남은 이전 비밀번호는 한 번만 .env로 옮기고 파일에서 제거)
+fn load_digest_settings() -> Result<DigestSettings, String> {
+    let mut settings: DigestSettings = load_json_config(DIGEST_SETTINGS_FILE)?;
+    let Some(smtp) = settings.smtp.as_mut().filter(|s| !s.password.is_empty()) else {
+        return Ok(settings);
+    };
+    if smtp_password().is_none() {
+        let env_path = get_project_root().join(".env");
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&env_path)
+            .map_err(|e| format!(".env 파일 열기 실패: {}", e))?;
+        writeln!(file, "\n{}={}", SMTP_PASSWORD_ENV, smtp.password).map_err(|e| format!(".env 파일 저장 실패: {}", e))?;
     }
+    smtp.password.clear();
+    save_json_config(DIGEST_SETTINGS_FILE, &settings)?;
+    Ok(settings)
+}
+
+fn get_digests_dir() -> PathBuf {
+    get_project_root().join("vault").join("90_indices").join("digests")
+}
+
+fn digest_feed_path(topic: &str) -> PathBuf {
+    get_digests_dir().join(format!("{}.xml", sanitize_vault_name(topic)))
+}
+
+// 인덱스에서 주제가 붙은 영상 중 since(YYYY-MM-DD) 이후 업로드된 영상
+fn build_topic_digest(topic: &str, since: &str, max_items: usize) -> Result<TopicDigest, String> {
+    let conn = open_index_db()?;
+    let mut videos: Vec<VideoInfo> = load_indexed_videos(&conn)?
+        .into_iter()
+        .filter(|v| v.upload_date.as_deref().map(|d| d >= since).unwrap_or(false))
+        .filter(|v| v.topic.as_ref().map(|t| t.iter().any(|x| x == topic)).unwrap_or(false))
+        .collect();
+    videos.sort_by(|a, b| b.upload_date.cmp(&a.upload_date));
+    videos.truncate(max_items);
     
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    if !venv_python.exists() {
-        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    let items = videos
+        .into_iter()
+        .map(|v| DigestItem {
+            summary: v.excerpt.unwrap_or_default().chars().take(DIGEST_SUMMARY_CHARS).collect(),
+            title: v.title,
+            channel: v.channel,
+            upload_date: v.upload_date,
+            video_id: v.video_id,
+            source_url: v.source_url,
+        })
+        .collect();
+    Ok(TopicDigest { topic: topic.to_string(), generated_at: chrono::Local::now().to_rfc3339(), items })
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn xml_unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn render_digest_rss(digest: &TopicDigest) -> String {
+    let to_rfc2822 = |date: &str| {
+        chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .ok()
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .map(|d| d.and_utc().to_rfc2822())
+            .unwrap_or_default()
+    };
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\">\n<channel>\n");
+    xml.push_str(&format!("<title>Y-Data-House: {}</title>\n", xml_escape(&digest.topic)));
+    xml.push_str(&format!("<description>{} 주제의 새 영상</description>\n", xml_escape(&digest.topic)));
+    xml.push_str(&format!("<lastBuildDate>{}</lastBuildDate>\n", chrono::Utc::now().to_rfc2822()));
+    for item in &digest.items {
+        xml.push_str("<item>\n");
+        xml.push_str(&format!("  <title>{}</title>\n", xml_escape(&item.title)));
+        if let Some(url) = &item.source_url {
+            xml.push_str(&format!("  <link>{}</link>\n", xml_escape(url)));
+        }
+        if let Some(id) = &item.video_id {
+            xml.push_str(&format!("  <guid isPermaLink=\"false\">{}</guid>\n", xml_escape(id)));
+        }
+        xml.push_str(&format!("  <author>{}</author>\n", xml_escape(&item.channel)));
+        if let Some(date) = &item.upload_date {
+            xml.push_str(&format!("  <pubDate>{}</pubDate>\n", to_rfc2822(date)));
+        }
+        xml.push_str(&format!("  <description>{}</description>\n", xml_escape(&item.summary)));
+        xml.push_str("</item>\n");
+    }
+    xml.push_str("</channel>\n</rss>\n");
+    xml
+}
+
+fn render_digest_email(digest: &TopicDigest) -> String {
+    let mut html = format!("<h2>📬 {} 다이제스트 ({}개)</h2>\n", xml_escape(&digest.topic), digest.items.len());
+    for item in &digest.items {
+        let title = match &item.source_url {
+            Some(url) => format!("<a href=\"{}\">{}</a>", xml_escape(url), xml_escape(&item.title)),
+            None => xml_escape(&item.title),
+        };
+        html.push_str(&format!(
+            "<h3>{}</h3>\n<p><small>{} · {}</small></p>\n<p>{}</p>\n",
+            title,
+            xml_escape(&item.channel),
+            item.upload_date.as_deref().unwrap_or(""),
+            xml_escape(&item.summary),
+        ));
+    }
+    html
+}
+
+fn send_digest_email(smtp: &SmtpSettings, digest: &TopicDigest) -> Result<(), String> {
+    use lettre::Transport;
+    
+    let mut builder = lettre::Message::builder()
+        .from(smtp.from.parse().map_err(|e| format!("보내는 주소 오류: {}", e))?)
+        .subject(format!("[Y-Data-House] {} 다이제스트 ({}개)", digest.topic, digest.items.len()))
+        .header(lettre::message::header::ContentType::TEXT_HTML);
+    for to in &smtp.to {
+        builder = builder.to(to.parse().map_err(|e| format!("받는 주소 오류 {}: {}", to, e))?);
     }
+    let email = builder.body(render_digest_email(digest)).map_err(|e| format!("이메일 생성 실패: {}", e))?;
     
-    let output = Command::new(&venv_python)
-        .args(&[get_video_info_script.to_str().unwrap(), &video_id, &channel_name])
-        .current_dir(&project_root)
-        .output()
-        .map_err(|e| e.to_string())?;
+    let relay = if smtp.starttls {
+        lettre::SmtpTransport::starttls_relay(&smtp.host)
+    } else {
+        lettre::SmtpTransport::relay(&smtp.host)
+    }
+    .map_err(|e| format!("SMTP 서버 설정 오류: {}", e))?;
+    let mut relay = relay.port(smtp.port);
+    if !smtp.username.is_empty() {
+        relay = relay.credentials(lettre::transport::smtp::authentication::Credentials::new(
+            smtp.username.clone(),
+            smtp_password().ok_or_else(|| format!("SMTP 비밀번호가 없습니다 ({} 환경 변수 또는 .env 설정 필요)", SMTP_PASSWORD_ENV))?,
+        ));
+    }
+    relay.build().send(&email).map_err(|e| format!("이메일 발송 실패: {}", e))?;
+    Ok(())
+}
+
+// 설정된 주제별 RSS 피드 갱신 및 이메일 발송 (force면 발송 주기 무시)
+fn publish_digests(force: bool) -> Result<Vec<DigestPublishResult>, String> {
+    let settings = load_digest_settings()?;
+    let mut last_sent: BTreeMap<String, String> = load_json_config(DIGEST_STATE_FILE)?;
+    let now = chrono::Local::now();
+    let lookback_since = (now - chrono::Duration::days(settings.lookback_days as i64)).format("%Y-%m-%d").to_string();
+    let mut results = Vec::new();
+    
+    for entry in &settings.topics {
+        let mut result = DigestPublishResult { topic: entry.topic.clone(), items: 0, rss_updated: false, emailed: false, error: None };
+        
+        if entry.rss {
+            match build_topic_digest(&entry.topic, &lookback_since, settings.max_items) {
+                Ok(digest) => {
+                    result.items = digest.items.len();
+                    let path = digest_feed_path(&entry.topic);
+                    let written = fs::create_dir_all(get_digests_dir()).and_then(|_| fs::write(&path, render_digest_rss(&digest)));
+                    match written {
+                        Ok(_) => result.rss_updated = true,
+                        Err(e) => result.error = Some(format!("RSS 피드 저장 실패: {}", e)),
+                    }
+                }
+                Err(e) => result.error = Some(e),
+            }
+        }
+        
+        if let (true, Some(smtp)) = (entry.email, &settings.smtp) {
+            let previous = last_sent.get(&entry.topic).and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok());
+            let is_due = force
+                || previous
+                    .map(|t| now.signed_duration_since(t) >= chrono::Duration::hours(settings.interval_hours as i64))
+                    .unwrap_or(true);
+            if is_due {
+                let since = previous.map(|t| t.format("%Y-%m-%d").to_string()).unwrap_or_else(|| lookback_since.clone());
+                let sent = build_topic_digest(&entry.topic, &since, settings.max_items).and_then(|digest| {
+                    if digest.items.is_empty() {
+                        return Ok(false);
+                    }
+                    send_digest_email(smtp, &digest).map(|_| true)
+                });
+                match sent {
+                    Ok(emailed) => {
+                        result.emailed = emailed;
+                        last_sent.insert(entry.topic.clone(), now.to_rfc3339());
+                    }
+                    Err(e) => result.error = Some(e),
+                }
+            }
+        }
+        results.push(result);
+    }
     
-    if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
+    save_json_config(DIGEST_STATE_FILE, &last_sent)?;
+    Ok(results)
+}
+
+fn spawn_digest_scheduler(vault_lock: VaultLockState) {
+    thread::spawn(move || loop {
+        thread::sleep(DIGEST_CHECK_INTERVAL);
+        if vault_lock.read_only.load(Ordering::SeqCst) {
+            continue;
+        }
         
-        // JSON 응답 파싱 시도
-        if let Ok(video_details) = serde_json::from_str::<VideoDetails>(&stdout) {
-            Ok(video_details)
-        } else {
-            // JSON 파싱 실패 시 기본 정보로 응답
-            Ok(VideoDetails {
-                video_id: video_id.clone(),
-                title: format!("영상 {}", video_id),
-                transcript: stdout.to_string(),
-                duration: None,
-                upload_date: None,
-                description: None,
-            })
+        let settings = load_digest_settings().unwrap_or_default();
+        if !settings.enabled || settings.topics.is_empty() {
+            continue;
+        }
+        match publish_digests(false) {
+            Ok(results) => {
+                for r in results.iter().filter(|r| r.error.is_some()) {
+                    eprintln!("⚠️ {} 다이제스트 실패: {}", r.topic, r.error.as_deref().unwrap_or(""));
+                }
+            }
+            Err(e) => eprintln!("❌ 다이제스트 발행 실패: {}", e),
         }
+    });
+}
+
+#[command]
+fn get_digest_settings() -> Result<DigestSettings, String> {
+    load_digest_settings()
+}
+
+// 비밀번호는 설정 파일에 저장하지 않으므로 함께 보내면 조용히 버리지 않고 거부
+#[command]
+fn save_digest_settings(settings: DigestSettings) -> Result<(), String> {
+    if settings.smtp.as_ref().map(|s| !s.password.is_empty()).unwrap_or(false) {
+        return Err(format!(
+            "SMTP 비밀번호는 설정에 저장하지 않습니다. {} 환경 변수 또는 .env에 설정하세요",
+            SMTP_PASSWORD_ENV
+        ));
+    }
+    save_json_config(DIGEST_SETTINGS_FILE, &settings)
+}
+
+#[command]
+fn preview_topic_digest(topic: String) -> Result<TopicDigest, String> {
+    let settings = load_digest_settings()?;
+    let since = (chrono::Local::now() - chrono::Duration::days(settings.lookback_days as i64)).format("%Y-%m-%d").to_string();
+    build_topic_digest(&topic, &since, settings.max_items)
+}
+
+#[command]
+async fn publish_digests_now(vault_lock: State<'_, VaultLockState>) -> Result<Vec<DigestPublishResult>, String> {
+    ensure_vault_writable(&vault_lock)?;
+    tokio::task::spawn_blocking(|| publish_digests(true))
+        .await
+        .map_err(|e| format!("다이제스트 발행 작업 실패: {}", e))?
+}
+
+// 로컬 비디오 서버의 주제별 RSS 피드 주소
+#[command]
+async fn get_digest_feed_urls(state: State<'_, VideoServerState>) -> Result<BTreeMap<String, String>, String> {
+    let port = state.server_port.read().await.ok_or("비디오 서버가 실행 중이 아닙니다")?;
+    let settings = load_digest_settings()?;
+    Ok(settings
+        .topics
+        .iter()
+        .filter(|t| t.rss)
+        .map(|t| {
+            let slug = sanitize_vault_name(&t.topic);
+            (t.topic.clone(), format!("http://127.0.0.1:{}/digest/{}.xml", port, urlencoding::encode(&slug)))
+        })
+        .collect())
+}
+
+// Vault 잠금 파일 (데스크톱 앱과 CLI의 동시 쓰기 방지)
+#[derive(Serialize, Deserialize, Clone)]
+struct VaultLockInfo {
+    pid: u32,
+    owner: String, // "desktop" | "cli"
+    started_at: String,
+    heartbeat_at: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct VaultLockStatus {
+    read_only: bool,
+    holder: Option<VaultLockInfo>, // 읽기 전용일 때 잠금을 가진 다른 인스턴스
+}
+
+const VAULT_LOCK_FILE: &str = ".ydh.lock";
+const VAULT_LOCK_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
+const VAULT_LOCK_STALE_SECONDS: i64 = 90;
+
+fn get_vault_lock_path() -> PathBuf {
+    get_project_root().join("vault").join(VAULT_LOCK_FILE)
+}
+
+fn read_vault_lock() -> Option<VaultLockInfo> {
+    let content = fs::read_to_string(get_vault_lock_path()).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+// 하트비트가 갱신되지 않은 잠금은 비정상 종료된 인스턴스의 것으로 간주
+fn is_vault_lock_alive(info: &VaultLockInfo) -> bool {
+    chrono::DateTime::parse_from_rfc3339(&info.heartbeat_at)
+        .map(|t| chrono::Local::now().signed_duration_since(t) < chrono::Duration::seconds(VAULT_LOCK_STALE_SECONDS))
+        .unwrap_or(false)
+}
+
+// 잠금 파일을 원자적으로 생성 (이미 있으면 AlreadyExists)
+fn create_vault_lock_file(lock_path: &Path, info: &VaultLockInfo) -> std::io::Result<()> {
+    let content = serde_json::to_string_pretty(info).map_err(std::io::Error::other)?;
+    let mut file = fs::OpenOptions::new().write(true).create_new(true).open(lock_path)?;
+    file.write_all(content.as_bytes())
+}
+
+// 내용을 읽을 수 없는 잠금은 막 생성되어 아직 쓰는 중일 수 있으므로 수정 시각으로 판단
+fn is_unreadable_vault_lock_alive(lock_path: &Path) -> bool {
+    fs::metadata(lock_path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.elapsed().ok())
+        .map(|age| age < Duration::from_secs(VAULT_LOCK_STALE_SECONDS as u64))
+        .unwrap_or(true)
+}
+
+// 만료된 잠금 인수: 고유 이름으로 옮긴 뒤 옮긴 내용이 확인했던 만료 잠금과 같을 때만 성공
+fn take_over_stale_vault_lock(lock_path: &Path, stale_content: &str) -> bool {
+    let claimed = lock_path.with_extension(format!("lock.stale.{}", std::process::id()));
+    if fs::rename(lock_path, &claimed).is_err() {
+        return false; // 다른 인스턴스가 먼저 인수함
+    }
+    if fs::read_to_string(&claimed).map(|c| c == stale_content).unwrap_or(false) {
+        let _ = fs::remove_file(&claimed);
+        true
     } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(format!("비디오 정보 조회 실패: {}", stderr))
+        // 그 사이 다른 인스턴스가 새로 만든 잠금을 옮겼으면 되돌림
+        let _ = fs::rename(&claimed, lock_path);
+        false
     }
 }
 
-// 채널 목록 조회 (Python 스크립트 기반)
-#[command]
-async fn get_channels_from_script() -> Result<Vec<AIChannelInfo>, String> {
-    let project_root = get_project_root();
-    let list_channels_script = project_root.join("vault").join("90_indices").join("list_channels.py");
-    
-    if !list_channels_script.exists() {
-        return Err(format!("list_channels.py 스크립트를 찾을 수 없습니다: {}", list_channels_script.display()));
+// 잠금 획득 또는 하트비트 갱신 (다른 인스턴스가 잡고 있으면 읽기 전용으로 전환)
+fn acquire_vault_lock(state: &VaultLockState) -> Result<VaultLockStatus, String> {
+    let own_pid = std::process::id();
+    let lock_path = get_vault_lock_path();
+    if let Some(parent) = lock_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Vault 디렉토리 생성 실패: {}", e))?;
     }
-    
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    if !venv_python.exists() {
-        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+    let now = chrono::Local::now().to_rfc3339();
+    
+    // 이미 가진 잠금은 하트비트만 갱신
+    if let Some(own) = read_vault_lock().filter(|l| l.pid == own_pid) {
+        let info = VaultLockInfo { heartbeat_at: now, ..own };
+        let content = serde_json::to_string_pretty(&info).map_err(|e| e.to_string())?;
+        let temp_path = lock_path.with_extension("lock.tmp");
+        fs::write(&temp_path, content).map_err(|e| format!("Vault 잠금 파일 저장 실패: {}", e))?;
+        fs::rename(&temp_path, &lock_path).map_err(|e| format!("Vault 잠금 파일 교체 실패: {}", e))?;
+        state.read_only.store(false, Ordering::SeqCst);
+        return Ok(VaultLockStatus { read_only: false, holder: None });
     }
     
-    let output = Command::new(&venv_python)
-        .arg(&list_channels_script)
-        .current_dir(&project_root)
-        .output()
-        .map_err(|e| e.to_string())?;
-    
-    if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        
-        // JSON 응답 파싱 시도
-        if let Ok(channels) = serde_json::from_str::<Vec<AIChannelInfo>>(&stdout) {
-            Ok(channels)
-        } else {
-            // JSON 파싱 실패 시 기존 방식으로 파싱
-            let channels = parse_channel_list(&stdout);
-            Ok(channels)
+    let info = VaultLockInfo { pid: own_pid, owner: "desktop".to_string(), started_at: now.clone(), heartbeat_at: now };
+    let mut holder = None;
+    // 만료된 잠금을 인수한 뒤 한 번 더 생성 시도
+    for _ in 0..2 {
+        match create_vault_lock_file(&lock_path, &info) {
+            Ok(()) => {
+                state.read_only.store(false, Ordering::SeqCst);
+                return Ok(VaultLockStatus { read_only: false, holder: None });
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                let content = fs::read_to_string(&lock_path).unwrap_or_default();
+                holder = serde_json::from_str::<VaultLockInfo>(&content).ok();
+                let alive = match &holder {
+                    Some(existing) => is_vault_lock_alive(existing),
+                    None => is_unreadable_vault_lock_alive(&lock_path),
+                };
+                if alive || !take_over_stale_vault_lock(&lock_path, &content) {
+                    break;
+                }
+            }
+            Err(e) => return Err(format!("Vault 잠금 파일 생성 실패: {}", e)),
         }
-    } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(format!("채널 목록 조회 실패: {}", stderr))
     }
+    
+    state.read_only.store(true, Ordering::SeqCst);
+    Ok(VaultLockStatus { read_only: true, holder })
+}
+
+fn release_vault_lock() {
+    if read_vault_lock().map(|l| l.pid == std::process::id()).unwrap_or(false) {
+        let _ = fs::remove_file(get_vault_lock_path());
+    }
+}
+
+fn ensure_vault_writable(state: &VaultLockState) -> Result<(), String> {
+    if !state.read_only.load(Ordering::SeqCst) {
+        return Ok(());
+    }
+    let holder = read_vault_lock()
+        .map(|l| format!(" ({} PID {})", l.owner, l.pid))
+        .unwrap_or_default();
+    Err(format!("다른 인스턴스{}가 Vault를 사용 중이어서 읽기 전용 모드입니다", holder))
+}
+
+// 하트비트 갱신 및 읽기 전용 상태에서 잠금 해제 감지
+fn spawn_vault_lock_heartbeat(app: tauri::AppHandle, state: VaultLockState) {
+    thread::spawn(move || loop {
+        thread::sleep(VAULT_LOCK_HEARTBEAT_INTERVAL);
+        let was_read_only = state.read_only.load(Ordering::SeqCst);
+        match acquire_vault_lock(&state) {
+            Ok(status) => {
+                if status.read_only != was_read_only {
+                    let _ = app.emit("vault-lock-changed", &status);
+                }
+            }
+            Err(e) => eprintln!("❌ Vault 잠금 갱신 실패: {}", e),
+        }
+    });
 }
 
-// 채팅 세션 저장
 #[command]
-async fn save_chat_session(session_data: String) -> Result<(), String> {
-    let project_root = get_project_root();
-    let sessions_dir = project_root.join("vault").join("90_indices").join("search_sessions");
-    
-    // 디렉토리 생성 (존재하지 않는 경우)
-    std::fs::create_dir_all(&sessions_dir).map_err(|e| e.to_string())?;
-    
-    // 타임스탬프 기반 파일명 생성
-    let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
-    let session_file = sessions_dir.join(format!("session_{}.json", timestamp));
-    
-    // 세션 데이터 저장
-    std::fs::write(&session_file, &session_data).map_err(|e| e.to_string())?;
-    
-    Ok(())
+fn get_vault_lock_status(state: State<'_, VaultLockState>) -> Result<VaultLockStatus, String> {
+    let read_only = state.read_only.load(Ordering::SeqCst);
+    let holder = if read_only { read_vault_lock() } else { None };
+    Ok(VaultLockStatus { read_only, holder })
 }
 
-// 최근 채팅 세션들 불러오기
+// 읽기 전용 모드에서 잠금 재시도 (다른 인스턴스 종료 후)
 #[command]
-async fn load_recent_sessions(limit: Option<usize>) -> Result<Vec<String>, String> {
-    let project_root = get_project_root();
-    let sessions_dir = project_root.join("vault").join("90_indices").join("search_sessions");
-    
-    if !sessions_dir.exists() {
-        return Ok(vec![]);
+fn retry_vault_lock(state: State<'_, VaultLockState>) -> Result<VaultLockStatus, String> {
+    acquire_vault_lock(&state)
+}
+
+// 다른 아카이브 도구에서 가져오기 관련 구조체들
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+enum ExternalArchiveSource {
+    TubeArchivist, // 백업 JSON (es_ta_video-*.json, NDJSON) 또는 API 응답 JSON
+    Pinchflat,     // pinchflat.db (SQLite)
+}
+
+impl ExternalArchiveSource {
+    fn label(self) -> &'static str {
+        match self {
+            ExternalArchiveSource::TubeArchivist => "tube_archivist",
+            ExternalArchiveSource::Pinchflat => "pinchflat",
+        }
     }
-    
-    let limit = limit.unwrap_or(10); // 기본값 10개
-    let mut sessions = Vec::new();
-    
-    // 세션 파일들 수집
-    let mut session_files: Vec<_> = std::fs::read_dir(&sessions_dir)
-        .map_err(|e| e.to_string())?
-        .filter_map(|entry| {
-            let entry = entry.ok()?;
-            let path = entry.path();
-            let filename = path.file_name()?.to_str()?;
-            
-            // session_으로 시작하고 .json으로 끝나는 파일만
-            if filename.starts_with("session_") && filename.ends_with(".json") {
-                Some((path, entry.metadata().ok()?.modified().ok()?))
-            } else {
-                None
+}
+
+// 외부 도구에서 읽은 영상 한 건 (경로는 내보낸 도구 기준, 가져올 때 media_root로 재매핑)
+struct ExternalVideo {
+    video_id: String,
+    title: String,
+    channel: String,
+    upload_date: Option<String>, // YYYYMMDD
+    description: String,
+    duration_seconds: Option<u64>,
+    view_count: Option<u64>,
+    tags: Vec<String>,
+    media_path: PathBuf,
+    subtitles: Vec<(String, PathBuf)>, // (언어, 자막 파일)
+    watched: Option<bool>,
+    watched_at: Option<String>,
+    watch_position_seconds: Option<f64>,
+}
+
+#[derive(Serialize, Default)]
+struct ExternalImportReport {
+    source: String,
+    dry_run: bool,
+    found: u32,
+    imported: Vec<String>,        // vault 상대 경로 (dry_run이면 예정 경로)
+    skipped_existing: u32,        // 이미 vault에 있는 video_id
+    missing_media: Vec<String>,   // 미디어 파일을 찾지 못한 영상 제목
+    watch_state_preserved: u32,
+    failed: Vec<String>,
+}
+
+// Tube Archivist 문서에서 영상 문서만 추출 (배열, {"data": [...]}, ES _source 래퍼, NDJSON 모두 허용)
+fn collect_tube_archivist_documents(value: serde_json::Value, documents: &mut Vec<serde_json::Value>) {
+    match value {
+        serde_json::Value::Array(items) => items.into_iter().for_each(|item| collect_tube_archivist_documents(item, documents)),
+        serde_json::Value::Object(mut map) => {
+            if map.contains_key("youtube_id") {
+                documents.push(serde_json::Value::Object(map));
+            } else if let Some(inner) = map.remove("_source").or_else(|| map.remove("data")) {
+                collect_tube_archivist_documents(inner, documents);
             }
-        })
-        .collect();
-    
-    // 수정 시간 기준 내림차순 정렬 (최신순)
-    session_files.sort_by(|a, b| b.1.cmp(&a.1));
-    
-    // 지정된 개수만큼 세션 데이터 로드
-    for (path, _) in session_files.into_iter().take(limit) {
-        if let Ok(session_data) = std::fs::read_to_string(&path) {
-            sessions.push(session_data);
         }
+        _ => {}
     }
-    
-    Ok(sessions)
 }
 
-// 모든 채팅 세션 파일 삭제
-#[command]
-async fn clear_all_sessions() -> Result<String, String> {
-    let project_root = get_project_root();
-    let sessions_dir = project_root.join("vault").join("90_indices").join("search_sessions");
+fn read_tube_archivist_export(path: &Path) -> Result<Vec<ExternalVideo>, String> {
+    let files: Vec<PathBuf> = if path.is_dir() {
+        let mut files: Vec<PathBuf> = fs::read_dir(path)
+            .map_err(|e| format!("내보내기 폴더 읽기 실패: {}", e))?
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().map(|e| e == "json").unwrap_or(false))
+            .collect();
+        // 백업 폴더면 영상 인덱스 파일만 사용
+        if files.iter().any(|p| p.to_string_lossy().contains("ta_video")) {
+            files.retain(|p| p.to_string_lossy().contains("ta_video"));
+        }
+        files.sort();
+        files
+    } else {
+        vec![path.to_path_buf()]
+    };
     
-    if !sessions_dir.exists() {
-        return Ok("삭제할 세션이 없습니다.".to_string());
+    let mut documents = Vec::new();
+    for file in &files {
+        let content = fs::read_to_string(file).map_err(|e| format!("{} 읽기 실패: {}", file.display(), e))?;
+        match serde_json::from_str::<serde_json::Value>(&content) {
+            Ok(value) => collect_tube_archivist_documents(value, &mut documents),
+            Err(_) => content
+                .lines()
+                .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+                .for_each(|value| collect_tube_archivist_documents(value, &mut documents)),
+        }
     }
     
-    let mut deleted_count = 0;
-    
-    // 세션 파일들 삭제
-    let entries = std::fs::read_dir(&sessions_dir).map_err(|e| e.to_string())?;
+    let text = |doc: &serde_json::Value, pointer: &str| doc.pointer(pointer).and_then(|v| v.as_str()).map(str::to_string);
+    Ok(documents
+        .iter()
+        .filter_map(|doc| {
+            let video_id = text(doc, "/youtube_id")?;
+            let media_path = PathBuf::from(text(doc, "/media_url")?);
+            let subtitles = doc
+                .get("subtitles")
+                .and_then(|s| s.as_array())
+                .map(|subs| {
+                    subs.iter()
+                        .filter_map(|s| Some((text(s, "/lang")?, PathBuf::from(text(s, "/media_url")?))))
+                        .collect()
+                })
+                .unwrap_or_default();
+            let watched_at = doc
+                .pointer("/player/watched_date")
+                .and_then(|v| v.as_i64())
+                .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+                .map(|t| t.with_timezone(&chrono::Local).to_rfc3339());
+            Some(ExternalVideo {
+                title: text(doc, "/title").unwrap_or_else(|| video_id.clone()),
+                channel: text(doc, "/channel/channel_name")
+                    .or_else(|| text(doc, "/channel_name"))
+                    .unwrap_or_else(|| "Tube Archivist".to_string()),
+                upload_date: text(doc, "/published").and_then(|d| normalize_upload_date(d.get(..10).unwrap_or(&d)).ok()),
+                description: text(doc, "/description").unwrap_or_default(),
+                duration_seconds: doc.pointer("/player/duration").and_then(|v| v.as_f64()).map(|d| d as u64),
+                view_count: doc.pointer("/stats/view_count").and_then(|v| v.as_u64()),
+                tags: doc
+                    .get("tags")
+                    .and_then(|t| t.as_array())
+                    .map(|tags| tags.iter().filter_map(|t| t.as_str().map(str::to_string)).collect())
+                    .unwrap_or_default(),
+                media_path,
+                subtitles,
+                watched: doc.pointer("/player/watched").and_then(|v| v.as_bool()),
+                watched_at,
+                watch_position_seconds: doc.pointer("/player/position").and_then(|v| v.as_f64()),
+                video_id,
+            })
+        })
+        .collect())
+}
+
+// Pinchflat DB의 다운로드된 항목 (시청 상태는 Pinchflat에 없음)
+fn read_pinchflat_export(path: &Path) -> Result<Vec<ExternalVideo>, String> {
+    let db_path = if path.is_dir() { path.join("pinchflat.db") } else { path.to_path_buf() };
+    let conn = rusqlite::Connection::open_with_flags(&db_path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(|e| format!("Pinchflat DB 열기 실패 {}: {}", db_path.display(), e))?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT m.media_id, m.title, m.description, CAST(m.upload_date AS TEXT), m.duration_seconds,
+                    m.media_filepath, m.subtitle_filepaths, COALESCE(s.custom_name, s.collection_name)
+             FROM media_items m LEFT JOIN sources s ON s.id = m.source_id
+             WHERE m.media_filepath IS NOT NULL AND m.media_filepath != ''",
+        )
+        .map_err(|e| format!("Pinchflat DB 형식을 읽을 수 없습니다: {}", e))?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, Option<String>>(1)?,
+                row.get::<_, Option<String>>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, Option<i64>>(4)?,
+                row.get::<_, String>(5)?,
+                row.get::<_, Option<String>>(6)?,
+                row.get::<_, Option<String>>(7)?,
+            ))
+        })
+        .map_err(|e| format!("Pinchflat 항목 조회 실패: {}", e))?;
     
-    for entry in entries {
-        if let Ok(entry) = entry {
-            let path = entry.path();
-            if let Some(filename) = path.file_name().and_then(|f| f.to_str()) {
-                // session_으로 시작하고 .json으로 끝나는 파일만 삭제
-                if filename.starts_with("session_") && filename.ends_with(".json") {
-                    if let Err(e) = std::fs::remove_file(&path) {
-                        eprintln!("세션 파일 삭제 실패 {}: {}", path.display(), e);
-                    } else {
-                        deleted_count += 1;
-                    }
-                }
+    let mut videos = Vec::new();
+    for row in rows {
+        let (video_id, title, description, upload_date, duration, media_filepath, subtitle_filepaths, source_name) =
+            row.map_err(|e| format!("Pinchflat 항목 조회 실패: {}", e))?;
+        // subtitle_filepaths: [["en", "/downloads/.../video.en.srt"], ...]
+        let subtitles = subtitle_filepaths
+            .and_then(|s| serde_json::from_str::<Vec<(String, String)>>(&s).ok())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(language, path)| (language, PathBuf::from(path)))
+            .collect();
+        videos.push(ExternalVideo {
+            title: title.unwrap_or_else(|| video_id.clone()),
+            channel: source_name.filter(|n| !n.is_empty()).unwrap_or_else(|| "Pinchflat".to_string()),
+            upload_date: upload_date.and_then(|d| normalize_upload_date(d.get(..10).unwrap_or(&d)).ok()),
+            description: description.unwrap_or_default(),
+            duration_seconds: duration.map(|d| d.max(0) as u64),
+            view_count: None,
+            tags: Vec::new(),
+            media_path: PathBuf::from(media_filepath),
+            subtitles,
+            watched: None,
+            watched_at: None,
+            watch_position_seconds: None,
+            video_id,
+        });
+    }
+    Ok(videos)
+}
+
+// 내보낸 도구 기준 경로를 이 컴퓨터의 파일로 찾기 (컨테이너 경로는 media_root 아래에서 뒤쪽 경로가 일치하는 파일 사용)
+fn locate_external_media(path: &Path, media_root: Option<&Path>, export_dir: &Path) -> Option<PathBuf> {
+    if path.is_absolute() && path.exists() {
+        return Some(path.to_path_buf());
+    }
+    let components: Vec<_> = path.components().filter(|c| matches!(c, std::path::Component::Normal(_))).collect();
+    let roots = media_root.into_iter().chain(std::iter::once(export_dir));
+    for root in roots {
+        for start in 0..components.len() {
+            let candidate = components[start..].iter().fold(root.to_path_buf(), |acc, c| acc.join(c));
+            if candidate.is_file() {
+                return Some(candidate);
             }
         }
     }
-    
-    Ok(format!("{}개의 세션 파일을 삭제했습니다.", deleted_count))
+    None
 }
 
-// 모든 채널의 무결성 검사 (개별 채널별)
-#[command]
-async fn check_channel_integrity(channel_name: String) -> Result<String, String> {
-    let project_root = get_project_root();
-    let integrity_script = project_root.join("vault").join("90_indices").join("integrity_check.py");
-    
-    if !integrity_script.exists() {
-        return Err(format!("정합성 검사 스크립트를 찾을 수 없습니다: {}", integrity_script.display()));
-    }
-    
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    if !venv_python.exists() {
-        return Err(format!("Python 가상환경이 설정되지 않았습니다: {}", venv_python.display()));
+// 미디어를 video.mp4로 배치 (같은 디스크면 하드 링크, mp4가 아니면 ffmpeg로 변환)
+fn place_external_media(source: &Path, video_file: &Path) -> Result<(), String> {
+    let is_mp4 = source.extension().map(|e| e.eq_ignore_ascii_case("mp4")).unwrap_or(false);
+    if is_mp4 {
+        if fs::hard_link(source, video_file).is_ok() {
+            return Ok(());
+        }
+        return fs::copy(source, video_file).map(|_| ()).map_err(|e| format!("영상 복사 실패: {}", e));
     }
-    
-    let output = Command::new(&venv_python)
-        .args(&[integrity_script.to_str().unwrap(), "--channel", &channel_name])
-        .current_dir(&project_root)
-        .output()
-        .map_err(|e| e.to_string())?;
-    
-    if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        Ok(format!("✅ {} 채널 정합성 검사 완료\n{}", channel_name, stdout))
+    let input = source.to_string_lossy().to_string();
+    let converted = run_throttled_ffmpeg(&["-i", &input, "-c", "copy", "-movflags", "+faststart"], video_file)
+        || run_throttled_ffmpeg(&["-i", &input, "-c:v", "libx264", "-c:a", "aac", "-movflags", "+faststart"], video_file);
+    if converted {
+        Ok(())
     } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(format!("{} 채널 정합성 검사 실패: {}", channel_name, stderr))
+        Err("mp4 변환 실패 (ffmpeg 확인 필요)".to_string())
     }
 }
 
-// RAG 컨트롤러 상태 조회
-#[command]
-async fn get_rag_controller_status() -> Result<String, String> {
-    let project_root = get_project_root();
-    let rag_script = project_root.join("vault").join("90_indices").join("rag.py");
-    
-    if !rag_script.exists() {
-        return Err("RAG 스크립트를 찾을 수 없습니다".to_string());
+fn write_external_video(video: &ExternalVideo, source: ExternalArchiveSource, media: &Path, export_dir: &Path, media_root: Option<&Path>) -> Result<PathBuf, String> {
+    let compact = video
+        .upload_date
+        .clone()
+        .unwrap_or_else(|| chrono::Local::now().format("%Y%m%d").to_string());
+    let folder = unique_video_folder(&video.channel, &compact, &video.title);
+    fs::create_dir_all(&folder).map_err(|e| format!("영상 폴더 생성 실패: {}", e))?;
+    if let Err(e) = place_external_media(media, &folder.join("video.mp4")) {
+        let _ = fs::remove_dir_all(&folder);
+        return Err(e);
     }
     
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    let output = Command::new(&venv_python)
-        .args(&[rag_script.to_str().unwrap(), "status"])
-        .current_dir(&project_root)
-        .output()
-        .map_err(|e| e.to_string())?;
+    // 자막 트랙은 플레이어용 이름(captions.<언어>.vtt/srt)으로 복사, 첫 자막으로 본문 작성
+    let mut transcript = String::new();
+    for (language, path) in &video.subtitles {
+        let Some(found) = locate_external_media(path, media_root, export_dir) else { continue };
+        let extension = found.extension().and_then(|e| e.to_str()).unwrap_or("vtt").to_lowercase();
+        if !matches!(extension.as_str(), "vtt" | "srt") || !language.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            continue;
+        }
+        let _ = fs::copy(&found, folder.join(format!("captions.{}.{}", language, extension)));
+        if transcript.is_empty() {
+            let content = fs::read_to_string(&found).unwrap_or_default();
+            transcript = parse_timed_captions(&content).into_iter().map(|s| s.text).collect::<Vec<_>>().join("\n");
+        }
+    }
     
-    if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
-    } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(format!("RAG 상태 조회 실패: {}", stderr))
+    let date = format!("{}-{}-{}", &compact[..4], &compact[4..6], &compact[6..8]);
+    let tags: Vec<String> = video.tags.iter().map(|t| yaml_scalar(&t.replace(',', " "))).collect();
+    let mut frontmatter = vec![
+        format!("title: {}", yaml_scalar(&video.title)),
+        format!("upload: {}", date),
+        format!("channel: {}", yaml_scalar(&video.channel)),
+        format!("video_id: {}", video.video_id),
+        format!("topic: [{}]", tags.join(", ")),
+        format!("source_url: https://www.youtube.com/watch?v={}", video.video_id),
+        format!("source: {}", source.label()),
+        format!("created_date: {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S")),
+    ];
+    if let Some(duration) = video.duration_seconds {
+        frontmatter.push(format!("duration_seconds: {}", duration));
+    }
+    if let Some(view_count) = video.view_count {
+        frontmatter.push(format!("view_count: {}", view_count));
+    }
+    if let Some(watched) = video.watched {
+        frontmatter.push(format!("watched: {}", watched));
+    }
+    if let Some(watched_at) = &video.watched_at {
+        frontmatter.push(format!("watched_at: {}", yaml_scalar(watched_at)));
     }
+    if let Some(position) = video.watch_position_seconds.filter(|p| *p > 0.0) {
+        frontmatter.push(format!("watch_position_seconds: {:.1}", position));
+    }
+    let content = format!(
+        "---\n{}\n---\n\n## 📹 비디오 정보\n\n- **제목**: {}\n- **채널**: {}\n- **업로드**: {}\n\n## 📝 설명\n\n{}\n\n{}\n\n{}\n\n## 💭 노트\n\n*여기에 개인적인 생각이나 메모를 추가하세요.*\n",
+        frontmatter.join("\n"),
+        video.title,
+        video.channel,
+        date,
+        video.description.trim(),
+        TRANSCRIPT_SECTION,
+        if transcript.is_empty() { NO_TRANSCRIPT_MARK.to_string() } else { transcript },
+    );
+    write_file_atomically(&folder.join("captions.md"), &content)?;
+    generate_derivatives_for_folder(&folder);
+    Ok(folder)
 }
 
-// RAG 캐시 정리
+// Tube Archivist/Pinchflat 아카이브를 vault 구조로 가져오기 (기본 dry_run, 이미 있는 video_id는 건너뜀)
 #[command]
-async fn clear_rag_cache() -> Result<String, String> {
-    let project_root = get_project_root();
-    let rag_script = project_root.join("vault").join("90_indices").join("rag.py");
-    
-    if !rag_script.exists() {
-        return Err("RAG 스크립트를 찾을 수 없습니다".to_string());
-    }
-    
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    let output = Command::new(&venv_python)
-        .args(&[rag_script.to_str().unwrap(), "clear-cache"])
-        .current_dir(&project_root)
-        .output()
-        .map_err(|e| e.to_string())?;
-    
-    if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+async fn import_external_archive(
+    window: Window,
+    jobs: State<'_, VaultJobState>,
+    vault_lock: State<'_, VaultLockState>,
+    source: ExternalArchiveSource,
+    export_path: String,
+    media_root: Option<String>,
+    dry_run: Option<bool>,
+) -> Result<ExternalImportReport, String> {
+    let dry_run = dry_run.unwrap_or(true);
+    let running = if dry_run {
+        None
     } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(format!("RAG 캐시 정리 실패: {}", stderr))
+        ensure_vault_writable(&vault_lock)?;
+        Some(RunningGuard::try_new(&jobs.importing).ok_or("이미 외부 아카이브를 가져오는 중입니다")?)
+    };
+    tokio::task::spawn_blocking(move || {
+        let _running = running;
+        let export_path = PathBuf::from(export_path);
+        let export_dir = if export_path.is_dir() {
+            export_path.clone()
+        } else {
+            export_path.parent().map(Path::to_path_buf).unwrap_or_default()
+        };
+        let media_root = media_root.filter(|r| !r.trim().is_empty()).map(PathBuf::from);
+        let videos = match source {
+            ExternalArchiveSource::TubeArchivist => read_tube_archivist_export(&export_path)?,
+            ExternalArchiveSource::Pinchflat => read_pinchflat_export(&export_path)?,
+        };
+        
+        let existing: std::collections::HashSet<String> = scan_vault_videos()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|v| v.video_id)
+            .collect();
+        let project_root = get_project_root();
+        let mut report = ExternalImportReport {
+            source: source.label().to_string(),
+            dry_run,
+            found: videos.len() as u32,
+            ..Default::default()
+        };
+        let total = videos.len();
+        for (index, video) in videos.iter().enumerate() {
+            let _ = window.emit("external-import-progress", serde_json::json!({ "current": index + 1, "total": total, "title": video.title }));
+            if existing.contains(&video.video_id) {
+                report.skipped_existing += 1;
+                continue;
+            }
+            let Some(media) = locate_external_media(&video.media_path, media_root.as_deref(), &export_dir) else {
+                report.missing_media.push(video.title.clone());
+                continue;
+            };
+            if dry_run {
+                report.imported.push(format!("{} ← {}", video.title, media.display()));
+                report.watch_state_preserved += video.watched.is_some() as u32;
+                continue;
+            }
+            match write_external_video(video, source, &media, &export_dir, media_root.as_deref()) {
+                Ok(folder) => {
+                    report.imported.push(folder.strip_prefix(&project_root).unwrap_or(&folder).to_string_lossy().to_string());
+                    report.watch_state_preserved += video.watched.is_some() as u32;
+                }
+                Err(e) => report.failed.push(format!("{}: {}", video.title, e)),
+            }
+        }
+        if !dry_run && !report.imported.is_empty() {
+            reconcile_vault_index()?;
+        }
+        Ok(report)
+    })
+    .await
+    .map_err(|e| format!("가져오기 작업 실패: {}", e))?
+}
+
+// 작업 완료 데스크톱 알림 (config/notifications.json, 작업 종류별로 켜고 끔)
+const NOTIFICATION_SETTINGS_FILE: &str = "notifications.json";
+const NOTIFICATION_BODY_CHARS: usize = 200;
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+struct NotificationSettings {
+    downloads: bool,
+    conversions: bool,
+    embeddings: bool,
+    integrity_checks: bool,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        NotificationSettings { downloads: true, conversions: true, embeddings: true, integrity_checks: true }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum NotifiedJob {
+    Download,
+    Conversion,
+    Embedding,
+    IntegrityCheck,
+}
+
+impl NotifiedJob {
+    fn label(self) -> &'static str {
+        match self {
+            NotifiedJob::Download => "다운로드",
+            NotifiedJob::Conversion => "영상 변환",
+            NotifiedJob::Embedding => "벡터 임베딩",
+            NotifiedJob::IntegrityCheck => "정합성 검사",
+        }
+    }
+
+    fn enabled(self, settings: &NotificationSettings) -> bool {
+        match self {
+            NotifiedJob::Download => settings.downloads,
+            NotifiedJob::Conversion => settings.conversions,
+            NotifiedJob::Embedding => settings.embeddings,
+            NotifiedJob::IntegrityCheck => settings.integrity_checks,
+        }
     }
 }
 
-// 고급 검색 설정 (RAG 컨트롤러 기반)
 #[command]
-async fn advanced_rag_search(
-    query: String, 
-    channel_name: String, 
-    model: String,
-    search_config: Option<String>
-) -> Result<String, String> {
-    let project_root = get_project_root();
-    let rag_script = project_root.join("vault").join("90_indices").join("rag.py");
-    
-    if !rag_script.exists() {
-        return Err("RAG 스크립트를 찾을 수 없습니다".to_string());
+fn get_notification_settings() -> Result<NotificationSettings, String> {
+    load_json_config(NOTIFICATION_SETTINGS_FILE)
+}
+
+#[command]
+fn set_notification_settings(settings: NotificationSettings) -> Result<NotificationSettings, String> {
+    save_json_config(NOTIFICATION_SETTINGS_FILE, &settings)?;
+    Ok(settings)
+}
+
+// 긴 작업이 끝나거나 실패하면 OS 알림 (창이 포커스되지 않아도 확인 가능)
+fn notify_job_finished(window: &Window, job: NotifiedJob, result: &Result<String, String>) {
+    use tauri_plugin_notification::NotificationExt;
+    let settings: NotificationSettings = load_json_config(NOTIFICATION_SETTINGS_FILE).unwrap_or_default();
+    if !job.enabled(&settings) {
+        return;
     }
-    
-    let venv_python = project_root.join("venv").join("bin").join("python");
-    
-    let mut args = vec![
-        rag_script.to_str().unwrap().to_string(),
-        query,
-        channel_name,
-        "--model".to_string(),
-        model
-    ];
-    
-    // 고급 검색 설정이 있는 경우 추가
-    if let Some(config) = search_config {
-        args.push("--config".to_string());
-        args.push(config);
+    let (title, message) = match result {
+        Ok(message) => (format!("✅ {} 완료", job.label()), message),
+        Err(message) => (format!("❌ {} 실패", job.label()), message),
+    };
+    // 결과 메시지는 여러 줄 보고서일 수 있어 첫 줄만 사용
+    let body: String = message.lines().next().unwrap_or_default().chars().take(NOTIFICATION_BODY_CHARS).collect();
+    if let Err(e) = window.app_handle().notification().builder().title(title).body(body).show() {
+        eprintln!("⚠️ 알림 표시 실패: {}", e);
     }
-    
-    let output = Command::new(&venv_python)
-        .args(&args)
-        .current_dir(&project_root)
-        .output()
-        .map_err(|e| e.to_string())?;
-    
-    if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).to_string())
-    } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(format!("고급 RAG 검색 실패: {}", stderr))
+}
+
+// 앱 업데이트 관련 구조체들
+const UPDATE_SETTINGS_FILE: &str = "update.json";
+const UPDATE_CHECK_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+// 자동 설치 대기 중 작업 종료 확인 간격
+const UPDATE_IDLE_POLL_INTERVAL: Duration = Duration::from_secs(60);
+// 릴리스 빌드 시 주입되는 업데이트 서명 공개키 (`tauri signer generate`로 만든 키, 릴리스 빌드는 build.rs에서 필수 검사)
+const UPDATER_PUBKEY: Option<&str> = option_env!("YDH_UPDATER_PUBKEY");
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+enum ReleaseChannel {
+    #[default]
+    Stable,
+    Beta,
+}
+
+impl ReleaseChannel {
+    // 채널별 업데이트 매니페스트 (Tauri updater latest.json + rollout_percentage)
+    fn endpoint(self) -> &'static str {
+        match self {
+            ReleaseChannel::Stable => "https://github.com/SeungyeonHwang/y-data-house/releases/latest/download/latest.json",
+            ReleaseChannel::Beta => "https://github.com/SeungyeonHwang/y-data-house/releases/download/beta/latest.json",
+        }
     }
 }
 
-// 설정 파일 경로 헬퍼 함수
-fn get_settings_file_path() -> PathBuf {
-    let project_root = get_project_root();
-    project_root.join("config").join("rag_settings.json")
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+struct UpdateSettings {
+    channel: ReleaseChannel,
+    auto_check: bool,               // 6시간마다 업데이트 확인
+    auto_install: bool,             // 진행 중인 작업이 없을 때 설치 후 재시작
+    install_id: String,             // 단계적 배포 대상 판정용 (설치마다 고정)
+    last_checked_at: Option<String>,
 }
 
-// 설정 디렉토리 확인 및 생성
-fn ensure_config_directory() -> Result<(), String> {
-    let config_dir = get_project_root().join("config");
-    if !config_dir.exists() {
-        fs::create_dir_all(&config_dir)
-            .map_err(|e| format!("설정 디렉토리 생성 실패: {}", e))?;
+impl Default for UpdateSettings {
+    fn default() -> Self {
+        Self {
+            channel: ReleaseChannel::Stable,
+            auto_check: true,
+            auto_install: false,
+            install_id: String::new(),
+            last_checked_at: None,
+        }
     }
-    Ok(())
 }
 
-// RAG 설정 저장
-#[command]
-async fn save_rag_settings(settings: RAGSettings) -> Result<String, String> {
-    ensure_config_directory()?;
-    
-    let settings_path = get_settings_file_path();
-    let settings_json = serde_json::to_string_pretty(&settings)
-        .map_err(|e| format!("설정 직렬화 실패: {}", e))?;
-    
-    fs::write(&settings_path, settings_json)
-        .map_err(|e| format!("설정 파일 저장 실패: {}", e))?;
-    
-    println!("✅ RAG 설정이 저장되었습니다: {}", settings_path.display());
-    Ok(format!("설정이 성공적으로 저장되었습니다: {}", settings_path.display()))
+#[derive(Serialize, Clone)]
+struct UpdateCheckResult {
+    current_version: String,
+    channel: ReleaseChannel,
+    available: bool,
+    version: Option<String>,
+    changelog: Option<String>,
+    published_at: Option<String>,
+    rollout_percentage: u8,
+    in_rollout: bool,                  // false면 아직 이 설치에는 배포되지 않음
+    deferred_by: Vec<String>,          // 설치를 미루게 하는 진행 중 작업
+    signature_verification: bool,      // 서명 공개키가 있어 설치 가능
 }
 
-// RAG 설정 로드
-#[command]
-async fn load_rag_settings() -> Result<RAGSettings, String> {
-    let settings_path = get_settings_file_path();
-    
-    if !settings_path.exists() {
-        println!("🔧 설정 파일이 없어 기본값을 반환합니다: {}", settings_path.display());
-        return Ok(RAGSettings::default());
+fn load_update_settings() -> UpdateSettings {
+    let mut settings: UpdateSettings = load_json_config(UPDATE_SETTINGS_FILE).unwrap_or_default();
+    if settings.install_id.is_empty() {
+        settings.install_id = new_token();
+        if let Err(e) = save_json_config(UPDATE_SETTINGS_FILE, &settings) {
+            eprintln!("⚠️ 업데이트 설정 저장 실패: {}", e);
+        }
     }
-    
-    let settings_content = fs::read_to_string(&settings_path)
-        .map_err(|e| format!("설정 파일 읽기 실패: {}", e))?;
-    
-    let settings: RAGSettings = serde_json::from_str(&settings_content)
-        .map_err(|e| format!("설정 파일 파싱 실패: {}", e))?;
-    
-    println!("✅ RAG 설정이 로드되었습니다: {}", settings_path.display());
-    Ok(settings)
+    settings
 }
 
-// RAG 설정 초기화 (기본값으로 리셋)
 #[command]
-async fn reset_rag_settings() -> Result<String, String> {
-    let default_settings = RAGSettings::default();
-    save_rag_settings(default_settings).await
+fn get_update_settings() -> Result<UpdateSettings, String> {
+    Ok(load_update_settings())
 }
 
-// 설정 프리셋 적용
+// 업데이트 설정 저장 (install_id는 유지)
 #[command]
-async fn apply_rag_preset(preset_name: String) -> Result<RAGSettings, String> {
-    let settings = match preset_name.as_str() {
-        "default" => RAGSettings::default(),
-        "fast" => {
-            let mut settings = RAGSettings::default();
-            settings.fast_mode = true;
-            settings.search_config.enable_rerank = false;
-            settings.search_config.enable_rag_fusion = false;
-            settings.search_config.max_results = 8;
-            settings.answer_config.enable_self_refine = false;
-            settings.answer_config.max_tokens = 600;
-            settings
-        },
-        "quality" => {
-            let mut settings = RAGSettings::default();
-            settings.search_config.enable_rerank = true;
-            settings.search_config.enable_rag_fusion = true;
-            settings.search_config.max_results = 20;
-            settings.search_config.rerank_top_k = 8;
-            settings.answer_config.enable_self_refine = true;
-            settings.answer_config.enable_react = true;
-            settings.answer_config.max_tokens = 1200;
-            settings
-        },
-        "research" => {
-            let mut settings = RAGSettings::default();
-            settings.debug_mode = true;
-            settings.search_config.similarity_threshold = 0.05;
-            settings.search_config.max_results = 25;
-            settings.search_config.enable_rag_fusion = true;
-            settings.search_config.rag_fusion_queries = 6;
-            settings.answer_config.style = AnswerStyle::Analytical;
-            settings.answer_config.enable_react = true;
-            settings.answer_config.max_tokens = 1500;
-            settings.ui_preferences.show_advanced_settings = true;
-            settings.ui_preferences.show_debug_info = true;
-            settings.ui_preferences.auto_expand_sources = true;
-            settings
-        },
-        _ => return Err(format!("알 수 없는 프리셋: {}", preset_name))
-    };
-    
-    save_rag_settings(settings.clone()).await?;
-    Ok(settings)
+fn save_update_settings(settings: UpdateSettings) -> Result<(), String> {
+    let current = load_update_settings();
+    save_json_config(UPDATE_SETTINGS_FILE, &UpdateSettings {
+        install_id: current.install_id,
+        last_checked_at: current.last_checked_at,
+        ..settings
+    })
 }
 
-// 설정 파일 존재 여부 확인
+// 설치를 미뤄야 하는 진행 중 작업 (다운로드/임베딩/변환/파생 파일/메타데이터)
+fn running_jobs_blocking_update(app: &tauri::AppHandle) -> Vec<String> {
+    let downloads = app.state::<DownloadState>();
+    // 대기 중인 다운로드도 곧 실행되므로 함께 확인
+    let downloads_pending = downloads.is_running.load(Ordering::SeqCst)
+        || downloads
+            .jobs
+            .lock()
+            .map(|jobs| jobs.iter().any(|j| matches!(j.status, DownloadJobStatus::Queued | DownloadJobStatus::Running)))
+            .unwrap_or(false);
+    let jobs = app.state::<VaultJobState>();
+    let flags = [
+        ("다운로드", downloads_pending),
+        ("임베딩", app.state::<EmbeddingState>().is_running.load(Ordering::SeqCst)),
+        ("영상 변환", app.state::<ConversionState>().is_converting.load(Ordering::SeqCst)),
+        ("썸네일/미리보기 생성", app.state::<DerivativeState>().is_running.load(Ordering::SeqCst)),
+        ("메타데이터 수정", app.state::<MetadataJobState>().is_running.load(Ordering::SeqCst)),
+        ("미디어 정보 수집", app.state::<MediaProbeState>().running.load(Ordering::SeqCst)),
+        ("외부 아카이브 가져오기", jobs.importing.load(Ordering::SeqCst)),
+        ("채널 폴더 병합", jobs.merging_channels.load(Ordering::SeqCst)),
+        ("폴더 구조 변환", jobs.migrating_layout.load(Ordering::SeqCst)),
+        ("자막 재전사", jobs.retranscribing.load(Ordering::SeqCst)),
+        ("업로드 영상 등록", jobs.finalizing_ingests.load(Ordering::SeqCst) > 0),
+        ("보관 정책 정리", jobs.housekeeping.load(Ordering::SeqCst)),
+        ("인덱스 유지보수", jobs.index_maintenance.load(Ordering::SeqCst)),
+    ];
+    flags.iter().filter(|(_, running)| *running).map(|(name, _)| name.to_string()).collect()
+}
+
+// 단계적 배포: 설치 ID + 버전 해시로 0~99 구간을 정해 배포 비율 안에 드는지 판정
+fn update_rollout_bucket(install_id: &str, version: &str) -> u8 {
+    use sha2::{Digest, Sha256};
+    let digest = Sha256::digest(format!("{}:{}", install_id, version).as_bytes());
+    (u16::from_be_bytes([digest[0], digest[1]]) % 100) as u8
+}
+
+async fn fetch_available_update(app: &tauri::AppHandle, settings: &UpdateSettings) -> Result<Option<tauri_plugin_updater::Update>, String> {
+    use tauri_plugin_updater::UpdaterExt;
+    let endpoint = settings
+        .channel
+        .endpoint()
+        .parse()
+        .map_err(|e| format!("업데이트 주소 오류: {}", e))?;
+    let mut builder = app
+        .updater_builder()
+        .endpoints(vec![endpoint])
+        .map_err(|e| format!("업데이트 설정 오류: {}", e))?;
+    if let Some(pubkey) = UPDATER_PUBKEY {
+        builder = builder.pubkey(pubkey);
+    }
+    let updater = builder.build().map_err(|e| format!("업데이트 설정 오류: {}", e))?;
+    updater.check().await.map_err(|e| format!("업데이트 확인 실패: {}", e))
+}
+
+fn update_check_result(app: &tauri::AppHandle, settings: &UpdateSettings, update: Option<&tauri_plugin_updater::Update>) -> UpdateCheckResult {
+    let rollout_percentage = update
+        .and_then(|u| u.raw_json.get("rollout_percentage"))
+        .and_then(|v| v.as_u64())
+        .map(|p| p.min(100) as u8)
+        .unwrap_or(100);
+    UpdateCheckResult {
+        current_version: app.package_info().version.to_string(),
+        channel: settings.channel,
+        available: update.is_some(),
+        version: update.map(|u| u.version.clone()),
+        changelog: update.and_then(|u| u.body.clone()),
+        published_at: update.and_then(|u| u.date.map(|d| d.to_string())),
+        rollout_percentage,
+        in_rollout: update
+            .map(|u| update_rollout_bucket(&settings.install_id, &u.version) < rollout_percentage)
+            .unwrap_or(false),
+        deferred_by: running_jobs_blocking_update(app),
+        signature_verification: UPDATER_PUBKEY.is_some(),
+    }
+}
+
+// 선택한 릴리스 채널의 업데이트와 변경 내역 확인
 #[command]
-async fn check_rag_settings_exists() -> Result<bool, String> {
-    let settings_path = get_settings_file_path();
-    Ok(settings_path.exists())
+async fn check_for_updates(window: Window) -> Result<UpdateCheckResult, String> {
+    let app = window.app_handle().clone();
+    let mut settings = load_update_settings();
+    let update = fetch_available_update(&app, &settings).await?;
+    settings.last_checked_at = Some(chrono::Local::now().to_rfc3339());
+    save_json_config(UPDATE_SETTINGS_FILE, &settings)?;
+    Ok(update_check_result(&app, &settings, update.as_ref()))
 }
 
-// 설정 검증
+// 업데이트 다운로드/서명 검증/설치 후 재시작
+async fn install_checked_update(app: &tauri::AppHandle, update: tauri_plugin_updater::Update) -> Result<(), String> {
+    if UPDATER_PUBKEY.is_none() {
+        return Err("이 빌드에는 업데이트 서명 공개키가 없어 설치할 수 없습니다".to_string());
+    }
+    let _ = app.emit("update-installing", &update.version);
+    update
+        .download_and_install(|_, _| {}, || {})
+        .await
+        .map_err(|e| format!("업데이트 설치 실패: {}", e))?;
+    app.restart()
+}
+
+// 사용자가 요청한 업데이트 설치 (단계적 배포 대상이 아니어도 수동 설치는 허용, 작업 중이면 오류)
 #[command]
-async fn validate_rag_settings(settings: RAGSettings) -> Result<RAGSettings, String> {
-    // 범위 검증 및 수정
-    let mut validated = settings;
-    
-    // SearchConfig 검증
-    validated.search_config.max_results = validated.search_config.max_results.clamp(1, 50);
-    validated.search_config.similarity_threshold = validated.search_config.similarity_threshold.clamp(0.0, 1.0);
-    validated.search_config.precision_threshold = validated.search_config.precision_threshold.clamp(0.0, 1.0);
-    validated.search_config.rag_fusion_queries = validated.search_config.rag_fusion_queries.clamp(2, 8);
-    validated.search_config.rerank_threshold = validated.search_config.rerank_threshold.clamp(0.0, 1.0);
-    validated.search_config.rerank_top_k = validated.search_config.rerank_top_k.clamp(1, 20);
-    
-    // AnswerConfig 검증
-    validated.answer_config.max_bullets = validated.answer_config.max_bullets.clamp(1, 10);
-    validated.answer_config.max_tokens = validated.answer_config.max_tokens.clamp(100, 2000);
-    validated.answer_config.temperature = validated.answer_config.temperature.clamp(0.0, 2.0);
-    validated.answer_config.factual_temperature = validated.answer_config.factual_temperature.clamp(0.0, 1.0);
-    validated.answer_config.analytical_temperature = validated.answer_config.analytical_temperature.clamp(0.0, 1.0);
-    
-    // Performance 검증
-    validated.performance.target_response_time_ms = validated.performance.target_response_time_ms.clamp(100, 10000);
-    validated.performance.max_concurrent_searches = validated.performance.max_concurrent_searches.clamp(1, 5);
-    validated.performance.cache_ttl_hours = validated.performance.cache_ttl_hours.clamp(1, 720); // 1시간~30일
-    
-    Ok(validated)
+async fn install_update(window: Window) -> Result<UpdateCheckResult, String> {
+    let app = window.app_handle().clone();
+    let settings = load_update_settings();
+    let update = fetch_available_update(&app, &settings).await?;
+    let result = update_check_result(&app, &settings, update.as_ref());
+    let Some(update) = update else { return Ok(result) };
+    if !result.deferred_by.is_empty() {
+        return Err(format!("진행 중인 작업이 있어 설치를 미뤘습니다: {}", result.deferred_by.join(", ")));
+    }
+    install_checked_update(&app, update).await?;
+    Ok(result)
+}
+
+// 주기적 업데이트 확인: 배포 대상이면 알리고, 자동 설치가 켜져 있으면 작업이 끝난 뒤 설치
+fn spawn_update_scheduler(app: tauri::AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(UPDATE_CHECK_INTERVAL).await;
+            let mut settings = load_update_settings();
+            if !settings.auto_check {
+                continue;
+            }
+            let update = match fetch_available_update(&app, &settings).await {
+                Ok(update) => update,
+                Err(e) => {
+                    eprintln!("⚠️ {}", e);
+                    continue;
+                }
+            };
+            settings.last_checked_at = Some(chrono::Local::now().to_rfc3339());
+            let _ = save_json_config(UPDATE_SETTINGS_FILE, &settings);
+            let Some(update) = update else { continue };
+            let result = update_check_result(&app, &settings, Some(&update));
+            if !result.in_rollout {
+                continue;
+            }
+            let _ = app.emit("update-available", &result);
+            if !settings.auto_install {
+                continue;
+            }
+            if !result.signature_verification {
+                eprintln!("⚠️ 업데이트 서명 공개키 없이 빌드되어 자동 설치를 건너뜁니다: {}", update.version);
+                continue;
+            }
+            while !running_jobs_blocking_update(&app).is_empty() {
+                tokio::time::sleep(UPDATE_IDLE_POLL_INTERVAL).await;
+            }
+            if let Err(e) = install_checked_update(&app, update).await {
+                eprintln!("⚠️ {}", e);
+            }
+        }
+    });
 }
 
 fn main() {
+    let download_state = DownloadState::default();
+    let embedding_state = EmbeddingState::default();
+    let vault_lock_state = VaultLockState::default();
+    
+    // 하위 Python 프로세스가 이 앱의 잠금을 자신의 것으로 인식하도록 전달
+    env::set_var("YDH_VAULT_LOCK_PID", std::process::id().to_string());
+    match acquire_vault_lock(&vault_lock_state) {
+        Ok(status) if status.read_only => {
+            if let Some(holder) = status.holder {
+                println!("🔒 다른 인스턴스({} PID {})가 Vault를 사용 중입니다. 읽기 전용 모드로 시작합니다", holder.owner, holder.pid);
+            }
+        }
+        Ok(_) => {}
+        Err(e) => eprintln!("❌ Vault 잠금 획득 실패: {}", e),
+    }
+    
     tauri::Builder::default()
         .plugin(tauri_plugin_fs::init())
-        .manage(DownloadState::default())
-        .manage(EmbeddingState::default())
+        .plugin(tauri_plugin_updater::Builder::new().build())
+        .plugin(tauri_plugin_notification::init())
+        .manage(download_state.clone())
+        .manage(embedding_state.clone())
+        .manage(vault_lock_state.clone())
         .manage(ConversionState::default())
         .manage(VideoServerState::default())
+        .manage(ShareServerState::default())
+        .manage(IngestServerState::default())
+        .manage(DerivativeState::default())
+        .manage(MetadataJobState::default())
+        .manage(VaultJobState::default())
+        .manage(PlayQueueState {
+            entries: Arc::new(Mutex::new(load_json_config(PLAY_QUEUE_FILE).unwrap_or_default())),
+        })
+        .manage(PlaybackSyncState::default())
+        .manage(SegmentIndexState::default())
+        .manage(AutocompleteState::default())
+        .manage(MediaProbeState::default())
+        .manage(DownloadPlanState::default())
         .invoke_handler(tauri::generate_handler![
             get_debug_info,
             list_videos,
             list_channels,
             add_channel,
+            validate_channel_url,
             remove_channel,
             toggle_channel,
             download_videos,
@@ -3427,13 +18378,365 @@ fn main() {
             reset_rag_settings,
             apply_rag_preset,
             check_rag_settings_exists,
-            validate_rag_settings
+            validate_rag_settings,
+            get_channel_settings,
+            detect_channel_language,
+            detect_all_channel_languages,
+            set_channel_glossary,
+            save_answer_as_note,
+            generate_video_derivatives,
+            cancel_derivative_generation,
+            get_derivative_status,
+            get_storage_forecast,
+            set_channel_retention_policy,
+            preview_housekeeping,
+            run_housekeeping,
+            get_last_housekeeping_report,
+            prune_channel_policy,
+            verify_downloaded_videos,
+            get_incomplete_videos,
+            list_formats,
+            download_single_video,
+            backup_vector_index,
+            list_vector_index_snapshots,
+            get_index_backup_settings,
+            save_index_backup_settings,
+            restore_vector_index,
+            score_transcript_quality,
+            estimate_retranscription,
+            retranscribe_low_quality,
+            get_index_schema_version,
+            get_index_health,
+            run_index_maintenance,
+            reconcile_vault,
+            get_videos_needing_reembedding,
+            reembed_flagged_videos,
+            get_channel_profile,
+            refresh_channel_profile,
+            check_dead_channels,
+            get_memorial_report,
+            get_upload_cadence,
+            get_quiet_channels,
+            refresh_view_counts,
+            get_video_trends,
+            get_term_trends,
+            create_workspace,
+            list_workspaces,
+            get_workspace,
+            add_item,
+            remove_item,
+            delete_workspace,
+            export_workspace,
+            set_note_template,
+            get_note_template,
+            render_video_notes,
+            detect_video_references,
+            get_video_references,
+            save_ui_session,
+            get_ui_session,
+            bulk_update_metadata,
+            cancel_bulk_metadata_update,
+            mark_video_important,
+            get_important_videos,
+            get_resurfaced_videos,
+            review_resurfaced_video,
+            get_resurface_settings,
+            save_resurface_settings,
+            get_channel_timeline,
+            get_prompt_globals,
+            set_prompt_globals,
+            record_playback,
+            get_usage_report,
+            queue_next,
+            add_to_play_queue,
+            get_play_queue,
+            reorder_play_queue,
+            remove_from_play_queue,
+            pop_play_queue,
+            clear_play_queue,
+            create_share_link,
+            revoke_share_links,
+            search_transcripts,
+            get_text_search_settings,
+            save_text_search_settings,
+            get_vault_lock_status,
+            retry_vault_lock,
+            migrate_vault_layout,
+            list_layout_migrations,
+            revert_vault_layout_migration,
+            get_digest_settings,
+            save_digest_settings,
+            preview_topic_digest,
+            publish_digests_now,
+            get_digest_feed_urls,
+            watch_question,
+            list_watched_questions,
+            unwatch_question,
+            recheck_watched_question,
+            enqueue_download,
+            list_jobs,
+            cancel_job,
+            open_player_window,
+            open_transcript_window,
+            get_detached_view_video,
+            update_playback_state,
+            get_playback_state,
+            control_playback,
+            handoff_playback,
+            get_embedding_backend_settings,
+            save_embedding_backend_settings,
+            benchmark_embedding_backends,
+            get_last_run_report,
+            get_run_report_settings,
+            save_run_report_settings,
+            get_download_concurrency_settings,
+            save_download_concurrency_settings,
+            get_pipeline_backlog,
+            remediate_pipeline_stage,
+            get_aligned_transcript,
+            get_audio_url,
+            set_channel_date_range,
+            set_channel_download_policy,
+            download_videos_since,
+            estimate_channel_backfill,
+            set_channel_subtitle_languages,
+            get_trash_settings,
+            save_trash_settings,
+            list_trash,
+            restore_from_trash,
+            get_auth_settings,
+            save_auth_settings,
+            verify_auth,
+            get_resource_limits,
+            save_resource_limits,
+            get_mirror_settings,
+            save_mirror_settings,
+            get_proxy_settings,
+            save_proxy_settings,
+            set_channel_proxy,
+            test_proxy,
+            get_download_limits,
+            set_download_limits,
+            get_change_history,
+            list_change_batches,
+            revert_changes,
+            list_caption_languages,
+            get_download_history,
+            get_ingest_settings,
+            save_ingest_settings,
+            regenerate_ingest_token,
+            list_ingest_sessions,
+            get_channel_content_filters,
+            set_channel_content_filters,
+            get_segment_at,
+            get_update_settings,
+            save_update_settings,
+            check_for_updates,
+            install_update,
+            import_external_archive,
+            get_downloader_settings,
+            set_downloader_settings,
+            autocomplete,
+            resume_incomplete_downloads,
+            set_channel_state,
+            start_media_probe,
+            get_media_probe,
+            find_videos_by_media,
+            estimate_batch_download,
+            get_index_provenance,
+            reembed_outdated,
+            format_citation,
+            export_session_citations,
+            plan_batch_download,
+            get_download_plan,
+            set_plan_selection,
+            execute_plan,
+            get_notification_settings,
+            set_notification_settings,
+            get_disk_guard_settings,
+            set_disk_guard_settings,
+            get_vault_health,
+            update_channel_config,
+            invalidate_cache,
+            get_event_schemas,
+            reorder_channels,
+            import_channels,
+            export_channels,
+            get_channel_stats,
+            detect_split_channels,
+            merge_channel_folders,
+            suggest_channels,
+            get_channel_watch_settings,
+            save_channel_watch_settings,
+            check_new_uploads,
+            rebuild_index,
+            list_videos_paged,
+            build_text_index,
+            search_captions,
+            set_watch_position,
+            get_watch_position,
+            continue_watching,
+            toggle_favorite,
+            list_favorites,
+            create_playlist,
+            add_to_playlist,
+            remove_from_playlist,
+            reorder_playlist,
+            rename_playlist,
+            delete_playlist,
+            list_playlists
         ])
         .setup(|app| {
             let window = app.get_webview_window("main").unwrap();
             window.show().unwrap();
+            if let Err(e) = open_index_db() {
+                eprintln!("❌ 인덱스 DB 초기화 실패: {}", e);
+            }
+            // 앱이 꺼져 있는 동안의 vault 변경 (Obsidian 편집 등) 반영, 바뀐 것이 있으면 목록 다시 조회하도록 알림
+            // 읽기 전용 모드면 잠금을 가진 인스턴스가 인덱스를 관리하므로 건너뜀
+            let index_app = app.handle().clone();
+            if !vault_lock_state.read_only.load(Ordering::SeqCst) {
+                thread::spawn(move || match reconcile_vault_index() {
+                    Ok(report) if report.added + report.updated + report.removed > 0 => {
+                        let _ = index_app.emit("video-index-updated", &report);
+                    }
+                    Ok(_) => {}
+                    Err(e) => eprintln!("⚠️ 시작 시 인덱스 동기화 실패: {}", e),
+                });
+            }
+            let vault_job_state = app.state::<VaultJobState>().inner().clone();
+            spawn_housekeeping_scheduler(vault_lock_state.clone(), vault_job_state.clone());
+            spawn_index_backup_scheduler(embedding_state.clone(), vault_lock_state.clone());
+            spawn_index_maintenance_scheduler(download_state, embedding_state, vault_lock_state.clone(), vault_job_state.clone());
+            spawn_channel_profile_refresher(vault_lock_state.clone());
+            spawn_resurface_scheduler(app.handle().clone());
+            spawn_channel_watcher(app.handle().clone());
+            spawn_digest_scheduler(vault_lock_state.clone());
+            let ingest_state = app.state::<IngestServerState>().inner().clone();
+            let ingest_lock = vault_lock_state.clone();
+            let ingest_jobs = vault_job_state;
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = restart_ingest_server(&ingest_state, ingest_lock, ingest_jobs).await {
+                    eprintln!("⚠️ {}", e);
+                }
+            });
+            spawn_update_scheduler(app.handle().clone());
+            spawn_vault_lock_heartbeat(app.handle().clone(), vault_lock_state);
             Ok(())
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while running tauri application")
+        .run(|_app, event| {
+            if let tauri::RunEvent::Exit = event {
+                release_vault_lock();
+            }
+        });
+}
+
+#[cfg(test)]
+mod event_schema_tests {
+    use super::*;
+
+    fn sample_download_progress() -> DownloadProgress {
+        DownloadProgress {
+            channel: "채널".to_string(),
+            status: "진행 중".to_string(),
+            progress: 42.0,
+            current_video: "영상".to_string(),
+            total_videos: 10,
+            completed_videos: 4,
+            log_message: "로그".to_string(),
+            throughput_bps: Some(1024.0),
+        }
+    }
+
+    fn sample_ai_progress() -> AIProgressUpdate {
+        AIProgressUpdate {
+            step: "search".to_string(),
+            message: "검색 중".to_string(),
+            progress: 30.0,
+            details: Some("3개 문서".to_string()),
+        }
+    }
+
+    fn sample_payload(payload: &str) -> serde_json::Value {
+        match payload {
+            "DownloadProgress" => serde_json::to_value(sample_download_progress()).unwrap(),
+            "AIProgressUpdate" => serde_json::to_value(sample_ai_progress()).unwrap(),
+            other => panic!("샘플이 없는 페이로드: {}", other),
+        }
+    }
+
+    fn json_type_matches(value: &serde_json::Value, field_type: &str) -> bool {
+        match field_type {
+            "string" => value.is_string(),
+            "number" => value.is_number(),
+            "integer" => value.is_u64() || value.is_i64(),
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn schema_fields_match_serialized_payloads() {
+        for schema in event_schemas() {
+            let payload = sample_payload(schema.payload);
+            let object = payload.as_object().unwrap();
+            let mut serialized: Vec<&str> = object.keys().map(String::as_str).collect();
+            let mut declared: Vec<&str> = schema.fields.iter().map(|f| f.name).collect();
+            serialized.sort();
+            declared.sort();
+            assert_eq!(serialized, declared, "{} 스키마와 페이로드 필드가 다릅니다", schema.event);
+
+            for field in schema.fields {
+                assert!(
+                    json_type_matches(&object[field.name], field.field_type),
+                    "{}.{} 타입이 {}가 아닙니다",
+                    schema.event,
+                    field.name,
+                    field.field_type
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn event_names_are_unique() {
+        let schemas = event_schemas();
+        let names: std::collections::HashSet<&str> = schemas.iter().map(|s| s.event).collect();
+        assert_eq!(names.len(), schemas.len());
+    }
+
+    // v1 페이로드 (선택 필드 누락)도 계속 읽을 수 있어야 함
+    #[test]
+    fn v1_payloads_without_optional_fields_deserialize() {
+        let download: DownloadProgress = serde_json::from_value(serde_json::json!({
+            "channel": "채널",
+            "status": "시작",
+            "progress": 0.0,
+            "current_video": "",
+            "total_videos": 0,
+            "completed_videos": 0,
+            "log_message": "",
+        }))
+        .unwrap();
+        assert!(download.throughput_bps.is_none());
+
+        let ai: AIProgressUpdate = serde_json::from_value(serde_json::json!({
+            "step": "search",
+            "message": "검색 중",
+            "progress": 10.0,
+        }))
+        .unwrap();
+        assert!(ai.details.is_none());
+    }
+
+    #[test]
+    fn optional_fields_serialize_as_null() {
+        let mut progress = sample_download_progress();
+        progress.throughput_bps = None;
+        let value = serde_json::to_value(progress).unwrap();
+        for field in DOWNLOAD_PROGRESS_FIELDS {
+            assert!(field.optional || !value[field.name].is_null(), "필수 필드 {}가 null입니다", field.name);
+        }
+    }
 }
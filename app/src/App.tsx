@@ -1,4 +1,4 @@
-import React, { useEffect, useState } from 'react';
+import React, { useEffect, useRef, useState } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 import { convertFileSrc } from '@tauri-apps/api/core';
 import { listen } from '@tauri-apps/api/event';
@@ -22,17 +22,293 @@ interface VideoInfo {
   video_id?: string;
   source_url?: string;
   excerpt?: string;
+  transcript_quality?: number;
+  published_at?: string;
+  status?: 'ready' | 'pending' | 'media_removed';
+  pipeline?: PipelineStatus;
+  caption_languages?: string[];
+  caption_language?: string;
+  playlist?: string;
+  favorite?: boolean;
+}
+
+// 보다 만 영상 (이어보기)
+interface ContinueWatchingItem {
+  video: VideoInfo;
+  position_seconds: number;
+  duration_seconds?: number;
+  updated_at: string;
+}
+
+// 사용자 재생목록 (영상은 재생 순서대로, 인덱스에 없는 영상은 missing_video_ids)
+interface UserPlaylist {
+  id: string;
+  name: string;
+  description: string;
+  created_at: string;
+  updated_at: string;
+  video_ids: string[];
+  videos: VideoInfo[];
+  missing_video_ids: string[];
+}
+
+// 라이브러리 목록 한 페이지 (정렬/페이지 나눔은 백엔드 인덱스에서 처리)
+interface VideoPage {
+  videos: VideoInfo[];
+  total: number;
+  offset: number;
+  limit: number;
+}
+
+// 라이브러리 서버 측 필터 (비어 있는 항목은 조건 없음)
+interface LibraryFilter {
+  channel?: string;
+  topic?: string;
+  upload_from?: string;
+  upload_to?: string;
+  min_duration_seconds?: number;
+  max_duration_seconds?: number;
+  has_captions?: boolean;
+  watched?: boolean;
+  favorite?: boolean;
+}
+
+const LIBRARY_PAGE_SIZE = 200;
+
+type PipelineStage = 'downloaded' | 'captions' | 'thumbnails' | 'embedded' | 'summarized';
+
+interface PipelineStatus {
+  downloaded: boolean;
+  captions: boolean;
+  thumbnails: boolean;
+  embedded: boolean;
+  summarized: boolean;
+  stuck_at?: PipelineStage;
+  updated_at: string;
 }
 
 interface ChannelInfo {
   url: string;
   name: string;
   enabled: boolean;
+  memorialized?: boolean;
+  playlist?: boolean;
+  state?: ChannelState;
+  // channels.toml의 채널별 설정
+  quality?: string | null;
+  language?: string | null;
+  group?: string | null;
+  filters?: { skip_shorts: boolean; skip_live: boolean; min_duration_seconds: number | null };
+}
+
+// 채널 상세 통계 (get_channel_stats)
+interface ChannelStats {
+  channel: string;
+  video_count: number;
+  total_duration_seconds: number;
+  disk_usage_bytes: number;
+  oldest_upload: string | null;
+  newest_upload: string | null;
+  captioned_videos: number;
+  caption_coverage: number;
+  embedded_videos: number | null;
+  embedding_coverage: number | null;
+}
+
+// 채널 ID가 같은데 폴더가 나뉜 채널 (detect_split_channels)
+interface SplitChannelGroup {
+  channel_id: string;
+  folders: { folder: string; video_count: number; newest_upload: string | null }[]; // vault/10_videos 아래 채널 폴더 이름
+  suggested_target: string;
+}
+
+// 보관된 영상의 주제 태그로 찾은 관련 채널 (suggest_channels)
+interface ChannelSuggestions {
+  keywords: string[];
+  suggestions: {
+    channel: string;
+    channel_id: string | null;
+    url: string;
+    matched_keywords: string[];
+    sample_titles: string[];
+    hits: number;
+  }[];
+}
+
+// 일시정지/보관 채널은 다운로드하지 않음 (보관 채널 영상은 기본 목록에서 숨김, 검색/AI 질문에는 포함)
+type ChannelState = 'active' | 'paused' | 'archived';
+
+// yt-dlp 인증 (멤버십/연령 제한 영상용)
+interface AuthSettings {
+  cookies_file?: string | null;
+  browser?: string | null;
+  browser_profile?: string | null;
+}
+
+// 다른 기기에서 녹화 파일을 올리는 업로드 서버 (/api/ingest)
+interface IngestSettings {
+  enabled: boolean;
+  port: number;
+  token: string;
+  default_channel: string;
+  transcribe: boolean;
+}
+
+// yt-dlp 요청 간격/타임아웃 (config/downloader_settings.json)
+interface DownloaderSettings {
+  sleep_interval: number;
+  max_sleep_interval: number;
+  sleep_requests: number;
+  socket_timeout: number;
+  retries: number;
+  full_scan_socket_timeout: number;
+  full_scan_retries: number;
+  resume_partials: boolean;
+  download_inactivity_timeout: number;
+  full_scan_inactivity_timeout: number;
+  single_inactivity_timeout: number;
+}
+
+// 디스크 여유 공간 보호 (GB)
+interface DiskGuardSettings {
+  min_free_gb: number;
+  warn_free_gb: number;
+  block_below_min: boolean;
+}
+
+// 보관소 종합 상태 (점수 + 우선순위별 권장 조치)
+interface HealthCheck {
+  key: string;
+  label: string;
+  ok: boolean;
+  penalty: number;
+  detail: string;
+}
+
+interface HealthAction {
+  priority: 'high' | 'medium' | 'low';
+  title: string;
+  description: string;
+  command: string;
+  args: Record<string, unknown>;
+}
+
+interface VaultHealth {
+  score: number;
+  level: 'good' | 'warning' | 'critical';
+  summary: string;
+  checked_at: string;
+  checks: HealthCheck[];
+  actions: HealthAction[];
+}
+
+interface DiskSpaceWarning {
+  available_bytes: number;
+  min_free_bytes: number;
+  warn_free_bytes: number;
+  aborting: boolean;
+  message: string;
+}
+
+// 작업 완료 데스크톱 알림 (작업 종류별)
+interface NotificationSettings {
+  downloads: boolean;
+  conversions: boolean;
+  embeddings: boolean;
+  integrity_checks: boolean;
+}
+
+// 새 업로드 감시 (채널 RSS 피드 주기 확인)
+interface ChannelWatchSettings {
+  enabled: boolean;
+  interval_minutes: number;
+  auto_download: boolean;
+}
+
+// new-video-available 이벤트
+interface NewVideoAvailable {
+  channel: string;
+  channel_url: string;
+  video_id: string;
+  title: string;
+  url: string;
+  published_at: string | null;
+  thumbnail_url: string;
+  auto_download: boolean;
+}
+
+// 앱 자동 업데이트 (릴리스 채널, 단계적 배포)
+interface UpdateSettings {
+  channel: 'stable' | 'beta';
+  auto_check: boolean;
+  auto_install: boolean;
+  install_id: string;
+  last_checked_at?: string | null;
+}
+
+interface UpdateCheckResult {
+  current_version: string;
+  channel: 'stable' | 'beta';
+  available: boolean;
+  version?: string | null;
+  changelog?: string | null;
+  published_at?: string | null;
+  rollout_percentage: number;
+  in_rollout: boolean;
+  deferred_by: string[];
+  signature_verification: boolean;
+}
+
+// ffprobe로 수집한 영상 기술 정보 (인덱스에 저장)
+interface MediaProbe {
+  video_codec?: string | null;
+  width?: number | null;
+  height?: number | null;
+  fps?: number | null;
+  bitrate?: number | null;
+  audio_codec?: string | null;
+  audio_channels?: number | null;
+  error?: string | null;
+}
+
+interface MediaProbeProgress {
+  probed: number;
+  total: number;
+  current: string;
+  done: boolean;
+}
+
+// 인덱스 동기화/재구성 결과
+interface ReconcileReport {
+  scanned: number;
+  added: number;
+  updated: number;
+  removed: number;
+  flagged_for_reembedding: string[];
+}
+
+// Tube Archivist / Pinchflat 아카이브 가져오기 결과
+interface ExternalImportReport {
+  source: string;
+  dry_run: boolean;
+  found: number;
+  imported: string[];
+  skipped_existing: number;
+  missing_media: string[];
+  watch_state_preserved: number;
+  failed: string[];
+}
+
+interface AuthCheckResult {
+  authenticated: boolean;
+  source: string;
+  message: string;
 }
 
 interface AppStatus {
   total_videos: number;
   total_channels: number;
+  memorialized_channels?: number;
   vault_size_mb: number;
   last_download?: string;
   vector_db_status: string;
@@ -55,6 +331,43 @@ interface DownloadProgress {
   total_videos: number;
   completed_videos: number;
   log_message: string;
+  throughput_bps?: number | null;
+}
+
+// 배치 다운로드 계획 (plan_batch_download → set_plan_selection → execute_plan)
+type PlanItemStatus = 'pending' | 'downloading' | 'downloaded' | 'failed';
+
+interface PlannedVideo {
+  video_id: string;
+  title: string;
+  upload_date?: string | null;
+  duration_seconds: number;
+  estimated_bytes: number;
+  estimated_seconds: number;
+  selected: boolean;
+  status: PlanItemStatus;
+  error?: string | null;
+}
+
+interface DownloadPlan {
+  id: string;
+  since?: string | null;
+  channels: { channel: string; url: string; already_archived: number; videos: PlannedVideo[] }[];
+  failed: { channel: string; error: string }[];
+  selected_videos: number;
+  estimated_bytes: number;
+  estimated_seconds: number;
+  job_id?: string | null;
+}
+
+interface PlanItemProgress {
+  plan_id: string;
+  channel: string;
+  video_id: string;
+  status: PlanItemStatus;
+  index: number;
+  total: number;
+  error?: string | null;
 }
 
 interface CaptionLine {
@@ -202,7 +515,14 @@ export default function App() {
   // 비디오 관련 상태
   const [videos, setVideos] = useState<VideoInfo[]>([]);
   const [recentVideos, setRecentVideos] = useState<RecentVideos>({ channels: [] });
+  const [continueWatching, setContinueWatching] = useState<ContinueWatchingItem[]>([]);
   const [selectedVideo, setSelectedVideo] = useState<VideoInfo | null>(null);
+  const [playlists, setPlaylists] = useState<UserPlaylist[]>([]);
+  const [newPlaylistName, setNewPlaylistName] = useState('');
+  const [playingPlaylist, setPlayingPlaylist] = useState<{ playlist: UserPlaylist; index: number } | null>(null);
+  const reportedWatchSecondsRef = useRef(0); // 시청 기록으로 이미 전송한 재생 시간
+  const pendingSeekRef = useRef<number | null>(null); // 다른 플레이어에서 넘겨받은 재생 위치
+  const savedWatchPositionRef = useRef(0); // 마지막으로 저장한 이어보기 위치
   const [captions, setCaptions] = useState<CaptionLine[]>([]);
   const [fuse, setFuse] = useState<Fuse<CaptionLine>>();
   
@@ -222,6 +542,34 @@ export default function App() {
   // 채널 관련 상태
   const [channels, setChannels] = useState<ChannelInfo[]>([]);
   const [newChannelUrl, setNewChannelUrl] = useState('');
+  const [channelImport, setChannelImport] = useState({ path: '', format: '' });
+  const [channelExport, setChannelExport] = useState({ path: '', format: 'json' });
+  const [channelStats, setChannelStats] = useState<Record<string, ChannelStats>>({});
+  const [splitChannels, setSplitChannels] = useState<SplitChannelGroup[] | null>(null);
+  const [channelSuggestions, setChannelSuggestions] = useState<ChannelSuggestions | null>(null);
+  const [suggestingChannels, setSuggestingChannels] = useState(false);
+  
+  // 인증 설정 상태
+  const [authSettings, setAuthSettings] = useState<AuthSettings>({});
+  const [authCheck, setAuthCheck] = useState<AuthCheckResult | null>(null);
+  const [authChecking, setAuthChecking] = useState(false);
+  const [ingestSettings, setIngestSettings] = useState<IngestSettings | null>(null);
+  const [updateSettings, setUpdateSettings] = useState<UpdateSettings | null>(null);
+  const [downloaderSettings, setDownloaderSettings] = useState<DownloaderSettings | null>(null);
+  const [notificationSettings, setNotificationSettings] = useState<NotificationSettings | null>(null);
+  const [channelWatchSettings, setChannelWatchSettings] = useState<ChannelWatchSettings | null>(null);
+  const [checkingUploads, setCheckingUploads] = useState(false);
+  const [diskGuardSettings, setDiskGuardSettings] = useState<DiskGuardSettings | null>(null);
+  const [vaultHealth, setVaultHealth] = useState<VaultHealth | null>(null);
+  const [vaultHealthLoading, setVaultHealthLoading] = useState(false);
+  const [updateCheck, setUpdateCheck] = useState<UpdateCheckResult | null>(null);
+  const [updateChecking, setUpdateChecking] = useState(false);
+  const [externalImport, setExternalImport] = useState({ source: 'tube_archivist', exportPath: '', mediaRoot: '' });
+  const [externalImportReport, setExternalImportReport] = useState<ExternalImportReport | null>(null);
+  const [externalImporting, setExternalImporting] = useState(false);
+  const [mediaProbeProgress, setMediaProbeProgress] = useState<MediaProbeProgress | null>(null);
+  const [rebuildingIndex, setRebuildingIndex] = useState(false);
+  const [ingestUrl, setIngestUrl] = useState<string | null>(null);
   
   // 검색 관련 상태
   const [searchQuery, setSearchQuery] = useState('');
@@ -231,6 +579,10 @@ export default function App() {
   const [keywordSearchQuery, setKeywordSearchQuery] = useState('');
   const [keywordSearchResults, setKeywordSearchResults] = useState<VideoInfo[]>([]);
   const [filteredVideos, setFilteredVideos] = useState<VideoInfo[]>([]);
+  const [captionLanguageFilter, setCaptionLanguageFilter] = useState('');
+  const [includeArchived, setIncludeArchived] = useState(false);
+  const [libraryPage, setLibraryPage] = useState<VideoPage | null>(null);
+  const [libraryFilter, setLibraryFilter] = useState<LibraryFilter>({});
   const [sortOrder, setSortOrder] = useState<'date' | 'title' | 'views' | 'duration'>('date');
   const [sortDirection, setSortDirection] = useState<'asc' | 'desc'>('desc');
   
@@ -254,6 +606,8 @@ export default function App() {
   const [downloadProgress, setDownloadProgress] = useState<DownloadProgress | null>(null);
   const [downloadLogs, setDownloadLogs] = useState<string[]>([]);
   const [showProgressModal, setShowProgressModal] = useState(false);
+  const [downloadPlan, setDownloadPlan] = useState<DownloadPlan | null>(null);
+  const [planLoading, setPlanLoading] = useState(false);
   const [videoQuality, setVideoQuality] = useState<string>('480p');
   const [downloadMode, setDownloadMode] = useState<'fast' | 'full'>('fast'); // 다운로드 모드 상태 추가
   
@@ -321,10 +675,41 @@ export default function App() {
     }
   };
 
-  // 비디오 목록이 변경될 때 필터링된 목록 업데이트
+  // 설정 탭을 열면 저장된 인증 설정 불러오기
   useEffect(() => {
-    setFilteredVideos(videos);
-  }, [videos]);
+    if (activeTab !== 'settings') return;
+    invoke<AuthSettings>('get_auth_settings')
+      .then(setAuthSettings)
+      .catch(err => console.error('인증 설정 로드 실패:', err));
+    invoke<IngestSettings>('get_ingest_settings')
+      .then(setIngestSettings)
+      .catch(err => console.error('업로드 설정 로드 실패:', err));
+    invoke<UpdateSettings>('get_update_settings')
+      .then(setUpdateSettings)
+      .catch(err => console.error('업데이트 설정 로드 실패:', err));
+    invoke<DownloaderSettings>('get_downloader_settings')
+      .then(setDownloaderSettings)
+      .catch(err => console.error('다운로더 설정 로드 실패:', err));
+    invoke<NotificationSettings>('get_notification_settings')
+      .then(setNotificationSettings)
+      .catch(err => console.error('알림 설정 로드 실패:', err));
+    invoke<ChannelWatchSettings>('get_channel_watch_settings')
+      .then(setChannelWatchSettings)
+      .catch(err => console.error('새 업로드 감시 설정 로드 실패:', err));
+    invoke<DiskGuardSettings>('get_disk_guard_settings')
+      .then(setDiskGuardSettings)
+      .catch(err => console.error('디스크 보호 설정 로드 실패:', err));
+    loadVaultHealth();
+  }, [activeTab]);
+
+  // 자막 언어 필터 (빈 값이면 전체)
+  const byCaptionLanguage = (list: VideoInfo[]) =>
+    captionLanguageFilter ? list.filter(video => video.caption_language === captionLanguageFilter) : list;
+
+  // 비디오 목록이나 언어 필터가 변경될 때 필터링된 목록 업데이트
+  useEffect(() => {
+    setFilteredVideos(byCaptionLanguage(keywordSearchQuery.trim() ? keywordSearchResults : videos));
+  }, [videos, captionLanguageFilter]);
 
   // 자막 필터가 변경될 때 자동으로 필터링 적용
   useEffect(() => {
@@ -333,6 +718,28 @@ export default function App() {
     }
   }, [captionFilter, captions]);
 
+  // 분리된 창/시스템 플레이어에서 재생을 넘겨받으면 같은 위치에서 이어보기
+  useEffect(() => {
+    const unlistenHandoff = listen<{ video_id: string; position_seconds: number; target: string }>('playback-handoff', (event) => {
+      const { video_id, position_seconds, target } = event.payload;
+      if (target !== 'embedded') return;
+      const video = videos.find(v => v.video_id === video_id);
+      if (!video) return;
+      pendingSeekRef.current = position_seconds;
+      setSelectedVideo(video);
+    });
+    return () => { unlistenHandoff.then(fn => fn()); };
+  }, [videos]);
+
+  // 재생을 다른 플레이어로 넘기기 (일시정지 시 onPause가 시청 시간을 기록)
+  const handoffPlayback = (player: HTMLVideoElement | null, target: 'detached' | 'system') => {
+    if (!selectedVideo?.video_id) return;
+    const position = player ? player.currentTime : 0;
+    player?.pause();
+    invoke('handoff_playback', { videoId: selectedVideo.video_id, position, target })
+      .catch(err => alert(`재생 넘기기 실패: ${err}`));
+  };
+
   // 초기 데이터 로드
   useEffect(() => {
     loadAppData();
@@ -380,6 +787,35 @@ export default function App() {
       setIntegrityLogs(prev => [...prev, progress.log_message].slice(-50));
     });
     
+    // 영상 기술 정보 수집 진행 상황
+    // 디스크 여유 공간 부족 (시작 전 확인 또는 다운로드 중 주기 확인)
+    const unlistenDiskSpace = listen<DiskSpaceWarning>('disk-space-low', (event) => {
+      setDownloadLogs(prev => [...prev, event.payload.message].slice(-100));
+    });
+    
+    // 감시 중인 채널의 새 업로드 (자동 다운로드는 백엔드 감시 작업이 이미 대기열에 추가함)
+    const unlistenNewVideo = listen<NewVideoAvailable>('new-video-available', (event) => {
+      const video = event.payload;
+      const suffix = video.auto_download ? ' → 다운로드 대기열에 추가됨' : '';
+      setDownloadLogs(prev => [...prev, `🆕 새 영상: [${video.channel}] ${video.title}${suffix}`].slice(-100));
+    });
+    
+    // 다운로드 계획 항목별 진행 상황
+    const unlistenPlan = listen<PlanItemProgress>('download-plan-progress', (event) => {
+      const item = event.payload;
+      setDownloadPlan(prev => prev && prev.id === item.plan_id ? {
+        ...prev,
+        channels: prev.channels.map(c => c.channel !== item.channel ? c : {
+          ...c,
+          videos: c.videos.map(v => v.video_id === item.video_id ? { ...v, status: item.status, error: item.error } : v),
+        }),
+      } : prev);
+    });
+    
+    const unlistenMediaProbe = listen<MediaProbeProgress>('media-probe-progress', (event) => {
+      setMediaProbeProgress(event.payload);
+    });
+    
     // 비디오 변환 진행 상황 이벤트 리스너
     const unlistenConversion = listen<DownloadProgress>('conversion-progress', (event) => {
       const progress = event.payload;
@@ -432,16 +868,94 @@ export default function App() {
       unlistenEmbedding.then(f => f());
       unlistenIntegrity.then(f => f());
       unlistenConversion.then(f => f());
+      unlistenMediaProbe.then(f => f());
+      unlistenPlan.then(f => f());
+      unlistenNewVideo.then(f => f());
+      unlistenDiskSpace.then(f => f());
     };
   }, []);
 
+  // 보관 채널 포함 여부를 바꾸면 비디오 목록만 다시 조회
+  const toggleIncludeArchived = async (include: boolean) => {
+    setIncludeArchived(include);
+    try {
+      setVideos(await invoke<VideoInfo[]>('list_videos', { includeArchived: include }));
+    } catch (err) {
+      setError(`비디오 목록 조회 실패: ${err}`);
+    }
+  };
+
+  // 앱 시작 시 vault 동기화로 인덱스가 바뀌면 목록 다시 조회 (list_videos는 인덱스만 읽음)
+  useEffect(() => {
+    const unlistenIndex = listen('video-index-updated', async () => {
+      try {
+        setVideos(await invoke<VideoInfo[]>('list_videos', { includeArchived }));
+      } catch (err) {
+        console.warn('비디오 목록 다시 조회 실패:', err);
+      }
+    });
+    return () => { unlistenIndex.then(fn => fn()); };
+  }, [includeArchived]);
+
+  // 라이브러리 목록 페이지 조회 (offset 0이면 처음부터, 아니면 이어 붙임)
+  const loadLibraryPage = async (offset: number) => {
+    const sortBy = { date: 'upload_date', title: 'title', views: 'view_count', duration: 'duration' }[sortOrder];
+    try {
+      const page = await invoke<VideoPage>('list_videos_paged', {
+        offset,
+        limit: LIBRARY_PAGE_SIZE,
+        sortBy,
+        order: sortDirection,
+        filter: { ...libraryFilter, caption_language: captionLanguageFilter || null, include_archived: includeArchived },
+      });
+      setLibraryPage(prev => (offset > 0 && prev ? { ...page, videos: [...prev.videos, ...page.videos] } : page));
+    } catch (err) {
+      console.error('비디오 목록 페이지 조회 실패:', err);
+    }
+  };
+
+  // 정렬/필터가 바뀌거나 전체 목록을 다시 읽으면 첫 페이지부터 조회
+  useEffect(() => {
+    loadLibraryPage(0);
+  }, [videos, sortOrder, sortDirection, captionLanguageFilter, includeArchived, libraryFilter]);
+
+  // 필터 항목 변경 (빈 값이면 조건 제거)
+  const updateLibraryFilter = (patch: LibraryFilter) => {
+    setLibraryFilter(prev => {
+      const next = { ...prev, ...patch };
+      (Object.keys(next) as (keyof LibraryFilter)[]).forEach(key => {
+        if (next[key] === '' || next[key] === undefined) delete next[key];
+      });
+      return next;
+    });
+  };
+
+  // 분 단위 입력을 초로 변환
+  const minutesToSeconds = (value: string) => (value === '' ? undefined : Math.round(Number(value) * 60));
+  const booleanOption = (value: string) => (value === '' ? undefined : value === 'yes');
+
+  // 인덱스를 비우고 vault 전체를 다시 읽음
+  const rebuildIndex = async () => {
+    if (!confirm('영상 인덱스를 처음부터 다시 만듭니다. 영상이 많으면 시간이 걸립니다. 계속할까요?')) return;
+    setRebuildingIndex(true);
+    try {
+      const report = await invoke<ReconcileReport>('rebuild_index');
+      alert(`인덱스 재구성 완료: 영상 ${report.added}개`);
+      await loadAppData();
+    } catch (err) {
+      alert(`인덱스 재구성 실패: ${err}`);
+    } finally {
+      setRebuildingIndex(false);
+    }
+  };
+
   const loadAppData = async () => {
     try {
       setLoading(true);
       const [statusResult, videosResult, channelsResult, recentResult, availableChannelsResult] = await Promise.all([
         invoke<AppStatus>('get_app_status'),
-        invoke<VideoInfo[]>('list_videos'),
-        invoke<ChannelInfo[]>('list_channels'),
+        invoke<VideoInfo[]>('list_videos', { includeArchived }),
+        invoke<ChannelInfo[]>('list_channels', { includeArchived: true }),
         invoke<RecentVideos>('get_recent_videos_by_channel', { limitPerChannel: 5 }),
         invoke<string[]>('get_available_channels_for_embedding')
       ]);
@@ -450,6 +964,10 @@ export default function App() {
       setVideos(videosResult);
       setChannels(channelsResult);
       setRecentVideos(recentResult);
+      invoke<ContinueWatchingItem[]>('continue_watching', { limit: 10 })
+        .then(setContinueWatching)
+        .catch(err => console.warn('이어보기 목록 조회 실패:', err));
+      loadPlaylists();
       setAvailableChannels(availableChannelsResult);
       setSelectedChannels(availableChannelsResult); // 기본적으로 모든 채널 선택
       setError(null);
@@ -517,6 +1035,20 @@ export default function App() {
         setCaptionLoading(false);
       });
 
+    // 수집된 기술 정보가 있으면 실제 코덱/해상도 표시
+    const indexedCodecInfo = async (videoPath: string) => {
+      try {
+        const probe = await invoke<MediaProbe | null>('get_media_probe', { videoPath });
+        if (!probe?.video_codec) return null;
+        const resolution = probe.height ? ` ${probe.height}p` : '';
+        const fps = probe.fps ? ` ${Math.round(probe.fps)}fps` : '';
+        const audio = probe.audio_codec ? ` · ${probe.audio_codec.toUpperCase()} ${probe.audio_channels ?? ''}ch` : '';
+        return `${probe.video_codec.toUpperCase()}${resolution}${fps}${audio}`;
+      } catch {
+        return null;
+      }
+    };
+
     // 비디오 URL 생성 (서버가 실행 중인 경우) 또는 서버 자동 시작
     const generateVideoUrl = async () => {
       if (videoServerPort) {
@@ -525,7 +1057,7 @@ export default function App() {
           const url = await invoke<string>('get_video_url', { videoPath: selectedVideo.video_path });
           setVideoUrl(url);
           setVideoError(null);
-          setCodecInfo('MP4 컨테이너 (H.264 또는 AV1 코덱)');
+          setCodecInfo(await indexedCodecInfo(selectedVideo.video_path) ?? 'MP4 컨테이너 (H.264 또는 AV1 코덱)');
         } catch (error) {
           console.error('비디오 URL 생성 실패:', error);
           setVideoUrl(null);
@@ -542,7 +1074,7 @@ export default function App() {
           const url = await invoke<string>('get_video_url', { videoPath: selectedVideo.video_path });
           setVideoUrl(url);
           setVideoError(null);
-          setCodecInfo('MP4 컨테이너 (H.264 또는 AV1 코덱)');
+          setCodecInfo(await indexedCodecInfo(selectedVideo.video_path) ?? 'MP4 컨테이너 (H.264 또는 AV1 코덱)');
         } catch (error) {
           console.error('서버 자동 시작 또는 URL 생성 실패:', error);
           setVideoUrl(null);
@@ -557,6 +1089,151 @@ export default function App() {
   }, [selectedVideo, videoServerPort]);
 
   // 비디오 에러 처리
+  // 재생한 구간 합계의 증가분을 시청 기록으로 전송 (로컬 사용량 리포트용)
+  const reportPlayback = (e: React.SyntheticEvent<HTMLVideoElement, Event>) => {
+    if (!selectedVideo) return;
+    const played = e.currentTarget.played;
+    let total = 0;
+    for (let i = 0; i < played.length; i++) {
+      total += played.end(i) - played.start(i);
+    }
+    const delta = total - reportedWatchSecondsRef.current;
+    if (delta < 1) return;
+    reportedWatchSecondsRef.current = total;
+    invoke('record_playback', { videoPath: selectedVideo.video_path, seconds: delta })
+      .catch(err => console.warn('시청 기록 저장 실패:', err));
+  };
+
+  // 이어보기 위치 저장 (재생 중에는 10초마다, 일시정지/종료 시에는 항상)
+  const saveWatchPosition = (e: React.SyntheticEvent<HTMLVideoElement, Event>, force = false) => {
+    if (!selectedVideo?.video_id) return;
+    const player = e.currentTarget;
+    if (!force && Math.abs(player.currentTime - savedWatchPositionRef.current) < 10) return;
+    savedWatchPositionRef.current = player.currentTime;
+    const duration = Number.isFinite(player.duration) ? player.duration : null;
+    invoke('set_watch_position', { videoId: selectedVideo.video_id, seconds: player.currentTime, duration })
+      .catch(err => console.warn('재생 위치 저장 실패:', err));
+  };
+
+  // 저장된 위치에서 이어서 재생 (다른 플레이어에서 넘겨받은 위치가 있으면 그쪽 우선)
+  const resumeWatchPosition = async (player: HTMLVideoElement) => {
+    savedWatchPositionRef.current = 0;
+    if (!selectedVideo?.video_id) return;
+    try {
+      const position = await invoke<number | null>('get_watch_position', { videoId: selectedVideo.video_id });
+      if (position !== null && player.currentTime < 1) {
+        player.currentTime = position;
+        savedWatchPositionRef.current = position;
+      }
+    } catch (err) {
+      console.warn('재생 위치 조회 실패:', err);
+    }
+  };
+
+  // 즐겨찾기 전환 후 목록/선택 영상에 바로 반영
+  const toggleFavorite = async (video: VideoInfo) => {
+    if (!video.video_id) return;
+    try {
+      const favorite = await invoke<boolean>('toggle_favorite', { videoId: video.video_id });
+      const apply = (v: VideoInfo) => (v.video_id === video.video_id ? { ...v, favorite } : v);
+      setVideos(prev => prev.map(apply));
+      setLibraryPage(prev => (prev ? { ...prev, videos: prev.videos.map(apply) } : prev));
+      setSelectedVideo(prev => (prev ? apply(prev) : prev));
+    } catch (err) {
+      console.error('즐겨찾기 변경 실패:', err);
+      alert(`즐겨찾기 변경 실패: ${err}`);
+    }
+  };
+
+  const loadPlaylists = async () => {
+    try {
+      setPlaylists(await invoke<UserPlaylist[]>('list_playlists'));
+    } catch (err) {
+      console.warn('재생목록 조회 실패:', err);
+    }
+  };
+
+  const createPlaylist = async () => {
+    if (!newPlaylistName.trim()) return;
+    try {
+      await invoke('create_playlist', { name: newPlaylistName });
+      setNewPlaylistName('');
+      await loadPlaylists();
+    } catch (err) {
+      alert(`재생목록 생성 실패: ${err}`);
+    }
+  };
+
+  const addToPlaylist = async (playlistId: string, video: VideoInfo) => {
+    if (!video.video_id) return;
+    try {
+      await invoke('add_to_playlist', { playlistId, videoId: video.video_id });
+      await loadPlaylists();
+    } catch (err) {
+      alert(`재생목록 추가 실패: ${err}`);
+    }
+  };
+
+  const removeFromPlaylist = async (playlistId: string, videoId: string) => {
+    try {
+      await invoke('remove_from_playlist', { playlistId, videoId });
+      await loadPlaylists();
+    } catch (err) {
+      alert(`재생목록에서 제거 실패: ${err}`);
+    }
+  };
+
+  // 한 칸 위/아래로 이동
+  const movePlaylistItem = async (playlist: UserPlaylist, index: number, delta: number) => {
+    const target = index + delta;
+    if (target < 0 || target >= playlist.video_ids.length) return;
+    const videoIds = [...playlist.video_ids];
+    [videoIds[index], videoIds[target]] = [videoIds[target], videoIds[index]];
+    try {
+      await invoke('reorder_playlist', { playlistId: playlist.id, videoIds });
+      await loadPlaylists();
+    } catch (err) {
+      alert(`재생목록 순서 변경 실패: ${err}`);
+      loadPlaylists();
+    }
+  };
+
+  const deletePlaylist = async (playlist: UserPlaylist) => {
+    if (!confirm(`재생목록 "${playlist.name}"을(를) 삭제할까요?`)) return;
+    try {
+      await invoke('delete_playlist', { playlistId: playlist.id });
+      if (playingPlaylist?.playlist.id === playlist.id) setPlayingPlaylist(null);
+      await loadPlaylists();
+    } catch (err) {
+      alert(`재생목록 삭제 실패: ${err}`);
+    }
+  };
+
+  // 재생목록의 index번째 영상부터 순서대로 재생
+  const playPlaylist = (playlist: UserPlaylist, index = 0) => {
+    const video = playlist.videos[index];
+    if (!video) return;
+    pendingSeekRef.current = 0;
+    setPlayingPlaylist({ playlist, index });
+    setSelectedVideo(video);
+    setActiveTab('videos');
+  };
+
+  // 재생이 끝나면 재생목록의 다음 영상으로 (다른 영상을 직접 고른 경우는 중단)
+  const advancePlaylist = () => {
+    if (!playingPlaylist || !selectedVideo) return;
+    const { playlist, index } = playingPlaylist;
+    if (playlist.videos[index]?.video_id !== selectedVideo.video_id) {
+      setPlayingPlaylist(null);
+      return;
+    }
+    if (index + 1 < playlist.videos.length) {
+      playPlaylist(playlist, index + 1);
+    } else {
+      setPlayingPlaylist(null);
+    }
+  };
+
   const handleVideoError = (e: React.SyntheticEvent<HTMLVideoElement, Event>) => {
     console.error('Video load error:', e);
     const video = e.currentTarget;
@@ -710,7 +1387,7 @@ export default function App() {
   const performKeywordSearch = () => {
     if (!keywordSearchQuery.trim()) {
       setKeywordSearchResults([]);
-      setFilteredVideos(videos);
+      setFilteredVideos(byCaptionLanguage(videos));
       return;
     }
     
@@ -723,7 +1400,7 @@ export default function App() {
     );
     
     setKeywordSearchResults(results);
-    setFilteredVideos(results);
+    setFilteredVideos(byCaptionLanguage(results));
   };
 
   // 정렬 함수
@@ -795,7 +1472,18 @@ export default function App() {
     if (!newChannelUrl.trim()) return;
     
     try {
-      await invoke('add_channel', { url: newChannelUrl });
+      // 추가 전에 URL 정규화 + 채널 존재 확인
+      const validation = await invoke<{ url: string; name: string; playlist: boolean; already_added: boolean }>(
+        'validate_channel_url',
+        { url: newChannelUrl }
+      );
+      if (validation.already_added) {
+        alert(`이미 추가된 채널입니다: ${validation.name}`);
+        return;
+      }
+      const kind = validation.playlist ? '재생목록' : '채널';
+      if (!confirm(`${kind} '${validation.name}'을(를) 추가할까요?\n${validation.url}`)) return;
+      await invoke('add_channel', { url: validation.url });
       setNewChannelUrl('');
       loadAppData();
     } catch (err) {
@@ -803,6 +1491,103 @@ export default function App() {
     }
   };
 
+  // 관련 채널 추천 (주제 태그별 YouTube 검색이라 시간이 걸림)
+  const suggestChannels = async () => {
+    setSuggestingChannels(true);
+    try {
+      setChannelSuggestions(await invoke<ChannelSuggestions>('suggest_channels'));
+    } catch (err) {
+      alert(`채널 추천 실패: ${err}`);
+    } finally {
+      setSuggestingChannels(false);
+    }
+  };
+
+  const addSuggestedChannel = async (url: string) => {
+    try {
+      await invoke('add_channel', { url });
+      if (channelSuggestions) {
+        setChannelSuggestions({
+          ...channelSuggestions,
+          suggestions: channelSuggestions.suggestions.filter((s) => s.url !== url),
+        });
+      }
+      loadAppData();
+    } catch (err) {
+      alert(`채널 추가 실패: ${err}`);
+    }
+  };
+
+  // 구독 목록 가져오기 (OPML / CSV / Google Takeout, 형식 미지정 시 자동 감지)
+  const importChannels = async () => {
+    if (!channelImport.path.trim()) return;
+    try {
+      const report = await invoke<{ added: string[]; skipped: string[]; invalid: string[] }>('import_channels', {
+        path: channelImport.path.trim(),
+        format: channelImport.format || null,
+      });
+      alert(`채널 가져오기 완료: 추가 ${report.added.length}개, 건너뜀 ${report.skipped.length}개, 인식 실패 ${report.invalid.length}개`);
+      setChannelImport({ path: '', format: '' });
+      loadAppData();
+    } catch (err) {
+      alert(`채널 가져오기 실패: ${err}`);
+    }
+  };
+
+  // 채널 목록 내보내기 (경로가 없으면 클립보드로 복사)
+  const exportChannels = async () => {
+    const path = channelExport.path.trim();
+    try {
+      const content = await invoke<string>('export_channels', { format: channelExport.format, path: path || null });
+      if (path) {
+        alert(`채널 목록을 저장했습니다: ${path}`);
+      } else {
+        await navigator.clipboard.writeText(content);
+        alert('채널 목록을 클립보드에 복사했습니다');
+      }
+    } catch (err) {
+      alert(`채널 내보내기 실패: ${err}`);
+    }
+  };
+
+  // 채널 통계 펼치기/접기
+  const toggleChannelStats = async (channelName: string) => {
+    if (channelStats[channelName]) {
+      const { [channelName]: _, ...rest } = channelStats;
+      setChannelStats(rest);
+      return;
+    }
+    try {
+      const stats = await invoke<ChannelStats>('get_channel_stats', { channel: channelName });
+      setChannelStats({ ...channelStats, [channelName]: stats });
+    } catch (err) {
+      alert(`채널 통계 조회 실패: ${err}`);
+    }
+  };
+
+  // 핸들 변경 등으로 나뉜 채널 폴더 찾기
+  const detectSplitChannels = async () => {
+    try {
+      setSplitChannels(await invoke<SplitChannelGroup[]>('detect_split_channels'));
+    } catch (err) {
+      alert(`채널 폴더 확인 실패: ${err}`);
+    }
+  };
+
+  // 나뉜 채널 폴더 병합 (old 폴더의 영상을 new 폴더로 이동)
+  const mergeChannelFolders = async (oldChannel: string, newChannel: string) => {
+    if (!confirm(`'${oldChannel}' 폴더의 영상을 '${newChannel}' 폴더로 옮길까요?`)) return;
+    try {
+      const report = await invoke<{ moved: number; skipped: string[] }>('merge_channel_folders', { old: oldChannel, new: newChannel });
+      const skipped = report.skipped.length > 0 ? `\n이미 있어 건너뜀 ${report.skipped.length}개 (기존 폴더 유지)` : '';
+      alert(`영상 ${report.moved}개를 옮겼습니다${skipped}`);
+      await detectSplitChannels();
+      loadAppData();
+    } catch (err) {
+      alert(`채널 폴더 병합 실패: ${err}`);
+    }
+  };
+
   // 채널 삭제
   const removeChannel = async (url: string) => {
     try {
@@ -813,6 +1598,20 @@ export default function App() {
     }
   };
 
+  // 채널 순서 변경 (목록/최근 영상/배치 다운로드에 반영)
+  const moveChannel = async (index: number, offset: number) => {
+    const target = index + offset;
+    if (target < 0 || target >= channels.length) return;
+    const urls = channels.map(channel => channel.url);
+    [urls[index], urls[target]] = [urls[target], urls[index]];
+    try {
+      await invoke('reorder_channels', { orderedUrls: urls });
+      loadAppData();
+    } catch (err) {
+      alert(`채널 순서 변경 실패: ${err}`);
+    }
+  };
+
   // 채널 토글
   const toggleChannel = async (url: string) => {
     try {
@@ -823,6 +1622,166 @@ export default function App() {
     }
   };
 
+  // channels.toml 채널 설정 변경 (화질/자막 언어/그룹)
+  const updateChannelConfig = async (channel: ChannelInfo, changes: Partial<ChannelInfo>) => {
+    const next = { ...channel, ...changes };
+    try {
+      await invoke('update_channel_config', {
+        url: channel.url,
+        quality: next.quality ?? null,
+        language: next.language ?? null,
+        group: next.group ?? null,
+        filters: next.filters ?? null,
+      });
+      loadAppData();
+    } catch (err) {
+      alert(`채널 설정 변경 실패: ${err}`);
+    }
+  };
+
+  // 채널 상태 변경 (활성/일시정지/보관)
+  const changeChannelState = async (channelName: string, state: ChannelState) => {
+    try {
+      await invoke('set_channel_state', { channelName, state });
+      loadAppData();
+    } catch (err) {
+      alert(`채널 상태 변경 실패: ${err}`);
+    }
+  };
+
+  // 인증 설정 저장 후 바로 확인
+  const saveAndVerifyAuth = async () => {
+    setAuthChecking(true);
+    setAuthCheck(null);
+    try {
+      await invoke('save_auth_settings', { settings: authSettings });
+      setAuthCheck(await invoke<AuthCheckResult>('verify_auth'));
+    } catch (err) {
+      alert(`인증 설정 실패: ${err}`);
+    } finally {
+      setAuthChecking(false);
+    }
+  };
+
+  // 업로드 서버 설정 저장 (서버 재시작 후 접속 주소 표시)
+  const saveIngestSettings = async (settings: IngestSettings) => {
+    try {
+      setIngestUrl(await invoke<string | null>('save_ingest_settings', { settings }));
+      setIngestSettings(await invoke<IngestSettings>('get_ingest_settings'));
+    } catch (err) {
+      alert(`업로드 설정 실패: ${err}`);
+    }
+  };
+
+  const saveUpdateSettings = async (settings: UpdateSettings) => {
+    try {
+      await invoke('save_update_settings', { settings });
+      setUpdateSettings(settings);
+    } catch (err) {
+      alert(`업데이트 설정 실패: ${err}`);
+    }
+  };
+
+  const saveDownloaderSettings = async (settings: DownloaderSettings) => {
+    try {
+      setDownloaderSettings(await invoke<DownloaderSettings>('set_downloader_settings', { settings }));
+    } catch (err) {
+      alert(`다운로더 설정 실패: ${err}`);
+    }
+  };
+
+  const loadVaultHealth = async () => {
+    setVaultHealthLoading(true);
+    try {
+      setVaultHealth(await invoke<VaultHealth>('get_vault_health'));
+    } catch (err) {
+      console.error('보관소 상태 조회 실패:', err);
+    } finally {
+      setVaultHealthLoading(false);
+    }
+  };
+
+  const runHealthAction = async (action: HealthAction) => {
+    try {
+      const result = await invoke(action.command, action.args);
+      if (typeof result === 'string') {
+        alert(result);
+      }
+    } catch (err) {
+      alert(`${action.title} 실패: ${err}`);
+    } finally {
+      loadVaultHealth();
+    }
+  };
+
+  const saveDiskGuardSettings = async (settings: DiskGuardSettings) => {
+    try {
+      setDiskGuardSettings(await invoke<DiskGuardSettings>('set_disk_guard_settings', { settings }));
+    } catch (err) {
+      alert(`디스크 보호 설정 실패: ${err}`);
+    }
+  };
+
+  const saveNotificationSettings = async (settings: NotificationSettings) => {
+    try {
+      setNotificationSettings(await invoke<NotificationSettings>('set_notification_settings', { settings }));
+    } catch (err) {
+      alert(`알림 설정 실패: ${err}`);
+    }
+  };
+
+  const saveChannelWatchSettings = async (settings: ChannelWatchSettings) => {
+    try {
+      await invoke('save_channel_watch_settings', { settings });
+      setChannelWatchSettings(settings);
+    } catch (err) {
+      alert(`새 업로드 감시 설정 실패: ${err}`);
+    }
+  };
+
+  // 새 업로드 즉시 확인 (찾은 영상은 new-video-available 이벤트로도 처리됨)
+  const checkNewUploads = async () => {
+    setCheckingUploads(true);
+    try {
+      const videos = await invoke<NewVideoAvailable[]>('check_new_uploads');
+      alert(videos.length > 0 ? `새 영상 ${videos.length}개를 찾았습니다` : '새 영상이 없습니다');
+    } catch (err) {
+      alert(`새 업로드 확인 실패: ${err}`);
+    } finally {
+      setCheckingUploads(false);
+    }
+  };
+
+  // 업데이트 확인/설치 (작업 중이면 설치가 거부되어 오류로 표시)
+  const runUpdateCommand = async (command: 'check_for_updates' | 'install_update') => {
+    setUpdateChecking(true);
+    try {
+      setUpdateCheck(await invoke<UpdateCheckResult>(command));
+    } catch (err) {
+      alert(`업데이트 실패: ${err}`);
+    } finally {
+      setUpdateChecking(false);
+    }
+  };
+
+  // 다른 아카이브 도구에서 가져오기 (미리보기 후 실제 가져오기)
+  const runExternalImport = async (dryRun: boolean) => {
+    setExternalImporting(true);
+    try {
+      setExternalImportReport(await invoke<ExternalImportReport>('import_external_archive', {
+        source: externalImport.source,
+        exportPath: externalImport.exportPath,
+        mediaRoot: externalImport.mediaRoot || null,
+        dryRun,
+      }));
+      if (!dryRun) await loadAppData();
+    } catch (err) {
+      alert(`가져오기 실패: ${err}`);
+    } finally {
+      setExternalImporting(false);
+    }
+  };
+
   // 비디오 다운로드 (진행 상황 포함) - 빠른 확인 모드
   const downloadVideos = async () => {
     setDownloadLoading(true);
@@ -862,6 +1821,16 @@ export default function App() {
   // 통합 다운로드 함수 (모드에 따라 다른 함수 호출)
   const executeDownload = async () => {
     if (downloadMode === 'full') {
+      // 전체 검사는 받을 용량이 클 수 있으므로 먼저 예상치를 확인
+      try {
+        const estimate = await invoke<{ video_count: number; estimated_bytes: number; estimated_seconds: number; warning?: string | null }>('estimate_batch_download');
+        if (estimate.warning) {
+          const hours = (estimate.estimated_seconds / 3600).toFixed(1);
+          if (!confirm(`${estimate.warning}\n영상 ${estimate.video_count}개 · 예상 소요 약 ${hours}시간\n계속할까요?`)) return;
+        }
+      } catch (err) {
+        console.warn('다운로드 예상치 계산 실패:', err);
+      }
       await downloadVideosFullScan();
     } else {
       await downloadVideos();
@@ -891,6 +1860,26 @@ export default function App() {
       return;
     }
 
+    // 임베딩 모델/청크 설정이 바뀐 채널은 섞이지 않도록 먼저 재임베딩 안내
+    let outdated: string[] = [];
+    try {
+      const provenance = await invoke<{ current: { embedding_model: string }; collections: { channel: string; embedding_model: string; expected_model?: string; outdated: boolean }[] }>('get_index_provenance');
+      const outdatedCollections = provenance.collections
+        .filter(c => c.outdated && selectedChannels.includes(c.channel));
+      outdated = outdatedCollections.map(c => c.channel);
+      if (outdated.length > 0 && !confirm(
+        `임베딩 모델이 바뀌어 ${outdated.length}개 채널은 기존 벡터와 섞을 수 없습니다.\n` +
+        outdatedCollections
+          .map(c => `- ${c.channel}: ${c.embedding_model} → ${c.expected_model ?? provenance.current.embedding_model}`)
+          .join('\n') +
+        `\n해당 채널을 새 모델로 처음부터 다시 임베딩할까요?\n(취소하면 해당 채널은 건너뜁니다)`
+      )) {
+        outdated = [];
+      }
+    } catch (err) {
+      console.warn('인덱스 출처 조회 실패:', err);
+    }
+
     setEmbedLoading(true);
     setEmbeddingProgress(null);
     setEmbeddingLogs([]);
@@ -898,6 +1887,9 @@ export default function App() {
     setShowChannelSelector(false);
     
     try {
+      if (outdated.length > 0) {
+        await invoke<string>('reembed_outdated', { channels: outdated });
+      }
       const result = await invoke<string>('create_embeddings_for_channels_with_progress', { 
         channels: selectedChannels 
       });
@@ -938,13 +1930,67 @@ export default function App() {
     }
   };
 
-  // 다운로드 중단
-  const cancelDownload = async () => {
+  // 다운로드 중단
+  const cancelDownload = async () => {
+    try {
+      await invoke('cancel_download');
+      setDownloadLogs(prev => [...prev, '🛑 사용자가 다운로드를 중단했습니다']);
+    } catch (err) {
+      setDownloadLogs(prev => [...prev, `❌ 중단 실패: ${err}`]);
+    }
+  };
+
+  // 다운로드 계획 미리보기 (다운로드 없이 받을 영상 목록과 예상 용량/시간)
+  const createDownloadPlan = async () => {
+    setPlanLoading(true);
+    try {
+      setDownloadPlan(await invoke<DownloadPlan>('plan_batch_download'));
+    } catch (err) {
+      alert(`계획 생성 실패: ${err}`);
+    } finally {
+      setPlanLoading(false);
+    }
+  };
+
+  const setPlanSelection = async (selected: boolean, videoIds: string[], channel?: string) => {
+    if (!downloadPlan) return;
+    try {
+      setDownloadPlan(await invoke<DownloadPlan>('set_plan_selection', {
+        planId: downloadPlan.id,
+        videoIds,
+        channel: channel ?? null,
+        selected,
+      }));
+    } catch (err) {
+      console.error('계획 선택 변경 실패:', err);
+    }
+  };
+
+  const executeDownloadPlan = async () => {
+    if (!downloadPlan) return;
+    try {
+      const jobId = await invoke<string>('execute_plan', { planId: downloadPlan.id });
+      setDownloadPlan({ ...downloadPlan, job_id: jobId });
+      setDownloadLogs(prev => [...prev, `📋 계획 실행 시작: ${downloadPlan.selected_videos}개 영상`]);
+    } catch (err) {
+      alert(`계획 실행 실패: ${err}`);
+    }
+  };
+
+  // 중단된 다운로드 이어받기 (남아 있는 .part 파일)
+  const resumeIncompleteDownloads = async () => {
     try {
-      await invoke('cancel_download');
-      setDownloadLogs(prev => [...prev, '🛑 사용자가 다운로드를 중단했습니다']);
+      const resumed = await invoke<{ job_id: string; title: string; partial_bytes: number }[]>('resume_incomplete_downloads');
+      if (resumed.length === 0) {
+        setDownloadLogs(prev => [...prev, 'ℹ️ 이어받을 다운로드가 없습니다']);
+        return;
+      }
+      setDownloadLogs(prev => [
+        ...prev,
+        ...resumed.map(r => `⏯️ 이어받기: ${r.title} (${(r.partial_bytes / (1024 * 1024)).toFixed(1)}MB 받음)`),
+      ]);
     } catch (err) {
-      setDownloadLogs(prev => [...prev, `❌ 중단 실패: ${err}`]);
+      setDownloadLogs(prev => [...prev, `❌ 이어받기 실패: ${err}`]);
     }
   };
   
@@ -964,7 +2010,8 @@ export default function App() {
   // 채널별로 비디오 그룹화
   const groupVideosByChannel = (videos: VideoInfo[]) => {
     const grouped = videos.reduce((acc, video) => {
-      const channel = video.channel || '알 수 없는 채널';
+      // 재생목록 소스로 받은 영상은 재생목록 단위로 묶음
+      const channel = video.playlist ? `📃 ${video.playlist}` : (video.channel || '알 수 없는 채널');
       if (!acc[channel]) {
         acc[channel] = [];
       }
@@ -1023,6 +2070,15 @@ export default function App() {
                 <div className="stat-label">보관함 크기</div>
               </div>
             </div>
+            <div className="stat-card">
+              <div className="stat-icon">🕒</div>
+              <div className="stat-content">
+                <div className="stat-number">
+                  {appStatus.last_download ? new Date(appStatus.last_download).toLocaleDateString() : '-'}
+                </div>
+                <div className="stat-label">마지막 다운로드</div>
+              </div>
+            </div>
           </div>
           
           <div className="dashboard-actions">
@@ -1056,6 +2112,14 @@ export default function App() {
               {downloadLoading ? '📥 다운로드 중...' : '📥 다운로드 시작'}
             </button>
             
+            <button 
+              onClick={createDownloadPlan} 
+              disabled={planLoading || downloadLoading}
+              className={`action-btn secondary ${planLoading ? 'loading' : ''}`}
+            >
+              {planLoading ? '📋 계획 계산 중...' : '📋 계획 미리보기'}
+            </button>
+            
             <button 
               onClick={() => setShowChannelSelector(true)} 
               disabled={embedLoading}
@@ -1075,6 +2139,82 @@ export default function App() {
         </div>
 
         <div className="dashboard-content">
+          {continueWatching.length > 0 && (
+            <div className="channel-section">
+              <div className="channel-header">
+                <h2 className="channel-title">▶️ 이어보기</h2>
+              </div>
+              <div className="video-list">
+                {continueWatching.map(item => (
+                  <div
+                    key={`continue-${item.video.video_id}`}
+                    className="video-item"
+                    onClick={() => {
+                      setSelectedVideo(item.video);
+                      setActiveTab('videos');
+                    }}
+                  >
+                    <div className="video-details">
+                      <h4 className="video-title-small">{item.video.title}</h4>
+                      <div className="video-meta-small">
+                        <span>📺 {item.video.channel}</span>
+                        <span className="video-duration">
+                          ⏱️ {formatDuration(Math.floor(item.position_seconds))}
+                          {item.duration_seconds ? ` / ${formatDuration(Math.floor(item.duration_seconds))}` : ''}
+                        </span>
+                      </div>
+                    </div>
+                  </div>
+                ))}
+              </div>
+            </div>
+          )}
+          <div className="channel-section">
+            <div className="channel-header">
+              <h2 className="channel-title">🎵 재생목록</h2>
+              <div className="playlist-create">
+                <input
+                  type="text"
+                  placeholder="새 재생목록 이름"
+                  value={newPlaylistName}
+                  onChange={(e) => setNewPlaylistName(e.target.value)}
+                  onKeyDown={(e) => e.key === 'Enter' && createPlaylist()}
+                />
+                <button className="sort-button" onClick={createPlaylist} disabled={!newPlaylistName.trim()}>만들기</button>
+              </div>
+            </div>
+            {playlists.map(playlist => (
+              <div key={playlist.id} className="playlist-card">
+                <div className="playlist-card-header">
+                  <h4 className="video-title-small">
+                    {playlist.name} ({playlist.videos.length}개)
+                    {playlist.missing_video_ids.length > 0 && ` · 찾을 수 없는 영상 ${playlist.missing_video_ids.length}개`}
+                  </h4>
+                  <button className="sort-button" onClick={() => playPlaylist(playlist)} disabled={playlist.videos.length === 0}>▶️ 재생</button>
+                  <button className="sort-button" onClick={() => deletePlaylist(playlist)}>삭제</button>
+                </div>
+                {playlist.videos.map((video, index) => (
+                  <div key={`${playlist.id}-${video.video_id}`} className="playlist-item">
+                    <span className="playlist-item-index">{index + 1}</span>
+                    <span className="playlist-item-title" onClick={() => playPlaylist(playlist, index)}>
+                      {video.title} <span className="video-meta-small">📺 {video.channel}</span>
+                    </span>
+                    <button
+                      className="sort-button"
+                      onClick={() => movePlaylistItem(playlist, playlist.video_ids.indexOf(video.video_id!), -1)}
+                      disabled={index === 0}
+                    >↑</button>
+                    <button
+                      className="sort-button"
+                      onClick={() => movePlaylistItem(playlist, playlist.video_ids.indexOf(video.video_id!), 1)}
+                      disabled={index === playlist.videos.length - 1}
+                    >↓</button>
+                    <button className="sort-button" onClick={() => removeFromPlaylist(playlist.id, video.video_id!)}>✕</button>
+                  </div>
+                ))}
+              </div>
+            ))}
+          </div>
           {recentVideos.channels.map((channel, channelIndex) => {
             // 인기 비디오 (전체 기간 중 조회수 상위 5개)
             const popularVideos = [...channel.videos]
@@ -1192,8 +2332,70 @@ export default function App() {
     );
   }
 
+  const planStatusIcon: Record<PlanItemStatus, string> = {
+    pending: '⏸️',
+    downloading: '📥',
+    downloaded: '✅',
+    failed: '❌',
+  };
+
   return (
     <>
+      {/* 다운로드 계획 모달 */}
+      {downloadPlan && (
+        <div className="modal-overlay">
+          <div className="progress-modal download-plan-modal">
+            <div className="modal-header">
+              <h3>📋 다운로드 계획</h3>
+              <button className="modal-close-btn" onClick={() => setDownloadPlan(null)}>✕</button>
+            </div>
+            <div className="usage-step">
+              선택 {downloadPlan.selected_videos}개 · 약 {(downloadPlan.estimated_bytes / 1024 / 1024 / 1024).toFixed(1)}GB
+              · 약 {(downloadPlan.estimated_seconds / 3600).toFixed(1)}시간
+            </div>
+            {downloadPlan.failed.map(f => (
+              <div key={f.channel} className="usage-step">⚠️ {f.channel}: {f.error}</div>
+            ))}
+            <div className="download-plan-list">
+              {downloadPlan.channels.map(channel => (
+                <div key={channel.channel} className="download-plan-channel">
+                  <label className="download-plan-channel-header">
+                    <input
+                      type="checkbox"
+                      checked={channel.videos.length > 0 && channel.videos.every(v => v.selected)}
+                      onChange={(e) => setPlanSelection(e.target.checked, [], channel.channel)}
+                    />
+                    <strong>{channel.channel}</strong> ({channel.videos.length}개, 보관됨 {channel.already_archived}개)
+                  </label>
+                  {channel.videos.map(video => (
+                    <label key={video.video_id} className="download-plan-item" title={video.error || undefined}>
+                      <input
+                        type="checkbox"
+                        checked={video.selected}
+                        disabled={video.status === 'downloading' || video.status === 'downloaded'}
+                        onChange={(e) => setPlanSelection(e.target.checked, [video.video_id], channel.channel)}
+                      />
+                      <span>{planStatusIcon[video.status]}</span>
+                      <span className="download-plan-title">{video.title}</span>
+                      <span>{(video.estimated_bytes / 1024 / 1024).toFixed(0)}MB</span>
+                    </label>
+                  ))}
+                </div>
+              ))}
+            </div>
+            <div className="progress-actions">
+              <button
+                className="action-btn primary"
+                onClick={executeDownloadPlan}
+                disabled={downloadPlan.selected_videos === 0}
+              >
+                ▶️ 선택한 영상 다운로드
+              </button>
+            </div>
+          </div>
+        </div>
+      )}
+
       {/* 진행 상황 모달 */}
       {showProgressModal && (
         <div className="modal-overlay">
@@ -1216,6 +2418,9 @@ export default function App() {
                   <span>📊 상태: {downloadProgress.status}</span>
                   <span>📈 진행률: {downloadProgress.progress.toFixed(1)}%</span>
                   <span>🎬 완료: {downloadProgress.completed_videos}/{downloadProgress.total_videos}</span>
+                  {downloadProgress.throughput_bps != null && (
+                    <span>⚡ 속도: {(downloadProgress.throughput_bps / (1024 * 1024)).toFixed(2)}MB/s</span>
+                  )}
                   <span className="mode-indicator">
                     {downloadMode === 'full' ? '전체 검사' : '빠른 확인'}
                   </span>
@@ -1237,6 +2442,13 @@ export default function App() {
                   >
                     🛑 다운로드 중단
                   </button>
+                  <button
+                    className="cancel-btn"
+                    onClick={resumeIncompleteDownloads}
+                    disabled={downloadLoading}
+                  >
+                    ⏯️ 중단된 다운로드 이어받기
+                  </button>
                 </div>
               </div>
             )}
@@ -1558,7 +2770,7 @@ export default function App() {
                 type="text"
                 value={newChannelUrl}
                 onChange={(e) => setNewChannelUrl(e.target.value)}
-                placeholder="YouTube 채널 URL을 입력하세요 (예: https://www.youtube.com/@채널명)"
+                placeholder="YouTube 채널 또는 재생목록 URL을 입력하세요 (예: https://www.youtube.com/@채널명)"
                 className="channel-input"
                 onKeyPress={(e) => e.key === 'Enter' && addChannel()}
               />
@@ -1566,6 +2778,113 @@ export default function App() {
                 ➕ 채널 추가
               </button>
             </div>
+            <div className="channel-add-section">
+              <input
+                type="text"
+                value={channelImport.path}
+                onChange={(e) => setChannelImport({ ...channelImport, path: e.target.value })}
+                placeholder="구독 목록 파일 경로 (OPML, CSV, Google Takeout subscriptions.csv/json)"
+                className="channel-input"
+              />
+              <select
+                value={channelImport.format}
+                onChange={(e) => setChannelImport({ ...channelImport, format: e.target.value })}
+                className="channel-state-select"
+              >
+                <option value="">자동 감지</option>
+                <option value="opml">OPML</option>
+                <option value="csv">CSV</option>
+                <option value="takeout">Google Takeout</option>
+                <option value="json">Y-Data-House 백업 (JSON)</option>
+              </select>
+              <button onClick={importChannels} className="add-channel-button">
+                📥 가져오기
+              </button>
+            </div>
+            <div className="channel-add-section">
+              <input
+                type="text"
+                value={channelExport.path}
+                onChange={(e) => setChannelExport({ ...channelExport, path: e.target.value })}
+                placeholder="내보낼 파일 경로 (비워 두면 클립보드로 복사)"
+                className="channel-input"
+              />
+              <select
+                value={channelExport.format}
+                onChange={(e) => setChannelExport({ ...channelExport, format: e.target.value })}
+                className="channel-state-select"
+              >
+                <option value="json">JSON (설정 포함 백업)</option>
+                <option value="opml">OPML</option>
+              </select>
+              <button onClick={exportChannels} className="add-channel-button">
+                📤 내보내기
+              </button>
+              <button onClick={detectSplitChannels} className="add-channel-button">
+                🔀 나뉜 채널 폴더 찾기
+              </button>
+              <button onClick={suggestChannels} className="add-channel-button" disabled={suggestingChannels}>
+                {suggestingChannels ? '⏳ 찾는 중...' : '💡 관련 채널 추천'}
+              </button>
+            </div>
+            {channelSuggestions && (
+              <div className="split-channels">
+                <div className="channel-url">키워드: {channelSuggestions.keywords.join(', ')}</div>
+                {channelSuggestions.suggestions.length === 0 && <div className="channel-url">추천할 채널이 없습니다</div>}
+                {channelSuggestions.suggestions.map((suggestion) => (
+                  <div key={suggestion.url} className="channel-item">
+                    <div className="channel-info">
+                      <div className="channel-name">💡 {suggestion.channel}</div>
+                      <div className="channel-url">{suggestion.url}</div>
+                      <div className="channel-stats">
+                        <span>키워드 {suggestion.matched_keywords.join(', ')}</span>
+                        {suggestion.sample_titles.map((title) => (
+                          <span key={title}>{title}</span>
+                        ))}
+                      </div>
+                    </div>
+                    <div className="channel-actions">
+                      <button onClick={() => addSuggestedChannel(suggestion.url)} className="channel-toggle-button">
+                        ➕ 추가
+                      </button>
+                    </div>
+                  </div>
+                ))}
+              </div>
+            )}
+            {splitChannels && (
+              <div className="split-channels">
+                {splitChannels.length === 0 && <div className="channel-url">나뉜 채널 폴더가 없습니다</div>}
+                {splitChannels.map((group) => (
+                  <div key={group.channel_id} className="channel-item">
+                    <div className="channel-info">
+                      <div className="channel-name">🔀 {group.suggested_target}</div>
+                      <div className="channel-url">채널 ID: {group.channel_id}</div>
+                      <div className="channel-stats">
+                        {group.folders.map((folder) => (
+                          <span key={folder.folder}>
+                            {folder.folder} · 영상 {folder.video_count}개{folder.newest_upload && ` · 최근 ${folder.newest_upload}`}
+                          </span>
+                        ))}
+                      </div>
+                    </div>
+                    <div className="channel-actions">
+                      {group.folders
+                        .filter((folder) => folder.channel !== group.suggested_target)
+                        .map((folder) => (
+                          <button
+                            key={folder.channel}
+                            onClick={() => mergeChannelFolders(folder.channel, group.suggested_target)}
+                            className="channel-toggle-button"
+                          >
+                            {folder.channel} → 병합
+                          </button>
+                        ))}
+                    </div>
+                  </div>
+                ))}
+              </div>
+            )}
 
             <div className="channel-list">
               {channels.length > 0 ? (
@@ -1573,17 +2892,89 @@ export default function App() {
                   <div key={index} className="channel-item">
                     <div className="channel-info">
                       <div className="channel-name">
-                        {channel.enabled ? '✅' : '❌'} {channel.name}
+                        {channel.enabled ? '✅' : '❌'} {channel.playlist && '📃 '}{channel.name}
+                        {channel.state === 'paused' && ' (일시정지)'}
+                        {channel.state === 'archived' && ' (보관됨)'}
                       </div>
                       <div className="channel-url">{channel.url}</div>
+                      <div className="channel-config">
+                        <input
+                          type="text"
+                          defaultValue={channel.group ?? ''}
+                          placeholder="그룹"
+                          onBlur={(e) => e.target.value !== (channel.group ?? '') && updateChannelConfig(channel, { group: e.target.value })}
+                        />
+                        <select
+                          value={channel.quality ?? ''}
+                          onChange={(e) => updateChannelConfig(channel, { quality: e.target.value })}
+                          title="채널 전용 화질 (기본값은 전역 설정)"
+                        >
+                          <option value="">기본 화질</option>
+                          <option value="1080p">1080p</option>
+                          <option value="720p">720p</option>
+                          <option value="480p">480p</option>
+                        </select>
+                        <input
+                          type="text"
+                          defaultValue={channel.language ?? ''}
+                          placeholder="자막 언어 (예: ko)"
+                          onBlur={(e) => e.target.value !== (channel.language ?? '') && updateChannelConfig(channel, { language: e.target.value })}
+                        />
+                      </div>
+                      {channelStats[channel.name] && (() => {
+                        const stats = channelStats[channel.name];
+                        const percent = (ratio: number | null) => ratio === null ? '-' : `${Math.round(ratio * 100)}%`;
+                        return (
+                          <div className="channel-stats">
+                            <span>🎥 {stats.video_count}개</span>
+                            <span>⏱️ {(stats.total_duration_seconds / 3600).toFixed(1)}시간</span>
+                            <span>💾 {(stats.disk_usage_bytes / 1024 / 1024 / 1024).toFixed(2)}GB</span>
+                            <span>📅 {stats.oldest_upload ?? '-'} ~ {stats.newest_upload ?? '-'}</span>
+                            <span>📝 자막 {percent(stats.caption_coverage)}</span>
+                            <span>🧠 임베딩 {percent(stats.embedding_coverage)}</span>
+                          </div>
+                        );
+                      })()}
                     </div>
                     <div className="channel-actions">
+                      <button
+                        onClick={() => moveChannel(index, -1)}
+                        className="channel-toggle-button"
+                        disabled={index === 0}
+                        title="위로"
+                      >
+                        ⬆️
+                      </button>
+                      <button
+                        onClick={() => moveChannel(index, 1)}
+                        className="channel-toggle-button"
+                        disabled={index === channels.length - 1}
+                        title="아래로"
+                      >
+                        ⬇️
+                      </button>
+                      <button
+                        onClick={() => toggleChannelStats(channel.name)}
+                        className="channel-toggle-button"
+                      >
+                        📊 통계
+                      </button>
                       <button 
                         onClick={() => toggleChannel(channel.url)}
                         className="channel-toggle-button"
                       >
                         {channel.enabled ? '⏸️ 비활성화' : '▶️ 활성화'}
                       </button>
+                      <select
+                        value={channel.state ?? 'active'}
+                        onChange={(e) => changeChannelState(channel.name, e.target.value as ChannelState)}
+                        className="channel-state-select"
+                        title="일시정지/보관 채널은 다운로드하지 않습니다. 보관 채널의 영상은 목록에서 숨겨지지만 검색과 AI 질문에는 포함됩니다."
+                      >
+                        <option value="active">활성</option>
+                        <option value="paused">일시정지</option>
+                        <option value="archived">보관</option>
+                      </select>
                       <button 
                         onClick={() => removeChannel(channel.url)}
                         className="channel-remove-button"
@@ -1629,7 +3020,7 @@ export default function App() {
                       onClick={() => {
                         setKeywordSearchQuery('');
                         setKeywordSearchResults([]);
-                        setFilteredVideos(videos);
+                        setFilteredVideos(byCaptionLanguage(videos));
                       }} 
                       className="clear-search-button"
                     >
@@ -1640,6 +3031,26 @@ export default function App() {
               </div>
               
               <div className="sort-controls">
+                <label className="sort-label">자막 언어:</label>
+                <select
+                  value={captionLanguageFilter}
+                  onChange={(e) => setCaptionLanguageFilter(e.target.value)}
+                >
+                  <option value="">전체</option>
+                  {[...new Set(videos.map(v => v.caption_language).filter(Boolean))].sort().map(language => (
+                    <option key={language} value={language}>
+                      {language} ({videos.filter(v => v.caption_language === language).length})
+                    </option>
+                  ))}
+                </select>
+                <label className="sort-label">
+                  <input
+                    type="checkbox"
+                    checked={includeArchived}
+                    onChange={(e) => toggleIncludeArchived(e.target.checked)}
+                  />
+                  보관 채널 포함
+                </label>
                 <label className="sort-label">정렬:</label>
                 <button 
                   onClick={() => handleSortChange('date')}
@@ -1666,6 +3077,76 @@ export default function App() {
                   ⏱️ 길이 {sortOrder === 'duration' && (sortDirection === 'desc' ? '↓' : '↑')}
                 </button>
               </div>
+
+              <div className="sort-controls library-filters">
+                <label className="sort-label">필터:</label>
+                <select value={libraryFilter.channel ?? ''} onChange={(e) => updateLibraryFilter({ channel: e.target.value })}>
+                  <option value="">모든 채널</option>
+                  {[...new Set(videos.map(v => v.channel))].sort().map(channel => (
+                    <option key={channel} value={channel}>{channel}</option>
+                  ))}
+                </select>
+                <input
+                  type="text"
+                  placeholder="주제 태그"
+                  value={libraryFilter.topic ?? ''}
+                  onChange={(e) => updateLibraryFilter({ topic: e.target.value })}
+                />
+                <input
+                  type="date"
+                  title="업로드 시작일"
+                  value={libraryFilter.upload_from ?? ''}
+                  onChange={(e) => updateLibraryFilter({ upload_from: e.target.value })}
+                />
+                <span>~</span>
+                <input
+                  type="date"
+                  title="업로드 종료일"
+                  value={libraryFilter.upload_to ?? ''}
+                  onChange={(e) => updateLibraryFilter({ upload_to: e.target.value })}
+                />
+                <input
+                  type="number"
+                  min={0}
+                  placeholder="최소 분"
+                  value={libraryFilter.min_duration_seconds !== undefined ? libraryFilter.min_duration_seconds / 60 : ''}
+                  onChange={(e) => updateLibraryFilter({ min_duration_seconds: minutesToSeconds(e.target.value) })}
+                />
+                <input
+                  type="number"
+                  min={0}
+                  placeholder="최대 분"
+                  value={libraryFilter.max_duration_seconds !== undefined ? libraryFilter.max_duration_seconds / 60 : ''}
+                  onChange={(e) => updateLibraryFilter({ max_duration_seconds: minutesToSeconds(e.target.value) })}
+                />
+                <select
+                  value={libraryFilter.has_captions === undefined ? '' : libraryFilter.has_captions ? 'yes' : 'no'}
+                  onChange={(e) => updateLibraryFilter({ has_captions: booleanOption(e.target.value) })}
+                >
+                  <option value="">자막 전체</option>
+                  <option value="yes">자막 있음</option>
+                  <option value="no">자막 없음</option>
+                </select>
+                <select
+                  value={libraryFilter.watched === undefined ? '' : libraryFilter.watched ? 'yes' : 'no'}
+                  onChange={(e) => updateLibraryFilter({ watched: booleanOption(e.target.value) })}
+                >
+                  <option value="">시청 전체</option>
+                  <option value="yes">시청함</option>
+                  <option value="no">안 본 영상</option>
+                </select>
+                <label className="library-filter-checkbox">
+                  <input
+                    type="checkbox"
+                    checked={libraryFilter.favorite === true}
+                    onChange={(e) => updateLibraryFilter({ favorite: e.target.checked ? true : undefined })}
+                  />
+                  ⭐ 즐겨찾기만
+                </label>
+                {Object.keys(libraryFilter).length > 0 && (
+                  <button className="sort-button" onClick={() => setLibraryFilter({})}>초기화</button>
+                )}
+              </div>
             </div>
 
             {/* 검색 결과 표시 */}
@@ -1678,11 +3159,17 @@ export default function App() {
             <div className="video-layout">
               <div className="video-sidebar">
                 <h3 className="sidebar-title">
-                  비디오 목록 ({filteredVideos.length}개
-                  {keywordSearchQuery && `/${videos.length}개`})
+                  {!keywordSearchQuery.trim() && libraryPage ? (
+                    <>비디오 목록 ({libraryPage.videos.length}/{libraryPage.total}개)</>
+                  ) : (
+                    <>
+                      비디오 목록 ({filteredVideos.length}개
+                      {(keywordSearchQuery || captionLanguageFilter) && `/${videos.length}개`})
+                    </>
+                  )}
                 </h3>
                 <div className="video-list">
-                  {groupVideosByChannel(filteredVideos).map(([channelName, channelVideos]) => (
+                  {groupVideosByChannel(!keywordSearchQuery.trim() && libraryPage ? libraryPage.videos : filteredVideos).map(([channelName, channelVideos]) => (
                     <div key={channelName} className="channel-group">
                       <div 
                         className="channel-group-header"
@@ -1700,13 +3187,19 @@ export default function App() {
                           {channelVideos.map((video, index) => (
                             <div
                               key={`${channelName}-${index}`}
-                              className={`video-item ${selectedVideo && (selectedVideo.video_id === video.video_id || selectedVideo.video_path === video.video_path) ? 'video-item-active' : ''}`}
-                              onClick={() => setSelectedVideo(video)}
+                              className={`video-item ${selectedVideo && (selectedVideo.video_id === video.video_id || selectedVideo.video_path === video.video_path) ? 'video-item-active' : ''} ${video.status === 'pending' ? 'video-item-pending' : ''}`}
+                              onClick={() => video.status !== 'pending' && setSelectedVideo(video)}
                             >
+                              {video.status === 'pending' && (
+                                <div className="video-date-small">⏳ 다운로드 중</div>
+                              )}
+                              {video.status === 'media_removed' && (
+                                <div className="video-date-small">🗑️ 영상 삭제됨 (자막만 보관)</div>
+                              )}
                               {video.upload_date && (
                                 <div className="video-date-small">📅 {video.upload_date}</div>
                               )}
-                              <div className="video-title">{video.title}</div>
+                              <div className="video-title">{video.favorite && '⭐ '}{video.title}</div>
                               <div className="video-meta-row">
                                 {video.duration && (
                                   <div className="video-duration">⏱️ {video.duration}</div>
@@ -1721,6 +3214,11 @@ export default function App() {
                       )}
                     </div>
                   ))}
+                  {!keywordSearchQuery.trim() && libraryPage && libraryPage.offset + libraryPage.limit < libraryPage.total && (
+                    <button className="load-more-button" onClick={() => loadLibraryPage(libraryPage.offset + libraryPage.limit)}>
+                      더 보기 ({libraryPage.total - libraryPage.videos.length}개 남음)
+                    </button>
+                  )}
                 </div>
               </div>
 
@@ -1740,6 +3238,7 @@ export default function App() {
                         <video
                           key={videoUrl} // URL 변경 시 비디오 엘리먼트 강제 리렌더링
                           src={videoUrl}
+                          crossOrigin="anonymous" // 자막 트랙(vtt)을 같은 비디오 서버에서 받기 위해 필요
                           controls
                           className="video-player"
                           preload="metadata"
@@ -1747,13 +3246,59 @@ export default function App() {
                           onError={handleVideoError}
                           onLoadStart={() => {
                             setVideoError(null); // 로딩 시작 시 에러 초기화
+                            reportedWatchSecondsRef.current = 0;
                           }}
+                          onPause={e => {
+                            reportPlayback(e);
+                            saveWatchPosition(e, true);
+                          }}
+                          onEnded={e => {
+                            reportPlayback(e);
+                            saveWatchPosition(e, true);
+                            advancePlaylist();
+                          }}
+                          onTimeUpdate={e => saveWatchPosition(e)}
                           onCanPlay={() => {
                             setVideoError(null); // 재생 가능 시 에러 초기화
                           }}
+                          onLoadedMetadata={e => {
+                            if (pendingSeekRef.current !== null) {
+                              e.currentTarget.currentTime = pendingSeekRef.current;
+                              pendingSeekRef.current = null;
+                              e.currentTarget.play().catch(() => undefined);
+                            } else {
+                              resumeWatchPosition(e.currentTarget);
+                            }
+                          }}
                         >
+                          {(selectedVideo.caption_languages ?? []).map((lang, index) => (
+                            <track
+                              key={lang}
+                              kind="subtitles"
+                              srcLang={lang}
+                              label={lang}
+                              src={videoUrl.replace(/video\.mp4$/, `captions.${lang}.vtt`)}
+                              default={index === 0}
+                            />
+                          ))}
                           비디오를 로드할 수 없습니다.
                         </video>
+                        <div className="handoff-buttons">
+                          <button
+                            className="detach-player-button"
+                            onClick={() => handoffPlayback(document.querySelector('.video-player'), 'detached')}
+                            disabled={!selectedVideo.video_id}
+                          >
+                            🪟 새 창에서 재생
+                          </button>
+                          <button
+                            className="detach-player-button"
+                            onClick={() => handoffPlayback(document.querySelector('.video-player'), 'system')}
+                            disabled={!selectedVideo.video_id}
+                          >
+                            🎬 시스템 플레이어에서 이어보기
+                          </button>
+                        </div>
                         
                                                  {/* 비디오 에러 표시 및 대체 방안 */}
                          {videoError && (
@@ -1799,11 +3344,41 @@ export default function App() {
                     )}
 
                     <div className="video-info">
-                      <h3 className="video-title-main">{selectedVideo.title}</h3>
+                      <h3 className="video-title-main">
+                        {selectedVideo.video_id && (
+                          <button
+                            className={`favorite-button ${selectedVideo.favorite ? 'active' : ''}`}
+                            onClick={() => toggleFavorite(selectedVideo)}
+                            title={selectedVideo.favorite ? '즐겨찾기 해제' : '즐겨찾기'}
+                          >
+                            {selectedVideo.favorite ? '⭐' : '☆'}
+                          </button>
+                        )}
+                        {selectedVideo.title}
+                      </h3>
                       <p className="video-channel-main">{selectedVideo.channel}</p>
                       {selectedVideo.upload_date && (
                         <p className="video-upload-date">업로드: {selectedVideo.upload_date}</p>
                       )}
+                      {playingPlaylist && playingPlaylist.playlist.videos[playingPlaylist.index]?.video_id === selectedVideo.video_id && (
+                        <p className="video-upload-date">
+                          🎵 {playingPlaylist.playlist.name} ({playingPlaylist.index + 1}/{playingPlaylist.playlist.videos.length})
+                        </p>
+                      )}
+                      {selectedVideo.video_id && playlists.length > 0 && (
+                        <select
+                          className="playlist-add-select"
+                          value=""
+                          onChange={(e) => e.target.value && addToPlaylist(e.target.value, selectedVideo)}
+                        >
+                          <option value="">🎵 재생목록에 추가...</option>
+                          {playlists.map(playlist => (
+                            <option key={playlist.id} value={playlist.id} disabled={playlist.video_ids.includes(selectedVideo.video_id!)}>
+                              {playlist.name}
+                            </option>
+                          ))}
+                        </select>
+                      )}
                     </div>
 
                     <div className="caption-search">
@@ -1923,6 +3498,40 @@ export default function App() {
             <h2 className="tab-title">⚙️ 설정</h2>
             
             <div className="settings-grid">
+              <div className="settings-card">
+                <h3 className="card-title">🩺 보관소 상태</h3>
+                <div className="card-content">
+                  {vaultHealth ? (
+                    <>
+                      <div className={`vault-health-score ${vaultHealth.level}`}>
+                        <strong>{vaultHealth.score}</strong>
+                        <span>{vaultHealth.summary}</span>
+                      </div>
+                      {vaultHealth.checks.map(check => (
+                        <div key={check.key} className="setting-item">
+                          <span>{check.ok ? '✅' : '⚠️'} {check.label}:</span>
+                          <span>{check.detail}</span>
+                        </div>
+                      ))}
+                      {vaultHealth.actions.map((action, index) => (
+                        <div key={`${action.command}-${index}`} className={`vault-health-action ${action.priority}`}>
+                          <div>
+                            <strong>{action.title}</strong>
+                            <p>{action.description}</p>
+                          </div>
+                          <button className="btn-secondary" onClick={() => runHealthAction(action)}>실행</button>
+                        </div>
+                      ))}
+                    </>
+                  ) : (
+                    <p>{vaultHealthLoading ? '상태 확인 중...' : '상태를 불러오지 못했습니다'}</p>
+                  )}
+                  <button className="btn-secondary" onClick={loadVaultHealth} disabled={vaultHealthLoading}>
+                    {vaultHealthLoading ? '확인 중...' : '🔄 다시 확인'}
+                  </button>
+                </div>
+              </div>
+
               <div className="settings-card">
                 <h3 className="card-title">🗂️ 프로젝트 정보</h3>
                 <div className="card-content">
@@ -1940,7 +3549,7 @@ export default function App() {
                   </div>
                   <div className="setting-item">
                     <span>채널 설정:</span>
-                    <span>../channels.txt</span>
+                    <span>../channels.toml</span>
                   </div>
                 </div>
               </div>
@@ -1967,6 +3576,355 @@ export default function App() {
                 </div>
               </div>
 
+              <div className="settings-card">
+                <h3 className="card-title">🔐 YouTube 인증</h3>
+                <div className="card-content">
+                  <div className="setting-item">
+                    <span>cookies.txt:</span>
+                    <input
+                      type="text"
+                      value={authSettings.cookies_file ?? ''}
+                      onChange={(e) => setAuthSettings({ ...authSettings, cookies_file: e.target.value })}
+                      placeholder="/path/to/cookies.txt (우선 사용)"
+                    />
+                  </div>
+                  <div className="setting-item">
+                    <span>브라우저:</span>
+                    <select
+                      value={authSettings.browser ?? ''}
+                      onChange={(e) => setAuthSettings({ ...authSettings, browser: e.target.value || null })}
+                    >
+                      <option value="">사용 안 함</option>
+                      {['chrome', 'firefox', 'safari', 'edge', 'brave', 'chromium', 'opera', 'vivaldi', 'whale'].map(browser => (
+                        <option key={browser} value={browser}>{browser}</option>
+                      ))}
+                    </select>
+                  </div>
+                  <div className="setting-item">
+                    <span>프로필:</span>
+                    <input
+                      type="text"
+                      value={authSettings.browser_profile ?? ''}
+                      onChange={(e) => setAuthSettings({ ...authSettings, browser_profile: e.target.value })}
+                      placeholder="기본 프로필"
+                    />
+                  </div>
+                  <button onClick={saveAndVerifyAuth} disabled={authChecking}>
+                    {authChecking ? '확인 중...' : '💾 저장 후 인증 확인'}
+                  </button>
+                  {authCheck && <div className="usage-step">{authCheck.message}</div>}
+                </div>
+              </div>
+
+              {ingestSettings && (
+                <div className="settings-card">
+                  <h3 className="card-title">📤 녹화 파일 업로드 받기</h3>
+                  <div className="card-content">
+                    <div className="setting-item">
+                      <span>업로드 서버:</span>
+                      <input
+                        type="checkbox"
+                        checked={ingestSettings.enabled}
+                        onChange={(e) => saveIngestSettings({ ...ingestSettings, enabled: e.target.checked })}
+                      />
+                    </div>
+                    <div className="setting-item">
+                      <span>포트:</span>
+                      <input
+                        type="number"
+                        value={ingestSettings.port}
+                        onChange={(e) => setIngestSettings({ ...ingestSettings, port: Number(e.target.value) })}
+                        onBlur={() => saveIngestSettings(ingestSettings)}
+                      />
+                    </div>
+                    <div className="setting-item">
+                      <span>기본 채널:</span>
+                      <input
+                        type="text"
+                        value={ingestSettings.default_channel}
+                        onChange={(e) => setIngestSettings({ ...ingestSettings, default_channel: e.target.value })}
+                        onBlur={() => saveIngestSettings(ingestSettings)}
+                      />
+                    </div>
+                    <div className="setting-item">
+                      <span>Whisper 자막 생성:</span>
+                      <input
+                        type="checkbox"
+                        checked={ingestSettings.transcribe}
+                        onChange={(e) => saveIngestSettings({ ...ingestSettings, transcribe: e.target.checked })}
+                      />
+                    </div>
+                    {ingestSettings.enabled && (
+                      <>
+                        <div className="usage-step">토큰: <code>{ingestSettings.token}</code></div>
+                        {ingestUrl && <div className="usage-step">주소: <code>{ingestUrl}</code></div>}
+                        <button onClick={async () => setIngestSettings(await invoke<IngestSettings>('regenerate_ingest_token'))}>
+                          🔄 토큰 재발급
+                        </button>
+                      </>
+                    )}
+                  </div>
+                </div>
+              )}
+
+              {updateSettings && (
+                <div className="settings-card">
+                  <h3 className="card-title">🔄 앱 업데이트</h3>
+                  <div className="card-content">
+                    <div className="setting-item">
+                      <span>릴리스 채널:</span>
+                      <select
+                        value={updateSettings.channel}
+                        onChange={(e) => saveUpdateSettings({ ...updateSettings, channel: e.target.value as UpdateSettings['channel'] })}
+                      >
+                        <option value="stable">안정 (stable)</option>
+                        <option value="beta">베타 (beta)</option>
+                      </select>
+                    </div>
+                    <div className="setting-item">
+                      <span>자동 확인:</span>
+                      <input
+                        type="checkbox"
+                        checked={updateSettings.auto_check}
+                        onChange={(e) => saveUpdateSettings({ ...updateSettings, auto_check: e.target.checked })}
+                      />
+                    </div>
+                    <div className="setting-item">
+                      <span>작업이 없을 때 자동 설치:</span>
+                      <input
+                        type="checkbox"
+                        checked={updateSettings.auto_install}
+                        onChange={(e) => saveUpdateSettings({ ...updateSettings, auto_install: e.target.checked })}
+                      />
+                    </div>
+                    <button onClick={() => runUpdateCommand('check_for_updates')} disabled={updateChecking}>
+                      {updateChecking ? '확인 중...' : '🔍 업데이트 확인'}
+                    </button>
+                    {updateCheck && (
+                      <>
+                        <div className="usage-step">
+                          현재 {updateCheck.current_version}
+                          {updateCheck.available ? ` → 새 버전 ${updateCheck.version}` : ' (최신 버전)'}
+                          {updateCheck.available && !updateCheck.in_rollout && ` · 단계적 배포 중 (${updateCheck.rollout_percentage}%)`}
+                        </div>
+                        {updateCheck.changelog && <pre className="usage-step">{updateCheck.changelog}</pre>}
+                        {updateCheck.deferred_by.length > 0 && (
+                          <div className="usage-step">⏳ 진행 중인 작업이 끝난 뒤 설치할 수 있습니다: {updateCheck.deferred_by.join(', ')}</div>
+                        )}
+                        {updateCheck.available && updateCheck.signature_verification && updateCheck.deferred_by.length === 0 && (
+                          <button onClick={() => runUpdateCommand('install_update')} disabled={updateChecking}>
+                            ⬇️ 설치 후 재시작
+                          </button>
+                        )}
+                      </>
+                    )}
+                  </div>
+                </div>
+              )}
+
+              {downloaderSettings && (
+                <div className="settings-card">
+                  <h3 className="card-title">⏱️ 무응답 타임아웃</h3>
+                  <div className="card-content">
+                    <p className="usage-step">출력이 없는 시간이 절반을 넘으면 정체 알림을 보내고, 다 지나면 프로세스를 종료합니다 (초, 0은 끄기)</p>
+                    {([
+                      ['download_inactivity_timeout', '채널 다운로드'],
+                      ['full_scan_inactivity_timeout', '전체 무결성 검사'],
+                      ['single_inactivity_timeout', '단일 영상'],
+                    ] as const).map(([key, label]) => (
+                      <div className="setting-item" key={key}>
+                        <span>{label}:</span>
+                        <input
+                          type="number"
+                          min={0}
+                          max={3600}
+                          defaultValue={downloaderSettings[key]}
+                          onBlur={(e) => saveDownloaderSettings({ ...downloaderSettings, [key]: Number(e.target.value) || 0 })}
+                        />
+                      </div>
+                    ))}
+                  </div>
+                </div>
+              )}
+
+              {diskGuardSettings && (
+                <div className="settings-card">
+                  <h3 className="card-title">💾 디스크 여유 공간 보호</h3>
+                  <div className="card-content">
+                    <div className="setting-item">
+                      <span>최소 여유 공간 (GB):</span>
+                      <input
+                        type="number"
+                        min={0}
+                        defaultValue={diskGuardSettings.min_free_gb}
+                        onBlur={(e) => saveDiskGuardSettings({ ...diskGuardSettings, min_free_gb: Number(e.target.value) || 0 })}
+                      />
+                    </div>
+                    <div className="setting-item">
+                      <span>경고 기준 (GB):</span>
+                      <input
+                        type="number"
+                        min={0}
+                        defaultValue={diskGuardSettings.warn_free_gb}
+                        onBlur={(e) => saveDiskGuardSettings({ ...diskGuardSettings, warn_free_gb: Number(e.target.value) || 0 })}
+                      />
+                    </div>
+                    <div className="setting-item">
+                      <span>최소치 아래면 시작/진행 중단:</span>
+                      <input
+                        type="checkbox"
+                        checked={diskGuardSettings.block_below_min}
+                        onChange={(e) => saveDiskGuardSettings({ ...diskGuardSettings, block_below_min: e.target.checked })}
+                      />
+                    </div>
+                  </div>
+                </div>
+              )}
+
+              {notificationSettings && (
+                <div className="settings-card">
+                  <h3 className="card-title">🔔 작업 완료 알림</h3>
+                  <div className="card-content">
+                    {([
+                      ['downloads', '다운로드'],
+                      ['conversions', '영상 변환'],
+                      ['embeddings', '벡터 임베딩'],
+                      ['integrity_checks', '정합성 검사'],
+                    ] as const).map(([key, label]) => (
+                      <div className="setting-item" key={key}>
+                        <span>{label}:</span>
+                        <input
+                          type="checkbox"
+                          checked={notificationSettings[key]}
+                          onChange={(e) => saveNotificationSettings({ ...notificationSettings, [key]: e.target.checked })}
+                        />
+                      </div>
+                    ))}
+                  </div>
+                </div>
+              )}
+
+              {channelWatchSettings && (
+                <div className="settings-card">
+                  <h3 className="card-title">👀 새 업로드 감시 (RSS)</h3>
+                  <div className="card-content">
+                    <div className="setting-item">
+                      <span>감시 사용:</span>
+                      <input
+                        type="checkbox"
+                        checked={channelWatchSettings.enabled}
+                        onChange={(e) => saveChannelWatchSettings({ ...channelWatchSettings, enabled: e.target.checked })}
+                      />
+                    </div>
+                    <div className="setting-item">
+                      <span>확인 주기 (분):</span>
+                      <input
+                        type="number"
+                        min={5}
+                        value={channelWatchSettings.interval_minutes}
+                        onChange={(e) => saveChannelWatchSettings({ ...channelWatchSettings, interval_minutes: Number(e.target.value) })}
+                      />
+                    </div>
+                    <div className="setting-item">
+                      <span>새 영상 자동 다운로드:</span>
+                      <input
+                        type="checkbox"
+                        checked={channelWatchSettings.auto_download}
+                        onChange={(e) => saveChannelWatchSettings({ ...channelWatchSettings, auto_download: e.target.checked })}
+                      />
+                    </div>
+                    <button onClick={checkNewUploads} disabled={checkingUploads}>
+                      {checkingUploads ? '⏳ 확인 중...' : '🔄 지금 확인'}
+                    </button>
+                  </div>
+                </div>
+              )}
+
+              <div className="settings-card">
+                <h3 className="card-title">📦 다른 아카이브에서 가져오기</h3>
+                <div className="card-content">
+                  <div className="setting-item">
+                    <span>도구:</span>
+                    <select
+                      value={externalImport.source}
+                      onChange={(e) => setExternalImport({ ...externalImport, source: e.target.value })}
+                    >
+                      <option value="tube_archivist">Tube Archivist (백업 JSON)</option>
+                      <option value="pinchflat">Pinchflat (pinchflat.db)</option>
+                    </select>
+                  </div>
+                  <div className="setting-item">
+                    <span>내보내기 경로:</span>
+                    <input
+                      type="text"
+                      value={externalImport.exportPath}
+                      onChange={(e) => setExternalImport({ ...externalImport, exportPath: e.target.value })}
+                      placeholder="백업 폴더, JSON 파일 또는 pinchflat.db"
+                    />
+                  </div>
+                  <div className="setting-item">
+                    <span>미디어 폴더:</span>
+                    <input
+                      type="text"
+                      value={externalImport.mediaRoot}
+                      onChange={(e) => setExternalImport({ ...externalImport, mediaRoot: e.target.value })}
+                      placeholder="영상 파일이 있는 폴더 (컨테이너 경로 재매핑)"
+                    />
+                  </div>
+                  <button onClick={() => runExternalImport(true)} disabled={externalImporting || !externalImport.exportPath}>
+                    🔍 미리보기
+                  </button>
+                  <button onClick={() => runExternalImport(false)} disabled={externalImporting || !externalImportReport?.dry_run}>
+                    {externalImporting ? '가져오는 중...' : '📥 가져오기'}
+                  </button>
+                  {externalImportReport && (
+                    <div className="usage-step">
+                      {externalImportReport.dry_run ? '예정' : '완료'}: {externalImportReport.imported.length}/{externalImportReport.found}개
+                      · 이미 있음 {externalImportReport.skipped_existing}개
+                      · 미디어 없음 {externalImportReport.missing_media.length}개
+                      · 시청 상태 {externalImportReport.watch_state_preserved}개
+                      {externalImportReport.failed.length > 0 && ` · 실패 ${externalImportReport.failed.length}개`}
+                    </div>
+                  )}
+                </div>
+              </div>
+
+              <div className="settings-card">
+                <h3 className="card-title">🎞️ 영상 기술 정보</h3>
+                <div className="card-content">
+                  <div className="usage-step">
+                    코덱/해상도/프레임/비트레이트/오디오 채널을 영상마다 한 번 읽어 인덱스에 저장합니다.
+                    다운로드가 끝나면 새 영상만 자동으로 읽습니다.
+                  </div>
+                  <button
+                    onClick={() => invoke<boolean>('start_media_probe').then(started => {
+                      if (!started) alert('이미 수집 중입니다');
+                    })}
+                    disabled={!!mediaProbeProgress && !mediaProbeProgress.done}
+                  >
+                    🔍 기술 정보 수집
+                  </button>
+                  {mediaProbeProgress && (
+                    <div className="usage-step">
+                      {mediaProbeProgress.done ? '완료' : '수집 중'}: {mediaProbeProgress.probed}/{mediaProbeProgress.total}개
+                    </div>
+                  )}
+                </div>
+              </div>
+
+              <div className="settings-card">
+                <h3 className="card-title">🗄️ 영상 인덱스</h3>
+                <div className="card-content">
+                  <div className="usage-step">
+                    비디오 목록은 인덱스(SQLite)에서 바로 읽고, 다운로드/변환/삭제와 앱 시작 때만 vault를 확인합니다.
+                    목록이 실제 폴더와 다르면 인덱스를 다시 만드세요. 다운로드/재생 기록은 유지됩니다.
+                  </div>
+                  <button onClick={rebuildIndex} disabled={rebuildingIndex}>
+                    {rebuildingIndex ? '재구성 중...' : '🔄 인덱스 재구성'}
+                  </button>
+                </div>
+              </div>
+
               <div className="settings-card">
                 <h3 className="card-title">📚 사용법</h3>
                 <div className="card-content">
@@ -9,10 +9,23 @@ interface VideoSource {
   excerpt: string;
 }
 
+interface ConfidenceSignals {
+  level: 'high' | 'medium' | 'low';
+  warnings: string[];
+  top_similarity?: number;
+  mean_similarity?: number;
+  independent_sources: number;
+  independent_channels?: number;
+  newest_source_age_days?: number | null;
+  oldest_source_age_days?: number | null;
+  stale?: boolean;
+}
+
 interface AIResponse {
   answer: string;
   sources?: VideoSource[];
   confidence?: number;
+  confidence_signals?: ConfidenceSignals;
   documents_found?: number;
   processing_time?: number;
   search_quality?: any;
@@ -31,6 +44,8 @@ interface VideoDetails {
   description?: string;
 }
 
+type CitationStyle = 'apa' | 'mla' | 'plain';
+
 interface AIAnswerComponentProps {
   response: AIResponse;
 }
@@ -39,6 +54,7 @@ export const AIAnswerComponent: React.FC<AIAnswerComponentProps> = ({ response }
   const [expandedVideos, setExpandedVideos] = useState<Set<string>>(new Set());
   const [videoDetails, setVideoDetails] = useState<Map<string, VideoDetails>>(new Map());
   const [loadingVideos, setLoadingVideos] = useState<Set<string>>(new Set());
+  const [citationStyle, setCitationStyle] = useState<CitationStyle>('apa');
 
   const openVideoAtTimestamp = (videoId: string, timestamp?: number) => {
     const url = `https://youtube.com/watch?v=${videoId}${timestamp ? `&t=${timestamp}s` : ''}`;
@@ -130,6 +146,40 @@ export const AIAnswerComponent: React.FC<AIAnswerComponentProps> = ({ response }
     }
   };
 
+  const copyCitation = async (source: VideoSource) => {
+    try {
+      const citation = await invoke<string>('format_citation', {
+        videoId: source.video_id,
+        timestamp: source.timestamp ?? null,
+        style: citationStyle,
+      });
+      await navigator.clipboard.writeText(citation);
+    } catch (err) {
+      console.error('인용 복사 실패:', err);
+    }
+  };
+
+  // 출처 전체 인용 (보관되지 않은 영상은 제외)
+  const copyAllCitations = async () => {
+    const citations: string[] = [];
+    for (const source of response.sources || []) {
+      try {
+        citations.push(await invoke<string>('format_citation', {
+          videoId: source.video_id,
+          timestamp: source.timestamp ?? null,
+          style: citationStyle,
+        }));
+      } catch (err) {
+        console.warn('인용 생성 실패:', source.video_id, err);
+      }
+    }
+    try {
+      await navigator.clipboard.writeText([...new Set(citations)].join('\n'));
+    } catch (err) {
+      console.error('인용 복사 실패:', err);
+    }
+  };
+
   const getModelDisplayName = (model: string) => {
     switch (model) {
       case 'deepseek-chat': return '🤖 DeepSeek Chat';
@@ -254,6 +304,14 @@ export const AIAnswerComponent: React.FC<AIAnswerComponentProps> = ({ response }
         </div>
       </div>
       
+      {response.confidence_signals && response.confidence_signals.warnings.length > 0 && (
+        <div className={`confidence-warnings confidence-${response.confidence_signals.level}`}>
+          {response.confidence_signals.warnings.map((warning, i) => (
+            <div key={i}>⚠️ {warning}</div>
+          ))}
+        </div>
+      )}
+      
       <div className="answer-content">
         <div className="answer-text">
           {renderAnswer(response.answer)}
@@ -266,6 +324,20 @@ export const AIAnswerComponent: React.FC<AIAnswerComponentProps> = ({ response }
             🎬 관련 영상 ({response.sources.length}개)
             <span className="sources-subtitle">클릭하여 영상과 자막 확인</span>
           </h4>
+          <div className="citation-actions">
+            <select
+              value={citationStyle}
+              onChange={(e) => setCitationStyle(e.target.value as CitationStyle)}
+              title="인용 형식"
+            >
+              <option value="apa">APA</option>
+              <option value="mla">MLA</option>
+              <option value="plain">일반</option>
+            </select>
+            <button className="copy-button" onClick={copyAllCitations} title="모든 출처를 인용 형식으로 복사">
+              📑 출처 인용 복사
+            </button>
+          </div>
           <div className="sources-list">
             {response.sources.map((source, i) => (
               <div key={i} className="source-item">
@@ -305,6 +377,16 @@ export const AIAnswerComponent: React.FC<AIAnswerComponentProps> = ({ response }
                     >
                       🔗 보기
                     </button>
+                    <button 
+                      className="video-link-button"
+                      onClick={(e) => {
+                        e.stopPropagation();
+                        copyCitation(source);
+                      }}
+                      title="인용 형식으로 복사"
+                    >
+                      📑 인용
+                    </button>
                   </div>
                 </div>
 
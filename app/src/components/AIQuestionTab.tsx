@@ -21,6 +21,7 @@ interface AIResponse {
   documents_found?: number;
   processing_time?: number;
   search_quality?: any;
+  confidence_signals?: any;
   debug_info?: any;
   channel_used: string;
   model_used: string;
@@ -349,6 +350,7 @@ export const AIQuestionTab: React.FC = () => {
           documents_found: parsedResult.documents_found || 0,
           processing_time: parsedResult.processing_time || 0,
           search_quality: parsedResult.search_quality || {},
+          confidence_signals: parsedResult.confidence_signals,
           debug_info: parsedResult.debug_info || {},
           channel_used: selectedChannel,
           model_used: selectedModel,
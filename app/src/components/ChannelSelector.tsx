@@ -27,10 +27,14 @@ export const ChannelSelector: React.FC<ChannelSelectorProps> = ({
     loadChannels();
   }, []);
 
-  const loadChannels = async () => {
+  // refresh면 백엔드 스크립트 캐시를 비우고 다시 조회
+  const loadChannels = async (refresh = false) => {
     try {
       setLoading(true);
       setError(null);
+      if (refresh) {
+        await invoke('invalidate_cache', { kind: 'ai_channels' });
+      }
       const result = await invoke<ChannelInfo[]>('get_available_channels_for_ai');
       setChannels(result);
     } catch (err) {
@@ -55,7 +59,7 @@ export const ChannelSelector: React.FC<ChannelSelectorProps> = ({
       <div className={`channel-error ${className}`}>
         <div className="error-message">
           <span>⚠️ {error}</span>
-          <button onClick={loadChannels} className="retry-button">
+          <button onClick={() => loadChannels(true)} className="retry-button">
             다시 시도
           </button>
         </div>
@@ -107,7 +111,7 @@ export const ChannelSelector: React.FC<ChannelSelectorProps> = ({
       </div>
       
       <div className="channel-actions">
-        <button onClick={loadChannels} className="refresh-button">
+        <button onClick={() => loadChannels(true)} className="refresh-button">
           🔄 새로고침
         </button>
       </div>
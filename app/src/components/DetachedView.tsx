@@ -0,0 +1,220 @@
+import React, { useEffect, useRef, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+
+interface DetachedViewVideo {
+  video_id: string;
+  title: string;
+  channel: string;
+  video_path: string;
+  transcript: string;
+}
+
+interface PlaybackSession {
+  video_id: string;
+  video_path: string;
+  title: string;
+  position_seconds: number;
+  playing: boolean;
+  window_label?: string;
+  updated_at?: string;
+}
+
+interface PlaybackControl {
+  command: 'play' | 'pause' | 'seek';
+  position?: number | null;
+}
+
+interface SegmentAtPosition {
+  index: number;
+  start: number;
+  end: number;
+  text: string;
+  active: boolean;
+}
+
+interface DetachedViewProps {
+  view: 'player' | 'transcript';
+  videoId: string;
+  startAt: number;
+}
+
+const formatTime = (seconds: number) => {
+  const total = Math.floor(seconds);
+  return `${Math.floor(total / 60)}:${String(total % 60).padStart(2, '0')}`;
+};
+
+// 메인 창과 분리된 플레이어/자막 창 (재생 상태는 백엔드를 통해 공유)
+export const DetachedView: React.FC<DetachedViewProps> = ({ view, videoId, startAt }) => {
+  const [video, setVideo] = useState<DetachedViewVideo | null>(null);
+  const [videoUrl, setVideoUrl] = useState<string | null>(null);
+  const [session, setSession] = useState<PlaybackSession | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [currentSegment, setCurrentSegment] = useState<SegmentAtPosition | null>(null);
+  const videoRef = useRef<HTMLVideoElement>(null);
+  const lastReportRef = useRef(0);
+  const reportedWatchSecondsRef = useRef(0); // 시청 기록으로 이미 전송한 재생 시간
+
+  useEffect(() => {
+    const load = async () => {
+      try {
+        const info = await invoke<DetachedViewVideo>('get_detached_view_video', { videoId });
+        setVideo(info);
+        if (view === 'player') {
+          await invoke<number>('start_video_server');
+          setVideoUrl(await invoke<string>('get_video_url', { videoPath: info.video_path }));
+        } else {
+          setSession(await invoke<PlaybackSession | null>('get_playback_state'));
+        }
+      } catch (err) {
+        setError(String(err));
+      }
+    };
+    load();
+  }, [view, videoId]);
+
+  // 자막 창: 플레이어 창의 재생 위치 추적
+  useEffect(() => {
+    if (view !== 'transcript') return;
+    const unlisten = listen<PlaybackSession>('playback-state-changed', event => setSession(event.payload));
+    return () => { unlisten.then(fn => fn()); };
+  }, [view]);
+
+  // 자막 창: 재생 중에는 보고 사이 위치를 추정해 초당 4회 현재 자막 줄 조회
+  useEffect(() => {
+    if (view !== 'transcript' || !session || session.video_id !== videoId) return;
+    const reportedAt = session.updated_at ? Date.parse(session.updated_at) : Date.now();
+    const lookup = () => {
+      const elapsed = session.playing ? Math.max(0, (Date.now() - reportedAt) / 1000) : 0;
+      invoke<SegmentAtPosition | null>('get_segment_at', { videoId, position: session.position_seconds + elapsed })
+        .then(setCurrentSegment)
+        .catch(() => setCurrentSegment(null));
+    };
+    lookup();
+    if (!session.playing) return;
+    const timer = setInterval(lookup, 250);
+    return () => clearInterval(timer);
+  }, [view, videoId, session]);
+
+  // 플레이어 창: 다른 창에서 보낸 재생 제어 명령 처리
+  useEffect(() => {
+    if (view !== 'player') return;
+    const unlisten = listen<PlaybackControl>('playback-control', event => {
+      const player = videoRef.current;
+      if (!player) return;
+      const { command, position } = event.payload;
+      if (typeof position === 'number') player.currentTime = position;
+      if (command === 'play') player.play().catch(() => undefined);
+      if (command === 'pause') player.pause();
+    });
+    return () => { unlisten.then(fn => fn()); };
+  }, [view]);
+
+  const reportState = (force = false) => {
+    const player = videoRef.current;
+    if (!player || !video) return;
+    const now = Date.now();
+    if (!force && now - lastReportRef.current < 1000) return;
+    lastReportRef.current = now;
+    invoke('update_playback_state', {
+      session: {
+        video_id: video.video_id,
+        video_path: video.video_path,
+        title: video.title,
+        position_seconds: player.currentTime,
+        playing: !player.paused,
+      },
+    }).catch(err => console.warn('재생 상태 공유 실패:', err));
+  };
+
+  // 아직 시청 기록으로 보내지 않은 재생 시간
+  const takeUnreportedWatchSeconds = () => {
+    const played = videoRef.current?.played;
+    if (!played) return 0;
+    let total = 0;
+    for (let i = 0; i < played.length; i++) {
+      total += played.end(i) - played.start(i);
+    }
+    const delta = total - reportedWatchSecondsRef.current;
+    reportedWatchSecondsRef.current = total;
+    return delta;
+  };
+
+  const reportPlayback = () => {
+    if (!video) return;
+    const delta = takeUnreportedWatchSeconds();
+    if (delta < 1) return;
+    invoke('record_playback', { videoPath: video.video_path, seconds: delta })
+      .catch(err => console.warn('시청 기록 저장 실패:', err));
+  };
+
+  const handoff = (target: 'embedded' | 'system') => {
+    if (!video) return;
+    const position = videoRef.current?.currentTime ?? 0;
+    const watchedSeconds = takeUnreportedWatchSeconds();
+    videoRef.current?.pause();
+    invoke('handoff_playback', { videoId: video.video_id, position, target, watchedSeconds })
+      .catch(err => setError(String(err)));
+  };
+
+  if (error) {
+    return <div className="detached-view detached-error">⚠️ {error}</div>;
+  }
+  if (!video) {
+    return <div className="detached-view">불러오는 중...</div>;
+  }
+
+  if (view === 'player') {
+    return (
+      <div className="detached-view detached-player">
+        {videoUrl && (
+          <video
+            ref={videoRef}
+            src={videoUrl}
+            controls
+            autoPlay
+            className="video-player"
+            onLoadedMetadata={e => { if (startAt > 0) e.currentTarget.currentTime = startAt; }}
+            onTimeUpdate={() => reportState()}
+            onPlay={() => reportState(true)}
+            onPause={() => { reportState(true); reportPlayback(); }}
+            onEnded={reportPlayback}
+            onSeeked={() => reportState(true)}
+          />
+        )}
+        <div className="detached-header">
+          <button onClick={() => handoff('embedded')}>⬅ 메인 창에서 이어보기</button>
+          <button onClick={() => handoff('system')}>🎬 시스템 플레이어</button>
+        </div>
+      </div>
+    );
+  }
+
+  const following = session && session.video_id === video.video_id;
+  return (
+    <div className="detached-view detached-transcript">
+      <div className="detached-header">
+        <strong>{video.title}</strong>
+        {following && (
+          <span className="detached-position">
+            {session.playing ? '▶' : '⏸'} {formatTime(session.position_seconds)}
+            <button onClick={() => invoke('control_playback', { command: session.playing ? 'pause' : 'play' })}>
+              {session.playing ? '일시정지' : '재생'}
+            </button>
+          </span>
+        )}
+      </div>
+      {following && currentSegment && (
+        <div className={`detached-current-line${currentSegment.active ? '' : ' inactive'}`}>
+          <button onClick={() => invoke('control_playback', { command: 'seek', position: currentSegment.start })}>
+            {formatTime(currentSegment.start)}
+          </button>
+          {currentSegment.text}
+        </div>
+      )}
+      <div className="detached-transcript-body">{video.transcript || '자막이 없습니다.'}</div>
+    </div>
+  );
+};
+
+export default DetachedView;
@@ -1,6 +1,16 @@
 import React from 'react';
 import { createRoot } from 'react-dom/client';
 import App from './App';
+import DetachedView from './components/DetachedView';
+
+// 분리된 창은 ?view=player|transcript&video_id=... 로 열림
+const params = new URLSearchParams(window.location.search);
+const view = params.get('view');
+const videoId = params.get('video_id');
 
 const root = createRoot(document.getElementById('root') as HTMLElement);
-root.render(<App />);
+if ((view === 'player' || view === 'transcript') && videoId) {
+  root.render(<DetachedView view={view} videoId={videoId} startAt={Number(params.get('t')) || 0} />);
+} else {
+  root.render(<App />);
+}
@@ -39,6 +39,7 @@ export interface AnswerConfig {
   enable_adaptive_temperature: boolean; // 기본값: true
   factual_temperature: number;       // 기본값: 0.4
   analytical_temperature: number;    // 기본값: 0.65
+  answer_language?: string | null;   // 기본값: null (채널 감지 언어)
 }
 
 export interface RAGSettings {
@@ -93,7 +94,8 @@ export const DEFAULT_ANSWER_CONFIG: AnswerConfig = {
   temperature: 0.7,
   enable_adaptive_temperature: true,
   factual_temperature: 0.4,
-  analytical_temperature: 0.65
+  analytical_temperature: 0.65,
+  answer_language: null
 };
 
 export const DEFAULT_RAG_SETTINGS: RAGSettings = {
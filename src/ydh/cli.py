@@ -9,6 +9,7 @@ import subprocess
 import json
 import warnings
 import os
+from datetime import datetime
 from pathlib import Path
 from typing import Optional, List
 
@@ -19,6 +20,7 @@ from .downloader import VideoDownloader
 from .transcript import TranscriptExtractor
 from .converter import CaptionConverter
 from .vault_writer import VaultWriter
+from .vault_lock import VaultLock, VaultLockedError
 
 # multiprocessing 경고 억제
 warnings.filterwarnings("ignore", category=UserWarning, module="multiprocessing.resource_tracker")
@@ -36,15 +38,34 @@ logging.basicConfig(
 
 logger = logging.getLogger(__name__)
 
+# Vault에 쓰는 명령 (다른 인스턴스가 잠금을 가진 경우 거부)
+VAULT_WRITE_COMMANDS = {
+    "batch", "single", "ingest", "convert", "maintenance", "vault",
+    "cleanup-txt", "fix-video-ids", "convert-single", "cleanup-backups",
+}
+
+
+DEFAULT_CHANNELS_FILES = ("channels.toml", "channels.txt")
+# channels.toml language 값별 자막 언어 후보 (데스크톱 앱과 같은 yt-dlp 언어 코드)
+SUBTITLE_LANGUAGE_VARIANTS = {
+    "ko": ["ko", "ko-KR", "ko_KR"],
+    "ja": ["ja", "ja-JP"],
+    "en": ["en", "en-US", "en-GB"],
+    "zh": ["zh", "zh-Hans", "zh-Hant", "zh-CN", "zh-TW"],
+}
+
 
 def load_channel_list(channels_file: Path) -> List[str]:
-    """채널 목록 파일을 로드합니다."""
+    """채널 목록 파일을 로드합니다. (channels.toml 또는 한 줄에 URL 하나인 텍스트 파일)"""
     channels = []
     
     if not channels_file.exists():
         logger.error(f"채널 목록 파일을 찾을 수 없습니다: {channels_file}")
         return channels
     
+    if channels_file.suffix == ".toml":
+        return load_channel_config(channels_file)
+    
     try:
         with open(channels_file, 'r', encoding='utf-8') as f:
             for line_num, line in enumerate(f, 1):
@@ -67,6 +88,39 @@ def load_channel_list(channels_file: Path) -> List[str]:
         return []
 
 
+def load_channel_config(channels_file: Path) -> List[str]:
+    """channels.toml의 활성 채널 URL ([[channels]] 항목, enabled = false는 제외)
+    
+    채널별 quality/language도 설정에 반영합니다. (데스크톱 앱이 환경 변수로 전달한 값이 우선)
+    """
+    try:
+        import tomllib
+    except ImportError:  # Python 3.10 이하
+        import tomli as tomllib
+    
+    try:
+        with open(channels_file, 'rb') as f:
+            entries = tomllib.load(f).get("channels", [])
+    except Exception as e:
+        logger.error(f"채널 목록 파일 읽기 실패: {e}")
+        return []
+    
+    channels = []
+    for entry in entries:
+        url = entry.get("url")
+        if not url or not entry.get("enabled", True):
+            continue
+        channels.append(url)
+        if entry.get("quality"):
+            settings.channel_qualities.setdefault(url, entry["quality"])
+        language = entry.get("language")
+        if language:
+            languages = SUBTITLE_LANGUAGE_VARIANTS.get(language, [language])
+            settings.channel_subtitles.setdefault(url, {"languages": languages})
+    logger.info(f"채널 목록 로드 완료: {len(channels)}개 채널")
+    return channels
+
+
 @click.group(invoke_without_command=True)
 @click.option('--debug', is_flag=True, help='디버그 모드 활성화')
 @click.option('--config', type=click.Path(exists=True), help='설정 파일 경로')
@@ -86,12 +140,22 @@ def main(ctx: click.Context, debug: bool, config: Optional[str]) -> None:
     settings.ensure_vault_structure()
     logger.info(f"Vault 경로: {settings.vault_root}")
     
+    # 쓰기 명령은 Vault 잠금 획득 (읽기 전용 명령은 잠금 없이 실행)
+    if ctx.invoked_subcommand in VAULT_WRITE_COMMANDS:
+        vault_lock = VaultLock(settings.vault_root)
+        try:
+            vault_lock.acquire()
+        except VaultLockedError as e:
+            logger.error(f"🔒 {e}. 읽기 전용 명령만 사용할 수 있습니다.")
+            sys.exit(1)
+        ctx.call_on_close(vault_lock.release)
+    
     # 명령어가 없으면 도움말 표시
     if ctx.invoked_subcommand is None:
         click.echo("Y-Data-House - YouTube 영상 다운로드 및 Vault 생성 도구")
         click.echo("")
         click.echo("주요 명령어:")
-        click.echo("  batch           - channels.txt의 모든 채널 처리")
+        click.echo("  batch           - channels.toml의 모든 채널 처리")
         click.echo("  ingest <URL>    - 개별 채널 처리")
         click.echo("  stats           - 다운로드 통계")
         click.echo("  config-show     - 설정 확인")
@@ -100,16 +164,16 @@ def main(ctx: click.Context, debug: bool, config: Optional[str]) -> None:
 
 
 @main.command()
-@click.option('--channels-file', type=click.Path(exists=True), default='channels.txt',
-              help='채널 목록 파일 경로 (기본: channels.txt)')
+@click.option('--channels-file', type=click.Path(exists=True), default=None,
+              help='채널 목록 파일 경로 (기본: channels.toml, 없으면 channels.txt)')
 @click.option('--vault-only', is_flag=True, help='다운로드 없이 Vault 생성만')
 @click.option('--no-vault', is_flag=True, help='다운로드만 하고 Vault 생성 안함')
 @click.option('--parallel', is_flag=True, help='🚀 병렬 처리로 성능 향상 (실험적)')
 @click.option('--max-workers', type=int, default=3, help='병렬 처리 최대 워커 수 (기본: 3)')
 @click.option('--full-scan', is_flag=True, help='🔍 전체 무결성 검사 모드 (모든 영상 확인)')
-def batch(channels_file: str, vault_only: bool, no_vault: bool, parallel: bool, max_workers: int, full_scan: bool) -> None:
+def batch(channels_file: Optional[str], vault_only: bool, no_vault: bool, parallel: bool, max_workers: int, full_scan: bool) -> None:
     """
-    channels.txt 파일의 모든 채널을 처리합니다.
+    channels.toml(또는 channels.txt) 파일의 모든 채널을 처리합니다.
     
     두 가지 모드:
     - 기본 모드: 빠른 확인으로 최신 영상만 체크 (수초 완료)
@@ -131,13 +195,21 @@ def batch(channels_file: str, vault_only: bool, no_vault: bool, parallel: bool,
     logger.info(f"PYTHONUNBUFFERED: {os.getenv('PYTHONUNBUFFERED', 'NOT SET')}")
     logger.info("==================")
     
+    if channels_file is None:
+        channels_file = next((name for name in DEFAULT_CHANNELS_FILES if Path(name).exists()), DEFAULT_CHANNELS_FILES[0])
     channels_path = Path(channels_file)
     
     # 채널 목록 로드
     channels = load_channel_list(channels_path)
     
+    # 추모 처리된 채널과 일시정지/보관 채널 제외 (데스크톱 앱에서 전달)
+    excluded_channels = set(json.loads(os.getenv('YDH_EXCLUDED_CHANNELS', '[]') or '[]'))
+    if excluded_channels:
+        channels = [c for c in channels if c not in excluded_channels]
+        logger.info(f"🕯️ 추모/일시정지/보관 채널 {len(excluded_channels)}개 제외")
+    
     if not channels:
-        logger.error("처리할 채널이 없습니다. channels.txt 파일을 확인하세요.")
+        logger.error(f"처리할 채널이 없습니다. {channels_path} 파일을 확인하세요.")
         sys.exit(1)
     
     # 모드별 로깅
@@ -151,7 +223,8 @@ def batch(channels_file: str, vault_only: bool, no_vault: bool, parallel: bool,
         "processed_channels": 0,
         "total_downloaded": 0,
         "total_failed": 0,
-        "total_vault_processed": 0
+        "total_vault_processed": 0,
+        "channel_results": [],
     }
     
     start_time = time.time()
@@ -186,6 +259,32 @@ def batch(channels_file: str, vault_only: bool, no_vault: bool, parallel: bool,
     # 🚀 성능 개선 효과 표시
     if parallel:
         logger.info(f"🚀 병렬 처리 사용: {max_workers}개 워커로 성능 향상")
+    
+    _write_batch_stats(total_stats, full_scan, duration)
+
+def _write_batch_stats(total_stats: dict, full_scan: bool, duration: float) -> None:
+    """채널별 결과를 90_indices/last_batch_stats.json에 기록 (데스크톱 앱 실행 보고서용)"""
+    # 데스크톱 앱이 채널별 프로세스를 병렬 실행할 때는 프로세스마다 별도 경로 지정
+    stats_path = Path(os.getenv("YDH_BATCH_STATS_PATH") or settings.vault_root / "90_indices" / "last_batch_stats.json")
+    try:
+        stats_path.parent.mkdir(parents=True, exist_ok=True)
+        stats_path.write_text(json.dumps({
+            "finished_at": datetime.now().astimezone().isoformat(),
+            "full_scan": full_scan,
+            "duration_seconds": round(duration, 1),
+            **total_stats,
+        }, ensure_ascii=False, indent=2), encoding='utf-8')
+    except Exception as e:
+        logger.warning(f"배치 결과 기록 실패: {e}")
+
+def _channel_result(channel_name: str, stats: dict, error: Optional[str] = None) -> dict:
+    return {
+        "channel": channel_name,
+        "downloaded": stats.get("downloaded", 0),
+        "failed": stats.get("failed", 0),
+        "failed_items": stats.get("failed_items", []),
+        "error": error,
+    }
 
 def _process_channels_sequential(channels: List[str], vault_only: bool, no_vault: bool, full_scan: bool, total_stats: dict) -> dict:
     """순차적으로 채널들을 처리합니다."""
@@ -215,6 +314,7 @@ def _process_channels_sequential(channels: List[str], vault_only: bool, no_vault
             total_stats["processed_channels"] += 1
             total_stats["total_downloaded"] += stats.get("downloaded", 0)
             total_stats["total_failed"] += stats.get("failed", 0)
+            total_stats["channel_results"].append(_channel_result(channel_name, stats))
             
             # Vault 생성
             if not no_vault and stats.get("downloaded", 0) > 0:
@@ -226,6 +326,7 @@ def _process_channels_sequential(channels: List[str], vault_only: bool, no_vault
             
         except Exception as e:
             logger.error(f"채널 처리 중 오류 발생: {channel_url} - {e}")
+            total_stats["channel_results"].append(_channel_result(channel_url, {}, str(e)))
             continue
     
     return total_stats
@@ -265,6 +366,7 @@ def _process_channels_parallel(channels: List[str], vault_only: bool, no_vault:
             local_stats["processed"] = 1
             local_stats["downloaded"] = stats.get("downloaded", 0)
             local_stats["failed"] = stats.get("failed", 0)
+            local_stats["channel_result"] = _channel_result(channel_name, stats)
             
             # Vault 생성
             if not no_vault and stats.get("downloaded", 0) > 0:
@@ -276,6 +378,7 @@ def _process_channels_parallel(channels: List[str], vault_only: bool, no_vault:
             
         except Exception as e:
             logger.error(f"❌ 병렬 처리 실패: {channel_url} - {e}")
+            local_stats["channel_result"] = _channel_result(channel_url, {}, str(e))
             return local_stats
     
     # ThreadPoolExecutor로 병렬 처리
@@ -296,6 +399,8 @@ def _process_channels_parallel(channels: List[str], vault_only: bool, no_vault:
                     total_stats["total_downloaded"] += local_stats["downloaded"]
                     total_stats["total_failed"] += local_stats["failed"]
                     total_stats["total_vault_processed"] += local_stats["vault_processed"]
+                    if "channel_result" in local_stats:
+                        total_stats["channel_results"].append(local_stats["channel_result"])
                     
             except Exception as e:
                 logger.error(f"병렬 처리 결과 수집 실패: {channel_url} - {e}")
@@ -369,6 +474,41 @@ def _process_vault_for_channel(channel_name: str) -> int:
     return vault_processed
 
 
+@main.command()
+@click.argument('video_url')
+@click.option('--channel-name', help='채널 이름 (지정하지 않으면 업로더 이름 사용)')
+@click.option('--format', 'format_selector', help='yt-dlp 포맷 선택자 (예: 137+140, bestvideo[vcodec^=avc1]+bestaudio)')
+@click.option('--no-vault', is_flag=True, help='다운로드만 하고 Vault 생성 안함')
+def single(video_url: str, channel_name: Optional[str], format_selector: Optional[str], no_vault: bool) -> None:
+    """
+    단일 영상을 다운로드하고 Vault에 저장합니다.
+    
+    기본 품질 선택 대신 --format으로 특정 포맷/코덱을 지정할 수 있습니다.
+    """
+    logger.info(f"🎬 단일 영상 다운로드 시작: {video_url}")
+    if format_selector:
+        logger.info(f"🎛️ 수동 포맷 선택: {format_selector}")
+    
+    downloader = VideoDownloader()
+    video_info = downloader.get_video_info(video_url)
+    if not video_info:
+        logger.error("비디오 정보를 가져올 수 없습니다.")
+        sys.exit(1)
+    
+    final_channel_name = channel_name or video_info.get('uploader', '') or video_info.get('channel', '')
+    folder_path = downloader.create_video_folder(video_info)
+    
+    if not downloader.download_video(video_info, folder_path, final_channel_name, format_selector):
+        logger.error(f"❌ 다운로드 실패: {video_info.get('title', video_url)}")
+        sys.exit(1)
+    
+    logger.info(f"✅ 다운로드 완료: {video_info.get('title', video_url)}")
+    
+    if not no_vault:
+        processed = _process_vault_for_channel(final_channel_name)
+        logger.info(f"Vault 처리 완료: {processed}개 영상")
+
+
 @main.command()
 @click.argument('channel_url')
 @click.option('--channel-name', help='채널 이름 (자동 감지되지 않는 경우)')
@@ -1014,6 +1154,11 @@ def _convert_video_file(video_file: Path, quality: str, codec: str, backup: bool
         # 호환성을 위한 픽셀 포맷
         cmd.extend(['-pix_fmt', 'yuv420p'])
         
+        # 데스크톱 앱의 자원 제한 설정 (CPU 코어 수 제한)
+        ffmpeg_threads = os.getenv('YDH_FFMPEG_THREADS')
+        if ffmpeg_threads:
+            cmd.extend(['-threads', ffmpeg_threads])
+        
         cmd.append(str(output_file))
         
         logger.info(f"🔧 실행 명령어: {' '.join(cmd)}")
@@ -16,6 +16,8 @@ class Settings(BaseSettings):
     # 기본 경로 설정
     vault_root: Path = Field(default_factory=lambda: Path("./vault"))
     download_path: Path = Field(default_factory=lambda: Path("./vault/downloads"))
+    # 채널 아카이브/백필 체크포인트 위치 (미지정 시 download_path, 병렬 다운로드 시 공유 폴더 지정)
+    download_state_path: Optional[Path] = None
     
     # 언어 설정
     language: str = "ko"
@@ -24,24 +26,59 @@ class Settings(BaseSettings):
     max_quality: str = Field(default="720p", env="YDH_VIDEO_QUALITY")
     max_downloads_per_run: int = 0  # 무제한으로 변경
     delete_vtt_after_conversion: bool = True
+    # 다운로드 속도 제한 (bytes/s, None이면 무제한) / 영상당 동시 조각 다운로드 수
+    rate_limit: Optional[int] = None
+    concurrent_fragments: int = 1
     detailed_debug: bool = False
     
     # 브라우저 쿠키 설정 (봇 감지 회피)
     browser: str = "chrome"
     use_browser_cookies: bool = True
+    browser_profile: Optional[str] = None  # 브라우저 프로필 이름/경로 (없으면 기본 프로필)
+    # cookies.txt (Netscape 형식, 멤버십/연령 제한 영상용) - 지정하면 브라우저 쿠키보다 우선
+    cookies_file: Optional[Path] = None
     
     # User-Agent 설정 (실제 브라우저로 위장)
     user_agent: str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/122.0.0.0 Safari/537.36"
     
     # 프록시 설정
+    # yt-dlp 프록시 (http/https/socks4/socks5 URL, 데스크톱 앱에서 전달)
+    proxy_url: Optional[str] = None
+    # 채널 URL별 프록시 (빈 문자열이면 프록시 없이 직접 연결)
+    channel_proxies: Dict[str, str] = Field(default_factory=dict)
+    # youtube-transcript-api용 프록시
     use_proxy: bool = False
     proxies: Dict[str, str] = Field(default_factory=lambda: {
         'http': 'socks5://127.0.0.1:9050',
         'https': 'socks5://127.0.0.1:9050'
     })
     
+    # YouTube 차단 시 메타데이터/자막만 Invidious/Piped 미러에서 가져오기 (영상은 항상 yt-dlp)
+    mirror_fallback_enabled: bool = False
+    mirror_instance: Optional[str] = None  # 예: https://yewtu.be, https://pipedapi.kavin.rocks
+    mirror_type: str = "invidious"         # invidious | piped
+    mirror_failure_threshold: int = 3      # 연속 추출 실패 횟수 (차단 메시지는 즉시 전환)
+    
+    # 채널 URL별 화질 (channels.toml의 quality, 전역 max_quality보다 우선)
+    channel_qualities: Dict[str, str] = Field(default_factory=dict)
+    
+    # 업로드 날짜 범위 (YYYYMMDD, 범위 밖 영상은 다운로드하지 않음)
+    download_since: Optional[str] = None
+    download_until: Optional[str] = None
+    # 채널 URL별 날짜 범위 {"since": ..., "until": ...} (전역 설정보다 우선)
+    channel_date_ranges: Dict[str, Dict[str, str]] = Field(default_factory=dict)
+    # 채널 URL별 다운로드 정책 {"max_videos": int, "max_archive_mb": int, "archive_mb": float}
+    # (archive_mb: 실행 시점의 채널 폴더 크기, 데스크톱 앱에서 전달)
+    channel_download_policies: Dict[str, Dict[str, Any]] = Field(default_factory=dict)
+    
     # 자막 언어 우선순위
     subtitle_languages: List[str] = Field(default_factory=lambda: ['ko', 'ko-KR', 'ko_KR'])
+    # 채널 URL별 자막 설정 {"languages": [...], "auto_generated": bool} (데스크톱 앱에서 전달)
+    channel_subtitles: Dict[str, Dict[str, Any]] = Field(default_factory=dict)
+    # 채널 URL별 콘텐츠 필터 {"skip_shorts": bool, "skip_live": bool, "min_duration_seconds": int}
+    channel_content_filters: Dict[str, Dict[str, Any]] = Field(default_factory=dict)
+    # 다운로드한 자막 트랙(VTT)을 Vault 영상 폴더에 captions.<언어>.vtt로 보관 (플레이어 자막 전환용)
+    keep_subtitle_tracks: bool = True
     
     # Obsidian Vault 설정
     vault_videos_folder: str = "10_videos"
@@ -76,6 +113,14 @@ class Settings(BaseSettings):
         """Vault 디렉토리 구조를 생성합니다."""
         self.get_vault_videos_path().mkdir(parents=True, exist_ok=True)
     
+    def cookie_options(self) -> Dict[str, Any]:
+        """yt-dlp 쿠키 옵션을 반환합니다. (cookies.txt 우선, 없으면 브라우저 쿠키)"""
+        if self.cookies_file:
+            return {'cookiefile': str(self.cookies_file)}
+        if self.use_browser_cookies:
+            return {'cookiesfrombrowser': (self.browser, self.browser_profile, None, None)}
+        return {}
+    
     def get_channel_tags(self, channel_name: str) -> List[str]:
         """채널에 해당하는 기본 태그를 반환합니다."""
         return self.channel_tags.get(channel_name, [])
@@ -8,7 +8,7 @@ import time
 import os
 import warnings
 from pathlib import Path
-from typing import Dict, List, Optional, Any, Set
+from typing import Dict, List, Optional, Any, Set, Tuple
 import re
 import signal
 import json
@@ -19,12 +19,23 @@ from tqdm import tqdm
 
 from .config import settings
 from .converter import CaptionConverter
+from .mirror import get_mirror_client, is_block_error
+from .progress import emit_progress
+from .vault_writer import playlist_id_from_url
 
 # multiprocessing 경고 억제
 warnings.filterwarnings("ignore", category=UserWarning, module="multiprocessing.resource_tracker")
 
 logger = logging.getLogger(__name__)
 
+# Shorts로 판단하는 최대 길이 (세로 영상 기준, 초)
+SHORTS_MAX_SECONDS = 180
+# 라이브/프리미어로 판단하는 yt-dlp live_status 값
+LIVE_STATUSES = ("is_live", "was_live", "post_live", "is_upcoming")
+
+# 영상 폴더의 이어받기 정보 (데스크톱 앱의 resume_incomplete_downloads가 읽음)
+RESUME_MARKER_FILE = "resume.json"
+
 
 class WarningCapturer:
     """yt-dlp 경고 메시지를 필터링하는 클래스."""
@@ -55,6 +66,29 @@ class VideoDownloader:
     def __init__(self):
         """VideoDownloader 초기화."""
         self._setup_logger()
+        # 현재 채널의 업로드 날짜 범위 (YYYYMMDD)
+        self.date_range: Tuple[Optional[str], Optional[str]] = (None, None)
+        # 현재 소스가 재생목록인지 (재생목록은 최신순이 아니므로 전체 목록을 확인)
+        self.is_playlist = False
+        # 현재 채널의 콘텐츠 필터 (Shorts/라이브/최소 길이)
+        self.content_filters: Dict[str, Any] = {}
+        # 현재 채널의 다운로드 정책: 최신 N개 업로드만, 채널 폴더 남은 용량 (None이면 제한 없음)
+        self.max_videos: Optional[int] = None
+        self.archive_budget_bytes: Optional[int] = None
+        # 범위 시작일보다 오래된 영상에 도달함 (목록은 최신순이므로 이후 영상도 모두 범위 밖)
+        self.reached_before_since = False
+        # YouTube 차단 시 메타데이터/자막 대체 경로 (설정된 경우에만)
+        self.mirror = get_mirror_client()
+        # 현재 채널의 yt-dlp 프록시 (None이면 직접 연결)
+        self.proxy: Optional[str] = settings.proxy_url
+        self.consecutive_extraction_failures = 0
+        # 진행률 줄 출력 간격 제한용
+        self._last_progress_print = 0.0
+        # 현재 채널의 자막 언어 / 자동 생성 자막 사용 여부
+        self.subtitle_languages: List[str] = settings.subtitle_languages
+        self.auto_generated_subtitles = True
+        # 현재 채널의 화질 (channels.toml에서 지정하지 않으면 전역 설정)
+        self.max_quality: str = settings.max_quality
     
     def _setup_logger(self) -> None:
         """yt-dlp 로거 설정."""
@@ -110,8 +144,9 @@ class VideoDownloader:
                 'http_headers': {
                     'User-Agent': settings.user_agent,
                 },
-                # 🛡️ 봇 감지 회피: 브라우저 쿠키 사용
-                'cookiesfrombrowser': (settings.browser, None, None, None) if settings.use_browser_cookies else None,
+                # 🛡️ 봇 감지 회피 + 멤버십 영상: 쿠키 사용 (cookies.txt 또는 브라우저)
+                **settings.cookie_options(),
+                **self._proxy_options(),
                 # 🔥 환경변수에서 rate limiting 설정 (더 짧은 타임아웃)
                 'socket_timeout': int(os.getenv('YDH_YTDLP_SOCKET_TIMEOUT', '8')),  # 8초로 단축
                 'retries': int(os.getenv('YDH_YTDLP_RETRIES', '1')),  # 1회로 단축
@@ -224,7 +259,8 @@ class VideoDownloader:
                 'User-Agent': settings.user_agent,
             },
             # 🛡️ 봇 감지 회피
-            'cookiesfrombrowser': (settings.browser, None, None, None) if settings.use_browser_cookies else None,
+            **settings.cookie_options(),
+            **self._proxy_options(),
             # 🔥 타임아웃 60초로 증가
             'socket_timeout': 60,  # 60초로 증가
             'retries': int(os.getenv('YDH_YTDLP_RETRIES', '1')),  # 1회만
@@ -442,15 +478,20 @@ class VideoDownloader:
         Uploads 재생목록 방식만 사용합니다.
         """
         try:
-            # 채널 ID 추출
-            channel_id = self._extract_channel_id(channel_url)
-            
-            if not channel_id:
-                logger.warning(f"채널 ID 추출 실패: {channel_url}")
-                return []
-            
-            # Uploads 재생목록 URL 생성
-            uploads_url = self._convert_to_uploads_playlist(channel_id)
+            playlist_id = playlist_id_from_url(channel_url)
+            if playlist_id:
+                # 재생목록 소스는 재생목록을 그대로 청크 단위로 수집
+                uploads_url = f"https://www.youtube.com/playlist?list={playlist_id}"
+            else:
+                # 채널 ID 추출
+                channel_id = self._extract_channel_id(channel_url)
+                
+                if not channel_id:
+                    logger.warning(f"채널 ID 추출 실패: {channel_url}")
+                    return []
+                
+                # Uploads 재생목록 URL 생성
+                uploads_url = self._convert_to_uploads_playlist(channel_id)
             logger.info(f"🌐 청크 {chunk_num} 수집 중... Uploads URL: {uploads_url}")
             
             # 옵션 설정 (타임아웃 제거)
@@ -542,8 +583,9 @@ class VideoDownloader:
             'http_headers': {
                 'User-Agent': settings.user_agent,
             },
-            # 🛡️ 봇 감지 회피: 브라우저 쿠키 사용
-            'cookiesfrombrowser': (settings.browser, None, None, None) if settings.use_browser_cookies else None,
+            # 🛡️ 봇 감지 회피 + 멤버십 영상: 쿠키 사용 (cookies.txt 또는 브라우저)
+            **settings.cookie_options(),
+            **self._proxy_options(),
             # 🔥 환경변수에서 rate limiting 및 타임아웃 설정 읽기
             'socket_timeout': int(os.getenv('YDH_YTDLP_SOCKET_TIMEOUT', '30')),
             'retries': int(os.getenv('YDH_YTDLP_RETRIES', '2')),
@@ -554,12 +596,48 @@ class VideoDownloader:
         
         try:
             with yt_dlp.YoutubeDL(ydl_opts) as ydl:
-                return ydl.extract_info(video_url, download=False)
+                info = ydl.extract_info(video_url, download=False)
+            self.consecutive_extraction_failures = 0
+            return info
         except Exception as e:
             logger.error(f"비디오 정보 추출 실패: {e}")
+            self.consecutive_extraction_failures += 1
+            if self.mirror and (is_block_error(str(e))
+                                or self.consecutive_extraction_failures >= settings.mirror_failure_threshold):
+                video_id = video_url.split("v=")[-1].split("&")[0]
+                info = self.mirror.fetch_video_info(video_id)
+                if info:
+                    logger.info(f"🪞 미러에서 메타데이터 조회: {info.get('title')} ({self.mirror.source})")
+                return info
             return None
     
     # 나머지 메서드들은 기존과 동일하게 유지
+    def _progress_hook(self, status: Dict[str, Any]) -> None:
+        """quiet 모드에서도 데스크톱 앱이 읽을 수 있도록 전송 진행 이벤트 출력 (1초 간격)"""
+        if status.get('status') != 'downloading':
+            return
+        now = time.time()
+        if now - self._last_progress_print < 1.0:
+            return
+        self._last_progress_print = now
+        
+        downloaded = status.get('downloaded_bytes') or 0
+        total = status.get('total_bytes') or status.get('total_bytes_estimate') or 0
+        if not total:
+            return
+        emit_progress(
+            "transfer",
+            percent=round(downloaded / total * 100, 1),
+            downloaded_bytes=downloaded,
+            total_bytes=total,
+            speed_bps=status.get('speed'),
+            eta_seconds=status.get('eta'),
+        )
+    
+    def _proxy_options(self) -> Dict[str, Any]:
+        """현재 채널의 yt-dlp 프록시 옵션"""
+        return {'proxy': self.proxy} if self.proxy else {}
+    
     def sanitize_filename(self, name: str) -> str:
         """파일/폴더 이름에 사용할 수 없는 문자를 '_'로 대체합니다."""
         return re.sub(r'[\\/*?:"<>|]', "_", name)
@@ -585,8 +663,9 @@ class VideoDownloader:
         
         return folder_path
     
-    def download_video(self, video_info: Dict[str, Any], output_folder: Path, channel_name: str = "") -> bool:
-        """개별 비디오를 다운로드합니다."""
+    def download_video(self, video_info: Dict[str, Any], output_folder: Path, channel_name: str = "",
+                       format_selector: Optional[str] = None) -> bool:
+        """개별 비디오를 다운로드합니다. format_selector를 지정하면 품질 설정 대신 사용합니다."""
         video_id = video_info.get('id', '')
         title = video_info.get('title', '제목 없음')
         
@@ -594,17 +673,20 @@ class VideoDownloader:
             logger.error("비디오 ID가 없습니다.")
             return False
         
-        # 품질 선택
-        format_selector = 'bestvideo[ext=mp4][height<=1080]+bestaudio[ext=m4a]/best[height<=1080]/best'
-        if settings.max_quality:
-            if settings.max_quality == "480p" or settings.max_quality == "low":
-                format_selector = 'bestvideo[ext=mp4][height<=480]+bestaudio[ext=m4a]/best[height<=480]/best'
+        manual_format = format_selector
+        # 품질 선택 (수동 포맷 지정이 우선)
+        if not format_selector:
+            format_selector = 'bestvideo[ext=mp4][height<=1080]+bestaudio[ext=m4a]/best[height<=1080]/best'
+            if self.max_quality:
+                if self.max_quality == "480p" or self.max_quality == "low":
+                    format_selector = 'bestvideo[ext=mp4][height<=480]+bestaudio[ext=m4a]/best[height<=480]/best'
         
         # yt-dlp 옵션 설정
         ydl_opts = {
             'outtmpl': str(output_folder / f'{title}.%(ext)s'),
             'format': format_selector,
             'merge_output_format': 'mp4',
+            'continuedl': True,  # 남아 있는 .part 파일에서 이어받기
             'logger': self.yt_dlp_logger,
             'ignoreerrors': True,
             'quiet': True,
@@ -618,23 +700,36 @@ class VideoDownloader:
             'sleep_interval': int(os.getenv('YDH_YTDLP_SLEEP_INTERVAL', '1')),
             'max_sleep_interval': int(os.getenv('YDH_YTDLP_MAX_SLEEP_INTERVAL', '3')),
             'sleep_interval_requests': int(os.getenv('YDH_YTDLP_SLEEP_REQUESTS', '10')),
-            # 🛡️ 봇 감지 회피: 브라우저 쿠키 사용
-            'cookiesfrombrowser': (settings.browser, None, None, None) if settings.use_browser_cookies else None,
+            # 속도/동시성 제한 (데스크톱 앱 설정)
+            'ratelimit': settings.rate_limit,
+            'concurrent_fragment_downloads': settings.concurrent_fragments,
+            'progress_hooks': [self._progress_hook],
+            # 🛡️ 봇 감지 회피 + 멤버십 영상: 쿠키 사용 (cookies.txt 또는 브라우저)
+            **settings.cookie_options(),
+            **self._proxy_options(),
             # 자막 다운로드 옵션
             'writesubtitles': True,
-            'writeautomaticsub': True,
-            'subtitleslangs': settings.subtitle_languages,
+            'writeautomaticsub': self.auto_generated_subtitles,
+            'subtitleslangs': self.subtitle_languages,
             'subtitlesformat': 'vtt',
         }
         
         try:
             video_url = f"https://www.youtube.com/watch?v={video_id}"
+            # 중단되면 데스크톱 앱이 남은 .part 파일을 같은 경로로 이어받을 수 있도록 기록
+            self._write_resume_marker(output_folder, video_url, title, channel_name, manual_format)
             
             with yt_dlp.YoutubeDL(ydl_opts) as ydl:
                 error_code = ydl.download([video_url])
                 
                 if error_code == 0:
                     logger.info(f"다운로드 완료: {title}")
+                    (output_folder / RESUME_MARKER_FILE).unlink(missing_ok=True)
+                    # 자막이 차단된 경우 미러에서 자막만 가져옴
+                    if self.mirror and not any(output_folder.glob("*.vtt")):
+                        if self.mirror.fetch_captions(video_id, self.subtitle_languages, output_folder, title):
+                            video_info['captions_source'] = self.mirror.source
+                            logger.info(f"🪞 미러에서 자막 조회: {title} ({self.mirror.source})")
                     self._save_video_metadata(video_info, output_folder)
                     self._add_to_archive(video_id, channel_name)
                     return True
@@ -646,6 +741,23 @@ class VideoDownloader:
             logger.error(f"다운로드 중 오류 발생: {e}")
             return False
     
+    def _write_resume_marker(self, output_folder: Path, video_url: str, title: str,
+                             channel_name: str, format_selector: Optional[str]) -> None:
+        """이어받기 정보 저장 (다운로드가 끝나면 삭제됨)"""
+        try:
+            import json
+            
+            marker = {
+                'url': video_url,
+                'title': title,
+                'channel_name': channel_name or None,
+                'format': format_selector,
+            }
+            with open(output_folder / RESUME_MARKER_FILE, 'w', encoding='utf-8') as f:
+                json.dump(marker, f, ensure_ascii=False, indent=2)
+        except Exception as e:
+            logger.warning(f"이어받기 정보 저장 실패: {e}")
+    
     def _save_video_metadata(self, video_info: Dict[str, Any], output_folder: Path) -> None:
         """비디오 메타데이터를 JSON 파일로 저장합니다."""
         try:
@@ -662,6 +774,10 @@ class VideoDownloader:
                 'description': video_info.get('description', ''),
                 'webpage_url': video_info.get('webpage_url', ''),
             }
+            # 미러에서 가져온 항목의 출처 (YouTube에서 직접 받았으면 생략)
+            for key in ('metadata_source', 'captions_source'):
+                if video_info.get(key):
+                    metadata[key] = video_info[key]
             
             # 메타데이터 파일 저장
             metadata_file = output_folder / 'metadata.json'
@@ -674,7 +790,7 @@ class VideoDownloader:
     def get_downloaded_archive_path(self, channel_name: str) -> Path:
         """채널별 downloaded 아카이브 파일 경로를 반환합니다."""
         safe_channel_name = re.sub(r'[\\/*?:"<>|]', "_", channel_name)
-        return settings.download_path / f"{safe_channel_name}_downloaded.txt"
+        return (settings.download_state_path or settings.download_path) / f"{safe_channel_name}_downloaded.txt"
     
     def _load_downloaded_archive(self, channel_name: str) -> Set[str]:
         """다운로드 아카이브 파일에서 이미 다운로드된 영상 ID 목록을 로드합니다."""
@@ -788,6 +904,23 @@ class VideoDownloader:
         # 모드별 로깅
         mode_text = "전체 무결성 검사" if full_scan else "빠른 확인"
         logger.info(f"🚀 {mode_text} 모드 시작: {channel_url}")
+        
+        self.date_range = self._date_range_for(channel_url)
+        self.reached_before_since = False
+        self.is_playlist = playlist_id_from_url(channel_url) is not None
+        self.content_filters = settings.channel_content_filters.get(channel_url, {})
+        channel_subtitles = settings.channel_subtitles.get(channel_url, {})
+        self.subtitle_languages = channel_subtitles.get("languages") or settings.subtitle_languages
+        self.auto_generated_subtitles = channel_subtitles.get("auto_generated", True)
+        self.proxy = settings.channel_proxies.get(channel_url, settings.proxy_url) or None
+        self.max_quality = settings.channel_qualities.get(channel_url, settings.max_quality)
+        self._apply_download_policy(channel_url)
+        if self.proxy:
+            logger.info(f"🌐 프록시 사용: {self.proxy}")
+        if any(self.date_range):
+            logger.info(f"📅 업로드 날짜 범위: {self.date_range[0] or '처음'} ~ {self.date_range[1] or '현재'}")
+        if any(self.content_filters.values()):
+            logger.info(f"🚫 콘텐츠 필터: {self.content_filters}")
         total_start_time = time.time()
         
         if full_scan:
@@ -797,12 +930,70 @@ class VideoDownloader:
             # 빠른 확인 모드 (기본)
             return self._fast_check_and_download(channel_url, channel_name, total_start_time)
     
+    def _apply_download_policy(self, channel_url: str) -> None:
+        """채널 다운로드 정책 적용 (최신 N개는 최신순 목록인 채널에만 적용)"""
+        policy = settings.channel_download_policies.get(channel_url, {})
+        self.max_videos = None if self.is_playlist else policy.get("max_videos")
+        max_archive_mb = policy.get("max_archive_mb")
+        self.archive_budget_bytes = None
+        if max_archive_mb:
+            self.archive_budget_bytes = int((max_archive_mb - (policy.get("archive_mb") or 0)) * 1024 * 1024)
+        if self.max_videos:
+            logger.info(f"🔢 최신 {self.max_videos}개 업로드만 다운로드")
+        if self.archive_budget_bytes is not None:
+            remaining_mb = max(self.archive_budget_bytes, 0) / (1024 * 1024)
+            logger.info(f"💾 채널 최대 용량: {max_archive_mb}MB (남은 용량 {remaining_mb:.0f}MB)")
+    
+    def _date_range_for(self, channel_url: str) -> Tuple[Optional[str], Optional[str]]:
+        """채널의 업로드 날짜 범위 (채널별 설정이 전역 설정보다 우선)"""
+        channel_range = settings.channel_date_ranges.get(channel_url, {})
+        since = channel_range.get("since") or settings.download_since
+        until = channel_range.get("until") or settings.download_until
+        normalize = lambda value: value.replace("-", "") if value else None
+        return normalize(since), normalize(until)
+    
+    def _date_filter_reason(self, upload_date: Optional[str]) -> Optional[str]:
+        """업로드 날짜가 범위 밖이면 사유 반환 (날짜를 모르면 None)"""
+        since, until = self.date_range
+        if not upload_date:
+            return None
+        if since and upload_date < since:
+            return f"{since} 이전 업로드"
+        if until and upload_date > until:
+            return f"{until} 이후 업로드"
+        return None
+    
+    def _content_filter_reason(self, info: Dict[str, Any]) -> Optional[str]:
+        """채널 콘텐츠 필터에 걸리면 사유 반환 (목록 항목/상세 정보 모두 사용, 모르는 값은 통과)"""
+        duration = info.get('duration')
+        if self.content_filters.get("skip_shorts"):
+            url = info.get('webpage_url') or info.get('url') or ''
+            width, height = info.get('width'), info.get('height')
+            vertical = bool(width and height and height > width)
+            if '/shorts/' in url or (duration and duration <= SHORTS_MAX_SECONDS and vertical):
+                return "Shorts"
+        if self.content_filters.get("skip_live"):
+            if info.get('live_status') in LIVE_STATUSES or info.get('was_live') or info.get('is_live'):
+                return "라이브/프리미어"
+        min_duration = self.content_filters.get("min_duration_seconds")
+        if min_duration and duration and duration < min_duration:
+            return f"{int(duration)}초 < {min_duration}초"
+        return None
+    
     def _fast_check_and_download(self, channel_url: str, channel_name: str, start_time: float) -> Dict[str, int]:
         """빠른 확인 모드: 최신 영상만 확인하여 신규 영상 다운로드"""
         logger.info("⚡ 1단계: 빠른 신규 영상 확인")
         
-        # 빠른 신규 영상 확인
-        fast_check = self.check_for_new_videos_fast(channel_url, channel_name)
+        # 재생목록은 추가 순서가 업로드 순서와 달라 최신 일부만 봐서는 새 항목을 놓칠 수 있음
+        if self.is_playlist:
+            logger.info("📃 재생목록: 전체 항목을 아카이브와 비교합니다...")
+            videos = self.get_channel_videos(channel_url)
+            all_excluded_ids = self._load_downloaded_archive(channel_name) | self._check_downloads_folder(channel_name)
+            new_videos = [v for v in videos if v.get('id') not in all_excluded_ids]
+            return self._execute_download(new_videos, len(videos) - len(new_videos), start_time, "빠른 확인", channel_name)
+        
+        # 빠른 신규 영상 확인 (최신 N개 정책이 있으면 그 범위만 확인)
+        fast_check = self.check_for_new_videos_fast(channel_url, channel_name, min(20, self.max_videos or 20))
         
         if not fast_check['has_new_videos']:
             logger.info("✅ 신규 영상이 없습니다. 다운로드를 건너뜁니다.")
@@ -821,6 +1012,8 @@ class VideoDownloader:
         if fast_check['new_video_count'] >= 15:  # 최신 20개 중 15개 이상이 신규면 더 수집
             logger.info("📚 신규 영상이 많아 전체 스캔을 수행합니다...")
             videos = self.get_channel_videos(channel_url)
+            if self.max_videos:
+                videos = videos[:self.max_videos]
             
             # 기존 다운로드 아카이브와 비교
             downloaded_ids = self._load_downloaded_archive(channel_name)
@@ -841,55 +1034,146 @@ class VideoDownloader:
         return self._execute_download(new_videos, skipped_count, start_time, "빠른 확인", channel_name)
     
     def _full_integrity_scan_and_download(self, channel_url: str, channel_name: str, start_time: float) -> Dict[str, int]:
-        """전체 무결성 검사 모드: 모든 영상과 아카이브를 비교하여 누락 영상 복구"""
+        """전체 무결성 검사 모드: 청크 단위로 아카이브와 비교하여 누락 영상 복구 (체크포인트로 이어받기 지원)"""
         logger.info("🔍 전체 무결성 검사 모드")
         logger.warning("⏰ 이 작업은 몇 분이 소요될 수 있습니다...")
         
-        # 1단계: 전체 영상 목록 수집
-        logger.info("📚 1단계: 채널의 전체 영상 목록 수집 중...")
-        all_videos = self.get_channel_videos(channel_url)
-        
-        if not all_videos:
-            logger.warning("채널에서 영상을 찾을 수 없습니다.")
-            elapsed = time.time() - start_time
-            logger.info(f"⚡ 총 소요시간: {elapsed:.1f}초")
-            return {"total": 0, "downloaded": 0, "skipped": 0, "failed": 0}
+        chunk_size = 100
+        max_chunks = 10  # 최대 10청크 (1000개 영상)
         
-        logger.info(f"📊 채널 전체 영상: {len(all_videos)}개")
+        # 이전에 중단된 백필이 있으면 마지막 완료 청크부터 재개
+        # (그 사이 올라온 신규 영상으로 순번이 밀릴 수 있어 완료 청크를 한 번 더 확인)
+        checkpoint = self._load_backfill_checkpoint(channel_name)
+        start_chunk = checkpoint.get("completed_chunk", 0) if checkpoint else 0
+        start_chunk = max(1, start_chunk)
+        if checkpoint:
+            logger.info(
+                f"♻️ 백필 체크포인트 발견: 청크 {checkpoint.get('completed_chunk')}까지 완료 "
+                f"(마지막 업로드 {checkpoint.get('last_upload_date') or '알 수 없음'}) → 청크 {start_chunk}부터 재개"
+            )
         
-        # 2단계: 로컬 아카이브와 비교
-        logger.info("📋 2단계: 로컬 아카이브와 비교 중...")
+        # 로컬 아카이브 + downloads 폴더의 진행중 영상은 제외
         downloaded_ids = self._load_downloaded_archive(channel_name)
-        logger.info(f"📥 이미 다운로드된 영상: {len(downloaded_ids)}개")
-        
-        # 3단계: downloads 폴더의 진행중인 영상 확인
-        logger.info("📁 3단계: downloads 폴더의 진행중 영상 확인 중...")
         downloading_ids = self._check_downloads_folder(channel_name)
-        
-        # 4단계: 누락된 영상 식별 (아카이브 + 진행중 영상 모두 제외)
         all_excluded_ids = downloaded_ids | downloading_ids
-        missing_videos = [v for v in all_videos if v.get('id') not in all_excluded_ids]
-        skipped_count = len(all_videos) - len(missing_videos)
-        
+        logger.info(f"📥 이미 다운로드된 영상: {len(downloaded_ids)}개")
         if downloading_ids:
             logger.info(f"🔄 downloads 폴더의 {len(downloading_ids)}개 진행중 영상 건너뜀")
         
-        if not missing_videos:
-            logger.info("✅ 누락된 영상이 없습니다. 모든 영상이 완전히 다운로드되어 있습니다.")
-            elapsed = time.time() - start_time
-            logger.info(f"⚡ 총 소요시간: {elapsed:.1f}초")
-            return {
-                "total": len(all_videos), 
-                "downloaded": 0, 
-                "skipped": skipped_count, 
-                "failed": 0
+        total_stats = {"total": 0, "downloaded": 0, "skipped": 0, "failed": 0}
+        failed_items = []
+        scanned_count = 0
+        reached_end = False
+        chunk_num = start_chunk
+        
+        while chunk_num <= max_chunks:
+            chunk_opts = {
+                'quiet': True,
+                'verbose': False,
+                'extract_flat': True,
+                'ignoreerrors': True,
+                'no_warnings': True,
+                'skip_download': True,
+                'logger': self.yt_dlp_logger,
+                'http_headers': {
+                    'User-Agent': settings.user_agent,
+                },
+                **settings.cookie_options(),
+                **self._proxy_options(),
             }
+            chunk_videos = self._get_chunk_videos(channel_url, chunk_opts, chunk_num)
+            
+            if not chunk_videos:
+                # 수집 오류와 구분할 수 없으므로 체크포인트는 유지
+                logger.info(f"📦 청크 {chunk_num}: 영상이 없습니다. 수집 종료")
+                break
+            
+            # 최신 N개 정책: 목록 순번이 N을 넘는 영상은 확인하지 않음
+            chunk_start = (chunk_num - 1) * chunk_size
+            reached_max_videos = bool(self.max_videos) and chunk_start + len(chunk_videos) >= self.max_videos
+            if reached_max_videos:
+                chunk_videos = chunk_videos[:max(self.max_videos - chunk_start, 0)]
+            
+            scanned_count += len(chunk_videos)
+            missing_videos = [v for v in chunk_videos if v.get('id') not in all_excluded_ids]
+            skipped_count = len(chunk_videos) - len(missing_videos)
+            logger.info(f"📦 청크 {chunk_num}: {len(chunk_videos)}개 중 누락 {len(missing_videos)}개")
+            
+            if missing_videos:
+                chunk_stats = self._execute_download(missing_videos, skipped_count, start_time, "무결성 검사", channel_name)
+            else:
+                chunk_stats = {"total": 0, "downloaded": 0, "skipped": skipped_count, "failed": 0}
+            for key in total_stats:
+                total_stats[key] += chunk_stats.get(key, 0)
+            failed_items.extend(chunk_stats.get("failed_items", []))
+            
+            # 청크 처리가 끝날 때마다 체크포인트 저장
+            last_upload_date = next(
+                (v.get('upload_date') for v in reversed(chunk_videos) if v.get('upload_date')),
+                None,
+            )
+            self._save_backfill_checkpoint(channel_name, chunk_num, last_upload_date)
+            
+            # 마지막 청크가 꽉 차지 않거나 날짜 범위 시작일 이전 영상 또는 최신 N개 경계에 도달하면 끝
+            if len(chunk_videos) < chunk_size or chunk_num == max_chunks or self.reached_before_since or reached_max_videos:
+                reached_end = True
+                break
+            
+            chunk_num += 1
+            time.sleep(2)
         
-        logger.info(f"🔍 누락된 영상 발견: {len(missing_videos)}개")
-        logger.info("📥 누락된 영상들을 다운로드합니다...")
+        # 채널 끝까지 처리했으면 체크포인트 삭제
+        if reached_end:
+            self._clear_backfill_checkpoint(channel_name)
         
-        # 4단계: 누락된 영상들 다운로드
-        return self._execute_download(missing_videos, skipped_count, start_time, "무결성 검사", channel_name)
+        logger.info(f"📊 확인한 영상: {scanned_count}개")
+        if total_stats["total"] == 0:
+            logger.info("✅ 누락된 영상이 없습니다. 모든 영상이 완전히 다운로드되어 있습니다.")
+        
+        elapsed = time.time() - start_time
+        logger.info(f"⚡ 총 소요시간: {elapsed:.1f}초")
+        total_stats["failed_items"] = failed_items
+        return total_stats
+    
+    def get_backfill_checkpoint_path(self, channel_name: str) -> Path:
+        """채널별 백필 체크포인트 파일 경로를 반환합니다."""
+        safe_channel_name = re.sub(r'[\\/*?:"<>|]', "_", channel_name)
+        return (settings.download_state_path or settings.download_path) / f"{safe_channel_name}_backfill.json"
+    
+    def _load_backfill_checkpoint(self, channel_name: str) -> Optional[Dict[str, Any]]:
+        """저장된 백필 체크포인트를 로드합니다."""
+        checkpoint_path = self.get_backfill_checkpoint_path(channel_name)
+        if not checkpoint_path.exists():
+            return None
+        
+        try:
+            with open(checkpoint_path, 'r', encoding='utf-8') as f:
+                return json.load(f)
+        except Exception as e:
+            logger.warning(f"백필 체크포인트 읽기 실패: {e}")
+            return None
+    
+    def _save_backfill_checkpoint(self, channel_name: str, completed_chunk: int, last_upload_date: Optional[str]) -> None:
+        """마지막으로 완전히 처리한 청크를 기록합니다."""
+        checkpoint_path = self.get_backfill_checkpoint_path(channel_name)
+        checkpoint = {
+            "channel": channel_name,
+            "completed_chunk": completed_chunk,
+            "last_upload_date": last_upload_date,
+            "updated_at": time.strftime("%Y-%m-%dT%H:%M:%S"),
+        }
+        try:
+            with open(checkpoint_path, 'w', encoding='utf-8') as f:
+                json.dump(checkpoint, f, ensure_ascii=False, indent=2)
+        except Exception as e:
+            logger.warning(f"백필 체크포인트 저장 실패: {e}")
+    
+    def _clear_backfill_checkpoint(self, channel_name: str) -> None:
+        """백필 완료 시 체크포인트를 삭제합니다."""
+        checkpoint_path = self.get_backfill_checkpoint_path(channel_name)
+        if checkpoint_path.exists():
+            checkpoint_path.unlink()
+            logger.debug(f"백필 체크포인트 삭제: {checkpoint_path.name}")
     
     def _execute_download(self, videos_to_download: List[Dict[str, Any]], skipped_count: int, start_time: float, mode_name: str, channel_name: str = "") -> Dict[str, int]:
         """실제 비디오 다운로드 실행"""
@@ -899,6 +1183,26 @@ class VideoDownloader:
             logger.info(f"⚡ 총 소요시간: {elapsed:.1f}초")
             return {"total": 0, "downloaded": 0, "skipped": skipped_count, "failed": 0}
         
+        # 목록 단계에서 날짜를 아는 영상은 미리 제외 (나머지는 상세 정보 조회 후 확인)
+        if any(self.date_range):
+            in_range = [v for v in videos_to_download if not self._date_filter_reason(v.get('upload_date'))]
+            skipped_count += len(videos_to_download) - len(in_range)
+            if not self.is_playlist and any(self.date_range[0] and v.get('upload_date') and v['upload_date'] < self.date_range[0] for v in videos_to_download):
+                self.reached_before_since = True
+            videos_to_download = in_range
+            if not videos_to_download:
+                logger.info("📅 날짜 범위 안의 영상이 없습니다.")
+                return {"total": 0, "downloaded": 0, "skipped": skipped_count, "failed": 0}
+        
+        # 목록 단계에서 알 수 있는 콘텐츠 필터 대상도 미리 제외
+        if any(self.content_filters.values()):
+            allowed = [v for v in videos_to_download if not self._content_filter_reason(v)]
+            skipped_count += len(videos_to_download) - len(allowed)
+            videos_to_download = allowed
+            if not videos_to_download:
+                logger.info("🚫 콘텐츠 필터를 통과한 영상이 없습니다.")
+                return {"total": 0, "downloaded": 0, "skipped": skipped_count, "failed": 0}
+        
         logger.info(f"📥 다운로드 대상: {len(videos_to_download)}개 영상")
         
         # 다운로드 수 제한 적용 (빠른 확인 모드에서만)
@@ -910,10 +1214,13 @@ class VideoDownloader:
         
         # 다운로드 통계 초기화
         stats = {"total": len(videos_to_download), "downloaded": 0, "skipped": skipped_count, "failed": 0}
+        # 실패 항목 (배치 실행 보고서용)
+        failed_items = []
         
         # 간단한 진행률 표시 - multiprocessing 이슈 방지
         total_videos = len(videos_to_download)
         logger.info(f"📥 {mode_name} 다운로드 시작: {total_videos}개 영상")
+        emit_progress("videos_found", channel=channel_name, total=total_videos)
         
         try:
             with WarningCapturer():
@@ -925,6 +1232,7 @@ class VideoDownloader:
                     if not video_id:
                         logger.warning(f"[{current_progress}/{total_videos}] ({progress_percent:.1f}%) 비디오 ID 없음")
                         stats["failed"] += 1
+                        failed_items.append({"video_id": None, "title": video.get('title'), "reason": "비디오 ID 없음"})
                         continue
                     
                     try:
@@ -935,10 +1243,40 @@ class VideoDownloader:
                         if not video_info:
                             logger.warning(f"[{current_progress}/{total_videos}] ({progress_percent:.1f}%) 비디오 정보 없음: {video_id}")
                             stats["failed"] += 1
+                            failed_items.append({"video_id": video_id, "title": video.get('title'), "reason": "비디오 정보 없음"})
                             continue
                         
                         video_title = video_info.get('title', '제목 없음')
+                        
+                        # 업로드 날짜 범위 밖이면 건너뜀 (아카이브에 기록하지 않아 범위를 바꾸면 다시 대상이 됨)
+                        date_reason = self._date_filter_reason(video_info.get('upload_date'))
+                        if date_reason:
+                            stats["skipped"] += 1
+                            logger.info(f"⏭️ [{current_progress}/{total_videos}] 날짜 범위 밖 ({date_reason}): {video_title}")
+                            if not self.is_playlist and self.date_range[0] and video_info.get('upload_date', '') < self.date_range[0]:
+                                # 목록은 최신순 → 남은 영상도 모두 범위 밖
+                                self.reached_before_since = True
+                                stats["skipped"] += total_videos - current_progress
+                                break
+                            continue
+                        
+                        # 콘텐츠 필터 대상도 아카이브에 기록하지 않음 (필터를 끄면 다시 대상이 됨)
+                        filter_reason = self._content_filter_reason(video_info)
+                        if filter_reason:
+                            stats["skipped"] += 1
+                            logger.info(f"⏭️ [{current_progress}/{total_videos}] 콘텐츠 필터 ({filter_reason}): {video_title}")
+                            continue
+                        
+                        # 채널 최대 용량에 도달하면 나머지는 다음 실행으로 미룸 (아카이브에 기록하지 않음)
+                        if self.archive_budget_bytes is not None and self.archive_budget_bytes <= 0:
+                            remaining = total_videos - current_progress + 1
+                            stats["skipped"] += remaining
+                            logger.info(f"💾 채널 최대 용량에 도달해 남은 {remaining}개 영상은 건너뜁니다")
+                            break
+                        
                         logger.info(f"[{current_progress}/{total_videos}] ({progress_percent:.1f}%) 다운로드 중: {video_title}")
+                        emit_progress("video_started", index=current_progress, total=total_videos,
+                                      video_id=video_id, title=video_title)
                         
                         # 영상별 폴더 생성
                         folder_path = self.create_video_folder(video_info)
@@ -947,10 +1285,17 @@ class VideoDownloader:
                         final_channel_name = channel_name or video_info.get('uploader', '') or video_info.get('channel', '')
                         if self.download_video(video_info, folder_path, final_channel_name):
                             stats["downloaded"] += 1
+                            video_status = "downloaded"
+                            if self.archive_budget_bytes is not None:
+                                self.archive_budget_bytes -= sum(f.stat().st_size for f in folder_path.rglob('*') if f.is_file())
                             logger.info(f"✅ [{current_progress}/{total_videos}] 다운로드 완료: {video_title}")
                         else:
                             stats["failed"] += 1
+                            video_status = "failed"
+                            failed_items.append({"video_id": video_id, "title": video_title, "reason": "다운로드 실패"})
                             logger.error(f"❌ [{current_progress}/{total_videos}] 다운로드 실패: {video_title}")
+                        emit_progress("video_finished", index=current_progress, total=total_videos,
+                                      video_id=video_id, title=video_title, status=video_status)
                         
                         # 서버 부하 방지를 위한 지연
                         time.sleep(0.5)
@@ -958,6 +1303,7 @@ class VideoDownloader:
                     except Exception as e:
                         logger.error(f"❌ [{current_progress}/{total_videos}] 영상 처리 중 예외 발생: {e}")
                         stats["failed"] += 1
+                        failed_items.append({"video_id": video_id, "title": video.get('title'), "reason": str(e)})
             
             # 결과 요약
             elapsed = time.time() - start_time
@@ -969,6 +1315,7 @@ class VideoDownloader:
             logger.info(f"⚡ 총 소요시간: {elapsed:.1f}초")
             logger.info(f"📂 다운로드 위치: {settings.download_path.absolute()}")
             
+            stats["failed_items"] = failed_items
             return stats
             
         except Exception as e:
@@ -980,6 +1327,8 @@ class VideoDownloader:
         finally:
             # 진행률 표시 완료
             logger.info(f"📊 {mode_name} 모드 진행률 표시 완료")
+            emit_progress("channel_finished", channel=channel_name, total=total_videos,
+                          downloaded=stats["downloaded"], skipped=stats["skipped"], failed=stats["failed"])
     
     def _add_to_archive(self, video_id: str, channel_name: str) -> None:
         """다운로드된 영상을 아카이브에 추가합니다."""
@@ -0,0 +1,142 @@
+"""
+Invidious/Piped mirror fallback.
+
+YouTube가 추출을 막을 때(봇 확인, 429 등) 설정된 미러 인스턴스에서
+메타데이터와 자막만 가져옵니다. 영상 파일은 항상 yt-dlp로 받습니다.
+미러에서 가져온 정보에는 metadata_source/captions_source 출처가 기록됩니다.
+"""
+
+import json
+import logging
+import urllib.parse
+import urllib.request
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any, Dict, List, Optional
+
+from .config import settings
+
+logger = logging.getLogger(__name__)
+
+# YouTube 차단으로 판단하는 yt-dlp 오류 메시지
+BLOCK_ERROR_MARKERS = (
+    "sign in to confirm",
+    "confirm you're not a bot",
+    "http error 429",
+    "too many requests",
+    "unable to extract",
+    "this content isn't available",
+    "ip address is blocked",
+)
+
+REQUEST_TIMEOUT_SECONDS = 20
+
+
+def is_block_error(message: str) -> bool:
+    """yt-dlp 오류가 IP 차단/봇 감지에 의한 것인지 확인"""
+    lowered = message.lower()
+    return any(marker in lowered for marker in BLOCK_ERROR_MARKERS)
+
+
+class MirrorClient:
+    """Invidious 또는 Piped API 클라이언트 (메타데이터/자막 전용)"""
+
+    def __init__(self, instance: str, mirror_type: str = "invidious"):
+        self.instance = instance.rstrip("/")
+        self.mirror_type = mirror_type
+
+    @property
+    def source(self) -> str:
+        """출처 표기 (예: invidious:https://yewtu.be)"""
+        return f"{self.mirror_type}:{self.instance}"
+
+    def _get(self, path: str) -> bytes:
+        request = urllib.request.Request(
+            urllib.parse.urljoin(self.instance + "/", path.lstrip("/")),
+            headers={"User-Agent": settings.user_agent},
+        )
+        with urllib.request.urlopen(request, timeout=REQUEST_TIMEOUT_SECONDS) as response:
+            return response.read()
+
+    def fetch_video_info(self, video_id: str) -> Optional[Dict[str, Any]]:
+        """yt-dlp extract_info와 같은 키로 변환한 영상 정보"""
+        try:
+            if self.mirror_type == "piped":
+                data = json.loads(self._get(f"/streams/{video_id}"))
+                upload_date = (data.get("uploadDate") or "")[:10].replace("-", "")
+                info = {
+                    "title": data.get("title"),
+                    "upload_date": upload_date,
+                    "uploader": data.get("uploader"),
+                    "duration": data.get("duration") or 0,
+                    "view_count": data.get("views") or 0,
+                    "description": data.get("description") or "",
+                }
+            else:
+                data = json.loads(self._get(f"/api/v1/videos/{video_id}"))
+                published = data.get("published")
+                info = {
+                    "title": data.get("title"),
+                    "upload_date": datetime.fromtimestamp(published, tz=timezone.utc).strftime("%Y%m%d") if published else "",
+                    "timestamp": published,
+                    "uploader": data.get("author"),
+                    "duration": data.get("lengthSeconds") or 0,
+                    "view_count": data.get("viewCount") or 0,
+                    "description": data.get("description") or "",
+                }
+        except Exception as e:
+            logger.warning(f"미러 메타데이터 조회 실패 ({self.source}): {video_id} - {e}")
+            return None
+
+        if not info.get("title"):
+            return None
+        info.update({
+            "id": video_id,
+            "channel": info.get("uploader"),
+            "webpage_url": f"https://www.youtube.com/watch?v={video_id}",
+            "metadata_source": self.source,
+        })
+        return info
+
+    def _caption_tracks(self, video_id: str) -> List[Dict[str, str]]:
+        """(언어 코드, VTT URL) 목록"""
+        if self.mirror_type == "piped":
+            data = json.loads(self._get(f"/streams/{video_id}"))
+            return [
+                {"language": s.get("code", ""), "url": s.get("url", "")}
+                for s in data.get("subtitles", [])
+                if s.get("mimeType", "text/vtt") == "text/vtt"
+            ]
+        data = json.loads(self._get(f"/api/v1/captions/{video_id}"))
+        return [
+            {"language": c.get("language_code") or c.get("languageCode", ""), "url": c.get("url", "")}
+            for c in data.get("captions", [])
+        ]
+
+    def fetch_captions(self, video_id: str, languages: List[str], output_folder: Path, title: str) -> List[Path]:
+        """우선순위 언어의 자막을 yt-dlp와 같은 이름(제목.언어.vtt)으로 저장"""
+        try:
+            tracks = self._caption_tracks(video_id)
+        except Exception as e:
+            logger.warning(f"미러 자막 목록 조회 실패 ({self.source}): {video_id} - {e}")
+            return []
+
+        saved = []
+        for language in languages:
+            track = next((t for t in tracks if t["language"] == language and t["url"]), None)
+            if not track:
+                continue
+            try:
+                target = output_folder / f"{title}.{language}.vtt"
+                target.write_bytes(self._get(track["url"]))
+                saved.append(target)
+            except Exception as e:
+                logger.warning(f"미러 자막 다운로드 실패 ({self.source}): {video_id} {language} - {e}")
+        return saved
+
+
+def get_mirror_client() -> Optional[MirrorClient]:
+    """미러 대체가 켜져 있고 인스턴스가 설정된 경우에만 클라이언트 반환"""
+    if not settings.mirror_fallback_enabled or not settings.mirror_instance:
+        return None
+    return MirrorClient(settings.mirror_instance, settings.mirror_type)
@@ -0,0 +1,23 @@
+"""
+Desktop app progress protocol.
+
+데스크톱 앱은 로그 문구 대신 `PROGRESS:{...}` JSON 한 줄(rag.py와 같은 형식)을
+타입 있는 진행 이벤트로 읽습니다. 로그 문구를 바꿔도 진행률 표시가 깨지지 않도록
+진행 정보는 반드시 이 모듈로 출력합니다.
+
+이벤트 (event 필드):
+- videos_found: channel, total
+- video_started: index, total, video_id, title
+- video_finished: index, total, video_id, title, status (downloaded/failed/skipped)
+- transfer: percent, downloaded_bytes, total_bytes, speed_bps, eta_seconds
+- channel_finished: channel, total, downloaded, skipped, failed
+"""
+
+import json
+
+PROGRESS_PREFIX = "PROGRESS:"
+
+
+def emit_progress(event: str, **fields) -> None:
+    """진행 이벤트를 stdout에 JSON 한 줄로 출력"""
+    print(f"{PROGRESS_PREFIX}{json.dumps({'event': event, **fields}, ensure_ascii=False)}", flush=True)
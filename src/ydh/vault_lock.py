@@ -0,0 +1,164 @@
+"""
+Vault lock file management.
+
+데스크톱 앱과 CLI가 같은 Vault에 동시에 쓰지 않도록 vault/.ydh.lock에
+PID와 하트비트를 기록합니다. 다른 인스턴스가 잠금을 가지고 있으면
+쓰기 명령은 거부되고 읽기 전용 명령만 실행됩니다.
+"""
+
+import json
+import logging
+import os
+import threading
+from datetime import datetime, timedelta
+from pathlib import Path
+from typing import Optional
+
+logger = logging.getLogger(__name__)
+
+LOCK_FILE_NAME = ".ydh.lock"
+HEARTBEAT_INTERVAL_SECONDS = 30
+STALE_SECONDS = 90
+
+
+class VaultLockedError(Exception):
+    """다른 인스턴스가 Vault 잠금을 가지고 있음"""
+
+    def __init__(self, holder: dict):
+        self.holder = holder
+        super().__init__(
+            f"다른 인스턴스({holder.get('owner', '?')} PID {holder.get('pid', '?')})가 Vault를 사용 중입니다"
+        )
+
+
+def read_lock(vault_root: Path) -> Optional[dict]:
+    try:
+        return json.loads((vault_root / LOCK_FILE_NAME).read_text(encoding='utf-8'))
+    except Exception:
+        return None
+
+
+def is_lock_alive(info: dict) -> bool:
+    """하트비트가 갱신되지 않은 잠금은 비정상 종료된 인스턴스의 것으로 간주"""
+    try:
+        heartbeat = datetime.fromisoformat(info["heartbeat_at"])
+    except Exception:
+        return False
+    now = datetime.now(heartbeat.tzinfo) if heartbeat.tzinfo else datetime.now()
+    return now - heartbeat < timedelta(seconds=STALE_SECONDS)
+
+
+class VaultLock:
+    """CLI용 Vault 잠금 (하트비트 스레드 포함)"""
+
+    def __init__(self, vault_root: Path, owner: str = "cli"):
+        self.vault_root = Path(vault_root)
+        self.owner = owner
+        self.started_at = datetime.now().astimezone().isoformat()
+        self._stop = threading.Event()
+        self._thread: Optional[threading.Thread] = None
+        self._held = False
+
+    @property
+    def path(self) -> Path:
+        return self.vault_root / LOCK_FILE_NAME
+
+    def _info(self) -> str:
+        info = {
+            "pid": os.getpid(),
+            "owner": self.owner,
+            "started_at": self.started_at,
+            "heartbeat_at": datetime.now().astimezone().isoformat(),
+        }
+        return json.dumps(info, ensure_ascii=False, indent=2)
+
+    def _write(self) -> None:
+        temp_path = self.path.with_suffix(".lock.tmp")
+        temp_path.write_text(self._info(), encoding='utf-8')
+        temp_path.replace(self.path)
+
+    def _create(self) -> bool:
+        """잠금 파일을 O_EXCL로 원자적으로 생성 (이미 있으면 False)"""
+        try:
+            fd = os.open(self.path, os.O_WRONLY | os.O_CREAT | os.O_EXCL, 0o644)
+        except FileExistsError:
+            return False
+        with os.fdopen(fd, "w", encoding='utf-8') as f:
+            f.write(self._info())
+        return True
+
+    def _take_over_stale(self, stale_content: str) -> bool:
+        """만료된 잠금을 고유 이름으로 옮긴 뒤, 옮긴 내용이 확인했던 만료 잠금과 같을 때만 인수"""
+        claimed = self.path.with_suffix(f".lock.stale.{os.getpid()}")
+        try:
+            self.path.rename(claimed)
+        except FileNotFoundError:
+            return False  # 다른 인스턴스가 먼저 인수함
+        try:
+            same = claimed.read_text(encoding='utf-8') == stale_content
+        except OSError:
+            same = False
+        if same:
+            claimed.unlink(missing_ok=True)
+            return True
+        # 그 사이 다른 인스턴스가 새로 만든 잠금을 옮겼으면 되돌림
+        claimed.replace(self.path)
+        return False
+
+    def _is_unreadable_lock_alive(self) -> bool:
+        """내용을 읽을 수 없는 잠금은 막 생성되어 아직 쓰는 중일 수 있으므로 수정 시각으로 판단"""
+        try:
+            age = datetime.now().timestamp() - self.path.stat().st_mtime
+        except OSError:
+            return True
+        return age < STALE_SECONDS
+
+    def acquire(self) -> bool:
+        """잠금 획득. 데스크톱 앱이 실행한 하위 프로세스면 부모의 잠금을 그대로 사용 (False 반환)"""
+        self.vault_root.mkdir(parents=True, exist_ok=True)
+        # 만료된 잠금을 인수한 뒤 한 번 더 생성 시도
+        for _ in range(2):
+            if self._create():
+                break
+            try:
+                content = self.path.read_text(encoding='utf-8')
+            except FileNotFoundError:
+                continue
+            try:
+                existing = json.loads(content)
+            except ValueError:
+                existing = None
+            if existing is not None and str(existing.get("pid")) == os.getenv("YDH_VAULT_LOCK_PID"):
+                return False
+            if existing is not None and existing.get("pid") == os.getpid():
+                self._write()
+                break
+            alive = is_lock_alive(existing) if existing is not None else self._is_unreadable_lock_alive()
+            if alive or not self._take_over_stale(content):
+                raise VaultLockedError(existing or {})
+        else:
+            raise VaultLockedError(read_lock(self.vault_root) or {})
+
+        self._held = True
+        self._thread = threading.Thread(target=self._heartbeat, daemon=True)
+        self._thread.start()
+        return True
+
+    def _heartbeat(self) -> None:
+        while not self._stop.wait(HEARTBEAT_INTERVAL_SECONDS):
+            try:
+                self._write()
+            except Exception as e:
+                logger.warning(f"Vault 잠금 하트비트 갱신 실패: {e}")
+
+    def release(self) -> None:
+        self._stop.set()
+        if not self._held:
+            return
+        self._held = False
+        existing = read_lock(self.vault_root)
+        if existing and existing.get("pid") == os.getpid():
+            try:
+                self.path.unlink()
+            except FileNotFoundError:
+                pass
@@ -4,7 +4,7 @@ Markdown generation with YAML frontmatter for Obsidian vault.
 
 import logging
 import re
-from datetime import datetime
+from datetime import datetime, timezone
 from pathlib import Path
 from typing import Dict, List, Optional, Any
 
@@ -13,6 +13,17 @@ from .converter import CaptionConverter
 
 logger = logging.getLogger(__name__)
 
+# 재생목록 소스의 Vault 폴더 접두사 (playlist_<재생목록 ID>, 데스크톱 앱과 동일)
+PLAYLIST_FOLDER_PREFIX = "playlist_"
+
+
+def playlist_id_from_url(url: str) -> Optional[str]:
+    """YouTube 재생목록 URL의 list ID (채널 URL이면 None)"""
+    if "youtube.com/" not in url or "/@" in url or "/channel/" in url:
+        return None
+    match = re.search(r'[?&]list=([A-Za-z0-9_-]+)', url)
+    return match.group(1) if match else None
+
 
 class VaultWriter:
     """Obsidian Vault용 마크다운 노트 생성 클래스."""
@@ -206,6 +217,10 @@ class VaultWriter:
             str: 채널 이름
         """
         try:
+            # 재생목록은 재생목록 폴더로 묶음
+            playlist_id = playlist_id_from_url(channel_url)
+            if playlist_id:
+                return f"{PLAYLIST_FOLDER_PREFIX}{playlist_id}"
             # URL에서 채널 이름 추출 시도
             if "@" in channel_url:
                 # @채널명 형태
@@ -302,6 +317,15 @@ class VaultWriter:
         # 🔥 NEW: 해시태그 정규화
         normalized_tags = self.normalize_hashtags(tags)
         
+        # 업로드 시각 (요일/시간대 분석용)
+        timestamp = video_info.get('timestamp') or video_info.get('release_timestamp')
+        published_at = ""
+        if timestamp:
+            try:
+                published_at = datetime.fromtimestamp(int(timestamp), tz=timezone.utc).isoformat()
+            except (TypeError, ValueError, OSError):
+                pass
+        
         # 소스 URL 생성
         source_url = f"https://www.youtube.com/watch?v={video_id}" if video_id else ""
         
@@ -314,6 +338,7 @@ class VaultWriter:
         metadata = {
             'title': title,
             'upload': formatted_date,
+            'published_at': published_at,
             'channel': uploader,
             'video_id': video_id,
             'topic': normalized_tags,
@@ -324,6 +349,13 @@ class VaultWriter:
             'excerpt': excerpt,
             'created_date': datetime.now().strftime('%Y-%m-%d %H:%M:%S'),
         }
+        # 핸들이 바뀌어 채널 폴더가 나뉘었을 때 같은 채널인지 확인하는 용도
+        if video_info.get('channel_id'):
+            metadata['channel_id'] = video_info['channel_id']
+        # Invidious/Piped 미러에서 가져온 메타데이터/자막 출처
+        for key in ('metadata_source', 'captions_source'):
+            if video_info.get(key):
+                metadata[key] = video_info[key]
         
         return metadata
     
@@ -444,6 +476,11 @@ class VaultWriter:
             
             logger.info(f"Vault 마크다운 생성: {md_file_path}")
             
+            # 영상 설명 저장 (다른 영상 링크 등 상호 참조 감지용)
+            description = video_info.get('description') or ''
+            if description:
+                (vault_path / "description.txt").write_text(description, encoding='utf-8')
+            
             # 원본 비디오 파일이 있으면 복사/이동
             if source_video_path and source_video_path.exists():
                 target_video_path = vault_path / "video.mp4"
@@ -456,12 +493,39 @@ class VaultWriter:
                     except Exception as e:
                         logger.warning(f"비디오 파일 이동 실패: {e}")
             
+            if source_video_path and settings.keep_subtitle_tracks:
+                self.copy_subtitle_tracks(source_video_path.parent, vault_path)
+            
             return True
             
         except Exception as e:
             logger.error(f"Vault 저장 중 오류 발생: {e}")
             return False
     
+    def copy_subtitle_tracks(self, source_folder: Path, vault_path: Path) -> int:
+        """
+        다운로드 폴더의 자막 트랙을 captions.<언어>.vtt로 Vault에 복사합니다.
+        (yt-dlp 파일명 형식: title.ko.vtt)
+        
+        Returns:
+            int: 복사된 자막 트랙 수
+        """
+        import shutil
+        copied = 0
+        for track in list(source_folder.glob("*.vtt")) + list(source_folder.glob("*.srt")):
+            language = Path(track.stem).suffix.lstrip(".")
+            if not language:
+                continue
+            target = vault_path / f"captions.{language}{track.suffix}"
+            if target.exists():
+                continue
+            try:
+                shutil.copy2(track, target)
+                copied += 1
+            except Exception as e:
+                logger.warning(f"자막 트랙 복사 실패: {track.name} - {e}")
+        return copied
+    
     def load_video_metadata(self, video_folder: Path) -> Dict[str, Any]:
         """
         비디오 폴더에서 메타데이터를 로드합니다.
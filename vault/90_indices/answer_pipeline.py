@@ -13,6 +13,7 @@ Prompt-Light 답변 파이프라인
 import os
 import time
 import json
+from datetime import datetime
 from typing import List, Dict, Optional, Any
 from pathlib import Path
 from dotenv import load_dotenv
@@ -26,6 +27,60 @@ from prompt_manager import PromptManager
 # 환경변수 로드
 load_dotenv()
 
+# 신뢰 신호 기준값
+STALE_SOURCE_DAYS = 365          # 가장 최근 인용 영상이 이보다 오래되면 오래된 정보로 표시
+AGREEING_SIMILARITY = 0.30       # 독립 출처로 셀 최소 유사도 (search precision_threshold와 동일)
+
+
+def _parse_upload_date(value: Any) -> Optional[datetime]:
+    """YYYY-MM-DD 또는 YYYYMMDD 업로드 날짜 파싱"""
+    digits = ''.join(ch for ch in str(value or '') if ch.isdigit())
+    if len(digits) != 8:
+        return None
+    try:
+        return datetime.strptime(digits, '%Y%m%d')
+    except ValueError:
+        return None
+
+
+def compute_confidence_signals(search_result: SearchResult, sources_used: List[str]) -> Dict[str, Any]:
+    """검색 점수, 인용 영상의 최신성, 독립 출처 수로 답변 신뢰 신호 계산"""
+    cited = [doc for doc in search_result.documents if doc.video_id in sources_used] or search_result.documents
+    if not cited:
+        return {"level": "low", "warnings": ["관련 영상을 찾지 못했습니다"], "independent_sources": 0}
+
+    similarities = [doc.similarity for doc in cited]
+    now = datetime.now()
+    ages = [
+        (now - date).days
+        for date in (_parse_upload_date(doc.metadata.get('upload_date')) for doc in cited)
+        if date
+    ]
+    agreeing = {doc.video_id for doc in cited if doc.similarity >= AGREEING_SIMILARITY}
+    channels = {doc.metadata.get('channel') for doc in cited if doc.video_id in agreeing}
+
+    newest_age = min(ages) if ages else None
+    warnings = []
+    if len(agreeing) <= 1:
+        warnings.append("하나의 영상에만 근거한 답변입니다")
+    if newest_age is not None and newest_age > STALE_SOURCE_DAYS:
+        warnings.append(f"가장 최근 근거 영상이 {newest_age // 30}개월 전 영상입니다")
+    if max(similarities) < AGREEING_SIMILARITY:
+        warnings.append("질문과 직접 관련된 내용이 적습니다")
+
+    level = "high" if not warnings else ("medium" if len(warnings) == 1 else "low")
+    return {
+        "level": level,
+        "warnings": warnings,
+        "top_similarity": round(max(similarities), 3),
+        "mean_similarity": round(sum(similarities) / len(similarities), 3),
+        "independent_sources": len(agreeing),
+        "independent_channels": len(channels),
+        "newest_source_age_days": newest_age,
+        "oldest_source_age_days": max(ages) if ages else None,
+        "stale": newest_age is not None and newest_age > STALE_SOURCE_DAYS,
+    }
+
 class AnswerPipeline:
     """Prompt-Light 답변 생성 파이프라인"""
     
@@ -147,14 +202,18 @@ class AnswerPipeline:
             try:
                 prompt_data = self.prompt_manager.get_channel_prompt(channel_name)
                 
+                # 전역 변수({{변수명}})는 길이 제한 후 질문 시점에 치환
+                prompt_globals = self.prompt_manager.load_prompt_globals()
+                resolve = lambda text: self.prompt_manager.resolve_prompt_variables(text, prompt_globals)
+                
                 # 경량화: 핵심 정보만 추출
                 return ChannelPrompt(
                     channel_name=channel_name,
-                    persona=prompt_data.get('persona', f'{channel_name} 채널 전문가')[:100],  # 1-2줄로 제한
-                    tone=prompt_data.get('tone', '친근하고 전문적인 스타일')[:50],
+                    persona=resolve(prompt_data.get('persona', f'{channel_name} 채널 전문가')[:100]),  # 1-2줄로 제한
+                    tone=resolve(prompt_data.get('tone', '친근하고 전문적인 스타일')[:50]),
                     expertise_keywords=prompt_data.get('expertise_keywords', [])[:5],  # 상위 5개만
-                    system_prompt=prompt_data.get('system_prompt', 
-                        f'당신은 {channel_name} 채널의 정보를 바탕으로 정확한 답변을 제공하는 AI입니다.')[:200]  # 간결하게
+                    system_prompt=resolve(prompt_data.get('system_prompt', 
+                        f'당신은 {channel_name} 채널의 정보를 바탕으로 정확한 답변을 제공하는 AI입니다.')[:200])  # 간결하게
                 )
             except Exception as e:
                 print(f"⚠️ 채널 프롬프트 로드 실패: {e}")
@@ -489,6 +548,24 @@ Final Answer: 다음 단계 결정
             print(f"⚠️ ReAct 패턴 실패: {e}")
             return "현재 정보로 충분합니다."
     
+    def _get_language_instruction(self) -> str:
+        """백엔드가 주입한 답변 언어/채널 용어집 지시문 (YDH_ANSWER_LANGUAGE, YDH_GLOSSARY)"""
+        instruction = ""
+        
+        answer_language = os.getenv('YDH_ANSWER_LANGUAGE', '').strip()
+        if answer_language:
+            instruction += f" 답변은 반드시 '{answer_language}' 언어로 작성하세요."
+        
+        try:
+            glossary = json.loads(os.getenv('YDH_GLOSSARY', '') or '{}')
+        except json.JSONDecodeError:
+            glossary = {}
+        if glossary:
+            terms = ", ".join(f"{term} → {translation}" for term, translation in glossary.items())
+            instruction += f" 다음 용어는 지정된 번역으로 일관되게 표기하세요: {terms}"
+        
+        return instruction
+    
     def _generate_initial_answer(self, request: AnswerRequest) -> str:
         """초기 답변 생성 (적응형 Temperature 적용)"""
         # 채널별 경량 프롬프트 로드
@@ -509,6 +586,7 @@ Final Answer: 다음 단계 결정
         
         # 도움이 되는 답변 중심 시스템 프롬프트
         system_message = f"{channel_prompt.system_prompt} {channel_prompt.tone}으로 답변하되, 영상에 없는 내용이라도 질문 해결에 도움이 된다면 적극적으로 포함하여 완전하고 유용한 답변을 제공하세요."
+        system_message += self._get_language_instruction()
         
         user_prompt = f"""## 검색된 컨텍스트 ({request.search_result.channel_name} 채널)
 {context}
@@ -707,5 +785,6 @@ Final Answer: 다음 단계 결정
             generation_time_ms=generation_time,
             self_refined=self_refined,
             react_steps=react_steps,
-            token_usage=token_usage
+            token_usage=token_usage,
+            confidence_signals=compute_confidence_signals(request.search_result, sources_used)
         ) 
\ No newline at end of file
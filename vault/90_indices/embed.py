@@ -3,21 +3,34 @@
 Vault 영상 자막을 Chroma DB에 임베딩하는 스크립트 (채널별 완전 격리)
 실행 경로: vault/10_videos → vault/90_indices/chroma
 각 채널은 독립된 컬렉션으로 완전히 분리됨
+- 영상마다 자막 해시(content_hash)를 메타데이터에 기록, 자막이 바뀐 영상만 문서를 지우고 다시 임베딩
+- 컬렉션마다 임베딩 모델/청크 설정을 기록, 다른 모델의 벡터가 섞이지 않도록 차단 (rebuild로 다시 생성)
+- 채널 모델은 데스크톱 앱이 감지 언어로 정해 YDH_EMBEDDING_MODELS로 전달 (없으면 기본 모델)
 """
 
+import os
 import sys
 from pathlib import Path
 import yaml
 import chromadb
 from chromadb.config import Settings as ChromaSettings
 import re
+import json
 import hashlib
+from datetime import datetime
+
+from embedding_backend import (
+    channel_embedding_function, embedding_function_for_model, embedding_batch_size, embedding_model_id, DEFAULT_MODEL_NAME,
+)
 
 # Vault 경로 설정
 VAULT_ROOT = Path(__file__).parent.parent
 VIDEOS_PATH = VAULT_ROOT / "10_videos"
 CHROMA_PATH = VAULT_ROOT / "90_indices" / "chroma"
 
+# 청크 설정 (현재는 영상 자막 전체를 문서 하나로 임베딩) - 바꾸면 버전을 올려 기존 컬렉션을 재임베딩 대상으로 표시
+CHUNKING = "whole_transcript:v1"
+
 def sanitize_collection_name(name: str) -> str:
     """ChromaDB 컬렉션 이름 생성 (해시 기반 고유 식별자)"""
     # 원본 이름의 해시값 생성 (SHA1의 처음 8자리)
@@ -47,6 +60,70 @@ def sanitize_collection_name(name: str) -> str:
     
     return collection_name
 
+def content_hash(transcript: str) -> str:
+    """임베딩한 자막 본문의 해시 (변경 감지용)"""
+    return hashlib.sha256(transcript.encode('utf-8')).hexdigest()[:16]
+
+def existing_video_documents(collection) -> dict:
+    """video_id → {"ids": 문서 id 목록, "metadatas": 메타데이터, "hashes": content_hash 집합}
+    (청크로 나뉜 영상도 메타데이터의 video_id로 묶음)"""
+    data = collection.get(include=["metadatas"])
+    videos = {}
+    for doc_id, metadata in zip(data.get("ids") or [], data.get("metadatas") or []):
+        metadata = metadata or {}
+        video_id = str(metadata.get("video_id") or doc_id)
+        entry = videos.setdefault(video_id, {"ids": [], "metadatas": [], "hashes": set()})
+        entry["ids"].append(doc_id)
+        entry["metadatas"].append(metadata)
+        entry["hashes"].add(metadata.get("content_hash"))
+    return videos
+
+def delete_video_documents(collection, video_ids: list) -> int:
+    """지정 영상의 문서만 삭제 (청크 포함), 삭제한 문서 수 반환"""
+    targets = set(video_ids)
+    ids = [
+        doc_id
+        for video_id, entry in existing_video_documents(collection).items()
+        if video_id in targets
+        for doc_id in entry["ids"]
+    ]
+    if ids:
+        collection.delete(ids=ids)
+    return len(ids)
+
+def collection_provenance(collection) -> dict:
+    """컬렉션을 만든 임베딩 모델/청크 설정 (기록 이전 컬렉션은 당시 유일한 기본 모델로 간주)"""
+    metadata = collection.metadata or {}
+    return {
+        "embedding_model": metadata.get("embedding_model", DEFAULT_MODEL_NAME),
+        "chunking": metadata.get("chunking", CHUNKING),
+        "recorded": "embedding_model" in metadata,
+    }
+
+
+def ensure_provenance(collection, current: dict) -> bool:
+    """현재 설정과 같으면 출처를 기록하고 True, 다른 모델/청크 설정의 벡터가 있으면 False (혼합 차단)"""
+    stored = collection_provenance(collection)
+    if collection.count() > 0 and (stored["embedding_model"], stored["chunking"]) != (current["embedding_model"], current["chunking"]):
+        return False
+    if not stored["recorded"] or collection.count() == 0:
+        collection.modify(metadata={
+            **(collection.metadata or {}),
+            "embedding_model": current["embedding_model"],
+            "chunking": current["chunking"],
+            "provenance_recorded_at": datetime.now().isoformat(),
+        })
+    return True
+
+
+def excluded_channels():
+    """데스크톱 앱이 넘겨준 보관 채널 목록 (전체 처리 시 건너뜀)"""
+    try:
+        return set(json.loads(os.environ.get("YDH_EXCLUDED_EMBEDDING_CHANNELS", "[]")))
+    except (json.JSONDecodeError, TypeError):
+        return set()
+
+
 def main(target_channels=None):
     """메인 임베딩 실행 함수 - 채널별 격리 컬렉션 생성"""
     print(f"🔍 영상 검색: {VIDEOS_PATH}")
@@ -65,6 +142,7 @@ def main(target_channels=None):
     
     # 채널별 처리 통계
     channel_stats = {}
+    excluded = set() if target_channels else excluded_channels()
     total_processed = 0
     total_skipped = 0
     
@@ -79,10 +157,20 @@ def main(target_channels=None):
         if target_channels and channel_name not in target_channels:
             print(f"⏭️  스킵: {channel_name} (선택되지 않음)")
             continue
+        if channel_name in excluded:
+            print(f"⏭️  스킵: {channel_name} (보관된 채널)")
+            continue
         collection_name = f"channel_{sanitize_collection_name(channel_name)}"
         
+        # 채널 언어별 모델 + Vault 설정의 임베딩 백엔드 (ort면 GPU 실행 공급자 + VRAM 기준 배치)
+        embedding_function = channel_embedding_function(channel_name)
+        batch_size = embedding_batch_size(embedding_function)
+        current_provenance = {"embedding_model": embedding_model_id(embedding_function), "chunking": CHUNKING}
+        
         print(f"\n📺 채널 처리: {channel_name}")
-        print(f"📦 컬렉션: {collection_name}")
+        print(f"📦 컬렉션: {collection_name} ({current_provenance['embedding_model']}, 배치 {batch_size})")
+        if getattr(embedding_function, "provider", None):
+            print(f"⚡ ORT 임베딩 백엔드: {embedding_function.provider}")
         sys.stdout.flush()  # 실시간 출력을 위한 flush
         
         # 채널별 컬렉션 생성 (독립적)
@@ -92,20 +180,52 @@ def main(target_channels=None):
                 "description": f"{channel_name} 영상 자막 임베딩 (격리됨)",
                 "channel_name": channel_name,
                 "isolated": True
-            }
+            },
+            **({"embedding_function": embedding_function} if embedding_function is not None else {})
         )
+        if not ensure_provenance(channel_collection, current_provenance):
+            stored = collection_provenance(channel_collection)
+            print(f"  ⛔ 모델 혼합 차단: 기존 벡터는 {stored['embedding_model']} ({stored['chunking']}), "
+                  f"현재 설정은 {current_provenance['embedding_model']} ({current_provenance['chunking']})")
+            print(f"  💡 재임베딩 필요: python embed.py rebuild {channel_name}")
+            sys.stdout.flush()
+            channel_stats[channel_name] = {"processed": 0, "skipped": 0, "replaced": 0,
+                                           "collection_name": collection_name, "blocked": True}
+            continue
         
-        # 기존 임베딩된 video_id 목록 가져오기
+        # 기존 임베딩된 영상별 문서/해시 가져오기
         try:
-            existing_data = channel_collection.get()
-            existing_ids = set(existing_data['ids']) if existing_data['ids'] else set()
-            print(f"  📊 기존 임베딩: {len(existing_ids)}개")
+            existing_videos = existing_video_documents(channel_collection)
+            print(f"  📊 기존 임베딩: {len(existing_videos)}개")
         except Exception:
-            existing_ids = set()
+            existing_videos = {}
             print(f"  📊 기존 임베딩: 0개 (새로운 컬렉션)")
+        existing_ids = set()
         
         channel_processed = 0
         channel_skipped = 0
+        channel_replaced = 0
+        pending = {"documents": [], "metadatas": [], "ids": [], "titles": []}
+        
+        def flush_pending():
+            """모아둔 문서를 배치로 임베딩"""
+            nonlocal channel_processed
+            if not pending["ids"]:
+                return
+            try:
+                channel_collection.add(
+                    documents=pending["documents"],
+                    metadatas=pending["metadatas"],
+                    ids=pending["ids"]
+                )
+                channel_processed += len(pending["ids"])
+                for title in pending["titles"]:
+                    print(f"  ✅ 처리됨: {title}")
+            except Exception as e:
+                print(f"  ❌ 배치 임베딩 오류 ({len(pending['ids'])}개): {e}")
+            sys.stdout.flush()
+            for values in pending.values():
+                values.clear()
         
         # 해당 채널의 모든 captions.md 파일 처리
         for captions_file in channel_dir.rglob("captions.md"):
@@ -121,13 +241,31 @@ def main(target_channels=None):
                         transcript = parts[2].strip()
                         
                         video_id = str(metadata.get("video_id", f"video_{total_processed}"))
+                        digest = content_hash(transcript)
                         
-                        # 중복 체크: 이미 임베딩된 경우 스킵
+                        # 중복 체크: 같은 실행에서 이미 추가한 영상
                         if video_id in existing_ids:
                             channel_skipped += 1
-                            print(f"  ⏭️  스킵됨: {metadata.get('title', 'Unknown')} (이미 임베딩됨)")
-                            sys.stdout.flush()
                             continue
+                        existing = existing_videos.get(video_id)
+                        if existing:
+                            if existing["hashes"] == {digest}:
+                                channel_skipped += 1
+                                print(f"  ⏭️  스킵됨: {metadata.get('title', 'Unknown')} (이미 임베딩됨)")
+                                sys.stdout.flush()
+                                continue
+                            if existing["hashes"] == {None}:
+                                # 해시 기록 이전의 임베딩: 다시 계산하지 않고 현재 자막 해시만 기록
+                                channel_collection.update(
+                                    ids=existing["ids"],
+                                    metadatas=[{**m, "content_hash": digest} for m in existing["metadatas"]],
+                                )
+                                channel_skipped += 1
+                                continue
+                            # 자막이 바뀐 영상: 이 영상의 문서만 지우고 다시 임베딩
+                            channel_collection.delete(ids=existing["ids"])
+                            channel_replaced += 1
+                            print(f"  🔄 자막 변경: {metadata.get('title', 'Unknown')} (기존 문서 {len(existing['ids'])}개 삭제)")
                         
                         # 메타데이터 정리 및 확장
                         enhanced_metadata = {
@@ -141,35 +279,37 @@ def main(target_channels=None):
                             "source_url": str(metadata.get("source_url", "")),
                             "file_path": str(captions_file.relative_to(VAULT_ROOT)),
                             "video_year": str(metadata.get("upload", ""))[:4] if metadata.get("upload") else "unknown",
+                            "transcript_quality": float(metadata.get("transcript_quality", -1.0)),  # -1: 미측정
+                            "content_hash": digest,
                             "isolated_channel": True  # 격리 모드 표시
                         }
                         
-                        # 채널별 컬렉션에만 추가 (통합 컬렉션 없음)
-                        channel_collection.add(
-                            documents=[transcript],
-                            metadatas=[enhanced_metadata],
-                            ids=[video_id]
-                        )
-                        
-                        channel_processed += 1
-                        print(f"  ✅ 처리됨: {metadata.get('title', 'Unknown')}")
-                        sys.stdout.flush()
+                        # 채널별 컬렉션에만 추가 (통합 컬렉션 없음, 배치 단위)
+                        pending["documents"].append(transcript)
+                        pending["metadatas"].append(enhanced_metadata)
+                        pending["ids"].append(video_id)
+                        pending["titles"].append(metadata.get('title', 'Unknown'))
+                        existing_ids.add(video_id)
+                        if len(pending["ids"]) >= batch_size:
+                            flush_pending()
                         
             except Exception as e:
                 print(f"  ❌ 오류: {captions_file} - {e}")
                 continue
+        flush_pending()
         
         # 채널별 통계 저장
         channel_stats[channel_name] = {
             "processed": channel_processed,
             "skipped": channel_skipped,
+            "replaced": channel_replaced,
             "collection_name": collection_name
         }
         
         total_processed += channel_processed
         total_skipped += channel_skipped
         
-        print(f"  📊 {channel_name}: {channel_processed}개 새로 임베딩, {channel_skipped}개 스킵됨")
+        print(f"  📊 {channel_name}: {channel_processed}개 새로 임베딩 (자막 변경 {channel_replaced}개), {channel_skipped}개 스킵됨")
         sys.stdout.flush()
     
     # 최종 결과 출력
@@ -182,6 +322,71 @@ def main(target_channels=None):
     for channel_name, stats in channel_stats.items():
         print(f"  📺 {channel_name}: {stats['processed']}개 처리 → {stats['collection_name']}")
 
+def reembed_videos(channel_name: str, video_ids: list):
+    """지정 영상의 기존 임베딩만 삭제하고 다시 임베딩 (다른 영상은 해시가 같으면 그대로 유지)"""
+    client = chromadb.PersistentClient(
+        path=str(CHROMA_PATH),
+        settings=ChromaSettings(anonymized_telemetry=False)
+    )
+    collection_name = f"channel_{sanitize_collection_name(channel_name)}"
+    try:
+        collection = client.get_collection(collection_name)
+        deleted = delete_video_documents(collection, video_ids)
+        print(f"🗑️ {channel_name}: {len(video_ids)}개 영상의 기존 문서 {deleted}개 삭제")
+    except Exception as e:
+        print(f"⚠️ {channel_name}: 기존 임베딩 삭제 실패 ({e}), 신규 임베딩만 진행")
+    sys.stdout.flush()
+    
+    main([channel_name])
+
+def rebuild_channels(channel_names: list):
+    """채널 컬렉션을 지우고 현재 임베딩 모델/청크 설정으로 처음부터 다시 임베딩"""
+    client = chromadb.PersistentClient(
+        path=str(CHROMA_PATH),
+        settings=ChromaSettings(anonymized_telemetry=False)
+    )
+    for channel_name in channel_names:
+        collection_name = f"channel_{sanitize_collection_name(channel_name)}"
+        try:
+            client.delete_collection(collection_name)
+            print(f"🗑️ {channel_name}: 기존 컬렉션 삭제 ({collection_name})")
+        except Exception:
+            print(f"ℹ️ {channel_name}: 기존 컬렉션 없음")
+    sys.stdout.flush()
+    
+    main(channel_names)
+
+def index_provenance() -> dict:
+    """채널 컬렉션별 임베딩 모델/청크 설정과 현재 설정 비교"""
+    client = chromadb.PersistentClient(
+        path=str(CHROMA_PATH),
+        settings=ChromaSettings(anonymized_telemetry=False)
+    )
+    current = {"embedding_model": embedding_model_id(embedding_function_for_model(None)), "chunking": CHUNKING}
+    collections = []
+    for collection in client.list_collections():
+        if not collection.name.startswith("channel_"):
+            continue
+        metadata = collection.metadata or {}
+        stored = collection_provenance(collection)
+        documents = collection.count()
+        expected = {
+            "embedding_model": embedding_model_id(channel_embedding_function(metadata.get("channel_name", ""))),
+            "chunking": CHUNKING,
+        }
+        collections.append({
+            "channel": metadata.get("channel_name", collection.name),
+            "collection": collection.name,
+            "embedding_model": stored["embedding_model"],
+            "chunking": stored["chunking"],
+            "recorded": stored["recorded"],
+            "recorded_at": metadata.get("provenance_recorded_at"),
+            "documents": documents,
+            "expected_model": expected["embedding_model"],
+            "outdated": documents > 0 and (stored["embedding_model"], stored["chunking"]) != (expected["embedding_model"], expected["chunking"]),
+        })
+    return {"current": current, "collections": collections}
+
 def list_collections():
     """생성된 컬렉션 목록 확인"""
     client = chromadb.PersistentClient(path=str(CHROMA_PATH))
@@ -216,7 +421,13 @@ def search_example(query: str, channel_name: str, n_results: int = 5):
     # 특정 채널에서만 검색
     collection_name = f"channel_{sanitize_collection_name(channel_name)}"
     try:
-        collection = client.get_collection(collection_name)
+        # 컬렉션을 만든 모델로 질의를 임베딩
+        stored_model = collection_provenance(client.get_collection(collection_name))["embedding_model"]
+        embedding_function = embedding_function_for_model(stored_model)
+        if embedding_function is not None:
+            collection = client.get_collection(collection_name, embedding_function=embedding_function)
+        else:
+            collection = client.get_collection(collection_name)
         print(f"🔍 채널별 검색: '{query}' in {channel_name}")
     except Exception:
         print(f"❌ 채널 '{channel_name}' 컬렉션을 찾을 수 없습니다.")
@@ -273,6 +484,20 @@ if __name__ == "__main__":
                 print("예시: python embed.py channels 도쿄부동산")
                 sys.exit(1)
             main(target_channels)
+        elif command == "provenance":
+            print(json.dumps(index_provenance(), ensure_ascii=False))
+        elif command == "rebuild":
+            # 임베딩 모델 변경 후 채널 전체 재임베딩: python embed.py rebuild <채널명1> [채널명2] ...
+            if len(sys.argv) < 3:
+                print("사용법: python embed.py rebuild <채널명1> [채널명2] ...")
+                sys.exit(1)
+            rebuild_channels(sys.argv[2:])
+        elif command == "reembed":
+            # 수정된 영상 재임베딩: python embed.py reembed <채널명> <video_id1> [video_id2] ...
+            if len(sys.argv) < 4:
+                print("사용법: python embed.py reembed <채널명> <video_id1> [video_id2] ...")
+                sys.exit(1)
+            reembed_videos(sys.argv[2], sys.argv[3:])
         else:
             main()
     else:
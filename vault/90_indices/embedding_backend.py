@@ -0,0 +1,261 @@
+#!/usr/bin/env python3
+"""
+로컬 임베딩 백엔드 선택 (Vault별 설정: 90_indices/embedding_backend.json)
+- default: Chroma 기본 임베딩 (all-MiniLM-L6-v2 ONNX, CPU)
+- ort: onnxruntime + CoreML/CUDA/DirectML 실행 공급자 (GPU 가속, 오프라인)
+  model_path를 지정하면 해당 폴더의 model.onnx + tokenizer.json 사용 (모델 변경 시 재임베딩 필요)
+- 배치 크기는 사용 가능한 VRAM(통합 메모리)에 맞춰 자동 결정
+- 채널 감지 언어별 모델(YDH_EMBEDDING_MODELS)이 기본 모델과 다르면 sentence-transformers로 임베딩
+"""
+
+import os
+import sys
+import json
+import time
+import platform
+import subprocess
+from functools import lru_cache
+from pathlib import Path
+from typing import Dict, List, Optional
+
+# Vault 경로 설정
+VAULT_ROOT = Path(__file__).parent.parent
+SETTINGS_PATH = VAULT_ROOT / "90_indices" / "embedding_backend.json"
+VIDEOS_PATH = VAULT_ROOT / "10_videos"
+
+DEFAULT_SETTINGS = {"backend": "default", "provider": "auto", "model_path": None, "batch_size": 0}
+# Chroma 기본 임베딩과 ORT 기본 모델이 쓰는 가중치
+DEFAULT_MODEL_NAME = "all-MiniLM-L6-v2"
+
+PROVIDER_NAMES = {
+    "coreml": "CoreMLExecutionProvider",
+    "cuda": "CUDAExecutionProvider",
+    "directml": "DmlExecutionProvider",
+    "cpu": "CPUExecutionProvider",
+}
+# auto 선택 시 우선순위
+AUTO_PROVIDER_ORDER = ["cuda", "coreml", "directml", "cpu"]
+
+# 배치 1건(문서)당 대략적인 활성화 메모리 (MiniLM, 256 토큰 기준)
+MEMORY_PER_ITEM_MB = 24
+MIN_BATCH_SIZE = 8
+MAX_BATCH_SIZE = 256
+CPU_BATCH_SIZE = 32
+
+
+def load_backend_settings() -> dict:
+    try:
+        return {**DEFAULT_SETTINGS, **json.loads(SETTINGS_PATH.read_text(encoding='utf-8'))}
+    except Exception:
+        return dict(DEFAULT_SETTINGS)
+
+
+def available_providers() -> List[str]:
+    try:
+        import onnxruntime
+        return onnxruntime.get_available_providers()
+    except ImportError:
+        return []
+
+
+def resolve_provider(preference: str) -> str:
+    """설정된 공급자를 사용 가능한 실행 공급자 키로 변환 (없으면 cpu)"""
+    available = available_providers()
+    candidates = AUTO_PROVIDER_ORDER if preference == "auto" else [preference, "cpu"]
+    for key in candidates:
+        if PROVIDER_NAMES.get(key) in available:
+            return key
+    return "cpu"
+
+
+def accelerator_memory_mb(provider: str) -> Optional[int]:
+    """가속기에서 사용 가능한 메모리 (MB). 확인할 수 없으면 None"""
+    try:
+        if provider == "cuda":
+            output = subprocess.run(
+                ["nvidia-smi", "--query-gpu=memory.free", "--format=csv,noheader,nounits"],
+                capture_output=True, text=True, timeout=5,
+            ).stdout
+            values = [int(v) for v in output.split() if v.strip().isdigit()]
+            return max(values) if values else None
+        if provider == "coreml" and platform.system() == "Darwin":
+            # Apple Silicon은 통합 메모리: 전체의 절반만 GPU 몫으로 간주
+            output = subprocess.run(["sysctl", "-n", "hw.memsize"], capture_output=True, text=True, timeout=5).stdout
+            return int(output.strip()) // (1024 * 1024) // 2
+    except Exception:
+        pass
+    return None
+
+
+def auto_batch_size(provider: str) -> int:
+    memory = accelerator_memory_mb(provider)
+    if provider == "cpu" or memory is None:
+        return CPU_BATCH_SIZE
+    # 절반은 모델/기타 용도로 남겨둠
+    return max(MIN_BATCH_SIZE, min(MAX_BATCH_SIZE, memory // 2 // MEMORY_PER_ITEM_MB))
+
+
+class OrtEmbeddingFunction:
+    """onnxruntime 실행 공급자를 지정한 Chroma 임베딩 함수"""
+
+    def __init__(self, provider: str = "auto", model_path: Optional[str] = None, batch_size: int = 0):
+        self.provider = resolve_provider(provider)
+        self.batch_size = batch_size or auto_batch_size(self.provider)
+        self.model_path = model_path
+        providers = [PROVIDER_NAMES[self.provider]]
+        if self.provider != "cpu":
+            providers.append(PROVIDER_NAMES["cpu"])
+
+        if model_path:
+            import onnxruntime
+            from tokenizers import Tokenizer
+
+            model_dir = Path(model_path).expanduser()
+            self.tokenizer = Tokenizer.from_file(str(model_dir / "tokenizer.json"))
+            self.tokenizer.enable_truncation(max_length=256)
+            self.tokenizer.enable_padding()
+            self.session = onnxruntime.InferenceSession(str(model_dir / "model.onnx"), providers=providers)
+            self.default_function = None
+        else:
+            # Chroma 기본 모델과 같은 가중치 → 기존 컬렉션과 호환
+            from chromadb.utils.embedding_functions import ONNXMiniLM_L6_V2
+            self.default_function = ONNXMiniLM_L6_V2(preferred_providers=providers)
+
+    def _embed_batch(self, texts: List[str]) -> List[List[float]]:
+        import numpy as np
+
+        if self.default_function is not None:
+            return [list(map(float, e)) for e in self.default_function(texts)]
+
+        encoded = self.tokenizer.encode_batch(texts)
+        input_ids = np.array([e.ids for e in encoded], dtype=np.int64)
+        attention_mask = np.array([e.attention_mask for e in encoded], dtype=np.int64)
+        inputs = {"input_ids": input_ids, "attention_mask": attention_mask}
+        input_names = {i.name for i in self.session.get_inputs()}
+        if "token_type_ids" in input_names:
+            inputs["token_type_ids"] = np.zeros_like(input_ids)
+        hidden = self.session.run(None, inputs)[0]
+
+        # mean pooling + L2 정규화 (sentence-transformers와 동일)
+        mask = attention_mask[..., None].astype(np.float32)
+        pooled = (hidden * mask).sum(axis=1) / np.clip(mask.sum(axis=1), 1e-9, None)
+        pooled = pooled / np.clip(np.linalg.norm(pooled, axis=1, keepdims=True), 1e-12, None)
+        return pooled.astype(float).tolist()
+
+    def __call__(self, input: List[str]) -> List[List[float]]:
+        embeddings = []
+        for start in range(0, len(input), self.batch_size):
+            embeddings.extend(self._embed_batch(input[start:start + self.batch_size]))
+        return embeddings
+
+
+def get_embedding_function(settings: Optional[dict] = None):
+    """Vault 설정에 맞는 임베딩 함수 (default 백엔드면 None → Chroma 기본값)"""
+    settings = settings or load_backend_settings()
+    if settings.get("backend") != "ort":
+        return None
+    try:
+        return OrtEmbeddingFunction(settings.get("provider", "auto"), settings.get("model_path"), int(settings.get("batch_size") or 0))
+    except Exception as e:
+        print(f"⚠️ ORT 임베딩 백엔드를 사용할 수 없어 기본 임베딩을 사용합니다: {e}")
+        return None
+
+
+@lru_cache(maxsize=None)
+def embedding_function_for_model(model_name: Optional[str] = None):
+    """모델 식별자에 맞는 임베딩 함수 (기본/ONNX 모델이면 Vault 백엔드 설정을 따름)"""
+    if not model_name or model_name == DEFAULT_MODEL_NAME or model_name.startswith("onnx:"):
+        return get_embedding_function()
+    from chromadb.utils.embedding_functions import SentenceTransformerEmbeddingFunction
+    function = SentenceTransformerEmbeddingFunction(model_name=model_name)
+    function.model_name = model_name
+    return function
+
+
+def channel_embedding_models() -> Dict[str, str]:
+    """데스크톱 앱이 넘겨준 채널별 임베딩 모델 (채널 감지 언어 기준)"""
+    try:
+        return json.loads(os.environ.get("YDH_EMBEDDING_MODELS", "") or "{}")
+    except json.JSONDecodeError:
+        return {}
+
+
+def channel_embedding_function(channel_name: str):
+    """채널 컬렉션을 만들 임베딩 함수 (ORT 사용자 모델(model_path)을 지정했으면 언어와 무관하게 그 모델)"""
+    settings = load_backend_settings()
+    if settings.get("backend") == "ort" and settings.get("model_path"):
+        return embedding_function_for_model(None)
+    return embedding_function_for_model(channel_embedding_models().get(channel_name))
+
+
+def embedding_model_id(embedding_function) -> str:
+    """실제로 사용하는 임베딩 모델 식별자 (실행 공급자와 무관, 벡터 호환 여부 판단용)"""
+    model_path = getattr(embedding_function, "model_path", None)
+    if model_path:
+        return f"onnx:{Path(model_path).expanduser().name}"
+    return getattr(embedding_function, "model_name", None) or DEFAULT_MODEL_NAME
+
+
+def embedding_batch_size(embedding_function) -> int:
+    return getattr(embedding_function, "batch_size", CPU_BATCH_SIZE)
+
+
+def sample_transcripts(count: int) -> List[str]:
+    """벤치마크용 실제 자막 샘플 (없으면 합성 텍스트)"""
+    samples = []
+    if VIDEOS_PATH.exists():
+        for captions_file in VIDEOS_PATH.rglob("captions.md"):
+            text = captions_file.read_text(encoding='utf-8', errors='ignore')
+            parts = text.split('---', 2)
+            body = parts[2] if len(parts) >= 3 else text
+            samples.extend(chunk for chunk in (body[i:i + 1000] for i in range(0, len(body), 1000)) if chunk.strip())
+            if len(samples) >= count:
+                break
+    while len(samples) < count:
+        samples.append(f"벤치마크 샘플 문장 {len(samples)}: 임베딩 처리량 측정을 위한 텍스트입니다. " * 8)
+    return samples[:count]
+
+
+def benchmark_backends(count: int = 256) -> List[Dict]:
+    """사용 가능한 백엔드/실행 공급자별 처리량 비교"""
+    texts = sample_transcripts(count)
+    configs = [("default", None)]
+    for key in AUTO_PROVIDER_ORDER:
+        if PROVIDER_NAMES[key] in available_providers():
+            configs.append(("ort", key))
+
+    settings = load_backend_settings()
+    results = []
+    for backend, provider in configs:
+        result = {"backend": backend, "provider": provider or "cpu", "batch_size": None,
+                  "texts": len(texts), "seconds": None, "texts_per_second": None, "error": None}
+        try:
+            if backend == "default":
+                from chromadb.utils.embedding_functions import DefaultEmbeddingFunction
+                function = DefaultEmbeddingFunction()
+                result["batch_size"] = len(texts)
+            else:
+                function = OrtEmbeddingFunction(provider, settings.get("model_path"), int(settings.get("batch_size") or 0))
+                result["batch_size"] = function.batch_size
+            function(texts[:4])  # 워밍업 (모델 로드/그래프 컴파일 제외)
+            started = time.perf_counter()
+            function(texts)
+            elapsed = time.perf_counter() - started
+            result["seconds"] = round(elapsed, 3)
+            result["texts_per_second"] = round(len(texts) / elapsed, 1) if elapsed > 0 else None
+        except Exception as e:
+            result["error"] = str(e)
+        results.append(result)
+        print(f"⏱️ {backend}/{result['provider']}: {result['texts_per_second'] or result['error']}", file=sys.stderr)
+    return results
+
+
+if __name__ == "__main__":
+    if len(sys.argv) > 1 and sys.argv[1] == "benchmark":
+        count = int(sys.argv[2]) if len(sys.argv) > 2 else 256
+        print(json.dumps(benchmark_backends(count), ensure_ascii=False))
+    elif len(sys.argv) > 1 and sys.argv[1] == "providers":
+        print(json.dumps({"available": available_providers(), "auto": resolve_provider("auto")}))
+    else:
+        print("사용법: python embedding_backend.py benchmark [샘플 수] | providers")
+        sys.exit(1)
@@ -7,6 +7,7 @@ Y-Data-House 채널별 격리 정합성 검사 스크립트
 """
 
 import sys
+import json
 from pathlib import Path
 import yaml
 import chromadb
@@ -20,6 +21,12 @@ VAULT_ROOT = Path(__file__).parent.parent
 VIDEOS_PATH = VAULT_ROOT / "10_videos"
 CHROMA_PATH = VAULT_ROOT / "90_indices" / "chroma"
 
+
+def emit_progress(stage: str, progress: int, message: str):
+    """데스크톱 앱 진행률 이벤트 (PROGRESS:{json} 한 줄, src/ydh/progress.py와 같은 형식)"""
+    event = {"event": "stage", "stage": stage, "progress": progress, "message": message}
+    print(f"PROGRESS:{json.dumps(event, ensure_ascii=False)}", flush=True)
+
 def sanitize_collection_name(name: str) -> str:
     """ChromaDB 컬렉션 이름 생성 (해시 기반 고유 식별자)"""
     hash_suffix = hashlib.sha1(name.encode('utf-8')).hexdigest()[:8]
@@ -258,18 +265,23 @@ def main():
     print("=" * 60)
     
     # 1. 파일 구조 검사
+    emit_progress("files", 5, "파일 구조 검사 중...")
     file_stats = check_file_structure()
     
     # 2. ChromaDB 컬렉션 검사  
+    emit_progress("collections", 30, "ChromaDB 컬렉션 검사 중...")
     collection_stats = check_chroma_collections()
     
     # 3. 교차 검증
+    emit_progress("cross_check", 55, "채널별 격리 정합성 교차 검증 중...")
     issues = cross_check_integrity(file_stats, collection_stats)
     
     # 4. 벡터 격리 검증
+    emit_progress("isolation", 75, "벡터 격리 검증 중...")
     check_vector_isolation()
     
     # 5. 최종 결과
+    emit_progress("report", 95, f"결과 정리 중... ({len(issues)}개 문제)")
     print(f"\n" + "=" * 60)
     print("📋 정합성 검사 결과:")
     
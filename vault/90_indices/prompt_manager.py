@@ -16,6 +16,10 @@ from zero_shot_prompt_generator import ZeroShotPromptGenerator
 # 환경변수 로드
 load_dotenv()
 
+# 모든 채널 프롬프트에서 {{변수명}}으로 참조하는 전역 변수 파일
+GLOBALS_FILE_NAME = "_globals.json"
+PROMPT_VARIABLE_PATTERN = re.compile(r'\{\{\s*([\w가-힣.-]+)\s*\}\}')
+
 
 class PromptManager:
     """채널별 프롬프트 관리 클래스"""
@@ -46,6 +50,36 @@ class PromptManager:
     
 
     
+    def load_prompt_globals(self) -> Dict:
+        """전역 프롬프트 변수 로드 (호출마다 다시 읽어 수정 즉시 반영)"""
+        globals_file = self.prompts_dir / GLOBALS_FILE_NAME
+        if not globals_file.exists():
+            return {}
+        try:
+            data = json.loads(globals_file.read_text(encoding='utf-8'))
+            return data if isinstance(data, dict) else {}
+        except Exception as e:
+            print(f"⚠️ 전역 프롬프트 변수 읽기 실패: {e}")
+            return {}
+    
+    def resolve_prompt_variables(self, text: str, prompt_globals: Dict = None) -> str:
+        """{{변수명}}을 전역 변수 값으로 치환 (정의되지 않은 변수는 그대로 유지)"""
+        if not text or '{{' not in text:
+            return text
+        prompt_globals = self.load_prompt_globals() if prompt_globals is None else prompt_globals
+        
+        def replace(match):
+            if match.group(1) not in prompt_globals:
+                return match.group(0)
+            value = prompt_globals[match.group(1)]
+            if isinstance(value, list):
+                return ", ".join(str(v) for v in value)
+            if isinstance(value, dict):
+                return json.dumps(value, ensure_ascii=False)
+            return str(value)
+        
+        return PROMPT_VARIABLE_PATTERN.sub(replace, text)
+    
     def sanitize_channel_name(self, channel_name: str) -> str:
         """채널명을 파일시스템에 안전한 형태로 변환"""
         sanitized = re.sub(r'[^\w가-힣\-_]', '_', channel_name)
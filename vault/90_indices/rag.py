@@ -75,7 +75,8 @@ def chat_with_progress(query: str, channel_name: str, model: str = "deepseek-cha
     sys.stdout.flush()
     time.sleep(0.4)
     
-    controller = RAGController(CHROMA_PATH, model)
+    # 질문 감시 재실행 시에는 캐시된 답변 대신 새 답변 생성
+    controller = RAGController(CHROMA_PATH, model, enable_cache=os.getenv('YDH_RAG_NO_CACHE') != '1')
     
     print(f"PROGRESS:{json.dumps({'step': 'query_analysis', 'message': '🧠 질문 분석 중...', 'progress': 10.0, 'details': f'질문 길이: {len(query)}자, 복잡도 판단 중'}, ensure_ascii=False)}")
     sys.stdout.flush()
@@ -128,6 +129,7 @@ def chat_with_progress(query: str, channel_name: str, model: str = "deepseek-cha
         "documents_found": response.documents_found,
         "processing_time": response.total_time_ms,
         "search_quality": response.search_quality,
+        "confidence_signals": response.confidence_signals,
         "debug_info": response.debug_info
     }
     
@@ -278,6 +278,7 @@ class RAGController:
                     "avg_similarity": sum(doc.similarity for doc in search_result.documents) / len(search_result.documents) if search_result.documents else 0.0,
                     "video_relevance": video_relevance_analysis
                 },
+                confidence_signals=answer_response.confidence_signals,
                 debug_info={
                     "query_type": search_query.query_type.value,
                     "fast_mode": fast_mode,
@@ -0,0 +1,201 @@
+#!/usr/bin/env python3
+"""
+저품질 자동 자막 재전사 스크립트 (Whisper)
+- transcript_quality.json 기준 threshold 미만 영상을 faster-whisper로 다시 전사
+- 기존 captions.md는 영상 폴더의 archive/ 로 보관
+- 재전사된 영상은 Chroma에서 삭제 후 다시 임베딩
+"""
+
+import os
+import sys
+import json
+import re
+import shutil
+import argparse
+from datetime import datetime
+from pathlib import Path
+
+import chromadb
+from chromadb.config import Settings as ChromaSettings
+
+from embed import sanitize_collection_name, delete_video_documents, main as embed_channels
+from transcript_quality import (
+    QUALITY_INDEX_PATH, TRANSCRIPT_SECTION, load_quality_index, score_transcript,
+    write_quality_to_frontmatter,
+)
+
+# Vault 경로 설정
+VAULT_ROOT = Path(__file__).parent.parent
+CHROMA_PATH = VAULT_ROOT / "90_indices" / "chroma"
+
+
+def select_targets(quality_index: dict, threshold: float, channel: str = None) -> list:
+    """품질 점수가 threshold 미만인 영상 목록 (낮은 점수 순)"""
+    targets = []
+    for video_id, item in quality_index.items():
+        if channel and item.get("channel") != channel:
+            continue
+        if item.get("score", 1.0) >= threshold:
+            continue
+        captions_file = VAULT_ROOT / item.get("file_path", "")
+        video_file = captions_file.parent / "video.mp4"
+        if captions_file.exists() and video_file.exists():
+            targets.append((video_id, item, captions_file, video_file))
+    return sorted(targets, key=lambda t: t[1].get("score", 0.0))
+
+
+def frontmatter_value(content: str, key: str) -> str:
+    match = re.search(rf'^{key}:\s*["\']?(.*?)["\']?\s*$', content.split('---', 2)[1] if content.startswith('---') else "", re.MULTILINE)
+    return match.group(1) if match else ""
+
+
+def targets_from_files(quality_index: dict, captions_files: list) -> list:
+    """지정한 captions.md 영상 목록 (점수와 관계없이, 자막이 없는 영상 복구용)"""
+    targets = []
+    for path in captions_files:
+        captions_file = Path(path).resolve()
+        video_file = captions_file.parent / "video.mp4"
+        if not captions_file.exists() or not video_file.exists():
+            print(f"⚠️ 영상 또는 자막 파일 없음: {captions_file.parent.name}")
+            continue
+        content = captions_file.read_text(encoding='utf-8')
+        video_id = frontmatter_value(content, "video_id") or captions_file.parent.name
+        item = quality_index.get(video_id) or {
+            "channel": frontmatter_value(content, "channel"),
+            "file_path": str(captions_file.relative_to(VAULT_ROOT.resolve())),
+            "score": 0.0,
+        }
+        targets.append((video_id, item, captions_file, video_file))
+    return targets
+
+
+def transcribe(model, video_file: Path, language: str = None) -> str:
+    """Whisper로 영상 음성 전사"""
+    segments, _ = model.transcribe(str(video_file), language=language, vad_filter=True)
+    return " ".join(segment.text.strip() for segment in segments).strip()
+
+
+def replace_transcript(content: str, transcript: str) -> str:
+    """captions.md의 자막 섹션만 새 전사 결과로 교체하고 출처 표시"""
+    if TRANSCRIPT_SECTION in content:
+        head, rest = content.split(TRANSCRIPT_SECTION, 1)
+        match = re.search(r'\n## ', rest)
+        tail = rest[match.start():] if match else "\n"
+        content = f"{head}{TRANSCRIPT_SECTION}\n\n{transcript}\n{tail}"
+    else:
+        content = f"{content.rstrip()}\n\n{TRANSCRIPT_SECTION}\n\n{transcript}\n"
+
+    # frontmatter에 전사 출처 기록
+    if content.startswith('---'):
+        end = content.find('---', 3)
+        if end != -1:
+            frontmatter = re.sub(r'^transcript_source:.*\n?', '', content[3:end], flags=re.MULTILINE)
+            if not frontmatter.endswith('\n'):
+                frontmatter += '\n'
+            frontmatter += "transcript_source: whisper\n"
+            content = '---' + frontmatter + content[end:]
+    return content
+
+
+def archive_captions(captions_file: Path) -> Path:
+    """기존 자막 파일을 archive/ 폴더로 보관"""
+    archive_dir = captions_file.parent / "archive"
+    archive_dir.mkdir(exist_ok=True)
+    archived = archive_dir / f"captions_{datetime.now().strftime('%Y%m%d_%H%M%S')}.md"
+    shutil.copy2(captions_file, archived)
+    return archived
+
+
+def delete_embeddings(video_ids_by_channel: dict) -> None:
+    """재전사된 영상의 기존 임베딩 삭제 (embed.py가 다시 추가하도록)"""
+    if not CHROMA_PATH.exists():
+        return
+    client = chromadb.PersistentClient(path=str(CHROMA_PATH), settings=ChromaSettings(anonymized_telemetry=False))
+    for channel, video_ids in video_ids_by_channel.items():
+        try:
+            collection = client.get_collection(f"channel_{sanitize_collection_name(channel)}")
+            deleted = delete_video_documents(collection, video_ids)
+            print(f"  🗑️ {channel}: {len(video_ids)}개 영상의 기존 문서 {deleted}개 삭제")
+        except Exception as e:
+            print(f"  ⚠️ {channel}: 임베딩 삭제 실패 - {e}")
+
+
+def main():
+    parser = argparse.ArgumentParser(description="저품질 자막 Whisper 재전사")
+    parser.add_argument("--threshold", type=float, default=0.5, help="이 점수 미만인 자막만 재전사")
+    parser.add_argument("--channel", help="특정 채널만 처리")
+    parser.add_argument("--model", default="small", help="Whisper 모델 크기 (tiny/base/small/medium/large-v3)")
+    parser.add_argument("--language", help="전사 언어 코드 (미지정 시 자동 감지)")
+    parser.add_argument("--limit", type=int, default=0, help="최대 처리 영상 수 (0 = 제한 없음)")
+    parser.add_argument("--captions-files", nargs="+", help="점수와 관계없이 전사할 captions.md 경로")
+    args = parser.parse_args()
+
+    quality_index = load_quality_index()
+    if args.captions_files:
+        targets = targets_from_files(quality_index, args.captions_files)
+    elif not quality_index:
+        print("❌ 자막 품질 점수가 없습니다. 먼저 transcript_quality.py를 실행하세요.")
+        sys.exit(1)
+    else:
+        targets = select_targets(quality_index, args.threshold, args.channel)
+    if args.limit > 0:
+        targets = targets[:args.limit]
+    total = len(targets)
+    print(f"🎯 재전사 대상: {total}개" + ("" if args.captions_files else f" (threshold={args.threshold})"))
+    sys.stdout.flush()
+    if not targets:
+        return
+
+    try:
+        from faster_whisper import WhisperModel
+    except ImportError:
+        print("❌ faster-whisper가 설치되지 않았습니다: pip install faster-whisper")
+        sys.exit(1)
+
+    print(f"🧠 Whisper 모델 로드: {args.model}")
+    sys.stdout.flush()
+    # 데스크톱 앱의 자원 제한 설정 (0이면 faster-whisper 기본값)
+    cpu_threads = int(os.getenv("YDH_WHISPER_THREADS", "0"))
+    model = WhisperModel(args.model, device="auto", compute_type="int8", cpu_threads=cpu_threads)
+
+    retranscribed = {}
+    for idx, (video_id, item, captions_file, video_file) in enumerate(targets, 1):
+        title = captions_file.parent.name
+        try:
+            print(f"[{idx}/{total}] 🎙️ 전사 중 (기존 {item.get('score', 0.0):.2f}): {title}")
+            sys.stdout.flush()
+            transcript = transcribe(model, video_file, args.language)
+            if not transcript:
+                print(f"[{idx}/{total}] ⚠️ 전사 결과 없음: {title}")
+                continue
+
+            archived = archive_captions(captions_file)
+            content = replace_transcript(captions_file.read_text(encoding='utf-8'), transcript)
+            result = score_transcript(transcript)
+            write_quality_to_frontmatter(captions_file, content, result["score"])
+
+            quality_index[video_id] = {
+                **item,
+                **result,
+                "source": "whisper",
+                "previous_score": item.get("score"),
+                "scored_at": datetime.now().isoformat(),
+            }
+            retranscribed.setdefault(item.get("channel", ""), []).append(video_id)
+            print(f"[{idx}/{total}] ✅ {item.get('score', 0.0):.2f} → {result['score']:.2f} (보관: {archived.name})")
+            sys.stdout.flush()
+        except Exception as e:
+            print(f"[{idx}/{total}] ❌ 오류: {title} - {e}")
+
+    QUALITY_INDEX_PATH.write_text(json.dumps(quality_index, ensure_ascii=False, indent=2), encoding='utf-8')
+
+    if retranscribed:
+        print(f"\n🔄 재임베딩: {sum(len(v) for v in retranscribed.values())}개 영상")
+        delete_embeddings(retranscribed)
+        embed_channels(list(retranscribed.keys()))
+
+    print(f"\n🎉 재전사 완료: {sum(len(v) for v in retranscribed.values())}/{total}개")
+
+
+if __name__ == "__main__":
+    main()
@@ -123,6 +123,7 @@ class AnswerResponse(BaseModel):
     self_refined: bool = Field(default=False, description="Self-Refine 적용 여부")
     react_steps: List[str] = Field(default_factory=list, description="ReAct 단계들")
     token_usage: Dict[str, int] = Field(default_factory=dict, description="토큰 사용량")
+    confidence_signals: Dict[str, Any] = Field(default_factory=dict, description="검색 점수/최신성/출처 합의 기반 신뢰 신호")
 
 # =============================================================================
 # 캐시 스키마 (semantic_cache.py)
@@ -164,6 +165,7 @@ class RAGResponse(BaseModel):
     documents_found: int = Field(..., description="발견된 문서 수")
     sources_used: List[str] = Field(..., description="사용된 소스들")
     search_quality: Dict[str, Any] = Field(default_factory=dict, description="검색 품질 정보")
+    confidence_signals: Dict[str, Any] = Field(default_factory=dict, description="검색 점수/최신성/출처 합의 기반 신뢰 신호")
     
     # 디버깅 정보 (개발용)
     debug_info: Dict[str, Any] = Field(default_factory=dict, description="디버깅 정보")
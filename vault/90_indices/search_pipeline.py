@@ -10,6 +10,7 @@ HyDE → Query Rewrite → Vector Search → Conditional Re-Rank
 """
 
 import os
+import json
 import time
 import hashlib
 import re
@@ -19,6 +20,7 @@ from dotenv import load_dotenv
 import chromadb
 from chromadb.config import Settings as ChromaSettings
 from openai import OpenAI
+from embedding_backend import embedding_function_for_model, DEFAULT_MODEL_NAME
 from schemas import (
     SearchQuery, SearchConfig, SearchResult, SearchDocument, 
     QueryType, CacheKey
@@ -27,6 +29,9 @@ from schemas import (
 # 환경변수 로드
 load_dotenv()
 
+# 자막 품질 최저점일 때 유사도 감쇠 비율 (품질 1.0 = 감쇠 없음)
+QUALITY_MAX_PENALTY = 0.2
+
 class SearchPipeline:
     """Search-First 검색 파이프라인"""
     
@@ -59,6 +64,9 @@ class SearchPipeline:
         except Exception as e:
             raise ValueError(f"❌ ChromaDB 로드 실패: {e}")
         
+        # 자막 품질 점수 (transcript_quality.py 결과, 없으면 감쇠 없음)
+        self.transcript_quality = self._load_transcript_quality(chroma_path.parent / "transcript_quality.json")
+        
         # 쿼리 복잡도 분류 패턴
         self.complex_patterns = [
             r'\b(비교|분석|평가|어떤.*좋|차이|장단점)\b',  # 비교/분석
@@ -70,6 +78,24 @@ class SearchPipeline:
         
         print("🔍 Search Pipeline 초기화 완료")
     
+    def _load_transcript_quality(self, quality_path: Path) -> Dict[str, float]:
+        """영상별 자막 품질 점수 로드"""
+        if not quality_path.exists():
+            return {}
+        try:
+            data = json.loads(quality_path.read_text(encoding='utf-8'))
+            return {video_id: float(item.get('score', 1.0)) for video_id, item in data.items()}
+        except Exception as e:
+            print(f"⚠️ 자막 품질 점수 로드 실패: {e}")
+            return {}
+    
+    def _quality_weight(self, video_id: str) -> float:
+        """저품질 자막 문서의 유사도 감쇠 계수"""
+        quality = self.transcript_quality.get(video_id)
+        if quality is None:
+            return 1.0
+        return 1.0 - QUALITY_MAX_PENALTY * (1.0 - max(0.0, min(1.0, quality)))
+    
     def _get_relevance_category(self, similarity: float) -> str:
         """유사도 점수를 기반으로 연관성 카테고리 분류"""
         if similarity >= 0.8:
@@ -152,6 +178,11 @@ class SearchPipeline:
                         if sample['metadatas'] and sample['metadatas'][0]:
                             metadata_channel = sample['metadatas'][0].get('channel', '')
                             if metadata_channel == channel_name:
+                                # 컬렉션을 만든 모델(채널 언어별)로 질의를 임베딩
+                                stored_model = (collection.metadata or {}).get("embedding_model", DEFAULT_MODEL_NAME)
+                                embedding_function = embedding_function_for_model(stored_model)
+                                if embedding_function is not None:
+                                    return self.chroma_client.get_collection(collection.name, embedding_function=embedding_function)
                                 return collection
                     except:
                         continue
@@ -349,23 +380,27 @@ class SearchPipeline:
             )):
                 # 영상 메타데이터 강화
                 safe_metadata = metadata if metadata else {}
+                video_id = safe_metadata.get('video_id', 'unknown')
+                # 저품질 자막은 유사도를 감쇠하여 순위를 낮춤
+                similarity = (1 - distance) * self._quality_weight(video_id)
                 formatted_results.append({
-                    'video_id': safe_metadata.get('video_id', 'unknown'),
+                    'video_id': video_id,
                     'title': safe_metadata.get('title', 'Unknown Title'),
                     'content': doc,
                     'metadata': {
-                        'upload_date': safe_metadata.get('upload_date', '날짜 미상'),
+                        'upload_date': safe_metadata.get('upload_date') or safe_metadata.get('upload') or '날짜 미상',
                         'duration': safe_metadata.get('duration', '시간 미상'),
                         'chunk_index': safe_metadata.get('chunk_index', 0),
                         'chunk_start_time': safe_metadata.get('chunk_start_time', '00:00'),
                         'channel': safe_metadata.get('channel', 'Unknown Channel'),
                         'view_count': safe_metadata.get('view_count', 'N/A'),
+                        'transcript_quality': self.transcript_quality.get(video_id),
                         'description': safe_metadata.get('description', '')[:100] + '...' if safe_metadata.get('description') else 'N/A'
                     },
                     'distance': distance,
-                    'similarity': 1 - distance,
+                    'similarity': similarity,
                     'search_time_ms': search_time,
-                    'relevance_category': self._get_relevance_category(1 - distance)
+                    'relevance_category': self._get_relevance_category(similarity)
                 })
             
             print(f"📊 벡터 검색 완료 ({search_time:.1f}ms): {len(formatted_results)}개 문서")
@@ -0,0 +1,98 @@
+#!/usr/bin/env python3
+"""
+영상 요약 생성 스크립트 (DeepSeek)
+- 지정한 captions.md의 자막을 요약해 같은 폴더의 summary.md로 저장
+- 처리 파이프라인의 마지막 단계 (다운로드 → 자막 → 썸네일 → 임베딩 → 요약)
+"""
+
+import os
+import sys
+import argparse
+from datetime import datetime
+from pathlib import Path
+
+from dotenv import load_dotenv
+from openai import OpenAI
+
+from transcript_quality import TRANSCRIPT_SECTION, NO_TRANSCRIPT_MARK
+
+load_dotenv()
+
+SUMMARY_FILE = "summary.md"
+# 긴 자막은 앞부분만 사용 (모델 컨텍스트/비용 제한)
+MAX_TRANSCRIPT_CHARS = 24000
+
+SUMMARY_PROMPT = """다음은 유튜브 영상의 자막입니다. 핵심 내용을 한국어로 요약하세요.
+- 첫 줄: 한 문장 요약
+- 이후: 주요 내용 3~7개 글머리표
+- 자막에 없는 내용은 추가하지 마세요"""
+
+
+def read_transcript(captions_file: Path) -> str:
+    content = captions_file.read_text(encoding='utf-8')
+    if content.startswith('---'):
+        parts = content.split('---', 2)
+        content = parts[2] if len(parts) >= 3 else content
+    if TRANSCRIPT_SECTION in content:
+        content = content.split(TRANSCRIPT_SECTION, 1)[1]
+    transcript = content.split('\n## ', 1)[0].strip()
+    return "" if transcript == NO_TRANSCRIPT_MARK else transcript
+
+
+def summarize(client: OpenAI, model: str, title: str, transcript: str) -> str:
+    response = client.chat.completions.create(
+        model=model,
+        messages=[
+            {"role": "system", "content": SUMMARY_PROMPT},
+            {"role": "user", "content": f"제목: {title}\n\n{transcript[:MAX_TRANSCRIPT_CHARS]}"},
+        ],
+        temperature=0.3,
+    )
+    return (response.choices[0].message.content or "").strip()
+
+
+def main():
+    parser = argparse.ArgumentParser(description="영상 자막 요약 생성")
+    parser.add_argument("captions_files", nargs="+", help="요약할 captions.md 경로")
+    parser.add_argument("--model", default="deepseek-chat", help="요약 모델")
+    parser.add_argument("--force", action="store_true", help="이미 있는 요약도 다시 생성")
+    args = parser.parse_args()
+
+    api_key = os.getenv('DEEPSEEK_API_KEY')
+    if not api_key:
+        print("❌ DEEPSEEK_API_KEY 환경변수가 필요합니다")
+        sys.exit(1)
+    client = OpenAI(api_key=api_key, base_url="https://api.deepseek.com/v1")
+
+    total = len(args.captions_files)
+    created = 0
+    for idx, path in enumerate(args.captions_files, 1):
+        captions_file = Path(path)
+        summary_file = captions_file.parent / SUMMARY_FILE
+        title = captions_file.parent.name
+        if summary_file.exists() and not args.force:
+            print(f"[{idx}/{total}] ⏭️ 이미 요약됨: {title}")
+            continue
+        try:
+            transcript = read_transcript(captions_file)
+            if not transcript:
+                print(f"[{idx}/{total}] ⚠️ 자막 없음: {title}")
+                continue
+            print(f"[{idx}/{total}] 📝 요약 중: {title}")
+            sys.stdout.flush()
+            summary = summarize(client, args.model, title, transcript)
+            summary_file.write_text(
+                f"---\nsummarized_at: {datetime.now().isoformat()}\nmodel: {args.model}\n---\n\n{summary}\n",
+                encoding='utf-8',
+            )
+            created += 1
+            print(f"[{idx}/{total}] ✅ 요약 완료: {title}")
+        except Exception as e:
+            print(f"[{idx}/{total}] ❌ 오류: {title} - {e}")
+        sys.stdout.flush()
+
+    print(f"\n🎉 요약 생성 완료: {created}/{total}개")
+
+
+if __name__ == "__main__":
+    main()
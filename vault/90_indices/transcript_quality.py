@@ -0,0 +1,228 @@
+#!/usr/bin/env python3
+"""
+자막 품질 점수 계산 스크립트
+- 휴리스틱: 문장부호 밀도, 반복도, 어휘 다양성
+- 선택: 로컬 소형 언어모델 perplexity (--lm 모델명, transformers 설치 시)
+- 결과: captions.md frontmatter의 transcript_quality + 90_indices/transcript_quality.json
+"""
+
+import sys
+import json
+import math
+import re
+import argparse
+from collections import Counter
+from datetime import datetime
+from pathlib import Path
+
+import yaml
+
+# Vault 경로 설정
+VAULT_ROOT = Path(__file__).parent.parent
+VIDEOS_PATH = VAULT_ROOT / "10_videos"
+QUALITY_INDEX_PATH = VAULT_ROOT / "90_indices" / "transcript_quality.json"
+
+TRANSCRIPT_SECTION = "## 📝 자막 내용"
+NO_TRANSCRIPT_MARK = "*자막을 사용할 수 없습니다.*"
+
+# 휴리스틱 가중치 (perplexity 사용 시 일부를 perplexity에 할당)
+HEURISTIC_WEIGHTS = {"punctuation": 0.35, "repetition": 0.40, "diversity": 0.25}
+PERPLEXITY_WEIGHT = 0.3
+
+
+def extract_transcript(content: str) -> str:
+    """captions.md에서 자막 본문만 추출"""
+    body = content
+    if content.startswith('---'):
+        parts = content.split('---', 2)
+        if len(parts) >= 3:
+            body = parts[2]
+
+    if TRANSCRIPT_SECTION in body:
+        body = body.split(TRANSCRIPT_SECTION, 1)[1]
+        # 다음 섹션(태그/노트) 전까지
+        body = re.split(r'\n## ', body, maxsplit=1)[0]
+
+    body = body.strip()
+    return "" if body == NO_TRANSCRIPT_MARK else body
+
+
+def punctuation_score(text: str) -> float:
+    """문장부호 밀도: 자동 자막은 마침표/쉼표가 거의 없음 (100자당 1~6개를 정상으로 간주)"""
+    chars = len(re.sub(r'\s', '', text))
+    if chars == 0:
+        return 0.0
+    marks = len(re.findall(r'[.!?,。！？、…]', text))
+    per_100 = marks / chars * 100
+    if per_100 < 1.0:
+        return per_100
+    if per_100 <= 6.0:
+        return 1.0
+    # 문장부호가 지나치게 많은 경우 (잡음/기호 위주 자막)
+    return max(0.3, 6.0 / per_100)
+
+
+def repetition_score(words: list) -> float:
+    """반복도: 중복 3-gram 비율이 높을수록 낮은 점수 (자동 자막의 반복 출력 감지)"""
+    if len(words) < 3:
+        return 1.0
+    trigrams = [tuple(words[i:i + 3]) for i in range(len(words) - 2)]
+    counts = Counter(trigrams)
+    repeated = sum(c - 1 for c in counts.values() if c > 1)
+    ratio = repeated / len(trigrams)
+    return max(0.0, 1.0 - ratio * 2.5)
+
+
+def diversity_score(words: list) -> float:
+    """어휘 다양성: 고유 단어 비율 (긴 텍스트 보정을 위해 앞 2000단어만 사용)"""
+    sample = words[:2000]
+    if not sample:
+        return 0.0
+    ratio = len(set(sample)) / len(sample)
+    # 일반적인 발화는 0.35~0.7 수준
+    return max(0.0, min(1.0, ratio / 0.45))
+
+
+class PerplexityScorer:
+    """로컬 소형 언어모델 perplexity 기반 점수 (transformers 미설치 시 비활성)"""
+
+    def __init__(self, model_name: str):
+        from transformers import AutoModelForCausalLM, AutoTokenizer
+        import torch
+
+        self.torch = torch
+        self.tokenizer = AutoTokenizer.from_pretrained(model_name)
+        self.model = AutoModelForCausalLM.from_pretrained(model_name)
+        self.model.eval()
+
+    def score(self, text: str) -> float:
+        encodings = self.tokenizer(text[:3000], return_tensors="pt", truncation=True, max_length=512)
+        with self.torch.no_grad():
+            loss = self.model(**encodings, labels=encodings["input_ids"]).loss.item()
+        perplexity = math.exp(loss)
+        # perplexity 20 이하 = 1.0, 500 이상 = 0.0 (로그 스케일)
+        return max(0.0, min(1.0, 1.0 - (math.log(perplexity) - math.log(20)) / (math.log(500) - math.log(20))))
+
+
+def score_transcript(text: str, perplexity_scorer=None) -> dict:
+    """자막 품질 점수 (0.0~1.0) 및 세부 지표"""
+    words = re.findall(r'\w+', text.lower())
+    if len(words) < 20:
+        return {"score": 0.0, "punctuation": 0.0, "repetition": 0.0, "diversity": 0.0, "perplexity": None}
+
+    metrics = {
+        "punctuation": punctuation_score(text),
+        "repetition": repetition_score(words),
+        "diversity": diversity_score(words),
+    }
+    heuristic = sum(metrics[k] * w for k, w in HEURISTIC_WEIGHTS.items())
+
+    perplexity = None
+    if perplexity_scorer:
+        try:
+            perplexity = perplexity_scorer.score(text)
+        except Exception as e:
+            print(f"  ⚠️ perplexity 계산 실패: {e}")
+
+    score = heuristic if perplexity is None else heuristic * (1 - PERPLEXITY_WEIGHT) + perplexity * PERPLEXITY_WEIGHT
+    return {"score": round(score, 3), **{k: round(v, 3) for k, v in metrics.items()},
+            "perplexity": None if perplexity is None else round(perplexity, 3)}
+
+
+def write_quality_to_frontmatter(captions_file: Path, content: str, score: float) -> None:
+    """frontmatter의 transcript_quality 필드 추가/갱신"""
+    if not content.startswith('---'):
+        return
+    end = content.find('---', 3)
+    if end == -1:
+        return
+
+    frontmatter = content[3:end]
+    line = f"transcript_quality: {score}\n"
+    if re.search(r'^transcript_quality:.*$', frontmatter, flags=re.MULTILINE):
+        frontmatter = re.sub(r'^transcript_quality:.*\n?', line, frontmatter, flags=re.MULTILINE)
+    else:
+        if not frontmatter.endswith('\n'):
+            frontmatter += '\n'
+        frontmatter += line
+
+    captions_file.write_text('---' + frontmatter + content[end:], encoding='utf-8')
+
+
+def load_quality_index() -> dict:
+    if QUALITY_INDEX_PATH.exists():
+        try:
+            return json.loads(QUALITY_INDEX_PATH.read_text(encoding='utf-8'))
+        except Exception:
+            pass
+    return {}
+
+
+def main():
+    parser = argparse.ArgumentParser(description="자막 품질 점수 계산")
+    parser.add_argument("--channel", help="특정 채널만 처리")
+    parser.add_argument("--force", action="store_true", help="이미 점수가 있는 영상도 다시 계산")
+    parser.add_argument("--lm", help="perplexity 계산용 로컬 언어모델 (예: skt/kogpt2-base-v2)")
+    args = parser.parse_args()
+
+    if not VIDEOS_PATH.exists():
+        print(f"❌ 비디오 경로가 존재하지 않습니다: {VIDEOS_PATH}")
+        sys.exit(1)
+
+    perplexity_scorer = None
+    if args.lm:
+        try:
+            perplexity_scorer = PerplexityScorer(args.lm)
+            print(f"🧠 perplexity 모델 로드: {args.lm}")
+        except Exception as e:
+            print(f"⚠️ perplexity 모델을 사용할 수 없어 휴리스틱만 사용합니다: {e}")
+
+    quality_index = load_quality_index()
+    captions_files = []
+    for channel_dir in sorted(VIDEOS_PATH.iterdir()):
+        if not channel_dir.is_dir() or (args.channel and channel_dir.name != args.channel):
+            continue
+        captions_files.extend(sorted(channel_dir.rglob("captions.md")))
+
+    total = len(captions_files)
+    scored = 0
+    skipped = 0
+    print(f"📊 자막 품질 점수 계산 시작: {total}개 파일")
+    sys.stdout.flush()
+
+    for idx, captions_file in enumerate(captions_files, 1):
+        try:
+            content = captions_file.read_text(encoding='utf-8')
+            metadata = {}
+            if content.startswith('---'):
+                parts = content.split('---', 2)
+                if len(parts) >= 3:
+                    metadata = yaml.safe_load(parts[1]) or {}
+
+            video_id = str(metadata.get("video_id", ""))
+            if not args.force and "transcript_quality" in metadata and video_id in quality_index:
+                skipped += 1
+                continue
+
+            result = score_transcript(extract_transcript(content), perplexity_scorer)
+            write_quality_to_frontmatter(captions_file, content, result["score"])
+
+            if video_id:
+                quality_index[video_id] = {
+                    **result,
+                    "channel": captions_file.relative_to(VIDEOS_PATH).parts[0],
+                    "file_path": str(captions_file.relative_to(VAULT_ROOT)),
+                    "scored_at": datetime.now().isoformat(),
+                }
+            scored += 1
+            print(f"[{idx}/{total}] {result['score']:.2f} {metadata.get('title', captions_file.parent.name)}")
+            sys.stdout.flush()
+        except Exception as e:
+            print(f"[{idx}/{total}] ❌ 오류: {captions_file} - {e}")
+
+    QUALITY_INDEX_PATH.write_text(json.dumps(quality_index, ensure_ascii=False, indent=2), encoding='utf-8')
+    print(f"\n🎉 완료: {scored}개 계산, {skipped}개 스킵")
+
+
+if __name__ == "__main__":
+    main()